@@ -45,6 +45,9 @@ const RING_SRCS: &[(&[&str], &str)] = &[
     (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/ecp_nistz.c"),
     (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/gfp_p256.c"),
     (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/gfp_p384.c"),
+    (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/gfp_p521.c"),
+    (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/gfp_secp256k1.c"),
+    (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/gfp_sm2.c"),
     (&[AARCH64, ARM, X86_64, X86], "crypto/fipsmodule/ec/p256.c"),
 
     (&[X86_64, X86], "crypto/cpu-intel.c"),
@@ -974,6 +977,9 @@ fn prefix_all_symbols(pp: char, prefix_prefix: &str, prefix: &str) -> String {
         "nistz384_point_add",
         "nistz384_point_double",
         "nistz384_point_mul",
+        "nistz521_point_add",
+        "nistz521_point_double",
+        "nistz521_point_mul",
         "p256_mul_mont",
         "p256_point_add",
         "p256_point_add_affine",
@@ -988,10 +994,31 @@ fn prefix_all_symbols(pp: char, prefix_prefix: &str, prefix: &str) -> String {
         "p384_elem_neg",
         "p384_elem_sub",
         "p384_scalar_mul_mont",
+        "p521_elem_div_by_2",
+        "p521_elem_mul_mont",
+        "p521_elem_neg",
+        "p521_elem_sub",
+        "p521_scalar_mul_mont",
         "openssl_poly1305_neon2_addmulmod",
         "openssl_poly1305_neon2_blocks",
+        "secp256k1_elem_div_by_2",
+        "secp256k1_elem_mul_mont",
+        "secp256k1_elem_neg",
+        "secp256k1_elem_sub",
+        "secp256k1_point_add",
+        "secp256k1_point_double",
+        "secp256k1_point_mul",
+        "secp256k1_scalar_mul_mont",
         "sha256_block_data_order",
         "sha512_block_data_order",
+        "sm2_elem_div_by_2",
+        "sm2_elem_mul_mont",
+        "sm2_elem_neg",
+        "sm2_elem_sub",
+        "sm2_point_add",
+        "sm2_point_double",
+        "sm2_point_mul",
+        "sm2_scalar_mul_mont",
         "vpaes_ctr32_encrypt_blocks",
         "vpaes_encrypt",
         "vpaes_encrypt_key_to_bsaes",
@@ -1004,6 +1031,10 @@ fn prefix_all_symbols(pp: char, prefix_prefix: &str, prefix: &str) -> String {
         "x25519_fe_tobytes",
         "x25519_ge_double_scalarmult_vartime",
         "x25519_ge_frombytes_vartime",
+        "x25519_ge_mul_by_cofactor",
+        "x25519_ge_multi_scalarmult_vartime",
+        "x25519_ge_p3_to_montgomery_u",
+        "x25519_ge_scalarmult",
         "x25519_ge_scalarmult_base",
         "x25519_public_from_private_generic_masked",
         "x25519_sc_mask",
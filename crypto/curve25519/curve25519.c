@@ -936,6 +936,81 @@ static void ge_double_scalarmult_vartime(ge_p2 *r, const uint8_t *a,
   }
 }
 
+// r = b * B + sum(scalars[j] * points[j]) for j in [0, num_points)
+// where each scalar is encoded as in |ge_double_scalarmult_vartime| and B is
+// the Ed25519 base point. |tables| must have room for 8 * |num_points|
+// elements and |slides| must have room for 256 * |num_points| elements.
+static void ge_multi_scalarmult_vartime(ge_p2 *r, const uint8_t *b,
+                                        const uint8_t *scalars,
+                                        const ge_p3 *points, size_t num_points,
+                                        ge_cached *tables,
+                                        signed char *slides) {
+  signed char bslide[256];
+  ge_p1p1 t;
+  ge_p3 u;
+  ge_p3 A2;
+  size_t j;
+  int i;
+
+  slide(bslide, b);
+
+  for (j = 0; j < num_points; ++j) {
+    // Ai = A,3A,5A,7A,9A,11A,13A,15A
+    ge_cached *Ai = &tables[8 * j];
+    slide(&slides[256 * j], &scalars[32 * j]);
+    x25519_ge_p3_to_cached(&Ai[0], &points[j]);
+    ge_p3_dbl(&t, &points[j]);
+    x25519_ge_p1p1_to_p3(&A2, &t);
+    for (i = 0; i < 7; ++i) {
+      x25519_ge_add(&t, &A2, &Ai[i]);
+      x25519_ge_p1p1_to_p3(&u, &t);
+      x25519_ge_p3_to_cached(&Ai[i + 1], &u);
+    }
+  }
+
+  ge_p2_0(r);
+
+  for (i = 255; i >= 0; --i) {
+    if (bslide[i]) {
+      break;
+    }
+    for (j = 0; j < num_points; ++j) {
+      if (slides[256 * j + i]) {
+        break;
+      }
+    }
+    if (j < num_points) {
+      break;
+    }
+  }
+
+  for (; i >= 0; --i) {
+    ge_p2_dbl(&t, r);
+
+    for (j = 0; j < num_points; ++j) {
+      const ge_cached *Ai = &tables[8 * j];
+      signed char aslide = slides[256 * j + i];
+      if (aslide > 0) {
+        x25519_ge_p1p1_to_p3(&u, &t);
+        x25519_ge_add(&t, &u, &Ai[aslide / 2]);
+      } else if (aslide < 0) {
+        x25519_ge_p1p1_to_p3(&u, &t);
+        x25519_ge_sub(&t, &u, &Ai[(-aslide) / 2]);
+      }
+    }
+
+    if (bslide[i] > 0) {
+      x25519_ge_p1p1_to_p3(&u, &t);
+      ge_madd(&t, &u, &Bi[bslide[i] / 2]);
+    } else if (bslide[i] < 0) {
+      x25519_ge_p1p1_to_p3(&u, &t);
+      ge_msub(&t, &u, &Bi[(-bslide[i]) / 2]);
+    }
+
+    x25519_ge_p1p1_to_p2(r, &t);
+  }
+}
+
 // int64_lshift21 returns |a << 21| but is defined when shifting bits into the
 // sign bit. This works around a language flaw in C.
 static inline int64_t int64_lshift21(int64_t a) {
@@ -1853,6 +1928,83 @@ void x25519_scalar_mult_generic_masked(uint8_t out[32],
   fe_tobytes(out, &x2);
 }
 
+void x25519_ge_p3_to_montgomery_u(uint8_t out[32], const ge_p3 *A) {
+  // We only need the u-coordinate of the curve25519 point. The map is
+  // u=(y+1)/(1-y). Since y=Y/Z, this gives u=(Z+Y)/(Z-Y).
+  fe_loose zplusy, zminusy;
+  fe zminusy_inv;
+  fe_add(&zplusy, &A->Z, &A->Y);
+  fe_sub(&zminusy, &A->Z, &A->Y);
+  fe_loose_invert(&zminusy_inv, &zminusy);
+  fe_mul_tlt(&zminusy_inv, &zplusy, &zminusy_inv);
+  fe_tobytes(out, &zminusy_inv);
+}
+
+static void ge_cached_0(ge_cached *h) {
+  fe_loose_1(&h->YplusX);
+  fe_loose_1(&h->YminusX);
+  fe_loose_1(&h->Z);
+  fe_loose_0(&h->T2d);
+}
+
+static void cmov_cached(ge_cached *t, const ge_cached *u, uint8_t b) {
+  fe_cmov(&t->YplusX, &u->YplusX, b);
+  fe_cmov(&t->YminusX, &u->YminusX, b);
+  fe_cmov(&t->Z, &u->Z, b);
+  fe_cmov(&t->T2d, &u->T2d, b);
+}
+
+// r = a * A
+// where a = a[0]+256*a[1]+...+256^31 a[31].
+//
+// Unlike |ge_double_scalarmult_vartime|, this runs in time independent of
+// |a|, for use with secret scalars. A is multiplied using fixed 4-bit windows.
+void x25519_ge_scalarmult(ge_p3 *r, const uint8_t a[32], const ge_p3 *A) {
+  // Ai[i] = i * A.
+  ge_cached Ai[16];
+  ge_p1p1 t;
+  ge_p3 u;
+
+  ge_cached_0(&Ai[0]);
+  x25519_ge_p3_to_cached(&Ai[1], A);
+  for (unsigned i = 2; i < 16; i++) {
+    x25519_ge_add(&t, A, &Ai[i - 1]);
+    x25519_ge_p1p1_to_p3(&u, &t);
+    x25519_ge_p3_to_cached(&Ai[i], &u);
+  }
+
+  ge_p3_0(r);
+  for (int i = 252; i >= 0; i -= 4) {
+    for (unsigned j = 0; j < 4; j++) {
+      ge_p3_dbl(&t, r);
+      x25519_ge_p1p1_to_p3(r, &t);
+    }
+
+    signed char window = (a[i / 8] >> (i & 4)) & 0xf;
+    ge_cached selected;
+    ge_cached_0(&selected);
+    for (unsigned j = 0; j < 16; j++) {
+      cmov_cached(&selected, &Ai[j], equal((signed char)j, window));
+    }
+
+    x25519_ge_add(&t, r, &selected);
+    x25519_ge_p1p1_to_p3(r, &t);
+  }
+}
+
+// r = 8 * A
+void x25519_ge_mul_by_cofactor(ge_p3 *r, const ge_p3 *A) {
+  ge_p1p1 t;
+  ge_p2 s;
+
+  ge_p3_dbl(&t, A);
+  x25519_ge_p1p1_to_p2(&s, &t);
+  ge_p2_dbl(&t, &s);
+  x25519_ge_p1p1_to_p2(&s, &t);
+  ge_p2_dbl(&t, &s);
+  x25519_ge_p1p1_to_p3(r, &t);
+}
+
 void x25519_public_from_private_generic_masked(uint8_t out_public_value[32],
                                                    const uint8_t private_key_masked[32]) {
   uint8_t e[32];
@@ -1861,15 +2013,7 @@ void x25519_public_from_private_generic_masked(uint8_t out_public_value[32],
   ge_p3 A;
   x25519_ge_scalarmult_base(&A, e);
 
-  // We only need the u-coordinate of the curve25519 point. The map is
-  // u=(y+1)/(1-y). Since y=Y/Z, this gives u=(Z+Y)/(Z-Y).
-  fe_loose zplusy, zminusy;
-  fe zminusy_inv;
-  fe_add(&zplusy, &A.Z, &A.Y);
-  fe_sub(&zminusy, &A.Z, &A.Y);
-  fe_loose_invert(&zminusy_inv, &zminusy);
-  fe_mul_tlt(&zminusy_inv, &zplusy, &zminusy_inv);
-  fe_tobytes(out_public_value, &zminusy_inv);
+  x25519_ge_p3_to_montgomery_u(out_public_value, &A);
 }
 
 void x25519_fe_invert(fe *out, const fe *z) {
@@ -1899,6 +2043,15 @@ void x25519_ge_double_scalarmult_vartime(ge_p2 *r, const uint8_t *a,
   ge_double_scalarmult_vartime(r, a, A, b);
 }
 
+void x25519_ge_multi_scalarmult_vartime(ge_p2 *r, const uint8_t *b,
+                                        const uint8_t *scalars,
+                                        const ge_p3 *points, size_t num_points,
+                                        ge_cached *tables,
+                                        signed char *slides) {
+  ge_multi_scalarmult_vartime(r, b, scalars, points, num_points, tables,
+                              slides);
+}
+
 void x25519_sc_mask(uint8_t a[32]) {
   a[0] &= 248;
   a[31] &= 127;
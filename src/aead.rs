@@ -22,19 +22,28 @@
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
 use crate::{cpu, error, hkdf, polyfill};
-use core::{
-    convert::{TryFrom, TryInto},
-    ops::RangeFrom,
-};
+use core::{convert::TryFrom, ops::RangeFrom};
 
 pub use self::{
+    aegis::{AEGIS_128L, AEGIS_256},
+    aes_ccm::{
+        AES_128_CCM, AES_128_CCM_13_16, AES_128_CCM_13_4, AES_128_CCM_13_8, AES_128_CCM_8,
+        AES_256_CCM, AES_256_CCM_8,
+    },
     aes_gcm::{AES_128_GCM, AES_256_GCM},
-    chacha20_poly1305::CHACHA20_POLY1305,
+    aes_gcm_siv::{AES_128_GCM_SIV, AES_256_GCM_SIV},
+    aes_ocb::{AES_128_OCB, AES_256_OCB},
+    aes_siv::{AES_SIV_CMAC_256, AES_SIV_CMAC_512},
+    ascon_aead128::ASCON_AEAD128,
+    chacha20_poly1305::{CHACHA20_POLY1305, XCHACHA20_POLY1305},
+    counter_nonce_sequence::{CounterNonceSequence, Endianness, XorCounterNonceSequence},
+    key_ratchet::KeyRatchet,
     less_safe_key::LessSafeKey,
-    nonce::{Nonce, NONCE_LEN},
+    nonce::{Nonce, MAX_NONCE_LEN, NONCE_LEN},
     opening_key::OpeningKey,
     sealing_key::SealingKey,
     unbound_key::UnboundKey,
+    usage_limits::{UsageLimits, UsageTracker},
 };
 
 /// A sequences of unique nonces.
@@ -42,7 +51,8 @@ pub use self::{
 /// A given `NonceSequence` must never return the same `Nonce` twice from
 /// `advance()`.
 ///
-/// A simple counter is a reasonable (but probably not ideal) `NonceSequence`.
+/// A simple counter is a reasonable (but probably not ideal) `NonceSequence`;
+/// see `CounterNonceSequence` and `XorCounterNonceSequence`.
 ///
 /// Intentionally not `Clone` or `Copy` since cloning would allow duplication
 /// of the sequence.
@@ -70,7 +80,8 @@ pub trait BoundKey<N: NonceSequence>: core::fmt::Debug {
 /// operation. This data is authenticated but is **not** encrypted.
 ///
 /// The type `A` could be a byte slice `&[u8]`, a byte array `[u8; N]`
-/// for some constant `N`, `Vec<u8>`, etc.
+/// for some constant `N`, `Vec<u8>`, etc., or `AadSlices` for AAD that is
+/// split across several slices.
 pub struct Aad<A>(A);
 
 impl<A: AsRef<[u8]>> Aad<A> {
@@ -81,6 +92,144 @@ impl<A: AsRef<[u8]>> Aad<A> {
     }
 }
 
+impl<'a> Aad<AadSlices<'a>> {
+    /// Construct the `Aad` from the concatenation of `slices`, without
+    /// concatenating them.
+    ///
+    /// `Aad::from_slices(&[a, b])` is equivalent to `Aad::from([a, b].concat())`.
+    #[inline]
+    pub fn from_slices(slices: &'a [&'a [u8]]) -> Self {
+        Self(AadSlices(slices))
+    }
+}
+
+/// The value of an `Aad`.
+///
+/// This is implemented for every type that implements `AsRef<[u8]>` and for
+/// `AadSlices`.
+pub trait AadValue: sealed::AadValue {}
+
+impl<T> AadValue for T where T: sealed::AadValue {}
+
+pub(crate) mod sealed {
+    use super::AadSlices;
+
+    pub trait AadValue {
+        /// Calls `f` with the value as a sequence of slices.
+        fn with_slices<R>(&self, f: impl FnOnce(AadSlices) -> R) -> R;
+    }
+
+    impl<T> AadValue for T
+    where
+        T: AsRef<[u8]>,
+    {
+        #[inline]
+        fn with_slices<R>(&self, f: impl FnOnce(AadSlices) -> R) -> R {
+            f(AadSlices(core::slice::from_ref(&self.as_ref())))
+        }
+    }
+
+    impl AadValue for AadSlices<'_> {
+        #[inline]
+        fn with_slices<R>(&self, f: impl FnOnce(AadSlices) -> R) -> R {
+            f(*self)
+        }
+    }
+}
+
+impl<A: AadValue> Aad<A> {
+    #[inline]
+    fn with_slices<R>(&self, f: impl FnOnce(Aad<AadSlices>) -> R) -> R {
+        sealed::AadValue::with_slices(&self.0, |slices| f(Aad(slices)))
+    }
+}
+
+/// AAD that is split across several slices; see `Aad::from_slices()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AadSlices<'a>(&'a [&'a [u8]]);
+
+impl<'a> AadSlices<'a> {
+    /// The total length of the slices.
+    fn len(&self) -> usize {
+        self.0.iter().map(|slice| slice.len()).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|slice| slice.is_empty())
+    }
+
+    /// Returns the bytes as a single slice, if there is at most one non-empty
+    /// slice.
+    fn as_contiguous(&self) -> Option<&'a [u8]> {
+        let mut non_empty = self.0.iter().filter(|slice| !slice.is_empty());
+        match (non_empty.next(), non_empty.next()) {
+            (None, _) => Some(&[]),
+            (Some(slice), None) => Some(slice),
+            (Some(_), Some(_)) => None,
+        }
+    }
+
+    /// Copies the bytes that start at offset `start` into `out`, which must
+    /// not extend past the end.
+    fn copy_to(&self, mut start: usize, mut out: &mut [u8]) {
+        for slice in self.0 {
+            if out.is_empty() {
+                break;
+            }
+            if start >= slice.len() {
+                start -= slice.len();
+                continue;
+            }
+            let src = &slice[start..];
+            let n = core::cmp::min(src.len(), out.len());
+            out[..n].copy_from_slice(&src[..n]);
+            out = &mut out[n..];
+            start = 0;
+        }
+        assert!(out.is_empty());
+    }
+
+    /// Calls `f` with consecutive pieces of the bytes that start at offset
+    /// `start`, in order.
+    ///
+    /// Every piece except the last is a multiple of `buf.len()` bytes long.
+    /// A piece that straddles slices is assembled in `buf`.
+    fn for_each_chunk(&self, mut start: usize, buf: &mut [u8], mut f: impl FnMut(&[u8])) {
+        let unit = buf.len();
+        let mut pending = 0;
+        for slice in self.0 {
+            if start >= slice.len() {
+                start -= slice.len();
+                continue;
+            }
+            let mut slice = &slice[start..];
+            start = 0;
+
+            if pending > 0 {
+                let n = core::cmp::min(unit - pending, slice.len());
+                buf[pending..][..n].copy_from_slice(&slice[..n]);
+                pending += n;
+                slice = &slice[n..];
+                if pending < unit {
+                    continue;
+                }
+                f(buf);
+            }
+
+            let whole_len = slice.len() - (slice.len() % unit);
+            if whole_len > 0 {
+                f(&slice[..whole_len]);
+            }
+            let remainder = &slice[whole_len..];
+            buf[..remainder.len()].copy_from_slice(remainder);
+            pending = remainder.len();
+        }
+        if pending > 0 {
+            f(&buf[..pending]);
+        }
+    }
+}
+
 impl<A> AsRef<[u8]> for Aad<A>
 where
     A: AsRef<[u8]>,
@@ -133,8 +282,16 @@ impl<A> Eq for Aad<A> where A: Eq {}
 #[allow(clippy::large_enum_variant, variant_size_differences)]
 #[derive(Clone)]
 enum KeyInner {
+    Aegis128L(aegis::Key),
+    Aegis256(aegis::Key),
+    AesCcm(aes_ccm::Key),
     AesGcm(aes_gcm::Key),
+    AesGcmSiv(aes_gcm_siv::Key),
+    AesOcb(aes_ocb::Key),
+    AesSiv(aes_siv::Key),
+    AsconAead128(ascon_aead128::Key),
     ChaCha20Poly1305(chacha20_poly1305::Key),
+    XChaCha20Poly1305(chacha20_poly1305::Key),
 }
 
 impl hkdf::KeyType for &'static Algorithm {
@@ -148,16 +305,23 @@ impl hkdf::KeyType for &'static Algorithm {
 pub struct Algorithm {
     init: fn(key: &[u8], cpu_features: cpu::Features) -> Result<KeyInner, error::Unspecified>,
 
-    seal: fn(key: &KeyInner, nonce: Nonce, aad: Aad<&[u8]>, in_out: &mut [u8]) -> Tag,
+    seal: fn(key: &KeyInner, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag,
+    /// Decrypts `in_out[src]` into `in_out[..]` and calculates the tag of
+    /// the result. `received_tag` is the tag that will be checked against the
+    /// calculated tag; it is only needed by algorithms, such as GCM-SIV, that
+    /// use the tag as the IV for decryption.
     open: fn(
         key: &KeyInner,
         nonce: Nonce,
-        aad: Aad<&[u8]>,
+        aad: Aad<AadSlices>,
+        received_tag: &Tag,
         in_out: &mut [u8],
         src: RangeFrom<usize>,
     ) -> Tag,
 
     key_len: usize,
+    nonce_len: usize,
+    tag_len: usize,
     id: AlgorithmID,
 
     /// Use `max_input_len!()` to initialize this.
@@ -184,13 +348,92 @@ impl Algorithm {
     /// See also `MAX_TAG_LEN`.
     #[inline(always)]
     pub fn tag_len(&self) -> usize {
-        TAG_LEN
+        self.tag_len
     }
 
     /// The length of the nonces.
+    ///
+    /// See also `MAX_NONCE_LEN`.
     #[inline(always)]
     pub fn nonce_len(&self) -> usize {
-        NONCE_LEN
+        self.nonce_len
+    }
+
+    /// The variant of this algorithm that has `tag_len`-byte tags.
+    ///
+    /// ```
+    /// use ring::aead;
+    ///
+    /// let algorithm = aead::AES_128_GCM.with_tag_len(8)?;
+    /// assert_eq!(algorithm.tag_len(), 8);
+    /// let key = aead::UnboundKey::new(algorithm, &[0; 16])?;
+    /// # Ok::<(), ring::error::Unspecified>(())
+    /// ```
+    ///
+    /// The supported variants are:
+    ///
+    /// * AES-GCM with 12, 13, 14, 15, or 8 byte tags, as allowed by
+    ///   [NIST SP 800-38D Section 5.2.1.2]. The tag is the truncated 16-byte
+    ///   tag. Tags shorter than 12 bytes significantly limit the amount of
+    ///   data that can be protected under a key; see Appendix C of SP 800-38D.
+    /// * The AES-CCM algorithms that have other tag lengths among the
+    ///   constants in this module, e.g. `AES_128_CCM.with_tag_len(8)` is
+    ///   `AES_128_CCM_8`. In CCM, the tag length affects the whole tag, so the
+    ///   result isn't a truncation.
+    ///
+    /// Every algorithm is its own variant for its own tag length. Fails for any
+    /// other tag length.
+    ///
+    /// [NIST SP 800-38D Section 5.2.1.2]: https://doi.org/10.6028/NIST.SP.800-38D
+    pub fn with_tag_len(
+        &'static self,
+        tag_len: usize,
+    ) -> Result<&'static Self, error::Unspecified> {
+        let variants: &[&'static Self] = match self.id {
+            AlgorithmID::AES_128_GCM
+            | AlgorithmID::AES_128_GCM_8
+            | AlgorithmID::AES_128_GCM_12
+            | AlgorithmID::AES_128_GCM_13
+            | AlgorithmID::AES_128_GCM_14
+            | AlgorithmID::AES_128_GCM_15 => &[
+                &AES_128_GCM,
+                &aes_gcm::AES_128_GCM_8,
+                &aes_gcm::AES_128_GCM_12,
+                &aes_gcm::AES_128_GCM_13,
+                &aes_gcm::AES_128_GCM_14,
+                &aes_gcm::AES_128_GCM_15,
+            ],
+            AlgorithmID::AES_256_GCM
+            | AlgorithmID::AES_256_GCM_8
+            | AlgorithmID::AES_256_GCM_12
+            | AlgorithmID::AES_256_GCM_13
+            | AlgorithmID::AES_256_GCM_14
+            | AlgorithmID::AES_256_GCM_15 => &[
+                &AES_256_GCM,
+                &aes_gcm::AES_256_GCM_8,
+                &aes_gcm::AES_256_GCM_12,
+                &aes_gcm::AES_256_GCM_13,
+                &aes_gcm::AES_256_GCM_14,
+                &aes_gcm::AES_256_GCM_15,
+            ],
+            AlgorithmID::AES_128_CCM | AlgorithmID::AES_128_CCM_8 => {
+                &[&AES_128_CCM, &AES_128_CCM_8]
+            }
+            AlgorithmID::AES_256_CCM | AlgorithmID::AES_256_CCM_8 => {
+                &[&AES_256_CCM, &AES_256_CCM_8]
+            }
+            AlgorithmID::AES_128_CCM_13_4
+            | AlgorithmID::AES_128_CCM_13_8
+            | AlgorithmID::AES_128_CCM_13_16 => {
+                &[&AES_128_CCM_13_4, &AES_128_CCM_13_8, &AES_128_CCM_13_16]
+            }
+            _ => &[self],
+        };
+        variants
+            .iter()
+            .copied()
+            .find(|variant| variant.tag_len == tag_len)
+            .ok_or(error::Unspecified)
     }
 }
 
@@ -198,9 +441,36 @@ derive_debug_via_id!(Algorithm);
 
 #[derive(Debug, Eq, PartialEq)]
 enum AlgorithmID {
+    AEGIS_128L,
+    AEGIS_256,
+    AES_128_CCM,
+    AES_256_CCM,
+    AES_128_CCM_8,
+    AES_256_CCM_8,
+    AES_128_CCM_13_4,
+    AES_128_CCM_13_8,
+    AES_128_CCM_13_16,
     AES_128_GCM,
     AES_256_GCM,
+    AES_128_GCM_8,
+    AES_128_GCM_12,
+    AES_128_GCM_13,
+    AES_128_GCM_14,
+    AES_128_GCM_15,
+    AES_256_GCM_8,
+    AES_256_GCM_12,
+    AES_256_GCM_13,
+    AES_256_GCM_14,
+    AES_256_GCM_15,
+    AES_128_GCM_SIV,
+    AES_256_GCM_SIV,
+    AES_128_OCB,
+    AES_256_OCB,
+    AES_SIV_CMAC_256,
+    AES_SIV_CMAC_512,
+    ASCON_AEAD128,
     CHACHA20_POLY1305,
+    XCHACHA20_POLY1305,
 }
 
 impl PartialEq for Algorithm {
@@ -212,53 +482,95 @@ impl PartialEq for Algorithm {
 impl Eq for Algorithm {}
 
 /// A possibly valid authentication tag.
+///
+/// Most algorithms use 128-bit tags but some, such as `AES_128_CCM_8`, use
+/// shorter ones; see `Algorithm::tag_len()`.
 #[must_use]
-#[repr(C)]
 #[derive(Clone, Copy)]
-pub struct Tag([u8; TAG_LEN]);
+pub struct Tag {
+    value: [u8; MAX_TAG_LEN],
+    len: usize,
+}
+
+impl Tag {
+    /// Returns the first `len` bytes of the tag.
+    ///
+    /// Panics if `len` is longer than the tag.
+    pub(super) fn truncated(self, len: usize) -> Self {
+        assert!(len <= self.len);
+        Self {
+            value: self.value,
+            len,
+        }
+    }
+}
 
 impl AsRef<[u8]> for Tag {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        &self.value[..self.len]
     }
 }
 
+/// Fails if `value` is empty or longer than `MAX_TAG_LEN`.
 impl TryFrom<&[u8]> for Tag {
     type Error = error::Unspecified;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let raw_tag: [u8; TAG_LEN] = value.try_into().map_err(|_| error::Unspecified)?;
-        Ok(Self::from(raw_tag))
+        if value.is_empty() || value.len() > MAX_TAG_LEN {
+            return Err(error::Unspecified);
+        }
+        let mut tag = Self::from([0u8; MAX_TAG_LEN]);
+        tag.value[..value.len()].copy_from_slice(value);
+        Ok(tag.truncated(value.len()))
     }
 }
 
 impl From<[u8; TAG_LEN]> for Tag {
     #[inline]
     fn from(value: [u8; TAG_LEN]) -> Self {
-        Self(value)
+        Self {
+            value,
+            len: TAG_LEN,
+        }
     }
 }
 
-const MAX_KEY_LEN: usize = 32;
+pub(crate) const MAX_KEY_LEN: usize = 64;
 
-// All the AEADs we support use 128-bit tags.
+// The length of a full, untruncated tag of each of the AEADs we support.
 const TAG_LEN: usize = 16;
 
 /// The maximum length of a tag for the algorithms in this module.
 pub const MAX_TAG_LEN: usize = TAG_LEN;
 
-mod aes;
+mod aegis;
+pub(crate) mod aes;
+mod aes_ccm;
 mod aes_gcm;
-mod block;
-mod chacha;
+mod aes_gcm_siv;
+mod aes_ocb;
+mod aes_siv;
+mod ascon_aead128;
+pub(crate) mod block;
+pub(crate) mod chacha;
 mod chacha20_poly1305;
 pub mod chacha20_poly1305_openssh;
+pub(crate) mod cmac;
+pub mod committing;
+mod counter_nonce_sequence;
 mod gcm;
+pub mod gmac;
+mod key_ratchet;
 mod less_safe_key;
 mod nonce;
 mod opening_key;
-mod poly1305;
+pub mod poly1305;
+mod polyval;
 pub mod quic;
+mod scatter;
 mod sealing_key;
 mod shift;
+pub mod siv;
+pub mod stream;
 mod unbound_key;
+mod usage_limits;
@@ -0,0 +1,582 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AEGIS-128L and AEGIS-256, as specified in [draft-irtf-cfrg-aegis-aead].
+//!
+//! AEGIS is built on the AES round function. On x86 and x86-64 processors
+//! with AES-NI, the state is kept in SIMD registers and each round is a
+//! single `AESENC` instruction. Elsewhere, the portable constant-time AES
+//! round function from `aes` is used, which is much slower.
+//!
+//! [draft-irtf-cfrg-aegis-aead]:
+//!     https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/
+
+use super::{aes, Aad, AadSlices, Nonce, Tag};
+use crate::{aead, cpu, error};
+use core::{convert::TryInto, ops::RangeFrom};
+
+/// AEGIS-128L with 128-bit tags.
+///
+/// The keys and nonces are 128 bits. The nonces are large enough to be
+/// generated randomly.
+pub static AEGIS_128L: aead::Algorithm = aead::Algorithm {
+    key_len: 128 / 8,
+    init: init_128l,
+    seal: aegis_128l_seal,
+    open: aegis_128l_open,
+    id: aead::AlgorithmID::AEGIS_128L,
+    nonce_len: 128 / 8,
+    tag_len: super::TAG_LEN,
+    // draft-irtf-cfrg-aegis-aead Section 2.4: P_MAX is 2^61 - 1 bytes.
+    max_input_len: (1 << 61) - 1,
+};
+
+/// AEGIS-256 with 128-bit tags.
+///
+/// The keys and nonces are 256 bits. The nonces are large enough to be
+/// generated randomly.
+pub static AEGIS_256: aead::Algorithm = aead::Algorithm {
+    key_len: 256 / 8,
+    init: init_256,
+    seal: aegis_256_seal,
+    open: aegis_256_open,
+    id: aead::AlgorithmID::AEGIS_256,
+    nonce_len: 256 / 8,
+    tag_len: super::TAG_LEN,
+    // draft-irtf-cfrg-aegis-aead Section 3.4: P_MAX is 2^61 - 1 bytes.
+    max_input_len: (1 << 61) - 1,
+};
+
+const BLOCK_LEN: usize = 16;
+
+// The rate of AEGIS-128L; AEGIS-256 has a rate of `BLOCK_LEN`.
+const MAX_RATE: usize = 2 * BLOCK_LEN;
+
+const C0: [u8; BLOCK_LEN] = [
+    0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x79, 0x62,
+];
+const C1: [u8; BLOCK_LEN] = [
+    0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5, 0x28, 0xdd,
+];
+
+#[derive(Clone)]
+pub struct Key {
+    value: [u8; 256 / 8],
+    len: usize,
+    cpu_features: cpu::Features,
+}
+
+fn init_128l(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    Ok(aead::KeyInner::Aegis128L(Key::new(
+        key,
+        128 / 8,
+        cpu_features,
+    )?))
+}
+
+fn init_256(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    Ok(aead::KeyInner::Aegis256(Key::new(
+        key,
+        256 / 8,
+        cpu_features,
+    )?))
+}
+
+impl Key {
+    fn new(
+        key: &[u8],
+        len: usize,
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        if key.len() != len {
+            return Err(error::Unspecified);
+        }
+        let mut value = [0u8; 256 / 8];
+        value[..len].copy_from_slice(key);
+        Ok(Self {
+            value,
+            len,
+            cpu_features,
+        })
+    }
+}
+
+fn aegis_128l_seal(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::Aegis128L(key) => key,
+        _ => unreachable!(),
+    };
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if cpu::intel::AES.available(key.cpu_features) {
+            return unsafe { aesni::seal::<State128L<aesni::Block>>(key, nonce, aad, in_out) };
+        }
+    }
+    seal::<State128L<u128>>(key, nonce, aad, in_out)
+}
+
+fn aegis_128l_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::Aegis128L(key) => key,
+        _ => unreachable!(),
+    };
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if cpu::intel::AES.available(key.cpu_features) {
+            return unsafe { aesni::open::<State128L<aesni::Block>>(key, nonce, aad, in_out, src) };
+        }
+    }
+    open::<State128L<u128>>(key, nonce, aad, in_out, src)
+}
+
+fn aegis_256_seal(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::Aegis256(key) => key,
+        _ => unreachable!(),
+    };
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if cpu::intel::AES.available(key.cpu_features) {
+            return unsafe { aesni::seal::<State256<aesni::Block>>(key, nonce, aad, in_out) };
+        }
+    }
+    seal::<State256<u128>>(key, nonce, aad, in_out)
+}
+
+fn aegis_256_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::Aegis256(key) => key,
+        _ => unreachable!(),
+    };
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if cpu::intel::AES.available(key.cpu_features) {
+            return unsafe { aesni::open::<State256<aesni::Block>>(key, nonce, aad, in_out, src) };
+        }
+    }
+    open::<State256<u128>>(key, nonce, aad, in_out, src)
+}
+
+/// A 128-bit AEGIS state word.
+trait Block: Copy {
+    fn from_bytes(bytes: &[u8; BLOCK_LEN]) -> Self;
+    fn to_bytes(self) -> [u8; BLOCK_LEN];
+    fn xor(self, other: Self) -> Self;
+    fn and(self, other: Self) -> Self;
+
+    /// `MixColumns(ShiftRows(SubBytes(self))) ^ round_key`.
+    fn aes_round(self, round_key: Self) -> Self;
+}
+
+impl Block for u128 {
+    #[inline(always)]
+    fn from_bytes(bytes: &[u8; BLOCK_LEN]) -> Self {
+        u128::from_le_bytes(*bytes)
+    }
+
+    #[inline(always)]
+    fn to_bytes(self) -> [u8; BLOCK_LEN] {
+        self.to_le_bytes()
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        self ^ other
+    }
+
+    #[inline(always)]
+    fn and(self, other: Self) -> Self {
+        self & other
+    }
+
+    #[inline(always)]
+    fn aes_round(self, round_key: Self) -> Self {
+        aes::encrypt_round(self, round_key)
+    }
+}
+
+/// The parts of AEGIS-128L and AEGIS-256 that differ.
+///
+/// Encryption of a block `m` is `keystream() ^ m` followed by `absorb(m)`,
+/// and decryption is the inverse; partial blocks are padded with zeros before
+/// they are absorbed.
+trait State {
+    const RATE: usize;
+
+    fn new(key: &[u8], nonce: &[u8]) -> Self;
+
+    /// Absorbs `RATE` bytes.
+    fn absorb(&mut self, input: &[u8]);
+
+    /// Returns the next `RATE` bytes of keystream.
+    fn keystream(&self) -> [u8; MAX_RATE];
+
+    fn finalize(self, aad_len: usize, in_out_len: usize) -> Tag;
+}
+
+/// draft-irtf-cfrg-aegis-aead Section 2.
+struct State128L<B>([B; 8]);
+
+impl<B: Block> State128L<B> {
+    #[inline(always)]
+    fn update(&mut self, m0: B, m1: B) {
+        let s = &self.0;
+        self.0 = [
+            s[7].aes_round(s[0].xor(m0)),
+            s[0].aes_round(s[1]),
+            s[1].aes_round(s[2]),
+            s[2].aes_round(s[3]),
+            s[3].aes_round(s[4].xor(m1)),
+            s[4].aes_round(s[5]),
+            s[5].aes_round(s[6]),
+            s[6].aes_round(s[7]),
+        ];
+    }
+}
+
+impl<B: Block> State for State128L<B> {
+    const RATE: usize = 2 * BLOCK_LEN;
+
+    #[inline(always)]
+    fn new(key: &[u8], nonce: &[u8]) -> Self {
+        let k = B::from_bytes(key.try_into().unwrap());
+        let n = B::from_bytes(nonce.try_into().unwrap());
+        let c0 = B::from_bytes(&C0);
+        let c1 = B::from_bytes(&C1);
+        let mut s = Self([
+            k.xor(n),
+            c1,
+            c0,
+            c1,
+            k.xor(n),
+            k.xor(c0),
+            k.xor(c1),
+            k.xor(c0),
+        ]);
+        for _ in 0..10 {
+            s.update(n, k);
+        }
+        s
+    }
+
+    #[inline(always)]
+    fn absorb(&mut self, input: &[u8]) {
+        let (m0, m1) = split(input);
+        self.update(m0, m1);
+    }
+
+    #[inline(always)]
+    fn keystream(&self) -> [u8; MAX_RATE] {
+        let s = &self.0;
+        let z0 = s[6].xor(s[1]).xor(s[2].and(s[3]));
+        let z1 = s[2].xor(s[5]).xor(s[6].and(s[7]));
+        let mut z = [0u8; MAX_RATE];
+        z[..BLOCK_LEN].copy_from_slice(&z0.to_bytes());
+        z[BLOCK_LEN..].copy_from_slice(&z1.to_bytes());
+        z
+    }
+
+    #[inline(always)]
+    fn finalize(mut self, aad_len: usize, in_out_len: usize) -> Tag {
+        let t = self.0[2].xor(lengths(aad_len, in_out_len));
+        for _ in 0..7 {
+            self.update(t, t);
+        }
+        let s = &self.0;
+        let tag = s[0]
+            .xor(s[1])
+            .xor(s[2])
+            .xor(s[3])
+            .xor(s[4])
+            .xor(s[5])
+            .xor(s[6]);
+        Tag::from(tag.to_bytes())
+    }
+}
+
+/// draft-irtf-cfrg-aegis-aead Section 3.
+struct State256<B>([B; 6]);
+
+impl<B: Block> State256<B> {
+    #[inline(always)]
+    fn update(&mut self, m: B) {
+        let s = &self.0;
+        self.0 = [
+            s[5].aes_round(s[0].xor(m)),
+            s[0].aes_round(s[1]),
+            s[1].aes_round(s[2]),
+            s[2].aes_round(s[3]),
+            s[3].aes_round(s[4]),
+            s[4].aes_round(s[5]),
+        ];
+    }
+}
+
+impl<B: Block> State for State256<B> {
+    const RATE: usize = BLOCK_LEN;
+
+    #[inline(always)]
+    fn new(key: &[u8], nonce: &[u8]) -> Self {
+        let (k0, k1) = split::<B>(key);
+        let (n0, n1) = split::<B>(nonce);
+        let c0 = B::from_bytes(&C0);
+        let c1 = B::from_bytes(&C1);
+        let mut s = Self([k0.xor(n0), k1.xor(n1), c1, c0, k0.xor(c0), k1.xor(c1)]);
+        for _ in 0..4 {
+            s.update(k0);
+            s.update(k1);
+            s.update(k0.xor(n0));
+            s.update(k1.xor(n1));
+        }
+        s
+    }
+
+    #[inline(always)]
+    fn absorb(&mut self, input: &[u8]) {
+        self.update(B::from_bytes(input.try_into().unwrap()));
+    }
+
+    #[inline(always)]
+    fn keystream(&self) -> [u8; MAX_RATE] {
+        let s = &self.0;
+        let z = s[1].xor(s[4]).xor(s[5]).xor(s[2].and(s[3]));
+        let mut keystream = [0u8; MAX_RATE];
+        keystream[..BLOCK_LEN].copy_from_slice(&z.to_bytes());
+        keystream
+    }
+
+    #[inline(always)]
+    fn finalize(mut self, aad_len: usize, in_out_len: usize) -> Tag {
+        let t = self.0[3].xor(lengths(aad_len, in_out_len));
+        for _ in 0..7 {
+            self.update(t);
+        }
+        let s = &self.0;
+        let tag = s[0].xor(s[1]).xor(s[2]).xor(s[3]).xor(s[4]).xor(s[5]);
+        Tag::from(tag.to_bytes())
+    }
+}
+
+#[inline(always)]
+fn split<B: Block>(input: &[u8]) -> (B, B) {
+    let (b0, b1) = input.split_at(BLOCK_LEN);
+    (
+        B::from_bytes(b0.try_into().unwrap()),
+        B::from_bytes(b1.try_into().unwrap()),
+    )
+}
+
+#[inline(always)]
+fn lengths<B: Block>(aad_len: usize, in_out_len: usize) -> B {
+    let mut bytes = [0u8; BLOCK_LEN];
+    bytes[..8].copy_from_slice(&(aad_len as u64).wrapping_mul(8).to_le_bytes());
+    bytes[8..].copy_from_slice(&(in_out_len as u64).wrapping_mul(8).to_le_bytes());
+    B::from_bytes(&bytes)
+}
+
+#[inline(always)]
+fn start<S: State>(key: &Key, nonce: Nonce, aad: AadSlices) -> S {
+    let mut s = S::new(&key.value[..key.len], nonce.as_bytes());
+    aad.for_each_chunk(0, &mut [0u8; MAX_RATE][..S::RATE], |chunk| {
+        for chunk in chunk.chunks(S::RATE) {
+            let mut block = [0u8; MAX_RATE];
+            block[..chunk.len()].copy_from_slice(chunk);
+            s.absorb(&block[..S::RATE]);
+        }
+    });
+    s
+}
+
+#[inline(always)]
+fn seal<S: State>(key: &Key, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag {
+    let aad = aad.0;
+    let mut s: S = start(key, nonce, aad);
+    for chunk in in_out.chunks_mut(S::RATE) {
+        let mut block = [0u8; MAX_RATE];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let keystream = s.keystream();
+        s.absorb(&block[..S::RATE]);
+        chunk
+            .iter_mut()
+            .zip(keystream.iter())
+            .for_each(|(b, k)| *b ^= k);
+    }
+    s.finalize(aad.len(), in_out.len())
+}
+
+#[inline(always)]
+fn open<S: State>(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let aad = aad.0;
+    let mut s: S = start(key, nonce, aad);
+
+    let in_prefix_len = src.start;
+    let plaintext_len = in_out.len() - in_prefix_len;
+
+    // Decrypt forwards so that each ciphertext block is read before the
+    // plaintext overwrites it.
+    for start in (0..plaintext_len).step_by(S::RATE) {
+        let len = core::cmp::min(S::RATE, plaintext_len - start);
+        let mut block = [0u8; MAX_RATE];
+        block[..len].copy_from_slice(&in_out[(in_prefix_len + start)..][..len]);
+        let keystream = s.keystream();
+        block
+            .iter_mut()
+            .zip(keystream.iter())
+            .for_each(|(b, k)| *b ^= k);
+        // Zero the keystream bytes past the end of a partial block so that the
+        // padding absorbed is zero.
+        block[len..].iter_mut().for_each(|b| *b = 0);
+        in_out[start..][..len].copy_from_slice(&block[..len]);
+        s.absorb(&block[..S::RATE]);
+    }
+    s.finalize(aad.len(), plaintext_len)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod aesni {
+    use super::{Aad, AadSlices, Key, Nonce, State, Tag, BLOCK_LEN};
+    use core::ops::RangeFrom;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// A state word in an SSE register.
+    ///
+    /// Values of this type are only created by `seal` and `open`, which
+    /// require AES-NI to be available, so the intrinsics used here are always
+    /// supported.
+    #[derive(Clone, Copy)]
+    pub(super) struct Block(__m128i);
+
+    impl super::Block for Block {
+        #[inline(always)]
+        fn from_bytes(bytes: &[u8; BLOCK_LEN]) -> Self {
+            Self(unsafe { _mm_loadu_si128(bytes.as_ptr() as *const __m128i) })
+        }
+
+        #[inline(always)]
+        fn to_bytes(self) -> [u8; BLOCK_LEN] {
+            let mut bytes = [0u8; BLOCK_LEN];
+            unsafe { _mm_storeu_si128(bytes.as_mut_ptr() as *mut __m128i, self.0) };
+            bytes
+        }
+
+        #[inline(always)]
+        fn xor(self, other: Self) -> Self {
+            Self(unsafe { _mm_xor_si128(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        fn and(self, other: Self) -> Self {
+            Self(unsafe { _mm_and_si128(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        fn aes_round(self, round_key: Self) -> Self {
+            Self(unsafe { _mm_aesenc_si128(self.0, round_key.0) })
+        }
+    }
+
+    /// The caller must verify that AES-NI is available.
+    #[target_feature(enable = "aes,sse2")]
+    pub(super) unsafe fn seal<S: State>(
+        key: &Key,
+        nonce: Nonce,
+        aad: Aad<AadSlices>,
+        in_out: &mut [u8],
+    ) -> Tag {
+        super::seal::<S>(key, nonce, aad, in_out)
+    }
+
+    /// The caller must verify that AES-NI is available.
+    #[target_feature(enable = "aes,sse2")]
+    pub(super) unsafe fn open<S: State>(
+        key: &Key,
+        nonce: Nonce,
+        aad: Aad<AadSlices>,
+        in_out: &mut [u8],
+        src: RangeFrom<usize>,
+    ) -> Tag {
+        super::open::<S>(key, nonce, aad, in_out, src)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The AES-NI and portable implementations must agree.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_aesni_matches_portable() {
+        fn check<P: State, A: State>(key_len: usize, nonce_len: usize) {
+            let key = Key::new(&[0x2a; 32][..key_len], key_len, cpu::features()).unwrap();
+            let nonce = || Nonce::try_assume_unique_for_key_any_len(&[7; 32][..nonce_len]).unwrap();
+            let aad = Aad::from_slices(&[&[0x17; 45]]);
+            for len in 0..100 {
+                let mut portable = [0u8; 100];
+                portable
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(i, b)| *b = i as u8);
+                let mut aesni = portable;
+                let portable_tag = seal::<P>(&key, nonce(), aad, &mut portable[..len]);
+                let aesni_tag = unsafe { aesni::seal::<A>(&key, nonce(), aad, &mut aesni[..len]) };
+                assert_eq!(portable, aesni);
+                assert_eq!(portable_tag.as_ref(), aesni_tag.as_ref());
+            }
+        }
+
+        if !cpu::intel::AES.available(cpu::features()) {
+            return;
+        }
+        check::<State128L<u128>, State128L<aesni::Block>>(16, 16);
+        check::<State256<u128>, State256<aesni::Block>>(32, 32);
+    }
+}
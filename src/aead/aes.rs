@@ -22,10 +22,14 @@ use crate::{
     c, cpu,
     endian::{ArrayEncoding, BigEndian},
     error,
-    polyfill::{self, ChunksFixed},
+    polyfill::{self, array_map::Map, ChunksFixed},
 };
 use core::ops::RangeFrom;
 
+pub(crate) use self::portable::{encrypt_round, DecryptKey};
+
+mod portable;
+
 #[derive(Clone)]
 pub(crate) struct Key {
     inner: AES_KEY,
@@ -207,7 +211,7 @@ impl Key {
     }
 
     #[inline]
-    pub(super) fn ctr32_encrypt_within(
+    pub(crate) fn ctr32_encrypt_within(
         &self,
         in_out: &mut [u8],
         src: RangeFrom<usize>,
@@ -316,6 +320,7 @@ pub(super) struct AES_KEY {
 // Keep this in sync with `AES_MAXNR` in aes.h.
 const MAX_ROUNDS: usize = 14;
 
+#[derive(Clone, Copy)]
 pub enum Variant {
     AES_128,
     AES_256,
@@ -323,20 +328,41 @@ pub enum Variant {
 
 /// Nonce || Counter, all big-endian.
 #[repr(transparent)]
-pub(super) struct Counter([BigEndian<u32>; 4]);
+pub(crate) struct Counter([BigEndian<u32>; 4]);
 
 impl Counter {
     pub fn one(nonce: Nonce) -> Self {
-        let nonce = nonce.as_ref().chunks_fixed();
+        let nonce = nonce.as_96_bits().chunks_fixed();
         Self([nonce[0].into(), nonce[1].into(), nonce[2].into(), 1.into()])
     }
 
+    /// Constructs a counter from a whole initial counter block, for modes
+    /// other than GCM that derive the initial counter block themselves.
+    ///
+    /// This is "less safe" because the 32-bit counter in the last four bytes
+    /// of `block` will wrap around if it overflows, so the caller must ensure
+    /// that it has enough headroom for the input it will be used with.
+    pub fn from_block_less_safe(block: Block) -> Self {
+        let block: &[[u8; 4]; 4] = block.as_ref().chunks_fixed();
+        Self(block.array_map(BigEndian::from))
+    }
+
     pub fn increment(&mut self) -> Iv {
         let iv = Iv(self.0);
         self.increment_by_less_safe(1);
         iv
     }
 
+    /// Returns the counter that is `increment_by` blocks after this one,
+    /// leaving this one unchanged, so that separate parts of the input can be
+    /// encrypted independently.
+    #[cfg(feature = "parallel")]
+    pub(super) fn offset_less_safe(&self, increment_by: u32) -> Self {
+        let mut offset = Self(self.0);
+        offset.increment_by_less_safe(increment_by);
+        offset
+    }
+
     fn increment_by_less_safe(&mut self, increment_by: u32) {
         let old_value: u32 = self.0[3].into();
         self.0[3] = (old_value + increment_by).into();
@@ -0,0 +1,292 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Portable implementations of the AES operations that the C and assembly
+//! language implementations don't provide: the inverse cipher, and the bare
+//! round function used by AEGIS.
+//!
+//! The S-box is computed by inversion in GF(2^8) instead of using lookup
+//! tables, so the memory access pattern doesn't depend on the key or the
+//! data. All 16 bytes of the state are processed at once, one byte per lane of
+//! a `u128`, but this is still much slower than the C and assembly language
+//! implementations, so modes should avoid decryption when they can.
+
+use super::{Block, Variant, BLOCK_LEN, MAX_ROUNDS};
+use crate::error;
+
+#[derive(Clone)]
+pub(crate) struct DecryptKey {
+    round_keys: [u128; MAX_ROUNDS + 1],
+    rounds: usize,
+}
+
+impl DecryptKey {
+    pub(crate) fn new(bytes: &[u8], variant: Variant) -> Result<Self, error::Unspecified> {
+        let nk = match variant {
+            Variant::AES_128 => 4,
+            Variant::AES_256 => 8,
+        };
+        if bytes.len() != 4 * nk {
+            return Err(error::Unspecified);
+        }
+        let rounds = nk + 6;
+
+        // FIPS 197 Section 5.2.
+        let mut w = [[0u8; 4]; 4 * (MAX_ROUNDS + 1)];
+        for (w, word) in w.iter_mut().zip(bytes.chunks(4)) {
+            w.copy_from_slice(word);
+        }
+        let mut rcon = 1u8;
+        for i in nk..(4 * (rounds + 1)) {
+            let mut temp = w[i - 1];
+            if i % nk == 0 {
+                temp.rotate_left(1);
+                temp = sub_word(temp);
+                temp[0] ^= rcon;
+                rcon = xtime_u8(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                temp = sub_word(temp);
+            }
+            for (t, p) in temp.iter_mut().zip(w[i - nk].iter()) {
+                *t ^= p;
+            }
+            w[i] = temp;
+        }
+
+        let mut round_keys = [0u128; MAX_ROUNDS + 1];
+        for (round_key, words) in round_keys.iter_mut().zip(w.chunks(4)) {
+            let mut bytes = [0u8; BLOCK_LEN];
+            for (b, word) in bytes.chunks_mut(4).zip(words) {
+                b.copy_from_slice(word);
+            }
+            *round_key = u128::from_le_bytes(bytes);
+        }
+
+        Ok(Self { round_keys, rounds })
+    }
+
+    /// FIPS 197 Section 5.3.
+    pub(crate) fn decrypt_block(&self, a: Block) -> Block {
+        let rounds = self.rounds;
+        let mut state = u128::from_le_bytes(*a.as_ref()) ^ self.round_keys[rounds];
+        for round_key in self.round_keys[1..rounds].iter().rev() {
+            state = inv_sub_bytes(inv_shift_rows(state)) ^ round_key;
+            state = inv_mix_columns(state);
+        }
+        state = inv_sub_bytes(inv_shift_rows(state)) ^ self.round_keys[0];
+        Block::from(&state.to_le_bytes())
+    }
+}
+
+/// One AES encryption round, `MixColumns(ShiftRows(SubBytes(state))) ^
+/// round_key`, i.e. the operation of the x86 `AESENC` instruction. The blocks
+/// are loaded with `u128::from_le_bytes`.
+pub(crate) fn encrypt_round(state: u128, round_key: u128) -> u128 {
+    mix_columns(shift_rows(sub_bytes(state))) ^ round_key
+}
+
+// The state is stored with byte `4 * c + r` of the block, i.e. row `r` of
+// column `c`, in bits `8 * (4 * c + r)..`.
+
+/// Replicates `byte` into every byte of a `u128`.
+#[inline(always)]
+const fn splat(byte: u8) -> u128 {
+    (byte as u128) * 0x0101_0101_0101_0101_0101_0101_0101_0101
+}
+
+/// Replicates `word` into every 32-bit lane (column) of a `u128`.
+#[inline(always)]
+const fn splat_u32(word: u32) -> u128 {
+    (word as u128) * 0x0000_0001_0000_0001_0000_0001_0000_0001
+}
+
+/// Multiplies each byte by x in GF(2^8).
+#[inline(always)]
+fn xtime(x: u128) -> u128 {
+    ((x & splat(0x7f)) << 1) ^ (((x >> 7) & splat(0x01)) * 0x1b)
+}
+
+fn xtime_u8(x: u8) -> u8 {
+    xtime(u128::from(x)) as u8
+}
+
+/// Multiplies each byte of `a` by the corresponding byte of `b` in GF(2^8).
+fn gf_mul(mut a: u128, b: u128) -> u128 {
+    let mut r = 0;
+    for i in 0..8 {
+        let mask = ((b >> i) & splat(0x01)) * 0xff;
+        r ^= a & mask;
+        a = xtime(a);
+    }
+    r
+}
+
+/// Inverts each byte in GF(2^8), mapping zero to zero, by raising it to the
+/// 254th power.
+fn gf_inv(x: u128) -> u128 {
+    let x2 = gf_mul(x, x);
+    let x3 = gf_mul(x2, x);
+    let x6 = gf_mul(x3, x3);
+    let x12 = gf_mul(x6, x6);
+    let x15 = gf_mul(x12, x3);
+    let mut x240 = x15;
+    for _ in 0..4 {
+        x240 = gf_mul(x240, x240);
+    }
+    let x252 = gf_mul(x240, x12);
+    gf_mul(x252, x2)
+}
+
+/// Rotates each byte left by `k` bits.
+#[inline(always)]
+fn rotl_bytes(x: u128, k: u32) -> u128 {
+    ((x << k) & splat(0xff << k)) | ((x >> (8 - k)) & splat(0xff >> (8 - k)))
+}
+
+fn sub_bytes(x: u128) -> u128 {
+    let b = gf_inv(x);
+    b ^ rotl_bytes(b, 1) ^ rotl_bytes(b, 2) ^ rotl_bytes(b, 3) ^ rotl_bytes(b, 4) ^ splat(0x63)
+}
+
+fn inv_sub_bytes(y: u128) -> u128 {
+    gf_inv(rotl_bytes(y, 1) ^ rotl_bytes(y, 3) ^ rotl_bytes(y, 6) ^ splat(0x05))
+}
+
+fn sub_word(word: [u8; 4]) -> [u8; 4] {
+    let substituted = sub_bytes(u128::from(u32::from_le_bytes(word)));
+    (substituted as u32).to_le_bytes()
+}
+
+fn shift_rows(state: u128) -> u128 {
+    let bytes = state.to_le_bytes();
+    let mut shifted = [0u8; BLOCK_LEN];
+    for (i, b) in shifted.iter_mut().enumerate() {
+        let (c, r) = (i / 4, i % 4);
+        *b = bytes[4 * ((c + r) % 4) + r];
+    }
+    u128::from_le_bytes(shifted)
+}
+
+fn inv_shift_rows(state: u128) -> u128 {
+    let bytes = state.to_le_bytes();
+    let mut shifted = [0u8; BLOCK_LEN];
+    for (i, b) in shifted.iter_mut().enumerate() {
+        let (c, r) = (i / 4, i % 4);
+        *b = bytes[4 * ((c + 4 - r) % 4) + r];
+    }
+    u128::from_le_bytes(shifted)
+}
+
+/// Moves row `r + k` of each column into row `r`.
+#[inline(always)]
+fn rotate_rows(x: u128, k: u32) -> u128 {
+    let low = splat_u32(u32::MAX >> (8 * k));
+    ((x >> (8 * k)) & low) | ((x << (32 - 8 * k)) & !low)
+}
+
+fn mix_columns(a: u128) -> u128 {
+    let a2 = xtime(a);
+    let a3 = a2 ^ a;
+    a2 ^ rotate_rows(a3, 1) ^ rotate_rows(a, 2) ^ rotate_rows(a, 3)
+}
+
+fn inv_mix_columns(a: u128) -> u128 {
+    let a2 = xtime(a);
+    let a4 = xtime(a2);
+    let a8 = xtime(a4);
+    let a9 = a8 ^ a;
+    let a11 = a9 ^ a2;
+    let a13 = a9 ^ a4;
+    let a14 = a8 ^ a4 ^ a2;
+    a14 ^ rotate_rows(a11, 1) ^ rotate_rows(a13, 2) ^ rotate_rows(a9, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::Key, *};
+    use crate::{cpu, test};
+    use core::convert::TryInto;
+
+    #[test]
+    pub fn test_aes_decrypt() {
+        test::run(test_file!("../aes_tests.txt"), |section, test_case| {
+            assert_eq!(section, "");
+            let key_bytes = test_case.consume_bytes("Key");
+            let variant = match key_bytes.len() {
+                16 => Variant::AES_128,
+                32 => Variant::AES_256,
+                _ => unreachable!(),
+            };
+            let input = test_case.consume_bytes("Input");
+            let expected_output = test_case.consume_bytes("Output");
+            let output: &[u8; BLOCK_LEN] = expected_output.as_slice().try_into()?;
+
+            let key = DecryptKey::new(&key_bytes, variant)?;
+            let decrypted = key.decrypt_block(Block::from(output));
+            assert_eq!(decrypted.as_ref(), &input[..]);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    pub fn test_aes_decrypt_round_trip() {
+        let cpu_features = cpu::features();
+        for (key_len, variant) in [(16, Variant::AES_128), (32, Variant::AES_256)] {
+            let mut key_bytes = [0u8; 32];
+            key_bytes
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, b)| *b = (i * 37 + 11) as u8);
+            let key_bytes = &key_bytes[..key_len];
+            let encrypt_key = Key::new(key_bytes, variant, cpu_features).unwrap();
+            let decrypt_key = DecryptKey::new(key_bytes, variant).unwrap();
+            let mut block = [0u8; BLOCK_LEN];
+            for _ in 0..100 {
+                let encrypted = encrypt_key.encrypt_block(Block::from(&block));
+                assert_eq!(decrypt_key.decrypt_block(encrypted).as_ref(), &block);
+                block = *encrypted.as_ref();
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub_bytes() {
+        // FIPS 197 Figure 7.
+        assert_eq!(sub_bytes(0x00) as u8, 0x63);
+        assert_eq!(sub_bytes(0x53) as u8, 0xed);
+        assert_eq!(inv_sub_bytes(0x63) as u8, 0x00);
+        assert_eq!(inv_sub_bytes(0xed) as u8, 0x53);
+    }
+
+    #[test]
+    fn test_encrypt_round() {
+        // FIPS 197 Appendix B, round 1: "Start of Round" to "Start of Round"
+        // using the round key of round 1.
+        let state = u128::from_le_bytes(hex16("193de3bea0f4e22b9ac68d2ae9f84808"));
+        let round_key = u128::from_le_bytes(hex16("a0fafe1788542cb123a339392a6c7605"));
+        let expected = hex16("a49c7ff2689f352b6b5bea43026a5049");
+        assert_eq!(encrypt_round(state, round_key).to_le_bytes(), expected);
+    }
+
+    fn hex16(s: &str) -> [u8; BLOCK_LEN] {
+        test::from_hex(s).unwrap().as_slice().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_decrypt_key_len() {
+        assert!(DecryptKey::new(&[0; 16], Variant::AES_256).is_err());
+        assert!(DecryptKey::new(&[0; 32], Variant::AES_128).is_err());
+    }
+}
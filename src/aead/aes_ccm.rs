@@ -0,0 +1,369 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-CCM, as described in [NIST SP 800-38C] and [RFC 3610].
+//!
+//! CCM is parameterized by the nonce length, which determines the maximum
+//! input length, and the tag length. Each combination is a separate
+//! `aead::Algorithm`.
+//!
+//! [NIST SP 800-38C]: https://doi.org/10.6028/NIST.SP.800-38C
+//! [RFC 3610]: https://tools.ietf.org/html/rfc3610
+
+use super::{
+    aes::{self, Counter},
+    block::{Block, BLOCK_LEN},
+    shift, Aad, AadSlices, Nonce, Tag,
+};
+use crate::{aead, cpu, error, polyfill};
+use core::ops::RangeFrom;
+
+/// AES-128 in CCM mode with 128-bit tags and 96 bit nonces, as used in TLS
+/// ([RFC 6655]) and as AEAD_AES_128_CCM ([RFC 5116]).
+///
+/// The maximum input length is 2^24 - 1 bytes.
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116#section-5.3
+pub static AES_128_CCM: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128_tag_16,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_128_CCM,
+    nonce_len: super::NONCE_LEN,
+    tag_len: 16,
+    max_input_len: max_input_len(super::NONCE_LEN),
+};
+
+/// AES-256 in CCM mode with 128-bit tags and 96 bit nonces, as used in TLS
+/// ([RFC 6655]) and as AEAD_AES_256_CCM ([RFC 5116]).
+///
+/// The maximum input length is 2^24 - 1 bytes.
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116#section-5.4
+pub static AES_256_CCM: aead::Algorithm = aead::Algorithm {
+    key_len: 32,
+    init: init_256_tag_16,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_256_CCM,
+    nonce_len: super::NONCE_LEN,
+    tag_len: 16,
+    max_input_len: max_input_len(super::NONCE_LEN),
+};
+
+/// AES-128 in CCM mode with 64-bit tags and 96 bit nonces, as used in the
+/// TLS `_CCM_8` cipher suites ([RFC 6655], [RFC 7251]).
+///
+/// The maximum input length is 2^24 - 1 bytes.
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+/// [RFC 7251]: https://tools.ietf.org/html/rfc7251
+pub static AES_128_CCM_8: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128_tag_8,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_128_CCM_8,
+    nonce_len: super::NONCE_LEN,
+    tag_len: 8,
+    max_input_len: max_input_len(super::NONCE_LEN),
+};
+
+/// AES-256 in CCM mode with 64-bit tags and 96 bit nonces, as used in the
+/// TLS `_CCM_8` cipher suites ([RFC 6655]).
+///
+/// The maximum input length is 2^24 - 1 bytes.
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+pub static AES_256_CCM_8: aead::Algorithm = aead::Algorithm {
+    key_len: 32,
+    init: init_256_tag_8,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_256_CCM_8,
+    nonce_len: super::NONCE_LEN,
+    tag_len: 8,
+    max_input_len: max_input_len(super::NONCE_LEN),
+};
+
+/// AES-128 in CCM mode with 32-bit tags and 104 bit nonces, as used by
+/// Bluetooth Low Energy and by IEEE 802.15.4 (e.g. Zigbee) at security
+/// level ENC-MIC-32.
+///
+/// The maximum input length is 2^16 - 1 bytes.
+pub static AES_128_CCM_13_4: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128_tag_4,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_128_CCM_13_4,
+    nonce_len: NONCE_LEN_13,
+    tag_len: 4,
+    max_input_len: max_input_len(NONCE_LEN_13),
+};
+
+/// AES-128 in CCM mode with 64-bit tags and 104 bit nonces, as used by
+/// IEEE 802.15.4 (e.g. Zigbee) at security level ENC-MIC-64, and in the
+/// [RFC 3610] examples.
+///
+/// The maximum input length is 2^16 - 1 bytes.
+///
+/// [RFC 3610]: https://tools.ietf.org/html/rfc3610
+pub static AES_128_CCM_13_8: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128_tag_8,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_128_CCM_13_8,
+    nonce_len: NONCE_LEN_13,
+    tag_len: 8,
+    max_input_len: max_input_len(NONCE_LEN_13),
+};
+
+/// AES-128 in CCM mode with 128-bit tags and 104 bit nonces, as used by
+/// IEEE 802.15.4 (e.g. Zigbee) at security level ENC-MIC-128.
+///
+/// The maximum input length is 2^16 - 1 bytes.
+pub static AES_128_CCM_13_16: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128_tag_16,
+    seal: aes_ccm_seal,
+    open: aes_ccm_open,
+    id: aead::AlgorithmID::AES_128_CCM_13_16,
+    nonce_len: NONCE_LEN_13,
+    tag_len: 16,
+    max_input_len: max_input_len(NONCE_LEN_13),
+};
+
+const NONCE_LEN_13: usize = 13;
+
+#[derive(Clone)]
+pub struct Key {
+    aes_key: aes::Key,
+    tag_len: usize,
+}
+
+fn init_128_tag_4(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_128, 4, cpu_features)
+}
+
+fn init_128_tag_8(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_128, 8, cpu_features)
+}
+
+fn init_128_tag_16(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_128, 16, cpu_features)
+}
+
+fn init_256_tag_8(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_256, 8, cpu_features)
+}
+
+fn init_256_tag_16(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_256, 16, cpu_features)
+}
+
+fn init(
+    key: &[u8],
+    variant: aes::Variant,
+    tag_len: usize,
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    let aes_key = aes::Key::new(key, variant, cpu_features)?;
+    Ok(aead::KeyInner::AesCcm(Key { aes_key, tag_len }))
+}
+
+fn aes_ccm_seal(key: &aead::KeyInner, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesCcm(key) => key,
+        _ => unreachable!(),
+    };
+    let mac = cbc_mac(key, &nonce, aad, in_out);
+    ctr_within(&key.aes_key, &nonce, in_out, 0..);
+    encrypt_mac(key, &nonce, mac)
+}
+
+fn aes_ccm_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesCcm(key) => key,
+        _ => unreachable!(),
+    };
+    let plaintext_len = in_out.len() - src.start;
+    ctr_within(&key.aes_key, &nonce, in_out, src);
+    let mac = cbc_mac(key, &nonce, aad, &in_out[..plaintext_len]);
+    encrypt_mac(key, &nonce, mac)
+}
+
+// The `L` parameter: the length of the field of each counter block that holds
+// the block counter, and of the field of B_0 that holds the plaintext length.
+#[inline]
+const fn counter_len(nonce_len: usize) -> usize {
+    BLOCK_LEN - 1 - nonce_len
+}
+
+// Returns the counter block A_i without the counter, i.e. A_0.
+fn counter_block_zero(nonce: &Nonce) -> [u8; BLOCK_LEN] {
+    let nonce = nonce.as_bytes();
+    let mut block = [0u8; BLOCK_LEN];
+    block[0] = (counter_len(nonce.len()) - 1) as u8;
+    block[1..][..nonce.len()].copy_from_slice(nonce);
+    block
+}
+
+// https://tools.ietf.org/html/rfc3610#section-2.2
+fn cbc_mac(key: &Key, nonce: &Nonce, aad: Aad<AadSlices>, plaintext: &[u8]) -> [u8; BLOCK_LEN] {
+    let nonce = nonce.as_bytes();
+    let aad = aad.0;
+    let counter_len = counter_len(nonce.len());
+
+    let mut b_0 = [0u8; BLOCK_LEN];
+    let adata = if aad.is_empty() { 0 } else { 0x40 };
+    b_0[0] = adata | ((((key.tag_len - 2) / 2) as u8) << 3) | ((counter_len - 1) as u8);
+    b_0[1..][..nonce.len()].copy_from_slice(nonce);
+    let plaintext_len = polyfill::u64_from_usize(plaintext.len()).to_be_bytes();
+    b_0[(BLOCK_LEN - counter_len)..].copy_from_slice(&plaintext_len[(8 - counter_len)..]);
+
+    let mut mac = CbcMac::new(&key.aes_key);
+    mac.update_block(b_0);
+
+    if !aad.is_empty() {
+        // The AAD is prefixed with its encoded length and then the whole
+        // thing is padded to a multiple of the block length.
+        let aad_len = polyfill::u64_from_usize(aad.len());
+        let mut first = [0u8; BLOCK_LEN];
+        let prefix_len = if aad_len < 0xff00 {
+            first[..2].copy_from_slice(&(aad_len as u16).to_be_bytes());
+            2
+        } else if aad_len <= u64::from(u32::MAX) {
+            first[..2].copy_from_slice(&[0xff, 0xfe]);
+            first[2..6].copy_from_slice(&(aad_len as u32).to_be_bytes());
+            6
+        } else {
+            first[..2].copy_from_slice(&[0xff, 0xff]);
+            first[2..10].copy_from_slice(&aad_len.to_be_bytes());
+            10
+        };
+        let first_len = core::cmp::min(aad.len(), BLOCK_LEN - prefix_len);
+        aad.copy_to(0, &mut first[prefix_len..][..first_len]);
+        mac.update_block(first);
+        aad.for_each_chunk(first_len, &mut [0u8; BLOCK_LEN], |chunk| {
+            mac.update_padded(chunk)
+        });
+    }
+
+    mac.update_padded(plaintext);
+    mac.state
+}
+
+// Encrypts the first `tag_len` bytes of the CBC-MAC with the key stream block
+// S_0 to produce the tag.
+fn encrypt_mac(key: &Key, nonce: &Nonce, mac: [u8; BLOCK_LEN]) -> Tag {
+    let s_0 = key
+        .aes_key
+        .encrypt_block(Block::from(&counter_block_zero(nonce)));
+    let tag = Block::from(&mac) ^ s_0;
+    Tag::from(*tag.as_ref()).truncated(key.tag_len)
+}
+
+struct CbcMac<'k> {
+    aes_key: &'k aes::Key,
+    state: [u8; BLOCK_LEN],
+}
+
+impl<'k> CbcMac<'k> {
+    fn new(aes_key: &'k aes::Key) -> Self {
+        Self {
+            aes_key,
+            state: [0u8; BLOCK_LEN],
+        }
+    }
+
+    fn update_block(&mut self, block: [u8; BLOCK_LEN]) {
+        let input = Block::from(&self.state) ^ Block::from(&block);
+        self.state = *self.aes_key.encrypt_block(input).as_ref();
+    }
+
+    // Zero pads the last partial block, if any.
+    fn update_padded(&mut self, input: &[u8]) {
+        for chunk in input.chunks(BLOCK_LEN) {
+            let mut block = [0u8; BLOCK_LEN];
+            block[..chunk.len()].copy_from_slice(chunk);
+            self.update_block(block);
+        }
+    }
+}
+
+// AES-CTR from `in_out[src]` into `in_out[..]`, starting with the counter
+// block A_1. The 32-bit counter of `aes::Counter` never overflows into the
+// nonce because the input is limited to fewer than 2^(8 * L) bytes.
+fn ctr_within(key: &aes::Key, nonce: &Nonce, in_out: &mut [u8], src: RangeFrom<usize>) {
+    let mut a_1 = counter_block_zero(nonce);
+    a_1[BLOCK_LEN - 1] = 1;
+    let mut ctr = Counter::from_block_less_safe(Block::from(&a_1));
+
+    let in_prefix_len = src.start;
+    let whole_len = {
+        let in_out_len = in_out.len() - in_prefix_len;
+        in_out_len - (in_out_len % BLOCK_LEN)
+    };
+    if whole_len > 0 {
+        key.ctr32_encrypt_within(&mut in_out[..(whole_len + in_prefix_len)], src, &mut ctr);
+    }
+
+    shift::shift_partial((in_prefix_len, &mut in_out[whole_len..]), |remainder| {
+        let mut input = Block::zero();
+        input.overwrite_part_at(0, remainder);
+        key.encrypt_iv_xor_block(ctr.into(), input)
+    });
+}
+
+// The plaintext length must fit in the L-byte length field of B_0.
+const fn max_input_len(nonce_len: usize) -> u64 {
+    (1u64 << (8 * counter_len(nonce_len))) - 1
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn max_input_len_test() {
+        assert_eq!(super::AES_128_CCM.max_input_len, (1u64 << 24) - 1);
+        assert_eq!(super::AES_256_CCM_8.max_input_len, (1u64 << 24) - 1);
+        assert_eq!(super::AES_128_CCM_13_4.max_input_len, (1u64 << 16) - 1);
+    }
+}
@@ -15,7 +15,7 @@
 use super::{
     aes::{self, Counter},
     block::{Block, BLOCK_LEN},
-    gcm, shift, Aad, Nonce, Tag,
+    gcm, scatter, shift, Aad, AadSlices, Nonce, Tag,
 };
 use crate::{aead, cpu, error, polyfill};
 use core::ops::RangeFrom;
@@ -27,6 +27,8 @@ pub static AES_128_GCM: aead::Algorithm = aead::Algorithm {
     seal: aes_gcm_seal,
     open: aes_gcm_open,
     id: aead::AlgorithmID::AES_128_GCM,
+    nonce_len: super::NONCE_LEN,
+    tag_len: super::TAG_LEN,
     max_input_len: AES_GCM_MAX_INPUT_LEN,
 };
 
@@ -37,9 +39,42 @@ pub static AES_256_GCM: aead::Algorithm = aead::Algorithm {
     seal: aes_gcm_seal,
     open: aes_gcm_open,
     id: aead::AlgorithmID::AES_256_GCM,
+    nonce_len: super::NONCE_LEN,
+    tag_len: super::TAG_LEN,
     max_input_len: AES_GCM_MAX_INPUT_LEN,
 };
 
+// AES-GCM with truncated tags; see `aead::Algorithm::with_tag_len()`.
+macro_rules! truncated_aes_gcm {
+    ( $( $name:ident: $key_len:expr, $init:ident, $tag_len:expr; )+ ) => {
+        $(
+            pub(super) static $name: aead::Algorithm = aead::Algorithm {
+                key_len: $key_len,
+                init: $init,
+                seal: aes_gcm_seal,
+                open: aes_gcm_open,
+                id: aead::AlgorithmID::$name,
+                nonce_len: super::NONCE_LEN,
+                tag_len: $tag_len,
+                max_input_len: AES_GCM_MAX_INPUT_LEN,
+            };
+        )+
+    };
+}
+
+truncated_aes_gcm! {
+    AES_128_GCM_8: 16, init_128, 8;
+    AES_128_GCM_12: 16, init_128, 12;
+    AES_128_GCM_13: 16, init_128, 13;
+    AES_128_GCM_14: 16, init_128, 14;
+    AES_128_GCM_15: 16, init_128, 15;
+    AES_256_GCM_8: 32, init_256, 8;
+    AES_256_GCM_12: 32, init_256, 12;
+    AES_256_GCM_13: 32, init_256, 13;
+    AES_256_GCM_14: 32, init_256, 14;
+    AES_256_GCM_15: 32, init_256, 15;
+}
+
 #[derive(Clone)]
 pub struct Key {
     gcm_key: gcm::Key, // First because it has a large alignment requirement.
@@ -59,14 +94,24 @@ fn init(
     variant: aes::Variant,
     cpu_features: cpu::Features,
 ) -> Result<aead::KeyInner, error::Unspecified> {
-    let aes_key = aes::Key::new(key, variant, cpu_features)?;
-    let gcm_key = gcm::Key::new(aes_key.encrypt_block(Block::zero()), cpu_features);
-    Ok(aead::KeyInner::AesGcm(Key { gcm_key, aes_key }))
+    Key::new(key, variant, cpu_features).map(aead::KeyInner::AesGcm)
+}
+
+impl Key {
+    pub(super) fn new(
+        key: &[u8],
+        variant: aes::Variant,
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let aes_key = aes::Key::new(key, variant, cpu_features)?;
+        let gcm_key = gcm::Key::new(aes_key.encrypt_block(Block::zero()), cpu_features);
+        Ok(Self { gcm_key, aes_key })
+    }
 }
 
 const CHUNK_BLOCKS: usize = 3 * 1024 / 16;
 
-fn aes_gcm_seal(key: &aead::KeyInner, nonce: Nonce, aad: Aad<&[u8]>, in_out: &mut [u8]) -> Tag {
+fn aes_gcm_seal(key: &aead::KeyInner, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag {
     let Key { gcm_key, aes_key } = match key {
         aead::KeyInner::AesGcm(key) => key,
         _ => unreachable!(),
@@ -136,7 +181,8 @@ fn aes_gcm_seal(key: &aead::KeyInner, nonce: Nonce, aad: Aad<&[u8]>, in_out: &mu
 fn aes_gcm_open(
     key: &aead::KeyInner,
     nonce: Nonce,
-    aad: Aad<&[u8]>,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
     in_out: &mut [u8],
     src: RangeFrom<usize>,
 ) -> Tag {
@@ -225,6 +271,160 @@ fn aes_gcm_open(
     finish(aes_key, auth, tag_iv, aad_len, total_in_out_len)
 }
 
+/// Like `aes_gcm_open`, except the ciphertext is the first `ciphertext_len`
+/// bytes of the concatenation of `in_out`, and it is decrypted in place.
+pub(super) fn open_scatter(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [&mut [u8]],
+    ciphertext_len: usize,
+) -> Tag {
+    let Key { gcm_key, aes_key } = key;
+
+    let mut ctr = Counter::one(nonce);
+    let tag_iv = ctr.increment();
+
+    let aad_len = aad.0.len();
+    let mut auth = gcm::Context::new(gcm_key, aad);
+
+    let mut buf = [0u8; BLOCK_LEN];
+    scatter::for_each_chunk(in_out, ciphertext_len, &mut buf, |chunk| {
+        let (whole, remainder) = chunk.split_at_mut(chunk.len() - (chunk.len() % BLOCK_LEN));
+        for chunk in whole.chunks_mut(CHUNK_BLOCKS * BLOCK_LEN) {
+            auth.update_blocks(chunk);
+            aes_key.ctr32_encrypt_within(chunk, 0.., &mut ctr);
+        }
+        if !remainder.is_empty() {
+            let mut input = Block::zero();
+            input.overwrite_part_at(0, remainder);
+            auth.update_block(input);
+            let output = aes_key.encrypt_iv_xor_block(ctr.increment(), input);
+            remainder.copy_from_slice(&output.as_ref()[..remainder.len()]);
+        }
+    });
+
+    finish(aes_key, auth, tag_iv, aad_len, ciphertext_len)
+}
+
+/// Like `aes_gcm_seal`, except the CTR encryption of large inputs is split
+/// across threads. GHASH is inherently sequential, so it is computed on the
+/// calling thread after the encryption.
+#[cfg(feature = "parallel")]
+pub(super) fn seal_parallel(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let Key { gcm_key, aes_key } = key;
+
+    let mut ctr = Counter::one(nonce);
+    let tag_iv = ctr.increment();
+
+    let total_in_out_len = in_out.len();
+    let aad_len = aad.0.len();
+    let mut auth = gcm::Context::new(gcm_key, aad);
+
+    let (whole, remainder) = {
+        let in_out_len = in_out.len();
+        let whole_len = in_out_len - (in_out_len % BLOCK_LEN);
+        in_out.split_at_mut(whole_len)
+    };
+
+    let ctr = ctr_parallel(aes_key, ctr, whole);
+    for chunk in whole.chunks(CHUNK_BLOCKS * BLOCK_LEN) {
+        auth.update_blocks(chunk);
+    }
+
+    if !remainder.is_empty() {
+        let mut input = Block::zero();
+        input.overwrite_part_at(0, remainder);
+        let mut output = aes_key.encrypt_iv_xor_block(ctr.into(), input);
+        output.zero_from(remainder.len());
+        auth.update_block(output);
+        remainder.copy_from_slice(&output.as_ref()[..remainder.len()]);
+    }
+
+    finish(aes_key, auth, tag_iv, aad_len, total_in_out_len)
+}
+
+/// Like `aes_gcm_open` with `src` of `0..`, except the CTR decryption of
+/// large inputs is split across threads. GHASH is computed on the calling
+/// thread before the decryption.
+#[cfg(feature = "parallel")]
+pub(super) fn open_parallel(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let Key { gcm_key, aes_key } = key;
+
+    let mut ctr = Counter::one(nonce);
+    let tag_iv = ctr.increment();
+
+    let total_in_out_len = in_out.len();
+    let aad_len = aad.0.len();
+    let mut auth = gcm::Context::new(gcm_key, aad);
+
+    let (whole, remainder) = {
+        let in_out_len = in_out.len();
+        let whole_len = in_out_len - (in_out_len % BLOCK_LEN);
+        in_out.split_at_mut(whole_len)
+    };
+
+    for chunk in whole.chunks(CHUNK_BLOCKS * BLOCK_LEN) {
+        auth.update_blocks(chunk);
+    }
+    let ctr = ctr_parallel(aes_key, ctr, whole);
+
+    if !remainder.is_empty() {
+        let mut input = Block::zero();
+        input.overwrite_part_at(0, remainder);
+        auth.update_block(input);
+        let output = aes_key.encrypt_iv_xor_block(ctr.into(), input);
+        remainder.copy_from_slice(&output.as_ref()[..remainder.len()]);
+    }
+
+    finish(aes_key, auth, tag_iv, aad_len, total_in_out_len)
+}
+
+// Encrypts the whole blocks `in_out` in CTR mode, starting at `ctr`, with each
+// `PARALLEL_CHUNK_BLOCKS` blocks being a separate task. Returns the counter
+// for the block after `in_out`.
+#[cfg(feature = "parallel")]
+fn ctr_parallel(aes_key: &aes::Key, ctr: Counter, in_out: &mut [u8]) -> Counter {
+    use rayon::prelude::*;
+
+    // `AES_GCM_MAX_INPUT_LEN` ensures that the block counts fit in a `u32`
+    // and that the counter doesn't wrap.
+    in_out
+        .par_chunks_mut(PARALLEL_CHUNK_BLOCKS * BLOCK_LEN)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let mut ctr = ctr.offset_less_safe((i * PARALLEL_CHUNK_BLOCKS) as u32);
+            aes_key.ctr32_encrypt_within(chunk, 0.., &mut ctr);
+        });
+    ctr.offset_less_safe((in_out.len() / BLOCK_LEN) as u32)
+}
+
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK_BLOCKS: usize = 256 * 1024 / BLOCK_LEN;
+
+/// Inputs shorter than this aren't worth splitting across threads.
+#[cfg(feature = "parallel")]
+pub(super) const PARALLEL_MIN_LEN: usize = 4 * PARALLEL_CHUNK_BLOCKS * BLOCK_LEN;
+
+/// GMAC: the tag of AES-GCM with an empty plaintext.
+pub(super) fn gmac(key: &Key, nonce: Nonce, aad: Aad<AadSlices>) -> Tag {
+    let Key { gcm_key, aes_key } = key;
+    let tag_iv = Counter::one(nonce).increment();
+    let aad_len = aad.0.len();
+    let auth = gcm::Context::new(gcm_key, aad);
+    finish(aes_key, auth, tag_iv, aad_len, 0)
+}
+
 fn finish(
     aes_key: &aes::Key,
     mut gcm_ctx: gcm::Context,
@@ -241,7 +441,7 @@ fn finish(
     gcm_ctx.pre_finish(|pre_tag| {
         let encrypted_iv = aes_key.encrypt_block(Block::from(tag_iv.as_bytes_less_safe()));
         let tag = pre_tag ^ encrypted_iv;
-        Tag(*tag.as_ref())
+        Tag::from(*tag.as_ref())
     })
 }
 
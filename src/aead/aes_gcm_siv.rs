@@ -0,0 +1,214 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{
+    aes,
+    block::{Block, BLOCK_LEN},
+    polyval, Aad, AadSlices, Nonce, Tag, NONCE_LEN,
+};
+use crate::{aead, cpu, error, polyfill};
+use core::{convert::TryInto, ops::RangeFrom};
+
+/// AES-128 in GCM-SIV mode with 128-bit tags and 96 bit nonces, as described
+/// in [RFC 8452].
+///
+/// GCM-SIV is nonce-misuse resistant: if a nonce is reused then the only
+/// information that is leaked is whether the same plaintext was sealed twice
+/// with the same nonce and AAD.
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+pub static AES_128_GCM_SIV: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128,
+    seal: aes_gcm_siv_seal,
+    open: aes_gcm_siv_open,
+    id: aead::AlgorithmID::AES_128_GCM_SIV,
+    nonce_len: NONCE_LEN,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_GCM_SIV_MAX_INPUT_LEN,
+};
+
+/// AES-256 in GCM-SIV mode with 128-bit tags and 96 bit nonces, as described
+/// in [RFC 8452].
+///
+/// See `AES_128_GCM_SIV`.
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+pub static AES_256_GCM_SIV: aead::Algorithm = aead::Algorithm {
+    key_len: 32,
+    init: init_256,
+    seal: aes_gcm_siv_seal,
+    open: aes_gcm_siv_open,
+    id: aead::AlgorithmID::AES_256_GCM_SIV,
+    nonce_len: NONCE_LEN,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_GCM_SIV_MAX_INPUT_LEN,
+};
+
+/// The key-generating key; the message authentication and encryption keys are
+/// derived from it for each nonce.
+#[derive(Clone)]
+pub struct Key {
+    aes_key: aes::Key,
+    variant: aes::Variant,
+    cpu_features: cpu::Features,
+}
+
+fn init_128(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_128, cpu_features)
+}
+
+fn init_256(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_256, cpu_features)
+}
+
+fn init(
+    key: &[u8],
+    variant: aes::Variant,
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    let aes_key = aes::Key::new(key, variant, cpu_features)?;
+    Ok(aead::KeyInner::AesGcmSiv(Key {
+        aes_key,
+        variant,
+        cpu_features,
+    }))
+}
+
+impl Key {
+    // https://tools.ietf.org/html/rfc8452#section-4
+    fn derive_keys(&self, nonce: &[u8; NONCE_LEN]) -> (polyval::Key, aes::Key) {
+        let enc_key_len = match self.variant {
+            aes::Variant::AES_128 => 16,
+            aes::Variant::AES_256 => 32,
+        };
+
+        // Each block of output contributes its first 8 bytes.
+        let mut derived = [0u8; 16 + 32];
+        let derived = &mut derived[..(16 + enc_key_len)];
+        for (counter, out) in (0u32..).zip(derived.chunks_mut(8)) {
+            let mut input = [0u8; BLOCK_LEN];
+            input[..4].copy_from_slice(&counter.to_le_bytes());
+            input[4..].copy_from_slice(nonce);
+            let output = self.aes_key.encrypt_block(Block::from(&input));
+            out.copy_from_slice(&output.as_ref()[..8]);
+        }
+
+        let (auth_key, enc_key) = derived.split_at(16);
+        let auth_key = polyval::Key::new(auth_key.try_into().unwrap(), self.cpu_features);
+        let enc_key = aes::Key::new(enc_key, self.variant, self.cpu_features).unwrap();
+        (auth_key, enc_key)
+    }
+}
+
+fn aes_gcm_siv_seal(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesGcmSiv(key) => key,
+        _ => unreachable!(),
+    };
+
+    let nonce = nonce.as_96_bits();
+    let (auth_key, enc_key) = key.derive_keys(nonce);
+    let tag = calculate_tag(&auth_key, &enc_key, nonce, aad, in_out);
+    ctr32_le_encrypt_within(&enc_key, &tag, in_out, 0..);
+    tag
+}
+
+fn aes_gcm_siv_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesGcmSiv(key) => key,
+        _ => unreachable!(),
+    };
+
+    let nonce = nonce.as_96_bits();
+    let (auth_key, enc_key) = key.derive_keys(nonce);
+    let plaintext_len = in_out.len() - src.start;
+    ctr32_le_encrypt_within(&enc_key, received_tag, in_out, src);
+    calculate_tag(&auth_key, &enc_key, nonce, aad, &in_out[..plaintext_len])
+}
+
+// https://tools.ietf.org/html/rfc8452#section-4
+fn calculate_tag(
+    auth_key: &polyval::Key,
+    enc_key: &aes::Key,
+    nonce: &[u8; NONCE_LEN],
+    aad: Aad<AadSlices>,
+    plaintext: &[u8],
+) -> Tag {
+    let mut auth = polyval::Context::new(auth_key);
+    aad.0
+        .for_each_chunk(0, &mut [0u8; BLOCK_LEN], |chunk| auth.update_padded(chunk));
+    auth.update_padded(plaintext);
+
+    let aad_bits = polyfill::u64_from_usize(aad.0.len()) << 3;
+    let plaintext_bits = polyfill::u64_from_usize(plaintext.len()) << 3;
+    let mut length_block = [0u8; BLOCK_LEN];
+    length_block[..8].copy_from_slice(&aad_bits.to_le_bytes());
+    length_block[8..].copy_from_slice(&plaintext_bits.to_le_bytes());
+    auth.update_block(length_block);
+
+    let mut s = auth.finish();
+    s.iter_mut().zip(nonce.iter()).for_each(|(s, n)| *s ^= n);
+    s[15] &= 0x7f;
+
+    Tag::from(*enc_key.encrypt_block(Block::from(&s)).as_ref())
+}
+
+/// AES-CTR with the tag, with its most significant bit set, as the initial
+/// counter block, and a 32-bit little-endian counter in the first four bytes.
+///
+/// Analogous to `slice::copy_within()`.
+fn ctr32_le_encrypt_within(key: &aes::Key, tag: &Tag, in_out: &mut [u8], src: RangeFrom<usize>) {
+    let mut counter_block = tag.value;
+    counter_block[15] |= 0x80;
+    let mut counter = u32::from_le_bytes(counter_block[..4].try_into().unwrap());
+
+    let in_out_len = in_out.len() - src.start;
+    for start in (0..in_out_len).step_by(BLOCK_LEN) {
+        counter_block[..4].copy_from_slice(&counter.to_le_bytes());
+        counter = counter.wrapping_add(1);
+        let key_stream = key.encrypt_block(Block::from(&counter_block));
+
+        let len = core::cmp::min(BLOCK_LEN, in_out_len - start);
+        for (i, k) in key_stream.as_ref()[..len].iter().enumerate() {
+            in_out[start + i] = in_out[src.start + start + i] ^ k;
+        }
+    }
+}
+
+const AES_GCM_SIV_MAX_INPUT_LEN: u64 = super::max_input_len(BLOCK_LEN, 0);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn max_input_len_test() {
+        // [RFC 8452 Section 6]: P_MAX is 2^36 bytes.
+        //
+        // [RFC 8452 Section 6]: https://tools.ietf.org/html/rfc8452#section-6
+        assert_eq!(super::AES_128_GCM_SIV.max_input_len, 1u64 << 36);
+        assert_eq!(super::AES_256_GCM_SIV.max_input_len, 1u64 << 36);
+    }
+}
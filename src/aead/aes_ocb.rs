@@ -0,0 +1,269 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-OCB3, as described in [RFC 7253], with 128-bit tags and 96-bit
+//! nonces.
+//!
+//! Sealing is faster than AES-GCM on targets without carry-less
+//! multiplication instructions since OCB needs only one AES block operation
+//! per block of input and no polynomial MAC. Opening uses AES decryption,
+//! which has only a portable, constant-time implementation (see
+//! `aes::DecryptKey`), so it is much slower than sealing.
+//!
+//! [RFC 7253]: https://tools.ietf.org/html/rfc7253
+
+use super::{
+    aes,
+    block::{Block, BLOCK_LEN},
+    cmac::dbl,
+    Aad, AadSlices, Nonce, Tag, NONCE_LEN,
+};
+use crate::{aead, cpu, error};
+use core::{convert::TryInto, ops::RangeFrom};
+
+/// AES-128 in OCB3 mode ([RFC 7253]) with 128-bit tags and 96 bit nonces.
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub static AES_128_OCB: aead::Algorithm = aead::Algorithm {
+    key_len: 16,
+    init: init_128,
+    seal: aes_ocb_seal,
+    open: aes_ocb_open,
+    id: aead::AlgorithmID::AES_128_OCB,
+    nonce_len: NONCE_LEN,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_OCB_MAX_INPUT_LEN,
+};
+
+/// AES-256 in OCB3 mode ([RFC 7253]) with 128-bit tags and 96 bit nonces.
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub static AES_256_OCB: aead::Algorithm = aead::Algorithm {
+    key_len: 32,
+    init: init_256,
+    seal: aes_ocb_seal,
+    open: aes_ocb_open,
+    id: aead::AlgorithmID::AES_256_OCB,
+    nonce_len: NONCE_LEN,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_OCB_MAX_INPUT_LEN,
+};
+
+#[derive(Clone)]
+pub struct Key {
+    aes_key: aes::Key,
+    decrypt_key: aes::DecryptKey,
+    l_star: Block,
+    l_dollar: Block,
+    // L_i for every `i` that is the number of trailing zeros of the index of
+    // a block of an input of at most `AES_OCB_MAX_INPUT_LEN` bytes.
+    l: [Block; L_LEN],
+}
+
+const L_LEN: usize = 32;
+
+fn init_128(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_128, cpu_features)
+}
+
+fn init_256(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    init(key, aes::Variant::AES_256, cpu_features)
+}
+
+fn init(
+    key: &[u8],
+    variant: aes::Variant,
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    let aes_key = aes::Key::new(key, variant, cpu_features)?;
+    let decrypt_key = aes::DecryptKey::new(key, variant)?;
+
+    let l_star = *aes_key.encrypt_block(Block::zero()).as_ref();
+    let l_dollar = dbl(l_star);
+    let mut l = [Block::zero(); L_LEN];
+    let mut l_i = l_dollar;
+    for l in l.iter_mut() {
+        l_i = dbl(l_i);
+        *l = Block::from(&l_i);
+    }
+
+    Ok(aead::KeyInner::AesOcb(Key {
+        aes_key,
+        decrypt_key,
+        l_star: Block::from(&l_star),
+        l_dollar: Block::from(&l_dollar),
+        l,
+    }))
+}
+
+impl Key {
+    #[inline]
+    fn l(&self, i: usize) -> Block {
+        self.l[i.trailing_zeros() as usize]
+    }
+
+    // https://tools.ietf.org/html/rfc7253#section-4.2
+    fn offset_0(&self, nonce: Nonce) -> Block {
+        let mut nonce_block = [0u8; BLOCK_LEN];
+        nonce_block[BLOCK_LEN - NONCE_LEN - 1] = 1;
+        nonce_block[(BLOCK_LEN - NONCE_LEN)..].copy_from_slice(nonce.as_bytes());
+
+        let bottom = u32::from(nonce_block[BLOCK_LEN - 1] & 0x3f);
+        nonce_block[BLOCK_LEN - 1] &= 0xc0;
+        let ktop = *self
+            .aes_key
+            .encrypt_block(Block::from(&nonce_block))
+            .as_ref();
+
+        // Stretch = Ktop || (Ktop[1..64] xor Ktop[9..72]), and Offset_0 is
+        // bits `bottom..(bottom + 128)` of Stretch. `bottom` depends only on
+        // the nonce so branching on it is OK.
+        let hi = u128::from_be_bytes(ktop);
+        let lo = u64::from_be_bytes(ktop[..8].try_into().unwrap())
+            ^ u64::from_be_bytes(ktop[1..9].try_into().unwrap());
+        let offset = if bottom == 0 {
+            hi
+        } else {
+            (hi << bottom) | (u128::from(lo) >> (64 - bottom))
+        };
+        Block::from(&offset.to_be_bytes())
+    }
+
+    // https://tools.ietf.org/html/rfc7253#section-4.1
+    fn hash(&self, aad: AadSlices) -> Block {
+        let mut sum = Block::zero();
+        let mut offset = Block::zero();
+        let mut i = 1;
+        aad.for_each_chunk(0, &mut [0u8; BLOCK_LEN], |chunk| {
+            let mut blocks = chunk.chunks_exact(BLOCK_LEN);
+            for a_i in &mut blocks {
+                offset ^= self.l(i);
+                i += 1;
+                let a_i: &[u8; BLOCK_LEN] = a_i.try_into().unwrap();
+                sum ^= self.aes_key.encrypt_block(Block::from(a_i) ^ offset);
+            }
+            // Only the last chunk can have a partial block.
+            let remainder = blocks.remainder();
+            if !remainder.is_empty() {
+                offset ^= self.l_star;
+                sum ^= self.aes_key.encrypt_block(pad(remainder) ^ offset);
+            }
+        });
+        sum
+    }
+
+    fn finish(&self, checksum: Block, offset: Block, aad: Aad<AadSlices>) -> Tag {
+        let tag = self
+            .aes_key
+            .encrypt_block(checksum ^ offset ^ self.l_dollar)
+            ^ self.hash(aad.0);
+        Tag::from(*tag.as_ref())
+    }
+}
+
+/// Returns `partial || 1 || 0*`.
+fn pad(partial: &[u8]) -> Block {
+    let mut padded = [0u8; BLOCK_LEN];
+    padded[..partial.len()].copy_from_slice(partial);
+    padded[partial.len()] = 0x80;
+    Block::from(&padded)
+}
+
+fn aes_ocb_seal(key: &aead::KeyInner, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesOcb(key) => key,
+        _ => unreachable!(),
+    };
+
+    let mut offset = key.offset_0(nonce);
+    let mut checksum = Block::zero();
+
+    let mut chunks = in_out.chunks_exact_mut(BLOCK_LEN);
+    for (i, chunk) in (1..).zip(&mut chunks) {
+        let chunk: &mut [u8; BLOCK_LEN] = chunk.try_into().unwrap();
+        let p_i = Block::from(&*chunk);
+        offset ^= key.l(i);
+        checksum ^= p_i;
+        *chunk = *(offset ^ key.aes_key.encrypt_block(p_i ^ offset)).as_ref();
+    }
+
+    let remainder = chunks.into_remainder();
+    if !remainder.is_empty() {
+        offset ^= key.l_star;
+        checksum ^= pad(remainder);
+        let pad = key.aes_key.encrypt_block(offset);
+        remainder
+            .iter_mut()
+            .zip(pad.as_ref().iter())
+            .for_each(|(b, p)| *b ^= p);
+    }
+
+    key.finish(checksum, offset, aad)
+}
+
+fn aes_ocb_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesOcb(key) => key,
+        _ => unreachable!(),
+    };
+
+    let mut offset = key.offset_0(nonce);
+    let mut checksum = Block::zero();
+
+    let in_prefix_len = src.start;
+    let plaintext_len = in_out.len() - in_prefix_len;
+    let whole_len = plaintext_len - (plaintext_len % BLOCK_LEN);
+
+    // Decrypt forwards so that each ciphertext block is read before the
+    // plaintext overwrites it.
+    for (i, start) in (1..).zip((0..whole_len).step_by(BLOCK_LEN)) {
+        let c_i: &[u8; BLOCK_LEN] = in_out[(in_prefix_len + start)..][..BLOCK_LEN]
+            .try_into()
+            .unwrap();
+        offset ^= key.l(i);
+        let p_i = offset ^ key.decrypt_key.decrypt_block(Block::from(c_i) ^ offset);
+        checksum ^= p_i;
+        in_out[start..][..BLOCK_LEN].copy_from_slice(p_i.as_ref());
+    }
+
+    if whole_len < plaintext_len {
+        offset ^= key.l_star;
+        let pad = key.aes_key.encrypt_block(offset);
+        for (i, p) in (whole_len..plaintext_len).zip(pad.as_ref().iter()) {
+            in_out[i] = in_out[in_prefix_len + i] ^ p;
+        }
+        checksum ^= self::pad(&in_out[whole_len..plaintext_len]);
+    }
+
+    key.finish(checksum, offset, aad)
+}
+
+// Limit the number of blocks so that the number of trailing zeros of a block
+// index is less than `L_LEN`.
+const AES_OCB_MAX_INPUT_LEN: u64 = super::max_input_len(BLOCK_LEN, 1);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn max_input_len_test() {
+        assert_eq!(super::AES_128_OCB.max_input_len, ((1u64 << 32) - 1) * 16);
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{
+    aes::{self, Counter},
+    block::{Block, BLOCK_LEN},
+    cmac, shift, Aad, AadSlices, Nonce, Tag,
+};
+use crate::{aead, cpu, error};
+use core::{convert::TryFrom, ops::RangeFrom};
+
+/// AES-SIV with two AES-128 keys, as described in [RFC 5297], where it is
+/// named AEAD_AES_SIV_CMAC_256.
+///
+/// The keys are 256 bits long: the first half is used for S2V and the second
+/// half for CTR. The nonces are zero bytes long, i.e. `Nonce::empty()`:
+/// encryption is deterministic, so the same plaintext and AAD always result in
+/// the same ciphertext and tag.
+/// Use `aead::siv::Key` to authenticate multiple, separate, headers.
+///
+/// Note that [RFC 5297] places the synthetic IV (the tag) *before* the
+/// ciphertext; use `seal_in_place_separate_tag()` to produce that layout.
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub static AES_SIV_CMAC_256: aead::Algorithm = aead::Algorithm {
+    key_len: 32,
+    init: init_256,
+    seal: aes_siv_seal,
+    open: aes_siv_open,
+    id: aead::AlgorithmID::AES_SIV_CMAC_256,
+    nonce_len: 0,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_SIV_MAX_INPUT_LEN,
+};
+
+/// AES-SIV with two AES-256 keys, as described in [RFC 5297], where it is
+/// named AEAD_AES_SIV_CMAC_512.
+///
+/// The keys are 512 bits long. See `AES_SIV_CMAC_256`.
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub static AES_SIV_CMAC_512: aead::Algorithm = aead::Algorithm {
+    key_len: 64,
+    init: init_512,
+    seal: aes_siv_seal,
+    open: aes_siv_open,
+    id: aead::AlgorithmID::AES_SIV_CMAC_512,
+    nonce_len: 0,
+    tag_len: super::TAG_LEN,
+    max_input_len: AES_SIV_MAX_INPUT_LEN,
+};
+
+#[derive(Clone)]
+pub struct Key {
+    mac_key: cmac::Key,
+    ctr_key: aes::Key,
+}
+
+fn init_256(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    Key::new(key, aes::Variant::AES_128, cpu_features).map(aead::KeyInner::AesSiv)
+}
+
+fn init_512(key: &[u8], cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    Key::new(key, aes::Variant::AES_256, cpu_features).map(aead::KeyInner::AesSiv)
+}
+
+impl Key {
+    fn new(
+        key: &[u8],
+        variant: aes::Variant,
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let (mac_key, ctr_key) = key.split_at(key.len() / 2);
+        Ok(Self {
+            mac_key: cmac::Key::new(aes::Key::new(mac_key, variant, cpu_features)?),
+            ctr_key: aes::Key::new(ctr_key, variant, cpu_features)?,
+        })
+    }
+
+    /// Seals `in_out` in place, authenticating each of `headers` as a
+    /// separate component of the S2V input.
+    pub(super) fn seal<'a>(
+        &self,
+        headers: impl IntoIterator<Item = AadSlices<'a>>,
+        in_out: &mut [u8],
+    ) -> Tag {
+        let tag = s2v(&self.mac_key, headers, in_out);
+        ctr_within(&self.ctr_key, &tag, in_out, 0..);
+        tag
+    }
+
+    /// Decrypts `in_out[src]` into `in_out[..]` using `received_tag` as the
+    /// IV and returns the tag calculated over the result.
+    pub(super) fn open<'a>(
+        &self,
+        headers: impl IntoIterator<Item = AadSlices<'a>>,
+        received_tag: &Tag,
+        in_out: &mut [u8],
+        src: RangeFrom<usize>,
+    ) -> Tag {
+        let plaintext_len = in_out.len() - src.start;
+        ctr_within(&self.ctr_key, received_tag, in_out, src);
+        s2v(&self.mac_key, headers, &in_out[..plaintext_len])
+    }
+}
+
+fn aes_siv_seal(
+    key: &aead::KeyInner,
+    _nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesSiv(key) => key,
+        _ => unreachable!(),
+    };
+    key.seal(Some(aad.0), in_out)
+}
+
+fn aes_siv_open(
+    key: &aead::KeyInner,
+    _nonce: Nonce,
+    aad: Aad<AadSlices>,
+    received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AesSiv(key) => key,
+        _ => unreachable!(),
+    };
+    key.open(Some(aad.0), received_tag, in_out, src)
+}
+
+/// The maximum number of headers, excluding the plaintext, that S2V can
+/// authenticate.
+pub(super) const MAX_HEADERS: usize = 126;
+
+// https://tools.ietf.org/html/rfc5297#section-2.4
+fn s2v<'a>(
+    key: &cmac::Key,
+    headers: impl IntoIterator<Item = AadSlices<'a>>,
+    plaintext: &[u8],
+) -> Tag {
+    let mut d = cmac::sign(key, &[0u8; BLOCK_LEN]);
+    for header in headers {
+        let mut header_ctx = cmac::Context::new(key);
+        header.0.iter().for_each(|slice| header_ctx.update(slice));
+        d = cmac::dbl(d);
+        cmac::xor_assign(&mut d, &header_ctx.finish());
+    }
+
+    let mut ctx = cmac::Context::new(key);
+    if plaintext.len() >= BLOCK_LEN {
+        // xorend: XOR `d` into the last block of the plaintext.
+        let (init, last) = plaintext.split_at(plaintext.len() - BLOCK_LEN);
+        let mut last = *<&[u8; BLOCK_LEN]>::try_from(last).unwrap();
+        cmac::xor_assign(&mut last, &d);
+        ctx.update(init);
+        ctx.update(&last);
+    } else {
+        let mut padded = [0u8; BLOCK_LEN];
+        padded[..plaintext.len()].copy_from_slice(plaintext);
+        padded[plaintext.len()] = 0x80;
+        cmac::xor_assign(&mut padded, &cmac::dbl(d));
+        ctx.update(&padded);
+    }
+    Tag::from(ctx.finish())
+}
+
+// AES-CTR from `in_out[src]` into `in_out[..]`, using the synthetic IV with
+// the 31st and 63rd bits (counting from the right) cleared as the initial
+// counter block. Clearing those bits ensures that the 32-bit counter can't
+// overflow for inputs up to `AES_SIV_MAX_INPUT_LEN` bytes.
+//
+// https://tools.ietf.org/html/rfc5297#section-2.6
+fn ctr_within(key: &aes::Key, tag: &Tag, in_out: &mut [u8], src: RangeFrom<usize>) {
+    let mut q = tag.value;
+    q[8] &= 0x7f;
+    q[12] &= 0x7f;
+    let mut ctr = Counter::from_block_less_safe(Block::from(&q));
+
+    let in_prefix_len = src.start;
+    let whole_len = {
+        let in_out_len = in_out.len() - in_prefix_len;
+        in_out_len - (in_out_len % BLOCK_LEN)
+    };
+    if whole_len > 0 {
+        key.ctr32_encrypt_within(&mut in_out[..(whole_len + in_prefix_len)], src, &mut ctr);
+    }
+
+    shift::shift_partial((in_prefix_len, &mut in_out[whole_len..]), |remainder| {
+        let mut input = Block::zero();
+        input.overwrite_part_at(0, remainder);
+        key.encrypt_iv_xor_block(ctr.into(), input)
+    });
+}
+
+// The 32-bit counter starts at a value less than 2^31.
+const AES_SIV_MAX_INPUT_LEN: u64 = super::max_input_len(BLOCK_LEN, 1 << 31);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn max_input_len_test() {
+        assert_eq!(super::AES_SIV_CMAC_256.max_input_len, 1u64 << 35);
+        assert_eq!(super::AES_SIV_CMAC_512.max_input_len, 1u64 << 35);
+    }
+}
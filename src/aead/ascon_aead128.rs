@@ -0,0 +1,207 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, AadSlices, Nonce, Tag};
+use crate::{aead, ascon, cpu, error};
+use core::{convert::TryInto, ops::RangeFrom};
+
+/// Ascon-AEAD128, as specified in [NIST SP 800-232].
+///
+/// The keys, nonces, and tags are all 128 bits. Ascon-AEAD128 uses only
+/// 64-bit bitwise operations and rotations, so it is fast and constant-time
+/// even on small microcontrollers without AES or carry-less multiplication
+/// instructions.
+///
+/// [NIST SP 800-232]: https://doi.org/10.6028/NIST.SP.800-232
+pub static ASCON_AEAD128: aead::Algorithm = aead::Algorithm {
+    key_len: KEY_LEN,
+    init,
+    seal: ascon_aead128_seal,
+    open: ascon_aead128_open,
+    id: aead::AlgorithmID::ASCON_AEAD128,
+    nonce_len: NONCE_LEN,
+    tag_len: super::TAG_LEN,
+    // NIST SP 800-232 Section 4.2.1 limits the amount of data processed
+    // under a key to 2^54 bytes; this is a per-nonce limit so it is only an
+    // approximation of that.
+    max_input_len: 1 << 54,
+};
+
+const KEY_LEN: usize = 128 / 8;
+const NONCE_LEN: usize = 128 / 8;
+const RATE: usize = 128 / 8;
+
+// NIST SP 800-232 Section 4.1.1.
+const IV: u64 = 0x0000_1000_808c_0001;
+
+#[derive(Clone)]
+pub struct Key([u64; 2]);
+
+fn init(key: &[u8], _cpu_features: cpu::Features) -> Result<aead::KeyInner, error::Unspecified> {
+    let key: &[u8; KEY_LEN] = key.try_into()?;
+    let (k0, k1) = split(key);
+    Ok(aead::KeyInner::AsconAead128(Key([k0, k1])))
+}
+
+fn split(bytes: &[u8; 16]) -> (u64, u64) {
+    (
+        u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+        u64::from_le_bytes(bytes[8..].try_into().unwrap()),
+    )
+}
+
+/// The rate, i.e. the first two words, of the state as bytes.
+fn rate_bytes(s: &ascon::State) -> [u8; RATE] {
+    let mut bytes = [0u8; RATE];
+    bytes[..8].copy_from_slice(&s[0].to_le_bytes());
+    bytes[8..].copy_from_slice(&s[1].to_le_bytes());
+    bytes
+}
+
+fn set_rate_bytes(s: &mut ascon::State, bytes: &[u8; RATE]) {
+    let (s0, s1) = split(bytes);
+    s[0] = s0;
+    s[1] = s1;
+}
+
+// NIST SP 800-232 Section 4.1.1: initialization and processing of the
+// associated data.
+fn start(key: &Key, nonce: Nonce, aad: Aad<AadSlices>) -> ascon::State {
+    let Key([k0, k1]) = *key;
+    let nonce: &[u8; NONCE_LEN] = nonce.as_bytes().try_into().unwrap();
+    let (n0, n1) = split(nonce);
+
+    let mut s = [IV, k0, k1, n0, n1];
+    ascon::permute(&mut s, 12);
+    s[3] ^= k0;
+    s[4] ^= k1;
+
+    let aad = aad.0;
+    if !aad.is_empty() {
+        let mut remainder = [0u8; RATE];
+        let mut remainder_len = 0;
+        aad.for_each_chunk(0, &mut [0u8; RATE], |chunk| {
+            let mut chunks = chunk.chunks_exact(RATE);
+            for chunk in &mut chunks {
+                let mut rate = rate_bytes(&s);
+                xor_assign(&mut rate, chunk);
+                set_rate_bytes(&mut s, &rate);
+                ascon::permute(&mut s, 8);
+            }
+            // Only the last chunk can have a partial block.
+            let partial = chunks.remainder();
+            remainder[..partial.len()].copy_from_slice(partial);
+            remainder_len = partial.len();
+        });
+        let mut rate = rate_bytes(&s);
+        xor_assign(&mut rate, &remainder[..remainder_len]);
+        rate[remainder_len] ^= 0x01;
+        set_rate_bytes(&mut s, &rate);
+        ascon::permute(&mut s, 8);
+    }
+
+    // Domain separation.
+    s[4] ^= 1 << 63;
+    s
+}
+
+fn finish(key: &Key, mut s: ascon::State) -> Tag {
+    let Key([k0, k1]) = *key;
+    s[2] ^= k0;
+    s[3] ^= k1;
+    ascon::permute(&mut s, 12);
+    let mut tag = [0u8; super::TAG_LEN];
+    tag[..8].copy_from_slice(&(s[3] ^ k0).to_le_bytes());
+    tag[8..].copy_from_slice(&(s[4] ^ k1).to_le_bytes());
+    Tag::from(tag)
+}
+
+fn ascon_aead128_seal(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AsconAead128(key) => key,
+        _ => unreachable!(),
+    };
+    let mut s = start(key, nonce, aad);
+
+    let mut chunks = in_out.chunks_exact_mut(RATE);
+    for chunk in &mut chunks {
+        let mut rate = rate_bytes(&s);
+        xor_assign(&mut rate, chunk);
+        chunk.copy_from_slice(&rate);
+        set_rate_bytes(&mut s, &rate);
+        ascon::permute(&mut s, 8);
+    }
+    let remainder = chunks.into_remainder();
+    let mut rate = rate_bytes(&s);
+    xor_assign(&mut rate, remainder);
+    remainder.copy_from_slice(&rate[..remainder.len()]);
+    rate[remainder.len()] ^= 0x01;
+    set_rate_bytes(&mut s, &rate);
+
+    finish(key, s)
+}
+
+fn ascon_aead128_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let key = match key {
+        aead::KeyInner::AsconAead128(key) => key,
+        _ => unreachable!(),
+    };
+    let mut s = start(key, nonce, aad);
+
+    let in_prefix_len = src.start;
+    let plaintext_len = in_out.len() - in_prefix_len;
+    let whole_len = plaintext_len - (plaintext_len % RATE);
+
+    // Decrypt forwards so that each ciphertext block is read before the
+    // plaintext overwrites it.
+    for start in (0..whole_len).step_by(RATE) {
+        let ciphertext: [u8; RATE] = in_out[(in_prefix_len + start)..][..RATE]
+            .try_into()
+            .unwrap();
+        let mut plaintext = rate_bytes(&s);
+        xor_assign(&mut plaintext, &ciphertext);
+        in_out[start..][..RATE].copy_from_slice(&plaintext);
+        set_rate_bytes(&mut s, &ciphertext);
+        ascon::permute(&mut s, 8);
+    }
+
+    let remainder_len = plaintext_len - whole_len;
+    let mut rate = rate_bytes(&s);
+    for i in 0..remainder_len {
+        let c = in_out[in_prefix_len + whole_len + i];
+        in_out[whole_len + i] = rate[i] ^ c;
+        rate[i] = c;
+    }
+    rate[remainder_len] ^= 0x01;
+    set_rate_bytes(&mut s, &rate);
+
+    finish(key, s)
+}
+
+#[inline]
+fn xor_assign(r: &mut [u8; RATE], a: &[u8]) {
+    r.iter_mut().zip(a.iter()).for_each(|(r, a)| *r ^= a);
+}
@@ -30,7 +30,7 @@ use crate::{
 ))]
 mod fallback;
 
-use core::ops::RangeFrom;
+use core::{convert::TryFrom, ops::RangeFrom};
 
 #[derive(Clone)]
 pub struct Key {
@@ -39,7 +39,7 @@ pub struct Key {
 }
 
 impl Key {
-    pub(super) fn new(value: [u8; KEY_LEN], cpu_features: cpu::Features) -> Self {
+    pub(crate) fn new(value: [u8; KEY_LEN], cpu_features: cpu::Features) -> Self {
         let value: &[[u8; 4]; KEY_LEN / 4] = value.chunks_fixed();
         Self {
             words: value.array_map(u32::from_le_bytes),
@@ -150,6 +150,28 @@ impl Key {
     pub(super) fn words_less_safe(&self) -> &[u32; KEY_LEN / 4] {
         &self.words
     }
+
+    /// HChaCha20, as described in [draft-irtf-cfrg-xchacha]: derives a new
+    /// key from this key and the first 128 bits of an extended nonce.
+    ///
+    /// [draft-irtf-cfrg-xchacha]:
+    ///     https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03#section-2.2
+    pub(super) fn hchacha20(&self, nonce: &[u8; HCHACHA20_NONCE_LEN]) -> Self {
+        let key = &self.words;
+        let nonce: &[[u8; 4]; 4] = nonce.chunks_fixed();
+        let nonce = nonce.array_map(u32::from_le_bytes);
+
+        let mut x = [
+            SIGMA[0], SIGMA[1], SIGMA[2], SIGMA[3], key[0], key[1], key[2], key[3], key[4], key[5],
+            key[6], key[7], nonce[0], nonce[1], nonce[2], nonce[3],
+        ];
+        rounds(&mut x);
+
+        Self {
+            words: [x[0], x[1], x[2], x[3], x[12], x[13], x[14], x[15]],
+            cpu_features: self.cpu_features,
+        }
+    }
 }
 
 /// Counter || Nonce, all native endian.
@@ -161,8 +183,8 @@ impl Counter {
         Self::from_nonce_and_ctr(nonce, 0)
     }
 
-    fn from_nonce_and_ctr(nonce: Nonce, ctr: u32) -> Self {
-        let nonce = nonce.as_ref().chunks_fixed();
+    pub(crate) fn from_nonce_and_ctr(nonce: Nonce, ctr: u32) -> Self {
+        let nonce = nonce.as_96_bits().chunks_fixed();
         Self([
             ctr,
             u32::from_le_bytes(nonce[0]),
@@ -177,6 +199,17 @@ impl Counter {
         iv
     }
 
+    /// Returns the counter for the next `blocks` blocks, and advances past
+    /// them.
+    ///
+    /// This is "less safe" because nothing prevents the returned counter
+    /// from being used for more than `blocks` blocks.
+    pub(super) fn take_blocks_less_safe(&mut self, blocks: usize) -> Self {
+        let counter = Self(self.0);
+        self.0[0] += u32::try_from(blocks).unwrap();
+        counter
+    }
+
     /// This is "less safe" because it hands off management of the counter to
     /// the caller.
     #[cfg(any(
@@ -211,13 +244,54 @@ impl Iv {
 
 pub const KEY_LEN: usize = 32;
 
-const BLOCK_LEN: usize = 64;
+/// The length of the nonce input to HChaCha20.
+pub const HCHACHA20_NONCE_LEN: usize = 16;
+
+pub(crate) const BLOCK_LEN: usize = 64;
+
+const SIGMA: [u32; 4] = [
+    u32::from_le_bytes(*b"expa"),
+    u32::from_le_bytes(*b"nd 3"),
+    u32::from_le_bytes(*b"2-by"),
+    u32::from_le_bytes(*b"te k"),
+];
+
+type State = [u32; BLOCK_LEN / 4];
+
+// Performs the 20 rounds of ChaCha on `x`, without the final addition of the
+// input.
+#[inline(always)]
+fn rounds(x: &mut State) {
+    for _ in (0..20).step_by(2) {
+        quarterround(x, 0, 4, 8, 12);
+        quarterround(x, 1, 5, 9, 13);
+        quarterround(x, 2, 6, 10, 14);
+        quarterround(x, 3, 7, 11, 15);
+        quarterround(x, 0, 5, 10, 15);
+        quarterround(x, 1, 6, 11, 12);
+        quarterround(x, 2, 7, 8, 13);
+        quarterround(x, 3, 4, 9, 14);
+    }
+}
+
+#[inline(always)]
+fn quarterround(x: &mut State, a: usize, b: usize, c: usize, d: usize) {
+    #[inline(always)]
+    fn step(x: &mut State, a: usize, b: usize, c: usize, rotation: u32) {
+        x[a] = x[a].wrapping_add(x[b]);
+        x[c] = (x[c] ^ x[a]).rotate_left(rotation);
+    }
+    step(x, a, b, d, 16);
+    step(x, c, d, b, 12);
+    step(x, a, b, d, 8);
+    step(x, c, d, b, 7);
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test;
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
     use core::convert::TryInto;
 
     const MAX_ALIGNMENT_AND_OFFSET: (usize, usize) = (15, 259);
@@ -296,6 +370,22 @@ mod tests {
         });
     }
 
+    #[test]
+    fn hchacha20_test() {
+        // https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03#section-2.2.1
+        let key: Vec<u8> = (0..KEY_LEN as u8).collect();
+        let key = Key::new(key.as_slice().try_into().unwrap(), cpu::features());
+        let nonce = test::from_hex("000000090000004a0000000031415927").unwrap();
+        let expected =
+            test::from_hex("82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc")
+                .unwrap();
+
+        let subkey = key.hchacha20(nonce.as_slice().try_into().unwrap());
+        let expected: &[u8; KEY_LEN] = expected.as_slice().try_into().unwrap();
+        let expected = Key::new(*expected, cpu::features());
+        assert_eq!(subkey.words_less_safe(), expected.words_less_safe());
+    }
+
     fn chacha20_test_case_inner(
         key: &Key,
         nonce: &[u8],
@@ -15,7 +15,7 @@
 // Adapted from the public domain, estream code by D. Bernstein.
 // Adapted from the BoringSSL crypto/chacha/chacha.c.
 
-use super::{Counter, Key, BLOCK_LEN};
+use super::{rounds, Counter, Key, State, BLOCK_LEN, SIGMA};
 use crate::polyfill::ChunksFixedMut;
 use core::ops::RangeFrom;
 
@@ -25,13 +25,6 @@ pub(super) fn ChaCha20_ctr32(
     in_out: &mut [u8],
     src: RangeFrom<usize>,
 ) {
-    const SIGMA: [u32; 4] = [
-        u32::from_le_bytes(*b"expa"),
-        u32::from_le_bytes(*b"nd 3"),
-        u32::from_le_bytes(*b"2-by"),
-        u32::from_le_bytes(*b"te k"),
-    ];
-
     let key = key.words_less_safe();
     let counter = counter.into_words_less_safe();
 
@@ -66,17 +59,7 @@ pub(super) fn ChaCha20_ctr32(
 #[inline(always)]
 fn chacha_core(output: &mut [u8; BLOCK_LEN], input: &State) {
     let mut x = *input;
-
-    for _ in (0..20).step_by(2) {
-        quarterround(&mut x, 0, 4, 8, 12);
-        quarterround(&mut x, 1, 5, 9, 13);
-        quarterround(&mut x, 2, 6, 10, 14);
-        quarterround(&mut x, 3, 7, 11, 15);
-        quarterround(&mut x, 0, 5, 10, 15);
-        quarterround(&mut x, 1, 6, 11, 12);
-        quarterround(&mut x, 2, 7, 8, 13);
-        quarterround(&mut x, 3, 4, 9, 14);
-    }
+    rounds(&mut x);
 
     for (x, input) in x.iter_mut().zip(input.iter()) {
         *x = x.wrapping_add(*input);
@@ -86,18 +69,3 @@ fn chacha_core(output: &mut [u8; BLOCK_LEN], input: &State) {
         *output = u32::to_le_bytes(x)
     }
 }
-
-#[inline(always)]
-fn quarterround(x: &mut State, a: usize, b: usize, c: usize, d: usize) {
-    #[inline(always)]
-    fn step(x: &mut State, a: usize, b: usize, c: usize, rotation: u32) {
-        x[a] = x[a].wrapping_add(x[b]);
-        x[c] = (x[c] ^ x[a]).rotate_left(rotation);
-    }
-    step(x, a, b, d, 16);
-    step(x, c, d, b, 12);
-    step(x, a, b, d, 8);
-    step(x, c, d, b, 7);
-}
-
-type State = [u32; BLOCK_LEN / 4];
@@ -14,7 +14,7 @@
 
 use super::{
     chacha::{self, Counter, Iv},
-    poly1305, Aad, Nonce, Tag,
+    poly1305, scatter, Aad, AadSlices, Nonce, Tag,
 };
 use crate::{aead, cpu, endian::*, error, polyfill};
 use core::{convert::TryInto, ops::RangeFrom};
@@ -30,9 +30,30 @@ pub static CHACHA20_POLY1305: aead::Algorithm = aead::Algorithm {
     seal: chacha20_poly1305_seal,
     open: chacha20_poly1305_open,
     id: aead::AlgorithmID::CHACHA20_POLY1305,
+    nonce_len: super::NONCE_LEN,
+    tag_len: super::TAG_LEN,
     max_input_len: super::max_input_len(64, 1),
 };
 
+/// XChaCha20-Poly1305 as described in [draft-irtf-cfrg-xchacha].
+///
+/// The keys are 256 bits long and the nonces are 192 bits long. The nonces are
+/// long enough that they may be generated randomly.
+///
+/// [draft-irtf-cfrg-xchacha]: https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03
+pub static XCHACHA20_POLY1305: aead::Algorithm = aead::Algorithm {
+    key_len: chacha::KEY_LEN,
+    init: xchacha20_poly1305_init,
+    seal: xchacha20_poly1305_seal,
+    open: xchacha20_poly1305_open,
+    id: aead::AlgorithmID::XCHACHA20_POLY1305,
+    nonce_len: XNONCE_LEN,
+    tag_len: super::TAG_LEN,
+    max_input_len: super::max_input_len(64, 1),
+};
+
+const XNONCE_LEN: usize = 192 / 8;
+
 /// Copies |key| into |ctx_buf|.
 fn chacha20_poly1305_init(
     key: &[u8],
@@ -45,20 +66,52 @@ fn chacha20_poly1305_init(
     )))
 }
 
+fn xchacha20_poly1305_init(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<aead::KeyInner, error::Unspecified> {
+    let key: [u8; chacha::KEY_LEN] = key.try_into()?;
+    Ok(aead::KeyInner::XChaCha20Poly1305(chacha::Key::new(
+        key,
+        cpu_features,
+    )))
+}
+
 fn chacha20_poly1305_seal(
     key: &aead::KeyInner,
     nonce: Nonce,
-    aad: Aad<&[u8]>,
+    aad: Aad<AadSlices>,
     in_out: &mut [u8],
 ) -> Tag {
     let chacha20_key = match key {
         aead::KeyInner::ChaCha20Poly1305(key) => key,
         _ => unreachable!(),
     };
+    seal(chacha20_key, nonce, aad, in_out)
+}
 
+fn xchacha20_poly1305_seal(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+) -> Tag {
+    let xchacha20_key = match key {
+        aead::KeyInner::XChaCha20Poly1305(key) => key,
+        _ => unreachable!(),
+    };
+    let (chacha20_key, nonce) = derive_xchacha20_subkey_and_nonce(xchacha20_key, nonce);
+    seal(&chacha20_key, nonce, aad, in_out)
+}
+
+fn seal(chacha20_key: &chacha::Key, nonce: Nonce, aad: Aad<AadSlices>, in_out: &mut [u8]) -> Tag {
     #[cfg(target_arch = "x86_64")]
     {
-        if cpu::intel::SSE41.available(chacha20_key.cpu_features()) {
+        // The assembly code requires the AAD to be contiguous.
+        if let (true, Some(aad)) = (
+            cpu::intel::SSE41.available(chacha20_key.cpu_features()),
+            aad.0.as_contiguous(),
+        ) {
             // XXX: BoringSSL uses `alignas(16)` on `key` instead of on the
             // structure, but Rust can't do that yet; see
             // https://github.com/rust-lang/rust/issues/73557.
@@ -79,7 +132,7 @@ fn chacha20_poly1305_seal(
                 input: seal_data_in {
                     key: *chacha20_key.words_less_safe(),
                     counter: 0,
-                    nonce: *nonce.as_ref(),
+                    nonce: *nonce.as_96_bits(),
                     extra_ciphertext: core::ptr::null(),
                     extra_ciphertext_len: 0,
                 },
@@ -102,14 +155,14 @@ fn chacha20_poly1305_seal(
                     in_out.as_mut_ptr(),
                     in_out.as_ptr(),
                     in_out.len(),
-                    aad.as_ref().as_ptr(),
-                    aad.as_ref().len(),
+                    aad.as_ptr(),
+                    aad.len(),
                     &mut data,
                 );
                 &data.out
             };
 
-            return Tag(out.tag);
+            return Tag::from(out.tag);
         }
     }
 
@@ -119,16 +172,17 @@ fn chacha20_poly1305_seal(
         poly1305::Context::from_key(key)
     };
 
-    poly1305_update_padded_16(&mut auth, aad.as_ref());
+    poly1305_update_aad(&mut auth, aad.0);
     chacha20_key.encrypt_in_place(counter, in_out);
     poly1305_update_padded_16(&mut auth, in_out);
-    finish(auth, aad.as_ref().len(), in_out.len())
+    finish(auth, aad.0.len(), in_out.len())
 }
 
 fn chacha20_poly1305_open(
     key: &aead::KeyInner,
     nonce: Nonce,
-    aad: Aad<&[u8]>,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
     in_out: &mut [u8],
     src: RangeFrom<usize>,
 ) -> Tag {
@@ -136,10 +190,39 @@ fn chacha20_poly1305_open(
         aead::KeyInner::ChaCha20Poly1305(key) => key,
         _ => unreachable!(),
     };
+    open(chacha20_key, nonce, aad, in_out, src)
+}
+
+fn xchacha20_poly1305_open(
+    key: &aead::KeyInner,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    _received_tag: &Tag,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
+    let xchacha20_key = match key {
+        aead::KeyInner::XChaCha20Poly1305(key) => key,
+        _ => unreachable!(),
+    };
+    let (chacha20_key, nonce) = derive_xchacha20_subkey_and_nonce(xchacha20_key, nonce);
+    open(&chacha20_key, nonce, aad, in_out, src)
+}
 
+fn open(
+    chacha20_key: &chacha::Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Tag {
     #[cfg(target_arch = "x86_64")]
     {
-        if cpu::intel::SSE41.available(chacha20_key.cpu_features()) {
+        // The assembly code requires the AAD to be contiguous.
+        if let (true, Some(aad)) = (
+            cpu::intel::SSE41.available(chacha20_key.cpu_features()),
+            aad.0.as_contiguous(),
+        ) {
             // XXX: BoringSSL uses `alignas(16)` on `key` instead of on the
             // structure, but Rust can't do that yet; see
             // https://github.com/rust-lang/rust/issues/73557.
@@ -158,7 +241,7 @@ fn chacha20_poly1305_open(
                 input: open_data_in {
                     key: *chacha20_key.words_less_safe(),
                     counter: 0,
-                    nonce: *nonce.as_ref(),
+                    nonce: *nonce.as_96_bits(),
                 },
             };
 
@@ -179,14 +262,14 @@ fn chacha20_poly1305_open(
                     in_out.as_mut_ptr(),
                     in_out.as_ptr().add(src.start),
                     in_out.len() - src.start,
-                    aad.as_ref().as_ptr(),
-                    aad.as_ref().len(),
+                    aad.as_ptr(),
+                    aad.len(),
                     &mut data,
                 );
                 &data.out
             };
 
-            return Tag(out.tag);
+            return Tag::from(out.tag);
         }
     }
 
@@ -196,10 +279,66 @@ fn chacha20_poly1305_open(
         poly1305::Context::from_key(key)
     };
 
-    poly1305_update_padded_16(&mut auth, aad.as_ref());
+    poly1305_update_aad(&mut auth, aad.0);
     poly1305_update_padded_16(&mut auth, &in_out[src.clone()]);
     chacha20_key.encrypt_within(counter, in_out, src.clone());
-    finish(auth, aad.as_ref().len(), in_out[src].len())
+    finish(auth, aad.0.len(), in_out[src].len())
+}
+
+/// Like `chacha20_poly1305_open`, except the ciphertext is the first
+/// `ciphertext_len` bytes of the concatenation of `in_out`, and it is
+/// decrypted in place.
+pub(super) fn open_scatter(
+    chacha20_key: &chacha::Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [&mut [u8]],
+    ciphertext_len: usize,
+) -> Tag {
+    let mut counter = Counter::zero(nonce);
+    let mut auth = {
+        let key = derive_poly1305_key(chacha20_key, counter.increment());
+        poly1305::Context::from_key(key)
+    };
+
+    poly1305_update_aad(&mut auth, aad.0);
+    let mut buf = [0u8; chacha::BLOCK_LEN];
+    scatter::for_each_chunk(in_out, ciphertext_len, &mut buf, |chunk| {
+        auth.update(chunk);
+        let blocks =
+            (chunk.len() / chacha::BLOCK_LEN) + usize::from(chunk.len() % chacha::BLOCK_LEN != 0);
+        chacha20_key.encrypt_in_place(counter.take_blocks_less_safe(blocks), chunk);
+    });
+    let remainder_len = ciphertext_len % poly1305::BLOCK_LEN;
+    if remainder_len != 0 {
+        auth.update(&[0; poly1305::BLOCK_LEN][..(poly1305::BLOCK_LEN - remainder_len)]);
+    }
+    finish(auth, aad.0.len(), ciphertext_len)
+}
+
+/// Like `open_scatter`, for XChaCha20-Poly1305.
+pub(super) fn xopen_scatter(
+    xchacha20_key: &chacha::Key,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [&mut [u8]],
+    ciphertext_len: usize,
+) -> Tag {
+    let (chacha20_key, nonce) = derive_xchacha20_subkey_and_nonce(xchacha20_key, nonce);
+    open_scatter(&chacha20_key, nonce, aad, in_out, ciphertext_len)
+}
+
+// The first 128 bits of the extended nonce are used with HChaCha20 to derive
+// the ChaCha20 subkey. The remaining 64 bits, prefixed with four zero bytes,
+// are the ChaCha20 nonce.
+fn derive_xchacha20_subkey_and_nonce(key: &chacha::Key, nonce: Nonce) -> (chacha::Key, Nonce) {
+    let nonce = nonce.as_bytes();
+    let (hchacha20_nonce, remainder) = nonce.split_at(chacha::HCHACHA20_NONCE_LEN);
+    let subkey = key.hchacha20(hchacha20_nonce.try_into().unwrap());
+
+    let mut subkey_nonce = [0u8; super::NONCE_LEN];
+    subkey_nonce[4..].copy_from_slice(remainder);
+    (subkey, Nonce::assume_unique_for_key(subkey_nonce))
 }
 
 fn finish(mut auth: poly1305::Context, aad_len: usize, in_out_len: usize) -> Tag {
@@ -239,6 +378,15 @@ struct Out {
 }
 
 #[inline]
+fn poly1305_update_aad(ctx: &mut poly1305::Context, aad: AadSlices) {
+    aad.0.iter().for_each(|slice| ctx.update(slice));
+    let remainder_len = aad.len() % poly1305::BLOCK_LEN;
+    if remainder_len != 0 {
+        const ZEROES: [u8; poly1305::BLOCK_LEN] = [0; poly1305::BLOCK_LEN];
+        ctx.update(&ZEROES[..(poly1305::BLOCK_LEN - remainder_len)])
+    }
+}
+
 fn poly1305_update_padded_16(ctx: &mut poly1305::Context, input: &[u8]) {
     if !input.is_empty() {
         ctx.update(input);
@@ -254,7 +402,7 @@ fn poly1305_update_padded_16(ctx: &mut poly1305::Context, input: &[u8]) {
 pub(super) fn derive_poly1305_key(chacha_key: &chacha::Key, iv: Iv) -> poly1305::Key {
     let mut key_bytes = [0u8; poly1305::KEY_LEN];
     chacha_key.encrypt_iv_xor_in_place(iv, &mut key_bytes);
-    poly1305::Key::new(key_bytes, chacha_key.cpu_features())
+    poly1305::Key::new_(key_bytes, chacha_key.cpu_features())
 }
 
 #[cfg(test)]
@@ -263,5 +411,6 @@ mod tests {
     fn max_input_len_test() {
         // https://tools.ietf.org/html/rfc8439#section-2.8
         assert_eq!(super::CHACHA20_POLY1305.max_input_len, 274_877_906_880u64);
+        assert_eq!(super::XCHACHA20_POLY1305.max_input_len, 274_877_906_880u64);
     }
 }
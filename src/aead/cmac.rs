@@ -0,0 +1,149 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-CMAC, as described in [RFC 4493].
+//!
+//! [RFC 4493]: https://tools.ietf.org/html/rfc4493
+
+use super::{
+    aes,
+    block::{Block, BLOCK_LEN},
+};
+use core::borrow::Borrow;
+
+#[derive(Clone)]
+pub(crate) struct Key {
+    aes_key: aes::Key,
+    k1: [u8; BLOCK_LEN],
+    k2: [u8; BLOCK_LEN],
+}
+
+impl Key {
+    pub(crate) fn new(aes_key: aes::Key) -> Self {
+        let l = aes_key.encrypt_block(Block::zero());
+        let k1 = dbl(*l.as_ref());
+        let k2 = dbl(k1);
+        Self { aes_key, k1, k2 }
+    }
+}
+
+/// A CMAC context, which either borrows or owns its key.
+///
+/// The last block of input is buffered until `finish()` because its
+/// processing depends on whether it is the last block.
+#[derive(Clone)]
+pub(crate) struct Context<K> {
+    key: K,
+    state: [u8; BLOCK_LEN],
+    pending: [u8; BLOCK_LEN],
+    pending_len: usize,
+}
+
+impl<K: Borrow<Key>> Context<K> {
+    pub(crate) fn new(key: K) -> Self {
+        Self {
+            key,
+            state: [0; BLOCK_LEN],
+            pending: [0; BLOCK_LEN],
+            pending_len: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.pending_len == BLOCK_LEN {
+                xor_assign(&mut self.state, &self.pending);
+                self.state = *self
+                    .key
+                    .borrow()
+                    .aes_key
+                    .encrypt_block(Block::from(&self.state))
+                    .as_ref();
+                self.pending_len = 0;
+            }
+            let todo = core::cmp::min(BLOCK_LEN - self.pending_len, input.len());
+            let (chunk, remainder) = input.split_at(todo);
+            self.pending[self.pending_len..][..todo].copy_from_slice(chunk);
+            self.pending_len += todo;
+            input = remainder;
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> [u8; BLOCK_LEN] {
+        let key = self.key.borrow();
+        if self.pending_len == BLOCK_LEN {
+            xor_assign(&mut self.pending, &key.k1);
+        } else {
+            self.pending[self.pending_len] = 0x80;
+            self.pending[(self.pending_len + 1)..].fill(0);
+            xor_assign(&mut self.pending, &key.k2);
+        }
+        xor_assign(&mut self.state, &self.pending);
+        *key.aes_key.encrypt_block(Block::from(&self.state)).as_ref()
+    }
+}
+
+pub(crate) fn sign(key: &Key, input: &[u8]) -> [u8; BLOCK_LEN] {
+    let mut ctx = Context::new(key);
+    ctx.update(input);
+    ctx.finish()
+}
+
+/// Multiplication by x in GF(2^128), as used by CMAC and S2V. `block` may be
+/// secret so this is done without branching on its value.
+pub(super) fn dbl(block: [u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+    let value = u128::from_be_bytes(block);
+    let reduce = 0u128.wrapping_sub(value >> 127) & 0x87;
+    ((value << 1) ^ reduce).to_be_bytes()
+}
+
+#[inline]
+pub(super) fn xor_assign(r: &mut [u8; BLOCK_LEN], a: &[u8; BLOCK_LEN]) {
+    r.iter_mut().zip(a.iter()).for_each(|(r, a)| *r ^= a);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cpu, test};
+
+    // https://tools.ietf.org/html/rfc4493#section-4
+    #[test]
+    fn cmac_test() {
+        let key = test::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+        let key = Key::new(aes::Key::new(&key, aes::Variant::AES_128, cpu::features()).unwrap());
+        let m = test::from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+        )
+        .unwrap();
+
+        for (len, expected) in [
+            (0, "bb1d6929e95937287fa37d129b756746"),
+            (16, "070a16b46b4d4144f79bdd9dd04a287c"),
+            (40, "dfa66747de9ae63030ca32611497c827"),
+            (64, "51f0bebf7e3b9d92fc49741779363cfe"),
+        ] {
+            let expected = test::from_hex(expected).unwrap();
+            assert_eq!(sign(&key, &m[..len]).as_ref(), expected.as_slice());
+
+            // The result must not depend on how the input is split.
+            let mut ctx = Context::new(&key);
+            for chunk in m[..len].chunks(7) {
+                ctx.update(chunk);
+            }
+            assert_eq!(ctx.finish().as_ref(), expected.as_slice());
+        }
+    }
+}
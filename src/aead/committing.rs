@@ -0,0 +1,151 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Key-committing authenticated encryption.
+//!
+//! Most AEADs, including AES-GCM and ChaCha20-Poly1305, are not
+//! *key-committing*: an attacker can construct a ciphertext that opens
+//! successfully under more than one key. When the keys are derived from
+//! guessable secrets like passwords, that enables partitioning oracle
+//! attacks; in multi-recipient settings it enables a sender to deliver
+//! different messages to different recipients (“invisible salamanders”).
+//! See [How to Abuse and Fix Authenticated Encryption Without Key Commitment].
+//!
+//! A `committing::Key` fixes this by deriving both the encryption key and a
+//! 256-bit commitment to the key from the key using HKDF-SHA-256, and by
+//! appending the commitment to every sealed message. Opening fails unless
+//! the commitment matches the key. Specifically, with `K` being the key and
+//! the HKDF salt being empty:
+//!
+//! ```text
+//! PRK = HKDF-Extract(salt, K)
+//! encryption_key = HKDF-Expand(PRK, "ring committing AEAD key", algorithm.key_len())
+//! commitment = HKDF-Expand(PRK, "ring committing AEAD commitment", 32)
+//! ```
+//!
+//! A sealed message is `ciphertext || tag || commitment`, where `ciphertext`
+//! and `tag` are the result of sealing with `encryption_key`.
+//!
+//! [How to Abuse and Fix Authenticated Encryption Without Key Commitment]:
+//!     https://www.usenix.org/conference/usenixsecurity22/presentation/albertini
+
+use super::{Aad, AadValue, Algorithm, LessSafeKey, Nonce, UnboundKey};
+use crate::{constant_time, error, hkdf};
+
+/// The length of the key commitment that is appended to each sealed message.
+pub const COMMITMENT_LEN: usize = 256 / 8;
+
+/// A key-committing AEAD key.
+pub struct Key {
+    inner: LessSafeKey,
+    commitment: [u8; COMMITMENT_LEN],
+}
+
+impl Key {
+    /// Constructs a `Key` for the given algorithm from `key_bytes`.
+    ///
+    /// `key_bytes` must be `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if key_bytes.len() != algorithm.key_len() {
+            return Err(error::Unspecified);
+        }
+        let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, &[]).extract(key_bytes);
+        let inner = LessSafeKey::new(UnboundKey::from(
+            prk.expand(&[ENCRYPTION_KEY_INFO], algorithm)?,
+        ));
+        let mut commitment = [0u8; COMMITMENT_LEN];
+        prk.expand(&[COMMITMENT_INFO], CommitmentLen)?
+            .fill(&mut commitment)?;
+        Ok(Self { inner, commitment })
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.inner.algorithm()
+    }
+
+    /// The number of bytes that sealing appends to the plaintext: the length
+    /// of the tag plus `COMMITMENT_LEN`.
+    #[inline]
+    pub fn overhead_len(&self) -> usize {
+        self.algorithm().tag_len() + COMMITMENT_LEN
+    }
+
+    /// Encrypts and signs (“seals”) data in place, appending the tag and the
+    /// key commitment.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AadValue,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.inner.seal_in_place_append_tag(nonce, aad, in_out)?;
+        in_out.extend(&self.commitment);
+        Ok(())
+    }
+
+    /// Authenticates and decrypts (“opens”) data in place.
+    ///
+    /// `in_out` is `ciphertext || tag || commitment`. Opening fails if the
+    /// commitment isn't this key's commitment or if the tag is wrong.
+    ///
+    /// On success, returns the plaintext, which is at the start of `in_out`.
+    /// On failure, the contents of `in_out` are unspecified.
+    pub fn open_in_place<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let sealed_len = in_out
+            .len()
+            .checked_sub(COMMITMENT_LEN)
+            .ok_or(error::Unspecified)?;
+        let (sealed, commitment) = in_out.split_at_mut(sealed_len);
+        constant_time::verify_slices_are_equal(commitment, &self.commitment)?;
+        self.inner.open_in_place(nonce, aad, sealed)
+    }
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm())
+            .finish()
+    }
+}
+
+const ENCRYPTION_KEY_INFO: &[u8] = b"ring committing AEAD key";
+const COMMITMENT_INFO: &[u8] = b"ring committing AEAD commitment";
+
+struct CommitmentLen;
+
+impl hkdf::KeyType for CommitmentLen {
+    fn len(&self) -> usize {
+        COMMITMENT_LEN
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Counter-based `NonceSequence`s.
+
+use super::{Nonce, NonceSequence, NONCE_LEN};
+use crate::error;
+
+/// The byte order in which a counter is encoded into a nonce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// The counter is encoded as a big-endian integer, so its least
+    /// significant byte is the last byte of the nonce.
+    Big,
+
+    /// The counter is encoded as a little-endian integer, so its least
+    /// significant byte is the first byte of the nonce.
+    Little,
+}
+
+/// A `NonceSequence` of the 96-bit nonces 0, 1, 2, ..., 2^96 - 1.
+///
+/// `advance()` fails once every nonce has been returned, and it keeps failing
+/// forever after that.
+pub struct CounterNonceSequence {
+    next: Option<u128>,
+    endianness: Endianness,
+}
+
+impl CounterNonceSequence {
+    /// Constructs a sequence that starts at zero.
+    pub fn new(endianness: Endianness) -> Self {
+        Self {
+            next: Some(0),
+            endianness,
+        }
+    }
+
+    /// Constructs a sequence that starts at `first`.
+    ///
+    /// This is useful for continuing a sequence that was interrupted. Fails if
+    /// `first` isn't less than 2^96.
+    pub fn starting_at(first: u128, endianness: Endianness) -> Result<Self, error::Unspecified> {
+        if first > MAX_COUNTER {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            next: Some(first),
+            endianness,
+        })
+    }
+}
+
+impl NonceSequence for CounterNonceSequence {
+    fn advance(&mut self) -> Result<Nonce, error::Unspecified> {
+        let counter = self.next.ok_or(error::Unspecified)?;
+        self.next = counter.checked_add(1).filter(|next| *next <= MAX_COUNTER);
+        Ok(Nonce::assume_unique_for_key(encode(
+            counter,
+            self.endianness,
+        )))
+    }
+}
+
+/// A `NonceSequence` of a fixed 96-bit IV XORed with a 64-bit counter that
+/// starts at zero, as in TLS 1.3 and QUIC. See [RFC 8446 Section 5.3].
+///
+/// The counter is encoded as a 96-bit integer before being XORed with the IV;
+/// with `Endianness::Big`, the construction is exactly that of TLS 1.3.
+///
+/// `advance()` fails once all 2^64 counter values have been used, and it keeps
+/// failing forever after that.
+///
+/// [RFC 8446 Section 5.3]: https://tools.ietf.org/html/rfc8446#section-5.3
+pub struct XorCounterNonceSequence {
+    iv: [u8; NONCE_LEN],
+    next: Option<u64>,
+    endianness: Endianness,
+}
+
+impl XorCounterNonceSequence {
+    /// Constructs a sequence for the IV `iv` whose counter starts at zero.
+    pub fn new(iv: [u8; NONCE_LEN], endianness: Endianness) -> Self {
+        Self::starting_at(iv, 0, endianness)
+    }
+
+    /// Constructs a sequence for the IV `iv` whose counter starts at `first`.
+    ///
+    /// This is useful for continuing a sequence that was interrupted.
+    pub fn starting_at(iv: [u8; NONCE_LEN], first: u64, endianness: Endianness) -> Self {
+        Self {
+            iv,
+            next: Some(first),
+            endianness,
+        }
+    }
+}
+
+impl NonceSequence for XorCounterNonceSequence {
+    fn advance(&mut self) -> Result<Nonce, error::Unspecified> {
+        let counter = self.next.ok_or(error::Unspecified)?;
+        self.next = counter.checked_add(1);
+        let mut nonce = encode(u128::from(counter), self.endianness);
+        nonce
+            .iter_mut()
+            .zip(self.iv.iter())
+            .for_each(|(n, iv)| *n ^= iv);
+        Ok(Nonce::assume_unique_for_key(nonce))
+    }
+}
+
+const MAX_COUNTER: u128 = (1 << (8 * NONCE_LEN)) - 1;
+
+fn encode(counter: u128, endianness: Endianness) -> [u8; NONCE_LEN] {
+    let mut nonce = [0u8; NONCE_LEN];
+    match endianness {
+        Endianness::Big => {
+            nonce.copy_from_slice(&counter.to_be_bytes()[(16 - NONCE_LEN)..]);
+        }
+        Endianness::Little => {
+            nonce.copy_from_slice(&counter.to_le_bytes()[..NONCE_LEN]);
+        }
+    }
+    nonce
+}
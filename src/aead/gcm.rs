@@ -14,7 +14,7 @@
 
 use super::{
     block::{Block, BLOCK_LEN},
-    Aad,
+    Aad, AadSlices,
 };
 use crate::cpu;
 use core::ops::BitXorAssign;
@@ -92,7 +92,7 @@ pub struct Context {
 }
 
 impl Context {
-    pub(crate) fn new(key: &Key, aad: Aad<&[u8]>) -> Self {
+    pub(crate) fn new(key: &Key, aad: Aad<AadSlices>) -> Self {
         let mut ctx = Self {
             inner: ContextInner {
                 Xi: Xi(Block::zero()),
@@ -102,11 +102,13 @@ impl Context {
             cpu_features: key.cpu_features,
         };
 
-        for ad in aad.0.chunks(BLOCK_LEN) {
-            let mut block = Block::zero();
-            block.overwrite_part_at(0, ad);
-            ctx.update_block(block);
-        }
+        aad.0.for_each_chunk(0, &mut [0u8; BLOCK_LEN], |chunk| {
+            for ad in chunk.chunks(BLOCK_LEN) {
+                let mut block = Block::zero();
+                block.overwrite_part_at(0, ad);
+                ctx.update_block(block);
+            }
+        });
 
         ctx
     }
@@ -234,9 +236,9 @@ impl Context {
         }
     }
 
-    pub(super) fn pre_finish<F>(self, f: F) -> super::Tag
+    pub(super) fn pre_finish<F, T>(self, f: F) -> T
     where
-        F: FnOnce(Block) -> super::Tag,
+        F: FnOnce(Block) -> T,
     {
         f(self.inner.Xi.0)
     }
@@ -0,0 +1,139 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! GMAC, the message authentication code of AES-GCM.
+//!
+//! GMAC authenticates data, without encrypting anything; its tag is the tag
+//! of AES-GCM with the data as the AAD and an empty plaintext. See
+//! [NIST SP 800-38D]. Like AES-GCM, a nonce must never be used more than once
+//! with the same key.
+//!
+//! [NIST SP 800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+
+use super::{aes, aes_gcm, Aad, Nonce, Tag};
+use crate::{constant_time, cpu, error};
+
+/// A GMAC algorithm.
+pub struct Algorithm {
+    variant: aes::Variant,
+    key_len: usize,
+    id: AlgorithmID,
+}
+
+impl Algorithm {
+    /// The length of the key.
+    #[inline(always)]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    /// The length of the nonces: 96 bits.
+    #[inline(always)]
+    pub fn nonce_len(&self) -> usize {
+        super::NONCE_LEN
+    }
+
+    /// The length of the tags: 128 bits.
+    #[inline(always)]
+    pub fn tag_len(&self) -> usize {
+        super::TAG_LEN
+    }
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    AES_128,
+    AES_256,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+/// GMAC with AES-128.
+pub static AES_128: Algorithm = Algorithm {
+    variant: aes::Variant::AES_128,
+    key_len: 16,
+    id: AlgorithmID::AES_128,
+};
+
+/// GMAC with AES-256.
+pub static AES_256: Algorithm = Algorithm {
+    variant: aes::Variant::AES_256,
+    key_len: 32,
+    id: AlgorithmID::AES_256,
+};
+
+/// A GMAC key.
+#[derive(Clone)]
+pub struct Key {
+    inner: aes_gcm::Key,
+    algorithm: &'static Algorithm,
+}
+
+impl Key {
+    /// Constructs a GMAC key.
+    ///
+    /// `key_bytes` must be exactly `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            inner: aes_gcm::Key::new(key_bytes, algorithm.variant, cpu::features())?,
+            algorithm,
+        })
+    }
+
+    /// The key's algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// Calculates the tag of `data`.
+    ///
+    /// `nonce` must be unique for every use of the key. Fails if `nonce` isn't
+    /// `NONCE_LEN` bytes long.
+    pub fn sign(&self, nonce: Nonce, data: &[u8]) -> Result<Tag, error::Unspecified> {
+        if nonce.len() != super::NONCE_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(aes_gcm::gmac(
+            &self.inner,
+            nonce,
+            Aad::from_slices(core::slice::from_ref(&data)),
+        ))
+    }
+
+    /// Verifies that `tag` is the tag of `data`.
+    pub fn verify(&self, nonce: Nonce, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+        let calculated = self.sign(nonce, data)?;
+        constant_time::verify_slices_are_equal(calculated.as_ref(), tag)
+    }
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Algorithm, UnboundKey};
+use crate::{error, hkdf};
+
+/// Derives a sequence of AEAD keys from a secret, for key updates.
+///
+/// Each call to `next_key()` derives a key from the current secret with
+/// HKDF-Expand and then replaces the secret with another value derived from
+/// it, so that a compromise of the current secret doesn't reveal any key that
+/// was previously derived. Install each key with `SealingKey::rekey()` or
+/// `OpeningKey::rekey()`.
+///
+/// Protocols that specify their own key schedule, like TLS 1.3, must use
+/// `hkdf` directly with that protocol's labels instead.
+pub struct KeyRatchet {
+    secret: hkdf::Prk,
+    hkdf_algorithm: hkdf::Algorithm,
+    algorithm: &'static Algorithm,
+}
+
+impl KeyRatchet {
+    /// Constructs a `KeyRatchet` that derives keys for `algorithm` from
+    /// `secret`, which must be a uniformly-random key at least as long as the
+    /// output of `hkdf_algorithm`, e.g. the output of HKDF-Extract.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        hkdf_algorithm: hkdf::Algorithm,
+        secret: &[u8],
+    ) -> Self {
+        Self {
+            secret: hkdf::Prk::new_less_safe(hkdf_algorithm, secret),
+            hkdf_algorithm,
+            algorithm,
+        }
+    }
+
+    /// The algorithm of the keys that are derived.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// Derives the next key, and replaces the secret.
+    pub fn next_key(&mut self) -> Result<UnboundKey, error::Unspecified> {
+        let key = UnboundKey::from(self.secret.expand(&[KEY_INFO], self.algorithm)?);
+        let secret = hkdf::Prk::from(
+            self.secret
+                .expand(&[NEXT_SECRET_INFO], self.hkdf_algorithm)?,
+        );
+        self.secret = secret;
+        Ok(key)
+    }
+}
+
+impl core::fmt::Debug for KeyRatchet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("KeyRatchet")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+const KEY_INFO: &[u8] = b"ring aead ratchet key";
+const NEXT_SECRET_INFO: &[u8] = b"ring aead ratchet secret";
@@ -12,14 +12,20 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use super::{Aad, Algorithm, KeyInner, Nonce, Tag, UnboundKey, TAG_LEN};
+use super::{
+    aes_gcm, chacha20_poly1305, scatter, Aad, AadSlices, AadValue, Algorithm, KeyInner, Nonce, Tag,
+    UnboundKey, MAX_TAG_LEN,
+};
 use crate::{constant_time, cpu, error, polyfill};
-use core::{convert::TryInto, ops::RangeFrom};
+use core::{convert::TryInto, mem::MaybeUninit, ops::RangeFrom};
 
 /// Immutable keys for use in situations where `OpeningKey`/`SealingKey` and
 /// `NonceSequence` cannot reasonably be used.
 ///
 /// Prefer to use `OpeningKey`/`SealingKey` and `NonceSequence` when practical.
+///
+/// A `LessSafeKey` doesn't limit how much data it seals; use a `UsageTracker`
+/// to do so.
 #[derive(Clone)]
 pub struct LessSafeKey {
     inner: KeyInner,
@@ -44,8 +50,14 @@ impl LessSafeKey {
         })
     }
 
-    /// Like [open_in_place](Self::open_in_place), except the authentication tag is
-    /// passed separately.
+    /// Like [`open_within()`](Self::open_within), except the authentication
+    /// tag is passed separately instead of being appended to the ciphertext.
+    ///
+    /// On input, `in_out[ciphertext]` must be the ciphertext alone; use `0..`
+    /// to open it in place. This is useful for formats that don't put the tag
+    /// directly after the ciphertext, e.g. formats that carry it in a header.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
     #[inline]
     pub fn open_in_place_separate_tag<'in_out, A>(
         &self,
@@ -56,10 +68,9 @@ impl LessSafeKey {
         ciphertext: RangeFrom<usize>,
     ) -> Result<&'in_out mut [u8], error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
-        let aad = Aad::from(aad.as_ref());
-        open_within_(self, nonce, aad, tag, in_out, ciphertext)
+        aad.with_slices(move |aad| open_within_(self, nonce, aad, tag, in_out, ciphertext))
     }
 
     /// Like [`OpeningKey::open_in_place()`], except it accepts an arbitrary nonce.
@@ -73,7 +84,7 @@ impl LessSafeKey {
         in_out: &'in_out mut [u8],
     ) -> Result<&'in_out mut [u8], error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
         self.open_within(nonce, aad, in_out, 0..)
     }
@@ -90,11 +101,11 @@ impl LessSafeKey {
         ciphertext_and_tag: RangeFrom<usize>,
     ) -> Result<&'in_out mut [u8], error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
         let tag_offset = in_out
             .len()
-            .checked_sub(TAG_LEN)
+            .checked_sub(self.algorithm.tag_len())
             .ok_or(error::Unspecified)?;
 
         // Split the tag off the end of `in_out`.
@@ -117,7 +128,7 @@ impl LessSafeKey {
         in_out: &mut InOut,
     ) -> Result<(), error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
         self.seal_in_place_separate_tag(nonce, aad, in_out.as_mut())
@@ -136,9 +147,262 @@ impl LessSafeKey {
         in_out: &mut [u8],
     ) -> Result<Tag, error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
+    {
+        aad.with_slices(|aad| seal_in_place_separate_tag_(self, nonce, aad, in_out))
+    }
+
+    /// Like [`SealingKey::seal_to()`], except it accepts an arbitrary nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    #[inline]
+    pub fn seal_to<'out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        plaintext: &[u8],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let sealed_len = plaintext
+            .len()
+            .checked_add(self.algorithm.tag_len())
+            .ok_or(error::Unspecified)?;
+        let out = out.get_mut(..sealed_len).ok_or(error::Unspecified)?;
+        let (ciphertext, tag_out) = out.split_at_mut(plaintext.len());
+        ciphertext.copy_from_slice(plaintext);
+        self.seal_copied_plaintext(nonce, aad, ciphertext, tag_out)?;
+        Ok(out)
+    }
+
+    /// Like [`seal_to()`](Self::seal_to), except `out` may be uninitialized;
+    /// this saves initializing a large `out` only to overwrite it.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    #[inline]
+    pub fn seal_to_uninit<'out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        plaintext: &[u8],
+        out: &'out mut [MaybeUninit<u8>],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
     {
-        seal_in_place_separate_tag_(self, nonce, Aad::from(aad.as_ref()), in_out)
+        let tag_len = self.algorithm.tag_len();
+        let sealed_len = plaintext
+            .len()
+            .checked_add(tag_len)
+            .ok_or(error::Unspecified)?;
+        let out = out.get_mut(..sealed_len).ok_or(error::Unspecified)?;
+        let (ciphertext, tag_out) = out.split_at_mut(plaintext.len());
+        let ciphertext = polyfill::write_slice(ciphertext, plaintext);
+        let tag_out = polyfill::write_slice(tag_out, &[0u8; MAX_TAG_LEN][..tag_len]);
+        self.seal_copied_plaintext(nonce, aad, ciphertext, tag_out)?;
+        // All of `out` was initialized above.
+        Ok(unsafe { polyfill::slice_assume_init_mut(out) })
+    }
+
+    fn seal_copied_plaintext<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        ciphertext: &mut [u8],
+        tag_out: &mut [u8],
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AadValue,
+    {
+        match self.seal_in_place_separate_tag(nonce, aad, ciphertext) {
+            Ok(tag) => {
+                tag_out.copy_from_slice(tag.as_ref());
+                Ok(())
+            }
+            Err(error::Unspecified) => {
+                // Don't leave a copy of the plaintext where the ciphertext
+                // was expected.
+                for b in ciphertext {
+                    *b = 0;
+                }
+                Err(error::Unspecified)
+            }
+        }
+    }
+
+    /// Like [`OpeningKey::open_to()`], except it accepts an arbitrary nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[inline]
+    pub fn open_to<'out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[u8],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let ciphertext_len = ciphertext_and_tag
+            .len()
+            .checked_sub(self.algorithm.tag_len())
+            .ok_or(error::Unspecified)?;
+        let (ciphertext, received_tag) = ciphertext_and_tag.split_at(ciphertext_len);
+        let received_tag = received_tag.try_into()?;
+        let out = out.get_mut(..ciphertext_len).ok_or(error::Unspecified)?;
+        out.copy_from_slice(ciphertext);
+        self.open_in_place_separate_tag(nonce, aad, received_tag, out, 0..)
+    }
+
+    /// Like [`open_to()`](Self::open_to), except `out` may be uninitialized;
+    /// this saves initializing a large `out` only to overwrite it.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[inline]
+    pub fn open_to_uninit<'out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[u8],
+        out: &'out mut [MaybeUninit<u8>],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let ciphertext_len = ciphertext_and_tag
+            .len()
+            .checked_sub(self.algorithm.tag_len())
+            .ok_or(error::Unspecified)?;
+        let (ciphertext, received_tag) = ciphertext_and_tag.split_at(ciphertext_len);
+        let received_tag = received_tag.try_into()?;
+        let out = out.get_mut(..ciphertext_len).ok_or(error::Unspecified)?;
+        let out = polyfill::write_slice(out, ciphertext);
+        self.open_in_place_separate_tag(nonce, aad, received_tag, out, 0..)
+    }
+
+    /// Like [`OpeningKey::open_in_place_scatter()`], except it accepts an
+    /// arbitrary nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[inline]
+    pub fn open_in_place_scatter<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut [&mut [u8]],
+    ) -> Result<usize, error::Unspecified>
+    where
+        A: AadValue,
+    {
+        aad.with_slices(|aad| open_scatter_(self, nonce, aad, in_out))
+    }
+
+    /// Like [`OpeningKey::open_gather()`], except it accepts an arbitrary
+    /// nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[inline]
+    pub fn open_gather<'out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[&[u8]],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let total_len = ciphertext_and_tag
+            .iter()
+            .try_fold(0usize, |len, segment| len.checked_add(segment.len()))
+            .ok_or(error::Unspecified)?;
+        let ciphertext_len = total_len
+            .checked_sub(self.algorithm.tag_len())
+            .ok_or(error::Unspecified)?;
+        let out = out.get_mut(..ciphertext_len).ok_or(error::Unspecified)?;
+
+        let mut received_tag = [0u8; MAX_TAG_LEN];
+        let received_tag = &mut received_tag[..self.algorithm.tag_len()];
+        let mut offset = 0;
+        for segment in ciphertext_and_tag {
+            for b in segment.iter() {
+                if offset < ciphertext_len {
+                    out[offset] = *b;
+                } else {
+                    received_tag[offset - ciphertext_len] = *b;
+                }
+                offset += 1;
+            }
+        }
+        let received_tag = (&*received_tag).try_into()?;
+
+        self.open_in_place_separate_tag(nonce, aad, received_tag, out, 0..)
+    }
+
+    /// Like [`seal_in_place_separate_tag()`](Self::seal_in_place_separate_tag),
+    /// except that AES-GCM encryption of large inputs is split across the
+    /// threads of rayon's global thread pool.
+    ///
+    /// The result is identical to that of `seal_in_place_separate_tag()`.
+    /// Small inputs, and inputs for other algorithms, are sealed on the
+    /// calling thread.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    #[cfg(feature = "parallel")]
+    pub fn seal_in_place_separate_tag_parallel<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AadValue,
+    {
+        aad.with_slices(|aad| match &self.inner {
+            KeyInner::AesGcm(inner) if in_out.len() >= aes_gcm::PARALLEL_MIN_LEN => {
+                check_nonce_len(self.algorithm, &nonce)?;
+                check_per_nonce_max_bytes(self.algorithm, in_out.len())?;
+                let tag = aes_gcm::seal_parallel(inner, nonce, aad, in_out);
+                Ok(tag.truncated(self.algorithm.tag_len()))
+            }
+            _ => seal_in_place_separate_tag_(self, nonce, aad, in_out),
+        })
+    }
+
+    /// Like [`open_in_place_separate_tag()`](Self::open_in_place_separate_tag)
+    /// with a `ciphertext` of `0..`, except that AES-GCM decryption of large
+    /// inputs is split across the threads of rayon's global thread pool.
+    ///
+    /// The result is identical to that of `open_in_place_separate_tag()`.
+    /// Small inputs, and inputs for other algorithms, are opened on the
+    /// calling thread.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[cfg(feature = "parallel")]
+    pub fn open_in_place_separate_tag_parallel<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        tag: Tag,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        aad.with_slices(move |aad| match &self.inner {
+            KeyInner::AesGcm(inner) if in_out.len() >= aes_gcm::PARALLEL_MIN_LEN => {
+                check_nonce_len(self.algorithm, &nonce)?;
+                check_tag_len(self.algorithm, &tag)?;
+                check_per_nonce_max_bytes(self.algorithm, in_out.len())?;
+                let calculated_tag = aes_gcm::open_parallel(inner, nonce, aad, in_out)
+                    .truncated(self.algorithm.tag_len());
+                verify_tag(calculated_tag, tag, in_out)
+            }
+            _ => open_within_(self, nonce, aad, tag, in_out, 0..),
+        })
     }
 
     /// The key's AEAD algorithm.
@@ -161,16 +425,28 @@ impl LessSafeKey {
 fn open_within_<'in_out>(
     key: &LessSafeKey,
     nonce: Nonce,
-    aad: Aad<&[u8]>,
+    aad: Aad<AadSlices>,
     received_tag: Tag,
     in_out: &'in_out mut [u8],
     src: RangeFrom<usize>,
 ) -> Result<&'in_out mut [u8], error::Unspecified> {
     let ciphertext_len = in_out.get(src.clone()).ok_or(error::Unspecified)?.len();
+    check_nonce_len(key.algorithm, &nonce)?;
+    check_tag_len(key.algorithm, &received_tag)?;
     check_per_nonce_max_bytes(key.algorithm, ciphertext_len)?;
 
-    let Tag(calculated_tag) = (key.algorithm.open)(&key.inner, nonce, aad, in_out, src);
+    let calculated_tag = (key.algorithm.open)(&key.inner, nonce, aad, &received_tag, in_out, src)
+        .truncated(key.algorithm.tag_len());
+
+    // `ciphertext_len` is also the plaintext length.
+    verify_tag(calculated_tag, received_tag, &mut in_out[..ciphertext_len])
+}
 
+fn verify_tag(
+    calculated_tag: Tag,
+    received_tag: Tag,
+    plaintext: &mut [u8],
+) -> Result<&mut [u8], error::Unspecified> {
     if constant_time::verify_slices_are_equal(calculated_tag.as_ref(), received_tag.as_ref())
         .is_err()
     {
@@ -178,25 +454,106 @@ fn open_within_<'in_out>(
         // after verification fails. It would be safest if we could check the
         // tag before decrypting, but some `open` implementations interleave
         // authentication with decryption for performance.
-        for b in &mut in_out[..ciphertext_len] {
+        for b in plaintext {
             *b = 0;
         }
         return Err(error::Unspecified);
     }
+    Ok(plaintext)
+}
 
-    // `ciphertext_len` is also the plaintext length.
-    Ok(&mut in_out[..ciphertext_len])
+fn open_scatter_(
+    key: &LessSafeKey,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    in_out: &mut [&mut [u8]],
+) -> Result<usize, error::Unspecified> {
+    let ciphertext_len = scatter::total_len(in_out)
+        .checked_sub(key.algorithm.tag_len())
+        .ok_or(error::Unspecified)?;
+    check_nonce_len(key.algorithm, &nonce)?;
+    check_per_nonce_max_bytes(key.algorithm, ciphertext_len)?;
+
+    let mut received_tag = [0u8; MAX_TAG_LEN];
+    let received_tag = &mut received_tag[..key.algorithm.tag_len()];
+    scatter::copy_out(in_out, ciphertext_len, received_tag);
+
+    let calculated_tag = match &key.inner {
+        KeyInner::AesGcm(inner) => aes_gcm::open_scatter(inner, nonce, aad, in_out, ciphertext_len),
+        KeyInner::ChaCha20Poly1305(inner) => {
+            chacha20_poly1305::open_scatter(inner, nonce, aad, in_out, ciphertext_len)
+        }
+        KeyInner::XChaCha20Poly1305(inner) => {
+            chacha20_poly1305::xopen_scatter(inner, nonce, aad, in_out, ciphertext_len)
+        }
+        #[cfg(feature = "alloc")]
+        _ => return open_scatter_gathered(key, nonce, aad, received_tag, in_out, ciphertext_len),
+        #[cfg(not(feature = "alloc"))]
+        _ => return Err(error::Unspecified),
+    }
+    .truncated(key.algorithm.tag_len());
+
+    if constant_time::verify_slices_are_equal(calculated_tag.as_ref(), received_tag).is_err() {
+        // See `open_within_`.
+        scatter::zero(in_out, ciphertext_len);
+        return Err(error::Unspecified);
+    }
+
+    Ok(ciphertext_len)
+}
+
+// For the algorithms that can't decrypt across segments: gathers the
+// ciphertext into a temporary buffer, opens it there, and scatters the
+// plaintext back.
+#[cfg(feature = "alloc")]
+fn open_scatter_gathered(
+    key: &LessSafeKey,
+    nonce: Nonce,
+    aad: Aad<AadSlices>,
+    received_tag: &[u8],
+    in_out: &mut [&mut [u8]],
+    ciphertext_len: usize,
+) -> Result<usize, error::Unspecified> {
+    let received_tag: Tag = received_tag.try_into()?;
+    let mut buf = alloc::vec![0; ciphertext_len];
+    scatter::copy_out(in_out, 0, &mut buf);
+    match open_within_(key, nonce, aad, received_tag, &mut buf, 0..) {
+        Ok(plaintext) => {
+            scatter::copy_in(in_out, plaintext);
+            Ok(ciphertext_len)
+        }
+        Err(error::Unspecified) => {
+            scatter::zero(in_out, ciphertext_len);
+            Err(error::Unspecified)
+        }
+    }
 }
 
 #[inline]
 pub(super) fn seal_in_place_separate_tag_(
     key: &LessSafeKey,
     nonce: Nonce,
-    aad: Aad<&[u8]>,
+    aad: Aad<AadSlices>,
     in_out: &mut [u8],
 ) -> Result<Tag, error::Unspecified> {
+    check_nonce_len(key.algorithm(), &nonce)?;
     check_per_nonce_max_bytes(key.algorithm(), in_out.len())?;
-    Ok((key.algorithm.seal)(&key.inner, nonce, aad, in_out))
+    let tag = (key.algorithm.seal)(&key.inner, nonce, aad, in_out);
+    Ok(tag.truncated(key.algorithm.tag_len()))
+}
+
+fn check_nonce_len(alg: &Algorithm, nonce: &Nonce) -> Result<(), error::Unspecified> {
+    if nonce.len() != alg.nonce_len() {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+fn check_tag_len(alg: &Algorithm, tag: &Tag) -> Result<(), error::Unspecified> {
+    if tag.as_ref().len() != alg.tag_len() {
+        return Err(error::Unspecified);
+    }
+    Ok(())
 }
 
 fn check_per_nonce_max_bytes(alg: &Algorithm, in_out_len: usize) -> Result<(), error::Unspecified> {
@@ -21,7 +21,10 @@ use core::convert::TryInto;
 ///
 /// `Nonce` intentionally doesn't implement `Clone` to ensure that each one is
 /// consumed at most once.
-pub struct Nonce([u8; NONCE_LEN]);
+pub struct Nonce {
+    value: [u8; MAX_NONCE_LEN],
+    len: u8,
+}
 
 impl Nonce {
     /// Constructs a `Nonce` with the given value, assuming that the value is
@@ -38,15 +41,73 @@ impl Nonce {
     /// unique for the lifetime of the key it is being used with.
     #[inline]
     pub fn assume_unique_for_key(value: [u8; NONCE_LEN]) -> Self {
-        Self(value)
+        let mut nonce = Self::empty();
+        nonce.value[..NONCE_LEN].copy_from_slice(&value);
+        nonce.len = NONCE_LEN as u8;
+        nonce
+    }
+
+    /// Constructs an empty `Nonce`, for algorithms like `AES_SIV_CMAC_256`
+    /// whose `nonce_len()` is zero.
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            value: [0; MAX_NONCE_LEN],
+            len: 0,
+        }
+    }
+
+    /// Like `try_assume_unique_for_key()`, except `value` may be of any
+    /// length up to `MAX_NONCE_LEN` bytes.
+    ///
+    /// This is for algorithms like `XCHACHA20_POLY1305` whose `nonce_len()`
+    /// isn't `NONCE_LEN`. Sealing or opening with the nonce will fail if its
+    /// length isn't the key's `algorithm().nonce_len()`.
+    ///
+    /// Fails if no algorithm in this module uses nonces of `value`'s length;
+    /// in particular, `value` must not be empty. Use `empty()` for the
+    /// algorithms whose `nonce_len()` is zero.
+    pub fn try_assume_unique_for_key_any_len(value: &[u8]) -> Result<Self, error::Unspecified> {
+        // The nonce lengths of 96-bit AEADs, the 104-bit AES-CCM variants,
+        // `AEGIS_128L`, `XCHACHA20_POLY1305`, and `AEGIS_256`.
+        if ![NONCE_LEN, 13, 16, 24, MAX_NONCE_LEN].contains(&value.len()) {
+            return Err(error::Unspecified);
+        }
+        let mut nonce = Self::empty();
+        nonce.value[..value.len()].copy_from_slice(value);
+        nonce.len = value.len() as u8;
+        Ok(nonce)
     }
-}
 
-impl AsRef<[u8; NONCE_LEN]> for Nonce {
-    fn as_ref(&self) -> &[u8; NONCE_LEN] {
-        &self.0
+    /// The length of the nonce in bytes.
+    #[inline]
+    pub(super) fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// The nonce's value, which is `NONCE_LEN` bytes long unless it was
+    /// constructed with `try_assume_unique_for_key_any_len()` or `empty()`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value[..self.len()]
+    }
+
+    /// The nonce as a 96-bit value.
+    ///
+    /// This unwraps, panicking if the nonce isn't `NONCE_LEN` bytes long. It
+    /// is only used by the implementations of algorithms whose `nonce_len()`
+    /// is `NONCE_LEN`, and every caller depends on the nonce's length having
+    /// already been checked by `check_nonce_len()` in `less_safe_key`, or on
+    /// the nonce having been constructed internally with
+    /// `assume_unique_for_key()`.
+    #[inline]
+    pub(super) fn as_96_bits(&self) -> &[u8; NONCE_LEN] {
+        self.as_bytes().try_into().unwrap()
     }
 }
 
-/// All the AEADs we support use 96-bit nonces.
+/// The length of the nonces of most of our AEADs: 96 bits.
 pub const NONCE_LEN: usize = 96 / 8;
+
+/// The maximum length of a nonce for the algorithms in this module.
+pub const MAX_NONCE_LEN: usize = 256 / 8;
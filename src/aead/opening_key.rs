@@ -21,9 +21,9 @@
 //! [AEAD]: http://www-cse.ucsd.edu/~mihir/papers/oem.html
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
-use super::{Aad, Algorithm, BoundKey, LessSafeKey, NonceSequence, UnboundKey};
+use super::{Aad, AadValue, Algorithm, BoundKey, LessSafeKey, NonceSequence, Tag, UnboundKey};
 use crate::error;
-use core::ops::RangeFrom;
+use core::{mem::MaybeUninit, ops::RangeFrom};
 
 /// An AEAD key for authenticating and decrypting ("opening"), bound to a nonce
 /// sequence.
@@ -56,6 +56,21 @@ impl<N: NonceSequence> core::fmt::Debug for OpeningKey<N> {
 }
 
 impl<N: NonceSequence> OpeningKey<N> {
+    /// Replaces the key with `key`, keeping the nonce sequence.
+    ///
+    /// This is for key updates, like those of TLS 1.3, where the nonce
+    /// sequence continues across keys; see also `KeyRatchet`.
+    ///
+    /// Fails, leaving the current key in place, if `key`'s algorithm isn't
+    /// the current key's algorithm.
+    pub fn rekey(&mut self, key: UnboundKey) -> Result<(), error::Unspecified> {
+        if key.algorithm() != self.algorithm() {
+            return Err(error::Unspecified);
+        }
+        self.key = key.into_inner();
+        Ok(())
+    }
+
     /// Authenticates and decrypts (“opens”) data in place.
     ///
     /// `aad` is the additional authenticated data (AAD), if any.
@@ -74,7 +89,7 @@ impl<N: NonceSequence> OpeningKey<N> {
         in_out: &'in_out mut [u8],
     ) -> Result<&'in_out mut [u8], error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
         self.key
             .open_in_place(self.nonce_sequence.advance()?, aad, in_out)
@@ -131,7 +146,7 @@ impl<N: NonceSequence> OpeningKey<N> {
         ciphertext_and_tag: RangeFrom<usize>,
     ) -> Result<&'in_out mut [u8], error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
         self.key.open_within(
             self.nonce_sequence.advance()?,
@@ -140,4 +155,122 @@ impl<N: NonceSequence> OpeningKey<N> {
             ciphertext_and_tag,
         )
     }
+
+    /// Like `open_within()`, except the authentication tag is passed
+    /// separately instead of being appended to the ciphertext.
+    ///
+    /// On input, `in_out[ciphertext]` must be the ciphertext alone; use `0..`
+    /// to open it in place. When `open_in_place_separate_tag()` returns
+    /// `Ok(plaintext)`, the plaintext will be at `in_out[0..plaintext.len()]`.
+    #[inline]
+    pub fn open_in_place_separate_tag<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        tag: Tag,
+        in_out: &'in_out mut [u8],
+        ciphertext: RangeFrom<usize>,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.key.open_in_place_separate_tag(
+            self.nonce_sequence.advance()?,
+            aad,
+            tag,
+            in_out,
+            ciphertext,
+        )
+    }
+
+    /// Authenticates and decrypts (“opens”) a message that is split across
+    /// several buffers, in place.
+    ///
+    /// `aad` is the additional authenticated data (AAD), if any.
+    ///
+    /// On input, the concatenation of `in_out` must be the ciphertext
+    /// followed by the tag; the segments may have any lengths, and the tag
+    /// may be split across segments. When `open_in_place_scatter()` returns
+    /// `Ok(plaintext_len)`, the ciphertext has been overwritten by the
+    /// plaintext, i.e. the first `plaintext_len` bytes of the concatenation
+    /// of `in_out` are the plaintext. When `open_in_place_scatter()` returns
+    /// `Err(..)`, those bytes have been zeroed.
+    ///
+    /// AES-GCM, ChaCha20-Poly1305, and XChaCha20-Poly1305 decrypt the
+    /// segments in place. The other algorithms copy the ciphertext into a
+    /// temporary buffer, open it there, and copy the plaintext back; without
+    /// the `alloc` feature they aren't supported and `open_in_place_scatter()`
+    /// fails without modifying `in_out`.
+    #[inline]
+    pub fn open_in_place_scatter<A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut [&mut [u8]],
+    ) -> Result<usize, error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.key
+            .open_in_place_scatter(self.nonce_sequence.advance()?, aad, in_out)
+    }
+
+    /// Authenticates and decrypts (“opens”) a message that is split across
+    /// several buffers into `out`, leaving the input unmodified.
+    ///
+    /// `aad` is the additional authenticated data (AAD), if any.
+    ///
+    /// The concatenation of `ciphertext_and_tag` must be the ciphertext
+    /// followed by the tag. Otherwise this is like `open_to()`.
+    #[inline]
+    pub fn open_gather<'out, A>(
+        &mut self,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[&[u8]],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.key
+            .open_gather(self.nonce_sequence.advance()?, aad, ciphertext_and_tag, out)
+    }
+
+    /// Authenticates and decrypts (“opens”) `ciphertext_and_tag` into `out`,
+    /// leaving `ciphertext_and_tag` unmodified.
+    ///
+    /// `aad` is the additional authenticated data (AAD), if any.
+    ///
+    /// `ciphertext_and_tag` must be the ciphertext followed by the tag. `out`
+    /// must be at least as long as the ciphertext. When `open_to()` returns
+    /// `Ok(plaintext)`, `plaintext` is the prefix of `out` that the plaintext
+    /// was written to; the rest of `out` is unmodified. When `open_to()`
+    /// returns `Err(..)`, that prefix of `out` has been zeroed.
+    #[inline]
+    pub fn open_to<'out, A>(
+        &mut self,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[u8],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.key
+            .open_to(self.nonce_sequence.advance()?, aad, ciphertext_and_tag, out)
+    }
+
+    /// Like [`open_to()`](Self::open_to), except `out` may be uninitialized;
+    /// this saves initializing a large `out` only to overwrite it.
+    #[inline]
+    pub fn open_to_uninit<'out, A>(
+        &mut self,
+        aad: Aad<A>,
+        ciphertext_and_tag: &[u8],
+        out: &'out mut [MaybeUninit<u8>],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.key
+            .open_to_uninit(self.nonce_sequence.advance()?, aad, ciphertext_and_tag, out)
+    }
 }
@@ -13,23 +13,45 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
+//! The Poly1305 one-time authenticator, as described in [RFC 8439].
+//!
+//! Each key must be used to authenticate only a single message; after that
+//! an attacker can forge tags for other messages. `Key` is consumed by
+//! `sign()`, `verify()`, and `Context::from_key()` to help ensure that. Keys
+//! are usually derived from a stream cipher and a nonce, as in
+//! `CHACHA20_POLY1305`; prefer an AEAD or `hmac` unless a protocol requires
+//! Poly1305 specifically.
+//!
+//! [RFC 8439]: https://tools.ietf.org/html/rfc8439#section-2.5
+
 // TODO: enforce maximum input length.
 
-use super::{Tag, TAG_LEN};
-use crate::{c, cpu};
+use super::Tag;
+use crate::{c, constant_time, cpu, error};
 
-/// A Poly1305 key.
-pub(super) struct Key {
+/// A Poly1305 one-time key: the 128-bit `r` followed by the 128-bit `s`.
+pub struct Key {
     key_and_nonce: [u8; KEY_LEN],
     cpu_features: cpu::Features,
 }
 
 pub(super) const BLOCK_LEN: usize = 16;
-pub(super) const KEY_LEN: usize = 2 * BLOCK_LEN;
+
+/// The length of a key.
+pub const KEY_LEN: usize = 2 * BLOCK_LEN;
+
+/// The length of a tag.
+pub const TAG_LEN: usize = super::TAG_LEN;
 
 impl Key {
+    /// Constructs a key from its bytes.
+    #[inline]
+    pub fn new(key_and_nonce: [u8; KEY_LEN]) -> Self {
+        Self::new_(key_and_nonce, cpu::features())
+    }
+
     #[inline]
-    pub(super) fn new(key_and_nonce: [u8; KEY_LEN], cpu_features: cpu::Features) -> Self {
+    pub(super) fn new_(key_and_nonce: [u8; KEY_LEN], cpu_features: cpu::Features) -> Self {
         Self {
             key_and_nonce,
             cpu_features,
@@ -37,6 +59,7 @@ impl Key {
     }
 }
 
+/// A context for calculating the tag of a message that is given in pieces.
 pub struct Context {
     state: poly1305_state,
     #[allow(dead_code)]
@@ -78,8 +101,9 @@ macro_rules! dispatch {
 }
 
 impl Context {
+    /// Constructs a context that uses the key `key`.
     #[inline]
-    pub(super) fn from_key(
+    pub fn from_key(
         Key {
             key_and_nonce,
             cpu_features,
@@ -99,6 +123,7 @@ impl Context {
         ctx
     }
 
+    /// Updates the context with the next piece of the message.
     #[inline(always)]
     pub fn update(&mut self, input: &[u8]) {
         dispatch!(
@@ -108,27 +133,34 @@ impl Context {
             (&mut self.state, input.as_ptr(), input.len()));
     }
 
-    pub(super) fn finish(mut self) -> Tag {
-        let mut tag = Tag([0u8; TAG_LEN]);
+    /// Returns the tag of the message.
+    pub fn finish(mut self) -> Tag {
+        let mut tag = [0u8; TAG_LEN];
         dispatch!(
             self.cpu_features =>
             (CRYPTO_poly1305_finish | CRYPTO_poly1305_finish_neon)
             (statep: &mut poly1305_state, mac: &mut [u8; TAG_LEN])
-            (&mut self.state, &mut tag.0));
-        tag
+            (&mut self.state, &mut tag));
+        Tag::from(tag)
     }
 }
 
-/// Implements the original, non-IETF padding semantics.
+/// Calculates the tag of `input`.
 ///
-/// This is used by chacha20_poly1305_openssh and the standalone
+/// This implements the original, non-IETF padding semantics; i.e. `input` is
+/// not padded. This is used by chacha20_poly1305_openssh and the standalone
 /// poly1305 test vectors.
-pub(super) fn sign(key: Key, input: &[u8]) -> Tag {
+pub fn sign(key: Key, input: &[u8]) -> Tag {
     let mut ctx = Context::from_key(key);
     ctx.update(input);
     ctx.finish()
 }
 
+/// Verifies that `tag` is the tag of `input`.
+pub fn verify(key: Key, input: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(sign(key, input).as_ref(), tag)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,8 +177,8 @@ mod tests {
             let key: &[u8; KEY_LEN] = key.as_slice().try_into().unwrap();
             let input = test_case.consume_bytes("Input");
             let expected_mac = test_case.consume_bytes("MAC");
-            let key = Key::new(*key, cpu_features);
-            let Tag(actual_mac) = sign(key, &input);
+            let key = Key::new_(*key, cpu_features);
+            let actual_mac = sign(key, &input);
             assert_eq!(expected_mac, actual_mac.as_ref());
 
             Ok(())
@@ -0,0 +1,123 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! POLYVAL, as described in [RFC 8452].
+//!
+//! POLYVAL is implemented in terms of GHASH as described in [RFC 8452
+//! Appendix A], so that it benefits from the accelerated implementations of
+//! GHASH.
+//!
+//! [RFC 8452]: https://tools.ietf.org/html/rfc8452#section-3
+//! [RFC 8452 Appendix A]: https://tools.ietf.org/html/rfc8452#appendix-A
+
+use super::{
+    block::{Block, BLOCK_LEN},
+    gcm, Aad,
+};
+use crate::cpu;
+
+#[derive(Clone)]
+pub(super) struct Key(gcm::Key);
+
+impl Key {
+    pub(super) fn new(h: [u8; BLOCK_LEN], cpu_features: cpu::Features) -> Self {
+        let h = mulx_ghash(byte_reverse(h));
+        Self(gcm::Key::new(Block::from(&h), cpu_features))
+    }
+}
+
+pub(super) struct Context(gcm::Context);
+
+impl Context {
+    pub(super) fn new(key: &Key) -> Self {
+        Self(gcm::Context::new(&key.0, Aad::from_slices(&[])))
+    }
+
+    /// Updates the hash with `input`, padded with zeros to a multiple of
+    /// `BLOCK_LEN` bytes.
+    pub(super) fn update_padded(&mut self, input: &[u8]) {
+        const CHUNK_BLOCKS: usize = 16;
+
+        // GHASH's input is the byte-reversed POLYVAL input, so the input is
+        // byte-reversed into a temporary buffer one chunk at a time.
+        let mut reversed = [0u8; CHUNK_BLOCKS * BLOCK_LEN];
+        for chunk in input.chunks(CHUNK_BLOCKS * BLOCK_LEN) {
+            let mut reversed_len = 0;
+            for block in chunk.chunks(BLOCK_LEN) {
+                let mut padded = [0u8; BLOCK_LEN];
+                padded[..block.len()].copy_from_slice(block);
+                reversed[reversed_len..][..BLOCK_LEN].copy_from_slice(&byte_reverse(padded));
+                reversed_len += BLOCK_LEN;
+            }
+            self.0.update_blocks(&reversed[..reversed_len]);
+        }
+    }
+
+    pub(super) fn update_block(&mut self, block: [u8; BLOCK_LEN]) {
+        self.0.update_block(Block::from(&byte_reverse(block)));
+    }
+
+    pub(super) fn finish(self) -> [u8; BLOCK_LEN] {
+        self.0.pre_finish(|xi| byte_reverse(*xi.as_ref()))
+    }
+}
+
+#[inline]
+fn byte_reverse(mut block: [u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+    block.reverse();
+    block
+}
+
+// Multiplies `h` by x in GHASH's field representation. `h` is secret so this
+// is done without branching on its value.
+fn mulx_ghash(h: [u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+    let h = u128::from_be_bytes(h);
+    let reduce = 0u128.wrapping_sub(h & 1) & (0xe1 << 120);
+    ((h >> 1) ^ reduce).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use core::convert::TryInto;
+
+    // https://tools.ietf.org/html/rfc8452#appendix-A
+    #[test]
+    fn polyval_test() {
+        let h = test::from_hex("25629347589242761d31f826ba4b757b").unwrap();
+        let x_1 = test::from_hex("4f4f95668c83dfb6401762bb2d01a262").unwrap();
+        let x_2 = test::from_hex("d1a24ddd2721d006bbe45f20d3c9f362").unwrap();
+        let expected = test::from_hex("f7a3b47b846119fae5b7866cf5e5b77e").unwrap();
+
+        let key = Key::new(h.as_slice().try_into().unwrap(), cpu::features());
+
+        let mut ctx = Context::new(&key);
+        ctx.update_block(x_1.as_slice().try_into().unwrap());
+        ctx.update_block(x_2.as_slice().try_into().unwrap());
+        assert_eq!(ctx.finish().as_ref(), expected.as_slice());
+
+        let mut ctx = Context::new(&key);
+        ctx.update_padded(&[x_1, x_2].concat());
+        assert_eq!(ctx.finish().as_ref(), expected.as_slice());
+    }
+
+    #[test]
+    fn mulx_ghash_test() {
+        let h = test::from_hex("25629347589242761d31f826ba4b757b").unwrap();
+        let expected = test::from_hex("dcbaa5dd137c188ebb21492c23c9b112").unwrap();
+        let actual = mulx_ghash(byte_reverse(h.as_slice().try_into().unwrap()));
+        assert_eq!(actual.as_ref(), expected.as_slice());
+    }
+}
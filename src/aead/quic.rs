@@ -12,13 +12,17 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-//! QUIC Header Protection.
+//! QUIC Header Protection and Retry Packet Integrity.
 //!
-//! See draft-ietf-quic-tls.
+//! See [RFC 9001] for QUIC version 1 and [RFC 9369] for QUIC version 2, which
+//! uses the same header protection algorithms.
+//!
+//! [RFC 9001]: https://www.rfc-editor.org/rfc/rfc9001
+//! [RFC 9369]: https://www.rfc-editor.org/rfc/rfc9369
 
 use crate::{
-    aead::{aes, chacha},
-    cpu, error, hkdf,
+    aead::{self, aes, chacha},
+    constant_time, cpu, error, hkdf,
 };
 use core::convert::{TryFrom, TryInto};
 
@@ -187,3 +191,64 @@ fn chacha20_new_mask(key: &KeyInner, sample: Sample) -> [u8; 5] {
 
     chacha20_key.new_mask(sample)
 }
+
+/// A QUIC version, for the version-specific constants of Retry packet
+/// integrity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Version {
+    /// QUIC version 1 ([RFC 9001 Section 5.8]).
+    ///
+    /// [RFC 9001 Section 5.8]: https://www.rfc-editor.org/rfc/rfc9001#section-5.8
+    V1,
+
+    /// QUIC version 2 ([RFC 9369 Section 3.3.3]).
+    ///
+    /// [RFC 9369 Section 3.3.3]: https://www.rfc-editor.org/rfc/rfc9369#section-3.3.3
+    V2,
+}
+
+/// Calculates the Retry Integrity Tag of a Retry packet.
+///
+/// `pseudo_packet` is the Retry Pseudo-Packet: the length of the original
+/// Destination Connection ID as a single byte, the original Destination
+/// Connection ID, and then the Retry packet without its Retry Integrity Tag.
+pub fn retry_integrity_tag(version: Version, pseudo_packet: &[u8]) -> aead::Tag {
+    let (key_bytes, nonce) = match version {
+        Version::V1 => (RETRY_KEY_V1, RETRY_NONCE_V1),
+        Version::V2 => (RETRY_KEY_V2, RETRY_NONCE_V2),
+    };
+    // The key is valid and the input is far shorter than AES-GCM's limits.
+    let key =
+        aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_128_GCM, &key_bytes).unwrap());
+    key.seal_in_place_separate_tag(
+        aead::Nonce::assume_unique_for_key(nonce),
+        aead::Aad::from(pseudo_packet),
+        &mut [],
+    )
+    .unwrap()
+}
+
+/// Verifies that `tag` is the Retry Integrity Tag of a Retry packet, in
+/// constant time; see `retry_integrity_tag()`.
+pub fn verify_retry_integrity_tag(
+    version: Version,
+    pseudo_packet: &[u8],
+    tag: &[u8],
+) -> Result<(), error::Unspecified> {
+    let calculated = retry_integrity_tag(version, pseudo_packet);
+    constant_time::verify_slices_are_equal(calculated.as_ref(), tag)
+}
+
+const RETRY_KEY_V1: [u8; 16] = [
+    0xbe, 0x0c, 0x69, 0x0b, 0x9f, 0x66, 0x57, 0x5a, 0x1d, 0x76, 0x6b, 0x54, 0xe3, 0x68, 0xc8, 0x4e,
+];
+const RETRY_NONCE_V1: [u8; aead::NONCE_LEN] = [
+    0x46, 0x15, 0x99, 0xd3, 0x5d, 0x63, 0x2b, 0xf2, 0x23, 0x98, 0x25, 0xbb,
+];
+const RETRY_KEY_V2: [u8; 16] = [
+    0x8f, 0xb4, 0xb0, 0x1b, 0x56, 0xac, 0x48, 0xe2, 0x60, 0xfb, 0xcb, 0xce, 0xad, 0x7c, 0xcc, 0x92,
+];
+const RETRY_NONCE_V2: [u8; aead::NONCE_LEN] = [
+    0xd8, 0x69, 0x69, 0xbc, 0x2d, 0x7c, 0x6d, 0x99, 0x90, 0xef, 0xb0, 0x4a,
+];
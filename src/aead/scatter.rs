@@ -0,0 +1,177 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Helpers for working with a message that is split across several
+//! non-contiguous buffers ("segments").
+
+use core::cmp::min;
+
+/// The total length of `segments`.
+pub(super) fn total_len(segments: &[&mut [u8]]) -> usize {
+    segments.iter().map(|segment| segment.len()).sum()
+}
+
+/// Copies `out.len()` bytes, starting at offset `start` of the concatenation
+/// of `segments`, into `out`.
+pub(super) fn copy_out(segments: &[&mut [u8]], mut start: usize, out: &mut [u8]) {
+    let mut out = out;
+    for segment in segments {
+        if out.is_empty() {
+            break;
+        }
+        if start >= segment.len() {
+            start -= segment.len();
+            continue;
+        }
+        let src = &segment[start..];
+        let n = min(src.len(), out.len());
+        out[..n].copy_from_slice(&src[..n]);
+        out = &mut out[n..];
+        start = 0;
+    }
+    assert!(out.is_empty());
+}
+
+/// Copies `src` into the first `src.len()` bytes of the concatenation of
+/// `segments`.
+#[cfg(feature = "alloc")]
+pub(super) fn copy_in(segments: &mut [&mut [u8]], src: &[u8]) {
+    let mut src = src;
+    for segment in segments.iter_mut() {
+        let n = min(segment.len(), src.len());
+        segment[..n].copy_from_slice(&src[..n]);
+        src = &src[n..];
+    }
+    assert!(src.is_empty());
+}
+
+/// Zeros the first `len` bytes of the concatenation of `segments`.
+pub(super) fn zero(segments: &mut [&mut [u8]], mut len: usize) {
+    for segment in segments.iter_mut() {
+        let n = min(segment.len(), len);
+        segment[..n].iter_mut().for_each(|b| *b = 0);
+        len -= n;
+    }
+}
+
+/// Calls `f` with consecutive pieces of the first `len` bytes of the
+/// concatenation of `segments`, in order.
+///
+/// Every piece except the last is a multiple of `buf.len()` bytes long.
+/// Pieces that lie within one segment are passed to `f` directly; a piece
+/// that straddles segments is copied into `buf` before `f` is called and
+/// copied back afterwards.
+pub(super) fn for_each_chunk(
+    segments: &mut [&mut [u8]],
+    mut len: usize,
+    buf: &mut [u8],
+    mut f: impl FnMut(&mut [u8]),
+) {
+    let unit = buf.len();
+    let (mut i, mut offset) = (0, 0);
+    while len > 0 {
+        let available = segments[i].len() - offset;
+        if available == 0 {
+            i += 1;
+            offset = 0;
+            continue;
+        }
+
+        let direct = min(available, len);
+        let whole = direct - (direct % unit);
+        if whole > 0 || direct == len {
+            let chunk_len = if whole > 0 { whole } else { len };
+            f(&mut segments[i][offset..][..chunk_len]);
+            offset += chunk_len;
+            len -= chunk_len;
+            continue;
+        }
+
+        let piece = &mut buf[..min(unit, len)];
+        let (start_i, start_offset) = (i, offset);
+        let mut filled = 0;
+        while filled < piece.len() {
+            let src = &segments[i][offset..];
+            let n = min(src.len(), piece.len() - filled);
+            piece[filled..][..n].copy_from_slice(&src[..n]);
+            filled += n;
+            offset += n;
+            if offset == segments[i].len() {
+                i += 1;
+                offset = 0;
+            }
+        }
+
+        f(piece);
+
+        let (mut j, mut offset_j) = (start_i, start_offset);
+        let mut written = 0;
+        while written < piece.len() {
+            let dst = &mut segments[j][offset_j..];
+            let n = min(dst.len(), piece.len() - written);
+            dst[..n].copy_from_slice(&piece[written..][..n]);
+            written += n;
+            offset_j += n;
+            if offset_j == segments[j].len() {
+                j += 1;
+                offset_j = 0;
+            }
+        }
+        len -= piece.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_chunk() {
+        const LEN: usize = 40;
+        for split_a in 0..=LEN {
+            for split_b in split_a..=LEN {
+                let mut bytes = [0u8; LEN];
+                bytes.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+                let (a, rest) = bytes.split_at_mut(split_a);
+                let (b, c) = rest.split_at_mut(split_b - split_a);
+                let mut segments = [a, b, c];
+
+                // Increment each byte by its position, checking the piece
+                // lengths along the way.
+                let mut position = 0;
+                let mut buf = [0u8; 16];
+                for_each_chunk(&mut segments, LEN - 3, &mut buf, |chunk| {
+                    assert!(chunk.len() % 16 == 0 || position + chunk.len() == LEN - 3);
+                    for b in chunk.iter_mut() {
+                        assert_eq!(*b as usize, position);
+                        *b = b.wrapping_add(position as u8);
+                        position += 1;
+                    }
+                });
+                assert_eq!(position, LEN - 3);
+
+                let mut out = [0u8; LEN];
+                copy_out(&segments, 0, &mut out);
+                for (i, b) in out.iter().enumerate() {
+                    let expected = if i < LEN - 3 { 2 * i } else { i };
+                    assert_eq!(*b as usize, expected);
+                }
+
+                zero(&mut segments, 5);
+                copy_out(&segments, 3, &mut out[..4]);
+                assert_eq!(&out[..4], &[0, 0, 10, 12]);
+            }
+        }
+    }
+}
@@ -21,8 +21,12 @@
 //! [AEAD]: http://www-cse.ucsd.edu/~mihir/papers/oem.html
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
-use super::{Aad, Algorithm, BoundKey, LessSafeKey, NonceSequence, Tag, UnboundKey};
+use super::{
+    Aad, AadValue, Algorithm, BoundKey, LessSafeKey, Nonce, NonceSequence, Tag, UnboundKey,
+    UsageLimits, UsageTracker,
+};
 use crate::error;
+use core::mem::MaybeUninit;
 
 /// An AEAD key for encrypting and signing ("sealing"), bound to a nonce
 /// sequence.
@@ -32,6 +36,7 @@ use crate::error;
 pub struct SealingKey<N: NonceSequence> {
     key: LessSafeKey,
     nonce_sequence: N,
+    usage: Option<UsageTracker>,
 }
 
 impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
@@ -39,6 +44,7 @@ impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
         Self {
             key: key.into_inner(),
             nonce_sequence,
+            usage: None,
         }
     }
 
@@ -55,6 +61,44 @@ impl<N: NonceSequence> core::fmt::Debug for SealingKey<N> {
 }
 
 impl<N: NonceSequence> SealingKey<N> {
+    /// Replaces the key with `key`, keeping the nonce sequence.
+    ///
+    /// This is for key updates, like those of TLS 1.3, where the nonce
+    /// sequence continues across keys; see also `KeyRatchet`. If usage limits
+    /// were set then they apply to the new key, with its usage counted from
+    /// zero.
+    ///
+    /// Fails, leaving the current key in place, if `key`'s algorithm isn't
+    /// the current key's algorithm.
+    pub fn rekey(&mut self, key: UnboundKey) -> Result<(), error::Unspecified> {
+        if key.algorithm() != self.algorithm() {
+            return Err(error::Unspecified);
+        }
+        self.key = key.into_inner();
+        if let Some(usage) = &mut self.usage {
+            *usage = UsageTracker::new(usage.limits());
+        }
+        Ok(())
+    }
+
+    /// Limits how much data may be sealed with this key.
+    ///
+    /// Afterwards, every sealing operation fails, without advancing the nonce
+    /// sequence, if it would exceed the limits. Usage is counted from when
+    /// this is called.
+    pub fn set_usage_limits(&mut self, limits: UsageLimits) {
+        self.usage = Some(UsageTracker::new(limits));
+    }
+
+    /// The usage of the key since `set_usage_limits()` was called, if it was.
+    ///
+    /// Use `UsageTracker::is_exhausted()` to tell when the key must be
+    /// replaced.
+    #[inline]
+    pub fn usage(&self) -> Option<&UsageTracker> {
+        self.usage.as_ref()
+    }
+
     /// Encrypts and signs (“seals”) data in place, appending the tag to the
     /// resulting ciphertext.
     ///
@@ -71,11 +115,13 @@ impl<N: NonceSequence> SealingKey<N> {
         in_out: &mut InOut,
     ) -> Result<(), error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
-        self.key
-            .seal_in_place_append_tag(self.nonce_sequence.advance()?, aad, in_out)
+        let plaintext_len = in_out.as_mut().len();
+        self.seal_with(plaintext_len, |key, nonce| {
+            key.seal_in_place_append_tag(nonce, aad, in_out)
+        })
     }
 
     /// Encrypts and signs (“seals”) data in place.
@@ -96,9 +142,77 @@ impl<N: NonceSequence> SealingKey<N> {
         in_out: &mut [u8],
     ) -> Result<Tag, error::Unspecified>
     where
-        A: AsRef<[u8]>,
+        A: AadValue,
     {
-        self.key
-            .seal_in_place_separate_tag(self.nonce_sequence.advance()?, aad, in_out)
+        self.seal_with(in_out.len(), |key, nonce| {
+            key.seal_in_place_separate_tag(nonce, aad, in_out)
+        })
+    }
+
+    /// Encrypts and signs (“seals”) `plaintext` into `out`, leaving
+    /// `plaintext` unmodified.
+    ///
+    /// `aad` is the additional authenticated data (AAD), if any.
+    ///
+    /// `out` must be at least `plaintext.len() + self.algorithm().tag_len()`
+    /// bytes long. The ciphertext followed by the tag is written to the start
+    /// of `out` and that prefix of `out` is returned; the rest of `out` is
+    /// unmodified.
+    #[inline]
+    pub fn seal_to<'out, A>(
+        &mut self,
+        aad: Aad<A>,
+        plaintext: &[u8],
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.seal_with(plaintext.len(), move |key, nonce| {
+            key.seal_to(nonce, aad, plaintext, out)
+        })
+    }
+
+    /// Like [`seal_to()`](Self::seal_to), except `out` may be uninitialized;
+    /// this saves initializing a large `out` only to overwrite it.
+    #[inline]
+    pub fn seal_to_uninit<'out, A>(
+        &mut self,
+        aad: Aad<A>,
+        plaintext: &[u8],
+        out: &'out mut [MaybeUninit<u8>],
+    ) -> Result<&'out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.seal_with(plaintext.len(), move |key, nonce| {
+            key.seal_to_uninit(nonce, aad, plaintext, out)
+        })
+    }
+
+    // Seals a `plaintext_len`-byte message with `seal` and the next nonce.
+    // The message is checked against the usage limits before the nonce
+    // sequence is advanced, but it is only recorded once it has been sealed,
+    // so that a failure to advance the nonce sequence, or a message that is
+    // too long for the algorithm or the output buffer, isn't counted.
+    fn seal_with<R>(
+        &mut self,
+        plaintext_len: usize,
+        seal: impl FnOnce(&LessSafeKey, Nonce) -> Result<R, error::Unspecified>,
+    ) -> Result<R, error::Unspecified> {
+        let usage = match &self.usage {
+            Some(usage) => {
+                let mut usage = usage.clone();
+                usage.record(plaintext_len)?;
+                Some(usage)
+            }
+            None => None,
+        };
+        let nonce = self.nonce_sequence.advance()?;
+        let sealed = seal(&self.key, nonce)?;
+        if usage.is_some() {
+            self.usage = usage;
+        }
+        Ok(sealed)
     }
 }
@@ -0,0 +1,170 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-SIV with multiple headers.
+//!
+//! [RFC 5297] allows AES-SIV to authenticate a vector of separate headers
+//! instead of a single AAD. That is different from authenticating the
+//! concatenation of the headers. `aead::AES_SIV_CMAC_256` and
+//! `aead::AES_SIV_CMAC_512` with `aead::LessSafeKey` are equivalent to using
+//! this module with a single header, the AAD.
+//!
+//! To use AES-SIV with a nonce, as in [RFC 5297 Section 3], pass the nonce as
+//! the last header.
+//!
+//! [RFC 5297]: https://tools.ietf.org/html/rfc5297
+//! [RFC 5297 Section 3]: https://tools.ietf.org/html/rfc5297#section-3
+
+use super::{aes_siv, AadSlices, Algorithm, AlgorithmID, KeyInner, Tag};
+use crate::{constant_time, cpu, error, polyfill};
+use core::{convert::TryInto, ops::RangeFrom};
+
+/// An AES-SIV key for sealing and opening with multiple headers.
+#[derive(Clone)]
+pub struct Key {
+    inner: aes_siv::Key,
+    algorithm: &'static Algorithm,
+}
+
+impl Key {
+    /// Constructs a new key.
+    ///
+    /// `algorithm` must be `aead::AES_SIV_CMAC_256` or
+    /// `aead::AES_SIV_CMAC_512` and `key_bytes` must be exactly
+    /// `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        match algorithm.id {
+            AlgorithmID::AES_SIV_CMAC_256 | AlgorithmID::AES_SIV_CMAC_512 => {}
+            _ => return Err(error::Unspecified),
+        }
+        let inner = match (algorithm.init)(key_bytes, cpu::features())? {
+            KeyInner::AesSiv(key) => key,
+            _ => unreachable!(),
+        };
+        Ok(Self { inner, algorithm })
+    }
+
+    /// Encrypts and signs (“seals”) `in_out` in place, authenticating each of
+    /// `headers`, and returns the tag (the synthetic IV).
+    ///
+    /// Fails if there are more than 126 headers.
+    pub fn seal_in_place_separate_tag(
+        &self,
+        headers: &[&[u8]],
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified> {
+        check_headers_and_input_len(self.algorithm, headers, in_out.len())?;
+        Ok(self.inner.seal(header_slices(headers), in_out))
+    }
+
+    /// Like [`Self::seal_in_place_separate_tag()`], except the tag is appended
+    /// to `in_out`.
+    pub fn seal_in_place_append_tag<InOut>(
+        &self,
+        headers: &[&[u8]],
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.seal_in_place_separate_tag(headers, in_out.as_mut())
+            .map(|tag| in_out.extend(tag.as_ref()))
+    }
+
+    /// Authenticates and decrypts (“opens”) `in_out[ciphertext]` into
+    /// `in_out[..]`, like [`aead::LessSafeKey::open_in_place_separate_tag()`],
+    /// with `headers` instead of the AAD.
+    ///
+    /// [`aead::LessSafeKey::open_in_place_separate_tag()`]:
+    ///     super::LessSafeKey::open_in_place_separate_tag
+    pub fn open_in_place_separate_tag<'in_out>(
+        &self,
+        headers: &[&[u8]],
+        tag: Tag,
+        in_out: &'in_out mut [u8],
+        ciphertext: RangeFrom<usize>,
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        let ciphertext_len = in_out
+            .get(ciphertext.clone())
+            .ok_or(error::Unspecified)?
+            .len();
+        check_headers_and_input_len(self.algorithm, headers, ciphertext_len)?;
+        if tag.as_ref().len() != self.algorithm.tag_len() {
+            return Err(error::Unspecified);
+        }
+
+        let calculated_tag = self
+            .inner
+            .open(header_slices(headers), &tag, in_out, ciphertext);
+        if constant_time::verify_slices_are_equal(calculated_tag.as_ref(), tag.as_ref()).is_err() {
+            // Zero out the plaintext so that it isn't accidentally leaked or
+            // used after verification fails.
+            in_out[..ciphertext_len].fill(0);
+            return Err(error::Unspecified);
+        }
+        Ok(&mut in_out[..ciphertext_len])
+    }
+
+    /// Like [`Self::open_in_place_separate_tag()`], except the tag is expected
+    /// at the end of `in_out` and the ciphertext is opened in place.
+    pub fn open_in_place<'in_out>(
+        &self,
+        headers: &[&[u8]],
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        let tag_offset = in_out
+            .len()
+            .checked_sub(super::TAG_LEN)
+            .ok_or(error::Unspecified)?;
+        let (in_out, received_tag) = in_out.split_at_mut(tag_offset);
+        let received_tag = (*received_tag).try_into()?;
+        self.open_in_place_separate_tag(headers, received_tag, in_out, 0..)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+fn header_slices<'a>(headers: &'a [&'a [u8]]) -> impl Iterator<Item = AadSlices<'a>> {
+    headers
+        .iter()
+        .map(|header| AadSlices(core::slice::from_ref(header)))
+}
+
+fn check_headers_and_input_len(
+    algorithm: &Algorithm,
+    headers: &[&[u8]],
+    in_out_len: usize,
+) -> Result<(), error::Unspecified> {
+    if headers.len() > aes_siv::MAX_HEADERS
+        || polyfill::u64_from_usize(in_out_len) > algorithm.max_input_len
+    {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
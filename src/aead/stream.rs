@@ -0,0 +1,296 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Online authenticated encryption of long messages using the STREAM
+//! construction.
+//!
+//! A message is split into segments, each of which is sealed separately. The
+//! nonce of each segment is a per-message nonce prefix, followed by the
+//! big-endian 32-bit index of the segment, followed by a byte that is 1 for
+//! the last segment and 0 for every other segment, as described in
+//! [Online Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance].
+//! Consequently the segments of a message cannot be reordered, dropped,
+//! duplicated, or truncated without opening failing.
+//!
+//! The nonce prefix must be unique for each message sealed with a key; it
+//! is `nonce_prefix_len(algorithm)` bytes long. For the algorithms with
+//! 96-bit nonces it is only 56 bits, which is too short to be generated
+//! randomly when many messages are sealed with the same key; use an algorithm
+//! with longer nonces, such as `XCHACHA20_POLY1305`, or derive a new key for
+//! each message, in that case.
+//!
+//! [Online Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance]:
+//!     https://eprint.iacr.org/2015/189
+
+use super::{Aad, AadValue, Algorithm, LessSafeKey, Nonce, Tag, UnboundKey, MAX_NONCE_LEN};
+use crate::error;
+use core::ops::RangeFrom;
+
+/// The length of the nonce prefix for the given algorithm.
+///
+/// Returns `None` if the algorithm's nonces are too short to hold the
+/// segment index and the last-segment flag, e.g. for `AES_SIV_CMAC_256` and
+/// `AES_SIV_CMAC_512`, which don't take a nonce. Such algorithms can't be used
+/// with STREAM.
+#[inline]
+pub fn nonce_prefix_len(algorithm: &'static Algorithm) -> Option<usize> {
+    algorithm
+        .nonce_len()
+        .checked_sub(COUNTER_LEN + LAST_FLAG_LEN)
+}
+
+const COUNTER_LEN: usize = 4;
+const LAST_FLAG_LEN: usize = 1;
+
+/// Seals the segments of a message, in order.
+///
+/// Intentionally not `Clone` or `Copy` since cloning would allow the
+/// segment nonces to be reused.
+pub struct Encryptor {
+    segments: Segments,
+}
+
+impl Encryptor {
+    /// Constructs an `Encryptor` for a message with the given nonce prefix.
+    ///
+    /// Fails if `nonce_prefix` isn't `nonce_prefix_len(key.algorithm())`
+    /// bytes long, or if that is `None`.
+    pub fn new(key: UnboundKey, nonce_prefix: &[u8]) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            segments: Segments::new(key, nonce_prefix)?,
+        })
+    }
+
+    /// Seals the next segment, which isn't the last segment, in place,
+    /// appending the tag.
+    ///
+    /// Fails if the maximum number of segments, 2^32, would be exceeded.
+    #[inline]
+    pub fn seal_next_in_place_append_tag<A, InOut>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AadValue,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.seal_next_in_place_separate_tag(aad, in_out.as_mut())
+            .map(|tag| in_out.extend(tag.as_ref()))
+    }
+
+    /// Like `seal_next_in_place_append_tag()`, except the tag is returned
+    /// separately.
+    #[inline]
+    pub fn seal_next_in_place_separate_tag<A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let (key, nonce) = self.segments.next(false)?;
+        let tag = key.seal_in_place_separate_tag(nonce, aad, in_out)?;
+        self.segments.advance();
+        Ok(tag)
+    }
+
+    /// Seals the last segment in place, appending the tag.
+    #[inline]
+    pub fn seal_last_in_place_append_tag<A, InOut>(
+        self,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AadValue,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.seal_last_in_place_separate_tag(aad, in_out.as_mut())
+            .map(|tag| in_out.extend(tag.as_ref()))
+    }
+
+    /// Like `seal_last_in_place_append_tag()`, except the tag is returned
+    /// separately.
+    #[inline]
+    pub fn seal_last_in_place_separate_tag<A>(
+        self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let (key, nonce) = self.segments.next(true)?;
+        key.seal_in_place_separate_tag(nonce, aad, in_out)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.segments.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for Encryptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.segments.key.fmt_debug("Encryptor", f)
+    }
+}
+
+/// Opens the segments of a message, in order.
+///
+/// A message has been received completely, and without truncation, only when
+/// `open_last_in_place()` or `open_last_within()` succeeds.
+pub struct Decryptor {
+    segments: Segments,
+}
+
+impl Decryptor {
+    /// Constructs a `Decryptor` for a message with the given nonce prefix.
+    ///
+    /// Fails if `nonce_prefix` isn't `nonce_prefix_len(key.algorithm())`
+    /// bytes long, or if that is `None`.
+    pub fn new(key: UnboundKey, nonce_prefix: &[u8]) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            segments: Segments::new(key, nonce_prefix)?,
+        })
+    }
+
+    /// Opens the next segment, which isn't the last segment, in place.
+    ///
+    /// The segment index only advances when opening succeeds, so a segment
+    /// that fails to open may be retried (e.g. if it was corrupted in
+    /// transit and is retransmitted).
+    ///
+    /// See `OpeningKey::open_in_place()` for the meaning of `in_out`.
+    #[inline]
+    pub fn open_next_in_place<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.open_next_within(aad, in_out, 0..)
+    }
+
+    /// Like `open_next_in_place()`, except the ciphertext and tag are at
+    /// `in_out[ciphertext_and_tag]`; see `OpeningKey::open_within()`.
+    #[inline]
+    pub fn open_next_within<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        ciphertext_and_tag: RangeFrom<usize>,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let (key, nonce) = self.segments.next(false)?;
+        let plaintext = key.open_within(nonce, aad, in_out, ciphertext_and_tag)?;
+        self.segments.advance();
+        Ok(plaintext)
+    }
+
+    /// Opens the last segment in place.
+    ///
+    /// See `OpeningKey::open_in_place()` for the meaning of `in_out`.
+    #[inline]
+    pub fn open_last_in_place<A>(
+        self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<&mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        self.open_last_within(aad, in_out, 0..)
+    }
+
+    /// Like `open_last_in_place()`, except the ciphertext and tag are at
+    /// `in_out[ciphertext_and_tag]`; see `OpeningKey::open_within()`.
+    #[inline]
+    pub fn open_last_within<A>(
+        self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+        ciphertext_and_tag: RangeFrom<usize>,
+    ) -> Result<&mut [u8], error::Unspecified>
+    where
+        A: AadValue,
+    {
+        let (key, nonce) = self.segments.next(true)?;
+        key.open_within(nonce, aad, in_out, ciphertext_and_tag)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.segments.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for Decryptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.segments.key.fmt_debug("Decryptor", f)
+    }
+}
+
+struct Segments {
+    key: LessSafeKey,
+    nonce_prefix: [u8; MAX_NONCE_LEN],
+    nonce_prefix_len: usize,
+    counter: u32,
+}
+
+impl Segments {
+    fn new(key: UnboundKey, nonce_prefix: &[u8]) -> Result<Self, error::Unspecified> {
+        let key = key.into_inner();
+        if Some(nonce_prefix.len()) != nonce_prefix_len(key.algorithm()) {
+            return Err(error::Unspecified);
+        }
+        let mut value = [0u8; MAX_NONCE_LEN];
+        value[..nonce_prefix.len()].copy_from_slice(nonce_prefix);
+        Ok(Self {
+            key,
+            nonce_prefix: value,
+            nonce_prefix_len: nonce_prefix.len(),
+            counter: 0,
+        })
+    }
+
+    /// Returns the key and the nonce for the current segment.
+    fn next(&self, last: bool) -> Result<(&LessSafeKey, Nonce), error::Unspecified> {
+        // Every segment but the last must leave room for another segment
+        // after it.
+        if !last && self.counter == u32::MAX {
+            return Err(error::Unspecified);
+        }
+        let prefix_len = self.nonce_prefix_len;
+        let mut nonce = self.nonce_prefix;
+        nonce[prefix_len..][..COUNTER_LEN].copy_from_slice(&self.counter.to_be_bytes());
+        nonce[prefix_len + COUNTER_LEN] = if last { 1 } else { 0 };
+        let nonce = Nonce::try_assume_unique_for_key_any_len(
+            &nonce[..(prefix_len + COUNTER_LEN + LAST_FLAG_LEN)],
+        )?;
+        Ok((&self.key, nonce))
+    }
+
+    fn advance(&mut self) {
+        self.counter += 1;
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use crate::{error, polyfill};
+
+/// Limits on how much data may be protected under a single key.
+///
+/// AEADs have limits on the number of messages, and the total amount of data,
+/// that can be protected under a key before confidentiality or integrity
+/// degrade; see [NIST SP 800-38D Section 8.3] for AES-GCM and
+/// [Usage Limits on AEAD Algorithms] in general. Protocols that use
+/// long-lived keys must replace their keys before reaching those limits.
+///
+/// [NIST SP 800-38D Section 8.3]: https://doi.org/10.6028/NIST.SP.800-38D
+/// [Usage Limits on AEAD Algorithms]:
+///     https://datatracker.ietf.org/doc/draft-irtf-cfrg-aead-limits/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UsageLimits {
+    max_messages: u64,
+    max_bytes: u64,
+}
+
+impl UsageLimits {
+    /// Constructs limits of `max_messages` messages and `max_bytes` bytes of
+    /// plaintext in total.
+    #[inline]
+    pub fn new(max_messages: u64, max_bytes: u64) -> Self {
+        Self {
+            max_messages,
+            max_bytes,
+        }
+    }
+
+    /// The maximum number of messages.
+    #[inline]
+    pub fn max_messages(&self) -> u64 {
+        self.max_messages
+    }
+
+    /// The maximum total number of bytes of plaintext.
+    #[inline]
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+}
+
+/// Tracks the usage of a key against `UsageLimits`.
+///
+/// `SealingKey` does this automatically once `SealingKey::set_usage_limits()`
+/// is called. Users of `LessSafeKey` should call `record()` before every
+/// sealing operation.
+#[derive(Clone, Debug)]
+pub struct UsageTracker {
+    limits: UsageLimits,
+    messages: u64,
+    bytes: u64,
+}
+
+impl UsageTracker {
+    /// Constructs a tracker of a key that hasn't been used yet.
+    #[inline]
+    pub fn new(limits: UsageLimits) -> Self {
+        Self {
+            limits,
+            messages: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Records the use of the key to protect a message of `len` bytes.
+    ///
+    /// Fails, without recording anything, if doing so would exceed the
+    /// limits. Once this fails for a message, the key must be replaced before
+    /// protecting it.
+    pub fn record(&mut self, len: usize) -> Result<(), error::Unspecified> {
+        let messages = self
+            .messages
+            .checked_add(1)
+            .filter(|messages| *messages <= self.limits.max_messages)
+            .ok_or(error::Unspecified)?;
+        let bytes = self
+            .bytes
+            .checked_add(polyfill::u64_from_usize(len))
+            .filter(|bytes| *bytes <= self.limits.max_bytes)
+            .ok_or(error::Unspecified)?;
+        self.messages = messages;
+        self.bytes = bytes;
+        Ok(())
+    }
+
+    /// The limits being tracked.
+    #[inline]
+    pub fn limits(&self) -> UsageLimits {
+        self.limits
+    }
+
+    /// The number of messages recorded so far.
+    #[inline]
+    pub fn messages(&self) -> u64 {
+        self.messages
+    }
+
+    /// The total length of the messages recorded so far.
+    #[inline]
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Whether either limit has been reached, i.e. whether the key must be
+    /// replaced before protecting another non-empty message.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.messages >= self.limits.max_messages || self.bytes >= self.limits.max_bytes
+    }
+}
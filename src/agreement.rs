@@ -60,10 +60,11 @@
 // The "NSA Guide" steps here are from from section 3.1, "Ephemeral Unified
 // Model."
 
-use crate::{cpu, debug, ec, error, rand};
+use crate::{cpu, debug, ec, error, hkdf, rand};
+use core::convert::TryFrom;
 
 pub use crate::ec::{
-    curve25519::x25519::X25519,
+    curve25519::x25519::{x25519_public_key_from_ed25519, X25519},
     suite_b::ecdh::{ECDH_P256, ECDH_P384},
 };
 
@@ -86,6 +87,14 @@ impl PartialEq for Algorithm {
     }
 }
 
+impl hkdf::KeyType for &'static Algorithm {
+    /// The length of a private key.
+    #[inline]
+    fn len(&self) -> usize {
+        self.curve.elem_scalar_seed_len
+    }
+}
+
 /// An ephemeral private key for use (only) with `agree_ephemeral`. The
 /// signature of `agree_ephemeral` ensures that an `EphemeralPrivateKey` can be
 /// used for at most one key agreement.
@@ -141,12 +150,47 @@ impl EphemeralPrivateKey {
         self.algorithm
     }
 
+    pub(crate) fn from_bytes(
+        algorithm: &'static Algorithm,
+        bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let private_key = ec::Seed::from_bytes(
+            algorithm.curve,
+            untrusted::Input::from(bytes),
+            cpu::features(),
+        )?;
+        Ok(Self {
+            private_key,
+            algorithm,
+        })
+    }
+
     #[cfg(test)]
     pub fn bytes(&self) -> &[u8] {
         self.private_key.bytes_less_safe()
     }
 }
 
+/// Derives a private key from HKDF output, e.g. to implement a protocol that
+/// derives its ephemeral key pairs deterministically.
+///
+/// The OKM is used as the private key as-is. For X25519 this always succeeds.
+/// For ECDH_P256 and ECDH_P384 it fails in the unlikely case that the OKM
+/// isn't a valid private key (the probability is about 2**-32 for P-256 and
+/// negligible for P-384); a protocol that must never fail needs to retry
+/// with a different `info`.
+impl TryFrom<hkdf::Okm<'_, &'static Algorithm>> for EphemeralPrivateKey {
+    type Error = error::Unspecified;
+
+    fn try_from(okm: hkdf::Okm<&'static Algorithm>) -> Result<Self, Self::Error> {
+        let algorithm = *okm.len();
+        let mut bytes = [0u8; ec::SCALAR_MAX_BYTES];
+        let bytes = &mut bytes[..algorithm.curve.elem_scalar_seed_len];
+        okm.fill(bytes)?;
+        EphemeralPrivateKey::from_bytes(algorithm, bytes)
+    }
+}
+
 /// A public key for key agreement.
 #[derive(Clone)]
 pub struct PublicKey {
@@ -170,6 +214,10 @@ impl core::fmt::Debug for PublicKey {
 }
 
 impl PublicKey {
+    pub(crate) fn new(algorithm: &'static Algorithm, bytes: ec::PublicKey) -> Self {
+        Self { algorithm, bytes }
+    }
+
     /// The algorithm for the public key.
     #[inline]
     pub fn algorithm(&self) -> &'static Algorithm {
@@ -0,0 +1,517 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Argon2id derivation and verification.
+//!
+//! Argon2id is a memory-hard password hashing function, specified in
+//! [RFC 9106], which resists both GPU cracking and side-channel attacks. Use
+//! `derive` to derive Argon2id outputs, e.g. to hash a new password. Use
+//! `verify` to verify a secret against a previously-derived output.
+//!
+//! ```
+//! use ring::argon2;
+//!
+//! // These parameters are far too weak for real use; see `Params::new`.
+//! let params = argon2::Params::new(256, 2, 1).unwrap();
+//! let salt = b"a per-user salt";
+//!
+//! let mut stored = [0u8; 32];
+//! argon2::derive(&params, salt, b"correct horse", &mut stored).unwrap();
+//!
+//! assert!(argon2::verify(&params, salt, b"correct horse", &stored).is_ok());
+//! assert!(argon2::verify(&params, salt, b"wrong horse", &stored).is_err());
+//! ```
+//!
+//! The lanes are computed one after another; `Params::parallelism` affects
+//! the output, but not how many threads are used.
+//!
+//! [RFC 9106]: https://www.rfc-editor.org/rfc/rfc9106
+
+use crate::{
+    constant_time,
+    digest::{self, blake2},
+    error, kdf, polyfill,
+};
+use alloc::vec;
+use core::convert::{TryFrom, TryInto};
+
+/// The minimum length of the salt, in bytes.
+pub const MIN_SALT_LEN: usize = 8;
+
+/// The minimum length of the output, in bytes.
+pub const MIN_OUTPUT_LEN: usize = 4;
+
+/// The maximum length of the secret key for `derive_keyed`, in bytes.
+pub const MAX_KEY_LEN: usize = 32;
+
+const VERSION: u32 = 0x13;
+
+/// The Argon2 type number of Argon2id.
+const TYPE_ID: u32 = 2;
+
+const SYNC_POINTS: u32 = 4;
+
+const BLOCK_WORDS: usize = 128;
+const BLOCK_LEN: usize = BLOCK_WORDS * 8;
+
+type Block = [u64; BLOCK_WORDS];
+
+/// Argon2id's cost parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Params {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Params {
+    /// Constructs the parameters with the memory size `memory_kib`, in KiB,
+    /// the number of passes `iterations`, and the degree of parallelism
+    /// (number of lanes) `parallelism`.
+    ///
+    /// RFC 9106 Section 4 recommends 2 GiB of memory with one pass, or 64 MiB
+    /// of memory with three passes when memory is constrained, and four
+    /// lanes either way.
+    ///
+    /// Fails if `iterations` is zero, if `parallelism` isn't between 1 and
+    /// 2^24 - 1, if `memory_kib` is less than `8 * parallelism`, or if the
+    /// memory required isn't addressable.
+    pub fn new(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Self, error::Unspecified> {
+        if iterations == 0
+            || parallelism == 0
+            || parallelism >= (1 << 24)
+            || u64::from(memory_kib) < 8 * u64::from(parallelism)
+        {
+            return Err(error::Unspecified);
+        }
+        let params = Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        };
+        if usize::try_from(params.num_blocks())
+            .ok()
+            .and_then(|n| n.checked_mul(BLOCK_LEN))
+            .is_none()
+        {
+            return Err(error::Unspecified);
+        }
+        Ok(params)
+    }
+
+    /// The memory size, in KiB.
+    #[inline]
+    pub fn memory_kib(&self) -> u32 {
+        self.memory_kib
+    }
+
+    /// The number of passes over the memory.
+    #[inline]
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// The number of lanes.
+    #[inline]
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+
+    /// The number of 1 KiB blocks of memory actually used: `memory_kib`
+    /// rounded down to a multiple of `4 * parallelism`.
+    fn num_blocks(&self) -> u32 {
+        let lanes_per_slice = SYNC_POINTS * self.parallelism;
+        (self.memory_kib / lanes_per_slice) * lanes_per_slice
+    }
+}
+
+/// The info is used as the associated data.
+impl kdf::Kdf for Params {
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        derive_keyed(self, &[], info, salt, secret, out)
+    }
+}
+
+/// Fills `out` with the Argon2id hash of `secret` using `salt`.
+///
+/// Do not use `derive` as part of verifying a secret; use `verify` instead, to
+/// minimize the effectiveness of timing attacks.
+///
+/// | Parameter   | RFC 9106 Section 3.1 Term
+/// |-------------|--------------------------
+/// | `params`    | m, t, and p
+/// | `salt`      | S (salt)
+/// | `secret`    | P (message)
+/// | `out`       | the tag
+/// | `out.len()` | T (tag length)
+///
+/// Fails if `salt` is shorter than `MIN_SALT_LEN`, if `out` is shorter than
+/// `MIN_OUTPUT_LEN`, or if any input is 2^32 bytes or longer.
+///
+/// # Panics
+///
+/// `derive` panics if the memory can't be allocated.
+pub fn derive(
+    params: &Params,
+    salt: &[u8],
+    secret: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    derive_keyed(params, &[], &[], salt, secret, out)
+}
+
+/// Like `derive`, with the optional secret value `key` (K, a "pepper") and
+/// associated data `associated_data` (X) of RFC 9106.
+///
+/// Fails if `key` is longer than `MAX_KEY_LEN`, and for the same reasons as
+/// `derive`.
+pub fn derive_keyed(
+    params: &Params,
+    key: &[u8],
+    associated_data: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    if key.len() > MAX_KEY_LEN || salt.len() < MIN_SALT_LEN || out.len() < MIN_OUTPUT_LEN {
+        return Err(error::Unspecified);
+    }
+    let tag_len = le32(out.len())?;
+
+    // RFC 9106 Section 3.2, step 1.
+    let mut ctx = digest::Context::new(&digest::BLAKE2B_512);
+    for value in [
+        params.parallelism,
+        tag_len,
+        params.memory_kib,
+        params.iterations,
+        VERSION,
+        TYPE_ID,
+    ]
+    .iter()
+    {
+        ctx.update(&value.to_le_bytes());
+    }
+    for input in [secret, salt, key, associated_data].iter() {
+        ctx.update(&le32(input.len())?.to_le_bytes());
+        ctx.update(input);
+    }
+    let h0 = ctx.finish();
+
+    let lanes = params.parallelism as usize;
+    let segment_len = (params.num_blocks() / (SYNC_POINTS * params.parallelism)) as usize;
+    let lane_len = segment_len * (SYNC_POINTS as usize);
+    let mut memory = vec![[0u64; BLOCK_WORDS]; lanes * lane_len];
+
+    // Steps 3 and 4: the first two blocks of each lane.
+    for (lane, blocks) in memory.chunks_exact_mut(lane_len).enumerate() {
+        let lane = (lane as u32).to_le_bytes();
+        for (j, block) in blocks[..2].iter_mut().enumerate() {
+            let mut bytes = [0u8; BLOCK_LEN];
+            h_prime(&mut bytes, &[h0.as_ref(), &(j as u32).to_le_bytes(), &lane]);
+            for (w, bytes) in block.iter_mut().zip(bytes.chunks_exact(8)) {
+                *w = u64::from_le_bytes(bytes.try_into().unwrap());
+            }
+        }
+    }
+
+    // Steps 5 and 6. Within a slice each lane only references its own blocks
+    // and the finished slices of the other lanes, so the lanes of a slice
+    // can be filled in any order.
+    let geometry = Geometry {
+        lanes,
+        lane_len,
+        segment_len,
+        num_blocks: params.num_blocks(),
+        iterations: params.iterations,
+    };
+    for pass in 0..params.iterations {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                fill_segment(&mut memory, &geometry, pass, slice, lane);
+            }
+        }
+    }
+
+    // Step 7.
+    let mut c = memory[lane_len - 1];
+    for blocks in memory.chunks_exact(lane_len).skip(1) {
+        xor_into(&mut c, &blocks[lane_len - 1]);
+    }
+    let mut c_bytes = [0u8; BLOCK_LEN];
+    for (w, bytes) in c.iter().zip(c_bytes.chunks_exact_mut(8)) {
+        bytes.copy_from_slice(&w.to_le_bytes());
+    }
+    h_prime(out, &[&c_bytes]);
+
+    Ok(())
+}
+
+/// Verifies that a previously-derived (e.g., using `derive`) Argon2id value
+/// matches the Argon2id value derived from the other inputs.
+///
+/// The comparison is done in constant time to prevent timing attacks. The
+/// comparison will fail if `previously_derived` is shorter than
+/// `MIN_OUTPUT_LEN`, or if any input is invalid for `derive`.
+///
+/// # Panics
+///
+/// `verify` panics if the memory can't be allocated.
+pub fn verify(
+    params: &Params,
+    salt: &[u8],
+    secret: &[u8],
+    previously_derived: &[u8],
+) -> Result<(), error::Unspecified> {
+    verify_keyed(params, &[], &[], salt, secret, previously_derived)
+}
+
+/// Like `verify`, for a value previously derived using `derive_keyed`.
+pub fn verify_keyed(
+    params: &Params,
+    key: &[u8],
+    associated_data: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    previously_derived: &[u8],
+) -> Result<(), error::Unspecified> {
+    let mut derived = vec![0u8; previously_derived.len()];
+    derive_keyed(params, key, associated_data, salt, secret, &mut derived)?;
+    constant_time::verify_slices_are_equal(&derived, previously_derived)
+}
+
+fn le32(len: usize) -> Result<u32, error::Unspecified> {
+    u32::try_from(len).map_err(|_| error::Unspecified)
+}
+
+/// The shape of the memory.
+struct Geometry {
+    lanes: usize,
+    lane_len: usize,
+    segment_len: usize,
+    num_blocks: u32,
+    iterations: u32,
+}
+
+/// RFC 9106 Section 3.4: fills the segment of `lane` in `slice` of `pass`.
+fn fill_segment(memory: &mut [Block], g: &Geometry, pass: u32, slice: u32, lane: usize) {
+    // Argon2id uses data-independent addressing for the first half of the
+    // first pass, and data-dependent addressing afterwards.
+    let data_independent = pass == 0 && slice < SYNC_POINTS / 2;
+
+    let mut input = [0u64; BLOCK_WORDS];
+    let mut addresses = [0u64; BLOCK_WORDS];
+    if data_independent {
+        input[..6].copy_from_slice(&[
+            u64::from(pass),
+            polyfill::u64_from_usize(lane),
+            u64::from(slice),
+            u64::from(g.num_blocks),
+            u64::from(g.iterations),
+            u64::from(TYPE_ID),
+        ]);
+    }
+
+    // The first two blocks of each lane were filled in during
+    // initialization.
+    let first = if pass == 0 && slice == 0 { 2 } else { 0 };
+    if data_independent && first != 0 {
+        next_addresses(&mut addresses, &mut input);
+    }
+
+    for index in first..g.segment_len {
+        let current = lane * g.lane_len + (slice as usize) * g.segment_len + index;
+        // The first block of a lane follows the last one.
+        let prev = if slice == 0 && index == 0 {
+            current + g.lane_len - 1
+        } else {
+            current - 1
+        };
+
+        let pseudo_rand = if data_independent {
+            let i = index % BLOCK_WORDS;
+            if i == 0 {
+                next_addresses(&mut addresses, &mut input);
+            }
+            addresses[i]
+        } else {
+            memory[prev][0]
+        };
+
+        let ref_lane = if pass == 0 && slice == 0 {
+            lane
+        } else {
+            ((pseudo_rand >> 32) % polyfill::u64_from_usize(g.lanes)) as usize
+        };
+        let ref_index = reference_index(g, pass, slice, index, ref_lane == lane, pseudo_rand);
+
+        let mut block = compress(&memory[prev], &memory[ref_lane * g.lane_len + ref_index]);
+        if pass > 0 {
+            xor_into(&mut block, &memory[current]);
+        }
+        memory[current] = block;
+    }
+}
+
+/// RFC 9106 Section 3.4.1.1: the index, within the reference lane, of the
+/// block the `index`-th block of the segment references.
+fn reference_index(
+    g: &Geometry,
+    pass: u32,
+    slice: u32,
+    index: usize,
+    same_lane: bool,
+    pseudo_rand: u64,
+) -> usize {
+    let slice = slice as usize;
+
+    // The blocks that may be referenced: those of the finished segments
+    // (the last three of the lane after the first pass) other than the
+    // previous block, and in the current lane the blocks already computed in
+    // this segment.
+    let finished = if pass == 0 {
+        slice * g.segment_len
+    } else {
+        g.lane_len - g.segment_len
+    };
+    let area = if same_lane {
+        finished + index - 1
+    } else if index == 0 {
+        finished - 1
+    } else {
+        finished
+    };
+
+    // Map J1 non-uniformly onto the area, favoring recent blocks.
+    let area = polyfill::u64_from_usize(area);
+    let j1 = pseudo_rand & 0xffff_ffff;
+    let x = (j1 * j1) >> 32;
+    let relative = area - 1 - ((area * x) >> 32);
+
+    let start = if pass == 0 || slice == (SYNC_POINTS as usize) - 1 {
+        0
+    } else {
+        (slice + 1) * g.segment_len
+    };
+    (start + relative as usize) % g.lane_len
+}
+
+/// Computes the next block of pseudo-random values for data-independent
+/// addressing.
+fn next_addresses(addresses: &mut Block, input: &mut Block) {
+    input[6] += 1;
+    let zero = [0u64; BLOCK_WORDS];
+    *addresses = compress(&zero, &compress(&zero, input));
+}
+
+fn xor_into(a: &mut Block, b: &Block) {
+    a.iter_mut().zip(b.iter()).for_each(|(a, b)| *a ^= b);
+}
+
+/// RFC 9106 Section 3.5: the compression function G.
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = *x;
+    xor_into(&mut r, y);
+
+    let mut q = r;
+    // Apply P to each row of eight 16-byte registers...
+    for row in 0..8 {
+        let mut indices = [0; 16];
+        for (k, i) in indices.iter_mut().enumerate() {
+            *i = 16 * row + k;
+        }
+        permute(&mut q, &indices);
+    }
+    // ...and then to each column.
+    for column in 0..8 {
+        let mut indices = [0; 16];
+        for (k, i) in indices.iter_mut().enumerate() {
+            *i = 2 * column + 16 * (k / 2) + (k % 2);
+        }
+        permute(&mut q, &indices);
+    }
+
+    xor_into(&mut q, &r);
+    q
+}
+
+/// RFC 9106 Section 3.6: the permutation P, on the words of `v` at
+/// `indices`.
+fn permute(v: &mut Block, indices: &[usize; 16]) {
+    #[inline(always)]
+    fn gb(v: &mut Block, a: usize, b: usize, c: usize, d: usize) {
+        #[inline(always)]
+        fn bla_mka(x: u64, y: u64) -> u64 {
+            let lo = u64::from(x as u32) * u64::from(y as u32);
+            x.wrapping_add(y).wrapping_add(lo.wrapping_mul(2))
+        }
+        v[a] = bla_mka(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = bla_mka(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = bla_mka(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = bla_mka(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    }
+    let i = indices;
+    gb(v, i[0], i[4], i[8], i[12]);
+    gb(v, i[1], i[5], i[9], i[13]);
+    gb(v, i[2], i[6], i[10], i[14]);
+    gb(v, i[3], i[7], i[11], i[15]);
+    gb(v, i[0], i[5], i[10], i[15]);
+    gb(v, i[1], i[6], i[11], i[12]);
+    gb(v, i[2], i[7], i[8], i[13]);
+    gb(v, i[3], i[4], i[9], i[14]);
+}
+
+/// RFC 9106 Section 3.3: the variable-length hash function H'.
+fn h_prime(out: &mut [u8], input: &[&[u8]]) {
+    let tag_len = (out.len() as u32).to_le_bytes();
+    if out.len() <= digest::BLAKE2B_512_OUTPUT_LEN {
+        let mut ctx = blake2::blake2b_context_with_output_len(out.len());
+        ctx.update(&tag_len);
+        input.iter().for_each(|input| ctx.update(input));
+        out.copy_from_slice(&ctx.finish().as_ref()[..out.len()]);
+        return;
+    }
+
+    let mut ctx = digest::Context::new(&digest::BLAKE2B_512);
+    ctx.update(&tag_len);
+    input.iter().for_each(|input| ctx.update(input));
+    let mut v = ctx.finish();
+
+    // The first half of each of V_1..V_r, then all of V_{r+1}.
+    let r = out.chunks(32).len() - 2;
+    let (head, tail) = out.split_at_mut(32 * r);
+    for (i, chunk) in head.chunks_exact_mut(32).enumerate() {
+        if i > 0 {
+            v = digest::digest(&digest::BLAKE2B_512, v.as_ref());
+        }
+        chunk.copy_from_slice(&v.as_ref()[..32]);
+    }
+    let mut ctx = blake2::blake2b_context_with_output_len(tail.len());
+    ctx.update(v.as_ref());
+    tail.copy_from_slice(&ctx.finish().as_ref()[..tail.len()]);
+}
@@ -446,6 +446,42 @@ impl<M> Elem<M, Unencoded> {
         limb::big_endian_from_limbs(&self.limbs, out)
     }
 
+    /// Parses the big-endian integer `input`, which may be longer than `m`,
+    /// and reduces it modulo `m`.
+    pub fn from_be_bytes_reduced(
+        input: untrusted::Input,
+        m: &Modulus<M>,
+    ) -> Result<Self, error::Unspecified> {
+        // Horner's method, one `m`-sized chunk at a time. The place value of
+        // a chunk is `R`, so for a Montgomery-encoded accumulator `acc`,
+        // (acc * R + chunk) * R == mont(acc, RR) + mont(chunk, RR). Each
+        // product is less than `m * R` since `acc`, `RR` < `m` and
+        // `chunk` < `R`, so the results are fully reduced.
+        let input = input.as_slice_less_safe();
+        if input.is_empty() {
+            return Err(error::Unspecified);
+        }
+        let chunk_len = m.limbs.len() * LIMB_BYTES;
+        let first_len = match input.len() % chunk_len {
+            0 => chunk_len,
+            n => n,
+        };
+        let (first, rest) = input.split_at(first_len);
+
+        let mut acc: Elem<M, R> = m.zero();
+        for chunk in core::iter::once(first).chain(rest.chunks(chunk_len)) {
+            let mut chunk_limbs = BoxedLimbs::<M>::zero(m.width());
+            limb::parse_big_endian_and_pad_consttime(
+                untrusted::Input::from(chunk),
+                &mut chunk_limbs,
+            )?;
+            limbs_mont_mul(&mut chunk_limbs, &m.oneRR.0.limbs, &m.limbs, &m.n0);
+            limbs_mont_mul(&mut acc.limbs, &m.oneRR.0.limbs, &m.limbs, &m.n0);
+            limb::limbs_add_assign_mod(&mut acc.limbs, &chunk_limbs, &m.limbs);
+        }
+        Ok(acc.into_unencoded(m))
+    }
+
     pub fn into_modulus<MM>(self) -> Result<Modulus<MM>, error::KeyRejected> {
         let (m, _bits) =
             Modulus::from_boxed_limbs(BoxedLimbs::minimal_width_from_unpadded(&self.limbs))?;
@@ -527,7 +563,7 @@ pub fn elem_reduced<Larger, Smaller: NotMuchSmallerModulus<Larger>>(
     r
 }
 
-fn elem_squared<M, E>(
+pub fn elem_squared<M, E>(
     mut a: Elem<M, E>,
     m: &PartialModulus<M>,
 ) -> Elem<M, <(E, E) as ProductEncoding>::Output>
@@ -692,6 +728,34 @@ pub(crate) fn elem_exp_vartime<M>(
     acc
 }
 
+/// Calculates base**exponent (mod m) for a non-zero big-endian `exponent` of
+/// any length.
+///
+/// The run time is a function of the number of limbs in `m` and the bit
+/// length and Hamming weight of `exponent`, so this must only be used for
+/// public exponents.
+pub(crate) fn elem_exp_vartime_be_bytes<M>(
+    base: Elem<M, R>,
+    exponent: &[u8],
+    m: &PartialModulus<M>,
+) -> Result<Elem<M, R>, error::Unspecified> {
+    // The same "S-and-X binary method" as `elem_exp_vartime`, starting at the
+    // most significant set bit.
+    let mut acc: Option<Elem<M, R>> = None;
+    for byte in exponent {
+        for i in (0..8).rev() {
+            acc = acc.map(|acc| elem_squared(acc, m));
+            if (byte >> i) & 1 == 1 {
+                acc = Some(match acc {
+                    Some(acc) => elem_mul_(&base, acc, m),
+                    None => base.clone(),
+                });
+            }
+        }
+    }
+    acc.ok_or(error::Unspecified)
+}
+
 // `M` represents the prime modulus for which the exponent is in the interval
 // [1, `m` - 1).
 pub struct PrivateExponent<M> {
@@ -0,0 +1,66 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The Ascon permutation, as specified in [NIST SP 800-232] Section 3.
+//!
+//! [NIST SP 800-232]: https://doi.org/10.6028/NIST.SP.800-232
+
+/// The 320-bit state, as five 64-bit words. Input and output bytes are mapped
+/// to the words in little-endian order.
+pub(crate) type State = [u64; 5];
+
+/// Applies the permutation Ascon-p[`rounds`], i.e. the last `rounds` of the
+/// 12 rounds.
+pub(crate) fn permute(s: &mut State, rounds: usize) {
+    debug_assert!(rounds <= 12);
+    for i in (12 - rounds)..12 {
+        round(s, (((0xf - i) << 4) | i) as u64);
+    }
+}
+
+#[inline(always)]
+fn round(s: &mut State, c: u64) {
+    let [mut x0, mut x1, mut x2, mut x3, mut x4] = *s;
+
+    // Constant-addition layer.
+    x2 ^= c;
+
+    // Substitution layer: the 5-bit S-box applied to each bit slice.
+    x0 ^= x4;
+    x4 ^= x3;
+    x2 ^= x1;
+    let t0 = !x0 & x1;
+    let t1 = !x1 & x2;
+    let t2 = !x2 & x3;
+    let t3 = !x3 & x4;
+    let t4 = !x4 & x0;
+    x0 ^= t1;
+    x1 ^= t2;
+    x2 ^= t3;
+    x3 ^= t4;
+    x4 ^= t0;
+    x1 ^= x0;
+    x0 ^= x4;
+    x3 ^= x2;
+    x2 = !x2;
+
+    // Linear diffusion layer.
+    x0 ^= x0.rotate_right(19) ^ x0.rotate_right(28);
+    x1 ^= x1.rotate_right(61) ^ x1.rotate_right(39);
+    x2 ^= x2.rotate_right(1) ^ x2.rotate_right(6);
+    x3 ^= x3.rotate_right(10) ^ x3.rotate_right(17);
+    x4 ^= x4.rotate_right(7) ^ x4.rotate_right(41);
+
+    *s = [x0, x1, x2, x3, x4];
+}
@@ -0,0 +1,273 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! bcrypt password hashing and verification.
+//!
+//! bcrypt is an older password hashing function, provided so that password
+//! databases with existing `$2b$` (or `$2a$` or `$2y$`) hashes can be
+//! verified while they are migrated to `argon2` or `scrypt`. Use it for new
+//! hashes only if a protocol requires it.
+//!
+//! ```
+//! use ring::{bcrypt, rand};
+//!
+//! let rng = rand::SystemRandom::new();
+//! let hash = bcrypt::hash(b"correct horse", 5, &rng)?;
+//! assert!(hash.as_ref().starts_with("$2b$05$"));
+//!
+//! bcrypt::verify(b"correct horse", hash.as_ref())?;
+//! assert!(bcrypt::verify(b"wrong horse", hash.as_ref()).is_err());
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! bcrypt only uses the first `MAX_PASSWORD_LEN` bytes of a password, and,
+//! as it was specified for C strings, nothing after a zero byte. `hash`
+//! rejects such passwords instead of silently ignoring part of them.
+//!
+//! bcrypt is described in [A Future-Adaptable Password Scheme]; the `$2b$`
+//! format is that of OpenBSD's implementation.
+//!
+//! [A Future-Adaptable Password Scheme]:
+//!     https://www.usenix.org/legacy/events/usenix99/provos/provos.pdf
+
+use crate::{constant_time, error, rand};
+
+mod blowfish;
+
+/// The minimum cost (base-2 logarithm of the number of rounds).
+pub const MIN_COST: u32 = 4;
+
+/// The maximum cost (base-2 logarithm of the number of rounds).
+pub const MAX_COST: u32 = 31;
+
+/// The length of the salt, in bytes.
+pub const SALT_LEN: usize = 16;
+
+/// The length of the longest password `hash` accepts, in bytes.
+pub const MAX_PASSWORD_LEN: usize = 72;
+
+/// The length of an encoded hash, e.g.
+/// `$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy`.
+pub const ENCODED_LEN: usize = 60;
+
+/// The length of the output, in bytes. (The last byte of the final
+/// ciphertext isn't used.)
+const OUTPUT_LEN: usize = 23;
+
+/// An encoded bcrypt hash, in the `$2b$` format.
+#[derive(Clone, Copy)]
+pub struct PasswordHash([u8; ENCODED_LEN]);
+
+impl AsRef<str> for PasswordHash {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        // The encoding is always ASCII.
+        core::str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl core::fmt::Debug for PasswordHash {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.write_str("PasswordHash")
+    }
+}
+
+impl core::fmt::Display for PasswordHash {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Hashes `password` with a random salt and `cost` (the base-2 logarithm of
+/// the number of rounds).
+///
+/// OWASP recommends a cost of at least 10.
+///
+/// Fails if `cost` isn't between `MIN_COST` and `MAX_COST`, or if `password`
+/// is longer than `MAX_PASSWORD_LEN` or contains a zero byte.
+pub fn hash(
+    password: &[u8],
+    cost: u32,
+    rng: &dyn rand::SecureRandom,
+) -> Result<PasswordHash, error::Unspecified> {
+    let mut salt = [0u8; SALT_LEN];
+    rng.fill(&mut salt)?;
+    hash_with_salt(password, cost, &salt)
+}
+
+/// Like `hash`, with the given salt.
+///
+/// The salt must be unique for each password hashed; use `hash` unless the
+/// salt must be chosen some other way.
+pub fn hash_with_salt(
+    password: &[u8],
+    cost: u32,
+    salt: &[u8; SALT_LEN],
+) -> Result<PasswordHash, error::Unspecified> {
+    if password.len() > MAX_PASSWORD_LEN || password.contains(&0) {
+        return Err(error::Unspecified);
+    }
+    encode(password, cost, salt)
+}
+
+/// Verifies that `password` matches the encoded hash `encoded`, in constant
+/// time.
+///
+/// `encoded` may use the `$2b$`, `$2y$`, or `$2a$` format; they're all
+/// treated the same way. (Before 2014, OpenBSD's implementation computed
+/// the length of the password modulo 256 for `$2a$`, so its hashes of
+/// passwords of 255 bytes or more won't verify.) As in the C
+/// implementations, only the part of `password` before the first zero byte,
+/// and at most its first `MAX_PASSWORD_LEN` bytes, is used.
+pub fn verify(password: &[u8], encoded: &str) -> Result<(), error::Unspecified> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() != ENCODED_LEN {
+        return Err(error::Unspecified);
+    }
+    if !matches!(&encoded[..4], b"$2b$" | b"$2y$" | b"$2a$") {
+        return Err(error::Unspecified);
+    }
+    let cost = match (&encoded[4..7], encoded[4], encoded[5]) {
+        ([b'0'..=b'9', b'0'..=b'9', b'$'], tens, ones) => {
+            u32::from(tens - b'0') * 10 + u32::from(ones - b'0')
+        }
+        _ => return Err(error::Unspecified),
+    };
+    let mut salt = [0u8; SALT_LEN];
+    base64_decode(&encoded[7..29], &mut salt)?;
+
+    let password = match password.iter().position(|&b| b == 0) {
+        Some(nul) => &password[..nul],
+        None => password,
+    };
+    let actual = encode(password, cost, &salt)?;
+    // The prefix, cost, and salt are the same whenever the hash is valid,
+    // so this only fails to match if the hash is wrong or the encoding isn't
+    // canonical.
+    constant_time::verify_slices_are_equal(&actual.0[4..], &encoded[4..])
+}
+
+fn encode(
+    password: &[u8],
+    cost: u32,
+    salt: &[u8; SALT_LEN],
+) -> Result<PasswordHash, error::Unspecified> {
+    if !(MIN_COST..=MAX_COST).contains(&cost) {
+        return Err(error::Unspecified);
+    }
+
+    // The key is the password as a C string, including its terminating zero
+    // byte, used cyclically. Only its first 72 bytes are used.
+    let mut key = [0u8; MAX_PASSWORD_LEN + 1];
+    let key_len = core::cmp::min(password.len(), MAX_PASSWORD_LEN);
+    key[..key_len].copy_from_slice(&password[..key_len]);
+    let key = &key[..(key_len + 1)];
+
+    let output = eks_blowfish(cost, salt, key);
+
+    let mut encoded = [0u8; ENCODED_LEN];
+    encoded[..4].copy_from_slice(b"$2b$");
+    encoded[4] = b'0' + (cost / 10) as u8;
+    encoded[5] = b'0' + (cost % 10) as u8;
+    encoded[6] = b'$';
+    base64_encode(salt, &mut encoded[7..29]);
+    base64_encode(&output, &mut encoded[29..]);
+    Ok(PasswordHash(encoded))
+}
+
+/// `EksBlowfishSetup`, followed by the encryption of the magic value.
+fn eks_blowfish(cost: u32, salt: &[u8; SALT_LEN], key: &[u8]) -> [u8; OUTPUT_LEN] {
+    let mut state = blowfish::State::new();
+    state.expand_key(Some(salt), key);
+    for _ in 0..(1u64 << cost) {
+        state.expand_key(None, key);
+        state.expand_key(None, salt);
+    }
+
+    let mut ctext = [0u32; 6];
+    for (w, bytes) in ctext
+        .iter_mut()
+        .zip(b"OrpheanBeholderScryDoubt".chunks_exact(4))
+    {
+        *w = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for _ in 0..64 {
+        for block in ctext.chunks_exact_mut(2) {
+            let (l, r) = state.encrypt(block[0], block[1]);
+            block[0] = l;
+            block[1] = r;
+        }
+    }
+
+    let mut output = [0u8; 24];
+    for (bytes, w) in output.chunks_exact_mut(4).zip(ctext.iter()) {
+        bytes.copy_from_slice(&w.to_be_bytes());
+    }
+    let mut truncated = [0u8; OUTPUT_LEN];
+    truncated.copy_from_slice(&output[..OUTPUT_LEN]);
+    truncated
+}
+
+/// bcrypt's base64 alphabet, which differs from that of RFC 4648.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Encodes `input` without padding; `out` must have room for exactly the
+/// encoding.
+fn base64_encode(input: &[u8], out: &mut [u8]) {
+    let mut out = out.iter_mut();
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let sextets = [
+            b[0] >> 2,
+            ((b[0] & 0x03) << 4) | (b[1] >> 4),
+            ((b[1] & 0x0f) << 2) | (b[2] >> 6),
+            b[2] & 0x3f,
+        ];
+        for s in sextets.iter().take(chunk.len() + 1) {
+            *out.next().unwrap() = BASE64_ALPHABET[usize::from(*s)];
+        }
+    }
+    debug_assert!(out.next().is_none());
+}
+
+/// Decodes `input` into `out`, ignoring any bits left over at the end, as
+/// the C implementations do.
+fn base64_decode(input: &[u8], out: &mut [u8]) -> Result<(), error::Unspecified> {
+    let mut bits = 0u32;
+    let mut num_bits = 0;
+    let mut out = out.iter_mut();
+    for c in input {
+        let value = BASE64_ALPHABET
+            .iter()
+            .position(|a| a == c)
+            .ok_or(error::Unspecified)?;
+        bits = (bits << 6) | (value as u32);
+        num_bits += 6;
+        if num_bits >= 8 {
+            num_bits -= 8;
+            match out.next() {
+                Some(b) => *b = (bits >> num_bits) as u8,
+                None => break,
+            }
+            bits &= (1 << num_bits) - 1;
+        }
+    }
+    Ok(())
+}
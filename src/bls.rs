@@ -0,0 +1,61 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! BLS signatures.
+//!
+//! BLS signatures over the pairing-friendly curve BLS12-381, as specified in
+//! [draft-irtf-cfrg-bls-signature-05]. Signatures by different keys, even of
+//! different messages, can be aggregated into a single signature that is
+//! verified at once.
+//!
+//! Each of the basic and proof-of-possession schemes comes in two variants:
+//! "min-pk", with 48-byte public keys and 96-byte signatures, and "min-sig",
+//! with 96-byte public keys and 48-byte signatures.
+//!
+//! ```
+//! use ring::{bls, signature::KeyPair};
+//!
+//! let alg = &bls::BLS12_381_MIN_PK_POP;
+//! let alice = bls::KeyPair::from_ikm(alg, &[1; 32], b"")?;
+//! let bob = bls::KeyPair::from_ikm(alg, &[2; 32], b"")?;
+//!
+//! // Before relying on them, check the proofs of possession of the keys.
+//! for key_pair in [&alice, &bob] {
+//!     let proof = key_pair.prove_possession()?;
+//!     bls::verify_possession(alg, key_pair.public_key().as_ref(), proof.as_ref())?;
+//! }
+//!
+//! let msg = b"block 42";
+//! let signature = bls::aggregate(
+//!     alg,
+//!     &[alice.sign(msg).as_ref(), bob.sign(msg).as_ref()],
+//! )?;
+//! bls::fast_aggregate_verify(
+//!     alg,
+//!     &[alice.public_key().as_ref(), bob.public_key().as_ref()],
+//!     msg,
+//!     signature.as_ref(),
+//! )?;
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [draft-irtf-cfrg-bls-signature-05]:
+//!     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05
+
+pub use crate::ec::bls12_381::bls::{
+    aggregate, aggregate_verify, fast_aggregate_verify, verify, verify_possession, Algorithm,
+    KeyPair, PublicKey, Signature, BLS12_381_MIN_PK_BASIC, BLS12_381_MIN_PK_POP,
+    BLS12_381_MIN_SIG_BASIC, BLS12_381_MIN_SIG_POP, PRIVATE_KEY_LEN,
+};
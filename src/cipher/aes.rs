@@ -0,0 +1,216 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES in the CTR and CBC modes of [NIST SP 800-38A].
+//!
+//! Neither mode authenticates anything, and CBC decryption in particular is
+//! the basis of padding oracle attacks when an attacker can learn whether a
+//! decrypted message was well-formed. These are provided for interoperability
+//! with formats that require them, such as PBES2-encrypted PKCS#8 keys.
+//!
+//! Neither mode pads its input: CBC requires input that is a multiple of
+//! `BLOCK_LEN` bytes long, so a caller that needs, e.g., PKCS#7 padding must
+//! add and remove it itself.
+//!
+//! [NIST SP 800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+
+use crate::{
+    aead::{
+        aes::{self, Counter},
+        block::Block,
+    },
+    cpu, error, polyfill,
+};
+use core::convert::{TryFrom, TryInto};
+
+/// The length of an AES block, and of the IVs of AES-CTR and AES-CBC.
+pub const BLOCK_LEN: usize = crate::aead::block::BLOCK_LEN;
+
+/// An AES algorithm.
+pub struct Algorithm {
+    variant: aes::Variant,
+    key_len: usize,
+    id: AlgorithmID,
+}
+
+impl Algorithm {
+    /// The length of the key.
+    #[inline(always)]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    AES_128,
+    AES_256,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+/// AES-128.
+pub static AES_128: Algorithm = Algorithm {
+    variant: aes::Variant::AES_128,
+    key_len: 16,
+    id: AlgorithmID::AES_128,
+};
+
+/// AES-256.
+pub static AES_256: Algorithm = Algorithm {
+    variant: aes::Variant::AES_256,
+    key_len: 32,
+    id: AlgorithmID::AES_256,
+};
+
+/// An AES key.
+#[derive(Clone)]
+pub struct Key {
+    encrypt_key: aes::Key,
+    decrypt_key: aes::DecryptKey,
+    algorithm: &'static Algorithm,
+}
+
+impl Key {
+    /// Constructs an AES key.
+    ///
+    /// `key_bytes` must be exactly `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            encrypt_key: aes::Key::new(key_bytes, algorithm.variant, cpu::features())?,
+            decrypt_key: aes::DecryptKey::new(key_bytes, algorithm.variant)?,
+            algorithm,
+        })
+    }
+
+    /// The key's algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// Encrypts a single block with the raw AES block cipher.
+    #[inline]
+    pub(crate) fn encrypt_block(&self, block: &[u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+        let mut out = [0u8; BLOCK_LEN];
+        out.copy_from_slice(self.encrypt_key.encrypt_block(Block::from(block)).as_ref());
+        out
+    }
+
+    /// Encrypts or decrypts `in_out` in place with AES-CTR.
+    ///
+    /// `iv` is the initial counter block. The whole 128-bit block is
+    /// incremented as a big-endian integer, wrapping around at 2^128. A
+    /// `(key, iv)` pair must be used to encrypt at most one message, and the
+    /// counter blocks used for different messages must never overlap.
+    pub fn ctr_apply_keystream(&self, iv: &[u8; BLOCK_LEN], mut in_out: &mut [u8]) {
+        let mut counter = u128::from_be_bytes(*iv);
+        while !in_out.is_empty() {
+            // The implementation has a 32-bit counter, so the input is split
+            // before the low 32 bits of the counter would wrap.
+            let max_blocks = usize::try_from(u32::MAX - counter as u32).unwrap_or(usize::MAX);
+            let blocks = core::cmp::min(in_out.len() / BLOCK_LEN, max_blocks);
+            let (len, blocks) = if blocks > 0 {
+                let len = blocks * BLOCK_LEN;
+                let mut ctr = Counter::from_block_less_safe(Block::from(&counter.to_be_bytes()));
+                self.encrypt_key
+                    .ctr32_encrypt_within(&mut in_out[..len], 0.., &mut ctr);
+                (len, u128::from(polyfill::u64_from_usize(blocks)))
+            } else {
+                // Encrypt the block whose counter has all ones in its low 32
+                // bits, or the final partial block.
+                let len = core::cmp::min(in_out.len(), BLOCK_LEN);
+                let keystream = self
+                    .encrypt_key
+                    .encrypt_block(Block::from(&counter.to_be_bytes()));
+                for (b, k) in in_out[..len].iter_mut().zip(keystream.as_ref().iter()) {
+                    *b ^= k;
+                }
+                (len, 1)
+            };
+            in_out = &mut in_out[len..];
+            counter = counter.wrapping_add(blocks);
+        }
+    }
+
+    /// Encrypts `in_out` in place with AES-CBC.
+    ///
+    /// Fails, without modifying `in_out`, unless `in_out.len()` is a multiple
+    /// of `BLOCK_LEN`. `iv` must be unpredictable to an attacker.
+    pub fn cbc_encrypt(
+        &self,
+        iv: &[u8; BLOCK_LEN],
+        in_out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let mut previous = Block::from(iv);
+        for chunk in whole_blocks(in_out)? {
+            let input: &[u8; BLOCK_LEN] = (&*chunk).try_into()?;
+            previous = self
+                .encrypt_key
+                .encrypt_block(Block::from(input) ^ previous);
+            chunk.copy_from_slice(previous.as_ref());
+        }
+        Ok(())
+    }
+
+    /// Decrypts `in_out` in place with AES-CBC.
+    ///
+    /// Fails, without modifying `in_out`, unless `in_out.len()` is a multiple
+    /// of `BLOCK_LEN`. The result hasn't been authenticated, so it must not be
+    /// acted upon, including by checking its padding, until it has been.
+    pub fn cbc_decrypt(
+        &self,
+        iv: &[u8; BLOCK_LEN],
+        in_out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let mut previous = Block::from(iv);
+        for chunk in whole_blocks(in_out)? {
+            let input: &[u8; BLOCK_LEN] = (&*chunk).try_into()?;
+            let input = Block::from(input);
+            let output = self.decrypt_key.decrypt_block(input) ^ previous;
+            chunk.copy_from_slice(output.as_ref());
+            previous = input;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+fn whole_blocks(
+    in_out: &mut [u8],
+) -> Result<core::slice::ChunksExactMut<'_, u8>, error::Unspecified> {
+    let partial = in_out.len() % BLOCK_LEN;
+    if partial != 0 {
+        return Err(error::Unspecified);
+    }
+    Ok(in_out.chunks_exact_mut(BLOCK_LEN))
+}
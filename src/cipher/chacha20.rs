@@ -0,0 +1,122 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The ChaCha20 stream cipher.
+//!
+//! Two variants are supported: the IETF variant of [RFC 8439], which has
+//! 96-bit nonces and a 32-bit block counter, and the original variant of
+//! [ChaCha, a variant of Salsa20], which has 64-bit nonces and a 64-bit block
+//! counter. Each (key, nonce) pair must be used to encrypt at most one
+//! message; reusing one reveals the XOR of the plaintexts.
+//!
+//! [RFC 8439]: https://tools.ietf.org/html/rfc8439#section-2.4
+//! [ChaCha, a variant of Salsa20]: https://cr.yp.to/chacha/chacha-20080128.pdf
+
+use crate::{
+    aead::{
+        chacha::{self, Counter, BLOCK_LEN},
+        Nonce,
+    },
+    cpu, error, polyfill,
+};
+use core::convert::{TryFrom, TryInto};
+
+/// The length of a key.
+pub const KEY_LEN: usize = chacha::KEY_LEN;
+
+/// The length of a nonce of the IETF variant.
+pub const NONCE_LEN: usize = 96 / 8;
+
+/// The length of a nonce of the original variant.
+pub const LEGACY_NONCE_LEN: usize = 64 / 8;
+
+/// A ChaCha20 key.
+pub struct Key(chacha::Key);
+
+impl Key {
+    /// Constructs a key from its bytes.
+    ///
+    /// Fails if `key_bytes` isn't `KEY_LEN` bytes long.
+    pub fn new(key_bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let key_bytes: [u8; KEY_LEN] = key_bytes.try_into()?;
+        Ok(Self(chacha::Key::new(key_bytes, cpu::features())))
+    }
+
+    /// Encrypts or decrypts `in_out` in place, using the IETF variant with the
+    /// initial block counter `counter`.
+    ///
+    /// Fails, without modifying `in_out`, if the counter would overflow, i.e.
+    /// if `in_out` is longer than `(2^32 - counter) * 64` bytes.
+    pub fn apply_keystream(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        counter: u32,
+        in_out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let blocks = blocks(in_out.len());
+        if blocks > (1u64 << 32) - u64::from(counter) {
+            return Err(error::Unspecified);
+        }
+        let counter = Counter::from_nonce_and_ctr(Nonce::assume_unique_for_key(*nonce), counter);
+        self.0.encrypt_in_place(counter, in_out);
+        Ok(())
+    }
+
+    /// Encrypts or decrypts `in_out` in place, using the original variant with
+    /// the initial block counter `counter`.
+    ///
+    /// Fails, without modifying `in_out`, if the counter would overflow.
+    pub fn apply_keystream_legacy(
+        &self,
+        nonce: &[u8; LEGACY_NONCE_LEN],
+        counter: u64,
+        mut in_out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        if u128::from(counter) + u128::from(blocks(in_out.len())) > 1u128 << 64 {
+            return Err(error::Unspecified);
+        }
+
+        // The implementation has a 32-bit counter that is followed by the
+        // 96-bit nonce, so the high half of the counter is treated as part of
+        // the nonce, and the input is split where the low half wraps.
+        let mut counter = counter;
+        loop {
+            let low = counter as u32;
+            let high = (counter >> 32) as u32;
+            let mut nonce_and_high = [0u8; NONCE_LEN];
+            nonce_and_high[..4].copy_from_slice(&high.to_le_bytes());
+            nonce_and_high[4..].copy_from_slice(nonce);
+
+            let max_len = ((1u64 << 32) - u64::from(low)) * polyfill::u64_from_usize(BLOCK_LEN);
+            let len = match usize::try_from(max_len) {
+                Ok(max_len) if max_len < in_out.len() => max_len,
+                _ => in_out.len(),
+            };
+            let (chunk, rest) = in_out.split_at_mut(len);
+            self.apply_keystream(&nonce_and_high, low, chunk)?;
+            if rest.is_empty() {
+                return Ok(());
+            }
+            in_out = rest;
+            counter += blocks(len);
+        }
+    }
+}
+
+fn blocks(len: usize) -> u64 {
+    let len = polyfill::u64_from_usize(len);
+    let block_len = polyfill::u64_from_usize(BLOCK_LEN);
+    let partial = len % block_len;
+    len / block_len + u64::from(partial != 0)
+}
@@ -0,0 +1,207 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-CMAC, as specified in [RFC 4493] and [NIST SP 800-38B].
+//!
+//! The API mirrors that of `hmac`: use `sign` and `verify` when the input is
+//! in one contiguous slice, and `Context` otherwise.
+//!
+//! ```
+//! use ring::cmac;
+//!
+//! let key = cmac::Key::new(&cmac::AES_128, &[1; 16]).unwrap();
+//! let tag = cmac::sign(&key, b"hello, world");
+//!
+//! let mut ctx = cmac::Context::with_key(&key);
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! assert_eq!(ctx.sign().as_ref(), tag.as_ref());
+//!
+//! cmac::verify(&key, b"hello, world", tag.as_ref()).unwrap();
+//! ```
+//!
+//! [RFC 4493]: https://tools.ietf.org/html/rfc4493
+//! [NIST SP 800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+
+use crate::{
+    aead::{aes, block::BLOCK_LEN, cmac},
+    constant_time, cpu, error,
+};
+
+/// The length of a CMAC tag.
+pub const TAG_LEN: usize = BLOCK_LEN;
+
+/// A CMAC algorithm.
+pub struct Algorithm {
+    variant: aes::Variant,
+    key_len: usize,
+    id: AlgorithmID,
+}
+
+impl Algorithm {
+    /// The length of the key.
+    #[inline(always)]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    AES_128,
+    AES_256,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+/// AES-CMAC with a 128-bit key.
+pub static AES_128: Algorithm = Algorithm {
+    variant: aes::Variant::AES_128,
+    key_len: 16,
+    id: AlgorithmID::AES_128,
+};
+
+/// AES-CMAC with a 256-bit key.
+pub static AES_256: Algorithm = Algorithm {
+    variant: aes::Variant::AES_256,
+    key_len: 32,
+    id: AlgorithmID::AES_256,
+};
+
+/// A CMAC tag.
+///
+/// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct Tag([u8; TAG_LEN]);
+
+impl AsRef<[u8]> for Tag {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A key to use for CMAC signing.
+#[derive(Clone)]
+pub struct Key {
+    inner: cmac::Key,
+    algorithm: &'static Algorithm,
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+impl Key {
+    /// Constructs a CMAC key.
+    ///
+    /// `key_value` must be exactly `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_value: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let aes_key = aes::Key::new(key_value, algorithm.variant, cpu::features())?;
+        Ok(Self {
+            inner: cmac::Key::new(aes_key),
+            algorithm,
+        })
+    }
+
+    /// The key's algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl core::borrow::Borrow<cmac::Key> for Key {
+    fn borrow(&self) -> &cmac::Key {
+        &self.inner
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) CMAC signing.
+///
+/// Use `sign` for single-step CMAC signing.
+#[derive(Clone)]
+pub struct Context {
+    inner: cmac::Context<Key>,
+    algorithm: &'static Algorithm,
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Context")
+            .field("algorithm", self.algorithm)
+            .finish()
+    }
+}
+
+impl Context {
+    /// Constructs a new CMAC signing context using the given key.
+    pub fn with_key(signing_key: &Key) -> Self {
+        Self {
+            inner: cmac::Context::new(signing_key.clone()),
+            algorithm: signing_key.algorithm,
+        }
+    }
+
+    /// Updates the CMAC with all the data in `data`. `update` may be called
+    /// zero or more times until `sign` is called.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data)
+    }
+
+    /// Finalizes the CMAC calculation and returns the CMAC value. `sign`
+    /// consumes the context so it cannot be (mis-)used after `sign` has been
+    /// called.
+    ///
+    /// It is generally not safe to implement CMAC verification by comparing
+    /// the return value of `sign` to a tag. Use `verify` for verification
+    /// instead.
+    pub fn sign(self) -> Tag {
+        Tag(self.inner.finish())
+    }
+}
+
+/// Calculates the CMAC of `data` using the key `key` in one step.
+///
+/// Use `Context` to calculate CMACs where the input is in multiple parts.
+///
+/// It is generally not safe to implement CMAC verification by comparing the
+/// return value of `sign` to a tag. Use `verify` for verification instead.
+pub fn sign(key: &Key, data: &[u8]) -> Tag {
+    Tag(cmac::sign(&key.inner, data))
+}
+
+/// Calculates the CMAC of `data` using the key `key`, and verifies whether
+/// the resultant value equals `tag`, in one step.
+///
+/// The verification will be done in constant time to prevent timing attacks.
+pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
+}
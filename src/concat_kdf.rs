@@ -0,0 +1,103 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The hash-based one-step key derivation functions used with Diffie-Hellman
+//! key agreement: the one-step KDF of [NIST SP 800-56C Rev. 2] Section 4.1
+//! (also known as the Concat KDF, of e.g. JOSE and CMS), and the KDF of
+//! ANSI X9.63, as specified in [SEC 1] Section 3.6.1 (used by e.g. ECIES).
+//!
+//! Both use a shared secret `Z`, such as the output of a key agreement, and
+//! some context information (`FixedInfo` or `SharedInfo`), and they differ
+//! only in where the 32-bit big-endian block counter goes:
+//!
+//! * One-step KDF: `H(counter || Z || FixedInfo)`.
+//! * X9.63 KDF: `H(Z || counter || SharedInfo)`.
+//!
+//! ```
+//! use ring::{agreement, concat_kdf, digest, rand};
+//!
+//! let rng = rand::SystemRandom::new();
+//! let my_private_key = agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng)?;
+//! # let peer_private_key = agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng)?;
+//! # let peer_public_key = peer_private_key.compute_public_key()?;
+//! let peer_public_key =
+//!     agreement::UnparsedPublicKey::new(&agreement::ECDH_P256, peer_public_key.as_ref());
+//!
+//! let mut key = [0u8; 16];
+//! agreement::agree_ephemeral(my_private_key, &peer_public_key, |z| {
+//!     concat_kdf::derive(&digest::SHA256, z, &[b"other info"], &mut key)
+//! })??;
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [NIST SP 800-56C Rev. 2]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+//! [SEC 1]: https://www.secg.org/sec1-v2.pdf
+
+use crate::{digest, error};
+use core::convert::TryFrom;
+
+/// Fills `out` with the output of the one-step KDF of NIST SP 800-56C using
+/// the hash function `algorithm`, the shared secret `z`, and the
+/// concatenation of the parts of `fixed_info`.
+///
+/// Fails if `out` is longer than `algorithm.output_len * (2^32 - 1)` bytes.
+pub fn derive(
+    algorithm: &'static digest::Algorithm,
+    z: &[u8],
+    fixed_info: &[&[u8]],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    derive_(algorithm, out, |ctx, counter| {
+        ctx.update(counter);
+        ctx.update(z);
+        fixed_info.iter().for_each(|part| ctx.update(part));
+    })
+}
+
+/// Fills `out` with the output of the ANSI X9.63 KDF using the hash function
+/// `algorithm`, the shared secret `z`, and the concatenation of the parts of
+/// `shared_info`.
+///
+/// Fails if `out` is longer than `algorithm.output_len * (2^32 - 1)` bytes.
+pub fn derive_x963(
+    algorithm: &'static digest::Algorithm,
+    z: &[u8],
+    shared_info: &[&[u8]],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    derive_(algorithm, out, |ctx, counter| {
+        ctx.update(z);
+        ctx.update(counter);
+        shared_info.iter().for_each(|part| ctx.update(part));
+    })
+}
+
+fn derive_(
+    algorithm: &'static digest::Algorithm,
+    out: &mut [u8],
+    update: impl Fn(&mut digest::Context, &[u8]),
+) -> Result<(), error::Unspecified> {
+    let chunks = out.chunks_mut(algorithm.output_len);
+    // The counter starts at 1, so its largest value must fit.
+    if u32::try_from(chunks.len()).is_err() {
+        return Err(error::Unspecified);
+    }
+    for (counter, chunk) in (1u32..).zip(chunks) {
+        let mut ctx = digest::Context::new(algorithm);
+        update(&mut ctx, &counter.to_be_bytes());
+        let block = ctx.finish();
+        chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+    }
+    Ok(())
+}
@@ -12,7 +12,8 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-//! SHA-2 and the legacy SHA-1 digest algorithm.
+//! SHA-2, SHA-3, BLAKE2, BLAKE3, SM3, Ascon-Hash256, and the legacy SHA-1
+//! and (optionally) MD5 digest algorithms.
 //!
 //! If all the data is available in a single contiguous slice then the `digest`
 //! function should be used. Otherwise, the digest can be calculated in
@@ -27,13 +28,33 @@
 use crate::polyfill::array_map::Map;
 use crate::{
     c, cpu, debug,
-    endian::{ArrayEncoding, BigEndian},
-    polyfill,
+    endian::{ArrayEncoding, BigEndian, FromByteArray},
+    error, keccak, polyfill,
 };
 use core::num::Wrapping;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod ascon_hash256;
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod blake2;
+pub mod blake3;
+pub mod fixed;
+pub mod kangaroo_twelve;
+#[cfg(feature = "insecure_md5")]
+mod md5;
+pub mod merkle;
+mod saved_state;
 mod sha1;
 mod sha2;
+mod sha3;
+mod sm3;
+pub mod tuple_hash;
+pub mod xof;
+
+pub use saved_state::{ContextState, VERSION as CONTEXT_STATE_VERSION};
 
 #[derive(Clone)]
 pub(crate) struct BlockContext {
@@ -75,11 +96,34 @@ impl BlockContext {
         }
     }
 
-    pub(crate) fn finish(mut self, pending: &mut [u8], num_pending: usize) -> Digest {
+    pub(crate) fn finish(self, pending: &mut [u8], num_pending: usize) -> Digest {
         let block_len = self.algorithm.block_len;
         assert_eq!(pending.len(), block_len);
         assert!(num_pending <= pending.len());
 
+        let algorithm = self.algorithm;
+        Digest {
+            algorithm,
+            value: (algorithm.finish)(self, pending, num_pending),
+        }
+    }
+
+    /// Implements `Algorithm::finish` for the Merkle–Damgård algorithms
+    /// (SHA-1, SHA-2, and SM3): pads the input with a 1 bit, zeros, and the
+    /// input length in bits.
+    fn merkle_damgard_finish(self, pending: &mut [u8], num_pending: usize) -> Output {
+        self.merkle_damgard_finish_with(pending, num_pending, u64::to_be_bytes)
+    }
+
+    /// Like `merkle_damgard_finish`, but encoding the input length in bits
+    /// with `encode_len`.
+    fn merkle_damgard_finish_with(
+        mut self,
+        pending: &mut [u8],
+        num_pending: usize,
+        encode_len: fn(u64) -> [u8; 8],
+    ) -> Output {
+        let block_len = self.algorithm.block_len;
         let mut padding_pos = num_pending;
         pending[padding_pos] = 0x80;
         padding_pos += 1;
@@ -96,7 +140,7 @@ impl BlockContext {
 
         pending[padding_pos..(block_len - 8)].fill(0);
 
-        // Output the length, in bits, in big endian order.
+        // Output the length, in bits.
         let completed_data_bits = self
             .completed_data_blocks
             .checked_mul(polyfill::u64_from_usize(block_len))
@@ -105,16 +149,13 @@ impl BlockContext {
             .unwrap()
             .checked_mul(8)
             .unwrap();
-        pending[(block_len - 8)..block_len].copy_from_slice(&u64::to_be_bytes(completed_data_bits));
+        pending[(block_len - 8)..block_len].copy_from_slice(&encode_len(completed_data_bits));
 
         unsafe {
             (self.algorithm.block_data_order)(&mut self.state, pending.as_ptr(), 1);
         }
 
-        Digest {
-            algorithm: self.algorithm,
-            value: (self.algorithm.format_output)(self.state),
-        }
+        (self.algorithm.format_output)(self.state)
     }
 }
 
@@ -146,11 +187,7 @@ pub struct Context {
 impl Context {
     /// Constructs a new context.
     pub fn new(algorithm: &'static Algorithm) -> Self {
-        Self {
-            block: BlockContext::new(algorithm),
-            pending: [0u8; MAX_BLOCK_LEN],
-            num_pending: 0,
-        }
+        Self::clone_from(&BlockContext::new(algorithm))
     }
 
     pub(crate) fn clone_from(block: &BlockContext) -> Self {
@@ -200,11 +237,119 @@ impl Context {
             .finish(&mut self.pending[..block_len], self.num_pending)
     }
 
+    /// Finalizes the digest calculation, returns the digest value, and resets
+    /// the context to the state of `Context::new(self.algorithm())`, so that
+    /// it can be used to calculate another digest.
+    ///
+    /// Use `blake2::Context::finish_and_reset` to keep the key of a keyed
+    /// BLAKE2 context.
+    ///
+    /// ```
+    /// use ring::digest;
+    ///
+    /// let mut ctx = digest::Context::new(&digest::SHA256);
+    /// for message in [&b"hello"[..], &b"world"[..]].iter() {
+    ///     ctx.update(message);
+    ///     let expected = digest::digest(&digest::SHA256, message);
+    ///     assert_eq!(ctx.finish_and_reset().as_ref(), expected.as_ref());
+    /// }
+    /// ```
+    pub fn finish_and_reset(&mut self) -> Digest {
+        let reset = Self::new(self.algorithm());
+        core::mem::replace(self, reset).finish()
+    }
+
     /// The algorithm that this context is using.
     #[inline(always)]
     pub fn algorithm(&self) -> &'static Algorithm {
         self.block.algorithm
     }
+
+    /// Saves the state of the context, so that the calculation can be resumed
+    /// later, possibly in another process, with `Context::from_state`.
+    ///
+    /// ```
+    /// use ring::digest;
+    ///
+    /// let mut ctx = digest::Context::new(&digest::SHA256);
+    /// ctx.update(b"hello, ");
+    /// let saved = ctx.state();
+    ///
+    /// let mut resumed = digest::Context::from_state(saved.as_ref()).unwrap();
+    /// resumed.update(b"world");
+    ///
+    /// let expected = digest::digest(&digest::SHA256, b"hello, world");
+    /// assert_eq!(resumed.finish().as_ref(), expected.as_ref());
+    /// ```
+    ///
+    /// The encoding is:
+    ///
+    /// * One byte: the version of the encoding, `CONTEXT_STATE_VERSION`,
+    ///   which is currently 1.
+    /// * One byte: the algorithm. 1: SHA-1, 2: SHA-224, 3: SHA-256,
+    ///   4: SHA-384, 5: SHA-512, 6: SHA-512/224, 7: SHA-512/256, 8: SHA3-224,
+    ///   9: SHA3-256, 10: SHA3-384, 11: SHA3-512, 12: BLAKE2b-256,
+    ///   13: BLAKE2b-512, 14: BLAKE2s-256, 15: SM3, 16: Ascon-Hash256,
+    ///   17: MD5.
+    /// * Eight bytes: the number of complete blocks of input processed, as a
+    ///   big-endian integer.
+    /// * The chaining state, which depends on the algorithm:
+    ///   - MD5, SHA-1, SHA-224, SHA-256, and SM3: eight 32-bit big-endian
+    ///     words, of which MD5 uses the first four and SHA-1 the first five.
+    ///   - SHA-384, SHA-512, SHA-512/224, SHA-512/256, and Ascon-Hash256:
+    ///     eight 64-bit big-endian words, of which Ascon-Hash256 uses the
+    ///     first five.
+    ///   - SHA-3: the 25 lanes of the Keccak state, as 64-bit little-endian
+    ///     words.
+    ///   - BLAKE2b: eight 64-bit little-endian words, then the most recent
+    ///     complete block of input (128 bytes), which BLAKE2 doesn't process
+    ///     until it knows whether the block is the last one.
+    ///   - BLAKE2s: likewise, with eight 32-bit little-endian words and a
+    ///     64-byte block.
+    /// * One byte: the length of the input that hasn't been processed yet,
+    ///   which is less than `algorithm().block_len`.
+    /// * That input.
+    ///
+    /// The state contains some of the input, so it must be protected
+    /// like the input itself.
+    pub fn state(&self) -> ContextState {
+        saved_state::encode(self)
+    }
+
+    /// Resumes a calculation from a state saved with `Context::state`.
+    ///
+    /// Fails if `state` isn't a valid encoding of a context's state, including
+    /// when it was saved by a version of *ring* that uses a newer encoding.
+    pub fn from_state(state: &[u8]) -> Result<Self, error::Unspecified> {
+        saved_state::decode(state)
+    }
+}
+
+/// Writing to a `Context` updates the digest with the written data, so a
+/// `Context` can be used with `std::io::copy` and anything else that writes
+/// to an `std::io::Write`.
+///
+/// ```
+/// use ring::digest;
+///
+/// let mut ctx = digest::Context::new(&digest::SHA256);
+/// std::io::copy(&mut &b"hello, world"[..], &mut ctx).unwrap();
+///
+/// let expected = digest::digest(&digest::SHA256, b"hello, world");
+/// assert_eq!(ctx.finish().as_ref(), expected.as_ref());
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Write for Context {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 /// Returns the digest of `data` using the given digest algorithm.
@@ -228,6 +373,38 @@ pub fn digest(algorithm: &'static Algorithm, data: &[u8]) -> Digest {
     ctx.finish()
 }
 
+/// Returns the digest of all the data read from `reader` using the given
+/// digest algorithm.
+///
+/// `reader` is read until it reports the end of its input. Reads that fail
+/// with `std::io::ErrorKind::Interrupted` are retried; any other error is
+/// returned.
+///
+/// ```
+/// use ring::digest;
+///
+/// let actual = digest::digest_reader(&digest::SHA256, &b"hello, world"[..]).unwrap();
+///
+/// let expected = digest::digest(&digest::SHA256, b"hello, world");
+/// assert_eq!(actual.as_ref(), expected.as_ref());
+/// ```
+#[cfg(feature = "std")]
+pub fn digest_reader(
+    algorithm: &'static Algorithm,
+    mut reader: impl std::io::Read,
+) -> std::io::Result<Digest> {
+    let mut ctx = Context::new(algorithm);
+    let mut buf = [0u8; 16 * 1024];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(ctx.finish()),
+            Ok(n) => ctx.update(&buf[..n]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 /// A calculated digest value.
 ///
 /// Use `as_ref` to get the value as a `&[u8]`.
@@ -238,6 +415,30 @@ pub struct Digest {
 }
 
 impl Digest {
+    /// Constructs a `Digest` from a digest value that was calculated
+    /// elsewhere, e.g. by another library or a hardware security module, so
+    /// that it can be passed to functions that take a precomputed digest.
+    ///
+    /// Fails if `value` isn't exactly `algorithm.output_len` bytes long. It is
+    /// up to the caller to ensure that `value` really is the `algorithm`
+    /// digest of the intended message.
+    pub fn from_bytes(
+        algorithm: &'static Algorithm,
+        value: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if value.len() != algorithm.output_len {
+            return Err(error::Unspecified);
+        }
+        let mut bytes = [0u8; MAX_OUTPUT_LEN];
+        bytes[..value.len()].copy_from_slice(value);
+        Ok(Self {
+            value: Output {
+                as64: FromByteArray::from_byte_array(&bytes),
+            },
+            algorithm,
+        })
+    }
+
     /// The algorithm that was used to calculate the digest value.
     #[inline(always)]
     pub fn algorithm(&self) -> &'static Algorithm {
@@ -282,6 +483,11 @@ pub struct Algorithm {
     block_data_order: unsafe extern "C" fn(state: &mut State, data: *const u8, num: c::size_t),
     format_output: fn(input: State) -> Output,
 
+    /// Pads and processes the final `num_pending` bytes of input, which are
+    /// at the start of `pending`, and returns the output. `pending` is
+    /// `block_len` bytes long and may be used as scratch space.
+    finish: fn(block: BlockContext, pending: &mut [u8], num_pending: usize) -> Output,
+
     initial_state: State,
 
     id: AlgorithmID,
@@ -289,11 +495,24 @@ pub struct Algorithm {
 
 #[derive(Debug, Eq, PartialEq)]
 enum AlgorithmID {
+    #[cfg(feature = "insecure_md5")]
+    MD5,
     SHA1,
+    SHA224,
     SHA256,
     SHA384,
     SHA512,
+    SHA512_224,
     SHA512_256,
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+    BLAKE2B_256,
+    BLAKE2B_512,
+    BLAKE2S_256,
+    SM3,
+    ASCON_HASH256,
 }
 
 impl PartialEq for Algorithm {
@@ -306,6 +525,39 @@ impl Eq for Algorithm {}
 
 derive_debug_via_id!(Algorithm);
 
+/// MD5 as specified in [RFC 1321]. Broken; only available with the
+/// `insecure_md5` feature.
+///
+/// MD5 isn't collision resistant, and chosen-prefix collisions can be found
+/// in seconds, so it must not be used for anything security-sensitive. It is
+/// provided only for interoperability with legacy protocols and formats that
+/// use it as a checksum.
+///
+/// [RFC 1321]: https://tools.ietf.org/html/rfc1321
+#[cfg(feature = "insecure_md5")]
+pub static MD5_FOR_LEGACY_USE_ONLY: Algorithm = Algorithm {
+    output_len: md5::OUTPUT_LEN,
+    chaining_len: md5::CHAINING_LEN,
+    block_len: md5::BLOCK_LEN,
+    len_len: 64 / 8,
+    block_data_order: md5::block_data_order,
+    format_output: md5::format_output,
+    finish: md5::finish,
+    initial_state: State {
+        as32: [
+            Wrapping(0x67452301u32),
+            Wrapping(0xefcdab89u32),
+            Wrapping(0x98badcfeu32),
+            Wrapping(0x10325476u32),
+            Wrapping(0),
+            Wrapping(0),
+            Wrapping(0),
+            Wrapping(0),
+        ],
+    },
+    id: AlgorithmID::MD5,
+};
+
 /// SHA-1 as specified in [FIPS 180-4]. Deprecated.
 ///
 /// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
@@ -316,6 +568,7 @@ pub static SHA1_FOR_LEGACY_USE_ONLY: Algorithm = Algorithm {
     len_len: 64 / 8,
     block_data_order: sha1::block_data_order,
     format_output: sha256_format_output,
+    finish: BlockContext::merkle_damgard_finish,
     initial_state: State {
         as32: [
             Wrapping(0x67452301u32),
@@ -331,6 +584,32 @@ pub static SHA1_FOR_LEGACY_USE_ONLY: Algorithm = Algorithm {
     id: AlgorithmID::SHA1,
 };
 
+/// SHA-224 as specified in [FIPS 180-4].
+///
+/// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+pub static SHA224: Algorithm = Algorithm {
+    output_len: SHA224_OUTPUT_LEN,
+    chaining_len: SHA256_OUTPUT_LEN,
+    block_len: 512 / 8,
+    len_len: 64 / 8,
+    block_data_order: sha2::sha256_block_data_order,
+    format_output: sha256_format_output,
+    finish: BlockContext::merkle_damgard_finish,
+    initial_state: State {
+        as32: [
+            Wrapping(0xc1059ed8u32),
+            Wrapping(0x367cd507u32),
+            Wrapping(0x3070dd17u32),
+            Wrapping(0xf70e5939u32),
+            Wrapping(0xffc00b31u32),
+            Wrapping(0x68581511u32),
+            Wrapping(0x64f98fa7u32),
+            Wrapping(0xbefa4fa4u32),
+        ],
+    },
+    id: AlgorithmID::SHA224,
+};
+
 /// SHA-256 as specified in [FIPS 180-4].
 ///
 /// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
@@ -341,6 +620,7 @@ pub static SHA256: Algorithm = Algorithm {
     len_len: 64 / 8,
     block_data_order: sha2::sha256_block_data_order,
     format_output: sha256_format_output,
+    finish: BlockContext::merkle_damgard_finish,
     initial_state: State {
         as32: [
             Wrapping(0x6a09e667u32),
@@ -366,6 +646,7 @@ pub static SHA384: Algorithm = Algorithm {
     len_len: SHA512_LEN_LEN,
     block_data_order: sha2::sha512_block_data_order,
     format_output: sha512_format_output,
+    finish: BlockContext::merkle_damgard_finish,
     initial_state: State {
         as64: [
             Wrapping(0xcbbb9d5dc1059ed8),
@@ -391,6 +672,7 @@ pub static SHA512: Algorithm = Algorithm {
     len_len: SHA512_LEN_LEN,
     block_data_order: sha2::sha512_block_data_order,
     format_output: sha512_format_output,
+    finish: BlockContext::merkle_damgard_finish,
     initial_state: State {
         as64: [
             Wrapping(0x6a09e667f3bcc908),
@@ -406,6 +688,36 @@ pub static SHA512: Algorithm = Algorithm {
     id: AlgorithmID::SHA512,
 };
 
+/// SHA-512/224 as specified in [FIPS 180-4].
+///
+/// This is *not* the same as just truncating the output of SHA-512, as
+/// SHA-512/224 has its own initial state distinct from SHA-512's initial
+/// state.
+///
+/// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+pub static SHA512_224: Algorithm = Algorithm {
+    output_len: SHA512_224_OUTPUT_LEN,
+    chaining_len: SHA512_OUTPUT_LEN,
+    block_len: SHA512_BLOCK_LEN,
+    len_len: SHA512_LEN_LEN,
+    block_data_order: sha2::sha512_block_data_order,
+    format_output: sha512_format_output,
+    finish: BlockContext::merkle_damgard_finish,
+    initial_state: State {
+        as64: [
+            Wrapping(0x8c3d37c819544da2),
+            Wrapping(0x73e1996689dcd4d6),
+            Wrapping(0x1dfab7ae32ff9c82),
+            Wrapping(0x679dd514582f9fcf),
+            Wrapping(0x0f6d2b697bd44da8),
+            Wrapping(0x77e36f7304c48942),
+            Wrapping(0x3f9d85a86a1d36c8),
+            Wrapping(0x1112e6ad91d692a1),
+        ],
+    },
+    id: AlgorithmID::SHA512_224,
+};
+
 /// SHA-512/256 as specified in [FIPS 180-4].
 ///
 /// This is *not* the same as just truncating the output of SHA-512, as
@@ -420,6 +732,7 @@ pub static SHA512_256: Algorithm = Algorithm {
     len_len: SHA512_LEN_LEN,
     block_data_order: sha2::sha512_block_data_order,
     format_output: sha512_format_output,
+    finish: BlockContext::merkle_damgard_finish,
     initial_state: State {
         as64: [
             Wrapping(0x22312194fc2bf72c),
@@ -435,11 +748,146 @@ pub static SHA512_256: Algorithm = Algorithm {
     id: AlgorithmID::SHA512_256,
 };
 
+macro_rules! sha3 {
+    ( $name:ident, $output_len:expr, $block_len:expr, $doc:expr ) => {
+        #[doc = $doc]
+        ///
+        /// [FIPS 202]: https://doi.org/10.6028/NIST.FIPS.202
+        pub static $name: Algorithm = Algorithm {
+            output_len: $output_len,
+            chaining_len: $output_len,
+            block_len: $block_len,
+            len_len: 0,
+            block_data_order: sha3::block_data_order::<$block_len>,
+            format_output: sha3::format_output,
+            finish: sha3::finish,
+            initial_state: sha3::INITIAL_STATE,
+            id: AlgorithmID::$name,
+        };
+    };
+}
+
+sha3!(
+    SHA3_224,
+    SHA3_224_OUTPUT_LEN,
+    { sha3::SHA3_224_BLOCK_LEN },
+    "SHA3-224 as specified in [FIPS 202]."
+);
+sha3!(
+    SHA3_256,
+    SHA3_256_OUTPUT_LEN,
+    { sha3::SHA3_256_BLOCK_LEN },
+    "SHA3-256 as specified in [FIPS 202]."
+);
+sha3!(
+    SHA3_384,
+    SHA3_384_OUTPUT_LEN,
+    { sha3::SHA3_384_BLOCK_LEN },
+    "SHA3-384 as specified in [FIPS 202]."
+);
+sha3!(
+    SHA3_512,
+    SHA3_512_OUTPUT_LEN,
+    { sha3::SHA3_512_BLOCK_LEN },
+    "SHA3-512 as specified in [FIPS 202]."
+);
+
+/// BLAKE2b with a 256-bit output, as specified in [RFC 7693].
+///
+/// This is the default of libsodium's `crypto_generichash`. See the
+/// `blake2` module for the keyed mode.
+///
+/// [RFC 7693]: https://tools.ietf.org/html/rfc7693
+pub static BLAKE2B_256: Algorithm = Algorithm {
+    output_len: BLAKE2B_256_OUTPUT_LEN,
+    chaining_len: BLAKE2B_512_OUTPUT_LEN,
+    block_len: blake2::BLAKE2B_BLOCK_LEN,
+    len_len: 0,
+    block_data_order: blake2::blake2b_block_data_order,
+    format_output: blake2::blake2b_format_output,
+    finish: blake2::blake2b_finish,
+    initial_state: blake2::blake2b_initial_state(BLAKE2B_256_OUTPUT_LEN),
+    id: AlgorithmID::BLAKE2B_256,
+};
+
+/// BLAKE2b with a 512-bit output, as specified in [RFC 7693].
+///
+/// See the `blake2` module for the keyed mode.
+///
+/// [RFC 7693]: https://tools.ietf.org/html/rfc7693
+pub static BLAKE2B_512: Algorithm = Algorithm {
+    output_len: BLAKE2B_512_OUTPUT_LEN,
+    chaining_len: BLAKE2B_512_OUTPUT_LEN,
+    block_len: blake2::BLAKE2B_BLOCK_LEN,
+    len_len: 0,
+    block_data_order: blake2::blake2b_block_data_order,
+    format_output: blake2::blake2b_format_output,
+    finish: blake2::blake2b_finish,
+    initial_state: blake2::blake2b_initial_state(BLAKE2B_512_OUTPUT_LEN),
+    id: AlgorithmID::BLAKE2B_512,
+};
+
+/// BLAKE2s with a 256-bit output, as specified in [RFC 7693].
+///
+/// See the `blake2` module for the keyed mode.
+///
+/// [RFC 7693]: https://tools.ietf.org/html/rfc7693
+pub static BLAKE2S_256: Algorithm = Algorithm {
+    output_len: BLAKE2S_256_OUTPUT_LEN,
+    chaining_len: BLAKE2S_256_OUTPUT_LEN,
+    block_len: blake2::BLAKE2S_BLOCK_LEN,
+    len_len: 0,
+    block_data_order: blake2::blake2s_block_data_order,
+    format_output: blake2::blake2s_format_output,
+    finish: blake2::blake2s_finish,
+    initial_state: blake2::blake2s_initial_state(BLAKE2S_256_OUTPUT_LEN),
+    id: AlgorithmID::BLAKE2S_256,
+};
+
+/// SM3 as specified in GB/T 32905-2016.
+///
+/// The same algorithm is also specified in ISO/IEC 10118-3:2018 and, in
+/// English, in [draft-sca-cfrg-sm3].
+///
+/// [draft-sca-cfrg-sm3]: https://datatracker.ietf.org/doc/html/draft-sca-cfrg-sm3
+pub static SM3: Algorithm = Algorithm {
+    output_len: SM3_OUTPUT_LEN,
+    chaining_len: SM3_OUTPUT_LEN,
+    block_len: sm3::BLOCK_LEN,
+    len_len: 64 / 8,
+    block_data_order: sm3::block_data_order,
+    format_output: sha256_format_output,
+    finish: BlockContext::merkle_damgard_finish,
+    initial_state: sm3::INITIAL_STATE,
+    id: AlgorithmID::SM3,
+};
+
+/// Ascon-Hash256 as specified in [NIST SP 800-232].
+///
+/// Like the Ascon-AEAD128 AEAD, Ascon-Hash256 is designed to be small and
+/// fast on constrained devices.
+///
+/// [NIST SP 800-232]: https://doi.org/10.6028/NIST.SP.800-232
+pub static ASCON_HASH256: Algorithm = Algorithm {
+    output_len: ASCON_HASH256_OUTPUT_LEN,
+    chaining_len: ASCON_HASH256_OUTPUT_LEN,
+    block_len: ascon_hash256::BLOCK_LEN,
+    len_len: 0,
+    block_data_order: ascon_hash256::block_data_order,
+    format_output: ascon_hash256::format_output,
+    finish: ascon_hash256::finish,
+    initial_state: ascon_hash256::INITIAL_STATE,
+    id: AlgorithmID::ASCON_HASH256,
+};
+
 #[derive(Clone, Copy)] // XXX: Why do we need to be `Copy`?
 #[repr(C)]
 union State {
     as64: [Wrapping<u64>; sha2::CHAINING_WORDS],
     as32: [Wrapping<u32>; sha2::CHAINING_WORDS],
+    keccak: keccak::State,
+    blake2b: blake2::State<u64, { blake2::BLAKE2B_BLOCK_LEN }>,
+    blake2s: blake2::State<u32, { blake2::BLAKE2S_BLOCK_LEN }>,
 }
 
 #[derive(Clone, Copy)]
@@ -451,7 +899,7 @@ union Output {
 
 /// The maximum block length (`Algorithm::block_len`) of all the algorithms in
 /// this module.
-pub const MAX_BLOCK_LEN: usize = 1024 / 8;
+pub const MAX_BLOCK_LEN: usize = sha3::SHA3_224_BLOCK_LEN;
 
 /// The maximum output length (`Algorithm::output_len`) of all the algorithms
 /// in this module.
@@ -475,9 +923,16 @@ fn sha512_format_output(input: State) -> Output {
     }
 }
 
+/// The length of the output of MD5, in bytes.
+#[cfg(feature = "insecure_md5")]
+pub const MD5_OUTPUT_LEN: usize = md5::OUTPUT_LEN;
+
 /// The length of the output of SHA-1, in bytes.
 pub const SHA1_OUTPUT_LEN: usize = sha1::OUTPUT_LEN;
 
+/// The length of the output of SHA-224, in bytes.
+pub const SHA224_OUTPUT_LEN: usize = 224 / 8;
+
 /// The length of the output of SHA-256, in bytes.
 pub const SHA256_OUTPUT_LEN: usize = 256 / 8;
 
@@ -487,9 +942,39 @@ pub const SHA384_OUTPUT_LEN: usize = 384 / 8;
 /// The length of the output of SHA-512, in bytes.
 pub const SHA512_OUTPUT_LEN: usize = 512 / 8;
 
+/// The length of the output of SHA-512/224, in bytes.
+pub const SHA512_224_OUTPUT_LEN: usize = 224 / 8;
+
 /// The length of the output of SHA-512/256, in bytes.
 pub const SHA512_256_OUTPUT_LEN: usize = 256 / 8;
 
+/// The length of the output of SHA3-224, in bytes.
+pub const SHA3_224_OUTPUT_LEN: usize = 224 / 8;
+
+/// The length of the output of SHA3-256, in bytes.
+pub const SHA3_256_OUTPUT_LEN: usize = 256 / 8;
+
+/// The length of the output of SHA3-384, in bytes.
+pub const SHA3_384_OUTPUT_LEN: usize = 384 / 8;
+
+/// The length of the output of SHA3-512, in bytes.
+pub const SHA3_512_OUTPUT_LEN: usize = 512 / 8;
+
+/// The length of the output of BLAKE2b-256, in bytes.
+pub const BLAKE2B_256_OUTPUT_LEN: usize = 256 / 8;
+
+/// The length of the output of BLAKE2b-512, in bytes.
+pub const BLAKE2B_512_OUTPUT_LEN: usize = 512 / 8;
+
+/// The length of the output of BLAKE2s-256, in bytes.
+pub const BLAKE2S_256_OUTPUT_LEN: usize = 256 / 8;
+
+/// The length of the output of SM3, in bytes.
+pub const SM3_OUTPUT_LEN: usize = sm3::OUTPUT_LEN;
+
+/// The length of the output of Ascon-Hash256, in bytes.
+pub const ASCON_HASH256_OUTPUT_LEN: usize = 256 / 8;
+
 /// The length of a block for SHA-512-based algorithms, in bytes.
 const SHA512_BLOCK_LEN: usize = 1024 / 8;
 
@@ -569,6 +1054,8 @@ mod tests {
             }
         }
 
+        #[cfg(feature = "insecure_md5")]
+        max_input_tests!(MD5_FOR_LEGACY_USE_ONLY);
         max_input_tests!(SHA1_FOR_LEGACY_USE_ONLY);
         max_input_tests!(SHA256);
         max_input_tests!(SHA384);
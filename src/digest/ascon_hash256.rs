@@ -0,0 +1,107 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Ascon-Hash256, as specified in [NIST SP 800-232] Section 5.1.
+//!
+//! The sponge's 64-bit rate is the block length; the first five words of
+//! `State::as64` hold the Ascon state.
+//!
+//! [NIST SP 800-232]: https://doi.org/10.6028/NIST.SP.800-232
+
+use super::{BlockContext, Output, State};
+use crate::{ascon, c, endian::BigEndian};
+use core::{convert::TryInto, num::Wrapping};
+
+pub const BLOCK_LEN: usize = 64 / 8;
+const OUTPUT_WORDS: usize = 256 / 64;
+
+/// Ascon-p[12] applied to IV || 0^256, where IV = 0x0000080100cc0002.
+pub const INITIAL_STATE: State = State {
+    as64: [
+        Wrapping(0x9b1e5494e934d681),
+        Wrapping(0x4bc3a01e333751d2),
+        Wrapping(0xae65396c6b34b81a),
+        Wrapping(0x3c7fd4a4d56a4db3),
+        Wrapping(0x1a5c464906c5976d),
+        Wrapping(0),
+        Wrapping(0),
+        Wrapping(0),
+    ],
+};
+
+fn load(state: &State) -> ascon::State {
+    let state = unsafe { &state.as64 };
+    [state[0].0, state[1].0, state[2].0, state[3].0, state[4].0]
+}
+
+fn store(s: ascon::State, state: &mut State) {
+    let state = unsafe { &mut state.as64 };
+    for (w, s) in state.iter_mut().zip(s.iter()) {
+        *w = Wrapping(*s);
+    }
+}
+
+fn absorb(s: &mut ascon::State, block: &[u8]) {
+    s[0] ^= u64::from_le_bytes(block.try_into().unwrap());
+}
+
+pub(super) extern "C" fn block_data_order(state: &mut State, data: *const u8, num: c::size_t) {
+    let data = unsafe { core::slice::from_raw_parts(data, num * BLOCK_LEN) };
+    let mut s = load(state);
+    for block in data.chunks_exact(BLOCK_LEN) {
+        absorb(&mut s, block);
+        ascon::permute(&mut s, 12);
+    }
+    store(s, state);
+}
+
+pub(super) fn finish(ctx: BlockContext, pending: &mut [u8], num_pending: usize) -> Output {
+    // Pad with a 1 bit and then zeros. NIST SP 800-232 maps bytes to words in
+    // little-endian order so the 1 bit is the low bit of the next byte.
+    pending[num_pending] = 0x01;
+    pending[(num_pending + 1)..].fill(0);
+    let mut s = load(&ctx.state);
+    absorb(&mut s, pending);
+    let mut state = ctx.state;
+    store(s, &mut state);
+    (ctx.algorithm.format_output)(state)
+}
+
+/// Squeezes the output from the state after the last block was absorbed.
+pub(super) fn format_output(state: State) -> Output {
+    let mut s = load(&state);
+    let mut output = Output {
+        as64: [BigEndian::from(0); 512 / 64],
+    };
+    let words = unsafe { &mut output.as64 };
+    for w in words[..OUTPUT_WORDS].iter_mut() {
+        ascon::permute(&mut s, 12);
+        // `Output` is serialized in big-endian order but Ascon's output is
+        // little-endian.
+        *w = BigEndian::from(s[0].swap_bytes());
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ascon;
+
+    #[test]
+    fn test_initial_state() {
+        let mut s = [0x0000_0801_00cc_0002, 0, 0, 0, 0];
+        ascon::permute(&mut s, 12);
+        assert_eq!(s, super::load(&super::INITIAL_STATE));
+    }
+}
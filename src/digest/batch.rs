@@ -0,0 +1,255 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Hashing many independent messages at once.
+//!
+//! Hashing a batch of short messages one at a time leaves most of the
+//! throughput of a SIMD unit unused, because each message's compression
+//! function calls depend on each other. `digest_all` instead hashes up to
+//! eight messages at once, one per SIMD lane, when the CPU supports it.
+//!
+//! Currently SHA-256 and SHA-224 are accelerated on x86-64 CPUs that support
+//! AVX2. For other algorithms and CPUs, the messages are hashed one at a time.
+//!
+//! ```
+//! use ring::digest::{self, batch};
+//!
+//! let messages: [&[u8]; 3] = [b"a", b"bc", b"def"];
+//! let digests = batch::digest_all(&digest::SHA256, &messages);
+//! for (message, d) in messages.iter().zip(digests.iter()) {
+//!     assert_eq!(d.as_ref(), digest::digest(&digest::SHA256, message).as_ref());
+//! }
+//! ```
+
+use super::{digest, Algorithm, Digest};
+use alloc::vec::Vec;
+
+/// Returns the digests of each of `messages`, in the same order, using the
+/// given digest algorithm.
+pub fn digest_all(algorithm: &'static Algorithm, messages: &[&[u8]]) -> Vec<Digest> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use super::AlgorithmID;
+        use crate::cpu;
+
+        if matches!(algorithm.id, AlgorithmID::SHA224 | AlgorithmID::SHA256)
+            && messages.len() > 1
+            && cpu::intel::AVX2.available(cpu::features())
+        {
+            return sha256_avx2::digest_all(algorithm, messages);
+        }
+    }
+
+    messages.iter().map(|m| digest(algorithm, m)).collect()
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sha256_avx2 {
+    use super::super::{sha2, sha256_format_output, Algorithm, Digest, State};
+    use crate::polyfill;
+    use alloc::vec::Vec;
+    use core::{arch::x86_64::*, convert::TryInto, num::Wrapping};
+
+    const LANES: usize = 8;
+    const BLOCK_LEN: usize = 512 / 8;
+
+    type LaneState = [Wrapping<u32>; 8];
+
+    pub(super) fn digest_all(algorithm: &'static Algorithm, messages: &[&[u8]]) -> Vec<Digest> {
+        let initial = unsafe { algorithm.initial_state.as32 };
+
+        // Hash messages of similar lengths together so that few lanes sit
+        // idle while the others process their last blocks.
+        let mut order = (0..messages.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| messages[i].len());
+
+        let mut states = alloc::vec![initial; messages.len()];
+        for group in order.chunks(LANES) {
+            let mut lanes: [&[u8]; LANES] = [&[]; LANES];
+            for (lane, &i) in lanes.iter_mut().zip(group.iter()) {
+                *lane = messages[i];
+            }
+            // The caller verified that AVX2 is available.
+            let results = unsafe { hash_lanes(&initial, &lanes) };
+            for (&i, result) in group.iter().zip(results.iter()) {
+                states[i] = *result;
+            }
+        }
+
+        states
+            .into_iter()
+            .map(|as32| Digest {
+                algorithm,
+                value: sha256_format_output(State { as32 }),
+            })
+            .collect()
+    }
+
+    /// The number of blocks in `len` bytes of input after padding.
+    fn num_blocks(len: usize) -> usize {
+        (len + 8) / BLOCK_LEN + 1
+    }
+
+    /// Returns block `i` of the padded message `msg`, which has `n` blocks in
+    /// total, using `buf` for the blocks that contain padding.
+    fn padded_block<'a>(
+        msg: &'a [u8],
+        i: usize,
+        n: usize,
+        buf: &'a mut [u8; BLOCK_LEN],
+    ) -> &'a [u8] {
+        let start = i * BLOCK_LEN;
+        if start + BLOCK_LEN <= msg.len() {
+            return &msg[start..][..BLOCK_LEN];
+        }
+        *buf = [0; BLOCK_LEN];
+        if start <= msg.len() {
+            let rest = &msg[start..];
+            buf[..rest.len()].copy_from_slice(rest);
+            buf[rest.len()] = 0x80;
+        }
+        if i + 1 == n {
+            let len_bits = polyfill::u64_from_usize(msg.len()).checked_mul(8).unwrap();
+            buf[(BLOCK_LEN - 8)..].copy_from_slice(&len_bits.to_be_bytes());
+        }
+        buf
+    }
+
+    macro_rules! add {
+        ( $a:expr, $b:expr ) => { _mm256_add_epi32($a, $b) };
+        ( $a:expr, $b:expr, $( $rest:expr ),+ ) => { add!(_mm256_add_epi32($a, $b), $( $rest ),+) };
+    }
+
+    macro_rules! xor {
+        ( $a:expr, $b:expr ) => {
+            _mm256_xor_si256($a, $b)
+        };
+        ( $a:expr, $b:expr, $c:expr ) => {
+            xor!(xor!($a, $b), $c)
+        };
+    }
+
+    macro_rules! rotr {
+        ( $x:expr, $n:literal ) => {
+            _mm256_or_si256(_mm256_srli_epi32($x, $n), _mm256_slli_epi32($x, 32 - $n))
+        };
+    }
+
+    /// Hashes the padded messages in `lanes`, one per lane, returning the
+    /// final state of each.
+    #[target_feature(enable = "avx2")]
+    unsafe fn hash_lanes(initial: &LaneState, lanes: &[&[u8]; LANES]) -> [LaneState; LANES] {
+        let mut n = [0; LANES];
+        for (n, lane) in n.iter_mut().zip(lanes.iter()) {
+            *n = num_blocks(lane.len());
+        }
+        let max_blocks = n.iter().copied().max().unwrap();
+
+        let mut h = [_mm256_setzero_si256(); 8];
+        for (h, initial) in h.iter_mut().zip(initial.iter()) {
+            *h = _mm256_set1_epi32(initial.0 as i32);
+        }
+
+        let mut bufs = [[0u8; BLOCK_LEN]; LANES];
+        for i in 0..max_blocks {
+            // Transpose the lanes' blocks so that `w[t]` holds word `t` of
+            // every lane's block.
+            let mut words = [[0u32; LANES]; 16];
+            let mut active = [0i32; LANES];
+            for (lane, buf) in bufs.iter_mut().enumerate() {
+                let block = padded_block(lanes[lane], i, n[lane], buf);
+                for (t, word) in block.chunks_exact(4).enumerate() {
+                    words[t][lane] = u32::from_be_bytes(word.try_into().unwrap());
+                }
+                active[lane] = if i < n[lane] { -1 } else { 0 };
+            }
+            let mut w = [_mm256_setzero_si256(); 64];
+            for (w, words) in w.iter_mut().zip(words.iter()) {
+                *w = _mm256_loadu_si256(words.as_ptr() as *const __m256i);
+            }
+            let active = _mm256_loadu_si256(active.as_ptr() as *const __m256i);
+
+            let compressed = compress(&h, &mut w);
+
+            // Lanes whose messages are complete keep their final state.
+            for (h, compressed) in h.iter_mut().zip(compressed.iter()) {
+                *h = _mm256_blendv_epi8(*h, *compressed, active);
+            }
+        }
+
+        let mut transposed = [[0u32; LANES]; 8];
+        for (out, h) in transposed.iter_mut().zip(h.iter()) {
+            _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, *h);
+        }
+        let mut states = [[Wrapping(0); 8]; LANES];
+        for (lane, state) in states.iter_mut().enumerate() {
+            for (word, transposed) in state.iter_mut().zip(transposed.iter()) {
+                *word = Wrapping(transposed[lane]);
+            }
+        }
+        states
+    }
+
+    /// FIPS 180-4 6.2.2, for each lane. `w` holds the first 16 words of the
+    /// message schedule on entry.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn compress(h: &[__m256i; 8], w: &mut [__m256i; 64]) -> [__m256i; 8] {
+        for t in 16..64 {
+            let s0 = xor!(
+                rotr!(w[t - 15], 7),
+                rotr!(w[t - 15], 18),
+                _mm256_srli_epi32(w[t - 15], 3)
+            );
+            let s1 = xor!(
+                rotr!(w[t - 2], 17),
+                rotr!(w[t - 2], 19),
+                _mm256_srli_epi32(w[t - 2], 10)
+            );
+            w[t] = add!(w[t - 16], s0, w[t - 7], s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = *h;
+        for (w, k) in w.iter().zip(sha2::SHA256_K.iter()) {
+            let s1 = xor!(rotr!(e, 6), rotr!(e, 11), rotr!(e, 25));
+            let ch = xor!(_mm256_and_si256(e, f), _mm256_andnot_si256(e, g));
+            let t1 = add!(hh, s1, ch, _mm256_set1_epi32(k.0 as i32), *w);
+            let s0 = xor!(rotr!(a, 2), rotr!(a, 13), rotr!(a, 22));
+            let maj = _mm256_or_si256(
+                _mm256_and_si256(a, b),
+                _mm256_and_si256(c, _mm256_or_si256(a, b)),
+            );
+            let t2 = add!(s0, maj);
+            hh = g;
+            g = f;
+            f = e;
+            e = add!(d, t1);
+            d = c;
+            c = b;
+            b = a;
+            a = add!(t1, t2);
+        }
+
+        [
+            add!(h[0], a),
+            add!(h[1], b),
+            add!(h[2], c),
+            add!(h[3], d),
+            add!(h[4], e),
+            add!(h[5], f),
+            add!(h[6], g),
+            add!(h[7], hh),
+        ]
+    }
+}
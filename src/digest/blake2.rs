@@ -0,0 +1,365 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! BLAKE2b and BLAKE2s, including the keyed mode.
+//!
+//! The unkeyed algorithms are `digest::BLAKE2B_256`, `digest::BLAKE2B_512`,
+//! and `digest::BLAKE2S_256`; they can be used like any other digest
+//! algorithm. This module provides the keyed mode of [RFC 7693], which turns
+//! BLAKE2 into a MAC.
+//!
+//! ```
+//! use ring::digest::{self, blake2};
+//!
+//! let key = blake2::Key::new(&digest::BLAKE2B_256, b"a secret key").unwrap();
+//! let tag = blake2::sign(&key, b"hello, world");
+//! blake2::verify(&key, b"hello, world", tag.as_ref()).unwrap();
+//! ```
+//!
+//! [RFC 7693]: https://tools.ietf.org/html/rfc7693
+
+use super::{AlgorithmID, BlockContext, Output, MAX_BLOCK_LEN};
+use crate::{c, constant_time, digest, endian::BigEndian, error, polyfill};
+use core::convert::TryInto;
+
+pub(super) const BLAKE2B_BLOCK_LEN: usize = 128;
+pub(super) const BLAKE2S_BLOCK_LEN: usize = 64;
+
+/// The BLAKE2 chaining value, the most recent full block of input, and the
+/// number of full blocks of input so far.
+///
+/// BLAKE2 marks the last block of input as such when compressing it, so the
+/// most recent full block can't be compressed until we know whether more
+/// input follows.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(super) struct State<W, const BLOCK_LEN: usize> {
+    pub(super) h: [W; 8],
+    pub(super) held: [u8; BLOCK_LEN],
+    pub(super) num_blocks: u64,
+}
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const BLAKE2S_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+macro_rules! blake2 {
+    {
+        $state_field:ident, $output_field:ident, $word:ty, $word_bits:expr, $rounds:expr, $iv:expr,
+        [$r1:expr, $r2:expr, $r3:expr, $r4:expr],
+        $block_len:expr,
+        $initial_state:ident, $block_data_order:ident, $finish:ident,
+        $format_output:ident, $compress:ident
+    } => {
+        /// The initial state for an unkeyed hash with an `output_len`-byte
+        /// output, using the default parameter block.
+        pub(super) const fn $initial_state(output_len: usize) -> super::State {
+            let iv = $iv;
+            let param = 0x0101_0000 ^ (output_len as $word);
+            super::State {
+                $state_field: State {
+                    h: [
+                        iv[0] ^ param, iv[1], iv[2], iv[3], iv[4], iv[5], iv[6], iv[7],
+                    ],
+                    held: [0; $block_len],
+                    num_blocks: 0,
+                },
+            }
+        }
+
+        pub(super) extern "C" fn $block_data_order(
+            state: &mut super::State,
+            data: *const u8,
+            num: c::size_t,
+        ) {
+            let data = unsafe { core::slice::from_raw_parts(data, num * $block_len) };
+            let s = unsafe { &mut state.$state_field };
+            for block in data.chunks_exact($block_len) {
+                if s.num_blocks > 0 {
+                    let t = bytes_through(s.num_blocks, $block_len);
+                    $compress(&mut s.h, &s.held, t, false);
+                }
+                s.held.copy_from_slice(block);
+                s.num_blocks = s.num_blocks.checked_add(1).unwrap();
+            }
+        }
+
+        pub(super) fn $finish(
+            ctx: BlockContext,
+            pending: &mut [u8],
+            num_pending: usize,
+        ) -> Output {
+            let mut state = ctx.state;
+            let s = unsafe { &mut state.$state_field };
+            let t = bytes_through(s.num_blocks, $block_len);
+            if num_pending == 0 && s.num_blocks > 0 {
+                $compress(&mut s.h, &s.held, t, true);
+            } else {
+                if s.num_blocks > 0 {
+                    $compress(&mut s.h, &s.held, t, false);
+                }
+                pending[num_pending..].fill(0);
+                let t = t.checked_add(polyfill::u64_from_usize(num_pending)).unwrap();
+                $compress(&mut s.h, pending, t, true);
+            }
+            (ctx.algorithm.format_output)(state)
+        }
+
+        pub(super) fn $format_output(state: super::State) -> Output {
+            let s = unsafe { &state.$state_field };
+            // `Output` is serialized in big-endian order but BLAKE2's output is
+            // little-endian.
+            let mut words = [BigEndian::from(0); 8];
+            for (w, h) in words.iter_mut().zip(s.h.iter()) {
+                *w = BigEndian::from(h.swap_bytes());
+            }
+            Output {
+                $output_field: words,
+            }
+        }
+
+        /// The compression function F. `t` is the number of bytes of input
+        /// up to and including `block`.
+        fn $compress(h: &mut [$word; 8], block: &[u8], t: u64, last: bool) {
+            const WORD_LEN: usize = $word_bits / 8;
+
+            #[inline(always)]
+            fn g(v: &mut [$word; 16], a: usize, b: usize, c: usize, d: usize, x: $word, y: $word) {
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                v[d] = (v[d] ^ v[a]).rotate_right($r1);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right($r2);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                v[d] = (v[d] ^ v[a]).rotate_right($r3);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right($r4);
+            }
+
+            let mut m = [0; 16];
+            for (m, bytes) in m.iter_mut().zip(block.chunks_exact(WORD_LEN)) {
+                *m = <$word>::from_le_bytes(bytes.try_into().unwrap());
+            }
+
+            let mut v = [0; 16];
+            v[..8].copy_from_slice(h);
+            v[8..].copy_from_slice(&$iv);
+            let t = u128::from(t);
+            v[12] ^= t as $word;
+            v[13] ^= (t >> $word_bits) as $word;
+            if last {
+                v[14] = !v[14];
+            }
+
+            for s in SIGMA.iter().cycle().take($rounds) {
+                g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+                g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+                g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+                g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+                g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+                g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+                g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+                g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+            }
+
+            for (i, h) in h.iter_mut().enumerate() {
+                *h ^= v[i] ^ v[i + 8];
+            }
+        }
+    };
+}
+
+blake2! {
+    blake2b, as64, u64, 64, 12, BLAKE2B_IV, [32, 24, 16, 63], BLAKE2B_BLOCK_LEN,
+    blake2b_initial_state, blake2b_block_data_order, blake2b_finish,
+    blake2b_format_output, blake2b_compress
+}
+
+blake2! {
+    blake2s, as32, u32, 32, 10, BLAKE2S_IV, [16, 12, 8, 7], BLAKE2S_BLOCK_LEN,
+    blake2s_initial_state, blake2s_block_data_order, blake2s_finish,
+    blake2s_format_output, blake2s_compress
+}
+
+fn bytes_through(num_blocks: u64, block_len: usize) -> u64 {
+    num_blocks
+        .checked_mul(polyfill::u64_from_usize(block_len))
+        .unwrap()
+}
+
+/// Returns a context for unkeyed BLAKE2b with an `output_len`-byte output,
+/// for Argon2's variable-length hash function. The digest of the returned
+/// context is the 64-byte BLAKE2b output; only the first `output_len` bytes
+/// of it are the `output_len`-byte hash. `finish_and_reset()` would reset it
+/// to plain BLAKE2b-512, so it mustn't be used.
+pub(crate) fn blake2b_context_with_output_len(output_len: usize) -> digest::Context {
+    assert!((1..=digest::BLAKE2B_512_OUTPUT_LEN).contains(&output_len));
+    let mut block = BlockContext::new(&digest::BLAKE2B_512);
+    let s = unsafe { &mut block.state.blake2b };
+    s.h[0] ^= polyfill::u64_from_usize(output_len ^ digest::BLAKE2B_512_OUTPUT_LEN);
+    digest::Context::clone_from(&block)
+}
+
+/// A key for the keyed mode of BLAKE2.
+#[derive(Clone)]
+pub struct Key {
+    block: BlockContext,
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+impl Key {
+    /// Constructs a key for the keyed mode of `algorithm`, which must be one
+    /// of the BLAKE2 algorithms.
+    ///
+    /// `key_value` must be between 1 and `algorithm.chaining_len` bytes long:
+    /// 64 bytes for BLAKE2b and 32 bytes for BLAKE2s.
+    pub fn new(
+        algorithm: &'static digest::Algorithm,
+        key_value: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        match algorithm.id {
+            AlgorithmID::BLAKE2B_256 | AlgorithmID::BLAKE2B_512 | AlgorithmID::BLAKE2S_256 => {}
+            _ => return Err(error::Unspecified),
+        }
+        if key_value.is_empty() || key_value.len() > algorithm.chaining_len {
+            return Err(error::Unspecified);
+        }
+
+        // Record the key length in the parameter block.
+        let mut block = BlockContext::new(algorithm);
+        let key_len = polyfill::u64_from_usize(key_value.len());
+        match algorithm.id {
+            AlgorithmID::BLAKE2S_256 => {
+                let s = unsafe { &mut block.state.blake2s };
+                s.h[0] ^= (key_len as u32) << 8;
+            }
+            _ => {
+                let s = unsafe { &mut block.state.blake2b };
+                s.h[0] ^= key_len << 8;
+            }
+        }
+
+        // The key, padded with zeros to a full block, is the first block of
+        // input.
+        let mut padded_key = [0u8; MAX_BLOCK_LEN];
+        let padded_key = &mut padded_key[..algorithm.block_len];
+        padded_key[..key_value.len()].copy_from_slice(key_value);
+        block.update(padded_key);
+
+        Ok(Self { block })
+    }
+
+    /// Returns a context for computing the keyed hash of a message in
+    /// multiple steps.
+    pub fn context(&self) -> Context {
+        Context {
+            inner: digest::Context::clone_from(&self.block),
+            key: self.block.clone(),
+        }
+    }
+
+    /// The algorithm for the key.
+    #[inline]
+    pub fn algorithm(&self) -> &'static digest::Algorithm {
+        self.block.algorithm
+    }
+}
+
+/// A context for computing a keyed hash in multiple steps.
+#[derive(Clone)]
+pub struct Context {
+    inner: digest::Context,
+    // The state to return to in `finish_and_reset`.
+    key: BlockContext,
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Context")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+impl Context {
+    /// Updates the hash with all the data in `data`.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Finalizes the hash calculation and returns the keyed hash.
+    pub fn finish(self) -> digest::Digest {
+        self.inner.finish()
+    }
+
+    /// Finalizes the hash calculation, returns the keyed hash, and resets the
+    /// context to the state `Key::context` returned, so that it can be used
+    /// to hash another message with the same key.
+    pub fn finish_and_reset(&mut self) -> digest::Digest {
+        let reset = digest::Context::clone_from(&self.key);
+        core::mem::replace(&mut self.inner, reset).finish()
+    }
+
+    /// The algorithm for the context's key.
+    #[inline]
+    pub fn algorithm(&self) -> &'static digest::Algorithm {
+        self.inner.algorithm()
+    }
+}
+
+/// Calculates the keyed hash of `data` using the key `key`.
+///
+/// It is generally not safe to implement verification by comparing the
+/// return value of `sign` to a tag. Use `verify` for verification instead.
+pub fn sign(key: &Key, data: &[u8]) -> digest::Digest {
+    let mut ctx = key.context();
+    ctx.update(data);
+    ctx.finish()
+}
+
+/// Calculates the keyed hash of `data` using the key `key`, and verifies
+/// whether the resultant value equals `tag`, in constant time.
+pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
+}
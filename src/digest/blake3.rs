@@ -0,0 +1,503 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! BLAKE3, including the keyed and key derivation modes.
+//!
+//! BLAKE3 hashes its input as a binary tree of 1 KiB chunks. That doesn't
+//! fit the block-at-a-time model of `digest::Algorithm`, so BLAKE3 has its
+//! own `Context` here. When the `parallel` feature is enabled,
+//! `Context::update_parallel` hashes the subtrees of large inputs on
+//! multiple threads.
+//!
+//! ```
+//! use ring::digest::blake3;
+//!
+//! let one_shot = blake3::hash(b"hello, world");
+//!
+//! let mut ctx = blake3::Context::new();
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! let multi_part = ctx.finish();
+//!
+//! assert_eq!(one_shot.as_ref(), multi_part.as_ref());
+//! ```
+//!
+//! See the [BLAKE3 specification].
+//!
+//! [BLAKE3 specification]: https://github.com/BLAKE3-team/BLAKE3-specs/blob/master/blake3.pdf
+
+use crate::{constant_time, debug, error, polyfill};
+use core::convert::TryInto;
+
+/// The length of a BLAKE3 hash, in bytes.
+pub const OUTPUT_LEN: usize = 32;
+
+/// The length of a key for `Context::new_keyed` and `keyed_hash`, in bytes.
+pub const KEY_LEN: usize = 32;
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+// Enough for 2^54 chunks, i.e. 2^64 bytes of input.
+const MAX_DEPTH: usize = 54;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+const KEYED_HASH: u32 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+type ChainingValue = [u32; 8];
+
+#[inline(always)]
+fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+fn compress(
+    cv: &ChainingValue,
+    block: &[u32; 16],
+    counter: u64,
+    block_len: usize,
+    flags: u32,
+) -> [u32; 16] {
+    let mut v = [
+        cv[0],
+        cv[1],
+        cv[2],
+        cv[3],
+        cv[4],
+        cv[5],
+        cv[6],
+        cv[7],
+        IV[0],
+        IV[1],
+        IV[2],
+        IV[3],
+        counter as u32,
+        (counter >> 32) as u32,
+        block_len as u32,
+        flags,
+    ];
+    let mut m = *block;
+    for _ in 0..7 {
+        g(&mut v, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut v, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut v, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut v, 3, 7, 11, 15, m[6], m[7]);
+        g(&mut v, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut v, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut v, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut v, 3, 4, 9, 14, m[14], m[15]);
+        let prev = m;
+        for (m, &i) in m.iter_mut().zip(MSG_PERMUTATION.iter()) {
+            *m = prev[i];
+        }
+    }
+    for i in 0..8 {
+        v[i] ^= v[i + 8];
+        v[i + 8] ^= cv[i];
+    }
+    v
+}
+
+fn words_from_le_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0; N];
+    for (w, bytes) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *w = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+    words
+}
+
+/// The inputs to the compression of the last block of a node, from which
+/// either the node's chaining value or, for the root, the output is derived.
+#[derive(Clone)]
+struct Node {
+    cv: ChainingValue,
+    block: [u32; 16],
+    counter: u64,
+    block_len: usize,
+    flags: u32,
+}
+
+impl Node {
+    fn parent(
+        left: &ChainingValue,
+        right: &ChainingValue,
+        key: &ChainingValue,
+        flags: u32,
+    ) -> Self {
+        let mut block = [0; 16];
+        block[..8].copy_from_slice(left);
+        block[8..].copy_from_slice(right);
+        Self {
+            cv: *key,
+            block,
+            counter: 0,
+            block_len: BLOCK_LEN,
+            flags: flags | PARENT,
+        }
+    }
+
+    fn chaining_value(&self) -> ChainingValue {
+        let v = compress(
+            &self.cv,
+            &self.block,
+            self.counter,
+            self.block_len,
+            self.flags,
+        );
+        let mut cv = [0; 8];
+        cv.copy_from_slice(&v[..8]);
+        cv
+    }
+
+    fn fill_root_output(&self, out: &mut [u8]) {
+        for (counter, out) in out.chunks_mut(BLOCK_LEN).enumerate() {
+            let v = compress(
+                &self.cv,
+                &self.block,
+                polyfill::u64_from_usize(counter),
+                self.block_len,
+                self.flags | ROOT,
+            );
+            for (out, w) in out.chunks_mut(4).zip(v.iter()) {
+                out.copy_from_slice(&w.to_le_bytes()[..out.len()]);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ChunkState {
+    cv: ChainingValue,
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32,
+}
+
+impl ChunkState {
+    fn new(key: &ChainingValue, chunk_counter: u64, flags: u32) -> Self {
+        Self {
+            cv: *key,
+            chunk_counter,
+            block: [0; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+            flags,
+        }
+    }
+
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // The last block of the chunk is compressed differently so a
+            // full block is only compressed when more input follows.
+            if self.block_len == BLOCK_LEN {
+                let v = compress(
+                    &self.cv,
+                    &words_from_le_bytes(&self.block),
+                    self.chunk_counter,
+                    BLOCK_LEN,
+                    self.flags | self.start_flag(),
+                );
+                self.cv.copy_from_slice(&v[..8]);
+                self.blocks_compressed += 1;
+                self.block = [0; BLOCK_LEN];
+                self.block_len = 0;
+            }
+            let to_copy = core::cmp::min(BLOCK_LEN - self.block_len, input.len());
+            let (chunk, rest) = input.split_at(to_copy);
+            self.block[self.block_len..][..to_copy].copy_from_slice(chunk);
+            self.block_len += to_copy;
+            input = rest;
+        }
+    }
+
+    fn node(&self) -> Node {
+        Node {
+            cv: self.cv,
+            block: words_from_le_bytes(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len,
+            flags: self.flags | self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) BLAKE3 calculations.
+#[derive(Clone)]
+pub struct Context {
+    chunk_state: ChunkState,
+    key: ChainingValue,
+    // The chaining values of the complete subtrees to the left of
+    // `chunk_state`, largest first.
+    cv_stack: [ChainingValue; MAX_DEPTH],
+    cv_stack_len: usize,
+    flags: u32,
+}
+
+impl Context {
+    fn new_internal(key: ChainingValue, flags: u32) -> Self {
+        Self {
+            chunk_state: ChunkState::new(&key, 0, flags),
+            key,
+            cv_stack: [[0; 8]; MAX_DEPTH],
+            cv_stack_len: 0,
+            flags,
+        }
+    }
+
+    /// Constructs a new context for the default (unkeyed) hash mode.
+    pub fn new() -> Self {
+        Self::new_internal(IV, 0)
+    }
+
+    /// Constructs a new context for the keyed hash mode, for use as a MAC.
+    pub fn new_keyed(key: &[u8; KEY_LEN]) -> Self {
+        Self::new_internal(words_from_le_bytes(key), KEYED_HASH)
+    }
+
+    /// Constructs a new context for the key derivation mode. The input is
+    /// the key material and `context` is a hardcoded, globally unique,
+    /// application-specific string.
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut ctx = Self::new_internal(IV, DERIVE_KEY_CONTEXT);
+        ctx.update(context.as_bytes());
+        let mut context_key = [0u8; KEY_LEN];
+        ctx.finish_extended(&mut context_key);
+        Self::new_internal(words_from_le_bytes(&context_key), DERIVE_KEY_MATERIAL)
+    }
+
+    /// Updates the hash with all the data in `data`. `update` may be called
+    /// zero or more times until `finish` is called.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            self.push_full_chunk();
+            let to_copy = core::cmp::min(CHUNK_LEN - self.chunk_state.len(), data.len());
+            let (chunk, rest) = data.split_at(to_copy);
+            self.chunk_state.update(chunk);
+            data = rest;
+        }
+    }
+
+    /// Like `update`, but hashes the subtrees of large inputs on multiple
+    /// threads. The result is the same as that of `update`.
+    #[cfg(feature = "parallel")]
+    pub fn update_parallel(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            self.push_full_chunk();
+            if self.chunk_state.len() == 0 && data.len() > CHUNK_LEN {
+                // Hash the largest complete subtree that starts at the current
+                // chunk and leaves at least one byte of input, since the last
+                // chunk must be kept in `chunk_state` for `finish`.
+                let counter = self.chunk_state.chunk_counter;
+                let max_chunks = (data.len() - 1) / CHUNK_LEN;
+                let mut subtree_chunks = 1;
+                while subtree_chunks * 2 <= max_chunks
+                    && counter & (polyfill::u64_from_usize(subtree_chunks * 2) - 1) == 0
+                {
+                    subtree_chunks *= 2;
+                }
+                let (subtree, rest) = data.split_at(subtree_chunks * CHUNK_LEN);
+                let subtree_chunks = polyfill::u64_from_usize(subtree_chunks);
+                let cv = subtree_cv_parallel(subtree, &self.key, counter, self.flags);
+                self.push_cv(cv, counter + subtree_chunks, subtree_chunks);
+                self.chunk_state = ChunkState::new(&self.key, counter + subtree_chunks, self.flags);
+                data = rest;
+            } else {
+                let to_copy = core::cmp::min(CHUNK_LEN - self.chunk_state.len(), data.len());
+                let (chunk, rest) = data.split_at(to_copy);
+                self.chunk_state.update(chunk);
+                data = rest;
+            }
+        }
+    }
+
+    /// If the current chunk is complete, adds it to the tree and starts the
+    /// next chunk. This must only be called when more input follows.
+    fn push_full_chunk(&mut self) {
+        if self.chunk_state.len() == CHUNK_LEN {
+            let cv = self.chunk_state.node().chaining_value();
+            let total_chunks = self.chunk_state.chunk_counter + 1;
+            self.push_cv(cv, total_chunks, 1);
+            self.chunk_state = ChunkState::new(&self.key, total_chunks, self.flags);
+        }
+    }
+
+    /// Adds the chaining value `cv` of a subtree of `subtree_chunks` chunks,
+    /// after which there are `total_chunks` chunks to the left of
+    /// `chunk_state`, merging complete subtrees as we go.
+    fn push_cv(&mut self, mut cv: ChainingValue, total_chunks: u64, subtree_chunks: u64) {
+        let mut total = total_chunks / subtree_chunks;
+        while total & 1 == 0 {
+            self.cv_stack_len -= 1;
+            cv = Node::parent(
+                &self.cv_stack[self.cv_stack_len],
+                &cv,
+                &self.key,
+                self.flags,
+            )
+            .chaining_value();
+            total >>= 1;
+        }
+        self.cv_stack[self.cv_stack_len] = cv;
+        self.cv_stack_len += 1;
+    }
+
+    fn root(&self) -> Node {
+        let mut node = self.chunk_state.node();
+        for cv in self.cv_stack[..self.cv_stack_len].iter().rev() {
+            node = Node::parent(cv, &node.chaining_value(), &self.key, self.flags);
+        }
+        node
+    }
+
+    /// Finalizes the calculation and returns the hash value. `finish`
+    /// consumes the context so it cannot be (mis-)used after `finish` has been
+    /// called.
+    pub fn finish(self) -> Hash {
+        let mut value = [0; OUTPUT_LEN];
+        self.root().fill_root_output(&mut value);
+        Hash { value }
+    }
+
+    /// Finalizes the calculation and fills `out` with the extended output.
+    /// The first `OUTPUT_LEN` bytes are the same as the value of `finish`.
+    pub fn finish_extended(self, out: &mut [u8]) {
+        self.root().fill_root_output(out);
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn subtree_cv_parallel(
+    input: &[u8],
+    key: &ChainingValue,
+    counter: u64,
+    flags: u32,
+) -> ChainingValue {
+    // Below this size the cost of spawning work exceeds the benefit.
+    const MIN_PARALLEL_LEN: usize = 16 * CHUNK_LEN;
+
+    if input.len() == CHUNK_LEN {
+        let mut chunk_state = ChunkState::new(key, counter, flags);
+        chunk_state.update(input);
+        return chunk_state.node().chaining_value();
+    }
+    let (left, right) = input.split_at(input.len() / 2);
+    let right_counter = counter + polyfill::u64_from_usize(left.len() / CHUNK_LEN);
+    let (left, right) = if input.len() >= MIN_PARALLEL_LEN {
+        rayon::join(
+            || subtree_cv_parallel(left, key, counter, flags),
+            || subtree_cv_parallel(right, key, right_counter, flags),
+        )
+    } else {
+        (
+            subtree_cv_parallel(left, key, counter, flags),
+            subtree_cv_parallel(right, key, right_counter, flags),
+        )
+    };
+    Node::parent(&left, &right, key, flags).chaining_value()
+}
+
+/// A BLAKE3 hash value.
+///
+/// Use `as_ref` to get the value as a `&[u8]`.
+#[derive(Clone, Copy)]
+pub struct Hash {
+    value: [u8; OUTPUT_LEN],
+}
+
+impl AsRef<[u8]> for Hash {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl core::fmt::Debug for Hash {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "BLAKE3:")?;
+        debug::write_hex_bytes(fmt, self.as_ref())
+    }
+}
+
+/// Returns the BLAKE3 hash of `data`.
+pub fn hash(data: &[u8]) -> Hash {
+    let mut ctx = Context::new();
+    ctx.update(data);
+    ctx.finish()
+}
+
+/// Returns the keyed BLAKE3 hash of `data`.
+///
+/// It is generally not safe to implement verification by comparing the
+/// return value of `keyed_hash` to a tag. Use `verify_keyed_hash` instead.
+pub fn keyed_hash(key: &[u8; KEY_LEN], data: &[u8]) -> Hash {
+    let mut ctx = Context::new_keyed(key);
+    ctx.update(data);
+    ctx.finish()
+}
+
+/// Calculates the keyed BLAKE3 hash of `data` and verifies whether it equals
+/// `tag`, in constant time.
+pub fn verify_keyed_hash(
+    key: &[u8; KEY_LEN],
+    data: &[u8],
+    tag: &[u8],
+) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(keyed_hash(key, data).as_ref(), tag)
+}
+
+/// Derives `out.len()` bytes of key from `key_material` using the BLAKE3 key
+/// derivation mode with the context string `context`.
+pub fn derive_key(context: &str, key_material: &[u8], out: &mut [u8]) {
+    let mut ctx = Context::new_derive_key(context);
+    ctx.update(key_material);
+    ctx.finish_extended(out);
+}
@@ -0,0 +1,188 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Digest algorithms whose output lengths are part of their types, so that
+//! digests can be calculated as arrays.
+//!
+//! ```
+//! use ring::digest::{self, fixed};
+//!
+//! let id: [u8; 32] = fixed::SHA256.digest(b"hello, world");
+//!
+//! let mut ctx = fixed::Context::new(&fixed::SHA256);
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! assert_eq!(ctx.finish(), id);
+//!
+//! assert_eq!(&id[..], digest::digest(&digest::SHA256, b"hello, world").as_ref());
+//! ```
+
+use crate::digest;
+use core::convert::TryInto;
+
+/// A digest algorithm whose digests are `N` bytes long.
+pub struct Algorithm<const N: usize> {
+    algorithm: &'static digest::Algorithm,
+}
+
+impl<const N: usize> Algorithm<N> {
+    /// The underlying digest algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static digest::Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the digest of `data`.
+    pub fn digest(&'static self, data: &[u8]) -> [u8; N] {
+        let mut ctx = Context::new(self);
+        ctx.update(data);
+        ctx.finish()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for Algorithm<N> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.algorithm.fmt(fmt)
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) digest calculations of
+/// `N`-byte digests.
+#[derive(Clone)]
+pub struct Context<const N: usize> {
+    inner: digest::Context,
+}
+
+impl<const N: usize> Context<N> {
+    /// Constructs a new context.
+    pub fn new(algorithm: &'static Algorithm<N>) -> Self {
+        Self {
+            inner: digest::Context::new(algorithm.algorithm),
+        }
+    }
+
+    /// Updates the digest with all the data in `data`.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data)
+    }
+
+    /// Finalizes the digest calculation and returns the digest value.
+    pub fn finish(self) -> [u8; N] {
+        // `N` is the output length of the algorithm, by construction.
+        self.inner.finish().as_ref().try_into().unwrap()
+    }
+
+    /// The algorithm that this context is using.
+    #[inline]
+    pub fn algorithm(&self) -> &'static digest::Algorithm {
+        self.inner.algorithm()
+    }
+}
+
+macro_rules! fixed {
+    ( $( #[$attr:meta] )* $name:ident, $output_len:ident ) => {
+        $( #[$attr] )*
+        pub static $name: Algorithm<{ digest::$output_len }> = Algorithm {
+            algorithm: &digest::$name,
+        };
+    };
+}
+
+fixed!(
+    #[cfg(feature = "insecure_md5")]
+    /// `digest::MD5_FOR_LEGACY_USE_ONLY`.
+    MD5_FOR_LEGACY_USE_ONLY,
+    MD5_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA1_FOR_LEGACY_USE_ONLY`.
+    SHA1_FOR_LEGACY_USE_ONLY,
+    SHA1_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA224`.
+    SHA224,
+    SHA224_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA256`.
+    SHA256,
+    SHA256_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA384`.
+    SHA384,
+    SHA384_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA512`.
+    SHA512,
+    SHA512_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA512_224`.
+    SHA512_224,
+    SHA512_224_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA512_256`.
+    SHA512_256,
+    SHA512_256_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA3_224`.
+    SHA3_224,
+    SHA3_224_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA3_256`.
+    SHA3_256,
+    SHA3_256_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA3_384`.
+    SHA3_384,
+    SHA3_384_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SHA3_512`.
+    SHA3_512,
+    SHA3_512_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::BLAKE2B_256`.
+    BLAKE2B_256,
+    BLAKE2B_256_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::BLAKE2B_512`.
+    BLAKE2B_512,
+    BLAKE2B_512_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::BLAKE2S_256`.
+    BLAKE2S_256,
+    BLAKE2S_256_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::SM3`.
+    SM3,
+    SM3_OUTPUT_LEN
+);
+fixed!(
+    /// `digest::ASCON_HASH256`.
+    ASCON_HASH256,
+    ASCON_HASH256_OUTPUT_LEN
+);
@@ -0,0 +1,207 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! KangarooTwelve (KT128) and KT256, as specified in [RFC 9861].
+//!
+//! KangarooTwelve is a tree hash built on TurboSHAKE. Inputs of more than
+//! 8 KiB are split into chunks that are hashed independently, so long inputs
+//! can be hashed quickly, and the reduced-round permutation makes even short
+//! inputs faster to hash than with SHA-3.
+//!
+//! ```
+//! use ring::digest::kangaroo_twelve;
+//!
+//! let mut ctx = kangaroo_twelve::Context::new(&kangaroo_twelve::KT128);
+//! ctx.update(b"hello, world");
+//! let mut out = [0u8; 32];
+//! ctx.finish(b"").squeeze(&mut out);
+//! ```
+//!
+//! [RFC 9861]: https://www.rfc-editor.org/rfc/rfc9861
+
+use super::xof;
+use crate::polyfill;
+
+/// A KangarooTwelve algorithm.
+pub struct Algorithm {
+    turboshake: &'static xof::Algorithm,
+    chaining_value_len: usize,
+    id: AlgorithmID,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    KT128,
+    KT256,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+derive_debug_via_id!(Algorithm);
+
+/// KT128, i.e. KangarooTwelve, built on TurboSHAKE128.
+pub static KT128: Algorithm = Algorithm {
+    turboshake: &xof::TURBOSHAKE128,
+    chaining_value_len: 32,
+    id: AlgorithmID::KT128,
+};
+
+/// KT256, built on TurboSHAKE256.
+pub static KT256: Algorithm = Algorithm {
+    turboshake: &xof::TURBOSHAKE256,
+    chaining_value_len: 64,
+    id: AlgorithmID::KT256,
+};
+
+const CHUNK_LEN: usize = 8192;
+
+const SINGLE_NODE: u8 = 0x07;
+const FINAL_NODE: u8 = 0x06;
+const LEAF_NODE: u8 = 0x0b;
+
+/// A context for multi-step KangarooTwelve calculations.
+#[derive(Clone)]
+pub struct Context {
+    // Absorbs the first chunk and then, if there is more than one chunk, the
+    // chaining values of the other chunks.
+    final_node: xof::Context,
+    first_chunk_len: usize,
+
+    // The chunk after the first one that is being absorbed, if any.
+    leaf: Option<(xof::Context, usize)>,
+    num_leaves: u64,
+
+    algorithm: &'static Algorithm,
+}
+
+impl Context {
+    /// Constructs a new context.
+    pub fn new(algorithm: &'static Algorithm) -> Self {
+        Self {
+            final_node: turboshake(algorithm, SINGLE_NODE),
+            first_chunk_len: 0,
+            leaf: None,
+            num_leaves: 0,
+            algorithm,
+        }
+    }
+
+    /// Updates the hash with all the data in `data`. `update` may be called
+    /// zero or more times until `finish` is called.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.first_chunk_len < CHUNK_LEN {
+                let to_copy = core::cmp::min(CHUNK_LEN - self.first_chunk_len, data.len());
+                let (chunk, rest) = data.split_at(to_copy);
+                self.final_node.update(chunk);
+                self.first_chunk_len += to_copy;
+                data = rest;
+                continue;
+            }
+
+            if self.leaf.is_none() && self.num_leaves == 0 {
+                self.final_node.update(&[0x03, 0, 0, 0, 0, 0, 0, 0]);
+            }
+            let algorithm = self.algorithm;
+            let (leaf, leaf_len) = self
+                .leaf
+                .get_or_insert_with(|| (turboshake(algorithm, LEAF_NODE), 0));
+            let to_copy = core::cmp::min(CHUNK_LEN - *leaf_len, data.len());
+            let (chunk, rest) = data.split_at(to_copy);
+            leaf.update(chunk);
+            *leaf_len += to_copy;
+            data = rest;
+            if *leaf_len == CHUNK_LEN {
+                self.finish_leaf();
+            }
+        }
+    }
+
+    fn finish_leaf(&mut self) {
+        if let Some((leaf, _)) = self.leaf.take() {
+            let mut chaining_value = [0u8; 64];
+            let chaining_value = &mut chaining_value[..self.algorithm.chaining_value_len];
+            leaf.finish().squeeze(chaining_value);
+            self.final_node.update(chaining_value);
+            self.num_leaves += 1;
+        }
+    }
+
+    /// Finishes the calculation with the customization string
+    /// `customization`, which may be empty, and returns a `Reader` from which
+    /// any amount of output can be squeezed.
+    pub fn finish(mut self, customization: &[u8]) -> xof::Reader {
+        self.update(customization);
+        self.update(length_encoded(polyfill::u64_from_usize(customization.len())).as_ref());
+
+        self.finish_leaf();
+        if self.num_leaves > 0 {
+            let num_leaves = self.num_leaves;
+            let mut final_node = self.final_node;
+            final_node.update(length_encoded(num_leaves).as_ref());
+            final_node.update(&[0xff, 0xff]);
+            final_node.set_domain_separation(FINAL_NODE);
+            final_node.finish()
+        } else {
+            self.final_node.finish()
+        }
+    }
+
+    /// The algorithm that this context is using.
+    #[inline(always)]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+/// Fills `out` with the hash of `message` with the customization string
+/// `customization`, which may be empty.
+pub fn hash(algorithm: &'static Algorithm, message: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut ctx = Context::new(algorithm);
+    ctx.update(message);
+    ctx.finish(customization).squeeze(out);
+}
+
+fn turboshake(algorithm: &'static Algorithm, domain_separation: u8) -> xof::Context {
+    // `algorithm.turboshake` is always TurboSHAKE and the domain separation
+    // bytes are all valid.
+    xof::Context::new_turboshake(algorithm.turboshake, domain_separation).unwrap()
+}
+
+/// `length_encode(x)`: the big-endian encoding of `x` in as few bytes as
+/// possible (none for zero), followed by the number of those bytes.
+struct LengthEncoded {
+    bytes: [u8; 9],
+    start: usize,
+}
+
+impl AsRef<[u8]> for LengthEncoded {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[self.start..]
+    }
+}
+
+fn length_encoded(x: u64) -> LengthEncoded {
+    let mut bytes = [0u8; 9];
+    bytes[..8].copy_from_slice(&x.to_be_bytes());
+    let start = bytes[..8].iter().take_while(|b| **b == 0).count();
+    bytes[8] = (8 - start) as u8;
+    LengthEncoded { bytes, start }
+}
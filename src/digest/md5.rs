@@ -0,0 +1,117 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! MD5, as specified in [RFC 1321].
+//!
+//! [RFC 1321]: https://tools.ietf.org/html/rfc1321
+
+use super::{BlockContext, Output};
+use crate::{c, endian::BigEndian};
+use core::{convert::TryInto, num::Wrapping};
+
+pub const BLOCK_LEN: usize = 512 / 8;
+pub const CHAINING_LEN: usize = 128 / 8;
+pub const OUTPUT_LEN: usize = 128 / 8;
+const CHAINING_WORDS: usize = CHAINING_LEN / 4;
+
+type W32 = Wrapping<u32>;
+type State = [W32; CHAINING_WORDS];
+
+// RFC 1321 3.4: T[i] = floor(2^32 * abs(sin(i))).
+#[rustfmt::skip]
+const T: [u32; 64] = [
+    // Round 1
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    // Round 2
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    // Round 3
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    // Round 4
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+// RFC 1321 3.4: the rotation amounts of each round.
+const S: [[u32; 4]; 4] = [
+    [7, 12, 17, 22],
+    [5, 9, 14, 20],
+    [4, 11, 16, 23],
+    [6, 10, 15, 21],
+];
+
+pub(super) extern "C" fn block_data_order(
+    state: &mut super::State,
+    data: *const u8,
+    num: c::size_t,
+) {
+    let state = unsafe { &mut state.as32 };
+    let state: &mut State = (&mut state[..CHAINING_WORDS]).try_into().unwrap();
+    let data = data as *const [u8; BLOCK_LEN];
+    let blocks = unsafe { core::slice::from_raw_parts(data, num) };
+    *state = block_data_order_(*state, blocks)
+}
+
+fn block_data_order_(mut state: State, blocks: &[[u8; BLOCK_LEN]]) -> State {
+    for block in blocks {
+        let mut x = [Wrapping(0); 16];
+        for (x, word) in x.iter_mut().zip(block.chunks_exact(4)) {
+            *x = Wrapping(u32::from_le_bytes(word.try_into().unwrap()));
+        }
+
+        let [mut a, mut b, mut c, mut d] = state;
+        for (i, t) in T.iter().enumerate() {
+            let round = i / 16;
+            // RFC 1321 3.4: the auxiliary function and the message word used in
+            // each round.
+            let (f, k) = match round {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((b & d) | (c & !d), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let sum = a + f + Wrapping(*t) + x[k];
+            a = d;
+            d = c;
+            c = b;
+            b += Wrapping(sum.0.rotate_left(S[round][i % 4]));
+        }
+
+        state[0] += a;
+        state[1] += b;
+        state[2] += c;
+        state[3] += d;
+    }
+    state
+}
+
+/// Like the SHA-2 padding, but with the length in little-endian order.
+pub(super) fn finish(ctx: BlockContext, pending: &mut [u8], num_pending: usize) -> Output {
+    ctx.merkle_damgard_finish_with(pending, num_pending, u64::to_le_bytes)
+}
+
+pub(super) fn format_output(input: super::State) -> Output {
+    let input = unsafe { &input.as32 };
+    let mut output = Output {
+        as32: [BigEndian::from(0); 256 / 32],
+    };
+    let words = unsafe { &mut output.as32 };
+    // The words are output in little-endian order.
+    for (out, w) in words.iter_mut().zip(input.iter()) {
+        *out = BigEndian::from(w.0.swap_bytes());
+    }
+    output
+}
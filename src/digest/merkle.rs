@@ -0,0 +1,170 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Merkle tree hashes and inclusion proofs, as specified in [RFC 6962] and
+//! used by Certificate Transparency and other transparency logs.
+//!
+//! Leaves and interior nodes are hashed with different prefixes (0x00 and
+//! 0x01, respectively), so a leaf can't be passed off as an interior node.
+//!
+//! ```
+//! use ring::digest::{self, merkle};
+//!
+//! let leaves: [&[u8]; 3] = [b"a", b"b", b"c"];
+//! let root = merkle::root(&digest::SHA256, &leaves);
+//!
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! let proof = merkle::inclusion_proof(&digest::SHA256, &leaves, 1).unwrap();
+//! merkle::verify_inclusion(&digest::SHA256, b"b", 1, 3, &proof, root.as_ref()).unwrap();
+//! # }
+//! ```
+//!
+//! [RFC 6962]: https://tools.ietf.org/html/rfc6962#section-2.1
+
+use super::{Algorithm, Context, Digest};
+use crate::{constant_time, error};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Returns the hash of the leaf `leaf`: `HASH(0x00 || leaf)`.
+pub fn leaf_hash(algorithm: &'static Algorithm, leaf: &[u8]) -> Digest {
+    let mut ctx = Context::new(algorithm);
+    ctx.update(&[LEAF_PREFIX]);
+    ctx.update(leaf);
+    ctx.finish()
+}
+
+/// Returns the hash of the interior node with children `left` and `right`:
+/// `HASH(0x01 || left || right)`.
+pub fn node_hash(algorithm: &'static Algorithm, left: &[u8], right: &[u8]) -> Digest {
+    let mut ctx = Context::new(algorithm);
+    ctx.update(&[NODE_PREFIX]);
+    ctx.update(left);
+    ctx.update(right);
+    ctx.finish()
+}
+
+/// Returns the Merkle tree hash (the root) of the tree with the leaves
+/// `leaves`, in order.
+///
+/// The root of the empty tree is the hash of the empty string.
+pub fn root<L: AsRef<[u8]>>(algorithm: &'static Algorithm, leaves: &[L]) -> Digest {
+    match leaves {
+        [] => super::digest(algorithm, &[]),
+        [leaf] => leaf_hash(algorithm, leaf.as_ref()),
+        _ => {
+            let (left, right) = leaves.split_at(split_point(leaves.len()));
+            node_hash(
+                algorithm,
+                root(algorithm, left).as_ref(),
+                root(algorithm, right).as_ref(),
+            )
+        }
+    }
+}
+
+/// Returns the inclusion proof (the audit path) of the leaf at index
+/// `index` in the tree with the leaves `leaves`, ordered from the leaf to the
+/// root.
+///
+/// Fails if `index` isn't the index of one of the leaves.
+#[cfg(feature = "alloc")]
+pub fn inclusion_proof<L: AsRef<[u8]>>(
+    algorithm: &'static Algorithm,
+    leaves: &[L],
+    index: usize,
+) -> Result<Vec<Digest>, error::Unspecified> {
+    if index >= leaves.len() {
+        return Err(error::Unspecified);
+    }
+
+    let mut proof = Vec::new();
+    let mut leaves = leaves;
+    let mut index = index;
+    // Walk down from the root, collecting the sibling at each level; the
+    // siblings nearest the root are found first.
+    while leaves.len() > 1 {
+        let (left, right) = leaves.split_at(split_point(leaves.len()));
+        if index < left.len() {
+            proof.push(root(algorithm, right));
+            leaves = left;
+        } else {
+            proof.push(root(algorithm, left));
+            leaves = right;
+            index -= left.len();
+        }
+    }
+    proof.reverse();
+    Ok(proof)
+}
+
+/// Verifies that `leaf` is the leaf at index `index` of the tree with
+/// `tree_size` leaves and the root `root`, using the inclusion proof `proof`.
+///
+/// This is the verification algorithm of [RFC 9162 Section 2.1.3.2].
+///
+/// [RFC 9162 Section 2.1.3.2]: https://tools.ietf.org/html/rfc9162#section-2.1.3.2
+pub fn verify_inclusion<P: AsRef<[u8]>>(
+    algorithm: &'static Algorithm,
+    leaf: &[u8],
+    index: u64,
+    tree_size: u64,
+    proof: &[P],
+    root: &[u8],
+) -> Result<(), error::Unspecified> {
+    if index >= tree_size {
+        return Err(error::Unspecified);
+    }
+
+    let mut f_n = index;
+    let mut s_n = tree_size - 1;
+    let mut r = leaf_hash(algorithm, leaf);
+    for p in proof {
+        let p = p.as_ref();
+        if s_n == 0 || p.len() != algorithm.output_len {
+            return Err(error::Unspecified);
+        }
+        if f_n & 1 == 1 || f_n == s_n {
+            r = node_hash(algorithm, p, r.as_ref());
+            while f_n & 1 == 0 && f_n != 0 {
+                f_n >>= 1;
+                s_n >>= 1;
+            }
+        } else {
+            r = node_hash(algorithm, r.as_ref(), p);
+        }
+        f_n >>= 1;
+        s_n >>= 1;
+    }
+    if s_n != 0 {
+        return Err(error::Unspecified);
+    }
+    constant_time::verify_slices_are_equal(r.as_ref(), root)
+}
+
+/// The number of leaves in the left subtree of a tree with `n` leaves: the
+/// largest power of two less than `n`.
+fn split_point(n: usize) -> usize {
+    debug_assert!(n > 1);
+    let mut k = 1;
+    while k * 2 < n {
+        k <<= 1;
+    }
+    k
+}
@@ -0,0 +1,261 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The serialized form of a `Context`; see `Context::state`.
+
+use super::{
+    Algorithm, AlgorithmID, BlockContext, Context, ASCON_HASH256, BLAKE2B_256, BLAKE2B_512,
+    BLAKE2S_256, MAX_BLOCK_LEN, SHA1_FOR_LEGACY_USE_ONLY, SHA224, SHA256, SHA384, SHA3_224,
+    SHA3_256, SHA3_384, SHA3_512, SHA512, SHA512_224, SHA512_256, SM3,
+};
+use crate::{cpu, error, polyfill};
+use core::{convert::TryInto, num::Wrapping};
+
+/// The version of the encoding produced by `Context::state`.
+pub const VERSION: u8 = 1;
+
+/// The algorithms in the order of their identifiers: the identifier of
+/// `ALGORITHMS[i]` is `i + 1`, and it is `None` if the algorithm is disabled.
+/// New algorithms must only be appended.
+static ALGORITHMS: [Option<&Algorithm>; 17] = [
+    Some(&SHA1_FOR_LEGACY_USE_ONLY),
+    Some(&SHA224),
+    Some(&SHA256),
+    Some(&SHA384),
+    Some(&SHA512),
+    Some(&SHA512_224),
+    Some(&SHA512_256),
+    Some(&SHA3_224),
+    Some(&SHA3_256),
+    Some(&SHA3_384),
+    Some(&SHA3_512),
+    Some(&BLAKE2B_256),
+    Some(&BLAKE2B_512),
+    Some(&BLAKE2S_256),
+    Some(&SM3),
+    Some(&ASCON_HASH256),
+    #[cfg(feature = "insecure_md5")]
+    Some(&super::MD5_FOR_LEGACY_USE_ONLY),
+    #[cfg(not(feature = "insecure_md5"))]
+    None,
+];
+
+/// How the chaining state of an algorithm is encoded.
+enum Layout {
+    /// Eight 32-bit big-endian words.
+    Words32,
+
+    /// Eight 64-bit big-endian words.
+    Words64,
+
+    /// The 25 lanes of the Keccak state as 64-bit little-endian words.
+    Keccak,
+
+    /// Eight 64-bit little-endian words, then the held-back block.
+    Blake2b,
+
+    /// Eight 32-bit little-endian words, then the held-back block.
+    Blake2s,
+}
+
+fn layout(algorithm: &Algorithm) -> Layout {
+    match algorithm.id {
+        AlgorithmID::SHA1 | AlgorithmID::SHA224 | AlgorithmID::SHA256 | AlgorithmID::SM3 => {
+            Layout::Words32
+        }
+        #[cfg(feature = "insecure_md5")]
+        AlgorithmID::MD5 => Layout::Words32,
+        AlgorithmID::SHA384
+        | AlgorithmID::SHA512
+        | AlgorithmID::SHA512_224
+        | AlgorithmID::SHA512_256
+        | AlgorithmID::ASCON_HASH256 => Layout::Words64,
+        AlgorithmID::SHA3_224
+        | AlgorithmID::SHA3_256
+        | AlgorithmID::SHA3_384
+        | AlgorithmID::SHA3_512 => Layout::Keccak,
+        AlgorithmID::BLAKE2B_256 | AlgorithmID::BLAKE2B_512 => Layout::Blake2b,
+        AlgorithmID::BLAKE2S_256 => Layout::Blake2s,
+    }
+}
+
+/// The version, the algorithm identifier, the block count, the largest
+/// chaining state (BLAKE2b's), the pending input length, and the pending
+/// input.
+const MAX_LEN: usize = 1 + 1 + 8 + (8 * 8 + 128) + 1 + MAX_BLOCK_LEN;
+
+/// The saved state of a `Context`, in the encoding documented at
+/// `Context::state`.
+///
+/// The state includes up to a block of not-yet-processed input, so it should
+/// be protected like the input itself.
+#[derive(Clone)]
+pub struct ContextState {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl AsRef<[u8]> for ContextState {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl ContextState {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes[self.len..][..bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+}
+
+pub(super) fn encode(ctx: &Context) -> ContextState {
+    let algorithm = ctx.block.algorithm;
+    let id = ALGORITHMS
+        .iter()
+        .position(|&a| a == Some(algorithm))
+        .unwrap()
+        + 1;
+
+    let mut out = ContextState {
+        bytes: [0; MAX_LEN],
+        len: 0,
+    };
+    out.write(&[VERSION, id as u8]);
+    out.write(&ctx.block.completed_data_blocks.to_be_bytes());
+
+    let state = &ctx.block.state;
+    match layout(algorithm) {
+        Layout::Words32 => {
+            for w in unsafe { &state.as32 } {
+                out.write(&w.0.to_be_bytes());
+            }
+        }
+        Layout::Words64 => {
+            for w in unsafe { &state.as64 } {
+                out.write(&w.0.to_be_bytes());
+            }
+        }
+        Layout::Keccak => {
+            for w in unsafe { &state.keccak } {
+                out.write(&w.to_le_bytes());
+            }
+        }
+        Layout::Blake2b => {
+            let s = unsafe { &state.blake2b };
+            for w in &s.h {
+                out.write(&w.to_le_bytes());
+            }
+            out.write(&s.held);
+        }
+        Layout::Blake2s => {
+            let s = unsafe { &state.blake2s };
+            for w in &s.h {
+                out.write(&w.to_le_bytes());
+            }
+            out.write(&s.held);
+        }
+    }
+
+    out.write(&[ctx.num_pending as u8]);
+    out.write(&ctx.pending[..ctx.num_pending]);
+    out
+}
+
+pub(super) fn decode(encoded: &[u8]) -> Result<Context, error::Unspecified> {
+    untrusted::Input::from(encoded).read_all(error::Unspecified, |input| {
+        if input.read_byte()? != VERSION {
+            return Err(error::Unspecified);
+        }
+        let id = usize::from(input.read_byte()?);
+        let algorithm = id
+            .checked_sub(1)
+            .and_then(|i| ALGORITHMS.get(i).copied().flatten())
+            .ok_or(error::Unspecified)?;
+        let completed_data_blocks = u64::from_be_bytes(read_array(input)?);
+
+        // Reject block counts that would overflow the bit length of the
+        // input when finishing.
+        let block_len = polyfill::u64_from_usize(algorithm.block_len);
+        if completed_data_blocks
+            .checked_add(1)
+            .and_then(|blocks| blocks.checked_mul(block_len))
+            .and_then(|bytes| bytes.checked_mul(8))
+            .is_none()
+        {
+            return Err(error::Unspecified);
+        }
+
+        let mut block = BlockContext {
+            state: algorithm.initial_state,
+            completed_data_blocks,
+            algorithm,
+            cpu_features: cpu::features(),
+        };
+        let state = &mut block.state;
+        match layout(algorithm) {
+            Layout::Words32 => {
+                for w in unsafe { &mut state.as32 } {
+                    *w = Wrapping(u32::from_be_bytes(read_array(input)?));
+                }
+            }
+            Layout::Words64 => {
+                for w in unsafe { &mut state.as64 } {
+                    *w = Wrapping(u64::from_be_bytes(read_array(input)?));
+                }
+            }
+            Layout::Keccak => {
+                for w in unsafe { &mut state.keccak } {
+                    *w = u64::from_le_bytes(read_array(input)?);
+                }
+            }
+            Layout::Blake2b => {
+                let s = unsafe { &mut state.blake2b };
+                for w in &mut s.h {
+                    *w = u64::from_le_bytes(read_array(input)?);
+                }
+                s.held = read_array(input)?;
+                s.num_blocks = completed_data_blocks;
+            }
+            Layout::Blake2s => {
+                let s = unsafe { &mut state.blake2s };
+                for w in &mut s.h {
+                    *w = u32::from_le_bytes(read_array(input)?);
+                }
+                s.held = read_array(input)?;
+                s.num_blocks = completed_data_blocks;
+            }
+        }
+
+        let num_pending = usize::from(input.read_byte()?);
+        if num_pending >= algorithm.block_len {
+            return Err(error::Unspecified);
+        }
+        let mut pending = [0u8; MAX_BLOCK_LEN];
+        pending[..num_pending].copy_from_slice(input.read_bytes(num_pending)?.as_slice_less_safe());
+
+        Ok(Context {
+            block,
+            pending,
+            num_pending,
+        })
+    })
+}
+
+fn read_array<const N: usize>(
+    input: &mut untrusted::Reader,
+) -> Result<[u8; N], error::Unspecified> {
+    let bytes = input.read_bytes(N)?;
+    Ok(bytes.as_slice_less_safe().try_into().unwrap())
+}
@@ -167,6 +167,10 @@ trait Sha2: Word + BitXor<Output = Self> + Shr<usize, Output = Self> {
     const K: &'static [Self];
 }
 
+/// The SHA-256 round constants, for implementations outside this module.
+#[cfg_attr(not(all(target_arch = "x86_64", feature = "alloc")), allow(dead_code))]
+pub(super) const SHA256_K: &[Wrapping<u32>] = <Wrapping<u32> as Sha2>::K;
+
 const MAX_ROUNDS: usize = 80;
 pub(super) const CHAINING_WORDS: usize = 8;
 
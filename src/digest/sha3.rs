@@ -0,0 +1,77 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SHA-3, as specified in [FIPS 202] Section 6.1.
+//!
+//! The sponge's rate is the block length; `State::keccak` holds the Keccak
+//! state.
+//!
+//! [FIPS 202]: https://doi.org/10.6028/NIST.FIPS.202
+
+use super::{BlockContext, Output, State};
+use crate::{c, endian::BigEndian, keccak};
+
+pub const SHA3_224_BLOCK_LEN: usize = rate(224);
+pub const SHA3_256_BLOCK_LEN: usize = rate(256);
+pub const SHA3_384_BLOCK_LEN: usize = rate(384);
+pub const SHA3_512_BLOCK_LEN: usize = rate(512);
+
+/// The rate of SHA3-`output_bits`, whose capacity is twice its output length.
+const fn rate(output_bits: usize) -> usize {
+    (1600 - (2 * output_bits)) / 8
+}
+
+pub const INITIAL_STATE: State = State { keccak: [0; 25] };
+
+pub(super) extern "C" fn block_data_order<const BLOCK_LEN: usize>(
+    state: &mut State,
+    data: *const u8,
+    num: c::size_t,
+) {
+    let data = unsafe { core::slice::from_raw_parts(data, num * BLOCK_LEN) };
+    let a = unsafe { &mut state.keccak };
+    for block in data.chunks_exact(BLOCK_LEN) {
+        keccak::xor_in(a, block);
+        keccak::permute(a, 24);
+    }
+}
+
+pub(super) fn finish(ctx: BlockContext, pending: &mut [u8], num_pending: usize) -> Output {
+    // The domain separation bits 01 and then the first bit of the pad10*1
+    // padding. FIPS 202 maps bits to bytes starting from the low bit.
+    pending[num_pending] = 0x06;
+    pending[(num_pending + 1)..].fill(0);
+    *pending.last_mut().unwrap() |= 0x80;
+    let mut state = ctx.state;
+    let a = unsafe { &mut state.keccak };
+    keccak::xor_in(a, pending);
+    keccak::permute(a, 24);
+    (ctx.algorithm.format_output)(state)
+}
+
+/// The output is the start of the state, since it is no longer than the
+/// rate.
+pub(super) fn format_output(state: State) -> Output {
+    let a = unsafe { &state.keccak };
+    let mut output = Output {
+        as64: [BigEndian::from(0); 512 / 64],
+    };
+    let words = unsafe { &mut output.as64 };
+    for (w, lane) in words.iter_mut().zip(a.iter()) {
+        // `Output` is serialized in big-endian order but Keccak's output is
+        // little-endian.
+        *w = BigEndian::from(lane.swap_bytes());
+    }
+    output
+}
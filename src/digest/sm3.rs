@@ -0,0 +1,114 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::sha2::{ch, maj, Word};
+use crate::c;
+use core::{convert::TryInto, num::Wrapping};
+
+pub const BLOCK_LEN: usize = 512 / 8;
+pub const OUTPUT_LEN: usize = 256 / 8;
+const CHAINING_WORDS: usize = OUTPUT_LEN / 4;
+
+type W32 = Wrapping<u32>;
+
+type State = [W32; CHAINING_WORDS];
+type BooleanFn = fn(W32, W32, W32) -> W32;
+const ROUNDS: usize = 64;
+
+pub const INITIAL_STATE: super::State = super::State {
+    as32: [
+        Wrapping(0x7380166f),
+        Wrapping(0x4914b2b9),
+        Wrapping(0x172442d7),
+        Wrapping(0xda8a0600),
+        Wrapping(0xa96f30bc),
+        Wrapping(0x163138aa),
+        Wrapping(0xe38dee4d),
+        Wrapping(0xb0fb0e4e),
+    ],
+};
+
+pub(super) extern "C" fn block_data_order(
+    state: &mut super::State,
+    data: *const u8,
+    num: c::size_t,
+) {
+    let state = unsafe { &mut state.as32 };
+    let state: &mut State = (&mut state[..CHAINING_WORDS]).try_into().unwrap();
+    let data = data as *const [<W32 as Word>::InputBytes; 16];
+    let blocks = unsafe { core::slice::from_raw_parts(data, num) };
+    *state = block_data_order_(*state, blocks)
+}
+
+#[inline]
+fn block_data_order_(mut V: State, B: &[[<W32 as Word>::InputBytes; 16]]) -> State {
+    for B in B {
+        // GB/T 32905-2016 5.3.2: Message expansion.
+        let mut W = [W32::ZERO; ROUNDS + 4];
+        for j in 0..16 {
+            W[j] = W32::from_be_bytes(B[j]);
+        }
+        for j in 16..(ROUNDS + 4) {
+            W[j] = p1(W[j - 16] ^ W[j - 9] ^ rotl(W[j - 3], 15)) ^ rotl(W[j - 13], 7) ^ W[j - 6];
+        }
+
+        // GB/T 32905-2016 5.3.3: Compression.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = V;
+        for j in 0..ROUNDS {
+            let (t, ff, gg): (W32, BooleanFn, BooleanFn) = if j < 16 {
+                (Wrapping(0x79cc4519), parity, parity)
+            } else {
+                (Wrapping(0x7a879d8a), maj, ch)
+            };
+            let ss1 = rotl(rotl(a, 12) + e + rotl(t, (j % 32) as u32), 7);
+            let ss2 = ss1 ^ rotl(a, 12);
+            let tt1 = ff(a, b, c) + d + ss2 + (W[j] ^ W[j + 4]);
+            let tt2 = gg(e, f, g) + h + ss1 + W[j];
+            d = c;
+            c = rotl(b, 9);
+            b = a;
+            a = tt1;
+            h = g;
+            g = rotl(f, 19);
+            f = e;
+            e = p0(tt2);
+        }
+
+        for (v, x) in V.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+            *v ^= x;
+        }
+    }
+
+    V
+}
+
+#[inline(always)]
+fn parity(x: W32, y: W32, z: W32) -> W32 {
+    x ^ y ^ z
+}
+
+#[inline(always)]
+fn p0(x: W32) -> W32 {
+    x ^ rotl(x, 9) ^ rotl(x, 17)
+}
+
+#[inline(always)]
+fn p1(x: W32) -> W32 {
+    x ^ rotl(x, 15) ^ rotl(x, 23)
+}
+
+#[inline(always)]
+fn rotl(x: W32, n: u32) -> W32 {
+    Wrapping(x.0.rotate_left(n))
+}
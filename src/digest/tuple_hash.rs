@@ -0,0 +1,105 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! TupleHash, as specified in [NIST SP 800-185].
+//!
+//! TupleHash hashes a tuple of byte strings such that different tuples
+//! always have different encodings; e.g. `("ab", "c")` and `("a", "bc")` hash
+//! differently.
+//!
+//! ```
+//! use ring::digest::{tuple_hash, xof};
+//!
+//! let mut ctx = tuple_hash::Context::new(&xof::SHAKE128, b"My Tuple App");
+//! ctx.append(b"hello");
+//! ctx.append(b"world");
+//! let mut out = [0u8; 32];
+//! ctx.finish(&mut out);
+//!
+//! let mut one_shot = [0u8; 32];
+//! tuple_hash::tuple_hash(&xof::SHAKE128, &[b"hello", b"world"], b"My Tuple App", &mut one_shot);
+//! assert_eq!(out, one_shot);
+//! ```
+//!
+//! [NIST SP 800-185]: https://doi.org/10.6028/NIST.SP.800-185
+
+use super::xof;
+use crate::polyfill;
+
+/// A context for TupleHash calculations.
+#[derive(Clone)]
+pub struct Context {
+    inner: xof::Context,
+}
+
+impl Context {
+    /// Constructs a new context for TupleHash128 (when `algorithm` is
+    /// `xof::SHAKE128`) or TupleHash256 (when `algorithm` is
+    /// `xof::SHAKE256`) with the customization string `customization`.
+    pub fn new(algorithm: &'static xof::Algorithm, customization: &[u8]) -> Self {
+        Self {
+            inner: xof::Context::new_cshake_with_function_name(
+                algorithm,
+                b"TupleHash",
+                customization,
+            ),
+        }
+    }
+
+    /// Appends `element` to the tuple. Each call appends exactly one
+    /// element.
+    pub fn append(&mut self, element: &[u8]) {
+        self.inner.update_encoded_string(element);
+    }
+
+    /// Finishes the calculation, filling `out` with the `out.len()`-byte
+    /// TupleHash output.
+    ///
+    /// The output length is part of the input, so outputs of different
+    /// lengths are unrelated to each other. Use `finish_xof` instead for
+    /// TupleHashXOF.
+    pub fn finish(mut self, out: &mut [u8]) {
+        let len_bits = polyfill::u64_from_usize(out.len()).checked_mul(8).unwrap();
+        self.inner.update_right_encoded(len_bits);
+        self.inner.finish().squeeze(out);
+    }
+
+    /// Finishes the calculation as TupleHashXOF, returning a `Reader` from
+    /// which any amount of output can be squeezed.
+    pub fn finish_xof(mut self) -> xof::Reader {
+        self.inner.update_right_encoded(0);
+        self.inner.finish()
+    }
+
+    /// The algorithm that this context is using.
+    #[inline(always)]
+    pub fn algorithm(&self) -> &'static xof::Algorithm {
+        self.inner.algorithm()
+    }
+}
+
+/// Fills `out` with the TupleHash of `elements` using the customization
+/// string `customization`.
+pub fn tuple_hash(
+    algorithm: &'static xof::Algorithm,
+    elements: &[&[u8]],
+    customization: &[u8],
+    out: &mut [u8],
+) {
+    let mut ctx = Context::new(algorithm, customization);
+    for element in elements {
+        ctx.append(element);
+    }
+    ctx.finish(out);
+}
@@ -0,0 +1,334 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Extendable-output functions (XOFs).
+//!
+//! Unlike the algorithms in `digest`, an XOF can produce output of any
+//! length. Input is absorbed with `Context::update`; `Context::finish` then
+//! returns a `Reader` from which any amount of output can be squeezed.
+//!
+//! SHAKE128 and SHAKE256 are supported, as is cSHAKE, their customizable
+//! variant; see `Context::new_cshake`. So are TurboSHAKE128 and
+//! TurboSHAKE256, their faster reduced-round variants.
+//!
+//! ```
+//! use ring::digest::xof;
+//!
+//! let mut ctx = xof::Context::new(&xof::SHAKE128);
+//! ctx.update(b"hello, world");
+//! let mut reader = ctx.finish();
+//!
+//! let mut first = [0u8; 16];
+//! let mut second = [0u8; 100];
+//! reader.squeeze(&mut first);
+//! reader.squeeze(&mut second);
+//! ```
+
+use crate::{error, keccak, polyfill};
+
+/// An extendable-output function.
+pub struct Algorithm {
+    rate: usize,
+    rounds: usize,
+    id: AlgorithmID,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    SHAKE128,
+    SHAKE256,
+    TURBOSHAKE128,
+    TURBOSHAKE256,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+derive_debug_via_id!(Algorithm);
+
+impl Algorithm {
+    /// The internal block length, i.e. the rate of the sponge.
+    #[inline]
+    pub fn block_len(&self) -> usize {
+        self.rate
+    }
+}
+
+/// SHAKE128 as specified in [FIPS 202].
+///
+/// [FIPS 202]: https://doi.org/10.6028/NIST.FIPS.202
+pub static SHAKE128: Algorithm = Algorithm {
+    rate: (1600 - 2 * 128) / 8,
+    rounds: 24,
+    id: AlgorithmID::SHAKE128,
+};
+
+/// SHAKE256 as specified in [FIPS 202].
+///
+/// [FIPS 202]: https://doi.org/10.6028/NIST.FIPS.202
+pub static SHAKE256: Algorithm = Algorithm {
+    rate: (1600 - 2 * 256) / 8,
+    rounds: 24,
+    id: AlgorithmID::SHAKE256,
+};
+
+/// TurboSHAKE128 as specified in [RFC 9861].
+///
+/// TurboSHAKE is SHAKE with the number of rounds of the permutation halved
+/// to 12, and with a selectable domain separation byte; see
+/// `Context::new_turboshake`.
+///
+/// [RFC 9861]: https://www.rfc-editor.org/rfc/rfc9861
+pub static TURBOSHAKE128: Algorithm = Algorithm {
+    rate: (1600 - 2 * 128) / 8,
+    rounds: 12,
+    id: AlgorithmID::TURBOSHAKE128,
+};
+
+/// TurboSHAKE256 as specified in [RFC 9861].
+///
+/// See `TURBOSHAKE128`.
+///
+/// [RFC 9861]: https://www.rfc-editor.org/rfc/rfc9861
+pub static TURBOSHAKE256: Algorithm = Algorithm {
+    rate: (1600 - 2 * 256) / 8,
+    rounds: 12,
+    id: AlgorithmID::TURBOSHAKE256,
+};
+
+const MAX_RATE: usize = (1600 - 2 * 128) / 8;
+
+// The domain separation bits 1111 (SHAKE) or 00 (cSHAKE) followed by the
+// first bit of the pad10*1 padding. 0x1F is also TurboSHAKE's default domain
+// separation byte.
+const SHAKE_PAD: u8 = 0x1f;
+const CSHAKE_PAD: u8 = 0x04;
+
+/// Returns the big-endian encoding of `x` and the minimum number of bytes,
+/// at least one, needed to encode it; the encoding is the last that many
+/// bytes.
+fn encoded(x: u64) -> ([u8; 8], u8) {
+    let bytes = x.to_be_bytes();
+    let leading_zero_bytes = bytes.iter().take_while(|b| **b == 0).count();
+    let n = core::cmp::max(8 - leading_zero_bytes, 1);
+    (bytes, n as u8)
+}
+
+/// The absorbing phase of an XOF computation.
+#[derive(Clone)]
+pub struct Context {
+    state: keccak::State,
+    pending: [u8; MAX_RATE],
+    num_pending: usize,
+    algorithm: &'static Algorithm,
+
+    // The domain separation bits and the first bit of the pad10*1 padding.
+    pad: u8,
+}
+
+impl Context {
+    /// Constructs a new context.
+    pub fn new(algorithm: &'static Algorithm) -> Self {
+        Self {
+            state: [0; 25],
+            pending: [0; MAX_RATE],
+            num_pending: 0,
+            algorithm,
+            pad: SHAKE_PAD,
+        }
+    }
+
+    /// Constructs a new context for TurboSHAKE with the domain separation byte
+    /// `domain_separation`, which must be in the range `0x01..=0x7F`.
+    /// `Context::new` uses the default domain separation byte, `0x1F`.
+    ///
+    /// `algorithm` must be `TURBOSHAKE128` or `TURBOSHAKE256`.
+    pub fn new_turboshake(
+        algorithm: &'static Algorithm,
+        domain_separation: u8,
+    ) -> Result<Self, error::Unspecified> {
+        match algorithm.id {
+            AlgorithmID::TURBOSHAKE128 | AlgorithmID::TURBOSHAKE256 => {}
+            _ => return Err(error::Unspecified),
+        }
+        if !(0x01..=0x7f).contains(&domain_separation) {
+            return Err(error::Unspecified);
+        }
+        let mut ctx = Self::new(algorithm);
+        ctx.pad = domain_separation;
+        Ok(ctx)
+    }
+
+    /// Changes the domain separation byte of a TurboSHAKE context, which was
+    /// validated by `new_turboshake`.
+    pub(super) fn set_domain_separation(&mut self, domain_separation: u8) {
+        debug_assert!((0x01..=0x7f).contains(&domain_separation));
+        self.pad = domain_separation;
+    }
+
+    /// Constructs a new context for cSHAKE128 (when `algorithm` is
+    /// `SHAKE128`) or cSHAKE256 (when `algorithm` is `SHAKE256`), as
+    /// specified in [NIST SP 800-185], with the customization string
+    /// `customization`.
+    ///
+    /// cSHAKE with an empty customization string is the same as SHAKE.
+    ///
+    /// [NIST SP 800-185]: https://doi.org/10.6028/NIST.SP.800-185
+    pub fn new_cshake(algorithm: &'static Algorithm, customization: &[u8]) -> Self {
+        Self::new_cshake_with_function_name(algorithm, b"", customization)
+    }
+
+    /// Like `new_cshake`, with the function name `function_name`, which NIST
+    /// SP 800-185 reserves for functions that NIST defines on top of cSHAKE.
+    pub(crate) fn new_cshake_with_function_name(
+        algorithm: &'static Algorithm,
+        function_name: &[u8],
+        customization: &[u8],
+    ) -> Self {
+        let mut ctx = Self::new(algorithm);
+        if function_name.is_empty() && customization.is_empty() {
+            return ctx;
+        }
+        ctx.pad = CSHAKE_PAD;
+        ctx.update_bytepad(|ctx| {
+            ctx.update_encoded_string(function_name);
+            ctx.update_encoded_string(customization);
+        });
+        ctx
+    }
+
+    /// Absorbs `left_encode(rate)`, then whatever `f` absorbs, then zeros up
+    /// to the next multiple of the rate; this is `bytepad` of NIST SP
+    /// 800-185.
+    pub(crate) fn update_bytepad(&mut self, f: impl FnOnce(&mut Self)) {
+        self.update_left_encoded(polyfill::u64_from_usize(self.algorithm.rate));
+        f(self);
+        if self.num_pending > 0 {
+            self.pending[self.num_pending..self.algorithm.rate].fill(0);
+            self.absorb_pending();
+        }
+    }
+
+    /// Absorbs `encode_string(s)` of NIST SP 800-185.
+    pub(crate) fn update_encoded_string(&mut self, s: &[u8]) {
+        let len_bits = polyfill::u64_from_usize(s.len()).checked_mul(8).unwrap();
+        self.update_left_encoded(len_bits);
+        self.update(s);
+    }
+
+    /// Absorbs `left_encode(x)` of NIST SP 800-185.
+    pub(crate) fn update_left_encoded(&mut self, x: u64) {
+        let (bytes, n) = encoded(x);
+        self.update(&[n]);
+        self.update(&bytes[(8 - usize::from(n))..]);
+    }
+
+    /// Absorbs `right_encode(x)` of NIST SP 800-185.
+    pub(crate) fn update_right_encoded(&mut self, x: u64) {
+        let (bytes, n) = encoded(x);
+        self.update(&bytes[(8 - usize::from(n))..]);
+        self.update(&[n]);
+    }
+
+    /// Absorbs all the data in `data`. `update` may be called zero or more
+    /// times until `finish` is called.
+    pub fn update(&mut self, mut data: &[u8]) {
+        let rate = self.algorithm.rate;
+        while !data.is_empty() {
+            let to_copy = core::cmp::min(rate - self.num_pending, data.len());
+            let (chunk, rest) = data.split_at(to_copy);
+            self.pending[self.num_pending..][..to_copy].copy_from_slice(chunk);
+            self.num_pending += to_copy;
+            data = rest;
+            if self.num_pending == rate {
+                self.absorb_pending();
+            }
+        }
+    }
+
+    fn absorb_pending(&mut self) {
+        let rate = self.algorithm.rate;
+        keccak::xor_in(&mut self.state, &self.pending[..rate]);
+        keccak::permute(&mut self.state, self.algorithm.rounds);
+        self.num_pending = 0;
+    }
+
+    /// Finishes absorbing input and returns a `Reader` for the output.
+    /// `finish` consumes the context so no more input can be absorbed.
+    pub fn finish(mut self) -> Reader {
+        let rate = self.algorithm.rate;
+        self.pending[self.num_pending] = self.pad;
+        self.pending[(self.num_pending + 1)..rate].fill(0);
+        self.pending[rate - 1] |= 0x80;
+        keccak::xor_in(&mut self.state, &self.pending[..rate]);
+        keccak::permute(&mut self.state, self.algorithm.rounds);
+
+        let mut block = [0; MAX_RATE];
+        keccak::copy_out(&self.state, &mut block[..rate]);
+        Reader {
+            state: self.state,
+            block,
+            position: 0,
+            algorithm: self.algorithm,
+        }
+    }
+
+    /// The algorithm that this context is using.
+    #[inline(always)]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+/// The squeezing phase of an XOF computation.
+#[derive(Clone)]
+pub struct Reader {
+    state: keccak::State,
+    block: [u8; MAX_RATE],
+    position: usize,
+    algorithm: &'static Algorithm,
+}
+
+impl Reader {
+    /// Fills `out` with the next `out.len()` bytes of output.
+    ///
+    /// Squeezing `a` bytes and then `b` bytes produces the same output as
+    /// squeezing `a + b` bytes at once.
+    pub fn squeeze(&mut self, mut out: &mut [u8]) {
+        let rate = self.algorithm.rate;
+        while !out.is_empty() {
+            if self.position == rate {
+                keccak::permute(&mut self.state, self.algorithm.rounds);
+                keccak::copy_out(&self.state, &mut self.block[..rate]);
+                self.position = 0;
+            }
+            let to_copy = core::cmp::min(rate - self.position, out.len());
+            let (chunk, rest) = core::mem::take(&mut out).split_at_mut(to_copy);
+            chunk.copy_from_slice(&self.block[self.position..][..to_copy]);
+            self.position += to_copy;
+            out = rest;
+        }
+    }
+
+    /// The algorithm that was used.
+    #[inline(always)]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
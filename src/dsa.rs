@@ -0,0 +1,282 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! Verification of DSA signatures, for legacy use only.
+//!
+//! This is [FIPS 186-4] DSA with SHA-256 and 2048-bit or 3072-bit primes.
+//! There is no DSA signing.
+//!
+//! [FIPS 186-4]: https://doi.org/10.6028/NIST.FIPS.186-4
+
+use crate::{
+    arithmetic::{
+        bigint,
+        montgomery::{Unencoded, R},
+    },
+    bits, cpu, digest, error,
+    io::{self, der},
+    sealed, signature,
+};
+use alloc::vec;
+
+/// Parameters for DSA verification.
+pub struct DsaParameters {
+    digest_alg: &'static digest::Algorithm,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+enum AlgorithmID {
+    DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY,
+}
+
+derive_debug_via_id!(DsaParameters);
+
+/// Verification of DSA signatures using SHA-256, with (*L*, *N*) of (2048,
+/// 224), (2048, 256), or (3072, 256).
+///
+/// With `signature::UnparsedPublicKey` the public key is a DER-encoded
+/// `SubjectPublicKeyInfo` with the domain parameters, as described in
+/// [RFC 3279 Section 2.3.2]. The signature is a DER-encoded `Dss-Sig-Value`
+/// as described in [RFC 3279 Section 2.2.2].
+///
+/// Only available in `alloc` mode.
+///
+/// [RFC 3279 Section 2.3.2]: https://tools.ietf.org/html/rfc3279#section-2.3.2
+/// [RFC 3279 Section 2.2.2]: https://tools.ietf.org/html/rfc3279#section-2.2.2
+pub static DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY: DsaParameters = DsaParameters {
+    digest_alg: &digest::SHA256,
+    id: AlgorithmID::DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY,
+};
+
+impl signature::VerificationAlgorithm for DsaParameters {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let digest = digest::digest(self.digest_alg, msg.as_slice_less_safe());
+        self.verify_digest(public_key, &digest, signature)
+    }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let (p, q, g, y) = parse_spki(public_key)?;
+        verify_dsa(
+            self,
+            (
+                p.big_endian_without_leading_zero_as_input(),
+                q.big_endian_without_leading_zero_as_input(),
+                g.big_endian_without_leading_zero_as_input(),
+                y.big_endian_without_leading_zero_as_input(),
+            ),
+            digest,
+            signature,
+        )
+    }
+}
+
+impl sealed::Sealed for DsaParameters {}
+
+/// DSA public key components.
+///
+/// `p`, `q`, `g`, and `y` are big-endian-encoded unsigned integers without
+/// leading zeros.
+#[derive(Clone, Copy)]
+pub struct DsaPublicKeyComponents<B: AsRef<[u8]>> {
+    /// The prime modulus *p*.
+    pub p: B,
+
+    /// The prime divisor *q* of *p* - 1.
+    pub q: B,
+
+    /// The generator *g*.
+    pub g: B,
+
+    /// The public key *y* = *g*^*x* mod *p*.
+    pub y: B,
+}
+
+impl<B: AsRef<[u8]> + core::fmt::Debug> core::fmt::Debug for DsaPublicKeyComponents<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("DsaPublicKeyComponents")
+            .field("p", &self.p)
+            .field("q", &self.q)
+            .field("g", &self.g)
+            .field("y", &self.y)
+            .finish()
+    }
+}
+
+impl<B: AsRef<[u8]>> DsaPublicKeyComponents<B> {
+    /// Verifies that `signature` is a valid signature of `message` using
+    /// `self` as the public key.
+    ///
+    /// The signature is a DER-encoded `Dss-Sig-Value`.
+    pub fn verify(
+        &self,
+        params: &DsaParameters,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let _ = cpu::features();
+        let digest = digest::digest(params.digest_alg, message);
+        verify_dsa(
+            params,
+            (
+                untrusted::Input::from(self.p.as_ref()),
+                untrusted::Input::from(self.q.as_ref()),
+                untrusted::Input::from(self.g.as_ref()),
+                untrusted::Input::from(self.y.as_ref()),
+            ),
+            &digest,
+            untrusted::Input::from(signature),
+        )
+    }
+}
+
+// Type-level representations of the primes *p* and *q*. See
+// `super::bigint`'s module-level documentation.
+enum P {}
+enum Q {}
+
+fn parse_spki(
+    input: untrusted::Input,
+) -> Result<(io::Positive, io::Positive, io::Positive, io::Positive), error::Unspecified> {
+    // id-dsa, RFC 3279 Section 2.3.2.
+    const ID_DSA: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x38, 0x04, 0x01];
+
+    input.read_all(error::Unspecified, |input| {
+        der::nested(input, der::Tag::Sequence, error::Unspecified, |spki| {
+            let (p, q, g) = der::nested(spki, der::Tag::Sequence, error::Unspecified, |alg_id| {
+                let oid = der::expect_tag_and_get_value(alg_id, der::Tag::OID)?;
+                if oid.as_slice_less_safe() != ID_DSA {
+                    return Err(error::Unspecified);
+                }
+                der::nested(alg_id, der::Tag::Sequence, error::Unspecified, |params| {
+                    let p = der::positive_integer(params)?;
+                    let q = der::positive_integer(params)?;
+                    let g = der::positive_integer(params)?;
+                    Ok((p, q, g))
+                })
+            })?;
+            let y = der::bit_string_with_no_unused_bits(spki)?
+                .read_all(error::Unspecified, der::positive_integer)?;
+            Ok((p, q, g, y))
+        })
+    })
+}
+
+fn verify_dsa(
+    params: &DsaParameters,
+    (p, q, g, y): (
+        untrusted::Input,
+        untrusted::Input,
+        untrusted::Input,
+        untrusted::Input,
+    ),
+    digest: &digest::Digest,
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    if digest.algorithm() != params.digest_alg {
+        return Err(error::Unspecified);
+    }
+
+    // FIPS 186-4 Section 4.2.
+    let (p, p_bits) = bigint::Modulus::<P>::from_be_bytes_with_bit_length(p)?;
+    let (q, q_bits, q_bytes) = {
+        let (modulus, bits) = bigint::Modulus::<Q>::from_be_bytes_with_bit_length(q)?;
+        (modulus, bits, q.as_slice_less_safe())
+    };
+    match (p_bits.as_usize_bits(), q_bits.as_usize_bits()) {
+        (2048, 224) | (2048, 256) | (3072, 256) => (),
+        _ => return Err(error::Unspecified),
+    }
+    let g = parse_group_element(g, &p)?;
+    let y = parse_group_element(y, &p)?;
+
+    let (r, s) = signature.read_all(error::Unspecified, |input| {
+        der::nested(input, der::Tag::Sequence, error::Unspecified, |input| {
+            let r = der::positive_integer(input)?;
+            let s = der::positive_integer(input)?;
+            Ok((r, s))
+        })
+    })?;
+
+    // FIPS 186-4 Section 4.7.
+
+    // Step 1: 0 < r < q and 0 < s < q. Zero isn't a positive integer.
+    let r = bigint::Elem::from_be_bytes_padded(r.big_endian_without_leading_zero_as_input(), &q)?;
+    let s = bigint::Elem::from_be_bytes_padded(s.big_endian_without_leading_zero_as_input(), &q)?;
+
+    // Step 2: w = s**-1 mod q; z = the leftmost min(N, outlen) bits of
+    // Hash(M); u1 = z * w mod q; u2 = r * w mod q.
+    // The signature is public so `w` is computed in variable time, using
+    // Fermat's Little Theorem: w = s**(q - 2) mod q. `q` has no leading
+    // zeros and is at least 2**223, so this doesn't underflow.
+    let mut q_minus_2 = [0u8; MAX_Q_BYTES];
+    let q_minus_2 = &mut q_minus_2[..q_bytes.len()];
+    q_minus_2.copy_from_slice(q_bytes);
+    let mut borrow = 2;
+    for byte in q_minus_2.iter_mut().rev() {
+        let (difference, underflow) = byte.overflowing_sub(borrow);
+        *byte = difference;
+        borrow = u8::from(underflow);
+    }
+    let s = bigint::elem_mul(q.oneRR().as_ref(), s, &q);
+    let w = bigint::elem_exp_vartime_be_bytes(s, q_minus_2, &q.as_partial())?;
+    let n_bytes = q_bits.as_usize_bytes_rounded_up();
+    let z = &digest.as_ref()[..core::cmp::min(n_bytes, digest.as_ref().len())];
+    let z = bigint::Elem::from_be_bytes_reduced(untrusted::Input::from(z), &q)?;
+    let u1 = bigint::elem_mul(&w, z, &q);
+    let u2 = bigint::elem_mul(&w, r.clone(), &q);
+
+    // v = ((g**u1 * y**u2) mod p) mod q.
+    let mut u = [0u8; MAX_Q_BYTES];
+    let u = &mut u[..n_bytes];
+    u1.fill_be_bytes(u);
+    let g_u1 = bigint::elem_exp_vartime_be_bytes(g, u, &p.as_partial())?;
+    u2.fill_be_bytes(u);
+    let y_u2 = bigint::elem_exp_vartime_be_bytes(y, u, &p.as_partial())?;
+    let v = bigint::elem_mul(&g_u1, y_u2, &p).into_unencoded(&p);
+    let mut v_bytes = vec![0u8; p_bits.as_usize_bytes_rounded_up()];
+    v.fill_be_bytes(&mut v_bytes);
+    let v = bigint::Elem::<Q, Unencoded>::from_be_bytes_reduced(
+        untrusted::Input::from(&v_bytes[..]),
+        &q,
+    )?;
+
+    // Step 3: The signature is valid if v == r.
+    bigint::elem_verify_equal_consttime(&v, &r)
+}
+
+// Parses `g` or `y`, which must be in the range (1, p), into Montgomery form.
+fn parse_group_element(
+    input: untrusted::Input,
+    p: &bigint::Modulus<P>,
+) -> Result<bigint::Elem<P, R>, error::Unspecified> {
+    let (value, bits) = bigint::Nonnegative::from_be_bytes_with_bit_length(input)?;
+    if bits < bits::BitLength::from_usize_bits(2) {
+        return Err(error::Unspecified);
+    }
+    let value = value.to_elem(p)?;
+    Ok(bigint::elem_mul(p.oneRR().as_ref(), value, p))
+}
+
+const MAX_Q_BYTES: usize = 256 / 8;
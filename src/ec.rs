@@ -39,9 +39,12 @@ pub enum CurveID {
     Curve25519,
     P256,
     P384,
+    P521,
+    Secp256k1,
+    Sm2,
 }
 
-const ELEM_MAX_BITS: usize = 384;
+const ELEM_MAX_BITS: usize = 521;
 pub const ELEM_MAX_BYTES: usize = (ELEM_MAX_BITS + 7) / 8;
 
 pub const SCALAR_MAX_BYTES: usize = ELEM_MAX_BYTES;
@@ -52,11 +55,13 @@ const SEED_MAX_BYTES: usize = ELEM_MAX_BYTES;
 /// This is NOT the maximum length of a PKCS#8 document that can be consumed by
 /// `pkcs8::unwrap_key()`.
 ///
-/// `40` is the length of the P-384 template. It is actually one byte shorter
+/// `42` is the length of the P-521 template. It is actually one byte shorter
 /// than the P-256 template, but the private key and the public key are much
 /// longer.
-pub const PKCS8_DOCUMENT_MAX_LEN: usize = 40 + SCALAR_MAX_BYTES + keys::PUBLIC_KEY_MAX_LEN;
+pub const PKCS8_DOCUMENT_MAX_LEN: usize = 42 + SCALAR_MAX_BYTES + keys::PUBLIC_KEY_MAX_LEN;
 
+pub mod bls12_381;
 pub mod curve25519;
+pub mod curve448;
 mod keys;
 pub mod suite_b;
@@ -0,0 +1,571 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! BLS signatures over BLS12-381, as specified in
+//! draft-irtf-cfrg-bls-signature-05.
+
+use super::{
+    curve::{Curve, Point, G1, G2, R},
+    fp12::Fp12,
+    hash_to_curve, pairing,
+};
+use crate::{digest, error, hkdf, hmac, rand, sealed, signature};
+use core::convert::TryInto;
+
+/// The length of a private key.
+pub const PRIVATE_KEY_LEN: usize = 32;
+
+// The length of the longest public key or signature: a compressed point of
+// G2.
+const MAX_LEN: usize = G2::ENCODED_LEN;
+
+/// A BLS signature scheme.
+pub struct Algorithm {
+    id: AlgorithmID,
+    variant: Variant,
+    scheme: Scheme,
+    dst: &'static [u8],
+    pop_dst: &'static [u8],
+}
+
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+enum AlgorithmID {
+    BLS12_381_MIN_PK_BASIC,
+    BLS12_381_MIN_PK_POP,
+    BLS12_381_MIN_SIG_BASIC,
+    BLS12_381_MIN_SIG_POP,
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Clone, Copy)]
+enum Variant {
+    MinPk,
+    MinSig,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Scheme {
+    Basic,
+    ProofOfPossession,
+}
+
+/// The minimal-pubkey-size variant of the basic scheme: 48-byte public keys
+/// in G1 and 96-byte signatures in G2.
+///
+/// `aggregate_verify` requires the messages to be distinct.
+pub static BLS12_381_MIN_PK_BASIC: Algorithm = Algorithm {
+    id: AlgorithmID::BLS12_381_MIN_PK_BASIC,
+    variant: Variant::MinPk,
+    scheme: Scheme::Basic,
+    dst: b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_",
+    pop_dst: b"",
+};
+
+/// The minimal-pubkey-size variant of the proof-of-possession scheme:
+/// 48-byte public keys in G1 and 96-byte signatures in G2.
+///
+/// This is the scheme that Ethereum's consensus layer uses.
+pub static BLS12_381_MIN_PK_POP: Algorithm = Algorithm {
+    id: AlgorithmID::BLS12_381_MIN_PK_POP,
+    variant: Variant::MinPk,
+    scheme: Scheme::ProofOfPossession,
+    dst: b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_",
+    pop_dst: b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_",
+};
+
+/// The minimal-signature-size variant of the basic scheme: 96-byte public
+/// keys in G2 and 48-byte signatures in G1.
+///
+/// `aggregate_verify` requires the messages to be distinct.
+pub static BLS12_381_MIN_SIG_BASIC: Algorithm = Algorithm {
+    id: AlgorithmID::BLS12_381_MIN_SIG_BASIC,
+    variant: Variant::MinSig,
+    scheme: Scheme::Basic,
+    dst: b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_",
+    pop_dst: b"",
+};
+
+/// The minimal-signature-size variant of the proof-of-possession scheme:
+/// 96-byte public keys in G2 and 48-byte signatures in G1.
+pub static BLS12_381_MIN_SIG_POP: Algorithm = Algorithm {
+    id: AlgorithmID::BLS12_381_MIN_SIG_POP,
+    variant: Variant::MinSig,
+    scheme: Scheme::ProofOfPossession,
+    dst: b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_",
+    pop_dst: b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_",
+};
+
+impl Algorithm {
+    /// The length of a public key.
+    pub fn public_key_len(&self) -> usize {
+        match self.variant {
+            Variant::MinPk => G1::ENCODED_LEN,
+            Variant::MinSig => G2::ENCODED_LEN,
+        }
+    }
+
+    /// The length of a signature.
+    pub fn signature_len(&self) -> usize {
+        match self.variant {
+            Variant::MinPk => G2::ENCODED_LEN,
+            Variant::MinSig => G1::ENCODED_LEN,
+        }
+    }
+}
+
+impl signature::VerificationAlgorithm for Algorithm {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        verify(
+            self,
+            public_key.as_slice_less_safe(),
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+}
+
+impl sealed::Sealed for Algorithm {}
+
+/// A BLS key pair.
+pub struct KeyPair {
+    algorithm: &'static Algorithm,
+
+    // Big-endian, in [1, r).
+    private_key: [u8; PRIVATE_KEY_LEN],
+
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(KeyPair, stringify!(KeyPair), public_key);
+
+impl KeyPair {
+    /// Generates a new key pair from 32 bytes of randomness from `rng`.
+    pub fn generate(
+        algorithm: &'static Algorithm,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::Unspecified> {
+        let ikm: [u8; 32] = rand::generate(rng)?.expose();
+        Self::from_ikm(algorithm, &ikm, b"").map_err(|_| error::Unspecified)
+    }
+
+    /// Derives a key pair from the secret input keying material `ikm` and
+    /// the optional `key_info`, which may be empty, with the `KeyGen`
+    /// procedure of draft-irtf-cfrg-bls-signature-05 Section 2.3.
+    ///
+    /// `ikm` must be at least 32 bytes long.
+    pub fn from_ikm(
+        algorithm: &'static Algorithm,
+        ikm: &[u8],
+        key_info: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        // L = ceil((3 * ceil(log2(r))) / 16).
+        const L: usize = 48;
+
+        struct OkmLen;
+        impl hkdf::KeyType for OkmLen {
+            fn len(&self) -> usize {
+                L
+            }
+        }
+
+        if ikm.len() < 32 {
+            return Err(error::KeyRejected::too_small());
+        }
+
+        let mut salt = digest::digest(&digest::SHA256, b"BLS-SIG-KEYGEN-SALT-");
+        loop {
+            // HKDF-Extract(salt, IKM || I2OSP(0, 1)).
+            let prk = {
+                let key = hmac::Key::new(hmac::HMAC_SHA256, salt.as_ref());
+                let mut ctx = hmac::Context::with_key(&key);
+                ctx.update(ikm);
+                ctx.update(&[0]);
+                ctx.sign()
+            };
+            let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, prk.as_ref());
+            let mut okm = [0; L];
+            prk.expand(&[key_info, &(L as u16).to_be_bytes()], OkmLen)
+                .and_then(|okm_| okm_.fill(&mut okm))
+                .map_err(|_| error::KeyRejected::unexpected_error())?;
+
+            let private_key = scalar_reduced(&okm);
+            if private_key.iter().any(|&b| b != 0) {
+                return Ok(Self::from_private_key_unchecked(algorithm, private_key));
+            }
+            salt = digest::digest(&digest::SHA256, salt.as_ref());
+        }
+    }
+
+    /// Constructs a key pair from the 32-byte big-endian private key, which
+    /// must be in the range [1, r).
+    pub fn from_private_key(
+        algorithm: &'static Algorithm,
+        private_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let private_key: [u8; PRIVATE_KEY_LEN] = private_key
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        if !scalar_is_valid(&private_key) {
+            return Err(error::KeyRejected::invalid_component());
+        }
+        Ok(Self::from_private_key_unchecked(algorithm, private_key))
+    }
+
+    fn from_private_key_unchecked(
+        algorithm: &'static Algorithm,
+        private_key: [u8; PRIVATE_KEY_LEN],
+    ) -> Self {
+        let mut public_key = PublicKey {
+            bytes: [0; MAX_LEN],
+            len: algorithm.public_key_len(),
+        };
+        let out = &mut public_key.bytes[..public_key.len];
+        match algorithm.variant {
+            Variant::MinPk => G1::GENERATOR.mul(&private_key).write_compressed(out),
+            Variant::MinSig => G2::GENERATOR.mul(&private_key).write_compressed(out),
+        }
+        Self {
+            algorithm,
+            private_key,
+            public_key,
+        }
+    }
+
+    /// The algorithm this key pair is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// Signs `msg`.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        self.core_sign(msg, self.algorithm.dst)
+    }
+
+    /// Computes the proof of possession of the private key, `PopProve` of
+    /// draft-irtf-cfrg-bls-signature-05 Section 3.3.2.
+    ///
+    /// Fails unless the algorithm is a proof-of-possession scheme.
+    pub fn prove_possession(&self) -> Result<Signature, error::Unspecified> {
+        if self.algorithm.scheme != Scheme::ProofOfPossession {
+            return Err(error::Unspecified);
+        }
+        Ok(self.core_sign(self.public_key.as_ref(), self.algorithm.pop_dst))
+    }
+
+    fn core_sign(&self, msg: &[u8], dst: &[u8]) -> Signature {
+        let mut signature = Signature {
+            bytes: [0; MAX_LEN],
+            len: self.algorithm.signature_len(),
+        };
+        let out = &mut signature.bytes[..signature.len];
+        match self.algorithm.variant {
+            Variant::MinPk => hash_to_curve::hash_to_g2(msg, dst)
+                .mul(&self.private_key)
+                .write_compressed(out),
+            Variant::MinSig => hash_to_curve::hash_to_g1(msg, dst)
+                .mul(&self.private_key)
+                .write_compressed(out),
+        }
+        signature
+    }
+}
+
+impl signature::KeyPair for KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+/// A compressed BLS public key.
+#[derive(Clone, Copy)]
+pub struct PublicKey {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+/// A compressed BLS signature, or an aggregate of them.
+#[derive(Clone, Copy)]
+pub struct Signature {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(Signature);
+
+/// Verifies that `signature` is a valid signature of `msg` by
+/// `public_key`.
+pub fn verify(
+    algorithm: &Algorithm,
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    core_aggregate_verify(
+        algorithm,
+        core::iter::once((public_key, msg)),
+        signature,
+        algorithm.dst,
+    )
+}
+
+/// Aggregates `signatures` into a single signature.
+///
+/// Fails if `signatures` is empty or if any of them is invalid.
+pub fn aggregate(
+    algorithm: &Algorithm,
+    signatures: &[&[u8]],
+) -> Result<Signature, error::Unspecified> {
+    if signatures.is_empty() {
+        return Err(error::Unspecified);
+    }
+    let mut aggregate = Signature {
+        bytes: [0; MAX_LEN],
+        len: algorithm.signature_len(),
+    };
+    let out = &mut aggregate.bytes[..aggregate.len];
+    match algorithm.variant {
+        Variant::MinPk => sum::<G2>(signatures)?.write_compressed(out),
+        Variant::MinSig => sum::<G1>(signatures)?.write_compressed(out),
+    }
+    Ok(aggregate)
+}
+
+/// Verifies that `signature` is an aggregate of a signature of
+/// `messages[i]` by `public_keys[i]` for each `i`.
+///
+/// For the basic schemes, the messages must be distinct.
+pub fn aggregate_verify(
+    algorithm: &Algorithm,
+    public_keys: &[&[u8]],
+    messages: &[&[u8]],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    if public_keys.is_empty() || public_keys.len() != messages.len() {
+        return Err(error::Unspecified);
+    }
+    if algorithm.scheme == Scheme::Basic {
+        for (i, a) in messages.iter().enumerate() {
+            if messages[(i + 1)..].iter().any(|b| a == b) {
+                return Err(error::Unspecified);
+            }
+        }
+    }
+    core_aggregate_verify(
+        algorithm,
+        public_keys.iter().copied().zip(messages.iter().copied()),
+        signature,
+        algorithm.dst,
+    )
+}
+
+/// Verifies that `signature` is an aggregate of signatures of the same
+/// message `msg` by each of `public_keys`.
+///
+/// This is only sound when every public key's proof of possession has been
+/// checked with `verify_possession`, so it fails unless the algorithm is a
+/// proof-of-possession scheme.
+pub fn fast_aggregate_verify(
+    algorithm: &Algorithm,
+    public_keys: &[&[u8]],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    if algorithm.scheme != Scheme::ProofOfPossession || public_keys.is_empty() {
+        return Err(error::Unspecified);
+    }
+    let mut aggregate = [0; MAX_LEN];
+    let aggregate = &mut aggregate[..algorithm.public_key_len()];
+    match algorithm.variant {
+        Variant::MinPk => sum::<G1>(public_keys)?.write_compressed(aggregate),
+        Variant::MinSig => sum::<G2>(public_keys)?.write_compressed(aggregate),
+    }
+    verify(algorithm, aggregate, msg, signature)
+}
+
+/// Verifies that `proof` is a proof of possession of the private key for
+/// `public_key`, `PopVerify` of draft-irtf-cfrg-bls-signature-05 Section
+/// 3.3.3.
+///
+/// Fails unless the algorithm is a proof-of-possession scheme.
+pub fn verify_possession(
+    algorithm: &Algorithm,
+    public_key: &[u8],
+    proof: &[u8],
+) -> Result<(), error::Unspecified> {
+    if algorithm.scheme != Scheme::ProofOfPossession {
+        return Err(error::Unspecified);
+    }
+    core_aggregate_verify(
+        algorithm,
+        core::iter::once((public_key, public_key)),
+        proof,
+        algorithm.pop_dst,
+    )
+}
+
+// Checks that e(g, signature) = e(public_key_1, H(msg_1)) * ... for the
+// generator g of the public key group.
+fn core_aggregate_verify<'a>(
+    algorithm: &Algorithm,
+    public_keys_and_messages: impl Iterator<Item = (&'a [u8], &'a [u8])>,
+    signature: &[u8],
+    dst: &[u8],
+) -> Result<(), error::Unspecified> {
+    let mut f = match algorithm.variant {
+        Variant::MinPk => pairing::miller_loop(
+            &G1::GENERATOR.neg(),
+            &Point::<G2>::from_compressed_vartime(signature)?,
+        ),
+        Variant::MinSig => pairing::miller_loop(
+            &Point::<G1>::from_compressed_vartime(signature)?,
+            &G2::GENERATOR.neg(),
+        ),
+    };
+    for (public_key, msg) in public_keys_and_messages {
+        let f_i: Fp12 = match algorithm.variant {
+            Variant::MinPk => pairing::miller_loop(
+                &public_key_from_bytes::<G1>(public_key)?,
+                &hash_to_curve::hash_to_g2(msg, dst),
+            ),
+            Variant::MinSig => pairing::miller_loop(
+                &hash_to_curve::hash_to_g1(msg, dst),
+                &public_key_from_bytes::<G2>(public_key)?,
+            ),
+        };
+        f = f.mul(&f_i);
+    }
+    if !pairing::final_exponentiation(&f).is_one() {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+// `KeyValidate` of draft-irtf-cfrg-bls-signature-05 Section 2.5.
+fn public_key_from_bytes<C: Curve>(public_key: &[u8]) -> Result<Point<C>, error::Unspecified> {
+    let p = Point::<C>::from_compressed_vartime(public_key)?;
+    if p.is_identity() {
+        return Err(error::Unspecified);
+    }
+    Ok(p)
+}
+
+fn sum<C: Curve>(points: &[&[u8]]) -> Result<Point<C>, error::Unspecified> {
+    points.iter().try_fold(Point::IDENTITY, |acc, p| {
+        Ok(acc.add(&Point::<C>::from_compressed_vartime(p)?))
+    })
+}
+
+// Whether the big-endian `scalar` is in [1, r), in constant time.
+fn scalar_is_valid(scalar: &[u8; PRIVATE_KEY_LEN]) -> bool {
+    let mut borrow = 0u16;
+    for (s, r) in scalar.iter().zip(R.iter()).rev() {
+        borrow = (u16::from(*s)
+            .wrapping_sub(u16::from(*r))
+            .wrapping_sub(borrow)
+            >> 8)
+            & 1;
+    }
+    let is_zero = scalar.iter().fold(0, |acc, b| acc | b) == 0;
+    (borrow == 1) & !is_zero
+}
+
+// Reduces the big-endian `bytes` modulo r in constant time.
+fn scalar_reduced(bytes: &[u8]) -> [u8; PRIVATE_KEY_LEN] {
+    // Shift in one bit at a time, keeping `acc < r`. `2 * acc + 1 < 2**256`
+    // always fits.
+    let mut acc = [0u8; PRIVATE_KEY_LEN];
+    for &byte in bytes {
+        for bit in (0..8).rev() {
+            let mut carry = (byte >> bit) & 1;
+            for a in acc.iter_mut().rev() {
+                let next = *a >> 7;
+                *a = (*a << 1) | carry;
+                carry = next;
+            }
+
+            // Subtract r if `acc >= r`.
+            let mut difference = [0u8; PRIVATE_KEY_LEN];
+            let mut borrow = 0u16;
+            for ((d, a), r) in difference.iter_mut().zip(acc.iter()).zip(R.iter()).rev() {
+                let t = u16::from(*a)
+                    .wrapping_sub(u16::from(*r))
+                    .wrapping_sub(borrow);
+                *d = t as u8;
+                borrow = (t >> 8) & 1;
+            }
+            // All ones if there was no borrow, i.e. if `acc >= r`.
+            let mask = (borrow as u8).wrapping_sub(1);
+            acc.iter_mut()
+                .zip(difference.iter())
+                .for_each(|(a, d)| *a = (d & mask) | (*a & !mask));
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_test() {
+        let mut r_minus_1 = R;
+        r_minus_1[31] -= 1;
+        assert!(scalar_is_valid(&r_minus_1));
+        assert!(!scalar_is_valid(&R));
+        assert!(!scalar_is_valid(&[0; 32]));
+        assert!(!scalar_is_valid(&[0xff; 32]));
+        let mut one = [0; 32];
+        one[31] = 1;
+        assert!(scalar_is_valid(&one));
+
+        assert_eq!(scalar_reduced(&R), [0; 32]);
+        assert_eq!(scalar_reduced(&r_minus_1), r_minus_1);
+        let mut r_plus_1 = [0; 48];
+        r_plus_1[16..].copy_from_slice(&R);
+        r_plus_1[47] += 1;
+        assert_eq!(scalar_reduced(&r_plus_1), one);
+        // 2**384 - 1 mod r, computed independently.
+        assert_eq!(
+            &scalar_reduced(&[0xff; 48])[..],
+            &crate::test::from_hex(
+                "2dbeaf1fd4843acb7abbe5687369510a9277efb8ac0a600dcf2ab21bf81f712c"
+            )
+            .unwrap()[..]
+        );
+    }
+}
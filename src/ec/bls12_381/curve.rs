@@ -0,0 +1,546 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The groups G1 and G2 of BLS12-381: the order-r subgroups of
+//! E(GF(p)): y**2 = x**3 + 4 and of its twist
+//! E'(GF(p**2)): y**2 = x**3 + 4(u + 1).
+//!
+//! Points are in homogeneous projective coordinates (X : Y : Z), where
+//! x = X/Z and y = Y/Z, and use the complete addition formulas of
+//! "Complete addition formulas for prime order elliptic curves" by Renes,
+//! Costello, and Batina, so that there are no exceptional cases to branch
+//! on.
+
+use super::{
+    fp::{Field, Fp},
+    fp2::Fp2,
+};
+use crate::error;
+
+pub trait Curve: Sized {
+    type Elem: Field;
+
+    /// The length of the compressed encoding of a point.
+    const ENCODED_LEN: usize;
+
+    const B: Self::Elem;
+
+    // 3 * B.
+    const B3: Self::Elem;
+
+    const GENERATOR: Point<Self>;
+}
+
+pub enum G1 {}
+
+pub enum G2 {}
+
+impl Curve for G1 {
+    type Elem = Fp;
+
+    const ENCODED_LEN: usize = 48;
+
+    const B: Fp = Fp::from_montgomery([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ]);
+
+    const B3: Fp = Fp::from_montgomery([
+        0x447600000027552e,
+        0xdcb8009a43480020,
+        0x6f7ee9ce4a6e8b59,
+        0xb10330b7c0a95bc6,
+        0x6140b1fcfb1e54b7,
+        0x0381be097f0bb4e1,
+    ]);
+
+    const GENERATOR: Point<Self> = Point {
+        x: Fp::from_montgomery([
+            0x5cb38790fd530c16,
+            0x7817fc679976fff5,
+            0x154f95c7143ba1c1,
+            0xf0ae6acdf3d0e747,
+            0xedce6ecc21dbf440,
+            0x120177419e0bfb75,
+        ]),
+        y: Fp::from_montgomery([
+            0xbaac93d50ce72271,
+            0x8c22631a7918fd8e,
+            0xdd595f13570725ce,
+            0x51ac582950405194,
+            0x0e1c8c3fad0059c0,
+            0x0bbc3efc5008a26a,
+        ]),
+        z: Fp::ONE,
+    };
+}
+
+impl Curve for G2 {
+    type Elem = Fp2;
+
+    const ENCODED_LEN: usize = 96;
+
+    const B: Fp2 = Fp2 {
+        c0: Fp::from_montgomery([
+            0xaa270000000cfff3,
+            0x53cc0032fc34000a,
+            0x478fe97a6b0a807f,
+            0xb1d37ebee6ba24d7,
+            0x8ec9733bbf78ab2f,
+            0x09d645513d83de7e,
+        ]),
+        c1: Fp::from_montgomery([
+            0xaa270000000cfff3,
+            0x53cc0032fc34000a,
+            0x478fe97a6b0a807f,
+            0xb1d37ebee6ba24d7,
+            0x8ec9733bbf78ab2f,
+            0x09d645513d83de7e,
+        ]),
+    };
+
+    const B3: Fp2 = Fp2 {
+        c0: Fp::from_montgomery([
+            0x447600000027552e,
+            0xdcb8009a43480020,
+            0x6f7ee9ce4a6e8b59,
+            0xb10330b7c0a95bc6,
+            0x6140b1fcfb1e54b7,
+            0x0381be097f0bb4e1,
+        ]),
+        c1: Fp::from_montgomery([
+            0x447600000027552e,
+            0xdcb8009a43480020,
+            0x6f7ee9ce4a6e8b59,
+            0xb10330b7c0a95bc6,
+            0x6140b1fcfb1e54b7,
+            0x0381be097f0bb4e1,
+        ]),
+    };
+
+    const GENERATOR: Point<Self> = Point {
+        x: Fp2 {
+            c0: Fp::from_montgomery([
+                0xf5f28fa202940a10,
+                0xb3f5fb2687b4961a,
+                0xa1a893b53e2ae580,
+                0x9894999d1a3caee9,
+                0x6f67b7631863366b,
+                0x058191924350bcd7,
+            ]),
+            c1: Fp::from_montgomery([
+                0xa5a9c0759e23f606,
+                0xaaa0c59dbccd60c3,
+                0x3bb17e18e2867806,
+                0x1b1ab6cc8541b367,
+                0xc2b6ed0ef2158547,
+                0x11922a097360edf3,
+            ]),
+        },
+        y: Fp2 {
+            c0: Fp::from_montgomery([
+                0x4c730af860494c4a,
+                0x597cfa1f5e369c5a,
+                0xe7e6856caa0a635a,
+                0xbbefb5e96e0d495f,
+                0x07d3a975f0ef25a2,
+                0x0083fd8e7e80dae5,
+            ]),
+            c1: Fp::from_montgomery([
+                0xadc0fc92df64b05d,
+                0x18aa270a2b1461dc,
+                0x86adac6a3be4eba0,
+                0x79495c4ec93da33a,
+                0xe7175850a43ccaed,
+                0x0b2bc2a163de1bf2,
+            ]),
+        },
+        z: Fp2::ONE,
+    };
+}
+
+/// The order of G1 and G2, big-endian.
+pub const R: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// The absolute value of the curve parameter x = -0xd201000000010000,
+/// big-endian.
+pub const X_ABS: [u8; 8] = [0xd2, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00];
+
+// The flags in the most significant bits of the first byte of a compressed
+// point encoding.
+const COMPRESSED_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+
+pub struct Point<C: Curve> {
+    pub x: C::Elem,
+    pub y: C::Elem,
+    pub z: C::Elem,
+}
+
+impl<C: Curve> Clone for Point<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Curve> Copy for Point<C> {}
+
+impl<C: Curve> Point<C> {
+    pub const IDENTITY: Self = Self {
+        x: C::Elem::ZERO,
+        y: C::Elem::ONE,
+        z: C::Elem::ZERO,
+    };
+
+    pub fn from_affine(x: C::Elem, y: C::Elem) -> Self {
+        Self {
+            x,
+            y,
+            z: C::Elem::ONE,
+        }
+    }
+
+    /// Returns the point (x/z, y/z), or the identity if `z` is zero.
+    pub fn from_projective(x: C::Elem, y: C::Elem, z: C::Elem) -> Self {
+        let is_zero = z.is_zero();
+        let p = Self { x, y, z };
+        Self::select(u64::from(is_zero).wrapping_neg(), &Self::IDENTITY, &p)
+    }
+
+    /// Decodes a compressed point and checks that it is in the order-r
+    /// subgroup. The identity is accepted.
+    pub fn from_compressed_vartime(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        if bytes.len() != C::ENCODED_LEN {
+            return Err(error::Unspecified);
+        }
+        let flags = bytes[0];
+        if flags & COMPRESSED_FLAG == 0 {
+            return Err(error::Unspecified);
+        }
+        let mut x = [0; 96];
+        let x = &mut x[..C::ENCODED_LEN];
+        x.copy_from_slice(bytes);
+        x[0] &= !(COMPRESSED_FLAG | INFINITY_FLAG | SIGN_FLAG);
+
+        if flags & INFINITY_FLAG != 0 {
+            if flags & SIGN_FLAG != 0 || x.iter().any(|&b| b != 0) {
+                return Err(error::Unspecified);
+            }
+            return Ok(Self::IDENTITY);
+        }
+
+        let x = C::Elem::from_bytes_checked(x)?;
+        let mut y = x
+            .square()
+            .mul(&x)
+            .add(&C::B)
+            .sqrt_vartime()
+            .ok_or(error::Unspecified)?;
+        if y.is_lexicographically_largest() != (flags & SIGN_FLAG != 0) {
+            y = y.neg();
+        }
+
+        let p = Self::from_affine(x, y);
+        if !p.is_torsion_free_vartime() {
+            return Err(error::Unspecified);
+        }
+        Ok(p)
+    }
+
+    /// Writes the compressed encoding of the point to `out`, which must be
+    /// `C::ENCODED_LEN` bytes long.
+    pub fn write_compressed(&self, out: &mut [u8]) {
+        match self.to_affine() {
+            None => {
+                out.iter_mut().for_each(|b| *b = 0);
+                out[0] = COMPRESSED_FLAG | INFINITY_FLAG;
+            }
+            Some((x, y)) => {
+                x.write_bytes(out);
+                out[0] |= COMPRESSED_FLAG;
+                if y.is_lexicographically_largest() {
+                    out[0] |= SIGN_FLAG;
+                }
+            }
+        }
+    }
+
+    /// Returns the affine coordinates, or `None` for the identity.
+    pub fn to_affine(self) -> Option<(C::Elem, C::Elem)> {
+        if self.is_identity() {
+            return None;
+        }
+        let z_inv = self.z.invert();
+        Some((self.x.mul(&z_inv), self.y.mul(&z_inv)))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    #[cfg(test)]
+    pub fn ct_eq(&self, b: &Self) -> bool {
+        self.x.mul(&b.z).ct_eq(&b.x.mul(&self.z)) & self.y.mul(&b.z).ct_eq(&b.y.mul(&self.z))
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y.neg(),
+            z: self.z,
+        }
+    }
+
+    /// Algorithm 7 of Renes, Costello, and Batina.
+    pub fn add(&self, b: &Self) -> Self {
+        let t0 = self.x.mul(&b.x);
+        let t1 = self.y.mul(&b.y);
+        let t2 = self.z.mul(&b.z);
+        let t3 = self.x.add(&self.y).mul(&b.x.add(&b.y)).sub(&t0.add(&t1));
+        let t4 = self.y.add(&self.z).mul(&b.y.add(&b.z)).sub(&t1.add(&t2));
+        let y3 = self.x.add(&self.z).mul(&b.x.add(&b.z)).sub(&t0.add(&t2));
+        let t0 = t0.double().add(&t0);
+        let t2 = C::B3.mul(&t2);
+        let z3 = t1.add(&t2);
+        let t1 = t1.sub(&t2);
+        let y3 = C::B3.mul(&y3);
+        Self {
+            x: t3.mul(&t1).sub(&t4.mul(&y3)),
+            y: t1.mul(&z3).add(&y3.mul(&t0)),
+            z: z3.mul(&t4).add(&t0.mul(&t3)),
+        }
+    }
+
+    /// Algorithm 9 of Renes, Costello, and Batina.
+    pub fn double(&self) -> Self {
+        let t0 = self.y.square();
+        let z3 = t0.double().double().double();
+        let t1 = self.y.mul(&self.z);
+        let t2 = C::B3.mul(&self.z.square());
+        let x3 = t2.mul(&z3);
+        let y3 = t0.add(&t2);
+        let z3 = t1.mul(&z3);
+        let t2 = t2.double().add(&t2);
+        let t0 = t0.sub(&t2);
+        let y3 = t0.mul(&y3).add(&x3);
+        let x3 = t0.mul(&self.x.mul(&self.y)).double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    fn select(mask: u64, a: &Self, b: &Self) -> Self {
+        Self {
+            x: C::Elem::select(mask, &a.x, &b.x),
+            y: C::Elem::select(mask, &a.y, &b.y),
+            z: C::Elem::select(mask, &a.z, &b.z),
+        }
+    }
+
+    /// Returns `[scalar]self`, where `scalar` is big-endian, in constant
+    /// time.
+    pub fn mul(&self, scalar: &[u8]) -> Self {
+        // table[i] = [i]self.
+        let mut table = [Self::IDENTITY; 16];
+        for i in 1..table.len() {
+            table[i] = table[i - 1].add(self);
+        }
+
+        let mut r = Self::IDENTITY;
+        for &byte in scalar {
+            for &nibble in [byte >> 4, byte & 0xf].iter() {
+                for _ in 0..4 {
+                    r = r.double();
+                }
+                let mut entry = Self::IDENTITY;
+                for (i, candidate) in table.iter().enumerate() {
+                    // All ones if `i == nibble`.
+                    let mask = ((i as u64 ^ u64::from(nibble)).wrapping_sub(1) as i64 >> 63) as u64;
+                    entry = Self::select(mask, candidate, &entry);
+                }
+                r = r.add(&entry);
+            }
+        }
+        r
+    }
+
+    /// Returns `[x]self` for the curve parameter x.
+    pub fn mul_by_x(&self) -> Self {
+        self.mul(&X_ABS).neg()
+    }
+
+    /// Whether `[r]self` is the identity.
+    pub fn is_torsion_free_vartime(&self) -> bool {
+        self.mul(&R).is_identity()
+    }
+}
+
+impl Point<G2> {
+    /// The endomorphism ψ = untwist ∘ Frobenius ∘ twist of RFC 9380
+    /// Appendix G.3.
+    pub fn psi(&self) -> Self {
+        // PSI_X = 1 / (u + 1)**((p - 1) / 3), PSI_Y = 1 / (u + 1)**((p - 1) / 2).
+        const PSI_X: Fp2 = Fp2 {
+            c0: Fp::from_montgomery([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+            c1: Fp::from_montgomery([
+                0x890dc9e4867545c3,
+                0x2af322533285a5d5,
+                0x50880866309b7e2c,
+                0xa20d1b8c7e881024,
+                0x14e4f04fe2db9068,
+                0x14e56d3f1564853a,
+            ]),
+        };
+        const PSI_Y: Fp2 = Fp2 {
+            c0: Fp::from_montgomery([
+                0x3e2f585da55c9ad1,
+                0x4294213d86c18183,
+                0x382844c88b623732,
+                0x92ad2afd19103e18,
+                0x1d794e4fac7cf0b9,
+                0x0bd592fc7d825ec8,
+            ]),
+            c1: Fp::from_montgomery([
+                0x7bcfa7a25aa30fda,
+                0xdc17dec12a927e7c,
+                0x2f088dd86b4ebef1,
+                0xd1ca2087da74d4a7,
+                0x2da2596696cebc1d,
+                0x0e2b7eedbbfd87d2,
+            ]),
+        };
+        Self {
+            x: self.x.conjugate().mul(&PSI_X),
+            y: self.y.conjugate().mul(&PSI_Y),
+            z: self.z.conjugate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group_law_test<C: Curve>() {
+        let g = C::GENERATOR;
+        let g2 = g.double();
+        assert!(g2.ct_eq(&g.add(&g)));
+        let g3 = g2.add(&g);
+        assert!(g3.ct_eq(&g.mul(&[3])));
+        assert!(g3.add(&g3.neg()).is_identity());
+        assert!(g.add(&Point::IDENTITY).ct_eq(&g));
+        assert!(Point::<C>::IDENTITY.double().is_identity());
+        assert!(!g.ct_eq(&g2));
+        assert!(!g.ct_eq(&Point::IDENTITY));
+        assert!(g.mul(&[0]).is_identity());
+
+        // (r - 1) * g = -g.
+        let mut r_minus_1 = R;
+        r_minus_1[31] -= 1;
+        assert!(g.mul(&r_minus_1).ct_eq(&g.neg()));
+        assert!(g.is_torsion_free_vartime());
+
+        let mut encoded = [0; 96];
+        let encoded = &mut encoded[..C::ENCODED_LEN];
+        for p in [g, g2, g2.neg(), Point::IDENTITY].iter() {
+            p.write_compressed(encoded);
+            let decoded = Point::<C>::from_compressed_vartime(encoded).unwrap();
+            assert!(decoded.ct_eq(p));
+        }
+    }
+
+    #[test]
+    fn g1_group_law_test() {
+        group_law_test::<G1>();
+    }
+
+    #[test]
+    fn g2_group_law_test() {
+        group_law_test::<G2>();
+    }
+
+    #[test]
+    fn generator_encoding_test() {
+        let mut g1 = [0; 48];
+        G1::GENERATOR.write_compressed(&mut g1);
+        assert_eq!(
+            &g1[..],
+            &crate::test::from_hex(
+                "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+            )
+            .unwrap()[..]
+        );
+
+        let mut g2 = [0; 96];
+        G2::GENERATOR.write_compressed(&mut g2);
+        assert_eq!(
+            &g2[..],
+            &crate::test::from_hex(
+                "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+            )
+            .unwrap()[..]
+        );
+    }
+
+    #[test]
+    fn from_compressed_rejects_test() {
+        let mut g1 = [0; 48];
+        G1::GENERATOR.write_compressed(&mut g1);
+
+        // Wrong length.
+        assert!(Point::<G1>::from_compressed_vartime(&g1[1..]).is_err());
+
+        // Uncompressed flag.
+        let mut bad = g1;
+        bad[0] &= !COMPRESSED_FLAG;
+        assert!(Point::<G1>::from_compressed_vartime(&bad).is_err());
+
+        // Infinity with other bits set.
+        let mut bad = [0; 48];
+        bad[0] = COMPRESSED_FLAG | INFINITY_FLAG;
+        bad[47] = 1;
+        assert!(Point::<G1>::from_compressed_vartime(&bad).is_err());
+        bad[47] = 0;
+        bad[0] |= SIGN_FLAG;
+        assert!(Point::<G1>::from_compressed_vartime(&bad).is_err());
+
+        // x = 0 gives (0, ±2), which has order 3 and so isn't in G1.
+        let mut bad = [0; 48];
+        bad[0] = COMPRESSED_FLAG;
+        assert!(Point::<G1>::from_compressed_vartime(&bad).is_err());
+
+        // x = p is non-canonical.
+        let mut bad = Fp::ZERO.sub(&Fp::ONE).to_bytes();
+        bad[47] += 1;
+        bad[0] |= COMPRESSED_FLAG;
+        assert!(Point::<G1>::from_compressed_vartime(&bad).is_err());
+    }
+}
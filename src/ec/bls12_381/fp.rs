@@ -0,0 +1,413 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic in the base field GF(p) of BLS12-381.
+//!
+//! Elements are kept fully reduced, in Montgomery form with R = 2**384.
+//! Everything that may involve secret values runs in constant time;
+//! functions whose names end in `_vartime` do not.
+
+use crate::error;
+use core::convert::TryInto;
+
+pub const ELEM_LEN: usize = 48;
+
+const NUM_LIMBS: usize = 6;
+
+type Limbs = [u64; NUM_LIMBS];
+
+/// The operations that the curve arithmetic needs from GF(p) and GF(p**2).
+pub trait Field: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn add(&self, b: &Self) -> Self;
+    fn sub(&self, b: &Self) -> Self;
+    fn neg(&self) -> Self;
+    fn mul(&self, b: &Self) -> Self;
+
+    fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(&self) -> Self;
+    fn is_zero(&self) -> bool;
+
+    /// Decodes the big-endian encoding `bytes`, which must be canonical.
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, error::Unspecified>;
+
+    /// Writes the big-endian encoding to `out`.
+    fn write_bytes(&self, out: &mut [u8]);
+
+    /// Returns `a` if `mask` is all ones and `b` if it is zero.
+    fn select(mask: u64, a: &Self, b: &Self) -> Self;
+
+    fn sqrt_vartime(&self) -> Option<Self>;
+
+    /// The `sgn0` function of RFC 9380 Section 4.1.
+    fn sgn0(&self) -> bool;
+
+    /// Whether this is the larger of itself and its negation, as the
+    /// compressed point encoding uses.
+    fn is_lexicographically_largest(&self) -> bool;
+
+    fn ct_eq(&self, b: &Self) -> bool {
+        self.sub(b).is_zero()
+    }
+}
+
+/// An element of GF(p).
+#[derive(Clone, Copy)]
+pub struct Fp(Limbs);
+
+const P: Limbs = [
+    0xb9feffffffffaaab,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+];
+
+// -1/p (mod 2**64).
+const INV: u64 = 0x89f3fffcfffcfffd;
+
+// R**2 and R**3 (mod p).
+const R2: Limbs = [
+    0xf4df1f341c341746,
+    0x0a76e6a609d104f1,
+    0x8de5476c4c95b6d5,
+    0x67eb88a9939d83c0,
+    0x9a793e85b519952d,
+    0x11988fe592cae3aa,
+];
+const R3: Limbs = [
+    0xed48ac6bd94ca1e0,
+    0x315f831e03a7adf8,
+    0x9a53352a615e29dd,
+    0x34c04e5e921e1761,
+    0x2512d43565724728,
+    0x0aa6346091755d4d,
+];
+
+const P_MINUS_2: Limbs = [
+    0xb9feffffffffaaa9,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+];
+pub const P_MINUS_1_OVER_2: Limbs = [
+    0xdcff7fffffffd555,
+    0x0f55ffff58a9ffff,
+    0xb39869507b587b12,
+    0xb23ba5c279c2895f,
+    0x258dd3db21a5d66b,
+    0x0d0088f51cbff34d,
+];
+const P_PLUS_1_OVER_4: Limbs = [
+    0xee7fbfffffffeaab,
+    0x07aaffffac54ffff,
+    0xd9cc34a83dac3d89,
+    0xd91dd2e13ce144af,
+    0x92c6e9ed90d2eb35,
+    0x0680447a8e5ff9a6,
+];
+pub const P_MINUS_3_OVER_4: Limbs = [
+    0xee7fbfffffffeaaa,
+    0x07aaffffac54ffff,
+    0xd9cc34a83dac3d89,
+    0xd91dd2e13ce144af,
+    0x92c6e9ed90d2eb35,
+    0x0680447a8e5ff9a6,
+];
+
+impl Fp {
+    pub const fn from_montgomery(limbs: Limbs) -> Self {
+        Self(limbs)
+    }
+
+    /// Reduces the 64-byte big-endian `bytes` modulo p, as `hash_to_field`
+    /// in RFC 9380 Section 5.2 requires.
+    pub fn from_wide_bytes_reduced(bytes: &[u8; 64]) -> Self {
+        let mut hi = [0; ELEM_LEN];
+        hi[(2 * ELEM_LEN - 64)..].copy_from_slice(&bytes[..(64 - ELEM_LEN)]);
+        let lo: &[u8; ELEM_LEN] = bytes[(64 - ELEM_LEN)..].try_into().unwrap();
+        // Montgomery multiplication accepts any operand below 2**384 as long
+        // as the other is reduced, so `lo` needn't be reduced first.
+        let lo = Self(mont_mul(&from_be_bytes(lo), &R2));
+        let hi = Self(mont_mul(&from_be_bytes(&hi), &R3));
+        lo.add(&hi)
+    }
+
+    pub fn to_bytes(self) -> [u8; ELEM_LEN] {
+        let mut one = [0; NUM_LIMBS];
+        one[0] = 1;
+        let limbs = mont_mul(&self.0, &one);
+        let mut bytes = [0; ELEM_LEN];
+        bytes
+            .chunks_mut(8)
+            .zip(limbs.iter().rev())
+            .for_each(|(chunk, limb)| chunk.copy_from_slice(&limb.to_be_bytes()));
+        bytes
+    }
+
+    pub fn pow_vartime(&self, exponent: &Limbs) -> Self {
+        let mut r = Self::ONE;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                r = r.square();
+                if (limb >> bit) & 1 == 1 {
+                    r = r.mul(self);
+                }
+            }
+        }
+        r
+    }
+}
+
+impl Field for Fp {
+    const ZERO: Self = Self([0; NUM_LIMBS]);
+    const ONE: Self = Self([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ]);
+
+    fn add(&self, b: &Self) -> Self {
+        // p < 2**381, so the sum doesn't overflow.
+        let mut sum = [0; NUM_LIMBS];
+        let mut carry = 0;
+        for (r, (a, b)) in sum.iter_mut().zip(self.0.iter().zip(b.0.iter())) {
+            let (s, c) = adc(*a, *b, carry);
+            *r = s;
+            carry = c;
+        }
+        Self(reduce_once(&sum))
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        let (difference, borrow) = sub_limbs(&self.0, &b.0);
+        // Add p back if the subtraction borrowed.
+        let mask = 0u64.wrapping_sub(borrow);
+        let mut r = [0; NUM_LIMBS];
+        let mut carry = 0;
+        for i in 0..NUM_LIMBS {
+            let (s, c) = adc(difference[i], P[i] & mask, carry);
+            r[i] = s;
+            carry = c;
+        }
+        Self(r)
+    }
+
+    fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        Self(mont_mul(&self.0, &b.0))
+    }
+
+    fn invert(&self) -> Self {
+        // The exponent is public.
+        self.pow_vartime(&P_MINUS_2)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().fold(0, |acc, limb| acc | limb) == 0
+    }
+
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let limbs = from_be_bytes(bytes.try_into()?);
+        let (_, borrow) = sub_limbs(&limbs, &P);
+        if borrow == 0 {
+            return Err(error::Unspecified);
+        }
+        Ok(Self(mont_mul(&limbs, &R2)))
+    }
+
+    fn write_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_bytes());
+    }
+
+    fn select(mask: u64, a: &Self, b: &Self) -> Self {
+        let mut r = [0; NUM_LIMBS];
+        r.iter_mut()
+            .zip(a.0.iter().zip(b.0.iter()))
+            .for_each(|(r, (a, b))| *r = (a & mask) | (b & !mask));
+        Self(r)
+    }
+
+    fn sqrt_vartime(&self) -> Option<Self> {
+        // p = 3 (mod 4).
+        let r = self.pow_vartime(&P_PLUS_1_OVER_4);
+        if r.square().ct_eq(self) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    fn sgn0(&self) -> bool {
+        self.to_bytes()[ELEM_LEN - 1] & 1 == 1
+    }
+
+    fn is_lexicographically_largest(&self) -> bool {
+        // `self > (p - 1) / 2` iff `2 * self > p - 1`, i.e. iff doubling
+        // `self` wraps around, which makes it odd.
+        self.double().sgn0()
+    }
+}
+
+fn from_be_bytes(bytes: &[u8; ELEM_LEN]) -> Limbs {
+    let mut limbs = [0; NUM_LIMBS];
+    limbs
+        .iter_mut()
+        .zip(bytes.chunks(8).rev())
+        .for_each(|(limb, chunk)| *limb = u64::from_be_bytes(chunk.try_into().unwrap()));
+    limbs
+}
+
+#[inline]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = u128::from(a) + u128::from(b) + u128::from(carry);
+    (t as u64, (t >> 64) as u64)
+}
+
+// Returns `a + b * c + carry`.
+#[inline]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = u128::from(a) + u128::from(b) * u128::from(c) + u128::from(carry);
+    (t as u64, (t >> 64) as u64)
+}
+
+// Returns `a - b` and the borrow, which is 1 if `a < b`.
+fn sub_limbs(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let mut r = [0; NUM_LIMBS];
+    let mut borrow = 0;
+    for i in 0..NUM_LIMBS {
+        let t = u128::from(a[i])
+            .wrapping_sub(u128::from(b[i]))
+            .wrapping_sub(u128::from(borrow));
+        r[i] = t as u64;
+        borrow = (t >> 127) as u64;
+    }
+    (r, borrow)
+}
+
+// Reduces `a < 2p`.
+fn reduce_once(a: &Limbs) -> Limbs {
+    let (a_minus_p, borrow) = sub_limbs(a, &P);
+    Fp::select(0u64.wrapping_sub(borrow), &Fp(*a), &Fp(a_minus_p)).0
+}
+
+// Returns `a * b / R (mod p)`, fully reduced, for `a, b < 2**384` with at
+// least one of them below p.
+fn mont_mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut t = [0u64; NUM_LIMBS + 2];
+    for &b_i in b.iter() {
+        let mut carry = 0;
+        for (t_j, &a_j) in t.iter_mut().zip(a.iter()) {
+            let (lo, hi) = mac(*t_j, a_j, b_i, carry);
+            *t_j = lo;
+            carry = hi;
+        }
+        let (s, c) = adc(t[NUM_LIMBS], carry, 0);
+        t[NUM_LIMBS] = s;
+        t[NUM_LIMBS + 1] = c;
+
+        let m = t[0].wrapping_mul(INV);
+        let (_, mut carry) = mac(t[0], m, P[0], 0);
+        for j in 1..NUM_LIMBS {
+            let (lo, hi) = mac(t[j], m, P[j], carry);
+            t[j - 1] = lo;
+            carry = hi;
+        }
+        let (s, c) = adc(t[NUM_LIMBS], carry, 0);
+        t[NUM_LIMBS - 1] = s;
+        t[NUM_LIMBS] = t[NUM_LIMBS + 1] + c;
+    }
+    // The result is below 2p < 2**382, so `t[NUM_LIMBS]` is zero.
+    reduce_once(t[..NUM_LIMBS].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fp_bytes_round_trip_test() {
+        let mut bytes = [0; ELEM_LEN];
+        bytes[ELEM_LEN - 1] = 2;
+        let two = Fp::from_bytes_checked(&bytes).unwrap();
+        assert!(two.ct_eq(&Fp::ONE.double()));
+        assert_eq!(two.to_bytes(), bytes);
+
+        let p_minus_1 = Fp::ONE.neg().to_bytes();
+        assert!(Fp::from_bytes_checked(&p_minus_1).is_ok());
+        let mut p = p_minus_1;
+        p[ELEM_LEN - 1] += 1;
+        assert!(Fp::from_bytes_checked(&p).is_err());
+        assert!(Fp::from_bytes_checked(&[0xff; ELEM_LEN]).is_err());
+    }
+
+    #[test]
+    fn fp_arithmetic_test() {
+        let two = Fp::ONE.double();
+        let three = two.add(&Fp::ONE);
+        let six = two.mul(&three);
+        assert!(six.mul(&six.invert()).ct_eq(&Fp::ONE));
+        assert!(six.sub(&three).ct_eq(&three));
+        assert!(three.sub(&six).add(&three).is_zero());
+        assert!(Fp::ZERO.neg().is_zero());
+        assert!(Fp::ZERO.invert().is_zero());
+
+        let nine = three.square();
+        let root = nine.sqrt_vartime().unwrap();
+        assert!(root.ct_eq(&three) || root.ct_eq(&three.neg()));
+        // -1 is not a square because p = 3 (mod 4).
+        assert!(Fp::ONE.neg().sqrt_vartime().is_none());
+
+        assert!(!Fp::ONE.is_lexicographically_largest());
+        assert!(Fp::ONE.neg().is_lexicographically_largest());
+        assert!(Fp::ONE.sgn0());
+        assert!(!Fp::ONE.neg().sgn0());
+    }
+
+    #[test]
+    fn fp_from_wide_bytes_reduced_test() {
+        // 2**384 + 1.
+        let mut bytes = [0; 64];
+        bytes[64 - ELEM_LEN - 1] = 1;
+        bytes[63] = 1;
+        let r_plus_1 = Fp::from_wide_bytes_reduced(&bytes);
+        let r = Fp(mont_mul(&Fp::ONE.0, &R2));
+        assert!(r_plus_1.ct_eq(&r.add(&Fp::ONE)));
+
+        // 2**512 - 1 = 2**384 * 2**128 - 1.
+        let all_ones = Fp::from_wide_bytes_reduced(&[0xff; 64]);
+        let two_128 = Fp(mont_mul(&[0, 0, 1, 0, 0, 0], &R2));
+        let two_384 = r;
+        let expected = two_384.mul(&two_128).sub(&Fp::ONE);
+        assert!(all_ones.ct_eq(&expected));
+    }
+}
@@ -0,0 +1,407 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic in GF(p**6) = GF(p**2)[v] / (v**3 - (u + 1)) and in
+//! GF(p**12) = GF(p**6)[w] / (w**2 - v), where the pairing takes its values.
+
+use super::{
+    fp::{Field, Fp},
+    fp2::Fp2,
+};
+
+#[derive(Clone, Copy)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub const ZERO: Self = Self {
+        c0: Fp2::ZERO,
+        c1: Fp2::ZERO,
+        c2: Fp2::ZERO,
+    };
+
+    const ONE: Self = Self {
+        c0: Fp2::ONE,
+        c1: Fp2::ZERO,
+        c2: Fp2::ZERO,
+    };
+
+    fn add(&self, b: &Self) -> Self {
+        Self {
+            c0: self.c0.add(&b.c0),
+            c1: self.c1.add(&b.c1),
+            c2: self.c2.add(&b.c2),
+        }
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        Self {
+            c0: self.c0.sub(&b.c0),
+            c1: self.c1.sub(&b.c1),
+            c2: self.c2.sub(&b.c2),
+        }
+    }
+
+    fn neg(&self) -> Self {
+        Self {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+            c2: self.c2.neg(),
+        }
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        let aa = self.c0.mul(&b.c0);
+        let bb = self.c1.mul(&b.c1);
+        let cc = self.c2.mul(&b.c2);
+
+        // c0 = a0*b0 + (a1*b2 + a2*b1)*v**3
+        let c0 = self
+            .c1
+            .add(&self.c2)
+            .mul(&b.c1.add(&b.c2))
+            .sub(&bb)
+            .sub(&cc)
+            .mul_by_nonresidue()
+            .add(&aa);
+        // c1 = a0*b1 + a1*b0 + a2*b2*v**3
+        let c1 = self
+            .c0
+            .add(&self.c1)
+            .mul(&b.c0.add(&b.c1))
+            .sub(&aa)
+            .sub(&bb)
+            .add(&cc.mul_by_nonresidue());
+        // c2 = a0*b2 + a2*b0 + a1*b1
+        let c2 = self
+            .c0
+            .add(&self.c2)
+            .mul(&b.c0.add(&b.c2))
+            .sub(&aa)
+            .sub(&cc)
+            .add(&bb);
+        Self { c0, c1, c2 }
+    }
+
+    /// Multiplies by v.
+    fn mul_by_nonresidue(&self) -> Self {
+        Self {
+            c0: self.c2.mul_by_nonresidue(),
+            c1: self.c0,
+            c2: self.c1,
+        }
+    }
+
+    fn invert(&self) -> Self {
+        let t0 = self
+            .c0
+            .square()
+            .sub(&self.c1.mul(&self.c2).mul_by_nonresidue());
+        let t1 = self
+            .c2
+            .square()
+            .mul_by_nonresidue()
+            .sub(&self.c0.mul(&self.c1));
+        let t2 = self.c1.square().sub(&self.c0.mul(&self.c2));
+        let norm = self
+            .c0
+            .mul(&t0)
+            .add(&self.c2.mul(&t1).add(&self.c1.mul(&t2)).mul_by_nonresidue());
+        let norm_inv = norm.invert();
+        Self {
+            c0: t0.mul(&norm_inv),
+            c1: t1.mul(&norm_inv),
+            c2: t2.mul(&norm_inv),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() & self.c1.is_zero() & self.c2.is_zero()
+    }
+}
+
+/// An element of GF(p**12), `c0 + c1*w`.
+#[derive(Clone, Copy)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+// FROBENIUS_COEFFS[k - 1] = (u + 1)**(k * (p - 1) / 6), so that
+// (w**k)**p = FROBENIUS_COEFFS[k - 1] * w**k.
+const FROBENIUS_COEFFS: [Fp2; 5] = [
+    Fp2 {
+        c0: Fp::from_montgomery([
+            0x07089552b319d465,
+            0xc6695f92b50a8313,
+            0x97e83cccd117228f,
+            0xa35baecab2dc29ee,
+            0x1ce393ea5daace4d,
+            0x08f2220fb0fb66eb,
+        ]),
+        c1: Fp::from_montgomery([
+            0xb2f66aad4ce5d646,
+            0x5842a06bfc497cec,
+            0xcf4895d42599d394,
+            0xc11b9cba40a8e8d0,
+            0x2e3813cbe5a0de89,
+            0x110eefda88847faf,
+        ]),
+    },
+    Fp2 {
+        c0: Fp::from_montgomery([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        c1: Fp::from_montgomery([
+            0xcd03c9e48671f071,
+            0x5dab22461fcda5d2,
+            0x587042afd3851b95,
+            0x8eb60ebe01bacb9e,
+            0x03f97d6e83d050d2,
+            0x18f0206554638741,
+        ]),
+    },
+    Fp2 {
+        c0: Fp::from_montgomery([
+            0x7bcfa7a25aa30fda,
+            0xdc17dec12a927e7c,
+            0x2f088dd86b4ebef1,
+            0xd1ca2087da74d4a7,
+            0x2da2596696cebc1d,
+            0x0e2b7eedbbfd87d2,
+        ]),
+        c1: Fp::from_montgomery([
+            0x7bcfa7a25aa30fda,
+            0xdc17dec12a927e7c,
+            0x2f088dd86b4ebef1,
+            0xd1ca2087da74d4a7,
+            0x2da2596696cebc1d,
+            0x0e2b7eedbbfd87d2,
+        ]),
+    },
+    Fp2 {
+        c0: Fp::from_montgomery([
+            0x890dc9e4867545c3,
+            0x2af322533285a5d5,
+            0x50880866309b7e2c,
+            0xa20d1b8c7e881024,
+            0x14e4f04fe2db9068,
+            0x14e56d3f1564853a,
+        ]),
+        c1: Fp::from_montgomery([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    },
+    Fp2 {
+        c0: Fp::from_montgomery([
+            0x82d83cf50dbce43f,
+            0xa2813e53df9d018f,
+            0xc6f0caa53c65e181,
+            0x7525cf528d50fe95,
+            0x4a85ed50f4798a6b,
+            0x171da0fd6cf8eebd,
+        ]),
+        c1: Fp::from_montgomery([
+            0x3726c30af242c66c,
+            0x7c2ac1aad1b6fe70,
+            0xa04007fbba4b14a2,
+            0xef517c3266341429,
+            0x0095ba654ed2226b,
+            0x02e370eccc86f7dd,
+        ]),
+    },
+];
+
+impl Fp12 {
+    pub const ONE: Self = Self {
+        c0: Fp6::ONE,
+        c1: Fp6::ZERO,
+    };
+
+    pub fn mul(&self, b: &Self) -> Self {
+        let aa = self.c0.mul(&b.c0);
+        let bb = self.c1.mul(&b.c1);
+        Self {
+            c0: bb.mul_by_nonresidue().add(&aa),
+            c1: self
+                .c0
+                .add(&self.c1)
+                .mul(&b.c0.add(&b.c1))
+                .sub(&aa)
+                .sub(&bb),
+        }
+    }
+
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Computes `self**(p**6)`, which is the inverse of `self` if `self` is
+    /// in the cyclotomic subgroup.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+
+    pub fn invert(&self) -> Self {
+        let t = self
+            .c0
+            .mul(&self.c0)
+            .sub(&self.c1.mul(&self.c1).mul_by_nonresidue())
+            .invert();
+        Self {
+            c0: self.c0.mul(&t),
+            c1: self.c1.mul(&t).neg(),
+        }
+    }
+
+    /// Computes `self**p`.
+    pub fn frobenius_map(&self) -> Self {
+        // As a polynomial in w, the coefficients of w**0, ..., w**5 are
+        // c0.c0, c1.c0, c0.c1, c1.c1, c0.c2, c1.c2.
+        let frob = |a: &Fp2, k: usize| a.conjugate().mul(&FROBENIUS_COEFFS[k - 1]);
+        Self {
+            c0: Fp6 {
+                c0: self.c0.c0.conjugate(),
+                c1: frob(&self.c0.c1, 2),
+                c2: frob(&self.c0.c2, 4),
+            },
+            c1: Fp6 {
+                c0: frob(&self.c1.c0, 1),
+                c1: frob(&self.c1.c1, 3),
+                c2: frob(&self.c1.c2, 5),
+            },
+        }
+    }
+
+    /// Multiplies by the sparse element `a + b*w**2 + c*w**3`, the form that
+    /// the Miller loop's line functions take.
+    pub fn mul_by_line(&self, a: &Fp2, b: &Fp2, c: &Fp2) -> Self {
+        self.mul(&Self {
+            c0: Fp6 {
+                c0: *a,
+                c1: *b,
+                c2: Fp2::ZERO,
+            },
+            c1: Fp6 {
+                c0: Fp2::ZERO,
+                c1: *c,
+                c2: Fp2::ZERO,
+            },
+        })
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.c0.sub(&Fp6::ONE).is_zero() & self.c1.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Fp12 {
+        let mut e = Fp2::ONE;
+        let mut next = || {
+            e = e.mul_by_nonresidue().add(&Fp2::ONE);
+            e
+        };
+        let c0 = Fp6 {
+            c0: next(),
+            c1: next(),
+            c2: next(),
+        };
+        let c1 = Fp6 {
+            c0: next(),
+            c1: next(),
+            c2: next(),
+        };
+        Fp12 { c0, c1 }
+    }
+
+    #[test]
+    fn fp12_arithmetic_test() {
+        let a = sample();
+        assert!(a.mul(&a.invert()).is_one());
+        assert!(!a.is_one());
+
+        // w**2 = v and v**3 = u + 1, so w**6 = u + 1.
+        let w = Fp12 {
+            c0: Fp6::ZERO,
+            c1: Fp6::ONE,
+        };
+        let w6 = w.square().square().mul(&w.square());
+        assert!(w6.c1.is_zero());
+        assert!(w6.c0.c1.is_zero() & w6.c0.c2.is_zero());
+        assert!(w6.c0.c0.ct_eq(&Fp2::ONE.mul_by_nonresidue()));
+    }
+
+    #[test]
+    fn fp12_frobenius_test() {
+        // The Frobenius map is a ring homomorphism, and applying it six
+        // times to an element of GF(p**12) is the same as conjugating it.
+        let a = sample();
+        let b = a.square().add_one();
+        assert!(a
+            .mul(&b)
+            .frobenius_map()
+            .sub(&a.frobenius_map().mul(&b.frobenius_map()))
+            .is_zero());
+        let mut f = a;
+        for _ in 0..6 {
+            f = f.frobenius_map();
+        }
+        assert!(f.sub(&a.conjugate()).is_zero());
+        // Twelve times is the identity.
+        for _ in 0..6 {
+            f = f.frobenius_map();
+        }
+        assert!(f.sub(&a).is_zero());
+    }
+
+    impl Fp12 {
+        fn sub(&self, b: &Self) -> Self {
+            Self {
+                c0: self.c0.sub(&b.c0),
+                c1: self.c1.sub(&b.c1),
+            }
+        }
+
+        fn add_one(&self) -> Self {
+            Self {
+                c0: self.c0.add(&Fp6::ONE),
+                c1: self.c1,
+            }
+        }
+
+        fn is_zero(&self) -> bool {
+            self.c0.is_zero() & self.c1.is_zero()
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic in GF(p**2) = GF(p)[u] / (u**2 + 1).
+
+use super::fp::{Field, Fp, ELEM_LEN, P_MINUS_1_OVER_2, P_MINUS_3_OVER_4};
+use crate::error;
+
+#[derive(Clone, Copy)]
+pub struct Fp2 {
+    pub c0: Fp,
+    pub c1: Fp,
+}
+
+impl Fp2 {
+    pub fn conjugate(&self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+
+    /// Multiplies by the non-residue u + 1 that defines GF(p**6).
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Self {
+            c0: self.c0.sub(&self.c1),
+            c1: self.c0.add(&self.c1),
+        }
+    }
+
+    pub fn mul_by_fp(&self, b: &Fp) -> Self {
+        Self {
+            c0: self.c0.mul(b),
+            c1: self.c1.mul(b),
+        }
+    }
+
+    fn norm(&self) -> Fp {
+        self.c0.square().add(&self.c1.square())
+    }
+
+    pub fn pow_vartime(&self, exponent: &[u64; 6]) -> Self {
+        let mut r = Self::ONE;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                r = r.square();
+                if (limb >> bit) & 1 == 1 {
+                    r = r.mul(self);
+                }
+            }
+        }
+        r
+    }
+}
+
+impl Field for Fp2 {
+    const ZERO: Self = Self {
+        c0: Fp::ZERO,
+        c1: Fp::ZERO,
+    };
+    const ONE: Self = Self {
+        c0: Fp::ONE,
+        c1: Fp::ZERO,
+    };
+
+    fn add(&self, b: &Self) -> Self {
+        Self {
+            c0: self.c0.add(&b.c0),
+            c1: self.c1.add(&b.c1),
+        }
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        Self {
+            c0: self.c0.sub(&b.c0),
+            c1: self.c1.sub(&b.c1),
+        }
+    }
+
+    fn neg(&self) -> Self {
+        Self {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+        }
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        // Karatsuba.
+        let aa = self.c0.mul(&b.c0);
+        let bb = self.c1.mul(&b.c1);
+        let cross = self.c0.add(&self.c1).mul(&b.c0.add(&b.c1));
+        Self {
+            c0: aa.sub(&bb),
+            c1: cross.sub(&aa).sub(&bb),
+        }
+    }
+
+    fn square(&self) -> Self {
+        // (c0 + c1*u)**2 = (c0 + c1)(c0 - c1) + 2*c0*c1*u.
+        Self {
+            c0: self.c0.add(&self.c1).mul(&self.c0.sub(&self.c1)),
+            c1: self.c0.mul(&self.c1).double(),
+        }
+    }
+
+    fn invert(&self) -> Self {
+        self.conjugate().mul_by_fp(&self.norm().invert())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() & self.c1.is_zero()
+    }
+
+    /// Decodes `c1 || c0`, the order that the point encodings use.
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        if bytes.len() != 2 * ELEM_LEN {
+            return Err(error::Unspecified);
+        }
+        let (c1, c0) = bytes.split_at(ELEM_LEN);
+        Ok(Self {
+            c0: Fp::from_bytes_checked(c0)?,
+            c1: Fp::from_bytes_checked(c1)?,
+        })
+    }
+
+    fn write_bytes(&self, out: &mut [u8]) {
+        let (c1, c0) = out.split_at_mut(ELEM_LEN);
+        self.c1.write_bytes(c1);
+        self.c0.write_bytes(c0);
+    }
+
+    fn select(mask: u64, a: &Self, b: &Self) -> Self {
+        Self {
+            c0: Fp::select(mask, &a.c0, &b.c0),
+            c1: Fp::select(mask, &a.c1, &b.c1),
+        }
+    }
+
+    // Algorithm 9 of "Square root computation over even extension fields"
+    // by Adj and Rodríguez-Henríquez, for p = 3 (mod 4).
+    fn sqrt_vartime(&self) -> Option<Self> {
+        let a1 = self.pow_vartime(&P_MINUS_3_OVER_4);
+        let alpha = a1.square().mul(self);
+        let x0 = a1.mul(self);
+        let x = if alpha.ct_eq(&Self::ONE.neg()) {
+            // u * x0.
+            Self {
+                c0: x0.c1.neg(),
+                c1: x0.c0,
+            }
+        } else {
+            let b = alpha.add(&Self::ONE).pow_vartime(&P_MINUS_1_OVER_2);
+            b.mul(&x0)
+        };
+        if x.square().ct_eq(self) {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() | (self.c0.is_zero() & self.c1.sgn0())
+    }
+
+    fn is_lexicographically_largest(&self) -> bool {
+        self.c1.is_lexicographically_largest()
+            | (self.c1.is_zero() & self.c0.is_lexicographically_largest())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fp2_arithmetic_test() {
+        let u = Fp2 {
+            c0: Fp::ZERO,
+            c1: Fp::ONE,
+        };
+        // u**2 = -1.
+        assert!(u.square().ct_eq(&Fp2::ONE.neg()));
+        assert!(u.mul(&u).ct_eq(&Fp2::ONE.neg()));
+
+        let a = u.add(&Fp2::ONE.double()).mul_by_nonresidue();
+        assert!(a.mul(&a.invert()).ct_eq(&Fp2::ONE));
+        assert!(a.square().ct_eq(&a.mul(&a)));
+        assert!(a.mul(&a.conjugate()).c1.is_zero());
+
+        let root = a.square().sqrt_vartime().unwrap();
+        assert!(root.ct_eq(&a) || root.ct_eq(&a.neg()));
+        // u and -1 = u**2 are squares; the non-residue u + 1 is not.
+        assert!(u.sqrt_vartime().is_some());
+        assert!(Fp2::ONE.neg().sqrt_vartime().is_some());
+        let xi = Fp2::ONE.add(&u);
+        assert!(xi.sqrt_vartime().is_none());
+        assert!(a.square().sqrt_vartime().is_some());
+    }
+
+    #[test]
+    fn fp2_bytes_round_trip_test() {
+        let mut bytes = [0; 2 * ELEM_LEN];
+        bytes[ELEM_LEN - 1] = 1;
+        bytes[2 * ELEM_LEN - 1] = 2;
+        let a = Fp2::from_bytes_checked(&bytes).unwrap();
+        assert!(a.c1.ct_eq(&Fp::ONE));
+        assert!(a.c0.ct_eq(&Fp::ONE.double()));
+        let mut encoded = [0; 2 * ELEM_LEN];
+        a.write_bytes(&mut encoded);
+        assert_eq!(&encoded[..], &bytes[..]);
+        assert!(Fp2::from_bytes_checked(&bytes[1..]).is_err());
+    }
+}
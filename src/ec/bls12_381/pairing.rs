@@ -0,0 +1,163 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The optimal ate pairing e: G1 × G2 → GF(p**12).
+//!
+//! Only products of pairings are compared against one, so the final
+//! exponentiation computes the cube of the usual pairing, which is just as
+//! bilinear and non-degenerate and is cheaper to compute; see
+//! "Efficient Final Exponentiation via Cyclotomic Structure for Pairings
+//! over Families of Elliptic Curves" by Hayashida, Hayasaka, and Teruya.
+
+use super::{
+    curve::{Curve, Point, G1, G2, X_ABS},
+    fp::Field,
+    fp12::Fp12,
+};
+
+/// Computes the Miller loop f_{x,Q}(P). The pairing is the final
+/// exponentiation of this, or of a product of these.
+///
+/// T and Q stay on the twist, over GF(p**2), and every line is scaled by a
+/// factor in a proper subfield of GF(p**12), which the final exponentiation
+/// removes. After that scaling, the line through T with slope λ on the twist
+/// evaluated at P = (xP, yP) is `c0 + c2*w**2 + c3*w**3`, where
+/// `c0 = λ*x_T - y_T`, `c2 = -λ*xP`, and `c3 = yP`.
+pub fn miller_loop(p: &Point<G1>, q: &Point<G2>) -> Fp12 {
+    let ((xp, yp), (xq, yq)) = match (p.to_affine(), q.to_affine()) {
+        (Some(p), Some(q)) => (p, q),
+        _ => return Fp12::ONE,
+    };
+
+    let mut f = Fp12::ONE;
+    let mut t = Point::<G2>::from_affine(xq, yq);
+
+    // The most significant bit of |x| is handled by the initial T = Q.
+    let bits = X_ABS
+        .iter()
+        .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .skip(1);
+
+    for is_set in bits {
+        // Tangent at T, scaled by 2 * Y * Z:
+        // c0 = Y**2 - 3b' * Z**2, c2 = -3 * X**2 * xP, c3 = 2 * Y * Z * yP.
+        let c0 = t.y.square().sub(&G2::B3.mul(&t.z.square()));
+        let x2 = t.x.square();
+        let c2 = x2.double().add(&x2).mul_by_fp(&xp).neg();
+        let c3 = t.y.mul(&t.z).double().mul_by_fp(&yp);
+        f = f.square().mul_by_line(&c0, &c2, &c3);
+        t = t.double();
+
+        if is_set {
+            // Line through T and Q, scaled by xQ * Z - X:
+            // c0 = θ * xQ - λ * yQ, c2 = -θ * xP, c3 = λ * yP,
+            // where θ = yQ * Z - Y and λ = xQ * Z - X.
+            let theta = yq.mul(&t.z).sub(&t.y);
+            let lambda = xq.mul(&t.z).sub(&t.x);
+            let c0 = theta.mul(&xq).sub(&lambda.mul(&yq));
+            let c2 = theta.mul_by_fp(&xp).neg();
+            let c3 = lambda.mul_by_fp(&yp);
+            f = f.mul_by_line(&c0, &c2, &c3);
+            t = t.add(&Point::from_affine(xq, yq));
+        }
+    }
+
+    // x is negative.
+    f.conjugate()
+}
+
+/// Raises the result of a Miller loop, or of a product of them, to the
+/// power 3 * (p**12 - 1) / r.
+pub fn final_exponentiation(f: &Fp12) -> Fp12 {
+    // The easy part: f**((p**6 - 1) * (p**2 + 1)). Afterwards, `f` is in
+    // the cyclotomic subgroup, where the inverse is the conjugate.
+    let f = f.conjugate().mul(&f.invert());
+    let f = f.frobenius_map().frobenius_map().mul(&f);
+
+    // The hard part, using
+    // 3 * (p**4 - p**2 + 1) / r = (x - 1)**2 * (x + p) * (x**2 + p**2 - 1) + 3.
+    let t = exp_by_x(&f).mul(&f.conjugate());
+    let t = exp_by_x(&t).mul(&t.conjugate());
+    let t = exp_by_x(&t).mul(&t.frobenius_map());
+    let t = exp_by_x(&exp_by_x(&t))
+        .mul(&t.frobenius_map().frobenius_map())
+        .mul(&t.conjugate());
+    t.mul(&f.square().mul(&f))
+}
+
+// Computes `f**x` for `f` in the cyclotomic subgroup.
+fn exp_by_x(f: &Fp12) -> Fp12 {
+    pow_vartime(f, &X_ABS).conjugate()
+}
+
+// `exponent` is big-endian.
+fn pow_vartime(f: &Fp12, exponent: &[u8]) -> Fp12 {
+    let mut r = Fp12::ONE;
+    for &byte in exponent {
+        for i in (0..8).rev() {
+            r = r.square();
+            if (byte >> i) & 1 == 1 {
+                r = r.mul(f);
+            }
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec::bls12_381::curve::R;
+
+    fn pairing(p: &Point<G1>, q: &Point<G2>) -> Fp12 {
+        final_exponentiation(&miller_loop(p, q))
+    }
+
+    #[test]
+    fn pairing_bilinearity_test() {
+        let g1 = G1::GENERATOR;
+        let g2 = G2::GENERATOR;
+        let e = pairing(&g1, &g2);
+        assert!(!e.is_one());
+        assert!(pow_vartime(&e, &R).is_one());
+
+        let a = [0x12, 0x34, 0x56];
+        let b = [0xab, 0xcd];
+        let e_ab = pairing(&g1.mul(&a), &g2.mul(&b));
+        let e_ba = pairing(&g1.mul(&b), &g2.mul(&a));
+        let expected = pow_vartime(&pow_vartime(&e, &a), &b);
+        assert!(e_ab.mul(&expected.conjugate()).is_one());
+        assert!(e_ba.mul(&expected.conjugate()).is_one());
+
+        // e(P, Q) * e(-P, Q) = 1, checked with a single final
+        // exponentiation, as the signature verification does.
+        let product = miller_loop(&g1, &g2).mul(&miller_loop(&g1.neg(), &g2));
+        assert!(final_exponentiation(&product).is_one());
+
+        assert!(pairing(&Point::IDENTITY, &g2).is_one());
+        assert!(pairing(&g1, &Point::IDENTITY).is_one());
+    }
+
+    #[test]
+    fn pairing_known_answer_test() {
+        // The first coefficient of e(G1, G2)**3, computed independently.
+        let e = pairing(&G1::GENERATOR, &G2::GENERATOR);
+        let mut c = [0; 96];
+        e.c0.c0.write_bytes(&mut c);
+        assert_eq!(
+            &c[..],
+            &crate::test::from_hex("089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6").unwrap()[..]
+        );
+    }
+}
@@ -14,7 +14,9 @@
 
 //! Elliptic curve operations and schemes using Curve25519.
 
+pub mod ecvrf;
 pub mod ed25519;
+pub mod frost;
 pub mod x25519;
 
 mod ops;
@@ -0,0 +1,361 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! ECVRF-EDWARDS25519-SHA512-TAI.
+
+use super::{ed25519::ED25519_PUBLIC_KEY_LEN, ops::*};
+use crate::{digest, error, signature};
+use core::convert::TryInto;
+
+/// The length of an ECVRF-EDWARDS25519-SHA512-TAI proof.
+pub const PROOF_LEN: usize = ELEM_LEN + CHALLENGE_LEN + SCALAR_LEN;
+
+/// The length of an ECVRF-EDWARDS25519-SHA512-TAI output (*beta*).
+pub const OUTPUT_LEN: usize = digest::SHA512_OUTPUT_LEN;
+
+// RFC 9381 Section 5.5: cLen.
+const CHALLENGE_LEN: usize = 16;
+
+const SUITE_STRING: u8 = 0x03;
+
+const ENCODE_TO_CURVE_DOMAIN_SEPARATOR_FRONT: u8 = 0x01;
+const CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT: u8 = 0x02;
+const PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT: u8 = 0x03;
+const DOMAIN_SEPARATOR_BACK: u8 = 0x00;
+
+/// An ECVRF-EDWARDS25519-SHA512-TAI key pair, for proving.
+///
+/// The keys are Ed25519 keys: the private key is a 32-byte seed and the
+/// public key is derived from it exactly as in RFC 8032. Nevertheless, a key
+/// shouldn't be used both as a VRF key and as a signing key.
+pub struct KeyPair {
+    // RFC 9381 Section 5.5 calls this *x*.
+    private_scalar: Scalar,
+
+    // The second half of the SHA-512 digest of the seed, used to generate
+    // nonces as in RFC 9381 Section 5.4.2.2.
+    private_prefix: [u8; digest::SHA512_OUTPUT_LEN - SCALAR_LEN],
+
+    // RFC 9381 calls this *Y*.
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(KeyPair, stringify!(KeyPair), public_key);
+
+impl KeyPair {
+    /// Constructs a key pair from the private key seed `seed`, verifying that
+    /// `public_key` is its public key.
+    ///
+    /// See `Ed25519KeyPair::from_seed_and_public_key()`.
+    pub fn from_seed_and_public_key(
+        seed: &[u8],
+        public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let pair = Self::from_seed_unchecked(seed)?;
+        if public_key != pair.public_key.as_ref() {
+            let err = if public_key.len() != pair.public_key.as_ref().len() {
+                error::KeyRejected::invalid_encoding()
+            } else {
+                error::KeyRejected::inconsistent_components()
+            };
+            return Err(err);
+        }
+        Ok(pair)
+    }
+
+    /// Constructs a key pair from the private key seed `seed`.
+    ///
+    /// See `Ed25519KeyPair::from_seed_unchecked()`.
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<Self, error::KeyRejected> {
+        let seed: &[u8; SCALAR_LEN] = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+
+        let h = digest::digest(&digest::SHA512, seed);
+        let (private_scalar, private_prefix) = h.as_ref().split_at(SCALAR_LEN);
+
+        let private_scalar: Scalar =
+            MaskedScalar::from_bytes_masked(private_scalar.try_into().unwrap()).into();
+
+        let mut a = ExtPoint::new_at_infinity();
+        unsafe {
+            x25519_ge_scalarmult_base(&mut a, &private_scalar);
+        }
+
+        Ok(Self {
+            private_scalar,
+            private_prefix: private_prefix.try_into().unwrap(),
+            public_key: PublicKey(a.into_encoded_point()),
+        })
+    }
+
+    /// Returns the proof for the input `alpha`.
+    ///
+    /// The VRF output is `proof.to_hash()`. This is RFC 9381's
+    /// `ECVRF_prove`.
+    pub fn prove(&self, alpha: &[u8]) -> Proof {
+        let public_key = &self.public_key.0;
+
+        // Step 2.
+        let h = encode_to_curve(public_key, alpha);
+        let h_string = h.clone().into_encoded_point();
+
+        // Step 4.
+        let gamma = h.scalar_mul(&self.private_scalar).into_encoded_point();
+
+        // Step 5.
+        let k = {
+            let mut ctx = digest::Context::new(&digest::SHA512);
+            ctx.update(&self.private_prefix);
+            ctx.update(&h_string);
+            Scalar::from_sha512_digest_reduced(ctx.finish())
+        };
+
+        // Step 6.
+        let k_b = {
+            let mut r = ExtPoint::new_at_infinity();
+            unsafe { x25519_ge_scalarmult_base(&mut r, &k) };
+            r.into_encoded_point()
+        };
+        let k_h = h.scalar_mul(&k).into_encoded_point();
+        let c = challenge(&[public_key, &h_string, &gamma, &k_b, &k_h]);
+
+        // Step 7.
+        let s = Scalar::mul_add(&challenge_scalar(&c), &self.private_scalar, &k);
+
+        // Step 8.
+        let mut proof = [0u8; PROOF_LEN];
+        let (proof_gamma, rest) = proof.split_at_mut(ELEM_LEN);
+        let (proof_c, proof_s) = rest.split_at_mut(CHALLENGE_LEN);
+        proof_gamma.copy_from_slice(&gamma);
+        proof_c.copy_from_slice(&c);
+        proof_s.copy_from_slice(s.as_ref());
+        Proof(proof)
+    }
+}
+
+impl signature::KeyPair for KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+/// The public key of a `KeyPair`, in the Ed25519 encoding.
+#[derive(Clone, Copy)]
+pub struct PublicKey([u8; ED25519_PUBLIC_KEY_LEN]);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+/// An ECVRF-EDWARDS25519-SHA512-TAI proof (*pi*).
+#[derive(Clone, Copy)]
+pub struct Proof([u8; PROOF_LEN]);
+
+impl Proof {
+    /// Returns the VRF output (*beta*) for this proof.
+    ///
+    /// This is RFC 9381's `ECVRF_proof_to_hash`. It doesn't verify the proof;
+    /// a verifier should use the output that `verify()` returns instead.
+    pub fn to_hash(&self) -> Output {
+        // A `Proof` is only constructed by `KeyPair::prove()`, so Gamma is
+        // always valid.
+        let gamma = decode_point(self.0[..ELEM_LEN].try_into().unwrap()).unwrap();
+        proof_to_hash(&gamma)
+    }
+}
+
+impl AsRef<[u8]> for Proof {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(Proof);
+
+/// An ECVRF-EDWARDS25519-SHA512-TAI output (*beta*).
+#[derive(Clone, Copy)]
+pub struct Output([u8; OUTPUT_LEN]);
+
+impl AsRef<[u8]> for Output {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(Output);
+
+/// Verifies that `proof` is a valid proof by `public_key` for the input
+/// `alpha`, and returns the VRF output if so.
+///
+/// This is RFC 9381's `ECVRF_verify` with `validate_key` set, so public keys
+/// of small order are rejected; that's needed for the output to be unique
+/// even when the keys aren't trusted. Points must be canonically encoded.
+pub fn verify(public_key: &[u8], alpha: &[u8], proof: &[u8]) -> Result<Output, error::Unspecified> {
+    let public_key: &EncodedPoint = public_key.try_into().map_err(|_| error::Unspecified)?;
+
+    // Step 1 (RFC 9381 Section 5.4.5).
+    let mut minus_y = decode_point(public_key)?;
+    if is_small_order(public_key) {
+        return Err(error::Unspecified);
+    }
+    minus_y.invert_vartime();
+
+    // Steps 4 through 6.
+    let proof: &[u8; PROOF_LEN] = proof.try_into().map_err(|_| error::Unspecified)?;
+    let (gamma_string, rest) = proof.split_at(ELEM_LEN);
+    let (c, s) = rest.split_at(CHALLENGE_LEN);
+    let gamma_string: &EncodedPoint = gamma_string.try_into().unwrap();
+    let gamma = decode_point(gamma_string)?;
+    let c_scalar = challenge_scalar(c.try_into().unwrap());
+    let s = Scalar::from_bytes_checked(s.try_into().unwrap())?;
+
+    // Step 8.
+    let h = encode_to_curve(public_key, alpha);
+    let h_string = h.clone().into_encoded_point();
+
+    // Step 9: U = s*B - c*Y.
+    let u = {
+        let mut r = Point::new_at_infinity();
+        unsafe { x25519_ge_double_scalarmult_vartime(&mut r, &c_scalar, &minus_y, &s) };
+        r.into_encoded_point()
+    };
+
+    // Step 10: V = s*H - c*Gamma.
+    let v = {
+        let mut minus_gamma = gamma.clone();
+        minus_gamma.invert_vartime();
+        let zero = Scalar::from_bytes_checked([0; SCALAR_LEN])?;
+        let coeffs = [s, c_scalar];
+        let points = [h, minus_gamma];
+        let mut tables = [CachedPoint::zero(); 8 * 2];
+        let mut slides = [0i8; 2 * 256];
+        let mut r = Point::new_at_infinity();
+        unsafe {
+            x25519_ge_multi_scalarmult_vartime(
+                &mut r,
+                &zero,
+                coeffs.as_ptr(),
+                points.as_ptr(),
+                points.len(),
+                tables.as_mut_ptr(),
+                slides.as_mut_ptr(),
+            )
+        };
+        r.into_encoded_point()
+    };
+
+    // Steps 11 and 12.
+    let expected_c = challenge(&[public_key, &h_string, gamma_string, &u, &v]);
+    if expected_c[..] != c[..] {
+        return Err(error::Unspecified);
+    }
+
+    Ok(proof_to_hash(&gamma))
+}
+
+// RFC 8032 Section 5.1.3 decoding, which is what RFC 9381's `string_to_point`
+// is for this suite. Unlike `ExtPoint::from_encoded_point_vartime()`, this
+// rejects non-canonical encodings.
+fn decode_point(encoded: &EncodedPoint) -> Result<ExtPoint, error::Unspecified> {
+    let point = ExtPoint::from_encoded_point_vartime(encoded)?;
+    if point.clone().into_encoded_point() != *encoded {
+        return Err(error::Unspecified);
+    }
+    Ok(point)
+}
+
+// RFC 9381 Section 5.4.1.1, ECVRF_encode_to_curve_try_and_increment, with the
+// public key as the salt.
+fn encode_to_curve(public_key: &EncodedPoint, alpha: &[u8]) -> ExtPoint {
+    let mut identity = [0; ELEM_LEN];
+    identity[0] = 1;
+
+    // Each candidate is a valid point with probability about 1/2 so running
+    // out of counter values is not a practical concern.
+    for ctr in 0..=u8::MAX {
+        let mut ctx = digest::Context::new(&digest::SHA512);
+        ctx.update(&[SUITE_STRING, ENCODE_TO_CURVE_DOMAIN_SEPARATOR_FRONT]);
+        ctx.update(public_key);
+        ctx.update(alpha);
+        ctx.update(&[ctr, DOMAIN_SEPARATOR_BACK]);
+        let hash = ctx.finish();
+        let candidate: &EncodedPoint = hash.as_ref()[..ELEM_LEN].try_into().unwrap();
+        if let Ok(h) = decode_point(candidate) {
+            let h = h.mul_by_cofactor();
+            if h.clone().into_encoded_point() != identity {
+                return h;
+            }
+        }
+    }
+    unreachable!()
+}
+
+// RFC 9381 Section 5.4.3, ECVRF_challenge_generation.
+fn challenge(points: &[&EncodedPoint; 5]) -> [u8; CHALLENGE_LEN] {
+    let mut ctx = digest::Context::new(&digest::SHA512);
+    ctx.update(&[SUITE_STRING, CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT]);
+    for point in points {
+        ctx.update(*point);
+    }
+    ctx.update(&[DOMAIN_SEPARATOR_BACK]);
+    ctx.finish().as_ref()[..CHALLENGE_LEN].try_into().unwrap()
+}
+
+fn challenge_scalar(c: &[u8; CHALLENGE_LEN]) -> Scalar {
+    let mut bytes = [0; SCALAR_LEN];
+    bytes[..CHALLENGE_LEN].copy_from_slice(c);
+    // 2**128 is much smaller than the group order.
+    Scalar::from_bytes_checked(bytes).unwrap()
+}
+
+// RFC 9381 Section 5.2, ECVRF_proof_to_hash.
+fn proof_to_hash(gamma: &ExtPoint) -> Output {
+    let mut ctx = digest::Context::new(&digest::SHA512);
+    ctx.update(&[SUITE_STRING, PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT]);
+    ctx.update(&gamma.mul_by_cofactor().into_encoded_point());
+    ctx.update(&[DOMAIN_SEPARATOR_BACK]);
+    Output(ctx.finish().as_ref().try_into().unwrap())
+}
+
+prefixed_extern! {
+    fn x25519_ge_scalarmult_base(h: &mut ExtPoint, a: &Scalar);
+}
+
+prefixed_extern! {
+    fn x25519_ge_double_scalarmult_vartime(
+        r: &mut Point,
+        a_coeff: &Scalar,
+        a: &ExtPoint,
+        b_coeff: &Scalar,
+    );
+}
+
+prefixed_extern! {
+    fn x25519_ge_multi_scalarmult_vartime(
+        r: &mut Point,
+        b_coeff: &Scalar,
+        coeffs: *const Scalar,
+        points: *const ExtPoint,
+        num_points: usize,
+        tables: *mut CachedPoint,
+        slides: *mut i8,
+    );
+}
@@ -15,7 +15,7 @@
 //! EdDSA Signatures.
 
 use super::ops::ELEM_LEN;
-use crate::digest;
+use crate::{digest, error};
 
 pub mod signing;
 pub mod verification;
@@ -23,8 +23,66 @@ pub mod verification;
 /// The length of an Ed25519 public key.
 pub const ED25519_PUBLIC_KEY_LEN: usize = ELEM_LEN;
 
-pub fn eddsa_digest(signature_r: &[u8], public_key: &[u8], msg: &[u8]) -> digest::Digest {
+/// The `dom2` prefix of RFC 8032 Section 5.1, which separates Ed25519ph and
+/// Ed25519ctx signatures from Ed25519 signatures and from each other, and
+/// signatures with different contexts from each other.
+pub struct Dom2<'a> {
+    phflag: u8,
+    context: &'a [u8],
+}
+
+impl<'a> Dom2<'a> {
+    /// The prefix for Ed25519ph.
+    pub fn ph(context: &'a [u8]) -> Result<Self, error::Unspecified> {
+        if context.len() > MAX_CONTEXT_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(Self { phflag: 1, context })
+    }
+
+    /// The prefix for Ed25519ctx, which requires a non-empty context.
+    pub fn ctx(context: &'a [u8]) -> Result<Self, error::Unspecified> {
+        if context.is_empty() || context.len() > MAX_CONTEXT_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(Self { phflag: 0, context })
+    }
+
+    fn update(&self, ctx: &mut digest::Context) {
+        ctx.update(b"SigEd25519 no Ed25519 collisions");
+        // `context.len()` fits in a byte; see the constructors.
+        ctx.update(&[self.phflag, self.context.len() as u8]);
+        ctx.update(self.context);
+    }
+}
+
+/// The maximum length of an Ed25519ph or Ed25519ctx context.
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// The message that is actually signed for an Ed25519ph signature: its
+/// SHA-512 digest, `PH(M)` in RFC 8032.
+pub fn ph(prehash: &digest::Digest) -> Result<&[u8], error::Unspecified> {
+    if prehash.algorithm() != &digest::SHA512 {
+        return Err(error::Unspecified);
+    }
+    Ok(prehash.as_ref())
+}
+
+pub fn eddsa_context(dom2: Option<&Dom2>) -> digest::Context {
     let mut ctx = digest::Context::new(&digest::SHA512);
+    if let Some(dom2) = dom2 {
+        dom2.update(&mut ctx);
+    }
+    ctx
+}
+
+pub fn eddsa_digest(
+    dom2: Option<&Dom2>,
+    signature_r: &[u8],
+    public_key: &[u8],
+    msg: &[u8],
+) -> digest::Digest {
+    let mut ctx = eddsa_context(dom2);
     ctx.update(signature_r);
     ctx.update(public_key);
     ctx.update(msg);
@@ -14,9 +14,11 @@
 
 //! EdDSA Signatures.
 
-use super::{super::ops::*, eddsa_digest, ED25519_PUBLIC_KEY_LEN};
+use super::{
+    super::ops::*, eddsa_context, eddsa_digest, ph, verification, Dom2, ED25519_PUBLIC_KEY_LEN,
+};
 use crate::{
-    digest, error,
+    agreement, digest, error,
     io::der,
     pkcs8, rand,
     signature::{self, KeyPair as SigningKeyPair},
@@ -33,6 +35,10 @@ pub struct Ed25519KeyPair {
 
     // RFC 8032 Section 5.1.5 calls this *A*.
     public_key: PublicKey,
+
+    // Only kept for key pairs constructed with one of the `_exportable`
+    // constructors.
+    seed: Option<Seed>,
 }
 
 derive_debug_via_field!(Ed25519KeyPair, stringify!(Ed25519KeyPair), public_key);
@@ -140,6 +146,35 @@ impl Ed25519KeyPair {
         Ok(pair)
     }
 
+    /// Constructs an Ed25519 key pair by parsing an unencrypted PKCS#8 v2
+    /// Ed25519 private key, like `Ed25519KeyPair::from_pkcs8()`, and keeps
+    /// the private key seed so that `Ed25519KeyPair::seed()` can return it.
+    ///
+    /// Only use this when the seed really needs to leave the key pair, e.g.
+    /// for backups, for import into an HSM, or for use with libsodium's
+    /// seed-based APIs.
+    pub fn from_pkcs8_exportable(pkcs8: &[u8]) -> Result<Self, error::KeyRejected> {
+        let (seed, public_key) =
+            unwrap_pkcs8(pkcs8::Version::V2Only, untrusted::Input::from(pkcs8))?;
+        Self::from_seed_and_public_key_exportable(
+            seed.as_slice_less_safe(),
+            public_key.unwrap().as_slice_less_safe(),
+        )
+    }
+
+    /// Constructs an Ed25519 key pair from the private key seed `seed` and its
+    /// public key `public_key`, like
+    /// `Ed25519KeyPair::from_seed_and_public_key()`, and keeps the seed so
+    /// that `Ed25519KeyPair::seed()` can return it.
+    pub fn from_seed_and_public_key_exportable(
+        seed: &[u8],
+        public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let mut pair = Self::from_seed_and_public_key(seed, public_key)?;
+        pair.seed = Some(seed.try_into().unwrap());
+        Ok(pair)
+    }
+
     /// Constructs a Ed25519 key pair from the private key seed `seed`.
     ///
     /// It is recommended to use `Ed25519KeyPair::from_pkcs8()` instead. When
@@ -172,25 +207,93 @@ impl Ed25519KeyPair {
             private_scalar,
             private_prefix: private_prefix.try_into().unwrap(),
             public_key: PublicKey(a.into_encoded_point()),
+            seed: None,
         }
     }
 
+    /// Returns the 32-byte private key seed, the private key of RFC 8032
+    /// Section 5.1.5.
+    ///
+    /// The seed is only available if the key pair was constructed with
+    /// `Ed25519KeyPair::from_pkcs8_exportable()` or
+    /// `Ed25519KeyPair::from_seed_and_public_key_exportable()`; otherwise this
+    /// fails.
+    pub fn seed(&self) -> Result<&[u8], error::Unspecified> {
+        self.seed
+            .as_ref()
+            .map(|seed| &seed[..])
+            .ok_or(error::Unspecified)
+    }
+
     /// Returns the signature of the message `msg`.
     pub fn sign(&self, msg: &[u8]) -> signature::Signature {
-        signature::Signature::new(|signature_bytes| {
-            prefixed_extern! {
-                fn x25519_sc_muladd(
-                    s: &mut [u8; SCALAR_LEN],
-                    a: &Scalar,
-                    b: &Scalar,
-                    c: &Scalar,
-                );
-            }
+        self.sign_(None, msg)
+    }
+
+    /// Returns the [Ed25519ph] signature, with the context `context`, of the
+    /// message whose SHA-512 digest is `prehash`.
+    ///
+    /// Since only the digest is needed, a message that is too large to hold
+    /// in memory can be signed by hashing it incrementally with a
+    /// `digest::Context`. Ed25519ph signatures are not Ed25519 signatures;
+    /// they must be verified with `signature::ED25519PH` or
+    /// `signature::verify_ed25519ph`. `context` is usually empty.
+    ///
+    /// Fails if `prehash` isn't a SHA-512 digest or if `context` is longer
+    /// than 255 bytes.
+    ///
+    /// [Ed25519ph]: https://tools.ietf.org/html/rfc8032#section-5.1
+    pub fn sign_prehashed(
+        &self,
+        context: &[u8],
+        prehash: &digest::Digest,
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let dom2 = Dom2::ph(context)?;
+        Ok(self.sign_(Some(&dom2), ph(prehash)?))
+    }
 
+    /// Returns the [Ed25519ctx] signature of the message `msg` with the
+    /// context `context`.
+    ///
+    /// The context separates the signatures of different protocols, or of
+    /// different uses within a protocol: an Ed25519ctx signature is only valid
+    /// for the same context, and is never a valid Ed25519 or Ed25519ph
+    /// signature. It must be verified with `signature::verify_ed25519ctx`.
+    ///
+    /// Fails unless `context` is between 1 and 255 bytes long.
+    ///
+    /// [Ed25519ctx]: https://tools.ietf.org/html/rfc8032#section-5.1
+    pub fn sign_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let dom2 = Dom2::ctx(context)?;
+        Ok(self.sign_(Some(&dom2), msg))
+    }
+
+    /// Returns the X25519 private key for this key pair's private key, for
+    /// protocols that use one identity key both for signing and for key
+    /// agreement.
+    ///
+    /// The X25519 private key is the clamped scalar *s* of RFC 8032, so its
+    /// public key is `agreement::x25519_public_key_from_ed25519()` of
+    /// `self.public_key()`. Since an `agreement::EphemeralPrivateKey` can be
+    /// used only once, call this once per key agreement. Reusing a key for
+    /// both signing and key agreement is only safe in protocols that were
+    /// designed and analyzed for it.
+    pub fn x25519_private_key(&self) -> agreement::EphemeralPrivateKey {
+        // Every 32-byte value is a valid X25519 private key.
+        agreement::EphemeralPrivateKey::from_bytes(&agreement::X25519, self.private_scalar.as_ref())
+            .unwrap()
+    }
+
+    fn sign_(&self, dom2: Option<&Dom2>, msg: &[u8]) -> signature::Signature {
+        signature::Signature::new(|signature_bytes| {
             let (signature_bytes, _unused) = signature_bytes.split_at_mut(ELEM_LEN + SCALAR_LEN);
             let (signature_r, signature_s) = signature_bytes.split_at_mut(ELEM_LEN);
             let nonce = {
-                let mut ctx = digest::Context::new(&digest::SHA512);
+                let mut ctx = eddsa_context(dom2);
                 ctx.update(&self.private_prefix);
                 ctx.update(msg);
                 ctx.finish()
@@ -202,16 +305,10 @@ impl Ed25519KeyPair {
                 x25519_ge_scalarmult_base(&mut r, &nonce);
             }
             signature_r.copy_from_slice(&r.into_encoded_point());
-            let hram_digest = eddsa_digest(signature_r, self.public_key.as_ref(), msg);
+            let hram_digest = eddsa_digest(dom2, signature_r, self.public_key.as_ref(), msg);
             let hram = Scalar::from_sha512_digest_reduced(hram_digest);
-            unsafe {
-                x25519_sc_muladd(
-                    signature_s.try_into().unwrap(),
-                    &hram,
-                    &self.private_scalar,
-                    &nonce,
-                );
-            }
+            let s = Scalar::mul_add(&hram, &self.private_scalar, &nonce);
+            signature_s.copy_from_slice(s.as_ref());
 
             SIGNATURE_LEN
         })
@@ -226,6 +323,19 @@ impl signature::KeyPair for Ed25519KeyPair {
     }
 }
 
+impl signature::KeyPairGenerator for verification::EdDSAParameters {
+    type KeyPair = Ed25519KeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(rng)?;
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct PublicKey([u8; ED25519_PUBLIC_KEY_LEN]);
 
@@ -237,6 +347,26 @@ impl AsRef<[u8]> for PublicKey {
 
 derive_debug_self_as_ref_hex_bytes!(PublicKey);
 
+#[cfg(feature = "alloc")]
+impl PublicKey {
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`, as
+    /// described in [RFC 8410].
+    ///
+    /// [RFC 8410]: https://tools.ietf.org/html/rfc8410
+    pub fn subject_public_key_info(&self) -> alloc::boxed::Box<[u8]> {
+        use crate::io::der_writer;
+        der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                output.write_bytes(PKCS8_TEMPLATE.alg_id_value_())
+            });
+            der_writer::write_tlv(output, der::Tag::BitString, |output| {
+                output.write_byte(0); // No unused bits.
+                output.write_bytes(self.as_ref());
+            });
+        })
+    }
+}
+
 fn unwrap_pkcs8(
     version: pkcs8::Version,
     input: untrusted::Input,
@@ -14,16 +14,51 @@
 
 //! EdDSA Signatures.
 
-use super::{super::ops::*, eddsa_digest};
-use crate::{error, sealed, signature};
+use super::{super::ops::*, eddsa_digest, ph, Dom2};
+use crate::{digest, error, sealed, signature};
 use core::convert::TryInto;
 
+#[cfg(feature = "alloc")]
+use crate::rand;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Parameters for EdDSA signing and verification.
-pub struct EdDSAParameters;
+pub struct EdDSAParameters {
+    policy: Policy,
+}
+
+// Which edge cases of the encodings and of the verification equation are
+// accepted. Implementations of Ed25519 disagree on these, so protocols where
+// verifiers must agree with each other pin down one of them.
+#[derive(Clone, Copy, PartialEq)]
+enum Policy {
+    // The public key's y coordinate may be reduced mod p and *R* must be the
+    // canonical encoding of [s]B - [h]A.
+    Default,
+
+    // Like `Default`, except that the public key must be canonically
+    // encoded and neither the public key nor *R* may have small order.
+    Strict,
+
+    // The rules of ZIP 215: the public key and *R* may be non-canonically
+    // encoded, and the cofactored equation [8][s]B = [8]R + [8][h]A is
+    // checked.
+    Zip215,
+}
 
 impl core::fmt::Debug for EdDSAParameters {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
-        write!(f, "ring::signature::ED25519")
+        let name = match self.policy {
+            Policy::Default => "ED25519",
+            Policy::Strict => "ED25519_STRICT",
+            Policy::Zip215 => "ED25519_ZIP215",
+        };
+        write!(f, "ring::signature::{}", name)
     }
 }
 
@@ -31,8 +66,43 @@ impl core::fmt::Debug for EdDSAParameters {
 ///
 /// Ed25519 uses SHA-512 as the digest algorithm.
 ///
+/// A public key whose *y* coordinate isn't fully reduced is accepted, and the
+/// encoding of *R* must be exactly the canonical encoding of the point that
+/// the (cofactorless) verification equation computes. See `ED25519_STRICT`
+/// and `ED25519_ZIP215` for alternatives.
+///
+/// [Ed25519]: https://ed25519.cr.yp.to/
+pub static ED25519: EdDSAParameters = EdDSAParameters {
+    policy: Policy::Default,
+};
+
+/// Verification of [Ed25519] signatures, rejecting non-canonical encodings
+/// and small-order points.
+///
+/// Like `ED25519`, except that the public key must be canonically encoded
+/// and that neither the public key nor *R* may be a point of small order.
+/// Those are the signatures that can't be valid for more than one message
+/// and public key, which some protocols require.
+///
+/// [Ed25519]: https://ed25519.cr.yp.to/
+pub static ED25519_STRICT: EdDSAParameters = EdDSAParameters {
+    policy: Policy::Strict,
+};
+
+/// Verification of [Ed25519] signatures as specified by [ZIP 215].
+///
+/// The public key and *R* may be any encoding of a point on the curve,
+/// including non-canonical ones, and the cofactored verification equation
+/// [8][*s*]*B* = [8]*R* + [8][*h*]*A* is checked. *s* must still be less
+/// than the group order. This is for consensus systems, in which all
+/// verifiers must agree on exactly which signatures are valid, and which
+/// follow ZIP 215.
+///
 /// [Ed25519]: https://ed25519.cr.yp.to/
-pub static ED25519: EdDSAParameters = EdDSAParameters {};
+/// [ZIP 215]: https://zips.z.cash/zip-0215
+pub static ED25519_ZIP215: EdDSAParameters = EdDSAParameters {
+    policy: Policy::Zip215,
+};
 
 impl signature::VerificationAlgorithm for EdDSAParameters {
     fn verify(
@@ -41,38 +111,495 @@ impl signature::VerificationAlgorithm for EdDSAParameters {
         msg: untrusted::Input,
         signature: untrusted::Input,
     ) -> Result<(), error::Unspecified> {
-        let public_key: &[u8; ELEM_LEN] = public_key.as_slice_less_safe().try_into()?;
-        let (signature_r, signature_s) = signature.read_all(error::Unspecified, |input| {
-            let signature_r: &[u8; ELEM_LEN] = input
-                .read_bytes(ELEM_LEN)?
-                .as_slice_less_safe()
-                .try_into()?;
-            let signature_s: &[u8; SCALAR_LEN] = input
-                .read_bytes(SCALAR_LEN)?
-                .as_slice_less_safe()
-                .try_into()?;
-            Ok((signature_r, signature_s))
-        })?;
-
-        let signature_s = Scalar::from_bytes_checked(*signature_s)?;
-
-        let mut a = ExtPoint::from_encoded_point_vartime(public_key)?;
-        a.invert_vartime();
-
-        let h_digest = eddsa_digest(signature_r, public_key, msg.as_slice_less_safe());
-        let h = Scalar::from_sha512_digest_reduced(h_digest);
+        let public_key =
+            ParsedPublicKey::parse(self.policy, public_key).map_err(|_| error::Unspecified)?;
+        verify_parsed_(
+            self.policy,
+            None,
+            &public_key,
+            msg.as_slice_less_safe(),
+            signature,
+        )
+    }
+
+    fn parse_public_key(
+        &self,
+        public_key: untrusted::Input,
+    ) -> Result<signature::parsed::PublicKey, error::KeyRejected> {
+        ParsedPublicKey::parse(self.policy, public_key).map(signature::parsed::PublicKey::Ed25519)
+    }
+
+    fn validate_public_key(&self, public_key: untrusted::Input) -> Result<(), error::KeyRejected> {
+        ParsedPublicKey::parse(Policy::Strict, public_key).map(|_| ())
+    }
+
+    fn verify_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        verify_parsed_(
+            self.policy,
+            None,
+            ParsedPublicKey::from_parsed(public_key)?,
+            msg.as_slice_less_safe(),
+            signature,
+        )
+    }
+}
+
+impl sealed::Sealed for EdDSAParameters {}
+
+/// Parameters for Ed25519ph verification.
+pub struct Ed25519phParameters;
+
+impl core::fmt::Debug for Ed25519phParameters {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "ring::signature::ED25519PH")
+    }
+}
+
+/// Verification of [Ed25519ph] signatures with an empty context.
+///
+/// The message is hashed with SHA-512 as part of the verification. Use
+/// `verify_ed25519ph` to verify the signature of a message that has already
+/// been hashed, or to use a non-empty context.
+///
+/// [Ed25519ph]: https://tools.ietf.org/html/rfc8032#section-5.1
+pub static ED25519PH: Ed25519phParameters = Ed25519phParameters {};
+
+impl signature::VerificationAlgorithm for Ed25519phParameters {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let prehash = digest::digest(&digest::SHA512, msg.as_slice_less_safe());
+        verify_ph(public_key, b"", &prehash, signature)
+    }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        verify_ph(public_key, b"", digest, signature)
+    }
+
+    fn parse_public_key(
+        &self,
+        public_key: untrusted::Input,
+    ) -> Result<signature::parsed::PublicKey, error::KeyRejected> {
+        ParsedPublicKey::parse(Policy::Default, public_key)
+            .map(signature::parsed::PublicKey::Ed25519)
+    }
+
+    fn validate_public_key(&self, public_key: untrusted::Input) -> Result<(), error::KeyRejected> {
+        ParsedPublicKey::parse(Policy::Strict, public_key).map(|_| ())
+    }
+
+    fn verify_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let prehash = digest::digest(&digest::SHA512, msg.as_slice_less_safe());
+        let dom2 = Dom2::ph(b"")?;
+        let public_key = ParsedPublicKey::from_parsed(public_key)?;
+        verify_parsed_(
+            Policy::Default,
+            Some(&dom2),
+            public_key,
+            ph(&prehash)?,
+            signature,
+        )
+    }
+
+    fn verify_digest_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let dom2 = Dom2::ph(b"")?;
+        let public_key = ParsedPublicKey::from_parsed(public_key)?;
+        verify_parsed_(
+            Policy::Default,
+            Some(&dom2),
+            public_key,
+            ph(digest)?,
+            signature,
+        )
+    }
+}
+
+impl sealed::Sealed for Ed25519phParameters {}
+
+/// Verifies that `signature` is a valid [Ed25519ph] signature, with the
+/// context `context`, by `public_key` of the message whose SHA-512 digest is
+/// `prehash`.
+///
+/// Since only the digest is needed, the message can be hashed incrementally
+/// with a `digest::Context`. Fails if `prehash` isn't a SHA-512 digest, if
+/// `context` is longer than 255 bytes, or if the signature is invalid.
+///
+/// [Ed25519ph]: https://tools.ietf.org/html/rfc8032#section-5.1
+pub fn verify_ed25519ph(
+    public_key: &[u8],
+    context: &[u8],
+    prehash: &digest::Digest,
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    verify_ph(
+        untrusted::Input::from(public_key),
+        context,
+        prehash,
+        untrusted::Input::from(signature),
+    )
+}
+
+/// Verifies that `signature` is a valid [Ed25519ctx] signature, with the
+/// context `context`, by `public_key` of the message `msg`.
+///
+/// Fails if `context` is empty or longer than 255 bytes, or if the signature
+/// is invalid.
+///
+/// [Ed25519ctx]: https://tools.ietf.org/html/rfc8032#section-5.1
+pub fn verify_ed25519ctx(
+    public_key: &[u8],
+    context: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    let dom2 = Dom2::ctx(context)?;
+    verify_(
+        Some(&dom2),
+        untrusted::Input::from(public_key),
+        msg,
+        untrusted::Input::from(signature),
+    )
+}
+
+fn verify_ph(
+    public_key: untrusted::Input,
+    context: &[u8],
+    prehash: &digest::Digest,
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    let dom2 = Dom2::ph(context)?;
+    verify_(Some(&dom2), public_key, ph(prehash)?, signature)
+}
+
+fn verify_(
+    dom2: Option<&Dom2>,
+    public_key: untrusted::Input,
+    msg: &[u8],
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    let public_key =
+        ParsedPublicKey::parse(Policy::Default, public_key).map_err(|_| error::Unspecified)?;
+    verify_parsed_(Policy::Default, dom2, &public_key, msg, signature)
+}
+
+/// An Ed25519 public key that has been decompressed.
+pub struct ParsedPublicKey {
+    encoded: EncodedPoint,
+    // The negation of the public key point, as `verify_parsed_` needs it.
+    minus_a: ExtPoint,
+}
+
+impl ParsedPublicKey {
+    fn parse(policy: Policy, public_key: untrusted::Input) -> Result<Self, error::KeyRejected> {
+        let encoded: EncodedPoint = public_key
+            .as_slice_less_safe()
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let mut minus_a = ExtPoint::from_encoded_point_vartime(&encoded)
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        if policy == Policy::Strict {
+            // Decoding reduces the y coordinate and ignores the sign of a zero
+            // x coordinate, so a non-canonical encoding is one that doesn't
+            // survive the round trip.
+            if minus_a.clone().into_encoded_point() != encoded {
+                return Err(error::KeyRejected::invalid_encoding());
+            }
+            if is_small_order(&encoded) {
+                return Err(error::KeyRejected::inconsistent_components());
+            }
+        }
+        minus_a.invert_vartime();
+        Ok(Self { encoded, minus_a })
+    }
+
+    fn from_parsed(public_key: &signature::parsed::PublicKey) -> Result<&Self, error::Unspecified> {
+        match public_key {
+            signature::parsed::PublicKey::Ed25519(public_key) => Ok(public_key),
+            _ => Err(error::Unspecified),
+        }
+    }
+}
+
+fn verify_parsed_(
+    policy: Policy,
+    dom2: Option<&Dom2>,
+    public_key: &ParsedPublicKey,
+    msg: &[u8],
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    let (signature_r, signature_s) = parse_signature(signature)?;
+    let a = &public_key.minus_a;
 
-        let mut r = Point::new_at_infinity();
-        unsafe { x25519_ge_double_scalarmult_vartime(&mut r, &h, &a, &signature_s) };
-        let r_check = r.into_encoded_point();
-        if *signature_r != r_check {
+    let h_digest = eddsa_digest(dom2, signature_r, &public_key.encoded, msg);
+    let h = Scalar::from_sha512_digest_reduced(h_digest);
+
+    if policy == Policy::Zip215 {
+        // [8]([s]B - [h]A - R) is the identity exactly when
+        // [s]B - [h]A - R has small order.
+        let mut minus_r = ExtPoint::from_encoded_point_vartime(signature_r)?;
+        minus_r.invert_vartime();
+        let one = {
+            let mut bytes = [0u8; SCALAR_LEN];
+            bytes[0] = 1;
+            Scalar::from_bytes_checked(bytes)?
+        };
+        let coeffs = [h, one];
+        let points = [a.clone(), minus_r];
+        let mut tables = [CachedPoint::zero(); 8 * 2];
+        let mut slides = [0i8; 2 * 256];
+        let mut sum = Point::new_at_infinity();
+        unsafe {
+            x25519_ge_multi_scalarmult_vartime(
+                &mut sum,
+                &signature_s,
+                coeffs.as_ptr(),
+                points.as_ptr(),
+                points.len(),
+                tables.as_mut_ptr(),
+                slides.as_mut_ptr(),
+            )
+        };
+        if !is_small_order(&sum.into_encoded_point()) {
             return Err(error::Unspecified);
         }
-        Ok(())
+        return Ok(());
+    }
+
+    if policy == Policy::Strict && is_small_order(signature_r) {
+        return Err(error::Unspecified);
+    }
+
+    let mut r = Point::new_at_infinity();
+    unsafe { x25519_ge_double_scalarmult_vartime(&mut r, &h, a, &signature_s) };
+    let r_check = r.into_encoded_point();
+    if *signature_r != r_check {
+        return Err(error::Unspecified);
     }
+    Ok(())
 }
 
-impl sealed::Sealed for EdDSAParameters {}
+#[cfg(feature = "alloc")]
+fn parse<'a>(
+    public_key: untrusted::Input<'a>,
+    signature: untrusted::Input<'a>,
+) -> Result<(&'a EncodedPoint, &'a EncodedPoint, Scalar), error::Unspecified> {
+    let public_key: &[u8; ELEM_LEN] = public_key.as_slice_less_safe().try_into()?;
+    let (signature_r, signature_s) = parse_signature(signature)?;
+    Ok((public_key, signature_r, signature_s))
+}
+
+fn parse_signature(
+    signature: untrusted::Input<'_>,
+) -> Result<(&EncodedPoint, Scalar), error::Unspecified> {
+    let (signature_r, signature_s) = signature.read_all(error::Unspecified, |input| {
+        let signature_r: &[u8; ELEM_LEN] = input
+            .read_bytes(ELEM_LEN)?
+            .as_slice_less_safe()
+            .try_into()?;
+        let signature_s: &[u8; SCALAR_LEN] = input
+            .read_bytes(SCALAR_LEN)?
+            .as_slice_less_safe()
+            .try_into()?;
+        Ok((signature_r, signature_s))
+    })?;
+
+    let signature_s = Scalar::from_bytes_checked(*signature_s)?;
+
+    Ok((signature_r, signature_s))
+}
+
+/// Verifies a batch of Ed25519 signatures, each given as a
+/// `(public_key, message, signature)` triple.
+///
+/// The signatures are checked in groups, each with a single random linear
+/// combination of the verification equations of its signatures, which is
+/// much faster than verifying each one with `UnparsedPublicKey::verify`.
+/// `rng` provides the coefficients of the combinations. If the combined check
+/// of a group fails, or if `rng` fails, the signatures in that group are
+/// verified individually so that the invalid ones can be identified.
+///
+/// A batch in which every signature is valid is always accepted. A batch
+/// that contains an invalid signature is rejected, except with negligible
+/// probability, unless that signature was crafted so that it differs from a
+/// valid one only by a point of small order; such a batch may be accepted
+/// even though verifying the signature individually fails. Applications
+/// where all verifiers must agree exactly on which signatures are valid
+/// should verify each signature individually, or use `ED25519_ZIP215`,
+/// which accepts such signatures individually too.
+///
+/// On failure, the error lists the indices of the invalid signatures.
+#[cfg(feature = "alloc")]
+pub fn verify_batch(
+    algorithm: &'static EdDSAParameters,
+    batch: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), BatchVerificationError> {
+    let mut invalid_indices = Vec::new();
+    for (chunk_index, chunk) in batch.chunks(CHUNK_LEN).enumerate() {
+        if verify_combined(algorithm.policy, chunk, rng).is_ok() {
+            continue;
+        }
+        let first = chunk_index * CHUNK_LEN;
+        invalid_indices.extend(chunk.iter().enumerate().filter_map(
+            |(i, &(public_key, msg, signature))| {
+                signature::VerificationAlgorithm::verify(
+                    algorithm,
+                    untrusted::Input::from(public_key),
+                    untrusted::Input::from(msg),
+                    untrusted::Input::from(signature),
+                )
+                .err()
+                .map(|_| first + i)
+            },
+        ));
+    }
+    if invalid_indices.is_empty() {
+        return Ok(());
+    }
+    Err(BatchVerificationError { invalid_indices })
+}
+
+// The number of signatures that are checked together. Larger chunks share
+// more of the work, but their precomputed multiples of the points no longer
+// fit in the cache.
+#[cfg(feature = "alloc")]
+const CHUNK_LEN: usize = 64;
+
+// Checks that [sum(z_i * s_i)]B - sum([z_i]R_i) - sum([z_i * h_i]A_i) is the
+// identity for random 128-bit z_i.
+#[cfg(feature = "alloc")]
+fn verify_combined(
+    policy: Policy,
+    batch: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), error::Unspecified> {
+    const Z_LEN: usize = 128 / 8;
+
+    let mut z_bytes = alloc::vec![0u8; batch.len() * Z_LEN];
+    rng.fill(&mut z_bytes)?;
+
+    let zero = Scalar::from_bytes_checked([0u8; SCALAR_LEN])?;
+    let mut b_coeff = Scalar::from_bytes_checked([0u8; SCALAR_LEN])?;
+    let mut points = Vec::with_capacity(2 * batch.len());
+    let mut coeffs = Vec::with_capacity(2 * batch.len());
+
+    for (&(public_key, msg, signature), z) in batch.iter().zip(z_bytes.chunks(Z_LEN)) {
+        let (public_key, signature_r, signature_s) = parse(
+            untrusted::Input::from(public_key),
+            untrusted::Input::from(signature),
+        )?;
+
+        let a = ParsedPublicKey::parse(policy, untrusted::Input::from(public_key))?.minus_a;
+        let mut r = ExtPoint::from_encoded_point_vartime(signature_r)?;
+        // Make the combined check reject what verifying individually would.
+        if policy != Policy::Zip215 && r.clone().into_encoded_point() != *signature_r {
+            return Err(error::Unspecified);
+        }
+        if policy == Policy::Strict && is_small_order(signature_r) {
+            return Err(error::Unspecified);
+        }
+        r.invert_vartime();
+
+        let h_digest = eddsa_digest(None, signature_r, public_key, msg);
+        let h = Scalar::from_sha512_digest_reduced(h_digest);
+
+        let z = {
+            let mut bytes = [0u8; SCALAR_LEN];
+            bytes[..Z_LEN].copy_from_slice(z);
+            Scalar::from_bytes_checked(bytes)?
+        };
+
+        b_coeff = Scalar::mul_add(&z, &signature_s, &b_coeff);
+        coeffs.push(Scalar::mul_add(&z, &h, &zero));
+        points.push(a);
+        coeffs.push(z);
+        points.push(r);
+    }
+
+    let mut tables: Vec<CachedPoint> = (0..(8 * points.len()))
+        .map(|_| CachedPoint::zero())
+        .collect();
+    let mut slides = alloc::vec![0i8; 256 * points.len()];
+
+    let mut sum = Point::new_at_infinity();
+    unsafe {
+        x25519_ge_multi_scalarmult_vartime(
+            &mut sum,
+            &b_coeff,
+            coeffs.as_ptr(),
+            points.as_ptr(),
+            points.len(),
+            tables.as_mut_ptr(),
+            slides.as_mut_ptr(),
+        )
+    };
+
+    let mut identity = [0u8; ELEM_LEN];
+    identity[0] = 1;
+    if sum.into_encoded_point() != identity {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// An error returned by `verify_batch`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchVerificationError {
+    pub(crate) invalid_indices: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerificationError {
+    /// The indices, in increasing order, of the invalid signatures in the
+    /// batch.
+    pub fn invalid_indices(&self) -> &[usize] {
+        &self.invalid_indices
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for BatchVerificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("ring::signature::BatchVerificationError")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchVerificationError {}
+
+#[cfg(feature = "alloc")]
+impl From<BatchVerificationError> for error::Unspecified {
+    fn from(_: BatchVerificationError) -> Self {
+        Self
+    }
+}
 
 prefixed_extern! {
     fn x25519_ge_double_scalarmult_vartime(
@@ -82,3 +609,15 @@ prefixed_extern! {
         b_coeff: &Scalar,
     );
 }
+
+prefixed_extern! {
+    fn x25519_ge_multi_scalarmult_vartime(
+        r: &mut Point,
+        b_coeff: &Scalar,
+        coeffs: *const Scalar,
+        points: *const ExtPoint,
+        num_points: usize,
+        tables: *mut CachedPoint,
+        slides: *mut i8,
+    );
+}
@@ -0,0 +1,665 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! FROST(Ed25519, SHA-512), as specified in RFC 9591.
+
+use super::{ed25519::ED25519_PUBLIC_KEY_LEN, ops::*};
+use crate::{digest, error, rand, signature};
+use core::convert::TryInto;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The length of an encoded signature share.
+pub const SIGNATURE_SHARE_LEN: usize = SCALAR_LEN;
+
+/// The length of each of the two encoded nonce commitments.
+pub const COMMITMENT_LEN: usize = ELEM_LEN;
+
+const CONTEXT_STRING: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+/// A participant's share of a FROST key.
+///
+/// `min_signers` of the participants holding shares of the same key are
+/// needed to produce a signature. Signatures are ordinary Ed25519 signatures
+/// that verify with the group public key.
+pub struct KeyPackage {
+    identifier: u16,
+    min_signers: u16,
+    signing_share: Scalar,
+    verifying_share: PublicKey,
+    group_public_key: PublicKey,
+}
+
+derive_debug_via_field!(KeyPackage, stringify!(KeyPackage), verifying_share);
+
+impl KeyPackage {
+    /// Constructs a key package from the participant's `signing_share`, the
+    /// 32-byte little-endian encoding of a scalar.
+    ///
+    /// `identifier` must not be zero and `min_signers` must be at least two.
+    /// Nothing checks that `signing_share` is a share of the private key for
+    /// `group_public_key`; that requires the verifiable secret sharing
+    /// commitment from key generation.
+    pub fn from_signing_share(
+        identifier: u16,
+        min_signers: u16,
+        signing_share: &[u8],
+        group_public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        if identifier == 0 || min_signers < 2 {
+            return Err(error::KeyRejected::invalid_component());
+        }
+        let signing_share = signing_share
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let signing_share = Scalar::from_bytes_checked(signing_share)
+            .map_err(|_| error::KeyRejected::invalid_component())?;
+        let group_public_key = group_public_key
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let _ = decode_element(group_public_key)
+            .map_err(|_| error::KeyRejected::invalid_component())?;
+        Ok(Self::new(
+            identifier,
+            min_signers,
+            signing_share,
+            *group_public_key,
+        ))
+    }
+
+    fn new(
+        identifier: u16,
+        min_signers: u16,
+        signing_share: Scalar,
+        group_public_key: EncodedPoint,
+    ) -> Self {
+        let verifying_share = PublicKey(scalar_mult_base(&signing_share));
+        Self {
+            identifier,
+            min_signers,
+            signing_share,
+            verifying_share,
+            group_public_key: PublicKey(group_public_key),
+        }
+    }
+
+    /// The participant's identifier.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// The number of participants needed to produce a signature.
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    /// The participant's public verifying share, which the coordinator uses
+    /// in `verify_signature_share()`.
+    pub fn verifying_share(&self) -> &PublicKey {
+        &self.verifying_share
+    }
+
+    /// The group public key, which is an Ed25519 public key.
+    pub fn group_public_key(&self) -> &PublicKey {
+        &self.group_public_key
+    }
+
+    /// Round one: generates fresh nonces and the commitments to them.
+    ///
+    /// The commitments are sent to the coordinator and the nonces are kept
+    /// for `sign()`, which consumes them, so that they can't be used twice.
+    /// This is RFC 9591's `commit`.
+    pub fn commit(
+        &self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(SigningNonces, SigningCommitments), error::Unspecified> {
+        let hiding = self.nonce_generate(rng)?;
+        let binding = self.nonce_generate(rng)?;
+        let commitments = SigningCommitments {
+            identifier: self.identifier,
+            hiding: scalar_mult_base(&hiding),
+            binding: scalar_mult_base(&binding),
+        };
+        let nonces = SigningNonces {
+            hiding,
+            binding,
+            commitments,
+        };
+        Ok((nonces, commitments))
+    }
+
+    // RFC 9591 Section 4.1.
+    fn nonce_generate(&self, rng: &dyn rand::SecureRandom) -> Result<Scalar, error::Unspecified> {
+        let random_bytes: [u8; 32] = rand::generate(rng)?.expose();
+        let mut ctx = digest::Context::new(&digest::SHA512);
+        ctx.update(CONTEXT_STRING);
+        ctx.update(b"nonce");
+        ctx.update(&random_bytes);
+        ctx.update(self.signing_share.as_ref());
+        Ok(Scalar::from_sha512_digest_reduced(ctx.finish()))
+    }
+
+    /// Round two: computes this participant's share of the signature of
+    /// `msg`, using the nonces from round one and the commitments of all the
+    /// signers, which the coordinator chose.
+    ///
+    /// `commitments` must be sorted by identifier, contain no duplicates,
+    /// have at least `min_signers()` elements, and include the commitments
+    /// for `nonces`. This is RFC 9591's `sign`.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces,
+        commitments: &[SigningCommitments],
+        msg: &[u8],
+    ) -> Result<SignatureShare, error::Unspecified> {
+        check_commitment_list(commitments)?;
+        if commitments.len() < usize::from(self.min_signers)
+            || !commitments.contains(&nonces.commitments)
+        {
+            return Err(error::Unspecified);
+        }
+
+        let group_public_key = &self.group_public_key.0;
+        let prefix = binding_factor_input_prefix(group_public_key, commitments, msg);
+        let binding_factor = binding_factor(&prefix, self.identifier);
+        let group_commitment = group_commitment(&prefix, commitments)?;
+        let lambda = lagrange_coefficient(commitments, self.identifier);
+        let challenge = challenge(&group_commitment, group_public_key, msg);
+
+        // z = d + (e * rho) + (lambda * s * c).
+        let z = Scalar::mul_add(&nonces.binding, &binding_factor, &nonces.hiding);
+        let lambda_s = scalar_mul(&lambda, &self.signing_share);
+        let z = Scalar::mul_add(&lambda_s, &challenge, &z);
+
+        Ok(SignatureShare {
+            identifier: self.identifier,
+            share: z.as_ref().try_into().unwrap(),
+        })
+    }
+}
+
+/// Generates a new key with a trusted dealer and splits it into
+/// `max_signers` shares, `min_signers` of which are needed to sign, with
+/// identifiers 1 through `max_signers`.
+///
+/// This is RFC 9591 Appendix C's `trusted_dealer_keygen`. The dealer knows
+/// the whole private key, so should be trusted and should forget the shares
+/// after distributing them.
+#[cfg(feature = "alloc")]
+pub fn generate_with_dealer(
+    min_signers: u16,
+    max_signers: u16,
+    rng: &dyn rand::SecureRandom,
+) -> Result<Vec<KeyPackage>, error::Unspecified> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(error::Unspecified);
+    }
+
+    // The secret is `coefficients[0]`.
+    let coefficients = (0..min_signers)
+        .map(|_| random_nonzero_scalar(rng))
+        .collect::<Result<Vec<_>, _>>()?;
+    let group_public_key = scalar_mult_base(&coefficients[0]);
+
+    Ok((1..=max_signers)
+        .map(|identifier| {
+            // Horner's method.
+            let x = scalar_from_u16(identifier);
+            let signing_share = coefficients
+                .iter()
+                .rev()
+                .fold(scalar_from_u16(0), |acc, coefficient| {
+                    Scalar::mul_add(&acc, &x, coefficient)
+                });
+            KeyPackage::new(identifier, min_signers, signing_share, group_public_key)
+        })
+        .collect())
+}
+
+/// The secret nonces from round one. They must only be used once.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitments: SigningCommitments,
+}
+
+derive_debug_via_field!(SigningNonces, stringify!(SigningNonces), commitments);
+
+impl SigningNonces {
+    /// The commitments to these nonces.
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+}
+
+/// A participant's commitments to its nonces, from round one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SigningCommitments {
+    identifier: u16,
+    hiding: EncodedPoint,
+    binding: EncodedPoint,
+}
+
+impl SigningCommitments {
+    /// Parses the commitments that participant `identifier` sent.
+    ///
+    /// Both must be canonically encoded elements of the prime-order subgroup
+    /// other than the identity, as RFC 9591 Section 6.5 requires.
+    pub fn from_bytes(
+        identifier: u16,
+        hiding: &[u8],
+        binding: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if identifier == 0 {
+            return Err(error::Unspecified);
+        }
+        let hiding = hiding.try_into()?;
+        let binding = binding.try_into()?;
+        let _ = decode_element(hiding)?;
+        let _ = decode_element(binding)?;
+        Ok(Self {
+            identifier,
+            hiding: *hiding,
+            binding: *binding,
+        })
+    }
+
+    /// The identifier of the participant.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// The encoded hiding nonce commitment.
+    pub fn hiding(&self) -> &[u8] {
+        &self.hiding
+    }
+
+    /// The encoded binding nonce commitment.
+    pub fn binding(&self) -> &[u8] {
+        &self.binding
+    }
+}
+
+/// A participant's share of a signature, from round two.
+#[derive(Clone, Copy)]
+pub struct SignatureShare {
+    identifier: u16,
+    share: [u8; SIGNATURE_SHARE_LEN],
+}
+
+impl SignatureShare {
+    /// Parses the signature share that participant `identifier` sent.
+    pub fn from_bytes(identifier: u16, share: &[u8]) -> Result<Self, error::Unspecified> {
+        if identifier == 0 {
+            return Err(error::Unspecified);
+        }
+        let share: [u8; SIGNATURE_SHARE_LEN] = share.try_into()?;
+        let _ = Scalar::from_bytes_checked(share)?;
+        Ok(Self { identifier, share })
+    }
+
+    /// The identifier of the participant.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+impl AsRef<[u8]> for SignatureShare {
+    fn as_ref(&self) -> &[u8] {
+        &self.share
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(SignatureShare);
+
+/// A group public key or a participant's verifying share, in the Ed25519
+/// encoding.
+#[derive(Clone, Copy)]
+pub struct PublicKey(EncodedPoint);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+/// Verifies that `share` is a valid signature share by the participant with
+/// the given `verifying_share`, so that the coordinator can identify a
+/// misbehaving participant when `aggregate()` fails.
+///
+/// This is RFC 9591 Section 5.4's `verify_signature_share`.
+pub fn verify_signature_share(
+    verifying_share: &[u8],
+    group_public_key: &[u8],
+    commitments: &[SigningCommitments],
+    msg: &[u8],
+    share: &SignatureShare,
+) -> Result<(), error::Unspecified> {
+    check_commitment_list(commitments)?;
+    let group_public_key = group_public_key.try_into()?;
+    let _ = decode_element(group_public_key)?;
+    let mut minus_verifying_share = decode_element(verifying_share.try_into()?)?;
+    minus_verifying_share.invert_vartime();
+    let own = commitments
+        .iter()
+        .find(|c| c.identifier == share.identifier)
+        .ok_or(error::Unspecified)?;
+
+    let prefix = binding_factor_input_prefix(group_public_key, commitments, msg);
+    let binding_factor = binding_factor(&prefix, share.identifier);
+    let group_commitment = group_commitment(&prefix, commitments)?;
+    let lambda = lagrange_coefficient(commitments, share.identifier);
+    let challenge = challenge(&group_commitment, group_public_key, msg);
+    let z = Scalar::from_bytes_checked(share.share)?;
+
+    // [z]B == D + [rho]E + [c * lambda]PK, which is
+    // [z]B - [c * lambda]PK - D - [rho]E == 0.
+    let mut minus_hiding = decode_element(&own.hiding)?;
+    minus_hiding.invert_vartime();
+    let mut minus_binding = decode_element(&own.binding)?;
+    minus_binding.invert_vartime();
+    let coeffs = [
+        scalar_mul(&challenge, &lambda),
+        scalar_from_u16(1),
+        binding_factor,
+    ];
+    let points = [minus_verifying_share, minus_hiding, minus_binding];
+    if multi_scalar_mult(&z, &coeffs, &points) != identity() {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// Aggregates the signature shares of the signers into an Ed25519 signature
+/// of `msg` by `group_public_key`.
+///
+/// `shares` must be in the same order as `commitments`, one for each signer.
+/// The signature is verified before it is returned; if that fails then
+/// `verify_signature_share()` identifies the bad shares. This is RFC 9591
+/// Section 5.3's `aggregate`.
+pub fn aggregate(
+    group_public_key: &[u8],
+    commitments: &[SigningCommitments],
+    msg: &[u8],
+    shares: &[SignatureShare],
+) -> Result<signature::Signature, error::Unspecified> {
+    check_commitment_list(commitments)?;
+    if shares.len() != commitments.len()
+        || shares
+            .iter()
+            .zip(commitments.iter())
+            .any(|(s, c)| s.identifier != c.identifier)
+    {
+        return Err(error::Unspecified);
+    }
+    let group_public_key: &EncodedPoint = group_public_key.try_into()?;
+    let _ = decode_element(group_public_key)?;
+
+    let prefix = binding_factor_input_prefix(group_public_key, commitments, msg);
+    let group_commitment = group_commitment(&prefix, commitments)?;
+    let one = scalar_from_u16(1);
+    let mut z = scalar_from_u16(0);
+    for share in shares {
+        z = Scalar::mul_add(&Scalar::from_bytes_checked(share.share)?, &one, &z);
+    }
+
+    let signature = signature::Signature::new(|buf| {
+        let (r, s) = buf.split_at_mut(ELEM_LEN);
+        r.copy_from_slice(&group_commitment);
+        s[..SCALAR_LEN].copy_from_slice(z.as_ref());
+        ELEM_LEN + SCALAR_LEN
+    });
+    signature::VerificationAlgorithm::verify(
+        &super::ed25519::verification::ED25519,
+        untrusted::Input::from(group_public_key),
+        untrusted::Input::from(msg),
+        untrusted::Input::from(signature.as_ref()),
+    )?;
+    Ok(signature)
+}
+
+// RFC 9591 Section 5.3: the list must be sorted by identifier, without
+// duplicates.
+fn check_commitment_list(commitments: &[SigningCommitments]) -> Result<(), error::Unspecified> {
+    if commitments.is_empty()
+        || commitments
+            .windows(2)
+            .any(|w| w[0].identifier >= w[1].identifier)
+    {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+// The part of `rho_input` in RFC 9591 Section 4.4 that is the same for every
+// participant.
+type BindingFactorInputPrefix = [u8; ED25519_PUBLIC_KEY_LEN + 2 * digest::SHA512_OUTPUT_LEN];
+
+fn binding_factor_input_prefix(
+    group_public_key: &EncodedPoint,
+    commitments: &[SigningCommitments],
+    msg: &[u8],
+) -> BindingFactorInputPrefix {
+    // H4.
+    let msg_hash = {
+        let mut ctx = digest::Context::new(&digest::SHA512);
+        ctx.update(CONTEXT_STRING);
+        ctx.update(b"msg");
+        ctx.update(msg);
+        ctx.finish()
+    };
+
+    // H5 of `encode_group_commitment_list`.
+    let encoded_commitment_hash = {
+        let mut ctx = digest::Context::new(&digest::SHA512);
+        ctx.update(CONTEXT_STRING);
+        ctx.update(b"com");
+        for c in commitments {
+            ctx.update(scalar_from_u16(c.identifier).as_ref());
+            ctx.update(&c.hiding);
+            ctx.update(&c.binding);
+        }
+        ctx.finish()
+    };
+
+    let mut prefix = [0; ED25519_PUBLIC_KEY_LEN + 2 * digest::SHA512_OUTPUT_LEN];
+    let (pk, rest) = prefix.split_at_mut(ED25519_PUBLIC_KEY_LEN);
+    let (m, c) = rest.split_at_mut(digest::SHA512_OUTPUT_LEN);
+    pk.copy_from_slice(group_public_key);
+    m.copy_from_slice(msg_hash.as_ref());
+    c.copy_from_slice(encoded_commitment_hash.as_ref());
+    prefix
+}
+
+// H1 of `rho_input`.
+fn binding_factor(prefix: &BindingFactorInputPrefix, identifier: u16) -> Scalar {
+    let mut ctx = digest::Context::new(&digest::SHA512);
+    ctx.update(CONTEXT_STRING);
+    ctx.update(b"rho");
+    ctx.update(prefix);
+    ctx.update(scalar_from_u16(identifier).as_ref());
+    Scalar::from_sha512_digest_reduced(ctx.finish())
+}
+
+// RFC 9591 Section 4.5. Everything here is public, so it doesn't need to run
+// in constant time.
+fn group_commitment(
+    prefix: &BindingFactorInputPrefix,
+    commitments: &[SigningCommitments],
+) -> Result<EncodedPoint, error::Unspecified> {
+    let zero = scalar_from_u16(0);
+    let mut acc = identity();
+    for c in commitments {
+        let coeffs = [
+            scalar_from_u16(1),
+            scalar_from_u16(1),
+            binding_factor(prefix, c.identifier),
+        ];
+        let points = [
+            ExtPoint::from_encoded_point_vartime(&acc)?,
+            decode_element(&c.hiding)?,
+            decode_element(&c.binding)?,
+        ];
+        acc = multi_scalar_mult(&zero, &coeffs, &points);
+    }
+    Ok(acc)
+}
+
+// RFC 9591 Section 4.2's `derive_interpolating_value`, for the identifiers in
+// `commitments`. The identifiers are public, so this doesn't need to run in
+// constant time.
+fn lagrange_coefficient(commitments: &[SigningCommitments], identifier: u16) -> Scalar {
+    let x_i = scalar_from_u16(identifier);
+    let minus_one = {
+        let mut bytes = ORDER_BYTES;
+        bytes[0] -= 1;
+        Scalar::from_bytes_checked(bytes).unwrap()
+    };
+    let mut numerator = scalar_from_u16(1);
+    let mut denominator = scalar_from_u16(1);
+    for c in commitments.iter().filter(|c| c.identifier != identifier) {
+        let x_j = scalar_from_u16(c.identifier);
+        numerator = scalar_mul(&numerator, &x_j);
+        let difference = Scalar::mul_add(&x_i, &minus_one, &x_j);
+        denominator = scalar_mul(&denominator, &difference);
+    }
+    scalar_mul(&numerator, &scalar_invert_vartime(&denominator))
+}
+
+// RFC 9591 Section 4.6, H2 of R || PK || msg.
+fn challenge(
+    group_commitment: &EncodedPoint,
+    group_public_key: &EncodedPoint,
+    msg: &[u8],
+) -> Scalar {
+    let mut ctx = digest::Context::new(&digest::SHA512);
+    ctx.update(group_commitment);
+    ctx.update(group_public_key);
+    ctx.update(msg);
+    Scalar::from_sha512_digest_reduced(ctx.finish())
+}
+
+// RFC 9591 Section 6.5's `DeserializeElement`.
+fn decode_element(encoded: &EncodedPoint) -> Result<ExtPoint, error::Unspecified> {
+    let point = ExtPoint::from_encoded_point_vartime(encoded)?;
+    if point.clone().into_encoded_point() != *encoded || *encoded == identity() {
+        return Err(error::Unspecified);
+    }
+
+    // The point is in the prime-order subgroup iff [n]P == 0, i.e.
+    // [n - 1]P == -P.
+    let mut n_minus_1 = ORDER_BYTES;
+    n_minus_1[0] -= 1;
+    let mut minus_point = point.clone();
+    minus_point.invert_vartime();
+    let n_minus_1 = Scalar::from_bytes_checked(n_minus_1)?;
+    if point.scalar_mul(&n_minus_1).into_encoded_point() != minus_point.into_encoded_point() {
+        return Err(error::Unspecified);
+    }
+    Ok(point)
+}
+
+#[cfg(feature = "alloc")]
+fn random_nonzero_scalar(rng: &dyn rand::SecureRandom) -> Result<Scalar, error::Unspecified> {
+    loop {
+        let random_bytes: [u8; 32] = rand::generate(rng)?.expose();
+        let digest = digest::digest(&digest::SHA512, &random_bytes);
+        let scalar = Scalar::from_sha512_digest_reduced(digest);
+        if scalar.as_ref().iter().any(|&b| b != 0) {
+            return Ok(scalar);
+        }
+    }
+}
+
+// The group order, little-endian.
+const ORDER_BYTES: [u8; SCALAR_LEN] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+fn identity() -> EncodedPoint {
+    let mut identity = [0; ELEM_LEN];
+    identity[0] = 1;
+    identity
+}
+
+fn scalar_from_u16(x: u16) -> Scalar {
+    let mut bytes = [0; SCALAR_LEN];
+    bytes[..2].copy_from_slice(&x.to_le_bytes());
+    Scalar::from_bytes_checked(bytes).unwrap()
+}
+
+fn scalar_mul(a: &Scalar, b: &Scalar) -> Scalar {
+    Scalar::mul_add(a, b, &scalar_from_u16(0))
+}
+
+// Computes `a**(n - 2)`. `a` must not be zero.
+fn scalar_invert_vartime(a: &Scalar) -> Scalar {
+    let mut exponent = ORDER_BYTES;
+    exponent[0] -= 2;
+    let mut r = scalar_from_u16(1);
+    for byte in exponent.iter().rev() {
+        for bit in (0..8).rev() {
+            r = scalar_mul(&r, &r);
+            if (byte >> bit) & 1 == 1 {
+                r = scalar_mul(&r, a);
+            }
+        }
+    }
+    r
+}
+
+fn scalar_mult_base(a: &Scalar) -> EncodedPoint {
+    let mut r = ExtPoint::new_at_infinity();
+    unsafe { x25519_ge_scalarmult_base(&mut r, a) };
+    r.into_encoded_point()
+}
+
+// Computes [b]B + [coeffs[0]]points[0] + [coeffs[1]]points[1] + ...
+fn multi_scalar_mult(b: &Scalar, coeffs: &[Scalar; 3], points: &[ExtPoint; 3]) -> EncodedPoint {
+    let mut tables = [CachedPoint::zero(); 8 * 3];
+    let mut slides = [0i8; 3 * 256];
+    let mut r = Point::new_at_infinity();
+    unsafe {
+        x25519_ge_multi_scalarmult_vartime(
+            &mut r,
+            b,
+            coeffs.as_ptr(),
+            points.as_ptr(),
+            points.len(),
+            tables.as_mut_ptr(),
+            slides.as_mut_ptr(),
+        )
+    };
+    r.into_encoded_point()
+}
+
+prefixed_extern! {
+    fn x25519_ge_scalarmult_base(h: &mut ExtPoint, a: &Scalar);
+}
+
+prefixed_extern! {
+    fn x25519_ge_multi_scalarmult_vartime(
+        r: &mut Point,
+        b_coeff: &Scalar,
+        coeffs: *const Scalar,
+        points: *const ExtPoint,
+        num_points: usize,
+        tables: *mut CachedPoint,
+        slides: *mut i8,
+    );
+}
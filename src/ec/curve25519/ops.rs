@@ -31,6 +31,14 @@ pub struct Elem<E: Encoding> {
     encoding: PhantomData<E>,
 }
 
+impl<E: Encoding> Clone for Elem<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Encoding> Copy for Elem<E> {}
+
 pub trait Encoding {}
 pub struct T;
 impl Encoding for T {}
@@ -64,6 +72,7 @@ pub type EncodedPoint = [u8; ELEM_LEN];
 pub const ELEM_LEN: usize = 32;
 
 // Keep this in sync with `ge_p3` in curve25519/internal.h.
+#[derive(Clone)]
 #[repr(C)]
 pub struct ExtPoint {
     x: Elem<T>,
@@ -96,9 +105,55 @@ impl ExtPoint {
         self.x.negate();
         self.t.negate();
     }
+
+    // The u-coordinate of the Curve25519 point that is birationally
+    // equivalent to this point, u = (1 + y) / (1 - y). Both this point and its
+    // negation have the same u-coordinate.
+    pub fn to_montgomery_u(&self) -> EncodedPoint {
+        let mut u = [0; ELEM_LEN];
+        unsafe { x25519_ge_p3_to_montgomery_u(&mut u, self) };
+        u
+    }
+
+    // Returns `a` times this point, in constant time with respect to `a`.
+    pub fn scalar_mul(&self, a: &Scalar) -> Self {
+        let mut r = Self::new_at_infinity();
+        unsafe { x25519_ge_scalarmult(&mut r, a, self) };
+        r
+    }
+
+    // Returns eight, the cofactor, times this point.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut r = Self::new_at_infinity();
+        unsafe { x25519_ge_mul_by_cofactor(&mut r, self) };
+        r
+    }
 }
 
 // Keep this in sync with `ge_p2` in curve25519/internal.h.
+// Scratch space for `x25519_ge_multi_scalarmult_vartime`. This is called
+// `ge_cached` in the C code, where its elements are `fe_loose`, which has the
+// same representation as `Elem<T>`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct CachedPoint {
+    y_plus_x: Elem<T>,
+    y_minus_x: Elem<T>,
+    z: Elem<T>,
+    t2d: Elem<T>,
+}
+
+impl CachedPoint {
+    pub fn zero() -> Self {
+        Self {
+            y_plus_x: Elem::zero(),
+            y_minus_x: Elem::zero(),
+            z: Elem::zero(),
+            t2d: Elem::zero(),
+        }
+    }
+}
+
 #[repr(C)]
 pub struct Point {
     x: Elem<T>,
@@ -144,6 +199,54 @@ fn encode_point(x: Elem<T>, y: Elem<T>, z: Elem<T>) -> EncodedPoint {
     bytes
 }
 
+// Whether `encoded` is the canonical encoding of one of the eight points of
+// small order.
+pub fn is_small_order(encoded: &EncodedPoint) -> bool {
+    const SMALL_ORDER_POINTS: [EncodedPoint; 8] = [
+        [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        [
+            0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0x7f,
+        ],
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x80,
+        ],
+        [
+            0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef,
+            0x98, 0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88,
+            0x6d, 0x53, 0xfc, 0x05,
+        ],
+        [
+            0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef,
+            0x98, 0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88,
+            0x6d, 0x53, 0xfc, 0x85,
+        ],
+        [
+            0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10,
+            0x67, 0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77,
+            0x92, 0xac, 0x03, 0x7a,
+        ],
+        [
+            0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10,
+            0x67, 0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77,
+            0x92, 0xac, 0x03, 0xfa,
+        ],
+    ];
+    SMALL_ORDER_POINTS.iter().any(|p| p == encoded)
+}
+
 prefixed_extern! {
     fn x25519_fe_invert(out: &mut Elem<T>, z: &Elem<T>);
     fn x25519_fe_isnegative(elem: &Elem<T>) -> u8;
@@ -151,4 +254,7 @@ prefixed_extern! {
     fn x25519_fe_neg(f: &mut Elem<T>);
     fn x25519_fe_tobytes(bytes: &mut EncodedPoint, elem: &Elem<T>);
     fn x25519_ge_frombytes_vartime(h: &mut ExtPoint, s: &EncodedPoint) -> bssl::Result;
+    fn x25519_ge_mul_by_cofactor(r: &mut ExtPoint, a: &ExtPoint);
+    fn x25519_ge_p3_to_montgomery_u(u: &mut EncodedPoint, p: &ExtPoint);
+    fn x25519_ge_scalarmult(r: &mut ExtPoint, a: &Scalar, p: &ExtPoint);
 }
@@ -52,6 +52,27 @@ impl Scalar {
         unsafe { x25519_sc_reduce(&mut unreduced) };
         Self((&unreduced[..SCALAR_LEN]).try_into().unwrap())
     }
+
+    // Returns `a * b + c` reduced modulo n.
+    pub fn mul_add(a: &Self, b: &Self, c: &Self) -> Self {
+        prefixed_extern! {
+            fn x25519_sc_muladd(
+                s: &mut [u8; SCALAR_LEN],
+                a: &Scalar,
+                b: &Scalar,
+                c: &Scalar,
+            );
+        }
+        let mut s = [0u8; SCALAR_LEN];
+        unsafe { x25519_sc_muladd(&mut s, a, b, c) };
+        Self(s)
+    }
+}
+
+impl AsRef<[u8]> for Scalar {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
 }
 
 #[repr(transparent)]
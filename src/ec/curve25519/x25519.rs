@@ -40,6 +40,38 @@ pub static X25519: agreement::Algorithm = agreement::Algorithm {
     ecdh: x25519_ecdh,
 };
 
+/// Converts an Ed25519 public key to the X25519 public key for the same
+/// private key, for protocols that use one identity key both for signing and
+/// for key agreement.
+///
+/// The X25519 private key that corresponds to the result is
+/// `signature::Ed25519KeyPair::x25519_private_key()`.
+///
+/// The Edwards point (x, y) maps to the Montgomery u-coordinate
+/// u = (1 + y) / (1 - y). The sign of x, which is the sign bit of the Ed25519
+/// encoding, is discarded: an Ed25519 public key and its negation convert to
+/// the same X25519 public key. Consequently the conversion can't be reversed
+/// without an external convention for that bit (XEdDSA always takes the sign
+/// bit to be zero), and *ring* doesn't provide the reverse conversion.
+///
+/// Fails if `ed25519_public_key` isn't the encoding of a point on the curve,
+/// or if it encodes one of the points of small order, which would only lead
+/// to an all-zero shared secret.
+pub fn x25519_public_key_from_ed25519(
+    ed25519_public_key: &[u8],
+) -> Result<agreement::PublicKey, error::Unspecified> {
+    let encoded: &ops::EncodedPoint = ed25519_public_key.try_into()?;
+    let point = ops::ExtPoint::from_encoded_point_vartime(encoded)?;
+    if ops::is_small_order(&point.clone().into_encoded_point()) {
+        return Err(error::Unspecified);
+    }
+    let bytes = ec::PublicKey::new(PUBLIC_KEY_LEN, |out| {
+        out.copy_from_slice(&point.to_montgomery_u());
+        Ok(())
+    })?;
+    Ok(agreement::PublicKey::new(&X25519, bytes))
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn x25519_check_private_key_bytes(bytes: &[u8]) -> Result<(), error::Unspecified> {
     debug_assert_eq!(bytes.len(), PRIVATE_KEY_LEN);
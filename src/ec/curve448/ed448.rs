@@ -0,0 +1,39 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Ed448 Signatures.
+
+use super::ops::ELEM_LEN;
+use crate::digest::xof;
+
+pub mod signing;
+pub mod verification;
+
+/// The length of an Ed448 public key.
+pub const ED448_PUBLIC_KEY_LEN: usize = ELEM_LEN;
+
+/// The length of the SHAKE256 output used to derive scalars.
+const DIGEST_LEN: usize = 2 * ELEM_LEN;
+
+// `dom4(0, "")` of RFC 8032 Section 5.2: phflag 0 and an empty context.
+const DOM4: &[u8] = b"SigEd448\x00\x00";
+
+fn eddsa_digest(parts: &[&[u8]]) -> [u8; DIGEST_LEN] {
+    let mut ctx = xof::Context::new(&xof::SHAKE256);
+    ctx.update(DOM4);
+    parts.iter().for_each(|part| ctx.update(part));
+    let mut digest = [0; DIGEST_LEN];
+    ctx.finish().squeeze(&mut digest);
+    digest
+}
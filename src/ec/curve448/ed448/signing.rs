@@ -0,0 +1,238 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Ed448 Signatures.
+
+use super::{
+    super::{ops::*, scalar::*},
+    eddsa_digest, verification, DIGEST_LEN, ED448_PUBLIC_KEY_LEN,
+};
+use crate::{
+    digest::xof,
+    error,
+    io::der,
+    pkcs8, rand,
+    signature::{self, KeyPair as SigningKeyPair},
+};
+use core::convert::TryInto;
+
+/// An Ed448 key pair, for signing.
+pub struct Ed448KeyPair {
+    // RFC 8032 Section 5.2.6 calls this *s*.
+    private_scalar: Scalar,
+
+    // RFC 8032 Section 5.2.6 calls this *prefix*.
+    private_prefix: Prefix,
+
+    // RFC 8032 Section 5.2.5 calls this *A*.
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(Ed448KeyPair, stringify!(Ed448KeyPair), public_key);
+
+impl Ed448KeyPair {
+    /// Generates a new key pair and returns the key pair serialized as a
+    /// PKCS#8 document.
+    ///
+    /// The PKCS#8 document will be a v2 `OneAsymmetricKey` with the public key,
+    /// as described in [RFC 5958 Section 2] and [RFC 8410].
+    ///
+    /// [RFC 5958 Section 2]: https://tools.ietf.org/html/rfc5958#section-2
+    /// [RFC 8410]: https://tools.ietf.org/html/rfc8410
+    pub fn generate_pkcs8(
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let seed: [u8; SEED_LEN] = rand::generate(rng)?.expose();
+        let key_pair = Self::from_seed_(&seed);
+        Ok(pkcs8::wrap_key(
+            &PKCS8_TEMPLATE,
+            &seed[..],
+            key_pair.public_key().as_ref(),
+        ))
+    }
+
+    /// Constructs an Ed448 key pair by parsing an unencrypted PKCS#8 v2
+    /// Ed448 private key.
+    ///
+    /// `openssl genpkey -algorithm ED448` generates PKCS#8 v1 keys, which
+    /// require the use of `Ed448KeyPair::from_pkcs8_maybe_unchecked()`
+    /// instead of `Ed448KeyPair::from_pkcs8()`.
+    ///
+    /// `from_pkcs8()` will verify that the public key and the private key are
+    /// consistent with each other.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, error::KeyRejected> {
+        let (seed, public_key) =
+            unwrap_pkcs8(pkcs8::Version::V2Only, untrusted::Input::from(pkcs8))?;
+        Self::from_seed_and_public_key(
+            seed.as_slice_less_safe(),
+            public_key.unwrap().as_slice_less_safe(),
+        )
+    }
+
+    /// Constructs an Ed448 key pair by parsing an unencrypted PKCS#8 v1 or v2
+    /// Ed448 private key.
+    ///
+    /// It is recommended to use `Ed448KeyPair::from_pkcs8()`, which accepts
+    /// only PKCS#8 v2 files that contain the public key. When a v1 file is
+    /// parsed the public key will be computed from the private key, and there
+    /// will be no consistency check between the public key and the private
+    /// key. PKCS#8 v2 files are parsed exactly like
+    /// `Ed448KeyPair::from_pkcs8()`.
+    pub fn from_pkcs8_maybe_unchecked(pkcs8: &[u8]) -> Result<Self, error::KeyRejected> {
+        let (seed, public_key) =
+            unwrap_pkcs8(pkcs8::Version::V1OrV2, untrusted::Input::from(pkcs8))?;
+        if let Some(public_key) = public_key {
+            Self::from_seed_and_public_key(
+                seed.as_slice_less_safe(),
+                public_key.as_slice_less_safe(),
+            )
+        } else {
+            Self::from_seed_unchecked(seed.as_slice_less_safe())
+        }
+    }
+
+    /// Constructs an Ed448 key pair from the 57-byte private key seed `seed`
+    /// and its public key `public_key`.
+    ///
+    /// It is recommended to use `Ed448KeyPair::from_pkcs8()` instead.
+    ///
+    /// The private and public keys will be verified to be consistent with each
+    /// other, as in `Ed25519KeyPair::from_seed_and_public_key()`.
+    pub fn from_seed_and_public_key(
+        seed: &[u8],
+        public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let pair = Self::from_seed_unchecked(seed)?;
+
+        // This implicitly verifies that `public_key` is the right length.
+        if public_key != pair.public_key.as_ref() {
+            let err = if public_key.len() != pair.public_key.as_ref().len() {
+                error::KeyRejected::invalid_encoding()
+            } else {
+                error::KeyRejected::inconsistent_components()
+            };
+            return Err(err);
+        }
+
+        Ok(pair)
+    }
+
+    /// Constructs an Ed448 key pair from the 57-byte private key seed `seed`.
+    ///
+    /// It is recommended to use `Ed448KeyPair::from_pkcs8()` instead. When
+    /// that is not practical, it is recommended to use
+    /// `Ed448KeyPair::from_seed_and_public_key()` instead.
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<Self, error::KeyRejected> {
+        let seed = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        Ok(Self::from_seed_(seed))
+    }
+
+    fn from_seed_(seed: &Seed) -> Self {
+        let mut h = [0; DIGEST_LEN];
+        let mut ctx = xof::Context::new(&xof::SHAKE256);
+        ctx.update(seed);
+        ctx.finish().squeeze(&mut h);
+        let (private_scalar, private_prefix) = h.split_at(SCALAR_LEN);
+
+        let private_scalar = Scalar::from_bytes_clamped(private_scalar.try_into().unwrap());
+        let a = Point::BASE.mul(private_scalar.as_ref());
+
+        Self {
+            private_scalar,
+            private_prefix: private_prefix.try_into().unwrap(),
+            public_key: PublicKey(a.into_encoded_point()),
+        }
+    }
+
+    /// Returns the signature of the message `msg`.
+    pub fn sign(&self, msg: &[u8]) -> signature::Signature {
+        signature::Signature::new(|signature_bytes| {
+            let (signature_bytes, _unused) = signature_bytes.split_at_mut(SIGNATURE_LEN);
+            let (signature_r, signature_s) = signature_bytes.split_at_mut(ELEM_LEN);
+            let nonce = eddsa_digest(&[&self.private_prefix, msg]);
+            let nonce = Scalar::from_bytes_reduced(&nonce);
+
+            let r = Point::BASE.mul(nonce.as_ref());
+            signature_r.copy_from_slice(&r.into_encoded_point());
+            let hram_digest = eddsa_digest(&[signature_r, self.public_key.as_ref(), msg]);
+            let hram = Scalar::from_bytes_reduced(&hram_digest);
+            let s = Scalar::mul_add(&hram, &self.private_scalar, &nonce);
+            signature_s.copy_from_slice(s.as_ref());
+
+            SIGNATURE_LEN
+        })
+    }
+}
+
+impl signature::KeyPair for Ed448KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+impl signature::KeyPairGenerator for verification::Ed448Parameters {
+    type KeyPair = Ed448KeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = Ed448KeyPair::generate_pkcs8(rng)?;
+        let key_pair = Ed448KeyPair::from_pkcs8(pkcs8.as_ref())?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PublicKey([u8; ED448_PUBLIC_KEY_LEN]);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+fn unwrap_pkcs8(
+    version: pkcs8::Version,
+    input: untrusted::Input,
+) -> Result<(untrusted::Input, Option<untrusted::Input>), error::KeyRejected> {
+    let (private_key, public_key) = pkcs8::unwrap_key(&PKCS8_TEMPLATE, version, input)?;
+    let private_key = private_key
+        .read_all(error::Unspecified, |input| {
+            der::expect_tag_and_get_value(input, der::Tag::OctetString)
+        })
+        .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+    Ok((private_key, public_key))
+}
+
+type Prefix = [u8; PREFIX_LEN];
+const PREFIX_LEN: usize = DIGEST_LEN - SCALAR_LEN;
+
+pub(crate) const SIGNATURE_LEN: usize = ELEM_LEN + SCALAR_LEN;
+
+type Seed = [u8; SEED_LEN];
+const SEED_LEN: usize = 57;
+
+static PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ed448_pkcs8_v2_template.der"),
+    alg_id_range: core::ops::Range { start: 8, end: 13 },
+    curve_id_index: 0,
+    private_key_index: 0x11,
+};
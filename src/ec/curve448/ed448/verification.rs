@@ -0,0 +1,76 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Ed448 Signatures.
+
+use super::{
+    super::{ops::*, scalar::*},
+    eddsa_digest,
+};
+use crate::{error, sealed, signature};
+use core::convert::TryInto;
+
+/// Parameters for Ed448 verification.
+pub struct Ed448Parameters;
+
+impl core::fmt::Debug for Ed448Parameters {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "ring::signature::ED448")
+    }
+}
+
+/// Verification of [Ed448] signatures.
+///
+/// Ed448 uses SHAKE256 as the digest algorithm.
+///
+/// [Ed448]: https://tools.ietf.org/html/rfc8032#section-5.2
+pub static ED448: Ed448Parameters = Ed448Parameters {};
+
+impl signature::VerificationAlgorithm for Ed448Parameters {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let public_key: &EncodedPoint = public_key.as_slice_less_safe().try_into()?;
+        let (signature_r, signature_s) = signature.read_all(error::Unspecified, |input| {
+            let signature_r: &EncodedPoint = input
+                .read_bytes(ELEM_LEN)?
+                .as_slice_less_safe()
+                .try_into()?;
+            let signature_s: &[u8; SCALAR_LEN] = input
+                .read_bytes(SCALAR_LEN)?
+                .as_slice_less_safe()
+                .try_into()?;
+            Ok((signature_r, signature_s))
+        })?;
+
+        let signature_s = Scalar::from_bytes_checked(*signature_s)?;
+
+        let a = Point::from_encoded_point_vartime(public_key)?.neg();
+
+        let h_digest = eddsa_digest(&[signature_r, public_key, msg.as_slice_less_safe()]);
+        let h = Scalar::from_bytes_reduced(&h_digest);
+
+        let r = Point::BASE.double_mul_vartime(signature_s.as_ref(), &a, h.as_ref());
+        let r_check = r.into_encoded_point();
+        if *signature_r != r_check {
+            return Err(error::Unspecified);
+        }
+        Ok(())
+    }
+}
+
+impl sealed::Sealed for Ed448Parameters {}
@@ -0,0 +1,448 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic in GF(2**448 - 2**224 - 1) and on edwards448, the untwisted
+//! Edwards curve that Ed448 uses.
+//!
+//! Everything that may involve secret values runs in constant time: there
+//! are no secret-dependent branches or table indices.
+
+use crate::error;
+use core::convert::TryInto;
+
+pub type EncodedPoint = [u8; ELEM_LEN];
+
+/// The length of an encoded point: 56 bytes for *y* and one more byte that
+/// holds only the sign of *x*.
+pub const ELEM_LEN: usize = 57;
+
+const FIELD_LEN: usize = 56;
+
+const LIMB_BITS: usize = 56;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+const LIMB_BYTES: usize = LIMB_BITS / 8;
+const NUM_LIMBS: usize = 8;
+
+type Limbs = [u64; NUM_LIMBS];
+
+/// An element of the field, as eight 56-bit limbs. Between operations a limb
+/// may be a few bits wider than 56 bits and the value needn't be fully
+/// reduced; `to_bytes` produces the canonical encoding.
+#[derive(Clone, Copy)]
+pub struct Elem(Limbs);
+
+const P: Limbs = [
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK - 1,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+];
+
+const TWO_P: Limbs = [
+    2 * P[0],
+    2 * P[1],
+    2 * P[2],
+    2 * P[3],
+    2 * P[4],
+    2 * P[5],
+    2 * P[6],
+    2 * P[7],
+];
+
+// -39081 mod p.
+const D: Elem = Elem([
+    0xffffffffff6756,
+    0xffffffffffffff,
+    0xffffffffffffff,
+    0xffffffffffffff,
+    0xfffffffffffffe,
+    0xffffffffffffff,
+    0xffffffffffffff,
+    0xffffffffffffff,
+]);
+
+impl Elem {
+    const ZERO: Self = Self([0; NUM_LIMBS]);
+    const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Decodes the little-endian encoding `bytes`, which must be canonical.
+    fn from_bytes_checked(bytes: &[u8; FIELD_LEN]) -> Result<Self, error::Unspecified> {
+        let mut limbs = [0; NUM_LIMBS];
+        limbs
+            .iter_mut()
+            .zip(bytes.chunks(LIMB_BYTES))
+            .for_each(|(limb, chunk)| {
+                *limb = chunk
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, &byte| (acc << 8) | u64::from(byte));
+            });
+        let r = Self(limbs);
+        if r.to_bytes() != *bytes {
+            return Err(error::Unspecified);
+        }
+        Ok(r)
+    }
+
+    fn to_bytes(self) -> [u8; FIELD_LEN] {
+        // Two carry passes leave every limb below 2**56, so the value is
+        // below 2**448 < 2p and one conditional subtraction of p finishes.
+        let mut a = self.0;
+        for _ in 0..2 {
+            a = carry(a);
+        }
+
+        let mut a_minus_p = [0; NUM_LIMBS];
+        let mut borrow = 0;
+        for i in 0..NUM_LIMBS {
+            let d = a[i].wrapping_sub(P[i]).wrapping_sub(borrow);
+            a_minus_p[i] = d & LIMB_MASK;
+            borrow = d >> 63;
+        }
+        // All ones if `a >= p`.
+        let mask = borrow.wrapping_sub(1);
+
+        let mut bytes = [0; FIELD_LEN];
+        bytes
+            .chunks_mut(LIMB_BYTES)
+            .zip(a.iter().zip(a_minus_p.iter()))
+            .for_each(|(chunk, (a, a_minus_p))| {
+                let limb = (a_minus_p & mask) | (a & !mask);
+                chunk
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(i, byte)| *byte = (limb >> (8 * i)) as u8);
+            });
+        bytes
+    }
+
+    fn is_zero(&self) -> bool {
+        self.to_bytes().iter().all(|&b| b == 0)
+    }
+
+    fn is_odd(&self) -> u8 {
+        self.to_bytes()[0] & 1
+    }
+
+    fn add(&self, b: &Self) -> Self {
+        let mut r = self.0;
+        r.iter_mut().zip(b.0.iter()).for_each(|(r, b)| *r += b);
+        Self(carry(r))
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        let mut r = self.0;
+        r.iter_mut()
+            .zip(b.0.iter().zip(TWO_P.iter()))
+            .for_each(|(r, (b, two_p))| *r = *r + two_p - b);
+        Self(carry(r))
+    }
+
+    fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        let (a, b) = (&self.0, &b.0);
+        let mut c = [0u128; 2 * NUM_LIMBS - 1];
+        for i in 0..NUM_LIMBS {
+            for j in 0..NUM_LIMBS {
+                c[i + j] += u128::from(a[i]) * u128::from(b[j]);
+            }
+        }
+
+        // 2**448 == 2**224 + 1 (mod p). Fold the high limbs from the top down
+        // so that the limbs they fold into are themselves folded afterwards.
+        for k in (NUM_LIMBS..c.len()).rev() {
+            let hi = c[k];
+            c[k - NUM_LIMBS] += hi;
+            c[k - NUM_LIMBS / 2] += hi;
+        }
+
+        let mut c = [c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]];
+        for _ in 0..2 {
+            for i in 0..(NUM_LIMBS - 1) {
+                c[i + 1] += c[i] >> LIMB_BITS;
+                c[i] &= u128::from(LIMB_MASK);
+            }
+            let top = c[NUM_LIMBS - 1] >> LIMB_BITS;
+            c[NUM_LIMBS - 1] &= u128::from(LIMB_MASK);
+            c[0] += top;
+            c[NUM_LIMBS / 2] += top;
+        }
+
+        let mut r = [0; NUM_LIMBS];
+        r.iter_mut().zip(c.iter()).for_each(|(r, c)| *r = *c as u64);
+        Self(r)
+    }
+
+    fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    fn square_times(&self, n: usize) -> Self {
+        let mut r = *self;
+        for _ in 0..n {
+            r = r.square();
+        }
+        r
+    }
+
+    /// Returns `self**((p - 3) / 4)`, i.e. `self**(2**446 - 2**222 - 1)`.
+    fn pow_p_minus_3_over_4(&self) -> Self {
+        // x_n = self**(2**n - 1).
+        let x1 = *self;
+        let x2 = x1.square().mul(&x1);
+        let x3 = x2.square().mul(&x1);
+        let x6 = x3.square_times(3).mul(&x3);
+        let x12 = x6.square_times(6).mul(&x6);
+        let x24 = x12.square_times(12).mul(&x12);
+        let x30 = x24.square_times(6).mul(&x6);
+        let x48 = x24.square_times(24).mul(&x24);
+        let x96 = x48.square_times(48).mul(&x48);
+        let x192 = x96.square_times(96).mul(&x96);
+        let x222 = x192.square_times(30).mul(&x30);
+        let x223 = x222.square().mul(&x1);
+        // (2**223 - 1) * 2**223 + (2**222 - 1) = 2**446 - 2**222 - 1.
+        x223.square_times(223).mul(&x222)
+    }
+
+    fn invert(&self) -> Self {
+        // self**(p - 2), where p - 2 = 4 * ((p - 3) / 4) + 1.
+        self.pow_p_minus_3_over_4().square_times(2).mul(self)
+    }
+
+    /// Returns `a` if `mask` is all ones and `b` if it is zero.
+    fn select(mask: u64, a: &Self, b: &Self) -> Self {
+        let mut r = [0; NUM_LIMBS];
+        r.iter_mut()
+            .zip(a.0.iter().zip(b.0.iter()))
+            .for_each(|(r, (a, b))| *r = (a & mask) | (b & !mask));
+        Self(r)
+    }
+}
+
+// Propagates carries so that every limb is below 2**56, except that limbs 0
+// and 4 may exceed it slightly because of the fold of the top carry.
+fn carry(mut a: Limbs) -> Limbs {
+    for i in 0..(NUM_LIMBS - 1) {
+        a[i + 1] += a[i] >> LIMB_BITS;
+        a[i] &= LIMB_MASK;
+    }
+    let top = a[NUM_LIMBS - 1] >> LIMB_BITS;
+    a[NUM_LIMBS - 1] &= LIMB_MASK;
+    a[0] += top;
+    a[NUM_LIMBS / 2] += top;
+    a
+}
+
+/// A point in projective coordinates (X : Y : Z), where x = X/Z and y = Y/Z.
+#[derive(Clone, Copy)]
+pub struct Point {
+    x: Elem,
+    y: Elem,
+    z: Elem,
+}
+
+impl Point {
+    pub const BASE: Self = Self {
+        x: Elem([
+            0x26a82bc70cc05e,
+            0x80e18b00938e26,
+            0xf72ab66511433b,
+            0xa3d3a46412ae1a,
+            0x0f1767ea6de324,
+            0x36da9e14657047,
+            0xed221d15a622bf,
+            0x4f1970c66bed0d,
+        ]),
+        y: Elem([
+            0x08795bf230fa14,
+            0x132c4ed7c8ad98,
+            0x1ce67c39c4fdbd,
+            0x05a0c2d73ad3ff,
+            0xa3984087789c1e,
+            0xc7624bea73736c,
+            0x248876203756c9,
+            0x693f46716eb6bc,
+        ]),
+        z: Elem::ONE,
+    };
+
+    const IDENTITY: Self = Self {
+        x: Elem::ZERO,
+        y: Elem::ONE,
+        z: Elem::ONE,
+    };
+
+    /// Decodes a point as described in RFC 8032 Section 5.2.3, rejecting
+    /// non-canonical encodings.
+    pub fn from_encoded_point_vartime(encoded: &EncodedPoint) -> Result<Self, error::Unspecified> {
+        let (y, last) = encoded.split_at(FIELD_LEN);
+        let x_0 = last[0] >> 7;
+        if last[0] & 0x7f != 0 {
+            return Err(error::Unspecified);
+        }
+        let y = Elem::from_bytes_checked(y.try_into()?)?;
+
+        // x**2 = (y**2 - 1) / (d*y**2 - 1) = u / v.
+        let y2 = y.square();
+        let u = y2.sub(&Elem::ONE);
+        let v = D.mul(&y2).sub(&Elem::ONE);
+
+        // x = u**3 * v * (u**5 * v**3)**((p - 3) / 4).
+        let u2 = u.square();
+        let u3 = u2.mul(&u);
+        let u5 = u3.mul(&u2);
+        let v3 = v.square().mul(&v);
+        let mut x = u3.mul(&v).mul(&u5.mul(&v3).pow_p_minus_3_over_4());
+
+        if !v.mul(&x.square()).sub(&u).is_zero() {
+            return Err(error::Unspecified);
+        }
+        if x.is_odd() != x_0 {
+            if x.is_zero() {
+                return Err(error::Unspecified);
+            }
+            x = x.neg();
+        }
+
+        Ok(Self { x, y, z: Elem::ONE })
+    }
+
+    pub fn into_encoded_point(self) -> EncodedPoint {
+        let z_inv = self.z.invert();
+        let x = self.x.mul(&z_inv);
+        let y = self.y.mul(&z_inv);
+
+        let mut encoded = [0; ELEM_LEN];
+        encoded[..FIELD_LEN].copy_from_slice(&y.to_bytes());
+        encoded[FIELD_LEN] = x.is_odd() << 7;
+        encoded
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    // The complete addition formula of RFC 8032 Section 5.2.4.
+    fn add(&self, b: &Self) -> Self {
+        let a = self.z.mul(&b.z);
+        let bb = a.square();
+        let c = self.x.mul(&b.x);
+        let d = self.y.mul(&b.y);
+        let e = D.mul(&c).mul(&d);
+        let f = bb.sub(&e);
+        let g = bb.add(&e);
+        let h = self.x.add(&self.y).mul(&b.x.add(&b.y));
+        Self {
+            x: a.mul(&f).mul(&h.sub(&c).sub(&d)),
+            y: a.mul(&g).mul(&d.sub(&c)),
+            z: f.mul(&g),
+        }
+    }
+
+    // The doubling formula of RFC 8032 Section 5.2.4.
+    fn double(&self) -> Self {
+        let b = self.x.add(&self.y).square();
+        let c = self.x.square();
+        let d = self.y.square();
+        let e = c.add(&d);
+        let h = self.z.square();
+        let j = e.sub(&h).sub(&h);
+        Self {
+            x: b.sub(&e).mul(&j),
+            y: e.mul(&c.sub(&d)),
+            z: e.mul(&j),
+        }
+    }
+
+    fn select(mask: u64, a: &Self, b: &Self) -> Self {
+        Self {
+            x: Elem::select(mask, &a.x, &b.x),
+            y: Elem::select(mask, &a.y, &b.y),
+            z: Elem::select(mask, &a.z, &b.z),
+        }
+    }
+
+    // table[i] = [i]self.
+    fn table(&self) -> [Self; 16] {
+        let mut table = [Self::IDENTITY; 16];
+        for i in 1..table.len() {
+            table[i] = table[i - 1].add(self);
+        }
+        table
+    }
+
+    /// Returns `[scalar]self`, where `scalar` is little-endian, in constant
+    /// time.
+    pub fn mul(&self, scalar: &[u8]) -> Self {
+        let table = self.table();
+        let mut r = Self::IDENTITY;
+        for_each_nibble(scalar, |nibble| {
+            for _ in 0..4 {
+                r = r.double();
+            }
+            let mut entry = Self::IDENTITY;
+            for (i, candidate) in table.iter().enumerate() {
+                // All ones if `i == nibble`.
+                let mask = ((i as u64 ^ u64::from(nibble)).wrapping_sub(1) as i64 >> 63) as u64;
+                entry = Self::select(mask, candidate, &entry);
+            }
+            r = r.add(&entry);
+        });
+        r
+    }
+
+    /// Returns `[a]self + [b]p`, where `a` and `b` are little-endian and of
+    /// the same length. This is not constant time.
+    pub fn double_mul_vartime(&self, a: &[u8], p: &Self, b: &[u8]) -> Self {
+        let self_table = self.table();
+        let p_table = p.table();
+        let mut b_nibbles = [0u8; 2 * ELEM_LEN];
+        let mut n = 0;
+        for_each_nibble(b, |nibble| {
+            b_nibbles[n] = nibble;
+            n += 1;
+        });
+        let mut r = Self::IDENTITY;
+        let mut n = 0;
+        for_each_nibble(a, |nibble| {
+            for _ in 0..4 {
+                r = r.double();
+            }
+            r = r.add(&self_table[usize::from(nibble)]);
+            r = r.add(&p_table[usize::from(b_nibbles[n])]);
+            n += 1;
+        });
+        r
+    }
+}
+
+// Calls `f` with each 4-bit digit of the little-endian `bytes`, from the most
+// significant to the least significant.
+fn for_each_nibble(bytes: &[u8], mut f: impl FnMut(u8)) {
+    bytes.iter().rev().for_each(|&byte| {
+        f(byte >> 4);
+        f(byte & 0xf);
+    })
+}
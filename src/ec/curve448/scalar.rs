@@ -0,0 +1,162 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic modulo the order L of the edwards448 base point.
+
+use crate::error;
+
+/// The length of an encoded scalar. Scalars reduced modulo L fit in 446 bits;
+/// the extra byte is part of the encoding that RFC 8032 specifies.
+pub const SCALAR_LEN: usize = 57;
+
+const NUM_LIMBS: usize = 7;
+
+// L = 2**446 - 13818066809895115352007386748515426880336692474882178609894547503885.
+const L: [u64; NUM_LIMBS] = [
+    0x2378c292ab5844f3,
+    0x216cc2728dc58f55,
+    0xc44edb49aed63690,
+    0xffffffff7cca23e9,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+];
+
+/// A little-endian scalar. Except for a clamped private scalar, it is reduced
+/// modulo L.
+#[derive(Clone, Copy)]
+pub struct Scalar([u8; SCALAR_LEN]);
+
+impl Scalar {
+    /// Parses the S component of a signature, which must be reduced.
+    pub fn from_bytes_checked(bytes: [u8; SCALAR_LEN]) -> Result<Self, error::Unspecified> {
+        let limbs = to_limbs(&bytes).ok_or(error::Unspecified)?;
+        // `limbs < L`, comparing from the most significant limb. S is public.
+        for (s, l) in limbs.iter().zip(L.iter()).rev() {
+            if s != l {
+                return if s < l {
+                    Ok(Self(bytes))
+                } else {
+                    Err(error::Unspecified)
+                };
+            }
+        }
+        Err(error::Unspecified)
+    }
+
+    /// Clamps the first half of the digest of the private key seed as
+    /// described in RFC 8032 Section 5.2.5.
+    pub fn from_bytes_clamped(mut bytes: [u8; SCALAR_LEN]) -> Self {
+        bytes[0] &= 0xfc;
+        bytes[SCALAR_LEN - 2] |= 0x80;
+        bytes[SCALAR_LEN - 1] = 0;
+        Self(bytes)
+    }
+
+    /// Reduces the little-endian `bytes`, a SHAKE256 output, modulo L in
+    /// constant time.
+    pub fn from_bytes_reduced(bytes: &[u8]) -> Self {
+        // Shift in one bit at a time, keeping `r < L`. `2 * r + 1 < 2**447`
+        // always fits.
+        let mut r = [0u64; NUM_LIMBS];
+        bytes.iter().rev().for_each(|&byte| {
+            for bit in (0..8).rev() {
+                let mut carry = u64::from(byte >> bit) & 1;
+                r.iter_mut().for_each(|limb| {
+                    let next = *limb >> 63;
+                    *limb = (*limb << 1) | carry;
+                    carry = next;
+                });
+
+                let mut r_minus_l = [0u64; NUM_LIMBS];
+                let mut borrow = 0;
+                for i in 0..NUM_LIMBS {
+                    let (d, b1) = r[i].overflowing_sub(L[i]);
+                    let (d, b2) = d.overflowing_sub(borrow);
+                    r_minus_l[i] = d;
+                    borrow = u64::from(b1 | b2);
+                }
+                // All ones if `r >= L`.
+                let mask = borrow.wrapping_sub(1);
+                r.iter_mut()
+                    .zip(r_minus_l.iter())
+                    .for_each(|(r, d)| *r = (d & mask) | (*r & !mask));
+            }
+        });
+
+        let mut bytes = [0; SCALAR_LEN];
+        bytes
+            .chunks_mut(8)
+            .zip(r.iter())
+            .for_each(|(chunk, limb)| chunk.copy_from_slice(&limb.to_le_bytes()));
+        Self(bytes)
+    }
+
+    /// Returns `(a * b + c) mod L`.
+    pub fn mul_add(a: &Self, b: &Self, c: &Self) -> Self {
+        // The top byte of each input is zero; see the constructors.
+        let a = to_limbs(&a.0).unwrap();
+        let b = to_limbs(&b.0).unwrap();
+        let c = to_limbs(&c.0).unwrap();
+
+        let mut r = [0u64; 2 * NUM_LIMBS];
+        for i in 0..NUM_LIMBS {
+            let mut carry = 0u128;
+            for j in 0..NUM_LIMBS {
+                let t = u128::from(r[i + j]) + u128::from(a[i]) * u128::from(b[j]) + carry;
+                r[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            r[i + NUM_LIMBS] = carry as u64;
+        }
+
+        // `a * b + c < 2**448 * L + L`, so this doesn't overflow.
+        let mut carry = 0u128;
+        for (i, r) in r.iter_mut().enumerate() {
+            let t = u128::from(*r) + u128::from(c.get(i).copied().unwrap_or(0)) + carry;
+            *r = t as u64;
+            carry = t >> 64;
+        }
+
+        let mut bytes = [0u8; 2 * NUM_LIMBS * 8];
+        bytes
+            .chunks_mut(8)
+            .zip(r.iter())
+            .for_each(|(chunk, limb)| chunk.copy_from_slice(&limb.to_le_bytes()));
+        Self::from_bytes_reduced(&bytes)
+    }
+}
+
+impl AsRef<[u8]> for Scalar {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// Returns `None` if the value doesn't fit in 448 bits.
+fn to_limbs(bytes: &[u8; SCALAR_LEN]) -> Option<[u64; NUM_LIMBS]> {
+    if bytes[SCALAR_LEN - 1] != 0 {
+        return None;
+    }
+    let mut limbs = [0; NUM_LIMBS];
+    limbs
+        .iter_mut()
+        .zip(bytes.chunks_exact(8))
+        .for_each(|(limb, chunk)| {
+            let mut le = [0; 8];
+            le.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(le);
+        });
+    Some(limbs)
+}
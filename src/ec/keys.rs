@@ -65,12 +65,9 @@ impl Seed {
     }
 
     pub fn compute_public_key(&self) -> Result<PublicKey, error::Unspecified> {
-        let mut public_key = PublicKey {
-            bytes: [0u8; PUBLIC_KEY_MAX_LEN],
-            len: self.curve.public_key_len,
-        };
-        (self.curve.public_from_private)(&mut public_key.bytes[..public_key.len], self)?;
-        Ok(public_key)
+        PublicKey::new(self.curve.public_key_len, |public_out| {
+            (self.curve.public_from_private)(public_out, self)
+        })
     }
 }
 
@@ -80,6 +77,21 @@ pub struct PublicKey {
     len: usize,
 }
 
+impl PublicKey {
+    // Panics if `len` is larger than `PUBLIC_KEY_MAX_LEN`.
+    pub(crate) fn new(
+        len: usize,
+        fill: impl FnOnce(&mut [u8]) -> Result<(), error::Unspecified>,
+    ) -> Result<Self, error::Unspecified> {
+        let mut r = Self {
+            bytes: [0u8; PUBLIC_KEY_MAX_LEN],
+            len,
+        };
+        fill(&mut r.bytes[..len])?;
+        Ok(r)
+    }
+}
+
 impl AsRef<[u8]> for PublicKey {
     fn as_ref(&self) -> &[u8] {
         &self.bytes[..self.len]
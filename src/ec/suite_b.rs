@@ -215,11 +215,7 @@ pub(crate) fn key_pair_from_bytes(
     public_key_bytes: untrusted::Input,
     cpu_features: cpu::Features,
 ) -> Result<ec::KeyPair, error::KeyRejected> {
-    let seed = ec::Seed::from_bytes(curve, private_key_bytes, cpu_features)
-        .map_err(|error::Unspecified| error::KeyRejected::invalid_component())?;
-
-    let r = ec::KeyPair::derive(seed)
-        .map_err(|error::Unspecified| error::KeyRejected::unexpected_error())?;
+    let r = key_pair_from_private_key_bytes(curve, private_key_bytes, cpu_features)?;
     if public_key_bytes.as_slice_less_safe() != r.public_key().as_ref() {
         return Err(error::KeyRejected::inconsistent_components());
     }
@@ -227,9 +223,24 @@ pub(crate) fn key_pair_from_bytes(
     Ok(r)
 }
 
+pub(crate) fn key_pair_from_private_key_bytes(
+    curve: &'static ec::Curve,
+    private_key_bytes: untrusted::Input,
+    cpu_features: cpu::Features,
+) -> Result<ec::KeyPair, error::KeyRejected> {
+    let seed = ec::Seed::from_bytes(curve, private_key_bytes, cpu_features)
+        .map_err(|error::Unspecified| error::KeyRejected::invalid_component())?;
+
+    ec::KeyPair::derive(seed).map_err(|error::Unspecified| error::KeyRejected::unexpected_error())
+}
+
 pub mod curve;
 pub mod ecdh;
 pub mod ecdsa;
+#[cfg(feature = "alloc")]
+pub mod musig2;
+pub mod schnorr;
+pub mod sm2;
 
 mod ops;
 
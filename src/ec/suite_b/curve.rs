@@ -33,8 +33,8 @@ macro_rules! suite_b_curve {
         /// [Suite B Implementer's Guide to NIST SP 800-56A]:
         ///     https://github.com/briansmith/ring/blob/main/doc/ecdh.pdf
         pub static $NAME: ec::Curve = ec::Curve {
-            public_key_len: 1 + (2 * (($bits + 7) / 8)),
-            elem_scalar_seed_len: ($bits + 7) / 8,
+            public_key_len: 1 + (2 * usize::div_ceil($bits, 8)),
+            elem_scalar_seed_len: usize::div_ceil($bits, 8),
             id: $id,
             check_private_key_bytes: $check_private_key_bytes,
             generate_private_key: $generate_private_key,
@@ -42,7 +42,7 @@ macro_rules! suite_b_curve {
         };
 
         fn $check_private_key_bytes(bytes: &[u8]) -> Result<(), error::Unspecified> {
-            debug_assert_eq!(bytes.len(), $bits / 8);
+            debug_assert_eq!(bytes.len(), usize::div_ceil($bits, 8));
             ec::suite_b::private_key::check_scalar_big_endian_bytes($private_key_ops, bytes)
         }
 
@@ -81,3 +81,33 @@ suite_b_curve!(
     p384_generate_private_key,
     p384_public_from_private
 );
+
+suite_b_curve!(
+    P521,
+    521,
+    &ec::suite_b::ops::p521::PRIVATE_KEY_OPS,
+    ec::CurveID::P521,
+    p521_check_private_key_bytes,
+    p521_generate_private_key,
+    p521_public_from_private
+);
+
+suite_b_curve!(
+    SECP256K1,
+    256,
+    &ec::suite_b::ops::secp256k1::PRIVATE_KEY_OPS,
+    ec::CurveID::Secp256k1,
+    secp256k1_check_private_key_bytes,
+    secp256k1_generate_private_key,
+    secp256k1_public_from_private
+);
+
+suite_b_curve!(
+    SM2,
+    256,
+    &ec::suite_b::ops::sm2::PRIVATE_KEY_OPS,
+    ec::CurveID::Sm2,
+    sm2_check_private_key_bytes,
+    sm2_generate_private_key,
+    sm2_public_from_private
+);
@@ -1,3 +1,6 @@
-mod digest_scalar;
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub(super) mod digest_scalar;
+pub mod recovery;
 pub mod signing;
 pub mod verification;
@@ -0,0 +1,388 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Batch verification of ECDSA signatures.
+
+use super::{digest_scalar::digest_scalar, verification::EcdsaVerificationAlgorithm};
+use crate::{
+    arithmetic::montgomery::*,
+    digest,
+    ec::{
+        curve25519::ed25519::verification::BatchVerificationError,
+        suite_b::{ops::*, public_key::parse_uncompressed_point},
+    },
+    error,
+    limb::{self, LimbMask},
+    rand, signature,
+};
+use alloc::vec::Vec;
+
+/// Verifies a batch of ECDSA signatures, each given as a
+/// `(public_key, message, signature)` triple, using `alg`.
+///
+/// The signatures are checked in groups, each with a single random linear
+/// combination of the verification equations of its signatures. `rng`
+/// provides the coefficients of the combinations. Within a group, the
+/// inversions of *s* are shared and the multiplications of the base point,
+/// and of each distinct public key, are combined into one; this is most
+/// effective when many signatures share a public key, as in certificate
+/// transparency. If the combined check of a group fails, or if `rng` fails,
+/// the signatures in that group are verified individually so that the
+/// invalid ones can be identified.
+///
+/// The result is the same as verifying each signature with
+/// `UnparsedPublicKey::verify`, except with negligible probability. For
+/// curves other than P-256, P-384, and secp256k1 the signatures are verified
+/// individually.
+///
+/// On failure, the error lists the indices of the invalid signatures.
+pub fn ecdsa_verify_batch(
+    alg: &'static EcdsaVerificationAlgorithm,
+    batch: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), BatchVerificationError> {
+    let mut invalid_indices = Vec::new();
+    for (chunk_index, chunk) in batch.chunks(CHUNK_LEN).enumerate() {
+        if verify_combined(alg, chunk, rng).is_ok() {
+            continue;
+        }
+        let first = chunk_index * CHUNK_LEN;
+        invalid_indices.extend(chunk.iter().enumerate().filter_map(
+            |(i, &(public_key, msg, signature))| {
+                signature::VerificationAlgorithm::verify(
+                    alg,
+                    untrusted::Input::from(public_key),
+                    untrusted::Input::from(msg),
+                    untrusted::Input::from(signature),
+                )
+                .err()
+                .map(|_| first + i)
+            },
+        ));
+    }
+    if invalid_indices.is_empty() {
+        return Ok(());
+    }
+    Err(BatchVerificationError { invalid_indices })
+}
+
+// The number of signatures that are checked together. A signature only
+// determines the point R up to its sign, so the combined check tries all
+// 2**(CHUNK_LEN - 1) combinations of signs, up to the sign of the sum.
+const CHUNK_LEN: usize = 8;
+
+const Z_LEN: usize = 128 / 8;
+
+struct Curve {
+    ops: &'static PublicScalarOps,
+    private_scalar_ops: &'static PrivateScalarOps,
+    elem_sqrt: fn(a: &Elem<R>) -> Elem<R>,
+}
+
+static CURVES: [Curve; 3] = [
+    Curve {
+        ops: &p256::PUBLIC_SCALAR_OPS,
+        private_scalar_ops: &p256::PRIVATE_SCALAR_OPS,
+        elem_sqrt: p256::elem_sqrt,
+    },
+    Curve {
+        ops: &p384::PUBLIC_SCALAR_OPS,
+        private_scalar_ops: &p384::PRIVATE_SCALAR_OPS,
+        elem_sqrt: p384::elem_sqrt,
+    },
+    Curve {
+        ops: &secp256k1::PUBLIC_SCALAR_OPS,
+        private_scalar_ops: &secp256k1::PRIVATE_SCALAR_OPS,
+        elem_sqrt: secp256k1::elem_sqrt,
+    },
+];
+
+// Checks that [sum(z_i * u1_i)]G + sum([z_i * u2_i]Q_i) == sum(±[z_i]R_i)
+// for random 128-bit z_i and some choice of signs, where R_i is either of
+// the points with x coordinate r_i.
+fn verify_combined(
+    alg: &EcdsaVerificationAlgorithm,
+    batch: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), error::Unspecified> {
+    let ops = alg.ops;
+    let curve = CURVES
+        .iter()
+        .find(|curve| core::ptr::eq(curve.ops, ops))
+        .ok_or(error::Unspecified)?;
+    let scalar_ops = ops.scalar_ops;
+    let cops = ops.public_key_ops.common;
+    let rr = &curve.private_scalar_ops.oneRR_mod_n;
+
+    let mut z_bytes = [0u8; CHUNK_LEN * Z_LEN];
+    let z_bytes = &mut z_bytes[..(batch.len() * Z_LEN)];
+    rng.fill(z_bytes)?;
+
+    // The distinct public keys, each with the sum of its coefficients z * u2.
+    struct PublicKey<'a> {
+        bytes: &'a [u8],
+        point: (Elem<R>, Elem<R>),
+        scalar: Scalar,
+    }
+    let mut public_keys: Vec<PublicKey> = Vec::new();
+    let mut rs = Vec::with_capacity(batch.len());
+    let mut ss = Vec::with_capacity(batch.len());
+    let mut es = Vec::with_capacity(batch.len());
+    let mut key_indices = Vec::with_capacity(batch.len());
+    let mut r_points = Vec::with_capacity(batch.len());
+
+    for &(public_key, msg, signature) in batch {
+        let key_index = match public_keys.iter().position(|k| k.bytes == public_key) {
+            Some(key_index) => key_index,
+            None => {
+                let point = parse_uncompressed_point(
+                    ops.public_key_ops,
+                    untrusted::Input::from(public_key),
+                )?;
+                public_keys.push(PublicKey {
+                    bytes: public_key,
+                    point,
+                    scalar: Scalar::zero(),
+                });
+                public_keys.len() - 1
+            }
+        };
+
+        let (r, s) = untrusted::Input::from(signature).read_all(error::Unspecified, |input| {
+            (alg.split_rs)(scalar_ops, input)
+        })?;
+        let r = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, r)?;
+        let s = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, s)?;
+        if alg.require_low_s && scalar_is_high_vartime(cops, &s) {
+            return Err(error::Unspecified);
+        }
+
+        r_points.push(lift_x(ops, curve.elem_sqrt, &r)?);
+        es.push(digest_scalar(
+            scalar_ops,
+            digest::digest(alg.digest_alg, msg),
+        ));
+        rs.push(r);
+        ss.push(s);
+        key_indices.push(key_index);
+    }
+
+    // Invert all the s_i with a single inversion, using Montgomery's trick.
+    let ws = {
+        let ss: Vec<Scalar<R>> = ss
+            .iter()
+            .map(|s| scalar_ops.scalar_product(s, rr))
+            .collect();
+        let mut prefixes: Vec<Scalar<R>> = Vec::with_capacity(ss.len());
+        for s in &ss {
+            let prefix = match prefixes.last() {
+                Some(prefix) => scalar_ops.scalar_product(prefix, s),
+                None => *s,
+            };
+            prefixes.push(prefix);
+        }
+        let mut one = Scalar::zero();
+        one.limbs[0] = 1;
+        let product: Scalar = scalar_ops.scalar_product(prefixes.last().unwrap(), &one);
+        let mut inverse = scalar_ops.scalar_inv_to_mont(&product);
+        let mut ws = alloc::vec![Scalar::<R>::zero(); ss.len()];
+        for i in (1..ss.len()).rev() {
+            ws[i] = scalar_ops.scalar_product(&inverse, &prefixes[i - 1]);
+            inverse = scalar_ops.scalar_product(&inverse, &ss[i]);
+        }
+        ws[0] = inverse;
+        ws
+    };
+
+    let mut g_scalar = Scalar::zero();
+    let mut p_points = Vec::with_capacity(batch.len());
+    for (i, z) in z_bytes.chunks(Z_LEN).enumerate() {
+        let z_scalar = scalar_parse_big_endian_variable(
+            cops,
+            limb::AllowZero::Yes,
+            untrusted::Input::from(z),
+        )?;
+        let z_scalar: Scalar<R> = scalar_ops.scalar_product(&z_scalar, rr);
+
+        // u1 = e * w, u2 = r * w; accumulate z * u1 and z * u2.
+        let u1: Scalar = scalar_ops.scalar_product(&es[i], &ws[i]);
+        let u2: Scalar = scalar_ops.scalar_product(&rs[i], &ws[i]);
+        g_scalar = scalar_sum(cops, &g_scalar, &scalar_ops.scalar_product(&u1, &z_scalar));
+        let q_scalar = &mut public_keys[key_indices[i]].scalar;
+        *q_scalar = scalar_sum(cops, q_scalar, &scalar_ops.scalar_product(&u2, &z_scalar));
+
+        p_points.push(point_mul_vartime(cops, z, &r_points[i]));
+    }
+
+    let private_key_ops = ops.private_key_ops;
+    let mut lhs = private_key_ops.point_mul_base(&g_scalar);
+    for public_key in &public_keys {
+        let product = private_key_ops.point_mul(&public_key.scalar, &public_key.point);
+        lhs = cops.point_sum(&lhs, &product);
+    }
+
+    // Enumerate the signs with a Gray code, flipping the sign of one term of
+    // the sum at each step. The sign of the first term is fixed; both signs
+    // of the whole sum are checked instead.
+    let mut sum = p_points
+        .iter()
+        .skip(1)
+        .fold(p_points[0].clone(), |acc, p| cops.point_sum(&acc, p));
+    let doubled: Vec<(Point, Point)> = p_points
+        .iter()
+        .map(|p| {
+            let doubled = cops.point_doubled(p);
+            (cops.point_negated(&doubled), doubled)
+        })
+        .collect();
+    let mut negated = [false; CHUNK_LEN];
+    for step in 0usize..(1 << (batch.len() - 1)) {
+        if step != 0 {
+            let j = 1 + step.trailing_zeros() as usize;
+            let (minus, plus) = &doubled[j];
+            sum = cops.point_sum(&sum, if negated[j] { plus } else { minus });
+            negated[j] = !negated[j];
+        }
+        if points_are_equal_up_to_sign_vartime(cops, &lhs, &sum) {
+            return Ok(());
+        }
+    }
+
+    Err(error::Unspecified)
+}
+
+// Returns a point with x coordinate `r`, which must not also be the reduced
+// x coordinate of another point, up to sign.
+fn lift_x(
+    ops: &PublicScalarOps,
+    elem_sqrt: fn(a: &Elem<R>) -> Elem<R>,
+    r: &Scalar,
+) -> Result<Point, error::Unspecified> {
+    let cops = ops.public_key_ops.common;
+
+    // A valid signature may have x = r + n instead, which is very unlikely;
+    // leave those signatures to individual verification.
+    let x = ops.scalar_as_elem(r);
+    if ops.elem_less_than(&x, &ops.q_minus_n) {
+        return Err(error::Unspecified);
+    }
+    let x = cops.elem_to_mont(&x);
+
+    let mut y_squared = cops.elem_squared(&x);
+    cops.elem_add(&mut y_squared, &cops.a);
+    cops.elem_mul(&mut y_squared, &x);
+    cops.elem_add(&mut y_squared, &cops.b);
+    let y = elem_sqrt(&y_squared);
+    if cops.elems_are_equal(&cops.elem_squared(&y), &y_squared) != LimbMask::True {
+        return Err(error::Unspecified);
+    }
+    Ok(cops.point_from_affine(&x, &y))
+}
+
+// Computes [z]p for a 128-bit big-endian `z`, using a 4-bit fixed window.
+// This isn't constant-time.
+fn point_mul_vartime(cops: &CommonOps, z: &[u8], p: &Point) -> Point {
+    let mut table = Vec::with_capacity(15);
+    table.push(p.clone());
+    for i in 1..15 {
+        let next = cops.point_sum(&table[i - 1], p);
+        table.push(next);
+    }
+    let mut acc = Point::new_at_infinity();
+    for &byte in z {
+        for &nibble in &[byte >> 4, byte & 0xf] {
+            for _ in 0..4 {
+                acc = cops.point_doubled(&acc);
+            }
+            if nibble != 0 {
+                acc = cops.point_sum(&acc, &table[usize::from(nibble) - 1]);
+            }
+        }
+    }
+    acc
+}
+
+fn points_are_equal_up_to_sign_vartime(cops: &CommonOps, a: &Point, b: &Point) -> bool {
+    let (a_z, b_z) = (cops.point_z(a), cops.point_z(b));
+    match (cops.is_zero(&a_z), cops.is_zero(&b_z)) {
+        (true, true) => return true,
+        (false, false) => (),
+        _ => return false,
+    }
+    let (a_z2, b_z2) = (cops.elem_squared(&a_z), cops.elem_squared(&b_z));
+    let a_x = cops.elem_product(&cops.point_x(a), &b_z2);
+    let b_x = cops.elem_product(&cops.point_x(b), &a_z2);
+    if cops.elems_are_equal(&a_x, &b_x) != LimbMask::True {
+        return false;
+    }
+    let a_y = cops.elem_product(&cops.point_y(a), &cops.elem_product(&b_z2, &b_z));
+    let b_y = cops.elem_product(&cops.point_y(b), &cops.elem_product(&a_z2, &a_z));
+    cops.elems_are_equal(&a_y, &b_y) == LimbMask::True
+        || cops.elems_are_equal(&a_y, &cops.elem_negated(&b_y)) == LimbMask::True
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::{self, KeyPair};
+
+    #[test]
+    fn verify_combined_test() {
+        let rng = rand::SystemRandom::new();
+        for &(signing_alg, verification_alg) in &[
+            (
+                &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                &signature::ECDSA_P256_SHA256_FIXED,
+            ),
+            (
+                &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                &signature::ECDSA_P384_SHA384_ASN1,
+            ),
+            (
+                &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                &signature::ECDSA_SECP256K1_SHA256_FIXED,
+            ),
+        ] {
+            let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+            let key_pair =
+                signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+            let public_key = key_pair.public_key().as_ref();
+            let msgs: Vec<[u8; 1]> = (0..CHUNK_LEN as u8).map(|i| [i]).collect();
+            let sigs: Vec<_> = msgs
+                .iter()
+                .map(|msg| key_pair.sign(&rng, msg).unwrap())
+                .collect();
+            let mut batch: Vec<(&[u8], &[u8], &[u8])> = msgs
+                .iter()
+                .zip(&sigs)
+                .map(|(msg, sig)| (public_key, &msg[..], sig.as_ref()))
+                .collect();
+
+            // The combined check succeeds for every size of chunk, without
+            // falling back to individual verification.
+            for len in 1..=CHUNK_LEN {
+                assert_eq!(
+                    verify_combined(verification_alg, &batch[..len], &rng),
+                    Ok(())
+                );
+            }
+
+            batch[CHUNK_LEN - 1].1 = &msgs[0];
+            assert_eq!(
+                verify_combined(verification_alg, &batch, &rng),
+                Err(error::Unspecified)
+            );
+        }
+    }
+}
@@ -12,13 +12,9 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-//! ECDSA Signatures using the P-256 and P-384 curves.
+//! ECDSA Signatures using the P-256, P-384, P-521, and secp256k1 curves.
 
-use crate::{
-    digest,
-    ec::suite_b::ops::*,
-    limb::{self, LIMB_BYTES},
-};
+use crate::{digest, ec::suite_b::ops::*, limb};
 
 /// Calculate the digest of `msg` using the digest algorithm `digest_alg`. Then
 /// convert the digest to a scalar in the range [0, n) as described in
@@ -45,7 +41,8 @@ use crate::{
 /// less than 2**256. If the value is larger than `n` then shifting it one bit
 /// right will give a value less than 2**255, which is less than `n`. The
 /// analogous argument applies for P-384. However, it does *not* apply in
-/// general; for example, it doesn't apply to P-521.
+/// general. For P-521, it only works because the only supported digest,
+/// SHA-512, is shorter than `n`, so no truncation or reduction occurs.
 pub fn digest_scalar(ops: &ScalarOps, msg: digest::Digest) -> Scalar {
     digest_scalar_(ops, msg.as_ref())
 }
@@ -59,9 +56,8 @@ pub(crate) fn digest_bytes_scalar(ops: &ScalarOps, digest: &[u8]) -> Scalar {
 // values like all-zero values and values larger than `n`.
 fn digest_scalar_(ops: &ScalarOps, digest: &[u8]) -> Scalar {
     let cops = ops.common;
-    let num_limbs = cops.num_limbs;
-    let digest = if digest.len() > num_limbs * LIMB_BYTES {
-        &digest[..(num_limbs * LIMB_BYTES)]
+    let digest = if digest.len() > cops.len() {
+        &digest[..cops.len()]
     } else {
         digest
     };
@@ -77,12 +73,7 @@ fn digest_scalar_(ops: &ScalarOps, digest: &[u8]) -> Scalar {
 #[cfg(test)]
 mod tests {
     use super::digest_bytes_scalar;
-    use crate::{
-        digest,
-        ec::suite_b::ops::*,
-        limb::{self, LIMB_BYTES},
-        test,
-    };
+    use crate::{digest, ec::suite_b::ops::*, limb, test};
 
     #[test]
     fn test() {
@@ -101,6 +92,7 @@ mod tests {
                     ("P-256", "SHA384") => (&p256::PUBLIC_SCALAR_OPS, &digest::SHA384),
                     ("P-384", "SHA256") => (&p384::PUBLIC_SCALAR_OPS, &digest::SHA256),
                     ("P-384", "SHA384") => (&p384::PUBLIC_SCALAR_OPS, &digest::SHA384),
+                    ("P-521", "SHA512") => (&p521::PUBLIC_SCALAR_OPS, &digest::SHA512),
                     _ => {
                         panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                     }
@@ -108,10 +100,7 @@ mod tests {
 
                 let num_limbs = ops.public_key_ops.common.num_limbs;
                 assert_eq!(input.len(), digest_alg.output_len);
-                assert_eq!(
-                    output.len(),
-                    ops.public_key_ops.common.num_limbs * LIMB_BYTES
-                );
+                assert_eq!(output.len(), ops.public_key_ops.common.len());
 
                 let expected = scalar_parse_big_endian_variable(
                     ops.public_key_ops.common,
@@ -0,0 +1,288 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! ECDSA public key recovery.
+
+use super::{
+    digest_scalar::digest_scalar,
+    signing::{self, PublicKey},
+    verification::{split_rs_asn1, split_rs_fixed, twin_mul, SplitRs},
+};
+use crate::{
+    arithmetic::montgomery::*,
+    digest,
+    ec::{
+        self,
+        suite_b::{ops::*, private_key},
+    },
+    error,
+    limb::{self, LimbMask},
+    pkcs8,
+};
+use core::convert::TryFrom;
+
+/// Identifies which of the public keys that an ECDSA signature is valid for
+/// was used to produce it.
+///
+/// A signature (*r*, *s*) is valid for up to four public keys. Bit 0 of the
+/// recovery ID is the parity of the *y* coordinate of the point *R* computed
+/// during signing, and bit 1 is set when the *x* coordinate of *R* was not
+/// less than *n*, so that it had to be reduced to get *r*. This is the
+/// encoding that Bitcoin and Ethereum use, before they add any
+/// application-specific offset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    pub(super) fn from_parts(x_was_reduced: bool, y_is_odd: bool) -> Self {
+        Self((u8::from(x_was_reduced) << 1) | u8::from(y_is_odd))
+    }
+
+    /// The recovery ID for the same signature with *s* replaced by -*s*,
+    /// which corresponds to the point -*R*.
+    pub(super) fn negated(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+
+    fn x_was_reduced(self) -> bool {
+        self.0 & 2 != 0
+    }
+
+    fn y_is_odd(self) -> bool {
+        self.0 & 1 != 0
+    }
+}
+
+/// Fails if `value` isn't in the range [0, 3].
+impl TryFrom<u8> for RecoveryId {
+    type Error = error::Unspecified;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 3 {
+            return Err(error::Unspecified);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<RecoveryId> for u8 {
+    fn from(recovery_id: RecoveryId) -> Self {
+        recovery_id.0
+    }
+}
+
+/// An ECDSA public key recovery algorithm.
+pub struct EcdsaRecoveryAlgorithm {
+    ops: &'static PublicScalarOps,
+    pkcs8_template: &'static pkcs8::Template,
+    digest_alg: &'static digest::Algorithm,
+    split_rs: SplitRs,
+    elem_sqrt: fn(a: &Elem<R>) -> Elem<R>,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    ECDSA_P256_SHA256_ASN1_RECOVERY,
+    ECDSA_P256_SHA256_FIXED_RECOVERY,
+    ECDSA_P384_SHA384_ASN1_RECOVERY,
+    ECDSA_P384_SHA384_FIXED_RECOVERY,
+    ECDSA_SECP256K1_SHA256_ASN1_RECOVERY,
+    ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+}
+
+derive_debug_via_id!(EcdsaRecoveryAlgorithm);
+
+impl EcdsaRecoveryAlgorithm {
+    /// Recovers the public key for which `signature` is a valid signature of
+    /// `message`, using the algorithm in [SEC 1: Elliptic Curve Cryptography,
+    /// Version 2.0] Section 4.1.6.
+    ///
+    /// The public key is encoded in uncompressed form. A successful recovery
+    /// only shows that *some* key signed the message; the caller must still
+    /// check that the recovered public key is one that it trusts, e.g. by
+    /// comparing it, or a hash of it, to an expected value.
+    ///
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
+    ///     http://www.secg.org/sec1-v2.pdf
+    pub fn recover_public_key(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        recovery_id: RecoveryId,
+    ) -> Result<PublicKey, error::Unspecified> {
+        let e = digest_scalar(
+            self.ops.scalar_ops,
+            digest::digest(self.digest_alg, message),
+        );
+        self.recover_digest(e, untrusted::Input::from(signature), recovery_id)
+    }
+
+    fn recover_digest(
+        &self,
+        e: Scalar,
+        signature: untrusted::Input,
+        recovery_id: RecoveryId,
+    ) -> Result<PublicKey, error::Unspecified> {
+        let scalar_ops = self.ops.scalar_ops;
+        let private_key_ops = self.ops.private_key_ops;
+        let cops = self.ops.public_key_ops.common;
+
+        let (r, s) = signature.read_all(error::Unspecified, |input| {
+            (self.split_rs)(scalar_ops, input)
+        })?;
+        let r = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, r)?;
+        let s = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, s)?;
+
+        // SEC 1 Steps 1.1 and 1.2: x = r + j*n, which must be a field element.
+        let mut x = self.ops.scalar_as_elem(&r);
+        if recovery_id.x_was_reduced() {
+            if !self.ops.elem_less_than(&x, &self.ops.q_minus_n) {
+                return Err(error::Unspecified);
+            }
+            cops.elem_add(&mut x, &cops.n);
+        }
+        let x = cops.elem_to_mont(&x);
+
+        // SEC 1 Step 1.3: Find the point R = (x, y) with the given parity of
+        // y. Since all the supported curves have prime order, R is in the
+        // right subgroup whenever it is on the curve, so Step 1.4 is
+        // unnecessary.
+        let y = {
+            let mut y_squared = cops.elem_squared(&x);
+            cops.elem_add(&mut y_squared, &cops.a);
+            cops.elem_mul(&mut y_squared, &x);
+            cops.elem_add(&mut y_squared, &cops.b);
+
+            let y = (self.elem_sqrt)(&y_squared);
+            if cops.elems_are_equal(&cops.elem_squared(&y), &y_squared) != LimbMask::True {
+                return Err(error::Unspecified);
+            }
+            let y_is_odd = cops.elem_unencoded(&y).limbs[0] & 1 == 1;
+            if y_is_odd == recovery_id.y_is_odd() {
+                y
+            } else {
+                cops.elem_negated(&y)
+            }
+        };
+
+        // SEC 1 Steps 1.5 and 1.6: Q = r**-1 * (s*R - e*G), which we compute
+        // as u1*G + u2*R where u1 = -e * r**-1 and u2 = s * r**-1.
+        let r_inv = scalar_ops.scalar_inv_to_mont(&r);
+        let u1 = scalar_ops.scalar_product(&scalar_negated(cops, &e), &r_inv);
+        let u2 = scalar_ops.scalar_product(&s, &r_inv);
+        let q = twin_mul(private_key_ops, &u1, &u2, &(x, y));
+
+        // A maliciously-constructed signature can make Q the point at
+        // infinity, which isn't a valid public key.
+        cops.elem_verify_is_not_zero(&cops.point_z(&q))?;
+
+        let elem_len = cops.len();
+        let public_key = ec::PublicKey::new(1 + (2 * elem_len), |public_out| {
+            public_out[0] = 4; // Uncompressed encoding.
+            let (x_out, y_out) = public_out[1..].split_at_mut(elem_len);
+            private_key::big_endian_affine_from_jacobian(
+                private_key_ops,
+                Some(x_out),
+                Some(y_out),
+                &q,
+            )
+        })?;
+        Ok(PublicKey::new(public_key, self.pkcs8_template))
+    }
+}
+
+/// Recovery of the public key from fixed-length (PKCS#11 style) ECDSA
+/// signatures using the P-256 curve and SHA-256.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P256_SHA256_FIXED_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &p256::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_fixed,
+    elem_sqrt: p256::elem_sqrt,
+    id: AlgorithmID::ECDSA_P256_SHA256_FIXED_RECOVERY,
+};
+
+/// Recovery of the public key from ASN.1 DER-encoded ECDSA
+/// signatures using the P-256 curve and SHA-256.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P256_SHA256_ASN1_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &p256::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_asn1,
+    elem_sqrt: p256::elem_sqrt,
+    id: AlgorithmID::ECDSA_P256_SHA256_ASN1_RECOVERY,
+};
+
+/// Recovery of the public key from fixed-length (PKCS#11 style) ECDSA
+/// signatures using the P-384 curve and SHA-384.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P384_SHA384_FIXED_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &p384::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA384,
+    split_rs: split_rs_fixed,
+    elem_sqrt: p384::elem_sqrt,
+    id: AlgorithmID::ECDSA_P384_SHA384_FIXED_RECOVERY,
+};
+
+/// Recovery of the public key from ASN.1 DER-encoded ECDSA
+/// signatures using the P-384 curve and SHA-384.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P384_SHA384_ASN1_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &p384::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA384,
+    split_rs: split_rs_asn1,
+    elem_sqrt: p384::elem_sqrt,
+    id: AlgorithmID::ECDSA_P384_SHA384_ASN1_RECOVERY,
+};
+
+/// Recovery of the public key from fixed-length (PKCS#11 style) ECDSA
+/// signatures using the secp256k1 curve and SHA-256.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_FIXED_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &secp256k1::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_fixed,
+    elem_sqrt: secp256k1::elem_sqrt,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+};
+
+/// Recovery of the public key from ASN.1 DER-encoded ECDSA signatures using
+/// the secp256k1 curve and SHA-256.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_ASN1_RECOVERY: EcdsaRecoveryAlgorithm = EcdsaRecoveryAlgorithm {
+    ops: &secp256k1::PUBLIC_SCALAR_OPS,
+    pkcs8_template: &signing::EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_asn1,
+    elem_sqrt: secp256k1::elem_sqrt,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_RECOVERY,
+};
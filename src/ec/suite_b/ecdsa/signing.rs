@@ -12,9 +12,11 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-//! ECDSA Signatures using the P-256 and P-384 curves.
+//! ECDSA Signatures using the P-256, P-384, P-521, and secp256k1 curves.
 
-use super::digest_scalar::digest_scalar;
+#[cfg(feature = "interop")]
+use super::verification;
+use super::{digest_scalar::digest_scalar, recovery::RecoveryId};
 use crate::{
     arithmetic::montgomery::*,
     cpu, digest,
@@ -22,18 +24,22 @@ use crate::{
         self,
         suite_b::{ops::*, private_key},
     },
-    error,
+    error, hmac,
     io::der,
     limb, pkcs8, rand, sealed, signature,
 };
+use core::cell::RefCell;
+
 /// An ECDSA signing algorithm.
 pub struct EcdsaSigningAlgorithm {
     curve: &'static ec::Curve,
     private_scalar_ops: &'static PrivateScalarOps,
     private_key_ops: &'static PrivateKeyOps,
     digest_alg: &'static digest::Algorithm,
+    hmac_alg: &'static hmac::Algorithm,
     pkcs8_template: &'static pkcs8::Template,
     format_rs: fn(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize,
+    normalize_s: bool,
     id: AlgorithmID,
 }
 
@@ -43,6 +49,18 @@ enum AlgorithmID {
     ECDSA_P384_SHA384_FIXED_SIGNING,
     ECDSA_P256_SHA256_ASN1_SIGNING,
     ECDSA_P384_SHA384_ASN1_SIGNING,
+    ECDSA_P521_SHA512_FIXED_SIGNING,
+    ECDSA_P521_SHA512_ASN1_SIGNING,
+    ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+    ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING,
+    ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING,
+    ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING,
+    ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING,
+    ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING,
+    ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING,
+    ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING,
+    ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING,
 }
 
 derive_debug_via_id!(EcdsaSigningAlgorithm);
@@ -60,6 +78,7 @@ impl sealed::Sealed for EcdsaSigningAlgorithm {}
 /// An ECDSA key pair, used for signing.
 pub struct EcdsaKeyPair {
     d: Scalar<R>,
+    seed: ec::Seed,
     nonce_key: NonceRandomKey,
     alg: &'static EcdsaSigningAlgorithm,
     public_key: PublicKey,
@@ -147,6 +166,31 @@ impl EcdsaKeyPair {
         Self::new(alg, key_pair, rng)
     }
 
+    /// Constructs an ECDSA key pair from the private key bytes alone,
+    /// computing the public key from the private key.
+    ///
+    /// The private key must be encoded like it is for
+    /// `EcdsaKeyPair::from_private_key_and_public_key()`, and it must be in
+    /// the range [1, n) where n is the order of the curve.
+    ///
+    /// This is intended for keys that come without their public key, e.g.
+    /// keys exported from an HSM or derived deterministically. Since the
+    /// public key isn't given, it is not possible to detect misuse or
+    /// corruption of the private key; when the public key is available, use
+    /// `EcdsaKeyPair::from_private_key_and_public_key()` instead.
+    pub fn from_private_key(
+        alg: &'static EcdsaSigningAlgorithm,
+        private_key: &[u8],
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::KeyRejected> {
+        let key_pair = ec::suite_b::key_pair_from_private_key_bytes(
+            alg.curve,
+            untrusted::Input::from(private_key),
+            cpu::features(),
+        )?;
+        Self::new(alg, key_pair, rng)
+    }
+
     fn new(
         alg: &'static EcdsaSigningAlgorithm,
         key_pair: ec::KeyPair,
@@ -159,12 +203,13 @@ impl EcdsaKeyPair {
             .scalar_ops
             .scalar_product(&d, &alg.private_scalar_ops.oneRR_mod_n);
 
-        let nonce_key = NonceRandomKey::new(alg, &seed, rng)?;
+        let nonce_key = NonceRandomKey::new(alg.curve, alg.digest_alg, &seed, rng)?;
         Ok(Self {
             d,
+            seed,
             nonce_key,
             alg,
-            public_key: PublicKey(public_key),
+            public_key: PublicKey::new(public_key, alg.pkcs8_template),
         })
     }
 
@@ -178,16 +223,120 @@ impl EcdsaKeyPair {
         // Step 4 (out of order).
         let h = digest::digest(self.alg.digest_alg, message);
 
-        // Incorporate `h` into the nonce to hedge against faulty RNGs. (This
-        // is not an approved random number generator that is mandated in
-        // the spec.)
+        self.sign_digest(rng, &h)
+    }
+
+    /// Returns the signature of the message whose digest is `digest` using a
+    /// random nonce generated by `rng`.
+    ///
+    /// This is for callers that have already digested the message, e.g.
+    /// because it was too large to buffer. Fails if `digest` wasn't
+    /// calculated using the signing algorithm's digest algorithm.
+    pub fn sign_digest(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        digest: &digest::Digest,
+    ) -> Result<signature::Signature, error::Unspecified> {
+        if digest.algorithm() != self.alg.digest_alg {
+            return Err(error::Unspecified);
+        }
+
+        // Incorporate `digest` into the nonce to hedge against faulty RNGs.
+        // (This is not an approved random number generator that is mandated
+        // in the spec.)
+        let nonce_rng = NonceRandom {
+            key: &self.nonce_key,
+            message_digest: digest,
+            rng,
+        };
+
+        self.sign_with_nonce_rng(*digest, &nonce_rng)
+            .map(|(signature, _)| signature)
+    }
+
+    /// Returns a context for signing a message that is given in parts, e.g.
+    /// because it is too large to hold in memory.
+    ///
+    /// The message is digested incrementally with the signing algorithm's
+    /// digest algorithm and then signed like `sign_digest()` does.
+    pub fn signing_context(&self) -> EcdsaSigningContext<'_> {
+        EcdsaSigningContext {
+            key_pair: self,
+            digest: digest::Context::new(self.alg.digest_alg),
+        }
+    }
+
+    /// Returns the signature of the `message` using a random nonce generated
+    /// by `rng`, along with the recovery ID needed to recover the public key
+    /// from the signature using an `EcdsaRecoveryAlgorithm`.
+    ///
+    /// The signature is the same as the one `sign()` would produce; the
+    /// recovery ID is separate from it and must be conveyed to the verifier
+    /// alongside it.
+    pub fn sign_recoverable(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        message: &[u8],
+    ) -> Result<(signature::Signature, RecoveryId), error::Unspecified> {
+        let h = digest::digest(self.alg.digest_alg, message);
         let nonce_rng = NonceRandom {
             key: &self.nonce_key,
             message_digest: &h,
             rng,
         };
+        self.sign_with_nonce_rng(h, &nonce_rng)
+    }
 
-        self.sign_digest(h, &nonce_rng)
+    /// Returns the signature of the `message` using a nonce derived
+    /// deterministically from the private key and the message, as described in
+    /// [RFC 6979].
+    ///
+    /// Signing the same message with the same key always gives the same
+    /// signature, and no random number generator is used, so a faulty random
+    /// number generator can't cause nonces to be repeated or biased. The
+    /// downside is that repeating the computation exactly makes some fault
+    /// attacks easier; see `sign_hedged()`.
+    ///
+    /// [RFC 6979]: https://tools.ietf.org/html/rfc6979
+    pub fn sign_deterministic(
+        &self,
+        message: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        self.sign_rfc6979(message, &[])
+    }
+
+    /// Returns the signature of the `message` using a nonce derived from the
+    /// private key, the message, and random bytes generated by `rng`, as
+    /// described in [RFC 6979 Section 3.6].
+    ///
+    /// The nonce is at least as strong as the one used by
+    /// `sign_deterministic()` even when `rng` is faulty, and the random bytes
+    /// make it much harder to induce faults by having the same signature
+    /// computed repeatedly.
+    ///
+    /// [RFC 6979 Section 3.6]: https://tools.ietf.org/html/rfc6979#section-3.6
+    pub fn sign_hedged(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        message: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let mut additional_data = [0u8; ec::SCALAR_MAX_BYTES];
+        let additional_data = &mut additional_data[..self.alg.curve.elem_scalar_seed_len];
+        rng.fill(additional_data)?;
+        self.sign_rfc6979(message, additional_data)
+    }
+
+    fn sign_rfc6979(
+        &self,
+        message: &[u8],
+        additional_data: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        // Step 4 (out of order).
+        let h = digest::digest(self.alg.digest_alg, message);
+
+        let nonce_rng = Rfc6979Nonce::new(self.alg, &self.seed, h, additional_data);
+        self.sign_with_nonce_rng(h, &nonce_rng)
+            .map(|(signature, _)| signature)
     }
 
     #[cfg(test)]
@@ -195,20 +344,65 @@ impl EcdsaKeyPair {
         &self,
         rng: &dyn rand::SecureRandom,
         message: &[u8],
-    ) -> Result<signature::Signature, error::Unspecified> {
+    ) -> Result<(signature::Signature, RecoveryId), error::Unspecified> {
         // Step 4 (out of order).
         let h = digest::digest(self.alg.digest_alg, message);
 
-        self.sign_digest(h, rng)
+        self.sign_with_nonce_rng(h, rng)
+    }
+
+    /// The algorithm that verifies the signatures made with this key pair.
+    #[cfg(feature = "interop")]
+    pub(crate) fn verification_algorithm(
+        &self,
+    ) -> &'static verification::EcdsaVerificationAlgorithm {
+        match self.alg.id {
+            AlgorithmID::ECDSA_P256_SHA256_FIXED_SIGNING => &verification::ECDSA_P256_SHA256_FIXED,
+            AlgorithmID::ECDSA_P384_SHA384_FIXED_SIGNING => &verification::ECDSA_P384_SHA384_FIXED,
+            AlgorithmID::ECDSA_P256_SHA256_ASN1_SIGNING => &verification::ECDSA_P256_SHA256_ASN1,
+            AlgorithmID::ECDSA_P384_SHA384_ASN1_SIGNING => &verification::ECDSA_P384_SHA384_ASN1,
+            AlgorithmID::ECDSA_P521_SHA512_FIXED_SIGNING => &verification::ECDSA_P521_SHA512_FIXED,
+            AlgorithmID::ECDSA_P521_SHA512_ASN1_SIGNING => &verification::ECDSA_P521_SHA512_ASN1,
+            AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_SIGNING => {
+                &verification::ECDSA_SECP256K1_SHA256_FIXED
+            }
+            AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_SIGNING => {
+                &verification::ECDSA_SECP256K1_SHA256_ASN1
+            }
+            AlgorithmID::ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING => {
+                &verification::ECDSA_P256_SHA256_FIXED_LOW_S
+            }
+            AlgorithmID::ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING => {
+                &verification::ECDSA_P384_SHA384_FIXED_LOW_S
+            }
+            AlgorithmID::ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING => {
+                &verification::ECDSA_P521_SHA512_FIXED_LOW_S
+            }
+            AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING => {
+                &verification::ECDSA_SECP256K1_SHA256_FIXED_LOW_S
+            }
+            AlgorithmID::ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING => {
+                &verification::ECDSA_P256_SHA256_ASN1_LOW_S
+            }
+            AlgorithmID::ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING => {
+                &verification::ECDSA_P384_SHA384_ASN1_LOW_S
+            }
+            AlgorithmID::ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING => {
+                &verification::ECDSA_P521_SHA512_ASN1_LOW_S
+            }
+            AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING => {
+                &verification::ECDSA_SECP256K1_SHA256_ASN1_LOW_S
+            }
+        }
     }
 
     /// Returns the signature of message digest `h` using a "random" nonce
-    /// generated by `rng`.
-    fn sign_digest(
+    /// generated by `rng`, and its recovery ID.
+    fn sign_with_nonce_rng(
         &self,
         h: digest::Digest,
         rng: &dyn rand::SecureRandom,
-    ) -> Result<signature::Signature, error::Unspecified> {
+    ) -> Result<(signature::Signature, RecoveryId), error::Unspecified> {
         // NSA Suite B Implementer's Guide to ECDSA Section 3.4.1: ECDSA
         // Signature Generation.
 
@@ -248,10 +442,20 @@ impl EcdsaKeyPair {
             let r = private_key_ops.point_mul_base(&k);
 
             // Step 3.
-            let r = {
-                let (x, _) = private_key::affine_from_jacobian(private_key_ops, &r)?;
+            let (r, recovery_id) = {
+                let (x, y) = private_key::affine_from_jacobian(private_key_ops, &r)?;
                 let x = cops.elem_unencoded(&x);
-                elem_reduced_to_scalar(cops, &x)
+                let r = elem_reduced_to_scalar(cops, &x);
+
+                // The recovery ID records whether `x` had to be reduced to get
+                // `r`, and the parity of `y`.
+                let num_limbs = cops.num_limbs;
+                let x_was_reduced =
+                    limb::limbs_equal_limbs_consttime(&x.limbs[..num_limbs], &r.limbs[..num_limbs])
+                        != limb::LimbMask::True;
+                let y = cops.elem_unencoded(&y);
+                let y_is_odd = y.limbs[0] & 1 == 1;
+                (r, RecoveryId::from_parts(x_was_reduced, y_is_odd))
             };
             if cops.is_zero(&r) {
                 continue;
@@ -272,10 +476,19 @@ impl EcdsaKeyPair {
                 continue;
             }
 
+            // Replacing `s` with `n - s` gives another valid signature, which
+            // corresponds to using `-k` and thus the point `-R`.
+            let (s, recovery_id) = if self.alg.normalize_s && scalar_is_high_vartime(cops, &s) {
+                (scalar_negated(cops, &s), recovery_id.negated())
+            } else {
+                (s, recovery_id)
+            };
+
             // Step 7 with encoding.
-            return Ok(signature::Signature::new(|sig_bytes| {
+            let signature = signature::Signature::new(|sig_bytes| {
                 (self.alg.format_rs)(scalar_ops, &r, &s, sig_bytes)
-            }));
+            });
+            return Ok((signature, recovery_id));
         }
 
         Err(error::Unspecified)
@@ -284,10 +497,10 @@ impl EcdsaKeyPair {
 
 /// Generates an ECDSA nonce in a way that attempts to protect against a faulty
 /// `SecureRandom`.
-struct NonceRandom<'a> {
-    key: &'a NonceRandomKey,
-    message_digest: &'a digest::Digest,
-    rng: &'a dyn rand::SecureRandom,
+pub(in crate::ec::suite_b) struct NonceRandom<'a> {
+    pub(in crate::ec::suite_b) key: &'a NonceRandomKey,
+    pub(in crate::ec::suite_b) message_digest: &'a digest::Digest,
+    pub(in crate::ec::suite_b) rng: &'a dyn rand::SecureRandom,
 }
 
 impl core::fmt::Debug for NonceRandom<'_> {
@@ -299,38 +512,43 @@ impl core::fmt::Debug for NonceRandom<'_> {
 impl rand::sealed::SecureRandom for NonceRandom<'_> {
     fn fill_impl(&self, dest: &mut [u8]) -> Result<(), error::Unspecified> {
         // Use the same digest algorithm that will be used to digest the
-        // message. The digest algorithm's output is exactly the right size;
-        // this is checked below.
+        // message. The digest algorithm's output is exactly the right size
+        // for P-256 and P-384. For P-521 the nonce is slightly longer than
+        // the SHA-512 output, so a second chunk is generated; the chunks are
+        // distinguished by a counter that is only digested for chunks after
+        // the first.
         //
-        // XXX(perf): The single iteration will require two digest block
-        // operations because the amount of data digested is larger than one
-        // block.
+        // XXX(perf): Each iteration will require two digest block operations
+        // because the amount of data digested is larger than one block.
         let digest_alg = self.key.0.algorithm();
-        let mut ctx = digest::Context::new(digest_alg);
-
-        // Digest the randomized digest of the private key.
-        let key = self.key.0.as_ref();
-        ctx.update(key);
-
-        // The random value is digested between the key and the message so that
-        // the key and the message are not directly digested in the same digest
-        // block.
-        assert!(key.len() <= digest_alg.block_len / 2);
-        {
-            let mut rand = [0u8; digest::MAX_BLOCK_LEN];
-            let rand = &mut rand[..digest_alg.block_len - key.len()];
-            assert!(rand.len() >= dest.len());
-            self.rng.fill(rand)?;
-            ctx.update(rand);
-        }
+        for (i, chunk) in dest.chunks_mut(digest_alg.output_len).enumerate() {
+            let mut ctx = digest::Context::new(digest_alg);
+
+            // Digest the randomized digest of the private key.
+            let key = self.key.0.as_ref();
+            ctx.update(key);
+
+            // The random value is digested between the key and the message so
+            // that the key and the message are not directly digested in the
+            // same digest block.
+            assert!(key.len() <= digest_alg.block_len / 2);
+            {
+                let mut rand = [0u8; digest::MAX_BLOCK_LEN];
+                let rand = &mut rand[..digest_alg.block_len - key.len()];
+                assert!(rand.len() >= chunk.len());
+                self.rng.fill(rand)?;
+                ctx.update(rand);
+            }
 
-        ctx.update(self.message_digest.as_ref());
+            ctx.update(self.message_digest.as_ref());
 
-        let nonce = ctx.finish();
+            if i > 0 {
+                ctx.update(&[i as u8]);
+            }
 
-        // `copy_from_slice()` panics if the lengths differ, so we don't have
-        // to separately assert that the lengths are the same.
-        dest.copy_from_slice(nonce.as_ref());
+            let nonce = ctx.finish();
+            chunk.copy_from_slice(&nonce.as_ref()[..chunk.len()]);
+        }
 
         Ok(())
     }
@@ -338,16 +556,131 @@ impl rand::sealed::SecureRandom for NonceRandom<'_> {
 
 impl<'a> sealed::Sealed for NonceRandom<'a> {}
 
-struct NonceRandomKey(digest::Digest);
+/// Generates ECDSA nonces deterministically as described in [RFC 6979]
+/// Section 3.2, with optional additional data as described in Section 3.6.
+///
+/// Each call to `fill()` produces the next candidate, `bits2int(T)`, from Step
+/// H.2. `private_key::random_scalar()` rejects out-of-range candidates, and
+/// `EcdsaKeyPair::sign_with_nonce_rng()` rejects candidates that result in a zero *r*
+/// or *s*, by asking for another one, which continues with Step H.3.
+///
+/// [RFC 6979]: https://tools.ietf.org/html/rfc6979
+struct Rfc6979Nonce {
+    state: RefCell<Rfc6979State>,
+    order_bits: usize,
+}
+
+struct Rfc6979State {
+    k: hmac::Key,
+    v: [u8; digest::MAX_OUTPUT_LEN],
+    rejected_previous: bool,
+}
 
-impl NonceRandomKey {
+impl Rfc6979Nonce {
     fn new(
         alg: &EcdsaSigningAlgorithm,
         seed: &ec::Seed,
+        h: digest::Digest,
+        additional_data: &[u8],
+    ) -> Self {
+        let scalar_ops = alg.private_scalar_ops.scalar_ops;
+        let v_len = alg.hmac_alg.digest_algorithm().output_len;
+
+        // Step A is done by the caller. `bits2octets(h1)` is
+        // `int2octets(bits2int(h1) mod q)`, i.e. the serialization of the
+        // digest scalar.
+        let mut h1 = [0u8; ec::SCALAR_MAX_BYTES];
+        let h1 = &mut h1[..scalar_ops.scalar_bytes_len()];
+        let e = digest_scalar(scalar_ops, h);
+        limb::big_endian_from_limbs(&e.limbs[..scalar_ops.common.num_limbs], h1);
+
+        // The seed is already the private key's `int2octets(x)`.
+        let x = seed.bytes_less_safe();
+
+        // Steps B and C.
+        let mut v = [0x01; digest::MAX_OUTPUT_LEN];
+        let mut k = hmac::Key::new(*alg.hmac_alg, &[0x00; digest::MAX_OUTPUT_LEN][..v_len]);
+
+        // Steps D through G.
+        for &separator in &[0x00, 0x01] {
+            let tag = hmac::sign_slices(&k, &[&v[..v_len], &[separator], x, h1, additional_data]);
+            k = hmac::Key::new(*alg.hmac_alg, tag.as_ref());
+            let tag = hmac::sign(&k, &v[..v_len]);
+            v[..v_len].copy_from_slice(tag.as_ref());
+        }
+
+        Self {
+            state: RefCell::new(Rfc6979State {
+                k,
+                v,
+                rejected_previous: false,
+            }),
+            order_bits: alg.private_key_ops.common.order_bits,
+        }
+    }
+}
+
+impl core::fmt::Debug for Rfc6979Nonce {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Rfc6979Nonce").finish()
+    }
+}
+
+impl rand::sealed::SecureRandom for Rfc6979Nonce {
+    fn fill_impl(&self, dest: &mut [u8]) -> Result<(), error::Unspecified> {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        let hmac_alg = state.k.algorithm();
+        let v_len = hmac_alg.digest_algorithm().output_len;
+
+        // Step H.3, when the previous candidate was rejected.
+        if state.rejected_previous {
+            let tag = hmac::sign_slices(&state.k, &[&state.v[..v_len], &[0x00]]);
+            state.k = hmac::Key::new(hmac_alg, tag.as_ref());
+            let tag = hmac::sign(&state.k, &state.v[..v_len]);
+            state.v[..v_len].copy_from_slice(tag.as_ref());
+        }
+        state.rejected_previous = true;
+
+        // Step H.2.
+        for chunk in dest.chunks_mut(v_len) {
+            let tag = hmac::sign(&state.k, &state.v[..v_len]);
+            state.v[..v_len].copy_from_slice(tag.as_ref());
+            chunk.copy_from_slice(&state.v[..chunk.len()]);
+        }
+
+        // `bits2int` keeps the leftmost `order_bits` bits. When the order
+        // isn't a multiple of 8 bits long (P-521), that means shifting the
+        // whole candidate right.
+        assert!(dest.len() * 8 >= self.order_bits);
+        let excess_bits = (dest.len() * 8) - self.order_bits;
+        assert!(excess_bits < 8);
+        if excess_bits > 0 {
+            let mut carry = 0;
+            for byte in dest.iter_mut() {
+                let next_carry = *byte << (8 - excess_bits);
+                *byte = (*byte >> excess_bits) | carry;
+                carry = next_carry;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl sealed::Sealed for Rfc6979Nonce {}
+
+pub(in crate::ec::suite_b) struct NonceRandomKey(digest::Digest);
+
+impl NonceRandomKey {
+    pub(in crate::ec::suite_b) fn new(
+        curve: &ec::Curve,
+        digest_alg: &'static digest::Algorithm,
+        seed: &ec::Seed,
         rng: &dyn rand::SecureRandom,
     ) -> Result<Self, error::KeyRejected> {
-        let mut rand = [0; digest::MAX_OUTPUT_LEN];
-        let rand = &mut rand[0..alg.curve.elem_scalar_seed_len];
+        let mut rand = [0; ec::SEED_MAX_BYTES];
+        let rand = &mut rand[0..curve.elem_scalar_seed_len];
 
         // XXX: `KeyRejected` isn't the right way to model  failure of the RNG,
         // but to fix that we'd need to break the API by changing the result type.
@@ -355,13 +688,45 @@ impl NonceRandomKey {
         rng.fill(rand)
             .map_err(|error::Unspecified| error::KeyRejected::rng_failed())?;
 
-        let mut ctx = digest::Context::new(alg.digest_alg);
+        let mut ctx = digest::Context::new(digest_alg);
         ctx.update(rand);
         ctx.update(seed.bytes_less_safe());
         Ok(Self(ctx.finish()))
     }
 }
 
+/// A context for signing a message given in parts with an `EcdsaKeyPair`.
+///
+/// See `EcdsaKeyPair::signing_context()`.
+#[derive(Clone)]
+pub struct EcdsaSigningContext<'a> {
+    key_pair: &'a EcdsaKeyPair,
+    digest: digest::Context,
+}
+
+derive_debug_via_field!(
+    EcdsaSigningContext<'_>,
+    stringify!(EcdsaSigningContext),
+    key_pair
+);
+
+impl EcdsaSigningContext<'_> {
+    /// Updates the message to sign with the next part `data`.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.digest.update(data)
+    }
+
+    /// Returns the signature of the whole message using a random nonce
+    /// generated by `rng`.
+    pub fn finish(
+        self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<signature::Signature, error::Unspecified> {
+        self.key_pair.sign_digest(rng, &self.digest.finish())
+    }
+}
+
 impl signature::KeyPair for EcdsaKeyPair {
     type PublicKey = PublicKey;
 
@@ -370,18 +735,67 @@ impl signature::KeyPair for EcdsaKeyPair {
     }
 }
 
+impl signature::KeyPairGenerator for EcdsaSigningAlgorithm {
+    type KeyPair = EcdsaKeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(self, rng)?;
+        let key_pair = EcdsaKeyPair::from_pkcs8(self, pkcs8.as_ref(), rng)?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
 #[derive(Clone, Copy)]
-pub struct PublicKey(ec::PublicKey);
+pub struct PublicKey {
+    public_key: ec::PublicKey,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pkcs8_template: &'static pkcs8::Template,
+}
 
 derive_debug_self_as_ref_hex_bytes!(PublicKey);
 
 impl AsRef<[u8]> for PublicKey {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.public_key.as_ref()
     }
 }
 
-fn format_rs_fixed(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize {
+impl PublicKey {
+    pub(super) fn new(public_key: ec::PublicKey, pkcs8_template: &'static pkcs8::Template) -> Self {
+        Self {
+            public_key,
+            pkcs8_template,
+        }
+    }
+
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`, as
+    /// described in [RFC 5480].
+    ///
+    /// [RFC 5480]: https://tools.ietf.org/html/rfc5480
+    #[cfg(feature = "alloc")]
+    pub fn subject_public_key_info(&self) -> alloc::boxed::Box<[u8]> {
+        use crate::io::der_writer;
+        der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                output.write_bytes(self.pkcs8_template.alg_id_value_())
+            });
+            der_writer::write_tlv(output, der::Tag::BitString, |output| {
+                output.write_byte(0); // No unused bits.
+                output.write_bytes(self.as_ref());
+            });
+        })
+    }
+}
+
+pub(in crate::ec::suite_b) fn format_rs_fixed(
+    ops: &'static ScalarOps,
+    r: &Scalar,
+    s: &Scalar,
+    out: &mut [u8],
+) -> usize {
     let scalar_len = ops.scalar_bytes_len();
 
     let (r_out, rest) = out.split_at_mut(scalar_len);
@@ -393,7 +807,12 @@ fn format_rs_fixed(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u
     2 * scalar_len
 }
 
-fn format_rs_asn1(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize {
+pub(in crate::ec::suite_b) fn format_rs_asn1(
+    ops: &'static ScalarOps,
+    r: &Scalar,
+    s: &Scalar,
+    out: &mut [u8],
+) -> usize {
     // This assumes `a` is not zero since neither `r` or `s` is allowed to be
     // zero.
     fn format_integer_tlv(ops: &ScalarOps, a: &Scalar, out: &mut [u8]) -> usize {
@@ -428,15 +847,25 @@ fn format_rs_asn1(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8
     }
 
     out[0] = der::Tag::Sequence as u8;
-    let r_tlv_len = format_integer_tlv(ops, r, &mut out[2..]);
-    let s_tlv_len = format_integer_tlv(ops, s, &mut out[2..][r_tlv_len..]);
 
-    // Lengths less than 128 are encoded in one byte.
+    // Leave room for a two-byte length, which P-521 signatures may need. The
+    // value is moved down a byte afterwards if a one-byte length suffices.
+    let r_tlv_len = format_integer_tlv(ops, r, &mut out[3..]);
+    let s_tlv_len = format_integer_tlv(ops, s, &mut out[3..][r_tlv_len..]);
     let value_len = r_tlv_len + s_tlv_len;
-    assert!(value_len < 128);
-    out[1] = value_len as u8;
 
-    2 + value_len
+    if value_len < 128 {
+        // Lengths less than 128 are encoded in one byte.
+        out[1] = value_len as u8;
+        out.copy_within(3..(3 + value_len), 2);
+        2 + value_len
+    } else {
+        // Lengths in [128, 256) are encoded as 0x81 followed by one byte.
+        assert!(value_len < 256);
+        out[1] = 0x81;
+        out[2] = value_len as u8;
+        3 + value_len
+    }
 }
 
 /// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the
@@ -449,8 +878,10 @@ pub static ECDSA_P256_SHA256_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigning
     private_scalar_ops: &p256::PRIVATE_SCALAR_OPS,
     private_key_ops: &p256::PRIVATE_KEY_OPS,
     digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
     pkcs8_template: &EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
     format_rs: format_rs_fixed,
+    normalize_s: false,
     id: AlgorithmID::ECDSA_P256_SHA256_FIXED_SIGNING,
 };
 
@@ -464,11 +895,47 @@ pub static ECDSA_P384_SHA384_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigning
     private_scalar_ops: &p384::PRIVATE_SCALAR_OPS,
     private_key_ops: &p384::PRIVATE_KEY_OPS,
     digest_alg: &digest::SHA384,
+    hmac_alg: &hmac::HMAC_SHA384,
     pkcs8_template: &EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
     format_rs: format_rs_fixed,
+    normalize_s: false,
     id: AlgorithmID::ECDSA_P384_SHA384_FIXED_SIGNING,
 };
 
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// P-521 curve and SHA-512.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P521_SHA512_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P521,
+    private_scalar_ops: &p521::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p521::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA512,
+    hmac_alg: &hmac::HMAC_SHA512,
+    pkcs8_template: &EC_PUBLIC_KEY_P521_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_fixed,
+    normalize_s: false,
+    id: AlgorithmID::ECDSA_P521_SHA512_FIXED_SIGNING,
+};
+
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// secp256k1 curve and SHA-256.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::SECP256K1,
+    private_scalar_ops: &secp256k1::PRIVATE_SCALAR_OPS,
+    private_key_ops: &secp256k1::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
+    pkcs8_template: &EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_fixed,
+    normalize_s: false,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+};
+
 /// Signing of ASN.1 DER-encoded ECDSA signatures using the P-256 curve and
 /// SHA-256.
 ///
@@ -479,8 +946,10 @@ pub static ECDSA_P256_SHA256_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningA
     private_scalar_ops: &p256::PRIVATE_SCALAR_OPS,
     private_key_ops: &p256::PRIVATE_KEY_OPS,
     digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
     pkcs8_template: &EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
     format_rs: format_rs_asn1,
+    normalize_s: false,
     id: AlgorithmID::ECDSA_P256_SHA256_ASN1_SIGNING,
 };
 
@@ -494,25 +963,213 @@ pub static ECDSA_P384_SHA384_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningA
     private_scalar_ops: &p384::PRIVATE_SCALAR_OPS,
     private_key_ops: &p384::PRIVATE_KEY_OPS,
     digest_alg: &digest::SHA384,
+    hmac_alg: &hmac::HMAC_SHA384,
     pkcs8_template: &EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
     format_rs: format_rs_asn1,
+    normalize_s: false,
     id: AlgorithmID::ECDSA_P384_SHA384_ASN1_SIGNING,
 };
 
-static EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the P-521 curve and
+/// SHA-512.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P521_SHA512_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P521,
+    private_scalar_ops: &p521::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p521::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA512,
+    hmac_alg: &hmac::HMAC_SHA512,
+    pkcs8_template: &EC_PUBLIC_KEY_P521_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_asn1,
+    normalize_s: false,
+    id: AlgorithmID::ECDSA_P521_SHA512_ASN1_SIGNING,
+};
+
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the secp256k1 curve and
+/// SHA-256.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::SECP256K1,
+    private_scalar_ops: &secp256k1::PRIVATE_SCALAR_OPS,
+    private_key_ops: &secp256k1::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
+    pkcs8_template: &EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_asn1,
+    normalize_s: false,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+};
+
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the P-256 curve and SHA-256, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P256,
+    private_scalar_ops: &p256::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p256::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
+    pkcs8_template: &EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_fixed,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING,
+};
+
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the P-384 curve and SHA-384, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P384,
+    private_scalar_ops: &p384::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p384::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA384,
+    hmac_alg: &hmac::HMAC_SHA384,
+    pkcs8_template: &EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_fixed,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING,
+};
+
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the P-521 curve and SHA-512, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P521,
+    private_scalar_ops: &p521::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p521::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA512,
+    hmac_alg: &hmac::HMAC_SHA512,
+    pkcs8_template: &EC_PUBLIC_KEY_P521_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_fixed,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING,
+};
+
+/// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the secp256k1 curve and SHA-256, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING: EcdsaSigningAlgorithm =
+    EcdsaSigningAlgorithm {
+        curve: &ec::suite_b::curve::SECP256K1,
+        private_scalar_ops: &secp256k1::PRIVATE_SCALAR_OPS,
+        private_key_ops: &secp256k1::PRIVATE_KEY_OPS,
+        digest_alg: &digest::SHA256,
+        hmac_alg: &hmac::HMAC_SHA256,
+        pkcs8_template: &EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+        format_rs: format_rs_fixed,
+        normalize_s: true,
+        id: AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING,
+    };
+
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the P-256 curve and SHA-256, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P256,
+    private_scalar_ops: &p256::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p256::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA256,
+    hmac_alg: &hmac::HMAC_SHA256,
+    pkcs8_template: &EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_asn1,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING,
+};
+
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the P-384 curve and SHA-384, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P384,
+    private_scalar_ops: &p384::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p384::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA384,
+    hmac_alg: &hmac::HMAC_SHA384,
+    pkcs8_template: &EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_asn1,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING,
+};
+
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the P-521 curve and SHA-512, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: &ec::suite_b::curve::P521,
+    private_scalar_ops: &p521::PRIVATE_SCALAR_OPS,
+    private_key_ops: &p521::PRIVATE_KEY_OPS,
+    digest_alg: &digest::SHA512,
+    hmac_alg: &hmac::HMAC_SHA512,
+    pkcs8_template: &EC_PUBLIC_KEY_P521_PKCS8_V1_TEMPLATE,
+    format_rs: format_rs_asn1,
+    normalize_s: true,
+    id: AlgorithmID::ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING,
+};
+
+/// Signing of ASN.1 DER-encoded ECDSA signatures using the secp256k1 curve and SHA-256, with
+/// *s* normalized to be at most *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING: EcdsaSigningAlgorithm =
+    EcdsaSigningAlgorithm {
+        curve: &ec::suite_b::curve::SECP256K1,
+        private_scalar_ops: &secp256k1::PRIVATE_SCALAR_OPS,
+        private_key_ops: &secp256k1::PRIVATE_KEY_OPS,
+        digest_alg: &digest::SHA256,
+        hmac_alg: &hmac::HMAC_SHA256,
+        pkcs8_template: &EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE,
+        format_rs: format_rs_asn1,
+        normalize_s: true,
+        id: AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING,
+    };
+
+pub(super) static EC_PUBLIC_KEY_P256_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
     bytes: include_bytes!("ecPublicKey_p256_pkcs8_v1_template.der"),
     alg_id_range: core::ops::Range { start: 8, end: 27 },
     curve_id_index: 9,
     private_key_index: 0x24,
 };
 
-static EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
+pub(super) static EC_PUBLIC_KEY_P384_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
     bytes: include_bytes!("ecPublicKey_p384_pkcs8_v1_template.der"),
     alg_id_range: core::ops::Range { start: 8, end: 24 },
     curve_id_index: 9,
     private_key_index: 0x23,
 };
 
+pub(super) static EC_PUBLIC_KEY_P521_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ecPublicKey_p521_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 8, end: 24 },
+    curve_id_index: 9,
+    private_key_index: 0x23,
+};
+
+pub(super) static EC_PUBLIC_KEY_SECP256K1_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ecPublicKey_secp256k1_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 8, end: 24 },
+    curve_id_index: 9,
+    private_key_index: 0x21,
+};
+
 #[cfg(test)]
 mod tests {
     use crate::{rand, signature, test};
@@ -538,6 +1195,8 @@ mod tests {
                 let alg = match (curve_name.as_str(), digest_name.as_str()) {
                     ("P-256", "SHA256") => &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
                     ("P-384", "SHA384") => &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                    ("P-521", "SHA512") => &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                    ("secp256k1", "SHA256") => &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
                     _ => {
                         panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                     }
@@ -548,7 +1207,7 @@ mod tests {
                         .unwrap();
                 let rng = test::rand::FixedSliceRandom { bytes: &k };
 
-                let actual_result = private_key
+                let (actual_result, _) = private_key
                     .sign_with_fixed_nonce_during_test(&rng, &msg)
                     .unwrap();
 
@@ -559,6 +1218,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signature_ecdsa_sign_deterministic_test() {
+        let rng = rand::SystemRandom::new();
+
+        test::run(
+            test_file!("ecdsa_sign_deterministic_tests.txt"),
+            |section, test_case| {
+                assert_eq!(section, "");
+
+                let curve_name = test_case.consume_string("Curve");
+                let digest_name = test_case.consume_string("Digest");
+                let msg = test_case.consume_bytes("Msg");
+                let d = test_case.consume_bytes("d");
+                let q = test_case.consume_bytes("Q");
+
+                let expected_result = test_case.consume_bytes("Sig");
+
+                let (alg, verification_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                    ("P-256", "SHA256") => (
+                        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_P256_SHA256_FIXED,
+                    ),
+                    ("P-384", "SHA384") => (
+                        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                        &signature::ECDSA_P384_SHA384_FIXED,
+                    ),
+                    ("P-521", "SHA512") => (
+                        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                        &signature::ECDSA_P521_SHA512_FIXED,
+                    ),
+                    ("secp256k1", "SHA256") => (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                    ),
+                    _ => {
+                        panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                    }
+                };
+
+                let private_key =
+                    signature::EcdsaKeyPair::from_private_key_and_public_key(alg, &d, &q, &rng)
+                        .unwrap();
+
+                let actual_result = private_key.sign_deterministic(&msg).unwrap();
+                assert_eq!(actual_result.as_ref(), &expected_result[..]);
+
+                // Hedged signatures differ from the deterministic ones but
+                // still verify.
+                let hedged = private_key.sign_hedged(&rng, &msg).unwrap();
+                assert_ne!(hedged.as_ref(), actual_result.as_ref());
+                let public_key = signature::UnparsedPublicKey::new(verification_alg, &q);
+                assert_eq!(public_key.verify(&msg, hedged.as_ref()), Ok(()));
+
+                Ok(())
+            },
+        );
+    }
+
     #[test]
     fn signature_ecdsa_sign_asn1_test() {
         let rng = rand::SystemRandom::new();
@@ -580,6 +1297,8 @@ mod tests {
                 let alg = match (curve_name.as_str(), digest_name.as_str()) {
                     ("P-256", "SHA256") => &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
                     ("P-384", "SHA384") => &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                    ("P-521", "SHA512") => &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+                    ("secp256k1", "SHA256") => &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
                     _ => {
                         panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                     }
@@ -590,7 +1309,7 @@ mod tests {
                         .unwrap();
                 let rng = test::rand::FixedSliceRandom { bytes: &k };
 
-                let actual_result = private_key
+                let (actual_result, _) = private_key
                     .sign_with_fixed_nonce_during_test(&rng, &msg)
                     .unwrap();
 
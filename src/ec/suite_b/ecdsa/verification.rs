@@ -12,9 +12,12 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-//! ECDSA Signatures using the P-256 and P-384 curves.
+//! ECDSA Signatures using the P-256, P-384, P-521, and secp256k1 curves.
 
-use super::digest_scalar::digest_scalar;
+use super::{
+    digest_scalar::digest_scalar,
+    signing::{format_rs_asn1, format_rs_fixed},
+};
 use crate::{
     arithmetic::montgomery::*,
     digest,
@@ -26,14 +29,11 @@ use crate::{
 
 /// An ECDSA verification algorithm.
 pub struct EcdsaVerificationAlgorithm {
-    ops: &'static PublicScalarOps,
-    digest_alg: &'static digest::Algorithm,
-    split_rs:
-        for<'a> fn(
-            ops: &'static ScalarOps,
-            input: &mut untrusted::Reader<'a>,
-        )
-            -> Result<(untrusted::Input<'a>, untrusted::Input<'a>), error::Unspecified>,
+    pub(super) ops: &'static PublicScalarOps,
+    pub(super) digest_alg: &'static digest::Algorithm,
+    pub(super) split_rs: SplitRs,
+    format_rs: fn(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize,
+    pub(super) require_low_s: bool,
     id: AlgorithmID,
 }
 
@@ -45,6 +45,18 @@ enum AlgorithmID {
     ECDSA_P384_SHA256_ASN1,
     ECDSA_P384_SHA384_ASN1,
     ECDSA_P384_SHA384_FIXED,
+    ECDSA_P521_SHA512_ASN1,
+    ECDSA_P521_SHA512_FIXED,
+    ECDSA_SECP256K1_SHA256_ASN1,
+    ECDSA_SECP256K1_SHA256_FIXED,
+    ECDSA_P256_SHA256_ASN1_LOW_S,
+    ECDSA_P256_SHA256_FIXED_LOW_S,
+    ECDSA_P384_SHA384_ASN1_LOW_S,
+    ECDSA_P384_SHA384_FIXED_LOW_S,
+    ECDSA_P521_SHA512_ASN1_LOW_S,
+    ECDSA_P521_SHA512_FIXED_LOW_S,
+    ECDSA_SECP256K1_SHA256_ASN1_LOW_S,
+    ECDSA_SECP256K1_SHA256_FIXED_LOW_S,
 }
 
 derive_debug_via_id!(EcdsaVerificationAlgorithm);
@@ -66,13 +78,101 @@ impl signature::VerificationAlgorithm for EcdsaVerificationAlgorithm {
             digest_scalar(self.ops.scalar_ops, h)
         };
 
-        self.verify_digest(public_key, e, signature)
+        self.verify_digest_scalar(public_key, e, signature)
+    }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        if digest.algorithm() != self.digest_alg {
+            return Err(error::Unspecified);
+        }
+        let e = digest_scalar(self.ops.scalar_ops, *digest);
+        self.verify_digest_scalar(public_key, e, signature)
+    }
+
+    fn parse_public_key(
+        &self,
+        public_key: untrusted::Input,
+    ) -> Result<signature::parsed::PublicKey, error::KeyRejected> {
+        let point = parse_uncompressed_point(self.ops.public_key_ops, public_key)
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        Ok(signature::parsed::PublicKey::Ecdsa(ParsedPublicKey {
+            point,
+        }))
+    }
+
+    fn verify_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let h = digest::digest(self.digest_alg, msg.as_slice_less_safe());
+        let e = digest_scalar(self.ops.scalar_ops, h);
+        self.verify_digest_scalar_with_point(parsed_point(public_key)?, e, signature)
+    }
+
+    fn verify_digest_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        if digest.algorithm() != self.digest_alg {
+            return Err(error::Unspecified);
+        }
+        let e = digest_scalar(self.ops.scalar_ops, *digest);
+        self.verify_digest_scalar_with_point(parsed_point(public_key)?, e, signature)
+    }
+}
+
+/// An ECDSA public key that has been parsed and checked to be on the curve.
+pub struct ParsedPublicKey {
+    point: (Elem<R>, Elem<R>),
+}
+
+fn parsed_point(
+    public_key: &signature::parsed::PublicKey,
+) -> Result<&(Elem<R>, Elem<R>), error::Unspecified> {
+    match public_key {
+        signature::parsed::PublicKey::Ecdsa(public_key) => Ok(&public_key.point),
+        _ => Err(error::Unspecified),
     }
 }
 
 impl EcdsaVerificationAlgorithm {
+    /// Returns `signature` with *s* replaced by *n* - *s* if *s* is greater
+    /// than *n* / 2, in the same encoding.
+    ///
+    /// (*r*, *s*) and (*r*, *n* - *s*) are both valid whenever either is, so
+    /// anybody can turn one into the other. This converts signatures that
+    /// were made without `ECDSA_*_LOW_S_SIGNING` into the form that the
+    /// `ECDSA_*_LOW_S` verification algorithms accept. The signature isn't
+    /// verified; this only fails if it isn't well-formed.
+    pub fn normalize_s(
+        &self,
+        signature: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let cops = self.ops.scalar_ops.common;
+        let (r, s) = parse_rs(self.ops.scalar_ops, signature, self.split_rs)?;
+        let s = if scalar_is_high_vartime(cops, &s) {
+            scalar_negated(cops, &s)
+        } else {
+            s
+        };
+        Ok(signature::Signature::new(|sig_bytes| {
+            (self.format_rs)(self.ops.scalar_ops, &r, &s, sig_bytes)
+        }))
+    }
+
     /// This is intentionally not public.
-    fn verify_digest(
+    fn verify_digest_scalar(
         &self,
         public_key: untrusted::Input,
         e: Scalar,
@@ -80,9 +180,6 @@ impl EcdsaVerificationAlgorithm {
     ) -> Result<(), error::Unspecified> {
         // NSA Suite B Implementer's Guide to ECDSA Section 3.4.2.
 
-        let public_key_ops = self.ops.public_key_ops;
-        let scalar_ops = self.ops.scalar_ops;
-
         // NSA Guide Prerequisites:
         //
         //    Prior to accepting a verified digital signature as valid the
@@ -100,7 +197,18 @@ impl EcdsaVerificationAlgorithm {
         // can do. Prerequisite #2 is handled implicitly as the domain
         // parameters are hard-coded into the source. Prerequisite #3 is
         // handled by `parse_uncompressed_point`.
-        let peer_pub_key = parse_uncompressed_point(public_key_ops, public_key)?;
+        let peer_pub_key = parse_uncompressed_point(self.ops.public_key_ops, public_key)?;
+        self.verify_digest_scalar_with_point(&peer_pub_key, e, signature)
+    }
+
+    fn verify_digest_scalar_with_point(
+        &self,
+        peer_pub_key: &(Elem<R>, Elem<R>),
+        e: Scalar,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let public_key_ops = self.ops.public_key_ops;
+        let scalar_ops = self.ops.scalar_ops;
 
         let (r, s) = signature.read_all(error::Unspecified, |input| {
             (self.split_rs)(scalar_ops, input)
@@ -111,6 +219,12 @@ impl EcdsaVerificationAlgorithm {
         let r = scalar_parse_big_endian_variable(public_key_ops.common, limb::AllowZero::No, r)?;
         let s = scalar_parse_big_endian_variable(public_key_ops.common, limb::AllowZero::No, s)?;
 
+        // Reject the second of the two valid signatures (r, s) and (r, -s)
+        // if the policy requires that.
+        if self.require_low_s && scalar_is_high_vartime(public_key_ops.common, &s) {
+            return Err(error::Unspecified);
+        }
+
         // NSA Guide Step 4: "Compute w = s**−1 mod n, using the routine in
         // Appendix B.1."
         let w = scalar_ops.scalar_inv_to_mont(&s);
@@ -123,7 +237,7 @@ impl EcdsaVerificationAlgorithm {
         // NSA Guide Step 6: "Compute the elliptic curve point
         // R = (xR, yR) = u1*G + u2*Q, using EC scalar multiplication and EC
         // addition. If R is equal to the point at infinity, output INVALID."
-        let product = twin_mul(self.ops.private_key_ops, &u1, &u2, &peer_pub_key);
+        let product = twin_mul(self.ops.private_key_ops, &u1, &u2, peer_pub_key);
 
         // Verify that the point we computed is on the curve; see
         // `verify_affine_point_is_on_the_curve_scaled` for details on why. It
@@ -172,7 +286,73 @@ impl EcdsaVerificationAlgorithm {
 
 impl sealed::Sealed for EcdsaVerificationAlgorithm {}
 
-fn split_rs_fixed<'a>(
+/// Converts an ASN.1 DER-encoded ECDSA signature for the curve of `alg` to
+/// the fixed-length (PKCS#11 style) encoding.
+///
+/// Only the curve of `alg` matters, not its encoding or digest algorithm.
+/// Both *r* and *s* must be in the range [1, *n*); the signature isn't
+/// verified.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_FIXED` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub fn ecdsa_asn1_to_fixed(
+    alg: &EcdsaVerificationAlgorithm,
+    signature: &[u8],
+) -> Result<signature::Signature, error::Unspecified> {
+    let scalar_ops = alg.ops.scalar_ops;
+    let (r, s) = parse_rs(scalar_ops, signature, split_rs_asn1)?;
+    Ok(signature::Signature::new(|sig_bytes| {
+        format_rs_fixed(scalar_ops, &r, &s, sig_bytes)
+    }))
+}
+
+/// Converts a fixed-length (PKCS#11 style) ECDSA signature for the curve of
+/// `alg` to the ASN.1 DER encoding.
+///
+/// Only the curve of `alg` matters, not its encoding or digest algorithm.
+/// Both *r* and *s* must be in the range [1, *n*); the signature isn't
+/// verified.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_FIXED` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub fn ecdsa_fixed_to_asn1(
+    alg: &EcdsaVerificationAlgorithm,
+    signature: &[u8],
+) -> Result<signature::Signature, error::Unspecified> {
+    let scalar_ops = alg.ops.scalar_ops;
+    let (r, s) = parse_rs(scalar_ops, signature, split_rs_fixed)?;
+    Ok(signature::Signature::new(|sig_bytes| {
+        format_rs_asn1(scalar_ops, &r, &s, sig_bytes)
+    }))
+}
+
+fn parse_rs(
+    scalar_ops: &'static ScalarOps,
+    signature: &[u8],
+    split_rs: impl for<'a> FnOnce(
+        &'static ScalarOps,
+        &mut untrusted::Reader<'a>,
+    ) -> Result<
+        (untrusted::Input<'a>, untrusted::Input<'a>),
+        error::Unspecified,
+    >,
+) -> Result<(Scalar, Scalar), error::Unspecified> {
+    let cops = scalar_ops.common;
+    let (r, s) = untrusted::Input::from(signature)
+        .read_all(error::Unspecified, |input| split_rs(scalar_ops, input))?;
+    let r = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, r)?;
+    let s = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, s)?;
+    Ok((r, s))
+}
+
+// The type of `split_rs_fixed` and `split_rs_asn1`.
+pub(in crate::ec::suite_b) type SplitRs =
+    for<'a> fn(
+        ops: &'static ScalarOps,
+        input: &mut untrusted::Reader<'a>,
+    ) -> Result<(untrusted::Input<'a>, untrusted::Input<'a>), error::Unspecified>;
+
+pub(in crate::ec::suite_b) fn split_rs_fixed<'a>(
     ops: &'static ScalarOps,
     input: &mut untrusted::Reader<'a>,
 ) -> Result<(untrusted::Input<'a>, untrusted::Input<'a>), error::Unspecified> {
@@ -182,7 +362,7 @@ fn split_rs_fixed<'a>(
     Ok((r, s))
 }
 
-fn split_rs_asn1<'a>(
+pub(in crate::ec::suite_b) fn split_rs_asn1<'a>(
     _ops: &'static ScalarOps,
     input: &mut untrusted::Reader<'a>,
 ) -> Result<(untrusted::Input<'a>, untrusted::Input<'a>), error::Unspecified> {
@@ -193,7 +373,7 @@ fn split_rs_asn1<'a>(
     })
 }
 
-fn twin_mul(
+pub(super) fn twin_mul(
     ops: &PrivateKeyOps,
     g_scalar: &Scalar,
     p_scalar: &Scalar,
@@ -214,6 +394,8 @@ pub static ECDSA_P256_SHA256_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificati
     ops: &p256::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA256,
     split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P256_SHA256_FIXED,
 };
 
@@ -226,9 +408,39 @@ pub static ECDSA_P384_SHA384_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificati
     ops: &p384::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA384,
     split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P384_SHA384_FIXED,
 };
 
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// P-521 curve and SHA-512.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P521_SHA512_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p521::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA512,
+    split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: false,
+    id: AlgorithmID::ECDSA_P521_SHA512_FIXED,
+};
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// secp256k1 curve and SHA-256.
+///
+/// See "`ECDSA_*_FIXED` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &secp256k1::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: false,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED,
+};
+
 /// Verification of ASN.1 DER-encoded ECDSA signatures using the P-256 curve
 /// and SHA-256.
 ///
@@ -238,6 +450,8 @@ pub static ECDSA_P256_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificatio
     ops: &p256::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA256,
     split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P256_SHA256_ASN1,
 };
 
@@ -255,6 +469,8 @@ pub static ECDSA_P256_SHA384_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificatio
     ops: &p256::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA384,
     split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P256_SHA384_ASN1,
 };
 
@@ -272,6 +488,8 @@ pub static ECDSA_P384_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificatio
     ops: &p384::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA256,
     split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P384_SHA256_ASN1,
 };
 
@@ -284,9 +502,161 @@ pub static ECDSA_P384_SHA384_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificatio
     ops: &p384::PUBLIC_SCALAR_OPS,
     digest_alg: &digest::SHA384,
     split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
     id: AlgorithmID::ECDSA_P384_SHA384_ASN1,
 };
 
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the P-521 curve
+/// and SHA-512.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_P521_SHA512_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p521::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA512,
+    split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
+    id: AlgorithmID::ECDSA_P521_SHA512_ASN1,
+};
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the secp256k1
+/// curve and SHA-256.
+///
+/// See "`ECDSA_*_ASN1` Details" in `ring::signature`'s module-level
+/// documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &secp256k1::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: false,
+    id: AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1,
+};
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// P-256 curve and SHA-256, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P256_SHA256_FIXED_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p256::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P256_SHA256_FIXED_LOW_S,
+};
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// P-384 curve and SHA-384, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P384_SHA384_FIXED_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p384::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA384,
+    split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P384_SHA384_FIXED_LOW_S,
+};
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// P-521 curve and SHA-512, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P521_SHA512_FIXED_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p521::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA512,
+    split_rs: split_rs_fixed,
+    format_rs: format_rs_fixed,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P521_SHA512_FIXED_LOW_S,
+};
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the
+/// secp256k1 curve and SHA-256, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_FIXED` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_FIXED_LOW_S: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        ops: &secp256k1::PUBLIC_SCALAR_OPS,
+        digest_alg: &digest::SHA256,
+        split_rs: split_rs_fixed,
+        format_rs: format_rs_fixed,
+        require_low_s: true,
+        id: AlgorithmID::ECDSA_SECP256K1_SHA256_FIXED_LOW_S,
+    };
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the
+/// P-256 curve and SHA-256, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P256_SHA256_ASN1_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p256::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA256,
+    split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P256_SHA256_ASN1_LOW_S,
+};
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the
+/// P-384 curve and SHA-384, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P384_SHA384_ASN1_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p384::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA384,
+    split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P384_SHA384_ASN1_LOW_S,
+};
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the
+/// P-521 curve and SHA-512, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_P521_SHA512_ASN1_LOW_S: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    ops: &p521::PUBLIC_SCALAR_OPS,
+    digest_alg: &digest::SHA512,
+    split_rs: split_rs_asn1,
+    format_rs: format_rs_asn1,
+    require_low_s: true,
+    id: AlgorithmID::ECDSA_P521_SHA512_ASN1_LOW_S,
+};
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the
+/// secp256k1 curve and SHA-256, rejecting signatures where *s* is greater than
+/// *n* / 2.
+///
+/// See "`ECDSA_*_ASN1` Details" and "`ECDSA_*_LOW_S` Details" in
+/// `ring::signature`'s module-level documentation for more details.
+pub static ECDSA_SECP256K1_SHA256_ASN1_LOW_S: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        ops: &secp256k1::PUBLIC_SCALAR_OPS,
+        digest_alg: &digest::SHA256,
+        split_rs: split_rs_asn1,
+        format_rs: format_rs_asn1,
+        require_low_s: true,
+        id: AlgorithmID::ECDSA_SECP256K1_SHA256_ASN1_LOW_S,
+    };
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,6 +693,7 @@ mod tests {
                 let alg = match curve_name.as_str() {
                     "P-256" => &ECDSA_P256_SHA256_FIXED,
                     "P-384" => &ECDSA_P384_SHA384_FIXED,
+                    "P-521" => &ECDSA_P521_SHA512_FIXED,
                     _ => {
                         panic!("Unsupported curve: {}", curve_name);
                     }
@@ -332,7 +703,7 @@ mod tests {
                     alg.ops.scalar_ops,
                     &digest[..],
                 );
-                let actual_result = alg.verify_digest(
+                let actual_result = alg.verify_digest_scalar(
                     untrusted::Input::from(&public_key[..]),
                     digest,
                     untrusted::Input::from(&sig[..]),
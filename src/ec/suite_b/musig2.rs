@@ -0,0 +1,633 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! MuSig2 multi-signatures over secp256k1, as specified in BIP-327.
+
+use super::{
+    ops::*,
+    private_key::{random_scalar, scalar_from_big_endian_bytes},
+    schnorr::*,
+};
+use crate::{arithmetic::montgomery::*, digest, error, limb, polyfill, rand, signature};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+/// The length of an encoded individual public key, which is a compressed
+/// point.
+pub const PUBLIC_KEY_LEN: usize = 1 + ELEM_LEN;
+
+/// The length of an encoded aggregated public key, which is a BIP-340 public
+/// key.
+pub const AGGREGATED_PUBLIC_KEY_LEN: usize = ELEM_LEN;
+
+/// The length of an encoded public nonce or aggregated nonce.
+pub const NONCE_LEN: usize = 2 * PUBLIC_KEY_LEN;
+
+/// The length of an encoded partial signature.
+pub const PARTIAL_SIGNATURE_LEN: usize = ELEM_LEN;
+
+/// A signer's secp256k1 key pair.
+pub struct KeyPair {
+    private_key: Scalar,
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(KeyPair, stringify!(KeyPair), public_key);
+
+impl KeyPair {
+    /// Generates a new key pair.
+    pub fn generate(rng: &dyn rand::SecureRandom) -> Result<Self, error::Unspecified> {
+        let private_key = random_scalar(&secp256k1::PRIVATE_KEY_OPS, rng)?;
+        Self::new(private_key)
+    }
+
+    /// Constructs a key pair from a private key, the 32-byte big-endian
+    /// encoding of an integer in the range [1, n).
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self, error::KeyRejected> {
+        if private_key.len() != ELEM_LEN {
+            return Err(error::KeyRejected::invalid_encoding());
+        }
+        let private_key = scalar_from_big_endian_bytes(&secp256k1::PRIVATE_KEY_OPS, private_key)
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_component())?;
+        Self::new(private_key).map_err(|error::Unspecified| error::KeyRejected::unexpected_error())
+    }
+
+    fn new(private_key: Scalar) -> Result<Self, error::Unspecified> {
+        let public_key = affine(&point_mul_base(&private_key))?;
+        Ok(Self {
+            private_key,
+            public_key: PublicKey(compressed_bytes(&public_key)),
+        })
+    }
+
+    /// Round one: generates a fresh secret nonce and the public nonce to send
+    /// to the other signers.
+    ///
+    /// `msg` is the message to be signed, if it is already known. Mixing it
+    /// and `key_agg_ctx` into the nonce limits the damage that a broken `rng`
+    /// can do. `Session::sign()` consumes the secret nonce, so that it can't
+    /// be used twice. This is BIP-327's `NonceGen` with the optional
+    /// arguments other than `extra_in`.
+    pub fn generate_nonce(
+        &self,
+        key_agg_ctx: &KeyAggContext,
+        msg: Option<&[u8]>,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(SecretNonce, PublicNonce), error::Unspecified> {
+        let random_bytes: [u8; ELEM_LEN] = rand::generate(rng)?.expose();
+        let mut rand = scalar_bytes(&self.private_key);
+        let aux = {
+            let mut ctx = tagged_hash(b"MuSig/aux");
+            ctx.update(&random_bytes);
+            ctx.finish()
+        };
+        rand.iter_mut()
+            .zip(aux.as_ref())
+            .for_each(|(r, aux)| *r ^= aux);
+
+        let nonce = |i: u8| {
+            let mut ctx = tagged_hash(b"MuSig/nonce");
+            ctx.update(&rand);
+            ctx.update(&[PUBLIC_KEY_LEN as u8]);
+            ctx.update(self.public_key.as_ref());
+            ctx.update(&[AGGREGATED_PUBLIC_KEY_LEN as u8]);
+            ctx.update(key_agg_ctx.aggregated_public_key());
+            match msg {
+                None => ctx.update(&[0]),
+                Some(msg) => {
+                    ctx.update(&[1]);
+                    ctx.update(&polyfill::u64_from_usize(msg.len()).to_be_bytes());
+                    ctx.update(msg);
+                }
+            }
+            ctx.update(&0u32.to_be_bytes()); // The empty `extra_in`.
+            ctx.update(&[i]);
+            hash_to_scalar(ctx)
+        };
+        let k1 = nonce(0);
+        let k2 = nonce(1);
+        let cops = &secp256k1::COMMON_OPS;
+        if cops.is_zero(&k1) || cops.is_zero(&k2) {
+            return Err(error::Unspecified);
+        }
+
+        let mut public_nonce = [0; NONCE_LEN];
+        let (r1, r2) = public_nonce.split_at_mut(PUBLIC_KEY_LEN);
+        r1.copy_from_slice(&compressed_bytes(&affine(&point_mul_base(&k1))?));
+        r2.copy_from_slice(&compressed_bytes(&affine(&point_mul_base(&k2))?));
+        let public_nonce = PublicNonce(public_nonce);
+        let secret_nonce = SecretNonce {
+            k1,
+            k2,
+            public_key: self.public_key,
+            public_nonce,
+        };
+        Ok((secret_nonce, public_nonce))
+    }
+}
+
+impl signature::KeyPair for KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+/// A signer's public key, in the compressed encoding.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PublicKey([u8; PUBLIC_KEY_LEN]);
+
+impl PublicKey {
+    /// Parses a compressed secp256k1 public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::KeyRejected> {
+        let bytes: [u8; PUBLIC_KEY_LEN] = bytes
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let _ = parse_point(&bytes)
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+/// The aggregated public key of a list of signers, with any tweaks applied
+/// to it.
+///
+/// This is BIP-327's `KeyAggContext`.
+pub struct KeyAggContext {
+    public_keys: Vec<PublicKey>,
+    list_hash: digest::Digest,
+    second_key: Option<PublicKey>,
+    q: AffinePoint,
+    aggregated_public_key: [u8; AGGREGATED_PUBLIC_KEY_LEN],
+    gacc_is_negated: bool,
+    tacc: Scalar,
+}
+
+derive_debug_via_field!(KeyAggContext, stringify!(KeyAggContext), public_keys);
+
+impl KeyAggContext {
+    /// Aggregates `public_keys`.
+    ///
+    /// The order of the keys matters, so the signers must agree on it, e.g.
+    /// by sorting the keys. This is BIP-327's `KeyAgg`.
+    pub fn new(public_keys: &[PublicKey]) -> Result<Self, error::Unspecified> {
+        let first = public_keys.first().ok_or(error::Unspecified)?;
+        let second_key = public_keys.iter().find(|pk| *pk != first).copied();
+        let list_hash = {
+            let mut ctx = tagged_hash(b"KeyAgg list");
+            public_keys.iter().for_each(|pk| ctx.update(pk.as_ref()));
+            ctx.finish()
+        };
+
+        let cops = &secp256k1::COMMON_OPS;
+        let mut q = Point::new_at_infinity();
+        for pk in public_keys {
+            let a = key_agg_coefficient(&list_hash, second_key.as_ref(), pk);
+            q = cops.point_sum(&q, &point_mul(&a, &parse_point(&pk.0)?));
+        }
+        let q = affine(&q)?;
+
+        Ok(Self {
+            public_keys: public_keys.to_vec(),
+            list_hash,
+            second_key,
+            aggregated_public_key: x_bytes(&q.0),
+            q,
+            gacc_is_negated: false,
+            tacc: Scalar::zero(),
+        })
+    }
+
+    /// The aggregated public key, which is a BIP-340 public key.
+    pub fn aggregated_public_key(&self) -> &[u8] {
+        &self.aggregated_public_key
+    }
+
+    /// Adds `tweak` times the generator to the aggregated public key, as
+    /// BIP-32 derivation does.
+    ///
+    /// `tweak` is a 32-byte big-endian integer less than n. This is
+    /// BIP-327's `ApplyTweak` for a plain tweak.
+    pub fn apply_plain_tweak(&mut self, tweak: &[u8]) -> Result<(), error::Unspecified> {
+        self.apply_tweak(tweak, false)
+    }
+
+    /// Like `apply_plain_tweak()`, but first negates the aggregated public key
+    /// if its *y* coordinate is odd, as BIP-341 Taproot tweaking does.
+    ///
+    /// This is BIP-327's `ApplyTweak` for an x-only tweak.
+    pub fn apply_x_only_tweak(&mut self, tweak: &[u8]) -> Result<(), error::Unspecified> {
+        self.apply_tweak(tweak, true)
+    }
+
+    fn apply_tweak(&mut self, tweak: &[u8], is_x_only: bool) -> Result<(), error::Unspecified> {
+        let cops = &secp256k1::COMMON_OPS;
+        let t = scalar_parse(tweak)?;
+        let negate = is_x_only && !has_even_y(&self.q.1);
+        let (x, y) = &self.q;
+        let y = if negate { cops.elem_negated(y) } else { *y };
+        let q = cops.point_sum(&cops.point_from_affine(x, &y), &point_mul_base(&t));
+        let q = affine(&q)?;
+        let tacc = if negate {
+            scalar_negated(cops, &self.tacc)
+        } else {
+            self.tacc
+        };
+
+        self.aggregated_public_key = x_bytes(&q.0);
+        self.q = q;
+        self.gacc_is_negated ^= negate;
+        self.tacc = scalar_sum(cops, &t, &tacc);
+        Ok(())
+    }
+
+    // BIP-327's `GetSessionKeyAggCoeff`.
+    fn coefficient(&self, public_key: &PublicKey) -> Result<Scalar, error::Unspecified> {
+        if !self.public_keys.contains(public_key) {
+            return Err(error::Unspecified);
+        }
+        Ok(key_agg_coefficient(
+            &self.list_hash,
+            self.second_key.as_ref(),
+            public_key,
+        ))
+    }
+
+    // Whether g * gacc is -1 (mod n) rather than 1, where g is -1 when the
+    // aggregated public key has an odd y coordinate.
+    fn negate_private_keys(&self) -> bool {
+        !has_even_y(&self.q.1) ^ self.gacc_is_negated
+    }
+}
+
+// BIP-327's `KeyAggCoeffInternal`.
+fn key_agg_coefficient(
+    list_hash: &digest::Digest,
+    second_key: Option<&PublicKey>,
+    public_key: &PublicKey,
+) -> Scalar {
+    if second_key == Some(public_key) {
+        return scalar_one();
+    }
+    let mut ctx = tagged_hash(b"KeyAgg coefficient");
+    ctx.update(list_hash.as_ref());
+    ctx.update(public_key.as_ref());
+    hash_to_scalar(ctx)
+}
+
+/// A signer's secret nonce from round one. It must only be used once.
+pub struct SecretNonce {
+    k1: Scalar,
+    k2: Scalar,
+    public_key: PublicKey,
+    public_nonce: PublicNonce,
+}
+
+derive_debug_via_field!(SecretNonce, stringify!(SecretNonce), public_nonce);
+
+impl SecretNonce {
+    /// The public nonce for this secret nonce.
+    pub fn public_nonce(&self) -> &PublicNonce {
+        &self.public_nonce
+    }
+}
+
+/// A signer's public nonce from round one.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PublicNonce([u8; NONCE_LEN]);
+
+impl PublicNonce {
+    /// Parses a public nonce, which is two compressed points.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let bytes: [u8; NONCE_LEN] = bytes.try_into()?;
+        let _ = parse_point(&bytes[..PUBLIC_KEY_LEN])?;
+        let _ = parse_point(&bytes[PUBLIC_KEY_LEN..])?;
+        Ok(Self(bytes))
+    }
+}
+
+impl AsRef<[u8]> for PublicNonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicNonce);
+
+/// The aggregate of the public nonces of all the signers.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AggregatedNonce([u8; NONCE_LEN]);
+
+impl AggregatedNonce {
+    /// Parses an aggregated nonce, which is two compressed points, either of
+    /// which may be 33 zero bytes for the point at infinity.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let bytes: [u8; NONCE_LEN] = bytes.try_into()?;
+        let _ = parse_point_ext(&bytes[..PUBLIC_KEY_LEN])?;
+        let _ = parse_point_ext(&bytes[PUBLIC_KEY_LEN..])?;
+        Ok(Self(bytes))
+    }
+}
+
+impl AsRef<[u8]> for AggregatedNonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(AggregatedNonce);
+
+/// Aggregates the public nonces from round one of all the signers.
+///
+/// Anybody can do this, e.g. a coordinator that then sends the aggregated
+/// nonce to all the signers. This is BIP-327's `NonceAgg`.
+pub fn aggregate_nonces(
+    public_nonces: &[PublicNonce],
+) -> Result<AggregatedNonce, error::Unspecified> {
+    if public_nonces.is_empty() {
+        return Err(error::Unspecified);
+    }
+    let cops = &secp256k1::COMMON_OPS;
+    let mut aggregated_nonce = [0; NONCE_LEN];
+    for (j, out) in aggregated_nonce.chunks_mut(PUBLIC_KEY_LEN).enumerate() {
+        let mut sum = Point::new_at_infinity();
+        for public_nonce in public_nonces {
+            let (x, y) = parse_point(&public_nonce.0[(j * PUBLIC_KEY_LEN)..][..PUBLIC_KEY_LEN])?;
+            sum = cops.point_sum(&sum, &cops.point_from_affine(&x, &y));
+        }
+        if !is_infinity(&sum) {
+            out.copy_from_slice(&compressed_bytes(&affine(&sum)?));
+        }
+    }
+    Ok(AggregatedNonce(aggregated_nonce))
+}
+
+/// A signer's share of a signature, from round two.
+#[derive(Clone, Copy)]
+pub struct PartialSignature([u8; PARTIAL_SIGNATURE_LEN]);
+
+impl PartialSignature {
+    /// Parses a partial signature.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let bytes: [u8; PARTIAL_SIGNATURE_LEN] = bytes.try_into()?;
+        let _ = scalar_parse(&bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl AsRef<[u8]> for PartialSignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PartialSignature);
+
+/// The values that the signers of a message with an aggregated nonce share in
+/// round two.
+///
+/// This is BIP-327's session context.
+pub struct Session<'a> {
+    key_agg_ctx: &'a KeyAggContext,
+    msg: &'a [u8],
+    b: Scalar,
+    r: [u8; ELEM_LEN],
+    r_has_even_y: bool,
+    e: Scalar,
+}
+
+derive_debug_via_field!(Session<'_>, stringify!(Session), key_agg_ctx);
+
+impl<'a> Session<'a> {
+    /// Starts round two of signing `msg` with the aggregated public key of
+    /// `key_agg_ctx`.
+    ///
+    /// This is BIP-327's `GetSessionValues`.
+    pub fn new(
+        key_agg_ctx: &'a KeyAggContext,
+        aggregated_nonce: &AggregatedNonce,
+        msg: &'a [u8],
+    ) -> Result<Self, error::Unspecified> {
+        let cops = &secp256k1::COMMON_OPS;
+        let q = key_agg_ctx.aggregated_public_key();
+
+        let b = {
+            let mut ctx = tagged_hash(b"MuSig/noncecoef");
+            ctx.update(aggregated_nonce.as_ref());
+            ctx.update(q);
+            ctx.update(msg);
+            hash_to_scalar(ctx)
+        };
+
+        let (r1, r2) = aggregated_nonce.0.split_at(PUBLIC_KEY_LEN);
+        let mut r = Point::new_at_infinity();
+        if let Some((x, y)) = parse_point_ext(r1)? {
+            r = cops.point_from_affine(&x, &y);
+        }
+        if let Some(r2) = parse_point_ext(r2)? {
+            r = cops.point_sum(&r, &point_mul(&b, &r2));
+        }
+        // Use the generator if the nonces of the signers cancel out.
+        if is_infinity(&r) {
+            r = point_mul_base(&scalar_one());
+        }
+        let (r_x, r_y) = affine(&r)?;
+        let r = x_bytes(&r_x);
+        let e = challenge(&r, q, msg);
+
+        Ok(Self {
+            key_agg_ctx,
+            msg,
+            b,
+            r,
+            r_has_even_y: has_even_y(&r_y),
+            e,
+        })
+    }
+
+    /// Round two: computes the signer's partial signature, using the secret
+    /// nonce from round one.
+    ///
+    /// The signer's public key must be one of those in the `KeyAggContext`.
+    /// This is BIP-327's `Sign`.
+    pub fn sign(
+        &self,
+        key_pair: &KeyPair,
+        secret_nonce: SecretNonce,
+    ) -> Result<PartialSignature, error::Unspecified> {
+        if secret_nonce.public_key != key_pair.public_key {
+            return Err(error::Unspecified);
+        }
+        let cops = &secp256k1::COMMON_OPS;
+        let a = self.key_agg_ctx.coefficient(&key_pair.public_key)?;
+
+        let (k1, k2) = if self.r_has_even_y {
+            (secret_nonce.k1, secret_nonce.k2)
+        } else {
+            (
+                scalar_negated(cops, &secret_nonce.k1),
+                scalar_negated(cops, &secret_nonce.k2),
+            )
+        };
+        let d = if self.key_agg_ctx.negate_private_keys() {
+            scalar_negated(cops, &key_pair.private_key)
+        } else {
+            key_pair.private_key
+        };
+
+        // s = k1 + b*k2 + e*a*d.
+        let s = scalar_sum(cops, &k1, &scalar_mul(&self.b, &k2));
+        let s = scalar_sum(cops, &s, &scalar_mul(&scalar_mul(&self.e, &a), &d));
+        Ok(PartialSignature(scalar_bytes(&s)))
+    }
+
+    /// Verifies that `partial_signature` is a valid partial signature by the
+    /// signer with the given `public_key` and `public_nonce`, so that a
+    /// misbehaving signer can be identified when `aggregate()` fails.
+    ///
+    /// This is BIP-327's `PartialSigVerify`.
+    pub fn verify_partial_signature(
+        &self,
+        public_key: &PublicKey,
+        public_nonce: &PublicNonce,
+        partial_signature: &PartialSignature,
+    ) -> Result<(), error::Unspecified> {
+        let cops = &secp256k1::COMMON_OPS;
+        let s = scalar_parse(&partial_signature.0)?;
+        let a = self.key_agg_ctx.coefficient(public_key)?;
+        let p = parse_point(&public_key.0)?;
+        let (r1_x, r1_y) = parse_point(&public_nonce.0[..PUBLIC_KEY_LEN])?;
+        let r2 = parse_point(&public_nonce.0[PUBLIC_KEY_LEN..])?;
+
+        // s*G == Re + e*a*g'*P, where Re = +/-(R1 + b*R2).
+        let mut re = cops.point_sum(
+            &cops.point_from_affine(&r1_x, &r1_y),
+            &point_mul(&self.b, &r2),
+        );
+        if !self.r_has_even_y {
+            re = cops.point_negated(&re);
+        }
+        let mut ea = scalar_mul(&self.e, &a);
+        if self.key_agg_ctx.negate_private_keys() {
+            ea = scalar_negated(cops, &ea);
+        }
+        let expected = cops.point_sum(&re, &point_mul(&ea, &p));
+        let difference = cops.point_sum(&point_mul_base(&s), &cops.point_negated(&expected));
+        if !is_infinity(&difference) {
+            return Err(error::Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Aggregates the partial signatures of all the signers into a BIP-340
+    /// signature of the message by the aggregated public key.
+    ///
+    /// The signature is verified before it is returned; if that fails then
+    /// `verify_partial_signature()` identifies the bad partial signatures.
+    /// This is BIP-327's `PartialSigAgg`.
+    pub fn aggregate(
+        &self,
+        partial_signatures: &[PartialSignature],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        if partial_signatures.is_empty() {
+            return Err(error::Unspecified);
+        }
+        let cops = &secp256k1::COMMON_OPS;
+        let mut s = Scalar::zero();
+        for partial_signature in partial_signatures {
+            s = scalar_sum(cops, &s, &scalar_parse(&partial_signature.0)?);
+        }
+
+        // s += e*g*tacc.
+        let mut e_tacc = scalar_mul(&self.e, &self.key_agg_ctx.tacc);
+        if !has_even_y(&self.key_agg_ctx.q.1) {
+            e_tacc = scalar_negated(cops, &e_tacc);
+        }
+        let s = scalar_sum(cops, &s, &e_tacc);
+
+        let signature = signature::Signature::new(|buf| {
+            let (r_out, s_out) = buf.split_at_mut(ELEM_LEN);
+            r_out.copy_from_slice(&self.r);
+            s_out[..ELEM_LEN].copy_from_slice(&scalar_bytes(&s));
+            2 * ELEM_LEN
+        });
+        verify(
+            self.key_agg_ctx.aggregated_public_key(),
+            self.msg,
+            signature.as_ref(),
+        )?;
+        Ok(signature)
+    }
+}
+
+// BIP-327's `cpoint`.
+fn parse_point(bytes: &[u8]) -> Result<AffinePoint, error::Unspecified> {
+    let (&prefix, x) = bytes.split_first().ok_or(error::Unspecified)?;
+    let (x, y) = lift_x(x)?;
+    match prefix {
+        2 => Ok((x, y)),
+        3 => Ok((x, secp256k1::COMMON_OPS.elem_negated(&y))),
+        _ => Err(error::Unspecified),
+    }
+}
+
+// BIP-327's `cpoint_ext`, which maps 33 zero bytes to the point at infinity.
+fn parse_point_ext(bytes: &[u8]) -> Result<Option<AffinePoint>, error::Unspecified> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Ok(None);
+    }
+    parse_point(bytes).map(Some)
+}
+
+// BIP-327's `cbytes`.
+fn compressed_bytes((x, y): &AffinePoint) -> [u8; PUBLIC_KEY_LEN] {
+    let mut out = [0; PUBLIC_KEY_LEN];
+    out[0] = if has_even_y(y) { 2 } else { 3 };
+    out[1..].copy_from_slice(&x_bytes(x));
+    out
+}
+
+// The big-endian encoding of the field element `x`.
+fn x_bytes(x: &Elem<R>) -> [u8; ELEM_LEN] {
+    let cops = &secp256k1::COMMON_OPS;
+    let mut out = [0; ELEM_LEN];
+    limb::big_endian_from_limbs(&cops.elem_unencoded(x).limbs[..cops.num_limbs], &mut out);
+    out
+}
+
+fn scalar_bytes(s: &Scalar) -> [u8; ELEM_LEN] {
+    let mut out = [0; ELEM_LEN];
+    limb::big_endian_from_limbs(&s.limbs[..secp256k1::COMMON_OPS.num_limbs], &mut out);
+    out
+}
+
+fn scalar_one() -> Scalar {
+    let mut bytes = [0; ELEM_LEN];
+    bytes[ELEM_LEN - 1] = 1;
+    scalar_parse(&bytes).unwrap()
+}
+
+fn scalar_mul(a: &Scalar, b: &Scalar) -> Scalar {
+    let ops = &secp256k1::SCALAR_OPS;
+    let a = ops.scalar_product(a, &secp256k1::PRIVATE_SCALAR_OPS.oneRR_mod_n);
+    ops.scalar_product(&a, b)
+}
@@ -33,6 +33,7 @@ pub type Scalar<E = Unencoded> = elem::Elem<N, E>;
 #[derive(Clone, Copy)]
 pub enum N {}
 
+#[derive(Clone)]
 pub struct Point {
     // The coordinates are stored in a contiguous array, where the first
     // `ops.num_limbs` elements are the X coordinate, the next
@@ -51,7 +52,7 @@ impl Point {
 }
 
 static ONE: Elem<Unencoded> = Elem {
-    limbs: limbs![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    limbs: limbs![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
     m: PhantomData,
     encoding: PhantomData,
 };
@@ -59,6 +60,7 @@ static ONE: Elem<Unencoded> = Elem {
 /// Operations and values needed by all curve operations.
 pub struct CommonOps {
     pub num_limbs: usize,
+    pub order_bits: usize,
     q: Modulus,
     pub n: Elem<Unencoded>,
 
@@ -70,9 +72,16 @@ pub struct CommonOps {
     elem_sqr_mont: unsafe extern "C" fn(r: *mut Limb, a: *const Limb),
 
     point_add_jacobian_impl: unsafe extern "C" fn(r: *mut Limb, a: *const Limb, b: *const Limb),
+    point_double_jacobian_impl: unsafe extern "C" fn(r: *mut Limb, a: *const Limb),
 }
 
 impl CommonOps {
+    /// The length of a serialized field element or scalar, in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.order_bits.div_ceil(8)
+    }
+
     #[inline]
     pub fn elem_add<E: Encoding>(&self, a: &mut Elem<E>, b: &Elem<E>) {
         let num_limbs = self.num_limbs;
@@ -93,6 +102,18 @@ impl CommonOps {
         self.elem_product(a, &ONE)
     }
 
+    #[inline]
+    pub fn elem_to_mont(&self, a: &Elem<Unencoded>) -> Elem<R> {
+        let mut r = Elem::zero();
+        unsafe { (self.elem_mul_mont)(r.limbs.as_mut_ptr(), a.limbs.as_ptr(), self.q.rr.as_ptr()) }
+        r
+    }
+
+    #[inline]
+    pub fn elem_negated(&self, a: &Elem<R>) -> Elem<R> {
+        negated(self, a, &self.q.p)
+    }
+
     #[inline]
     pub fn elem_mul(&self, a: &mut Elem<R>, b: &Elem<R>) {
         binary_op_assign(self.elem_mul_mont, a, b)
@@ -141,6 +162,32 @@ impl CommonOps {
         r
     }
 
+    pub fn point_doubled(&self, a: &Point) -> Point {
+        let mut r = Point::new_at_infinity();
+        unsafe { (self.point_double_jacobian_impl)(r.xyz.as_mut_ptr(), a.xyz.as_ptr()) }
+        r
+    }
+
+    /// Returns the point with affine coordinates (`x`, `y`) in Jacobian
+    /// coordinates, with *z* = 1.
+    pub fn point_from_affine(&self, x: &Elem<R>, y: &Elem<R>) -> Point {
+        let num_limbs = self.num_limbs;
+        let one = self.elem_to_mont(&ONE);
+        let mut r = Point::new_at_infinity();
+        r.xyz[..num_limbs].copy_from_slice(&x.limbs[..num_limbs]);
+        r.xyz[num_limbs..(2 * num_limbs)].copy_from_slice(&y.limbs[..num_limbs]);
+        r.xyz[(2 * num_limbs)..(3 * num_limbs)].copy_from_slice(&one.limbs[..num_limbs]);
+        r
+    }
+
+    pub fn point_negated(&self, p: &Point) -> Point {
+        let num_limbs = self.num_limbs;
+        let y = self.elem_negated(&self.point_y(p));
+        let mut r = Point { xyz: p.xyz };
+        r.xyz[num_limbs..(2 * num_limbs)].copy_from_slice(&y.limbs[..num_limbs]);
+        r
+    }
+
     pub fn point_x(&self, p: &Point) -> Elem<R> {
         let mut r = Elem::zero();
         r.limbs[..self.num_limbs].copy_from_slice(&p.xyz[0..self.num_limbs]);
@@ -218,19 +265,9 @@ impl PublicKeyOps {
     // implements NIST SP 800-56A Step 2: "Verify that xQ and yQ are integers
     // in the interval [0, p-1] in the case that q is an odd prime p[.]"
     pub fn elem_parse(&self, input: &mut untrusted::Reader) -> Result<Elem<R>, error::Unspecified> {
-        let encoded_value = input.read_bytes(self.common.num_limbs * LIMB_BYTES)?;
+        let encoded_value = input.read_bytes(self.common.len())?;
         let parsed = elem_parse_big_endian_fixed_consttime(self.common, encoded_value)?;
-        let mut r = Elem::zero();
-        // Montgomery encode (elem_to_mont).
-        // TODO: do something about this.
-        unsafe {
-            (self.common.elem_mul_mont)(
-                r.limbs.as_mut_ptr(),
-                parsed.limbs.as_ptr(),
-                self.common.q.rr.as_ptr(),
-            )
-        }
-        Ok(r)
+        Ok(self.common.elem_to_mont(&parsed))
     }
 }
 
@@ -246,7 +283,7 @@ pub struct ScalarOps {
 impl ScalarOps {
     // The (maximum) length of a scalar, not including any padding.
     pub fn scalar_bytes_len(&self) -> usize {
-        self.common.num_limbs * LIMB_BYTES
+        self.common.len()
     }
 
     /// Returns the modular inverse of `a` (mod `n`). Panics of `a` is zero,
@@ -335,6 +372,38 @@ pub fn scalar_sum(ops: &CommonOps, a: &Scalar, b: &Scalar) -> Scalar {
     r
 }
 
+pub fn scalar_negated(ops: &CommonOps, a: &Scalar) -> Scalar {
+    negated(ops, a, &ops.n.limbs)
+}
+
+/// Returns whether `a` is greater than `n / 2`, i.e. whether `-a` (mod n) is
+/// less than `a`. This is not constant-time.
+pub fn scalar_is_high_vartime(ops: &CommonOps, a: &Scalar) -> bool {
+    let num_limbs = ops.num_limbs;
+    let negated = scalar_negated(ops, a);
+    limbs_less_than_limbs_vartime(&negated.limbs[..num_limbs], &a.limbs[..num_limbs])
+}
+
+// Returns -`a` (mod `m`). `a` must be less than `m`.
+fn negated<M, E: Encoding>(
+    ops: &CommonOps,
+    a: &elem::Elem<M, E>,
+    m: &[Limb; MAX_LIMBS],
+) -> elem::Elem<M, E> {
+    let zero = elem::Elem::<M, E>::zero();
+    let mut r = elem::Elem::zero();
+    unsafe {
+        LIMBS_sub_mod(
+            r.limbs.as_mut_ptr(),
+            zero.limbs.as_ptr(),
+            a.limbs.as_ptr(),
+            m.as_ptr(),
+            ops.num_limbs,
+        )
+    }
+    r
+}
+
 // Returns (`a` squared `squarings` times) * `b`.
 fn elem_sqr_mul(ops: &CommonOps, a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
     debug_assert!(squarings >= 1);
@@ -407,7 +476,7 @@ fn parse_big_endian_fixed_consttime<M>(
     allow_zero: AllowZero,
     max_exclusive: &[Limb],
 ) -> Result<elem::Elem<M, Unencoded>, error::Unspecified> {
-    if bytes.len() != ops.num_limbs * LIMB_BYTES {
+    if bytes.len() != ops.len() {
         return Err(error::Unspecified);
     }
     let mut r = elem::Elem::zero();
@@ -428,6 +497,13 @@ prefixed_extern! {
         m: *const Limb,
         num_limbs: c::size_t,
     );
+    fn LIMBS_sub_mod(
+        r: *mut Limb,
+        a: *const Limb,
+        b: *const Limb,
+        m: *const Limb,
+        num_limbs: c::size_t,
+    );
 }
 
 #[cfg(test)]
@@ -459,6 +535,21 @@ mod tests {
         q_minus_n_plus_n_equals_0_test(&p384::PUBLIC_SCALAR_OPS);
     }
 
+    #[test]
+    fn p521_q_minus_n_plus_n_equals_0_test() {
+        q_minus_n_plus_n_equals_0_test(&p521::PUBLIC_SCALAR_OPS);
+    }
+
+    #[test]
+    fn secp256k1_q_minus_n_plus_n_equals_0_test() {
+        q_minus_n_plus_n_equals_0_test(&secp256k1::PUBLIC_SCALAR_OPS);
+    }
+
+    #[test]
+    fn sm2_q_minus_n_plus_n_equals_0_test() {
+        q_minus_n_plus_n_equals_0_test(&sm2::PUBLIC_SCALAR_OPS);
+    }
+
     #[test]
     fn p256_elem_add_test() {
         elem_add_test(
@@ -475,6 +566,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_elem_add_test() {
+        elem_add_test(
+            &p521::PUBLIC_SCALAR_OPS,
+            test_file!("ops/p521_elem_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_elem_add_test() {
+        elem_add_test(
+            &secp256k1::PUBLIC_SCALAR_OPS,
+            test_file!("ops/secp256k1_elem_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_elem_add_test() {
+        elem_add_test(
+            &sm2::PUBLIC_SCALAR_OPS,
+            test_file!("ops/sm2_elem_sum_tests.txt"),
+        );
+    }
+
     fn elem_add_test(ops: &PublicScalarOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -511,6 +626,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_elem_sub_test() {
+        prefixed_extern! {
+            fn p521_elem_sub(r: *mut Limb, a: *const Limb, b: *const Limb);
+        }
+        elem_sub_test(
+            &p521::COMMON_OPS,
+            p521_elem_sub,
+            test_file!("ops/p521_elem_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_elem_sub_test() {
+        prefixed_extern! {
+            fn secp256k1_elem_sub(r: *mut Limb, a: *const Limb, b: *const Limb);
+        }
+        elem_sub_test(
+            &secp256k1::COMMON_OPS,
+            secp256k1_elem_sub,
+            test_file!("ops/secp256k1_elem_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_elem_sub_test() {
+        prefixed_extern! {
+            fn sm2_elem_sub(r: *mut Limb, a: *const Limb, b: *const Limb);
+        }
+        elem_sub_test(
+            &sm2::COMMON_OPS,
+            sm2_elem_sub,
+            test_file!("ops/sm2_elem_sum_tests.txt"),
+        );
+    }
+
     fn elem_sub_test(
         ops: &CommonOps,
         elem_sub: unsafe extern "C" fn(r: *mut Limb, a: *const Limb, b: *const Limb),
@@ -562,6 +713,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_elem_div_by_2_test() {
+        prefixed_extern! {
+            fn p521_elem_div_by_2(r: *mut Limb, a: *const Limb);
+        }
+        elem_div_by_2_test(
+            &p521::COMMON_OPS,
+            p521_elem_div_by_2,
+            test_file!("ops/p521_elem_div_by_2_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_elem_div_by_2_test() {
+        prefixed_extern! {
+            fn secp256k1_elem_div_by_2(r: *mut Limb, a: *const Limb);
+        }
+        elem_div_by_2_test(
+            &secp256k1::COMMON_OPS,
+            secp256k1_elem_div_by_2,
+            test_file!("ops/secp256k1_elem_div_by_2_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_elem_div_by_2_test() {
+        prefixed_extern! {
+            fn sm2_elem_div_by_2(r: *mut Limb, a: *const Limb);
+        }
+        elem_div_by_2_test(
+            &sm2::COMMON_OPS,
+            sm2_elem_div_by_2,
+            test_file!("ops/sm2_elem_div_by_2_tests.txt"),
+        );
+    }
+
     fn elem_div_by_2_test(
         ops: &CommonOps,
         elem_div_by_2: unsafe extern "C" fn(r: *mut Limb, a: *const Limb),
@@ -609,6 +796,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_elem_neg_test() {
+        prefixed_extern! {
+            fn p521_elem_neg(r: *mut Limb, a: *const Limb);
+        }
+        elem_neg_test(
+            &p521::COMMON_OPS,
+            p521_elem_neg,
+            test_file!("ops/p521_elem_neg_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_elem_neg_test() {
+        prefixed_extern! {
+            fn secp256k1_elem_neg(r: *mut Limb, a: *const Limb);
+        }
+        elem_neg_test(
+            &secp256k1::COMMON_OPS,
+            secp256k1_elem_neg,
+            test_file!("ops/secp256k1_elem_neg_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_elem_neg_test() {
+        prefixed_extern! {
+            fn sm2_elem_neg(r: *mut Limb, a: *const Limb);
+        }
+        elem_neg_test(
+            &sm2::COMMON_OPS,
+            sm2_elem_neg,
+            test_file!("ops/sm2_elem_neg_tests.txt"),
+        );
+    }
+
     fn elem_neg_test(
         ops: &CommonOps,
         elem_neg: unsafe extern "C" fn(r: *mut Limb, a: *const Limb),
@@ -652,6 +875,24 @@ mod tests {
         elem_mul_test(&p384::COMMON_OPS, test_file!("ops/p384_elem_mul_tests.txt"));
     }
 
+    #[test]
+    fn p521_elem_mul_test() {
+        elem_mul_test(&p521::COMMON_OPS, test_file!("ops/p521_elem_mul_tests.txt"));
+    }
+
+    #[test]
+    fn secp256k1_elem_mul_test() {
+        elem_mul_test(
+            &secp256k1::COMMON_OPS,
+            test_file!("ops/secp256k1_elem_mul_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_elem_mul_test() {
+        elem_mul_test(&sm2::COMMON_OPS, test_file!("ops/sm2_elem_mul_tests.txt"));
+    }
+
     fn elem_mul_test(ops: &CommonOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -682,6 +923,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_scalar_mul_test() {
+        scalar_mul_test(
+            &p521::SCALAR_OPS,
+            test_file!("ops/p521_scalar_mul_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_scalar_mul_test() {
+        scalar_mul_test(
+            &secp256k1::SCALAR_OPS,
+            test_file!("ops/secp256k1_scalar_mul_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_scalar_mul_test() {
+        scalar_mul_test(&sm2::SCALAR_OPS, test_file!("ops/sm2_scalar_mul_tests.txt"));
+    }
+
     fn scalar_mul_test(ops: &ScalarOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -755,6 +1017,24 @@ mod tests {
         let _ = p384::SCALAR_OPS.scalar_inv_to_mont(&ZERO_SCALAR);
     }
 
+    #[test]
+    #[should_panic(expected = "!self.common.is_zero(a)")]
+    fn p521_scalar_inv_to_mont_zero_panic_test() {
+        let _ = p521::SCALAR_OPS.scalar_inv_to_mont(&ZERO_SCALAR);
+    }
+
+    #[test]
+    #[should_panic(expected = "!self.common.is_zero(a)")]
+    fn secp256k1_scalar_inv_to_mont_zero_panic_test() {
+        let _ = secp256k1::SCALAR_OPS.scalar_inv_to_mont(&ZERO_SCALAR);
+    }
+
+    #[test]
+    #[should_panic(expected = "!self.common.is_zero(a)")]
+    fn sm2_scalar_inv_to_mont_zero_panic_test() {
+        let _ = sm2::SCALAR_OPS.scalar_inv_to_mont(&ZERO_SCALAR);
+    }
+
     #[test]
     fn p256_point_sum_test() {
         point_sum_test(
@@ -771,6 +1051,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_point_sum_test() {
+        point_sum_test(
+            &p521::PRIVATE_KEY_OPS,
+            test_file!("ops/p521_point_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_sum_test() {
+        point_sum_test(
+            &secp256k1::PRIVATE_KEY_OPS,
+            test_file!("ops/secp256k1_point_sum_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_point_sum_test() {
+        point_sum_test(
+            &sm2::PRIVATE_KEY_OPS,
+            test_file!("ops/sm2_point_sum_tests.txt"),
+        );
+    }
+
     fn point_sum_test(ops: &PrivateKeyOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -861,6 +1165,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_point_double_test() {
+        prefixed_extern! {
+            fn nistz521_point_double(
+                r: *mut Limb,   // [p521::COMMON_OPS.num_limbs*3]
+                a: *const Limb, // [p521::COMMON_OPS.num_limbs*3]
+            );
+        }
+        point_double_test(
+            &p521::PRIVATE_KEY_OPS,
+            nistz521_point_double,
+            test_file!("ops/p521_point_double_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_double_test() {
+        prefixed_extern! {
+            fn secp256k1_point_double(
+                r: *mut Limb,   // [secp256k1::COMMON_OPS.num_limbs*3]
+                a: *const Limb, // [secp256k1::COMMON_OPS.num_limbs*3]
+            );
+        }
+        point_double_test(
+            &secp256k1::PRIVATE_KEY_OPS,
+            secp256k1_point_double,
+            test_file!("ops/secp256k1_point_double_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_point_double_test() {
+        prefixed_extern! {
+            fn sm2_point_double(
+                r: *mut Limb,   // [sm2::COMMON_OPS.num_limbs*3]
+                a: *const Limb, // [sm2::COMMON_OPS.num_limbs*3]
+            );
+        }
+        point_double_test(
+            &sm2::PRIVATE_KEY_OPS,
+            sm2_point_double,
+            test_file!("ops/sm2_point_double_tests.txt"),
+        );
+    }
+
     fn point_double_test(
         ops: &PrivateKeyOps,
         point_double: unsafe extern "C" fn(
@@ -902,6 +1251,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_point_mul_test() {
+        point_mul_tests(
+            &p521::PRIVATE_KEY_OPS,
+            test_file!("ops/p521_point_mul_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_mul_test() {
+        point_mul_tests(
+            &secp256k1::PRIVATE_KEY_OPS,
+            test_file!("ops/secp256k1_point_mul_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_point_mul_test() {
+        point_mul_tests(
+            &sm2::PRIVATE_KEY_OPS,
+            test_file!("ops/sm2_point_mul_tests.txt"),
+        );
+    }
+
     fn point_mul_tests(ops: &PrivateKeyOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -950,9 +1323,9 @@ mod tests {
 
             let product = priv_ops.point_mul(&p_scalar, &p);
 
-            let mut actual_result = vec![4u8; 1 + (2 * (cops.num_limbs * LIMB_BYTES))];
+            let mut actual_result = vec![4u8; 1 + (2 * cops.len())];
             {
-                let (x, y) = actual_result[1..].split_at_mut(cops.num_limbs * LIMB_BYTES);
+                let (x, y) = actual_result[1..].split_at_mut(cops.len());
                 super::super::private_key::big_endian_affine_from_jacobian(
                     priv_ops,
                     Some(x),
@@ -984,6 +1357,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p521_point_mul_base_test() {
+        point_mul_base_tests(
+            &p521::PRIVATE_KEY_OPS,
+            test_file!("ops/p521_point_mul_base_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_mul_base_test() {
+        point_mul_base_tests(
+            &secp256k1::PRIVATE_KEY_OPS,
+            test_file!("ops/secp256k1_point_mul_base_tests.txt"),
+        );
+    }
+
+    #[test]
+    fn sm2_point_mul_base_test() {
+        point_mul_base_tests(
+            &sm2::PRIVATE_KEY_OPS,
+            test_file!("ops/sm2_point_mul_base_tests.txt"),
+        );
+    }
+
     fn point_mul_base_tests(ops: &PrivateKeyOps, test_file: test::File) {
         test::run(test_file, |section, test_case| {
             assert_eq!(section, "");
@@ -1163,7 +1560,7 @@ mod tests {
         name: &str,
     ) -> Vec<u8> {
         let unpadded_bytes = test_case.consume_bytes(name);
-        let mut bytes = vec![0; (ops.num_limbs * LIMB_BYTES) - unpadded_bytes.len()];
+        let mut bytes = vec![0; ops.len() - unpadded_bytes.len()];
         bytes.extend(&unpadded_bytes);
         bytes
     }
@@ -1172,3 +1569,6 @@ mod tests {
 mod elem;
 pub mod p256;
 pub mod p384;
+pub mod p521;
+pub mod secp256k1;
+pub mod sm2;
@@ -117,4 +117,6 @@ pub fn unary_op_from_binary_op_assign<M, E: Encoding>(
     unsafe { f(a.limbs.as_mut_ptr(), a.limbs.as_ptr(), a.limbs.as_ptr()) }
 }
 
-pub const MAX_LIMBS: usize = (384 + (LIMB_BITS - 1)) / LIMB_BITS;
+// P-521 elements are stored in 576 bits regardless of the limb size so that
+// the same Montgomery constants work for both 32-bit and 64-bit limbs.
+pub const MAX_LIMBS: usize = 576 / LIMB_BITS;
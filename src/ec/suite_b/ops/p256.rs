@@ -20,12 +20,13 @@ use core::marker::PhantomData;
 
 macro_rules! p256_limbs {
     [ $($limb:expr),+ ] => {
-        limbs![$($limb),+, 0, 0, 0, 0]
+        limbs![$($limb),+, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
     };
 }
 
 pub static COMMON_OPS: CommonOps = CommonOps {
     num_limbs: 256 / LIMB_BITS,
+    order_bits: 256,
 
     q: Modulus {
         p: p256_limbs![
@@ -68,6 +69,7 @@ pub static COMMON_OPS: CommonOps = CommonOps {
     elem_sqr_mont: p256_sqr_mont,
 
     point_add_jacobian_impl: p256_point_add,
+    point_double_jacobian_impl: p256_point_double,
 };
 
 pub static PRIVATE_KEY_OPS: PrivateKeyOps = PrivateKeyOps {
@@ -122,6 +124,47 @@ fn p256_elem_inv_squared(a: &Elem<R>) -> Elem<R> {
     acc
 }
 
+/// Returns a square root of `a` (mod q). The result is only meaningful if `a`
+/// is a quadratic residue; the caller must check that the square of the
+/// result is `a`.
+pub fn elem_sqrt(a: &Elem<R>) -> Elem<R> {
+    // Since q == 3 (mod 4), a**((q + 1)/4) (mod q) is a square root of `a`
+    // whenever `a` has one.
+    //
+    // The exponent (q + 1)/4 is:
+    //
+    //    0x3fffffffc0000000400000000000000000000000400000000000000000000000
+
+    #[inline]
+    fn sqr_mul(a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
+        elem_sqr_mul(&COMMON_OPS, a, squarings, b)
+    }
+
+    #[inline]
+    fn sqr_mul_acc(a: &mut Elem<R>, squarings: usize, b: &Elem<R>) {
+        elem_sqr_mul_acc(&COMMON_OPS, a, squarings, b)
+    }
+
+    let b_1 = &a;
+    let b_11 = sqr_mul(b_1, 1, b_1);
+    let f = sqr_mul(&b_11, 2, &b_11);
+    let ff = sqr_mul(&f, 4, &f);
+    let ffff = sqr_mul(&ff, 8, &ff);
+    let ffffffff = sqr_mul(&ffff, 16, &ffff);
+
+    // ffffffff00000001, i.e. bits 253..190 of the exponent.
+    let mut acc = sqr_mul(&ffffffff, 32, b_1);
+
+    // Bit 94.
+    sqr_mul_acc(&mut acc, 96, b_1);
+
+    for _ in 0..94 {
+        COMMON_OPS.elem_square(&mut acc);
+    }
+
+    acc
+}
+
 fn p256_point_mul_base_impl(g_scalar: &Scalar) -> Point {
     prefixed_extern! {
         fn p256_point_mul_base(
@@ -313,6 +356,10 @@ prefixed_extern! {
         a: *const Limb, // [3][COMMON_OPS.num_limbs]
         b: *const Limb, // [3][COMMON_OPS.num_limbs]
     );
+    fn p256_point_double(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
     fn p256_point_mul(
         r: *mut Limb,          // [3][COMMON_OPS.num_limbs]
         p_scalar: *const Limb, // [COMMON_OPS.num_limbs]
@@ -20,12 +20,13 @@ use core::marker::PhantomData;
 
 macro_rules! p384_limbs {
     [$($limb:expr),+] => {
-        limbs![$($limb),+]
+        limbs![$($limb),+, 0, 0, 0, 0, 0, 0]
     };
 }
 
 pub static COMMON_OPS: CommonOps = CommonOps {
     num_limbs: 384 / LIMB_BITS,
+    order_bits: 384,
 
     q: Modulus {
         p: p384_limbs![
@@ -64,6 +65,7 @@ pub static COMMON_OPS: CommonOps = CommonOps {
     elem_sqr_mont: p384_elem_sqr_mont,
 
     point_add_jacobian_impl: nistz384_point_add,
+    point_double_jacobian_impl: nistz384_point_double,
 };
 
 pub static PRIVATE_KEY_OPS: PrivateKeyOps = PrivateKeyOps {
@@ -129,6 +131,64 @@ fn p384_elem_inv_squared(a: &Elem<R>) -> Elem<R> {
     acc
 }
 
+/// Returns a square root of `a` (mod q). The result is only meaningful if `a`
+/// is a quadratic residue; the caller must check that the square of the
+/// result is `a`.
+pub fn elem_sqrt(a: &Elem<R>) -> Elem<R> {
+    // Since q == 3 (mod 4), a**((q + 1)/4) (mod q) is a square root of `a`
+    // whenever `a` has one.
+    //
+    // The exponent (q + 1)/4 is:
+    //
+    //    0x3fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+    //      bfffffffc00000000000000040000000
+
+    #[inline]
+    fn sqr_mul(a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
+        elem_sqr_mul(&COMMON_OPS, a, squarings, b)
+    }
+
+    #[inline]
+    fn sqr_mul_acc(a: &mut Elem<R>, squarings: usize, b: &Elem<R>) {
+        elem_sqr_mul_acc(&COMMON_OPS, a, squarings, b)
+    }
+
+    let b_1 = &a;
+    let b_11 = sqr_mul(b_1, 1, b_1);
+    let b_111 = sqr_mul(&b_11, 1, b_1);
+    let f_11 = sqr_mul(&b_111, 3, &b_111);
+    let fff = sqr_mul(&f_11, 6, &f_11);
+    let fff_111 = sqr_mul(&fff, 3, &b_111);
+    let fffffff_11 = sqr_mul(&fff_111, 15, &fff_111);
+
+    let fffffffffffffff = sqr_mul(&fffffff_11, 30, &fffffff_11);
+
+    let ffffffffffffffffffffffffffffff = sqr_mul(&fffffffffffffff, 60, &fffffffffffffff);
+
+    // ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+    let mut acc = sqr_mul(
+        &ffffffffffffffffffffffffffffff,
+        120,
+        &ffffffffffffffffffffffffffffff,
+    );
+
+    // 255 one bits.
+    sqr_mul_acc(&mut acc, 15, &fff_111);
+
+    // A zero bit followed by 32 one bits.
+    sqr_mul_acc(&mut acc, 1 + 30, &fffffff_11);
+    sqr_mul_acc(&mut acc, 2, &b_11);
+
+    // 63 zero bits followed by a one bit.
+    sqr_mul_acc(&mut acc, 64, b_1);
+
+    for _ in 0..30 {
+        COMMON_OPS.elem_square(&mut acc);
+    }
+
+    acc
+}
+
 fn p384_point_mul_base_impl(a: &Scalar) -> Point {
     // XXX: Not efficient. TODO: Precompute multiples of the generator.
     static GENERATOR: (Elem<R>, Elem<R>) = (
@@ -349,6 +409,10 @@ prefixed_extern! {
         a: *const Limb, // [3][COMMON_OPS.num_limbs]
         b: *const Limb, // [3][COMMON_OPS.num_limbs]
     );
+    fn nistz384_point_double(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
     fn nistz384_point_mul(
         r: *mut Limb,          // [3][COMMON_OPS.num_limbs]
         p_scalar: *const Limb, // [COMMON_OPS.num_limbs]
@@ -0,0 +1,309 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{
+    elem::{binary_op, binary_op_assign},
+    elem_sqr_mul, elem_sqr_mul_acc, Modulus, *,
+};
+use core::marker::PhantomData;
+
+// P-521 elements are stored in 576 bits; see `MAX_LIMBS`.
+macro_rules! p521_limbs {
+    [$($limb:expr),+] => {
+        limbs![$($limb),+]
+    };
+}
+
+pub static COMMON_OPS: CommonOps = CommonOps {
+    num_limbs: 576 / LIMB_BITS,
+    order_bits: 521,
+
+    q: Modulus {
+        p: p521_limbs![
+            0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff, 0xffffffff, 0x000001ff, 0x00000000
+        ],
+        rr: p521_limbs![0, 0, 0, 0x00004000, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    },
+    n: Elem {
+        limbs: p521_limbs![
+            0x91386409, 0xbb6fb71e, 0x899c47ae, 0x3bb5c9b8, 0xf709a5d0, 0x7fcc0148, 0xbf2f966b,
+            0x51868783, 0xfffffffa, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff, 0xffffffff, 0x000001ff, 0x00000000
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+
+    a: Elem {
+        limbs: p521_limbs![
+            0xffffffff, 0xfe7fffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff, 0xffffffff, 0x000001ff, 0x00000000
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // Unreduced
+    },
+    b: Elem {
+        limbs: p521_limbs![
+            0xae586387, 0x8014654f, 0xea35a81f, 0x78f7a28f, 0xc41e961a, 0x839ab9ef, 0x5e9dd8df,
+            0xbd8b2960, 0xa8f63f49, 0xf0ab0c9c, 0xc8c77884, 0xf9dc5a44, 0x2dccd98a, 0x77516d39,
+            0xd05b42a0, 0x0fc94d10, 0x0000004d, 0x00000000
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // Unreduced
+    },
+
+    elem_mul_mont: p521_elem_mul_mont,
+    elem_sqr_mont: p521_elem_sqr_mont,
+
+    point_add_jacobian_impl: nistz521_point_add,
+    point_double_jacobian_impl: nistz521_point_double,
+};
+
+pub static PRIVATE_KEY_OPS: PrivateKeyOps = PrivateKeyOps {
+    common: &COMMON_OPS,
+    elem_inv_squared: p521_elem_inv_squared,
+    point_mul_base_impl: p521_point_mul_base_impl,
+    point_mul_impl: nistz521_point_mul,
+};
+
+fn p521_elem_inv_squared(a: &Elem<R>) -> Elem<R> {
+    // Calculate a**-2 (mod q) == a**(q - 3) (mod q)
+    //
+    // The exponent (q - 3) is 2**521 - 4, i.e. 519 one bits followed by two
+    // zero bits.
+
+    #[inline]
+    fn sqr_mul(a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
+        elem_sqr_mul(&COMMON_OPS, a, squarings, b)
+    }
+
+    #[inline]
+    fn sqr_mul_acc(a: &mut Elem<R>, squarings: usize, b: &Elem<R>) {
+        elem_sqr_mul_acc(&COMMON_OPS, a, squarings, b)
+    }
+
+    // `x_n` is `a**(2**n - 1)`, i.e. `n` one bits.
+    let x_1 = a;
+    let x_2 = sqr_mul(x_1, 1, x_1);
+    let x_3 = sqr_mul(&x_2, 1, x_1);
+    let x_4 = sqr_mul(&x_2, 2, &x_2);
+    let x_7 = sqr_mul(&x_4, 3, &x_3);
+    let x_8 = sqr_mul(&x_4, 4, &x_4);
+    let x_16 = sqr_mul(&x_8, 8, &x_8);
+    let x_32 = sqr_mul(&x_16, 16, &x_16);
+    let x_64 = sqr_mul(&x_32, 32, &x_32);
+    let x_128 = sqr_mul(&x_64, 64, &x_64);
+    let x_256 = sqr_mul(&x_128, 128, &x_128);
+
+    // x_519
+    let mut acc = sqr_mul(&x_256, 256, &x_256);
+    sqr_mul_acc(&mut acc, 7, &x_7);
+
+    COMMON_OPS.elem_square(&mut acc);
+    COMMON_OPS.elem_square(&mut acc);
+
+    acc
+}
+
+fn p521_point_mul_base_impl(a: &Scalar) -> Point {
+    // XXX: Not efficient. TODO: Precompute multiples of the generator.
+    static GENERATOR: (Elem<R>, Elem<R>) = (
+        Elem {
+            limbs: p521_limbs![
+                0x81adc101, 0xb331a163, 0x18e172de, 0x4dfcbf3f, 0xe0c2b521, 0x6f19a459, 0x93d17fd4,
+                0x947f0ee0, 0x3bf7f3ac, 0xdd50a5af, 0xb035a69e, 0x90fc1457, 0x9c829fda, 0x214e3240,
+                0xb311cada, 0xe6cf1f65, 0x00000074, 0x00000000
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+        Elem {
+            limbs: p521_limbs![
+                0x5a9e268e, 0x28460e4a, 0x3b4fe8b3, 0x20445f4a, 0x43513961, 0xb09a9e38, 0x809fd683,
+                0x2062a85c, 0x4caf7a13, 0x164bf739, 0x8b939f33, 0x340bd7de, 0x24abcda2, 0xeccc7aa2,
+                0xda163e8d, 0x022e452f, 0x000001e0, 0x00000000
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+    );
+
+    PRIVATE_KEY_OPS.point_mul(a, &GENERATOR)
+}
+
+pub static PUBLIC_KEY_OPS: PublicKeyOps = PublicKeyOps {
+    common: &COMMON_OPS,
+};
+
+pub static SCALAR_OPS: ScalarOps = ScalarOps {
+    common: &COMMON_OPS,
+    scalar_inv_to_mont_impl: p521_scalar_inv_to_mont,
+    scalar_mul_mont: p521_scalar_mul_mont,
+};
+
+pub static PUBLIC_SCALAR_OPS: PublicScalarOps = PublicScalarOps {
+    scalar_ops: &SCALAR_OPS,
+    public_key_ops: &PUBLIC_KEY_OPS,
+    private_key_ops: &PRIVATE_KEY_OPS,
+
+    q_minus_n: Elem {
+        limbs: p521_limbs![
+            0x6ec79bf6, 0x449048e1, 0x7663b851, 0xc44a3647, 0x08f65a2f, 0x8033feb7, 0x40d06994,
+            0xae79787c, 0x00000005, 0, 0, 0, 0, 0, 0, 0, 0, 0
+        ],
+
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+};
+
+pub static PRIVATE_SCALAR_OPS: PrivateScalarOps = PrivateScalarOps {
+    scalar_ops: &SCALAR_OPS,
+
+    oneRR_mod_n: Scalar {
+        limbs: N_RR_LIMBS,
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+};
+
+fn p521_scalar_inv_to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+    // Calculate the modular inverse of scalar |a| using Fermat's Little
+    // Theorem:
+    //
+    //   a**-1 (mod n) == a**(n - 2) (mod n)
+    //
+    // The exponent (n - 2) is:
+    //
+    //     0x1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+    //       a51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386407
+    //
+    // i.e. 261 one bits followed by the 65 hex digits in `REMAINING_DIGITS`.
+
+    fn mul(a: &Scalar<R>, b: &Scalar<R>) -> Scalar<R> {
+        binary_op(p521_scalar_mul_mont, a, b)
+    }
+
+    fn sqr(a: &Scalar<R>) -> Scalar<R> {
+        binary_op(p521_scalar_mul_mont, a, a)
+    }
+
+    fn sqr_mut(a: &mut Scalar<R>) {
+        unary_op_from_binary_op_assign(p521_scalar_mul_mont, a);
+    }
+
+    // Returns (`a` squared `squarings` times) * `b`.
+    fn sqr_mul(a: &Scalar<R>, squarings: usize, b: &Scalar<R>) -> Scalar<R> {
+        debug_assert!(squarings >= 1);
+        let mut tmp = sqr(a);
+        for _ in 1..squarings {
+            sqr_mut(&mut tmp);
+        }
+        mul(&tmp, b)
+    }
+
+    fn to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+        static N_RR: Scalar<Unencoded> = Scalar {
+            limbs: N_RR_LIMBS,
+            m: PhantomData,
+            encoding: PhantomData,
+        };
+        binary_op(p521_scalar_mul_mont, a, &N_RR)
+    }
+
+    // `d[i]` is `a**i`; `d[0]` is unused.
+    let mut d = [Scalar::zero(); 16];
+    d[1] = to_mont(a);
+    for i in 2..d.len() {
+        d[i] = mul(&d[i - 1], &d[1]);
+    }
+
+    // `x_n` is `a**(2**n - 1)`, i.e. `n` one bits.
+    let x_4 = d[0xf];
+    let x_5 = sqr_mul(&x_4, 1, &d[1]);
+    let x_8 = sqr_mul(&x_4, 4, &x_4);
+    let x_16 = sqr_mul(&x_8, 8, &x_8);
+    let x_32 = sqr_mul(&x_16, 16, &x_16);
+    let x_64 = sqr_mul(&x_32, 32, &x_32);
+    let x_128 = sqr_mul(&x_64, 64, &x_64);
+    let x_256 = sqr_mul(&x_128, 128, &x_128);
+
+    // x_261
+    let mut acc = sqr_mul(&x_256, 5, &x_5);
+
+    static REMAINING_DIGITS: [u8; 65] = [
+        0xa, 0x5, 0x1, 0x8, 0x6, 0x8, 0x7, 0x8, 0x3, 0xb, 0xf, 0x2, 0xf, 0x9, 0x6, 0x6, 0xb, 0x7,
+        0xf, 0xc, 0xc, 0x0, 0x1, 0x4, 0x8, 0xf, 0x7, 0x0, 0x9, 0xa, 0x5, 0xd, 0x0, 0x3, 0xb, 0xb,
+        0x5, 0xc, 0x9, 0xb, 0x8, 0x8, 0x9, 0x9, 0xc, 0x4, 0x7, 0xa, 0xe, 0xb, 0xb, 0x6, 0xf, 0xb,
+        0x7, 0x1, 0xe, 0x9, 0x1, 0x3, 0x8, 0x6, 0x4, 0x0, 0x7,
+    ];
+
+    for &digit in &REMAINING_DIGITS[..] {
+        for _ in 0..4 {
+            sqr_mut(&mut acc);
+        }
+        if digit != 0 {
+            binary_op_assign(p521_scalar_mul_mont, &mut acc, &d[usize::from(digit)]);
+        }
+    }
+
+    acc
+}
+
+unsafe extern "C" fn p521_elem_sqr_mont(
+    r: *mut Limb,   // [COMMON_OPS.num_limbs]
+    a: *const Limb, // [COMMON_OPS.num_limbs]
+) {
+    // XXX: Inefficient. TODO: Make a dedicated squaring routine.
+    p521_elem_mul_mont(r, a, a);
+}
+
+const N_RR_LIMBS: [Limb; MAX_LIMBS] = p521_limbs![
+    0xcf15dd04, 0x137cd04d, 0xe5547ea3, 0xf707badc, 0x794573ff, 0x12a78d38, 0x57f75e06, 0xd3721ef5,
+    0x2e49c7db, 0xdd6e23d8, 0xb7756e3e, 0xcff3d142, 0xa8e567bc, 0x5bcc6d61, 0x492d0d45, 0x2d8e03d1,
+    0x0000003d, 0x00000000
+];
+
+prefixed_extern! {
+    fn p521_elem_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+
+    fn nistz521_point_add(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+        b: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn nistz521_point_double(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn nistz521_point_mul(
+        r: *mut Limb,          // [3][COMMON_OPS.num_limbs]
+        p_scalar: *const Limb, // [COMMON_OPS.num_limbs]
+        p_x: *const Limb,      // [COMMON_OPS.num_limbs]
+        p_y: *const Limb,      // [COMMON_OPS.num_limbs]
+    );
+
+    fn p521_scalar_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+}
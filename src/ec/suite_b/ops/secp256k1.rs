@@ -0,0 +1,367 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{
+    elem::{binary_op, binary_op_assign},
+    elem_sqr_mul, elem_sqr_mul_acc, Modulus, *,
+};
+use core::marker::PhantomData;
+
+macro_rules! secp256k1_limbs {
+    [$($limb:expr),+] => {
+        limbs![$($limb),+, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    };
+}
+
+pub static COMMON_OPS: CommonOps = CommonOps {
+    num_limbs: 256 / LIMB_BITS,
+    order_bits: 256,
+
+    q: Modulus {
+        p: secp256k1_limbs![
+            0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xffffffff
+        ],
+        rr: secp256k1_limbs![0x000e90a1, 0x000007a2, 0x00000001, 0, 0, 0, 0, 0],
+    },
+    n: Elem {
+        limbs: secp256k1_limbs![
+            0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff,
+            0xffffffff
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+
+    a: Elem {
+        limbs: secp256k1_limbs![0, 0, 0, 0, 0, 0, 0, 0],
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+    b: Elem {
+        limbs: secp256k1_limbs![0x00001ab7, 0x00000007, 0, 0, 0, 0, 0, 0],
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+
+    elem_mul_mont: secp256k1_elem_mul_mont,
+    elem_sqr_mont: secp256k1_elem_sqr_mont,
+
+    point_add_jacobian_impl: secp256k1_point_add,
+    point_double_jacobian_impl: secp256k1_point_double,
+};
+
+pub static PRIVATE_KEY_OPS: PrivateKeyOps = PrivateKeyOps {
+    common: &COMMON_OPS,
+    elem_inv_squared: secp256k1_elem_inv_squared,
+    point_mul_base_impl: secp256k1_point_mul_base_impl,
+    point_mul_impl: secp256k1_point_mul,
+};
+
+#[inline]
+fn sqr_mul(a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
+    elem_sqr_mul(&COMMON_OPS, a, squarings, b)
+}
+
+#[inline]
+fn sqr_mul_acc(a: &mut Elem<R>, squarings: usize, b: &Elem<R>) {
+    elem_sqr_mul_acc(&COMMON_OPS, a, squarings, b)
+}
+
+// Returns (`x_2`, `x_22`, `x_223`), where `x_n` is `a**(2**n - 1)`, i.e. `n`
+// one bits. Both (q - 3) and (q + 1)/4 start with a run of 223 one bits and
+// then differ only in the last 33 or 31 bits, respectively.
+fn elem_x_2_x_22_x_223(a: &Elem<R>) -> (Elem<R>, Elem<R>, Elem<R>) {
+    let x_1 = a;
+    let x_2 = sqr_mul(x_1, 1, x_1);
+    let x_3 = sqr_mul(&x_2, 1, x_1);
+    let x_6 = sqr_mul(&x_3, 3, &x_3);
+    let x_9 = sqr_mul(&x_6, 3, &x_3);
+    let x_11 = sqr_mul(&x_9, 2, &x_2);
+    let x_22 = sqr_mul(&x_11, 11, &x_11);
+    let x_44 = sqr_mul(&x_22, 22, &x_22);
+    let x_88 = sqr_mul(&x_44, 44, &x_44);
+    let x_176 = sqr_mul(&x_88, 88, &x_88);
+    let x_220 = sqr_mul(&x_176, 44, &x_44);
+    let x_223 = sqr_mul(&x_220, 3, &x_3);
+    (x_2, x_22, x_223)
+}
+
+fn secp256k1_elem_inv_squared(a: &Elem<R>) -> Elem<R> {
+    // Calculate a**-2 (mod q) == a**(q - 3) (mod q)
+    //
+    // The exponent (q - 3) is:
+    //
+    //    0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2c
+
+    // After the 223 one bits, the rest of the exponent, in binary, is:
+    //
+    //    0 1111111111111111111111 00001 011 00
+
+    let (x_2, x_22, mut acc) = elem_x_2_x_22_x_223(a);
+    sqr_mul_acc(&mut acc, 1 + 22, &x_22);
+    sqr_mul_acc(&mut acc, 4 + 1, a);
+    sqr_mul_acc(&mut acc, 1 + 2, &x_2);
+    COMMON_OPS.elem_square(&mut acc);
+    COMMON_OPS.elem_square(&mut acc);
+
+    acc
+}
+
+/// Returns a square root of `a` (mod q). The result is only meaningful if `a`
+/// is a quadratic residue; the caller must check that the square of the
+/// result is `a`.
+pub fn elem_sqrt(a: &Elem<R>) -> Elem<R> {
+    // Since q == 3 (mod 4), a**((q + 1)/4) (mod q) is a square root of `a`
+    // whenever `a` has one.
+    //
+    // The exponent (q + 1)/4 is:
+    //
+    //    0x3fffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffff0c
+
+    // After the 223 one bits, the rest of the exponent, in binary, is:
+    //
+    //    0 1111111111111111111111 000011 00
+
+    let (x_2, x_22, mut acc) = elem_x_2_x_22_x_223(a);
+    sqr_mul_acc(&mut acc, 1 + 22, &x_22);
+    sqr_mul_acc(&mut acc, 4 + 2, &x_2);
+    COMMON_OPS.elem_square(&mut acc);
+    COMMON_OPS.elem_square(&mut acc);
+
+    acc
+}
+
+fn secp256k1_point_mul_base_impl(a: &Scalar) -> Point {
+    // XXX: Not efficient. TODO: Precompute multiples of the generator.
+    static GENERATOR: (Elem<R>, Elem<R>) = (
+        Elem {
+            limbs: secp256k1_limbs![
+                0x487e2097, 0xd7362e5a, 0x29bc66db, 0x231e2953, 0x33fd129c, 0x979f48c0, 0xe9089f48,
+                0x9981e643
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+        Elem {
+            limbs: secp256k1_limbs![
+                0xd3dbabe2, 0xb15ea6d2, 0x1f1dc64d, 0x8dfc5d5d, 0xac19c136, 0x70b6b59a, 0xd4a582d6,
+                0xcf3f851f
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+    );
+
+    PRIVATE_KEY_OPS.point_mul(a, &GENERATOR)
+}
+
+pub static PUBLIC_KEY_OPS: PublicKeyOps = PublicKeyOps {
+    common: &COMMON_OPS,
+};
+
+pub static SCALAR_OPS: ScalarOps = ScalarOps {
+    common: &COMMON_OPS,
+    scalar_inv_to_mont_impl: secp256k1_scalar_inv_to_mont,
+    scalar_mul_mont: secp256k1_scalar_mul_mont,
+};
+
+pub static PUBLIC_SCALAR_OPS: PublicScalarOps = PublicScalarOps {
+    scalar_ops: &SCALAR_OPS,
+    public_key_ops: &PUBLIC_KEY_OPS,
+    private_key_ops: &PRIVATE_KEY_OPS,
+
+    q_minus_n: Elem {
+        limbs: secp256k1_limbs![0x2fc9baee, 0x402da172, 0x50b75fc4, 0x45512319, 1, 0, 0, 0],
+
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+};
+
+pub static PRIVATE_SCALAR_OPS: PrivateScalarOps = PrivateScalarOps {
+    scalar_ops: &SCALAR_OPS,
+
+    oneRR_mod_n: Scalar {
+        limbs: N_RR_LIMBS,
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+};
+
+fn secp256k1_scalar_inv_to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+    // Calculate the modular inverse of scalar |a| using Fermat's Little
+    // Theorem:
+    //
+    //   a**-1 (mod n) == a**(n - 2) (mod n)
+    //
+    // The exponent (n - 2) is:
+    //
+    //     0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f.
+
+    fn mul(a: &Scalar<R>, b: &Scalar<R>) -> Scalar<R> {
+        binary_op(secp256k1_scalar_mul_mont, a, b)
+    }
+
+    fn sqr(a: &Scalar<R>) -> Scalar<R> {
+        binary_op(secp256k1_scalar_mul_mont, a, a)
+    }
+
+    fn sqr_mut(a: &mut Scalar<R>) {
+        unary_op_from_binary_op_assign(secp256k1_scalar_mul_mont, a);
+    }
+
+    // Returns (`a` squared `squarings` times) * `b`.
+    fn sqr_mul(a: &Scalar<R>, squarings: usize, b: &Scalar<R>) -> Scalar<R> {
+        debug_assert!(squarings >= 1);
+        let mut tmp = sqr(a);
+        for _ in 1..squarings {
+            sqr_mut(&mut tmp);
+        }
+        mul(&tmp, b)
+    }
+
+    // Sets `acc` = (`acc` squared `squarings` times) * `b`.
+    fn sqr_mul_acc(acc: &mut Scalar<R>, squarings: usize, b: &Scalar<R>) {
+        debug_assert!(squarings >= 1);
+        for _ in 0..squarings {
+            sqr_mut(acc);
+        }
+        binary_op_assign(secp256k1_scalar_mul_mont, acc, b)
+    }
+
+    fn to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+        static N_RR: Scalar<Unencoded> = Scalar {
+            limbs: N_RR_LIMBS,
+            m: PhantomData,
+            encoding: PhantomData,
+        };
+        binary_op(secp256k1_scalar_mul_mont, a, &N_RR)
+    }
+
+    // Indexes into `d`.
+    const B_1: usize = 0;
+    const B_11: usize = 1;
+    const B_101: usize = 2;
+    const B_111: usize = 3;
+    const B_1001: usize = 4;
+    const B_1011: usize = 5;
+    const B_1101: usize = 6;
+    const B_1111: usize = 7;
+    const DIGIT_COUNT: usize = 8;
+
+    let mut d = [Scalar::zero(); DIGIT_COUNT];
+    d[B_1] = to_mont(a);
+    let b_10 = sqr(&d[B_1]);
+    for i in B_11..DIGIT_COUNT {
+        d[i] = mul(&d[i - 1], &b_10);
+    }
+
+    let ff = sqr_mul(&d[B_1111], 4, &d[B_1111]);
+    let ffff = sqr_mul(&ff, 8, &ff);
+    let ffffffff = sqr_mul(&ffff, 16, &ffff);
+
+    let ffffffffffffffff = sqr_mul(&ffffffff, 32, &ffffffff);
+
+    let ffffffffffffffffffffffff = sqr_mul(&ffffffffffffffff, 32, &ffffffff);
+
+    // ffffffffffffffffffffffffffffff
+    let mut acc = sqr_mul(&ffffffffffffffffffffffff, 16, &ffff);
+    sqr_mul_acc(&mut acc, 8, &ff);
+
+    // The rest of the exponent, in binary, is:
+    //
+    //    1111111010111010101011101101110011100110101011110100100010100000
+    //    0011101110111111110100100101111010001100110100000011011001000001
+    //    00111111
+
+    static REMAINING_WINDOWS: [(u8, u8); 28] = [
+        (4, B_1111 as u8),
+        (3, B_111 as u8),
+        (1 + 4, B_1011 as u8),
+        (3, B_101 as u8),
+        (1 + 3, B_101 as u8),
+        (1 + 3, B_111 as u8),
+        (1 + 4, B_1101 as u8),
+        (2, B_11 as u8),
+        (2 + 3, B_111 as u8),
+        (2 + 4, B_1101 as u8),
+        (1 + 4, B_1011 as u8),
+        (4, B_1101 as u8),
+        (2 + 1, B_1 as u8),
+        (3 + 3, B_101 as u8),
+        (7 + 3, B_111 as u8),
+        (1 + 3, B_111 as u8),
+        (1 + 4, B_1111 as u8),
+        (4, B_1111 as u8),
+        (1 + 4, B_1001 as u8),
+        (2 + 4, B_1011 as u8),
+        (4, B_1101 as u8),
+        (3 + 2, B_11 as u8),
+        (2 + 4, B_1101 as u8),
+        (6 + 4, B_1101 as u8),
+        (4, B_1001 as u8),
+        (5 + 4, B_1001 as u8),
+        (4, B_1111 as u8),
+        (1, B_1 as u8),
+    ];
+
+    for &(squarings, digit) in &REMAINING_WINDOWS[..] {
+        sqr_mul_acc(&mut acc, usize::from(squarings), &d[usize::from(digit)]);
+    }
+
+    acc
+}
+
+unsafe extern "C" fn secp256k1_elem_sqr_mont(
+    r: *mut Limb,   // [COMMON_OPS.num_limbs]
+    a: *const Limb, // [COMMON_OPS.num_limbs]
+) {
+    // XXX: Inefficient. TODO: Make a dedicated squaring routine.
+    secp256k1_elem_mul_mont(r, a, a);
+}
+
+const N_RR_LIMBS: [Limb; MAX_LIMBS] = secp256k1_limbs![
+    0x67d7d140, 0x896cf214, 0x0e7cf878, 0x741496c2, 0x5bcd07c6, 0xe697f5e4, 0x81c69bc5, 0x9d671cd5
+];
+
+prefixed_extern! {
+    fn secp256k1_elem_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+
+    fn secp256k1_point_add(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+        b: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn secp256k1_point_double(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn secp256k1_point_mul(
+        r: *mut Limb,          // [3][COMMON_OPS.num_limbs]
+        p_scalar: *const Limb, // [COMMON_OPS.num_limbs]
+        p_x: *const Limb,      // [COMMON_OPS.num_limbs]
+        p_y: *const Limb,      // [COMMON_OPS.num_limbs]
+    );
+
+    fn secp256k1_scalar_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+}
@@ -0,0 +1,342 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{
+    elem::{binary_op, binary_op_assign},
+    elem_sqr_mul, elem_sqr_mul_acc, Modulus, *,
+};
+use core::marker::PhantomData;
+
+macro_rules! sm2_limbs {
+    [$($limb:expr),+] => {
+        limbs![$($limb),+, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    };
+}
+
+pub static COMMON_OPS: CommonOps = CommonOps {
+    num_limbs: 256 / LIMB_BITS,
+    order_bits: 256,
+
+    q: Modulus {
+        p: sm2_limbs![
+            0xffffffff, 0xffffffff, 0, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xfffffffe
+        ],
+        rr: sm2_limbs![
+            0x00000003, 0x00000002, 0xffffffff, 0x00000002, 0x00000001, 0x00000001, 0x00000002,
+            0x00000004
+        ],
+    },
+    n: Elem {
+        limbs: sm2_limbs![
+            0x39d54123, 0x53bbf409, 0x21c6052b, 0x7203df6b, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xfffffffe
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+
+    a: Elem {
+        limbs: sm2_limbs![
+            0xfffffffc, 0xffffffff, 0x00000003, 0xfffffffc, 0xffffffff, 0xffffffff, 0xffffffff,
+            0xfffffffb
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+    b: Elem {
+        limbs: sm2_limbs![
+            0x2bc0dd42, 0x90d23063, 0xe9b537ab, 0x71cf379a, 0x5ea51c3c, 0x52798150, 0xba20e2c8,
+            0x240fe188
+        ],
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+
+    elem_mul_mont: sm2_elem_mul_mont,
+    elem_sqr_mont: sm2_elem_sqr_mont,
+
+    point_add_jacobian_impl: sm2_point_add,
+    point_double_jacobian_impl: sm2_point_double,
+};
+
+pub static PRIVATE_KEY_OPS: PrivateKeyOps = PrivateKeyOps {
+    common: &COMMON_OPS,
+    elem_inv_squared: sm2_elem_inv_squared,
+    point_mul_base_impl: sm2_point_mul_base_impl,
+    point_mul_impl: sm2_point_mul,
+};
+
+#[inline]
+fn sqr_mul(a: &Elem<R>, squarings: usize, b: &Elem<R>) -> Elem<R> {
+    elem_sqr_mul(&COMMON_OPS, a, squarings, b)
+}
+
+#[inline]
+fn sqr_mul_acc(a: &mut Elem<R>, squarings: usize, b: &Elem<R>) {
+    elem_sqr_mul_acc(&COMMON_OPS, a, squarings, b)
+}
+
+fn sm2_elem_inv_squared(a: &Elem<R>) -> Elem<R> {
+    // Calculate a**-2 (mod q) == a**(q - 3) (mod q)
+    //
+    // The exponent (q - 3) is:
+    //
+    //    0xfffffffeffffffffffffffffffffffffffffffff00000000fffffffffffffffc
+    //
+    // In binary, that is 31 one bits, a zero bit, 128 one bits, 32 zero bits,
+    // 62 one bits, and two zero bits. Below, `x_n` is `a**(2**n - 1)`, i.e.
+    // `n` one bits.
+
+    let x_1 = a;
+    let x_2 = sqr_mul(x_1, 1, x_1);
+    let x_3 = sqr_mul(&x_2, 1, x_1);
+    let x_6 = sqr_mul(&x_3, 3, &x_3);
+    let x_12 = sqr_mul(&x_6, 6, &x_6);
+    let x_15 = sqr_mul(&x_12, 3, &x_3);
+    let x_30 = sqr_mul(&x_15, 15, &x_15);
+    let x_31 = sqr_mul(&x_30, 1, x_1);
+    let x_32 = sqr_mul(&x_31, 1, x_1);
+    let x_62 = sqr_mul(&x_31, 31, &x_31);
+
+    let mut acc = x_31;
+    sqr_mul_acc(&mut acc, 1 + 32, &x_32);
+    for _ in 0..3 {
+        sqr_mul_acc(&mut acc, 32, &x_32);
+    }
+    sqr_mul_acc(&mut acc, 32 + 62, &x_62);
+    COMMON_OPS.elem_square(&mut acc);
+    COMMON_OPS.elem_square(&mut acc);
+
+    acc
+}
+
+fn sm2_point_mul_base_impl(a: &Scalar) -> Point {
+    // XXX: Not efficient. TODO: Precompute multiples of the generator.
+    static GENERATOR: (Elem<R>, Elem<R>) = (
+        Elem {
+            limbs: sm2_limbs![
+                0xf418029e, 0x61328990, 0xdca6c050, 0x3e7981ed, 0xac24c3c3, 0xd6a1ed99, 0xe1c13b05,
+                0x91167a5e
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+        Elem {
+            limbs: sm2_limbs![
+                0x3c2d0ddd, 0xc1354e59, 0x8d3295fa, 0xc1f5e578, 0x6e2a48f8, 0x8d4cfb06, 0x81d735bd,
+                0x63cd65d4
+            ],
+            m: PhantomData,
+            encoding: PhantomData,
+        },
+    );
+
+    PRIVATE_KEY_OPS.point_mul(a, &GENERATOR)
+}
+
+pub static PUBLIC_KEY_OPS: PublicKeyOps = PublicKeyOps {
+    common: &COMMON_OPS,
+};
+
+pub static SCALAR_OPS: ScalarOps = ScalarOps {
+    common: &COMMON_OPS,
+    scalar_inv_to_mont_impl: sm2_scalar_inv_to_mont,
+    scalar_mul_mont: sm2_scalar_mul_mont,
+};
+
+pub static PUBLIC_SCALAR_OPS: PublicScalarOps = PublicScalarOps {
+    scalar_ops: &SCALAR_OPS,
+    public_key_ops: &PUBLIC_KEY_OPS,
+    private_key_ops: &PRIVATE_KEY_OPS,
+
+    q_minus_n: Elem {
+        limbs: sm2_limbs![0xc62abedc, 0xac440bf6, 0xde39fad5, 0x8dfc2093, 0, 0, 0, 0],
+
+        m: PhantomData,
+        encoding: PhantomData, // Unencoded
+    },
+};
+
+pub static PRIVATE_SCALAR_OPS: PrivateScalarOps = PrivateScalarOps {
+    scalar_ops: &SCALAR_OPS,
+
+    oneRR_mod_n: Scalar {
+        limbs: N_RR_LIMBS,
+        m: PhantomData,
+        encoding: PhantomData, // R
+    },
+};
+
+fn sm2_scalar_inv_to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+    // Calculate the modular inverse of scalar |a| using Fermat's Little
+    // Theorem:
+    //
+    //   a**-1 (mod n) == a**(n - 2) (mod n)
+    //
+    // The exponent (n - 2) is:
+    //
+    //     0xfffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54121.
+
+    fn mul(a: &Scalar<R>, b: &Scalar<R>) -> Scalar<R> {
+        binary_op(sm2_scalar_mul_mont, a, b)
+    }
+
+    fn sqr(a: &Scalar<R>) -> Scalar<R> {
+        binary_op(sm2_scalar_mul_mont, a, a)
+    }
+
+    fn sqr_mut(a: &mut Scalar<R>) {
+        unary_op_from_binary_op_assign(sm2_scalar_mul_mont, a);
+    }
+
+    // Returns (`a` squared `squarings` times) * `b`.
+    fn sqr_mul(a: &Scalar<R>, squarings: usize, b: &Scalar<R>) -> Scalar<R> {
+        debug_assert!(squarings >= 1);
+        let mut tmp = sqr(a);
+        for _ in 1..squarings {
+            sqr_mut(&mut tmp);
+        }
+        mul(&tmp, b)
+    }
+
+    // Sets `acc` = (`acc` squared `squarings` times) * `b`.
+    fn sqr_mul_acc(acc: &mut Scalar<R>, squarings: usize, b: &Scalar<R>) {
+        debug_assert!(squarings >= 1);
+        for _ in 0..squarings {
+            sqr_mut(acc);
+        }
+        binary_op_assign(sm2_scalar_mul_mont, acc, b)
+    }
+
+    fn to_mont(a: &Scalar<Unencoded>) -> Scalar<R> {
+        static N_RR: Scalar<Unencoded> = Scalar {
+            limbs: N_RR_LIMBS,
+            m: PhantomData,
+            encoding: PhantomData,
+        };
+        binary_op(sm2_scalar_mul_mont, a, &N_RR)
+    }
+
+    // Indexes into `d`.
+    const B_1: usize = 0;
+    const B_11: usize = 1;
+    const B_101: usize = 2;
+    const B_111: usize = 3;
+    const B_1001: usize = 4;
+    const B_1011: usize = 5;
+    const B_1101: usize = 6;
+    const B_1111: usize = 7;
+    const DIGIT_COUNT: usize = 8;
+
+    let mut d = [Scalar::zero(); DIGIT_COUNT];
+    d[B_1] = to_mont(a);
+    let b_10 = sqr(&d[B_1]);
+    for i in B_11..DIGIT_COUNT {
+        d[i] = mul(&d[i - 1], &b_10);
+    }
+
+    let ff = sqr_mul(&d[B_1111], 4, &d[B_1111]);
+    let ffff = sqr_mul(&ff, 8, &ff);
+    let ffffffff = sqr_mul(&ffff, 16, &ffff);
+
+    // fffffffe, which is 31 one bits and a zero bit.
+    let mut acc = sqr_mul(&ffff, 8, &ff);
+    sqr_mul_acc(&mut acc, 4, &d[B_1111]);
+    sqr_mul_acc(&mut acc, 3, &d[B_111]);
+
+    // fffffffeffffffffffffffffffffffff
+    sqr_mul_acc(&mut acc, 1 + 32, &ffffffff);
+    sqr_mul_acc(&mut acc, 32, &ffffffff);
+    sqr_mul_acc(&mut acc, 32, &ffffffff);
+
+    // The rest of the exponent, in binary, is:
+    //
+    //    0111001000000011110111110110101100100001110001100000010100101011
+    //    0101001110111011111101000000100100111001110101010100000100100001
+
+    static REMAINING_WINDOWS: [(u8, u8); 24] = [
+        (1 + 3, B_111 as u8),
+        (2 + 1, B_1 as u8),
+        (7 + 4, B_1111 as u8),
+        (1 + 4, B_1111 as u8),
+        (4, B_1011 as u8),
+        (1 + 4, B_1011 as u8),
+        (2 + 1, B_1 as u8),
+        (4 + 3, B_111 as u8),
+        (3 + 2, B_11 as u8),
+        (6 + 3, B_101 as u8),
+        (2 + 3, B_101 as u8),
+        (1 + 4, B_1101 as u8),
+        (1 + 4, B_1001 as u8),
+        (4, B_1101 as u8),
+        (4, B_1101 as u8),
+        (4, B_1111 as u8),
+        (3, B_101 as u8),
+        (6 + 4, B_1001 as u8),
+        (2 + 3, B_111 as u8),
+        (2 + 3, B_111 as u8),
+        (1 + 3, B_101 as u8),
+        (1 + 3, B_101 as u8),
+        (5 + 4, B_1001 as u8),
+        (4 + 1, B_1 as u8),
+    ];
+
+    for &(squarings, digit) in &REMAINING_WINDOWS[..] {
+        sqr_mul_acc(&mut acc, usize::from(squarings), &d[usize::from(digit)]);
+    }
+
+    acc
+}
+
+unsafe extern "C" fn sm2_elem_sqr_mont(
+    r: *mut Limb,   // [COMMON_OPS.num_limbs]
+    a: *const Limb, // [COMMON_OPS.num_limbs]
+) {
+    // XXX: Inefficient. TODO: Make a dedicated squaring routine.
+    sm2_elem_mul_mont(r, a, a);
+}
+
+const N_RR_LIMBS: [Limb; MAX_LIMBS] = sm2_limbs![
+    0x7c114f20, 0x901192af, 0xde6fa2fa, 0x3464504a, 0x3affe0d4, 0x620fc84c, 0xa22b3d3b, 0x1eb5e412
+];
+
+prefixed_extern! {
+    fn sm2_elem_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+
+    fn sm2_point_add(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+        b: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn sm2_point_double(
+        r: *mut Limb,   // [3][COMMON_OPS.num_limbs]
+        a: *const Limb, // [3][COMMON_OPS.num_limbs]
+    );
+    fn sm2_point_mul(
+        r: *mut Limb,          // [3][COMMON_OPS.num_limbs]
+        p_scalar: *const Limb, // [COMMON_OPS.num_limbs]
+        p_x: *const Limb,      // [COMMON_OPS.num_limbs]
+        p_y: *const Limb,      // [COMMON_OPS.num_limbs]
+    );
+
+    fn sm2_scalar_mul_mont(
+        r: *mut Limb,   // [COMMON_OPS.num_limbs]
+        a: *const Limb, // [COMMON_OPS.num_limbs]
+        b: *const Limb, // [COMMON_OPS.num_limbs]
+    );
+}
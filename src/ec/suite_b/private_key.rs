@@ -16,21 +16,15 @@
 //! ECDSA signing).
 
 use super::{ops::*, verify_affine_point_is_on_the_curve};
-use crate::{
-    arithmetic::montgomery::R,
-    ec, error,
-    limb::{self, LIMB_BYTES},
-    rand,
-};
+use crate::{arithmetic::montgomery::R, ec, error, limb, rand};
 
 /// Generates a random scalar in the range [1, n).
 pub fn random_scalar(
     ops: &PrivateKeyOps,
     rng: &dyn rand::SecureRandom,
 ) -> Result<Scalar, error::Unspecified> {
-    let num_limbs = ops.common.num_limbs;
     let mut bytes = [0; ec::SCALAR_MAX_BYTES];
-    let bytes = &mut bytes[..(num_limbs * LIMB_BYTES)];
+    let bytes = &mut bytes[..ops.common.len()];
     generate_private_scalar_bytes(ops, rng, bytes)?;
     scalar_from_big_endian_bytes(ops, bytes)
 }
@@ -71,6 +65,10 @@ pub fn generate_private_scalar_bytes(
         // requested security strength is delegated to `rng`.
         rng.fill(candidate)?;
 
+        // When the order isn't a multiple of 8 bits long (P-521), clear the
+        // excess high-order bits so that most candidates are in range.
+        candidate[0] &= 0xff >> ((8 - (ops.common.order_bits % 8)) % 8);
+
         // NSA Guide Steps 5, 6, and 7.
         if check_scalar_big_endian_bytes(ops, candidate).is_err() {
             continue;
@@ -99,7 +97,7 @@ pub fn check_scalar_big_endian_bytes(
     ops: &PrivateKeyOps,
     bytes: &[u8],
 ) -> Result<(), error::Unspecified> {
-    debug_assert_eq!(bytes.len(), ops.common.num_limbs * LIMB_BYTES);
+    debug_assert_eq!(bytes.len(), ops.common.len());
     scalar_from_big_endian_bytes(ops, bytes).map(|_| ())
 }
 
@@ -135,7 +133,7 @@ pub fn public_from_private(
     public_out: &mut [u8],
     my_private_key: &ec::Seed,
 ) -> Result<(), error::Unspecified> {
-    let elem_and_scalar_bytes = ops.common.num_limbs * LIMB_BYTES;
+    let elem_and_scalar_bytes = ops.common.len();
     debug_assert_eq!(public_out.len(), 1 + (2 * elem_and_scalar_bytes));
     let my_private_key = private_key_as_scalar(ops, my_private_key);
     let my_public_key = ops.point_mul_base(&my_private_key);
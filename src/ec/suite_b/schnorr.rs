@@ -0,0 +1,196 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! Schnorr signatures over secp256k1, as specified in BIP-340.
+
+use super::{ecdsa::digest_scalar::digest_scalar, ops::*, private_key::affine_from_jacobian};
+use crate::{
+    arithmetic::montgomery::*,
+    digest, error,
+    limb::{self, LimbMask},
+    sealed, signature,
+};
+
+/// The length of a secp256k1 field element or scalar.
+pub(super) const ELEM_LEN: usize = 32;
+
+pub(super) type AffinePoint = (Elem<R>, Elem<R>);
+
+/// A BIP-340 signature verification algorithm.
+///
+/// Public keys are the 32-byte *x* coordinates of points with even *y*
+/// coordinates, and signatures are 64 bytes long.
+pub struct SchnorrVerificationAlgorithm {
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    BIP340_SCHNORR_SECP256K1,
+}
+
+derive_debug_via_id!(SchnorrVerificationAlgorithm);
+
+/// Verification of BIP-340 Schnorr signatures using the secp256k1 curve and
+/// SHA-256.
+pub static BIP340_SCHNORR_SECP256K1: SchnorrVerificationAlgorithm = SchnorrVerificationAlgorithm {
+    id: AlgorithmID::BIP340_SCHNORR_SECP256K1,
+};
+
+impl signature::VerificationAlgorithm for SchnorrVerificationAlgorithm {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        verify(
+            public_key.as_slice_less_safe(),
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+
+    fn validate_public_key(&self, public_key: untrusted::Input) -> Result<(), error::KeyRejected> {
+        lift_x(public_key.as_slice_less_safe())
+            .map(|_| ())
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())
+    }
+}
+
+impl sealed::Sealed for SchnorrVerificationAlgorithm {}
+
+// BIP-340's `Verify`.
+pub(super) fn verify(
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    let cops = &secp256k1::COMMON_OPS;
+
+    let p = lift_x(public_key)?;
+    if signature.len() != 2 * ELEM_LEN {
+        return Err(error::Unspecified);
+    }
+    let (r, s) = signature.split_at(ELEM_LEN);
+    let r_elem = elem_parse_big_endian_fixed_consttime(cops, untrusted::Input::from(r))?;
+    let s = scalar_parse(s)?;
+
+    // R = s*G - e*P.
+    let e = challenge(r, public_key, msg);
+    let product = cops.point_sum(
+        &point_mul_base(&s),
+        &point_mul(&scalar_negated(cops, &e), &p),
+    );
+    let (x, y) = affine(&product)?;
+    if !has_even_y(&y)
+        || !secp256k1::PUBLIC_SCALAR_OPS.elem_equals_vartime(&cops.elem_unencoded(&x), &r_elem)
+    {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+// hash_BIP0340/challenge(r || P || m) mod n.
+pub(super) fn challenge(r: &[u8], public_key: &[u8], msg: &[u8]) -> Scalar {
+    let mut ctx = tagged_hash(b"BIP0340/challenge");
+    ctx.update(r);
+    ctx.update(public_key);
+    ctx.update(msg);
+    hash_to_scalar(ctx)
+}
+
+/// Returns a context for BIP-340's `hash_tag`, to which the message still
+/// needs to be added.
+pub(super) fn tagged_hash(tag: &[u8]) -> digest::Context {
+    let tag_hash = digest::digest(&digest::SHA256, tag);
+    let mut ctx = digest::Context::new(&digest::SHA256);
+    ctx.update(tag_hash.as_ref());
+    ctx.update(tag_hash.as_ref());
+    ctx
+}
+
+/// Finishes `ctx` and interprets the digest as an integer (mod n).
+pub(super) fn hash_to_scalar(ctx: digest::Context) -> Scalar {
+    digest_scalar(&secp256k1::SCALAR_OPS, ctx.finish())
+}
+
+/// BIP-340's `lift_x`: the point with the given *x* coordinate and an even
+/// *y* coordinate.
+pub(super) fn lift_x(x: &[u8]) -> Result<AffinePoint, error::Unspecified> {
+    let cops = &secp256k1::COMMON_OPS;
+    let x = elem_parse_big_endian_fixed_consttime(cops, untrusted::Input::from(x))?;
+    let x = cops.elem_to_mont(&x);
+
+    // y**2 = x**3 + 7.
+    let mut y_squared = cops.elem_squared(&x);
+    cops.elem_mul(&mut y_squared, &x);
+    cops.elem_add(&mut y_squared, &cops.b);
+
+    let y = secp256k1::elem_sqrt(&y_squared);
+    if cops.elems_are_equal(&cops.elem_squared(&y), &y_squared) != LimbMask::True {
+        return Err(error::Unspecified);
+    }
+    let y = if has_even_y(&y) {
+        y
+    } else {
+        cops.elem_negated(&y)
+    };
+    Ok((x, y))
+}
+
+pub(super) fn has_even_y(y: &Elem<R>) -> bool {
+    secp256k1::COMMON_OPS.elem_unencoded(y).limbs[0] & 1 == 0
+}
+
+/// Parses a 32-byte big-endian scalar in the range [0, n).
+pub(super) fn scalar_parse(bytes: &[u8]) -> Result<Scalar, error::Unspecified> {
+    if bytes.len() != ELEM_LEN {
+        return Err(error::Unspecified);
+    }
+    scalar_parse_big_endian_variable(
+        &secp256k1::COMMON_OPS,
+        limb::AllowZero::Yes,
+        untrusted::Input::from(bytes),
+    )
+}
+
+pub(super) fn is_infinity(p: &Point) -> bool {
+    let cops = &secp256k1::COMMON_OPS;
+    cops.is_zero(&cops.point_z(p))
+}
+
+/// The affine coordinates of `p`, which must not be the point at infinity.
+pub(super) fn affine(p: &Point) -> Result<AffinePoint, error::Unspecified> {
+    if is_infinity(p) {
+        return Err(error::Unspecified);
+    }
+    affine_from_jacobian(&secp256k1::PRIVATE_KEY_OPS, p)
+}
+
+// The point multiplication functions are only used with nonzero scalars
+// elsewhere, so don't rely on them handling zero.
+
+pub(super) fn point_mul_base(s: &Scalar) -> Point {
+    if secp256k1::COMMON_OPS.is_zero(s) {
+        return Point::new_at_infinity();
+    }
+    secp256k1::PRIVATE_KEY_OPS.point_mul_base(s)
+}
+
+pub(super) fn point_mul(s: &Scalar, p: &AffinePoint) -> Point {
+    if secp256k1::COMMON_OPS.is_zero(s) {
+        return Point::new_at_infinity();
+    }
+    secp256k1::PRIVATE_KEY_OPS.point_mul(s, p)
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SM2 signatures, as specified in GB/T 32918.2-2016, using SM3.
+
+use crate::{digest, error};
+
+pub mod signing;
+pub mod verification;
+
+/// The distinguishing identifier that is used when the signer and the
+/// verifier haven't agreed on another one, as recommended in GB/T 35276-2017.
+pub const SM2_DEFAULT_ID: &[u8] = b"1234567812345678";
+
+/// The encoded curve parameters *a* || *b* || *x<sub>G</sub>* ||
+/// *y<sub>G</sub>* that are digested into *Z<sub>A</sub>*.
+#[rustfmt::skip]
+static CURVE_PARAMS: [u8; 4 * 32] = [
+    // a
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+    // b
+    0x28, 0xe9, 0xfa, 0x9e, 0x9d, 0x9f, 0x5e, 0x34,
+    0x4d, 0x5a, 0x9e, 0x4b, 0xcf, 0x65, 0x09, 0xa7,
+    0xf3, 0x97, 0x89, 0xf5, 0x15, 0xab, 0x8f, 0x92,
+    0xdd, 0xbc, 0xbd, 0x41, 0x4d, 0x94, 0x0e, 0x93,
+    // x_G
+    0x32, 0xc4, 0xae, 0x2c, 0x1f, 0x19, 0x81, 0x19,
+    0x5f, 0x99, 0x04, 0x46, 0x6a, 0x39, 0xc9, 0x94,
+    0x8f, 0xe3, 0x0b, 0xbf, 0xf2, 0x66, 0x0b, 0xe1,
+    0x71, 0x5a, 0x45, 0x89, 0x33, 0x4c, 0x74, 0xc7,
+    // y_G
+    0xbc, 0x37, 0x36, 0xa2, 0xf4, 0xf6, 0x77, 0x9c,
+    0x59, 0xbd, 0xce, 0xe3, 0x6b, 0x69, 0x21, 0x53,
+    0xd0, 0xa9, 0x87, 0x7c, 0xc6, 0x2a, 0x47, 0x40,
+    0x02, 0xdf, 0x32, 0xe5, 0x21, 0x39, 0xf0, 0xa0,
+];
+
+/// Returns the digest *e* = SM3(*Z<sub>A</sub>* || *M*) of `msg`, where
+/// *Z<sub>A</sub>* = SM3(*ENTL<sub>A</sub>* || *ID<sub>A</sub>* || *a* || *b*
+/// || *x<sub>G</sub>* || *y<sub>G</sub>* || *x<sub>A</sub>* ||
+/// *y<sub>A</sub>*) binds the signer's identifier `id` and `public_key` to the
+/// signature.
+///
+/// `public_key` must already have been validated; it is the uncompressed
+/// encoding of the point *P<sub>A</sub>* = (*x<sub>A</sub>*, *y<sub>A</sub>*).
+/// *ENTL<sub>A</sub>* is the length of `id` in bits as a 16-bit big-endian
+/// integer, so `id` can be at most 8191 bytes long.
+fn digest_message(
+    id: &[u8],
+    public_key: &[u8],
+    msg: &[u8],
+) -> Result<digest::Digest, error::Unspecified> {
+    let entl = id
+        .len()
+        .checked_mul(8)
+        .filter(|&bits| bits <= usize::from(u16::MAX))
+        .ok_or(error::Unspecified)? as u16;
+    let (&tag, xy) = public_key.split_first().ok_or(error::Unspecified)?;
+    debug_assert_eq!(tag, 0x04);
+
+    let mut ctx = digest::Context::new(&digest::SM3);
+    ctx.update(&entl.to_be_bytes());
+    ctx.update(id);
+    ctx.update(&CURVE_PARAMS);
+    ctx.update(xy);
+    let za = ctx.finish();
+
+    let mut ctx = digest::Context::new(&digest::SM3);
+    ctx.update(za.as_ref());
+    ctx.update(msg);
+    Ok(ctx.finish())
+}
@@ -0,0 +1,359 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SM2 signing.
+
+use super::{digest_message, SM2_DEFAULT_ID};
+use crate::{
+    arithmetic::montgomery::*,
+    cpu, digest,
+    ec::{
+        self,
+        suite_b::{
+            ecdsa::{
+                digest_scalar::digest_scalar,
+                signing::{format_rs_asn1, format_rs_fixed, NonceRandom, NonceRandomKey},
+            },
+            ops::*,
+            private_key,
+        },
+    },
+    error, limb, pkcs8, rand, sealed, signature,
+};
+
+/// An SM2 signing algorithm.
+pub struct Sm2SigningAlgorithm {
+    format_rs: fn(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize,
+    id: AlgorithmID,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    SM2_SM3_ASN1_SIGNING,
+    SM2_SM3_FIXED_SIGNING,
+}
+
+derive_debug_via_id!(Sm2SigningAlgorithm);
+
+impl PartialEq for Sm2SigningAlgorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Sm2SigningAlgorithm {}
+
+impl sealed::Sealed for Sm2SigningAlgorithm {}
+
+/// An SM2 key pair, used for signing.
+pub struct Sm2KeyPair {
+    d: Scalar<R>,
+    d_plus_1_inv: Scalar<R>,
+    nonce_key: NonceRandomKey,
+    alg: &'static Sm2SigningAlgorithm,
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(Sm2KeyPair, stringify!(Sm2KeyPair), public_key);
+
+impl Sm2KeyPair {
+    /// Generates a new key pair and returns the key pair serialized as a
+    /// PKCS#8 document.
+    ///
+    /// The PKCS#8 document has the same form as the ones that
+    /// `EcdsaKeyPair::generate_pkcs8()` generates, with the SM2 curve
+    /// (1.2.156.10197.1.301) as the named curve.
+    pub fn generate_pkcs8(
+        alg: &'static Sm2SigningAlgorithm,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let _ = alg;
+        let private_key = ec::Seed::generate(&ec::suite_b::curve::SM2, rng, cpu::features())?;
+        let public_key = private_key.compute_public_key()?;
+        Ok(pkcs8::wrap_key(
+            &EC_PUBLIC_KEY_SM2_PKCS8_V1_TEMPLATE,
+            private_key.bytes_less_safe(),
+            public_key.as_ref(),
+        ))
+    }
+
+    /// Constructs an SM2 key pair by parsing an unencrypted PKCS#8 v1
+    /// id-ecPublicKey `ECPrivateKey` key for the SM2 curve.
+    ///
+    /// See `EcdsaKeyPair::from_pkcs8()` for the details of the format.
+    pub fn from_pkcs8(
+        alg: &'static Sm2SigningAlgorithm,
+        pkcs8: &[u8],
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::KeyRejected> {
+        let key_pair = ec::suite_b::key_pair_from_pkcs8(
+            &ec::suite_b::curve::SM2,
+            &EC_PUBLIC_KEY_SM2_PKCS8_V1_TEMPLATE,
+            untrusted::Input::from(pkcs8),
+            cpu::features(),
+        )?;
+        Self::new(alg, key_pair, rng)
+    }
+
+    /// Constructs an SM2 key pair from the private key and public key bytes.
+    ///
+    /// The private key must be encoded as a 32-byte big-endian integer and the
+    /// public key must be in uncompressed form; see
+    /// `EcdsaKeyPair::from_private_key_and_public_key()`.
+    pub fn from_private_key_and_public_key(
+        alg: &'static Sm2SigningAlgorithm,
+        private_key: &[u8],
+        public_key: &[u8],
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::KeyRejected> {
+        let key_pair = ec::suite_b::key_pair_from_bytes(
+            &ec::suite_b::curve::SM2,
+            untrusted::Input::from(private_key),
+            untrusted::Input::from(public_key),
+            cpu::features(),
+        )?;
+        Self::new(alg, key_pair, rng)
+    }
+
+    fn new(
+        alg: &'static Sm2SigningAlgorithm,
+        key_pair: ec::KeyPair,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::KeyRejected> {
+        let scalar_ops = sm2::PRIVATE_SCALAR_OPS.scalar_ops;
+        let cops = scalar_ops.common;
+
+        let (seed, public_key) = key_pair.split();
+        let d = private_key::private_key_as_scalar(&sm2::PRIVATE_KEY_OPS, &seed);
+
+        // GB/T 32918.2-2016 requires d ∈ [1, n-2] so that 1 + d is invertible.
+        let one = scalar_parse_big_endian_variable(
+            cops,
+            limb::AllowZero::No,
+            untrusted::Input::from(&[1]),
+        )
+        .unwrap();
+        let d_plus_1 = scalar_sum(cops, &d, &one);
+        if cops.is_zero(&d_plus_1) {
+            return Err(error::KeyRejected::invalid_component());
+        }
+        let d_plus_1_inv = scalar_ops.scalar_inv_to_mont(&d_plus_1);
+
+        let d = scalar_ops.scalar_product(&d, &sm2::PRIVATE_SCALAR_OPS.oneRR_mod_n);
+
+        let nonce_key = NonceRandomKey::new(&ec::suite_b::curve::SM2, &digest::SM3, &seed, rng)?;
+        Ok(Self {
+            d,
+            d_plus_1_inv,
+            nonce_key,
+            alg,
+            public_key: PublicKey(public_key),
+        })
+    }
+
+    /// Returns the signature of `msg` using a random nonce generated by `rng`
+    /// and the default distinguishing identifier `SM2_DEFAULT_ID`.
+    pub fn sign(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        self.sign_with_id(rng, SM2_DEFAULT_ID, msg)
+    }
+
+    /// Returns the signature of `msg` using a random nonce generated by `rng`
+    /// and the distinguishing identifier `id`.
+    ///
+    /// The verifier must use the same `id`; see
+    /// `Sm2VerificationAlgorithm::verify_with_id()`. Fails if `id` is longer
+    /// than 8191 bytes.
+    pub fn sign_with_id(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        id: &[u8],
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        // Steps A1 and A2: e = SM3(Z_A || M).
+        let h = digest_message(id, self.public_key.as_ref(), msg)?;
+
+        // Incorporate `h` into the nonce to hedge against faulty RNGs, as
+        // `EcdsaKeyPair` does.
+        let nonce_rng = NonceRandom {
+            key: &self.nonce_key,
+            message_digest: &h,
+            rng,
+        };
+
+        self.sign_with_nonce_rng(h, &nonce_rng)
+    }
+
+    #[cfg(test)]
+    fn sign_with_fixed_nonce_during_test(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        id: &[u8],
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let h = digest_message(id, self.public_key.as_ref(), msg)?;
+        self.sign_with_nonce_rng(h, rng)
+    }
+
+    /// Returns the signature of message digest `h` using a "random" nonce
+    /// generated by `rng`.
+    fn sign_with_nonce_rng(
+        &self,
+        h: digest::Digest,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<signature::Signature, error::Unspecified> {
+        // GB/T 32918.2-2016 Section 6.1.
+        let scalar_ops = sm2::PRIVATE_SCALAR_OPS.scalar_ops;
+        let cops = scalar_ops.common;
+        let private_key_ops = &sm2::PRIVATE_KEY_OPS;
+
+        let e = digest_scalar(scalar_ops, h);
+
+        for _ in 0..100 {
+            // Step A3.
+            let k = private_key::random_scalar(private_key_ops, rng)?;
+
+            // Step A4: (x1, y1) = [k]G.
+            let x1 = {
+                let p = private_key_ops.point_mul_base(&k);
+                let (x, _) = private_key::affine_from_jacobian(private_key_ops, &p)?;
+                elem_reduced_to_scalar(cops, &cops.elem_unencoded(&x))
+            };
+
+            // Step A5: r = (e + x1) mod n; retry if r = 0 or r + k = n.
+            let r = scalar_sum(cops, &e, &x1);
+            if cops.is_zero(&r) || cops.is_zero(&scalar_sum(cops, &r, &k)) {
+                continue;
+            }
+
+            // Step A6: s = ((1 + d)**-1 * (k - r*d)) mod n; retry if s = 0.
+            let s = {
+                let rd = scalar_ops.scalar_product(&self.d, &r);
+                let k_minus_rd = scalar_sum(cops, &k, &scalar_negated(cops, &rd));
+                scalar_ops.scalar_product(&self.d_plus_1_inv, &k_minus_rd)
+            };
+            if cops.is_zero(&s) {
+                continue;
+            }
+
+            // Step A7 with encoding.
+            return Ok(signature::Signature::new(|sig_bytes| {
+                (self.alg.format_rs)(scalar_ops, &r, &s, sig_bytes)
+            }));
+        }
+
+        Err(error::Unspecified)
+    }
+}
+
+impl signature::KeyPair for Sm2KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+impl signature::KeyPairGenerator for Sm2SigningAlgorithm {
+    type KeyPair = Sm2KeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = Sm2KeyPair::generate_pkcs8(self, rng)?;
+        let key_pair = Sm2KeyPair::from_pkcs8(self, pkcs8.as_ref(), rng)?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
+/// The public key of an `Sm2KeyPair`, in uncompressed form.
+#[derive(Clone, Copy)]
+pub struct PublicKey(ec::PublicKey);
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+/// Signing of ASN.1 DER-encoded SM2 signatures using SM3.
+///
+/// The signatures are the same as the ones that `SM2_SM3_ASN1` verifies.
+pub static SM2_SM3_ASN1_SIGNING: Sm2SigningAlgorithm = Sm2SigningAlgorithm {
+    format_rs: format_rs_asn1,
+    id: AlgorithmID::SM2_SM3_ASN1_SIGNING,
+};
+
+/// Signing of fixed-length (*r* || *s*) SM2 signatures using SM3.
+///
+/// The signatures are the same as the ones that `SM2_SM3_FIXED` verifies.
+pub static SM2_SM3_FIXED_SIGNING: Sm2SigningAlgorithm = Sm2SigningAlgorithm {
+    format_rs: format_rs_fixed,
+    id: AlgorithmID::SM2_SM3_FIXED_SIGNING,
+};
+
+static EC_PUBLIC_KEY_SM2_PKCS8_V1_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ecPublicKey_sm2_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 8, end: 27 },
+    curve_id_index: 9,
+    private_key_index: 0x24,
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::{rand, signature, test};
+
+    #[test]
+    fn signature_sm2_sign_fixed_test() {
+        let rng = rand::SystemRandom::new();
+
+        test::run(
+            test_file!("sm2_sign_fixed_tests.txt"),
+            |section, test_case| {
+                assert_eq!(section, "");
+
+                let id = test_case.consume_bytes("ID");
+                let msg = test_case.consume_bytes("Msg");
+                let d = test_case.consume_bytes("d");
+                let q = test_case.consume_bytes("Q");
+                let k = test_case.consume_bytes("k");
+
+                let expected_result = test_case.consume_bytes("Sig");
+
+                let private_key = signature::Sm2KeyPair::from_private_key_and_public_key(
+                    &signature::SM2_SM3_FIXED_SIGNING,
+                    &d,
+                    &q,
+                    &rng,
+                )
+                .unwrap();
+                let rng = test::rand::FixedSliceRandom { bytes: &k };
+
+                let actual_result = private_key
+                    .sign_with_fixed_nonce_during_test(&rng, &id, &msg)
+                    .unwrap();
+
+                assert_eq!(actual_result.as_ref(), &expected_result[..]);
+
+                Ok(())
+            },
+        );
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SM2 signature verification.
+
+use super::{digest_message, SM2_DEFAULT_ID};
+use crate::{
+    arithmetic::montgomery::*,
+    ec::suite_b::{
+        ecdsa::{
+            digest_scalar::digest_scalar,
+            verification::{split_rs_asn1, split_rs_fixed, SplitRs},
+        },
+        ops::*,
+        public_key::*,
+        verify_jacobian_point_is_on_the_curve,
+    },
+    error, limb, sealed, signature,
+};
+
+/// An SM2 verification algorithm.
+pub struct Sm2VerificationAlgorithm {
+    split_rs: SplitRs,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    SM2_SM3_ASN1,
+    SM2_SM3_FIXED,
+}
+
+derive_debug_via_id!(Sm2VerificationAlgorithm);
+
+impl signature::VerificationAlgorithm for Sm2VerificationAlgorithm {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        self.verify_(
+            public_key,
+            SM2_DEFAULT_ID,
+            msg.as_slice_less_safe(),
+            signature,
+        )
+    }
+
+    fn validate_public_key(&self, public_key: untrusted::Input) -> Result<(), error::KeyRejected> {
+        parse_uncompressed_point(sm2::PUBLIC_SCALAR_OPS.public_key_ops, public_key)
+            .map(|_| ())
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())
+    }
+}
+
+impl sealed::Sealed for Sm2VerificationAlgorithm {}
+
+impl Sm2VerificationAlgorithm {
+    /// Verifies that `signature` is a valid signature of `msg` by
+    /// `public_key`, where the signer's distinguishing identifier is `id`
+    /// instead of `SM2_DEFAULT_ID`.
+    ///
+    /// Fails if `id` is longer than 8191 bytes.
+    pub fn verify_with_id(
+        &self,
+        public_key: &[u8],
+        id: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        self.verify_(
+            untrusted::Input::from(public_key),
+            id,
+            msg,
+            untrusted::Input::from(signature),
+        )
+    }
+
+    fn verify_(
+        &self,
+        public_key: untrusted::Input,
+        id: &[u8],
+        msg: &[u8],
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        // GB/T 32918.2-2016 Section 7.1.
+        let ops = &sm2::PUBLIC_SCALAR_OPS;
+        let public_key_ops = ops.public_key_ops;
+        let scalar_ops = ops.scalar_ops;
+        let cops = public_key_ops.common;
+
+        let peer_pub_key = parse_uncompressed_point(public_key_ops, public_key)?;
+
+        let (r, s) = signature.read_all(error::Unspecified, |input| {
+            (self.split_rs)(scalar_ops, input)
+        })?;
+
+        // Steps B1 and B2: "verify whether r' ∈ [1, n-1] holds" and "verify
+        // whether s' ∈ [1, n-1] holds."
+        let r = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, r)?;
+        let s = scalar_parse_big_endian_variable(cops, limb::AllowZero::No, s)?;
+
+        // Steps B3 and B4: e' = SM3(Z_A || M').
+        let h = digest_message(id, public_key.as_slice_less_safe(), msg)?;
+        let e = digest_scalar(scalar_ops, h);
+
+        // Step B5: t = (r' + s') mod n; reject if t = 0.
+        let t = scalar_sum(cops, &r, &s);
+        if cops.is_zero(&t) {
+            return Err(error::Unspecified);
+        }
+
+        // Step B6: (x1', y1') = [s']G + [t]P_A.
+        let private_key_ops = ops.private_key_ops;
+        let product = private_key_ops.common.point_sum(
+            &private_key_ops.point_mul_base(&s),
+            &private_key_ops.point_mul(&t, &peer_pub_key),
+        );
+
+        // See `EcdsaVerificationAlgorithm` for why the Jacobian point is
+        // verified to be on the curve.
+        let z2 = verify_jacobian_point_is_on_the_curve(cops, &product)?;
+
+        // Step B7: R = (e' + x1') mod n, and the signature is valid if R = r'.
+        //
+        // Equivalently, x1' = (r' - e') mod n, which can be checked without
+        // computing the affine X coordinate using the same trick that ECDSA
+        // verification uses.
+        let x = cops.point_x(&product);
+        fn v_equals_x(
+            ops: &PublicScalarOps,
+            v: &Elem<Unencoded>,
+            x: &Elem<R>,
+            z2: &Elem<R>,
+        ) -> bool {
+            let cops = ops.public_key_ops.common;
+            let v_jacobian = cops.elem_product(z2, v);
+            let x = cops.elem_unencoded(x);
+            ops.elem_equals_vartime(&v_jacobian, &x)
+        }
+        let v = scalar_sum(cops, &r, &scalar_negated(cops, &e));
+        let mut v = ops.scalar_as_elem(&v);
+        if v_equals_x(ops, &v, &x, &z2) {
+            return Ok(());
+        }
+        if ops.elem_less_than(&v, &ops.q_minus_n) {
+            private_key_ops.common.elem_add(&mut v, &cops.n);
+            if v_equals_x(ops, &v, &x, &z2) {
+                return Ok(());
+            }
+        }
+
+        Err(error::Unspecified)
+    }
+}
+
+/// Verification of ASN.1 DER-encoded SM2 signatures using SM3 and the
+/// default distinguishing identifier `SM2_DEFAULT_ID`.
+///
+/// Use `Sm2VerificationAlgorithm::verify_with_id()` to verify signatures made
+/// with another distinguishing identifier.
+pub static SM2_SM3_ASN1: Sm2VerificationAlgorithm = Sm2VerificationAlgorithm {
+    split_rs: split_rs_asn1,
+    id: AlgorithmID::SM2_SM3_ASN1,
+};
+
+/// Verification of fixed-length (*r* || *s*) SM2 signatures using SM3 and the
+/// default distinguishing identifier `SM2_DEFAULT_ID`.
+///
+/// Use `Sm2VerificationAlgorithm::verify_with_id()` to verify signatures made
+/// with another distinguishing identifier.
+pub static SM2_SM3_FIXED: Sm2VerificationAlgorithm = Sm2VerificationAlgorithm {
+    split_rs: split_rs_fixed,
+    id: AlgorithmID::SM2_SM3_FIXED,
+};
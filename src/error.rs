@@ -154,7 +154,6 @@ impl KeyRejected {
         Self("PublicKeyIsMissing")
     }
 
-    #[cfg(feature = "alloc")]
     pub(crate) fn too_small() -> Self {
         Self("TooSmall")
     }
@@ -0,0 +1,73 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! FROST threshold signatures.
+//!
+//! FROST is a two-round protocol in which `t` of the `n` holders of shares
+//! of a private key, with the help of a coordinator, produce a signature
+//! without ever reconstructing the private key. The signature is an ordinary
+//! Ed25519 signature that verifies with the group public key.
+//!
+//! This implements FROST(Ed25519, SHA-512), as specified in [RFC 9591].
+//!
+//! In round one, each signer calls `KeyPackage::commit()`, keeps the
+//! nonces, and sends the commitments to the coordinator. The coordinator
+//! chooses the signers, sorts their commitments by identifier, and sends the
+//! list and the message to each of them. In round two, each signer calls
+//! `KeyPackage::sign()` and sends its signature share back, and the
+//! coordinator calls `aggregate()` to get the signature.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use ring::{frost, rand, signature};
+//!
+//! let rng = rand::SystemRandom::new();
+//! let key_packages = frost::generate_with_dealer(2, 3, &rng)?;
+//! let group_public_key = key_packages[0].group_public_key().as_ref();
+//!
+//! // Round one, for the participants 1 and 3.
+//! let signers = [&key_packages[0], &key_packages[2]];
+//! let mut nonces = Vec::new();
+//! let mut commitments = Vec::new();
+//! for signer in signers.iter() {
+//!     let (n, c) = signer.commit(&rng)?;
+//!     nonces.push(n);
+//!     commitments.push(c);
+//! }
+//!
+//! // Round two.
+//! let msg = b"transfer 100";
+//! let mut shares = Vec::new();
+//! for (signer, n) in signers.iter().zip(nonces) {
+//!     shares.push(signer.sign(n, &commitments, msg)?);
+//! }
+//!
+//! let sig = frost::aggregate(group_public_key, &commitments, msg, &shares)?;
+//! let public_key = signature::UnparsedPublicKey::new(&signature::ED25519, group_public_key);
+//! public_key.verify(msg, sig.as_ref())?;
+//! # }
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 9591]: https://www.rfc-editor.org/rfc/rfc9591
+
+#[cfg(feature = "alloc")]
+pub use crate::ec::curve25519::frost::generate_with_dealer;
+
+pub use crate::ec::curve25519::frost::{
+    aggregate, verify_signature_share, KeyPackage, PublicKey, SignatureShare, SigningCommitments,
+    SigningNonces, COMMITMENT_LEN, SIGNATURE_SHARE_LEN,
+};
@@ -18,7 +18,8 @@
 //!
 //! [RFC 5869]: https://tools.ietf.org/html/rfc5869
 
-use crate::{error, hmac};
+use crate::{aead, agreement, error, hmac, kdf};
+use core::convert::TryFrom;
 
 /// An HKDF algorithm.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -51,6 +52,49 @@ impl KeyType for Algorithm {
     }
 }
 
+/// HKDF-Extract with `salt`, then HKDF-Expand with `info`.
+impl kdf::Kdf for Algorithm {
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let prk = Salt::new(*self, salt).extract(secret);
+        let info = [info];
+        prk.expand(&info, OutLen(out.len()))?.fill(out)
+    }
+}
+
+/// An output length for `Kdf::derive`.
+struct OutLen(usize);
+
+impl KeyType for OutLen {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+/// HKDF-Extract with `salt`, then HKDF-Expand with `info` into an array of
+/// `N` bytes.
+///
+/// This is equivalent to `Salt::new(algorithm, salt).extract(secret)`
+/// followed by `expand(info, ...)` and `fill()`.
+///
+/// Fails if (and only if) `N` is too large for `algorithm`.
+pub fn derive<const N: usize>(
+    algorithm: Algorithm,
+    salt: &[u8],
+    secret: &[u8],
+    info: &[&[u8]],
+) -> Result<[u8; N], error::Unspecified> {
+    let prk = Salt::new(algorithm, salt).extract(secret);
+    let mut out = [0u8; N];
+    prk.expand(info, OutLen(N))?.fill(&mut out)?;
+    Ok(out)
+}
+
 /// A salt for HKDF operations.
 #[derive(Debug)]
 pub struct Salt(hmac::Key);
@@ -135,11 +179,93 @@ impl Prk {
         }
         Ok(Okm {
             prk: self,
-            info,
+            info: Info::Parts(info),
             len,
             len_cached,
         })
     }
+
+    /// The HKDF-Expand-Label operation of [TLS 1.3], which is also used by
+    /// QUIC: HKDF-Expand with the `info` being the encoded `HkdfLabel`
+    /// structure with the given `label` and `context`.
+    ///
+    /// `label` must not include the "tls13 " prefix; it is added
+    /// automatically. For example, a QUIC packet protection key is derived
+    /// with the label `b"quic key"`.
+    ///
+    /// Fails if `len` is too large, if `label` is empty or longer than 249
+    /// bytes, or if `context` is longer than 255 bytes.
+    ///
+    /// [TLS 1.3]: https://tools.ietf.org/html/rfc8446#section-7.1
+    pub fn expand_label<'a, L: KeyType>(
+        &'a self,
+        label: &'a [u8],
+        context: &'a [u8],
+        len: L,
+    ) -> Result<Okm<'a, L>, error::Unspecified> {
+        let len_cached = len.len();
+        if len_cached > 255 * self.0.algorithm().digest_algorithm().output_len {
+            return Err(error::Unspecified);
+        }
+        let full_label_len = TLS13_LABEL_PREFIX.len() + label.len();
+        if label.is_empty() || full_label_len > 255 || context.len() > 255 {
+            return Err(error::Unspecified);
+        }
+        Ok(Okm {
+            prk: self,
+            info: Info::Label {
+                // `len_cached` fits in 16 bits because the digest output
+                // length is at most 64 bytes.
+                len: (len_cached as u16).to_be_bytes(),
+                label,
+                context,
+            },
+            len,
+            len_cached,
+        })
+    }
+}
+
+/// The prefix of every TLS 1.3 HKDF-Expand-Label label.
+const TLS13_LABEL_PREFIX: &[u8] = b"tls13 ";
+
+/// The `info` input of HKDF-Expand.
+#[derive(Clone, Copy, Debug)]
+enum Info<'a> {
+    /// The concatenation of the given parts.
+    Parts(&'a [&'a [u8]]),
+
+    /// The TLS 1.3 `HkdfLabel` structure.
+    Label {
+        len: [u8; 2],
+        label: &'a [u8],
+        context: &'a [u8],
+    },
+}
+
+impl Info<'_> {
+    fn update(&self, ctx: &mut hmac::Context) {
+        match self {
+            Info::Parts(parts) => {
+                for part in parts.iter() {
+                    ctx.update(part);
+                }
+            }
+            Info::Label {
+                len,
+                label,
+                context,
+            } => {
+                // The lengths were checked by `Prk::expand_label`.
+                ctx.update(len);
+                ctx.update(&[(TLS13_LABEL_PREFIX.len() + label.len()) as u8]);
+                ctx.update(TLS13_LABEL_PREFIX);
+                ctx.update(label);
+                ctx.update(&[context.len() as u8]);
+                ctx.update(context);
+            }
+        }
+    }
 }
 
 impl From<Okm<'_, Algorithm>> for Prk {
@@ -160,12 +286,12 @@ impl From<Okm<'_, Algorithm>> for Prk {
 #[derive(Debug)]
 pub struct Okm<'a, L: KeyType> {
     prk: &'a Prk,
-    info: &'a [&'a [u8]],
+    info: Info<'a>,
     len: L,
     len_cached: usize,
 }
 
-impl<L: KeyType> Okm<'_, L> {
+impl<'a, L: KeyType> Okm<'a, L> {
     /// The `OkmLength` given to `Prk::expand()`.
     #[inline]
     pub fn len(&self) -> &L {
@@ -181,16 +307,59 @@ impl<L: KeyType> Okm<'_, L> {
     /// constructed.)
     #[inline]
     pub fn fill(self, out: &mut [u8]) -> Result<(), error::Unspecified> {
-        fill_okm(self.prk, self.info, out, self.len_cached)
+        fill_okm(self.prk, &self.info, out, self.len_cached)
+    }
+
+    /// Uses the OKM as a key for `algorithm`, without copying it through a
+    /// buffer owned by the caller.
+    ///
+    /// Fails if the OKM's length isn't `algorithm.key_len()`.
+    pub fn into_aead_key(
+        self,
+        algorithm: &'static aead::Algorithm,
+    ) -> Result<aead::UnboundKey, error::Unspecified> {
+        Ok(aead::UnboundKey::from(self.with_len(algorithm)?))
+    }
+
+    /// Uses the OKM as an HMAC key for `algorithm`.
+    ///
+    /// Fails if the OKM's length isn't the output length of `algorithm`'s
+    /// digest algorithm.
+    pub fn into_hmac_key(
+        self,
+        algorithm: hmac::Algorithm,
+    ) -> Result<hmac::Key, error::Unspecified> {
+        Ok(hmac::Key::from(self.with_len(algorithm)?))
+    }
+
+    /// Uses the OKM as a private key for `algorithm`; see the
+    /// `TryFrom<Okm>` implementation of `agreement::EphemeralPrivateKey`.
+    ///
+    /// Fails if the OKM's length isn't the length of `algorithm`'s private
+    /// keys, or if the OKM isn't a valid private key.
+    pub fn into_agreement_private_key(
+        self,
+        algorithm: &'static agreement::Algorithm,
+    ) -> Result<agreement::EphemeralPrivateKey, error::Unspecified> {
+        agreement::EphemeralPrivateKey::try_from(self.with_len(algorithm)?)
+    }
+
+    /// The same OKM, with the length given as `len`, which must be the
+    /// same length.
+    fn with_len<L2: KeyType>(self, len: L2) -> Result<Okm<'a, L2>, error::Unspecified> {
+        if len.len() != self.len_cached {
+            return Err(error::Unspecified);
+        }
+        Ok(Okm {
+            prk: self.prk,
+            info: self.info,
+            len,
+            len_cached: self.len_cached,
+        })
     }
 }
 
-fn fill_okm(
-    prk: &Prk,
-    info: &[&[u8]],
-    out: &mut [u8],
-    len: usize,
-) -> Result<(), error::Unspecified> {
+fn fill_okm(prk: &Prk, info: &Info, out: &mut [u8], len: usize) -> Result<(), error::Unspecified> {
     if out.len() != len {
         return Err(error::Unspecified);
     }
@@ -203,9 +372,7 @@ fn fill_okm(
     let mut n = 1u8;
     let mut out = out;
     loop {
-        for info in info {
-            ctx.update(info);
-        }
+        info.update(&mut ctx);
         ctx.update(&[n]);
 
         let t = ctx.sign();
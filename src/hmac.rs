@@ -111,6 +111,9 @@
 
 use crate::{constant_time, digest, error, hkdf, rand};
 
+#[cfg(feature = "std")]
+extern crate std;
+
 /// An HMAC algorithm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Algorithm(&'static digest::Algorithm);
@@ -121,8 +124,22 @@ impl Algorithm {
     pub fn digest_algorithm(&self) -> &'static digest::Algorithm {
         self.0
     }
+
+    /// The length of the shortest truncated tag that `verify_truncated`
+    /// accepts for this algorithm: half the digest algorithm's output length,
+    /// but at least `MIN_TRUNCATED_TAG_LEN`, per [RFC 2104 Section 5].
+    ///
+    /// [RFC 2104 Section 5]: https://tools.ietf.org/html/rfc2104#section-5
+    #[inline]
+    pub fn min_truncated_tag_len(&self) -> usize {
+        core::cmp::max(MIN_TRUNCATED_TAG_LEN, self.0.output_len / 2)
+    }
 }
 
+/// The length of the shortest truncated tag that `verify_truncated` accepts
+/// for any algorithm: 80 bits.
+pub const MIN_TRUNCATED_TAG_LEN: usize = 80 / 8;
+
 /// HMAC using SHA-1. Obsolete.
 pub static HMAC_SHA1_FOR_LEGACY_USE_ONLY: Algorithm = Algorithm(&digest::SHA1_FOR_LEGACY_USE_ONLY);
 
@@ -135,6 +152,18 @@ pub static HMAC_SHA384: Algorithm = Algorithm(&digest::SHA384);
 /// HMAC using SHA-512.
 pub static HMAC_SHA512: Algorithm = Algorithm(&digest::SHA512);
 
+/// HMAC using SHA-512/256.
+pub static HMAC_SHA512_256: Algorithm = Algorithm(&digest::SHA512_256);
+
+/// HMAC using SHA3-256.
+pub static HMAC_SHA3_256: Algorithm = Algorithm(&digest::SHA3_256);
+
+/// HMAC using SHA3-384.
+pub static HMAC_SHA3_384: Algorithm = Algorithm(&digest::SHA3_384);
+
+/// HMAC using SHA3-512.
+pub static HMAC_SHA3_512: Algorithm = Algorithm(&digest::SHA3_512);
+
 /// An HMAC tag.
 ///
 /// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
@@ -273,6 +302,8 @@ impl From<hkdf::Okm<'_, Algorithm>> for Key {
 pub struct Context {
     inner: digest::Context,
     outer: digest::BlockContext,
+    // The state of `inner` to return to in `sign_and_reset`.
+    inner_initial: digest::BlockContext,
 }
 
 impl core::fmt::Debug for Context {
@@ -290,6 +321,7 @@ impl Context {
         Self {
             inner: digest::Context::clone_from(&signing_key.inner),
             outer: signing_key.outer.clone(),
+            inner_initial: signing_key.inner.clone(),
         }
     }
 
@@ -307,15 +339,60 @@ impl Context {
     /// the return value of `sign` to a tag. Use `verify` for verification
     /// instead.
     pub fn sign(self) -> Tag {
-        let algorithm = self.inner.algorithm();
-        let mut pending = [0u8; digest::MAX_BLOCK_LEN];
-        let pending = &mut pending[..algorithm.block_len];
-        let num_pending = algorithm.output_len;
-        pending[..num_pending].copy_from_slice(self.inner.finish().as_ref());
-        Tag(self.outer.finish(pending, num_pending))
+        finish_outer(self.outer, self.inner.finish())
+    }
+
+    /// Finalizes the HMAC calculation, returns the HMAC value, and resets the
+    /// context to the state `Context::with_key` returned, so that it can be
+    /// used to sign another message with the same key without constructing a
+    /// new context.
+    ///
+    /// It is generally not safe to implement HMAC verification by comparing
+    /// the return value of `sign_and_reset` to a tag. Use `verify` for
+    /// verification instead.
+    pub fn sign_and_reset(&mut self) -> Tag {
+        let reset = digest::Context::clone_from(&self.inner_initial);
+        let inner = core::mem::replace(&mut self.inner, reset).finish();
+        finish_outer(self.outer.clone(), inner)
+    }
+}
+
+/// Writing to a `Context` updates the HMAC with the written data, so a
+/// `Context` can be used with `std::io::copy` and anything else that writes
+/// to an `std::io::Write`.
+///
+/// ```
+/// use ring::hmac;
+///
+/// let key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+/// let mut ctx = hmac::Context::with_key(&key);
+/// std::io::copy(&mut &b"hello, world"[..], &mut ctx).unwrap();
+///
+/// hmac::verify(&key, b"hello, world", ctx.sign().as_ref()).unwrap();
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Write for Context {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
     }
 }
 
+fn finish_outer(outer: digest::BlockContext, inner: digest::Digest) -> Tag {
+    let algorithm = inner.algorithm();
+    let mut pending = [0u8; digest::MAX_BLOCK_LEN];
+    let pending = &mut pending[..algorithm.block_len];
+    let num_pending = algorithm.output_len;
+    pending[..num_pending].copy_from_slice(inner.as_ref());
+    Tag(outer.finish(pending, num_pending))
+}
+
 /// Calculates the HMAC of `data` using the key `key` in one step.
 ///
 /// Use `Context` to calculate HMACs where the input is in multiple parts.
@@ -328,6 +405,28 @@ pub fn sign(key: &Key, data: &[u8]) -> Tag {
     ctx.sign()
 }
 
+/// Calculates the HMAC of the concatenation of `parts` using the key `key`
+/// in one step, without concatenating them first.
+///
+/// ```
+/// use ring::hmac;
+///
+/// let key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+/// let (header, payload) = (&b"header"[..], &b"payload"[..]);
+///
+/// let tag = hmac::sign_slices(&key, &[header, payload]);
+/// hmac::verify(&key, b"headerpayload", tag.as_ref()).unwrap();
+/// ```
+///
+/// It is generally not safe to implement HMAC verification by comparing the
+/// return value of `sign_slices` to a tag. Use `verify` for verification
+/// instead.
+pub fn sign_slices(key: &Key, parts: &[&[u8]]) -> Tag {
+    let mut ctx = Context::with_key(key);
+    parts.iter().for_each(|part| ctx.update(part));
+    ctx.sign()
+}
+
 /// Calculates the HMAC of `data` using the signing key `key`, and verifies
 /// whether the resultant value equals `tag`, in one step.
 ///
@@ -339,6 +438,26 @@ pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecifi
     constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
 }
 
+/// Calculates the HMAC of `data` using the key `key`, and verifies whether
+/// `tag` is the HMAC truncated to `tag.len()` bytes, i.e. a prefix of it,
+/// in one step.
+///
+/// This is for protocols that use truncated tags, like IPsec's
+/// HMAC-SHA-256-128. `tag` must be at least `min_truncated_tag_len()` bytes
+/// of `key.algorithm()`, and no longer than the untruncated tag; any other
+/// length is rejected.
+///
+/// The verification will be done in constant time to prevent timing attacks.
+pub fn verify_truncated(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    let algorithm = key.algorithm();
+    if tag.len() < algorithm.min_truncated_tag_len()
+        || tag.len() > algorithm.digest_algorithm().output_len
+    {
+        return Err(error::Unspecified);
+    }
+    constant_time::verify_slices_are_equal(&sign(key, data).as_ref()[..tag.len()], tag)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{hmac, rand};
@@ -357,6 +476,10 @@ mod tests {
             hmac::HMAC_SHA256,
             hmac::HMAC_SHA384,
             hmac::HMAC_SHA512,
+            hmac::HMAC_SHA512_256,
+            hmac::HMAC_SHA3_256,
+            hmac::HMAC_SHA3_384,
+            hmac::HMAC_SHA3_512,
         ] {
             let key = hmac::Key::generate(*algorithm, &rng).unwrap();
             let tag = hmac::sign(&key, HELLO_WORLD_GOOD);
@@ -0,0 +1,376 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Implementations of the traits of the [`signature`] crate.
+//!
+//! [`signature`]: https://crates.io/crates/signature
+
+use crate::{
+    error,
+    signature::{self, KeyPair},
+};
+use core::convert::TryFrom;
+use rustcrypto_signature as traits;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    rand,
+    rsa::{padding::RsaEncoding, RsaParameters},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+impl TryFrom<&[u8]> for signature::Signature {
+    type Error = traits::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > signature::MAX_LEN {
+            return Err(traits::Error::new());
+        }
+        Ok(Self::new(|value| {
+            value[..bytes.len()].copy_from_slice(bytes);
+            bytes.len()
+        }))
+    }
+}
+
+impl traits::SignatureEncoding for signature::Signature {
+    type Repr = Self;
+}
+
+impl<B: AsRef<[u8]>> traits::Verifier<signature::Signature> for signature::UnparsedPublicKey<B> {
+    fn verify(&self, msg: &[u8], signature: &signature::Signature) -> Result<(), traits::Error> {
+        Self::verify(self, msg, signature.as_ref())
+            .map_err(|error::Unspecified| traits::Error::new())
+    }
+}
+
+impl<B: AsRef<[u8]>> traits::Verifier<signature::Signature> for signature::ParsedPublicKey<B> {
+    fn verify(&self, msg: &[u8], signature: &signature::Signature) -> Result<(), traits::Error> {
+        Self::verify(self, msg, signature.as_ref())
+            .map_err(|error::Unspecified| traits::Error::new())
+    }
+}
+
+impl traits::Signer<signature::Signature> for signature::Ed25519KeyPair {
+    fn try_sign(&self, msg: &[u8]) -> Result<signature::Signature, traits::Error> {
+        Ok(self.sign(msg))
+    }
+}
+
+impl traits::Keypair for signature::Ed25519KeyPair {
+    type VerifyingKey =
+        signature::UnparsedPublicKey<<signature::Ed25519KeyPair as KeyPair>::PublicKey>;
+
+    fn verifying_key(&self) -> Self::VerifyingKey {
+        signature::UnparsedPublicKey::new(&signature::ED25519, *self.public_key())
+    }
+}
+
+/// The signatures are deterministic, as described in RFC 6979, since the
+/// trait doesn't provide a way to pass in a random number generator.
+impl traits::Signer<signature::Signature> for signature::EcdsaKeyPair {
+    fn try_sign(&self, msg: &[u8]) -> Result<signature::Signature, traits::Error> {
+        self.sign_deterministic(msg)
+            .map_err(|error::Unspecified| traits::Error::new())
+    }
+}
+
+impl traits::Keypair for signature::EcdsaKeyPair {
+    type VerifyingKey =
+        signature::UnparsedPublicKey<<signature::EcdsaKeyPair as KeyPair>::PublicKey>;
+
+    fn verifying_key(&self) -> Self::VerifyingKey {
+        signature::UnparsedPublicKey::new(self.verification_algorithm(), *self.public_key())
+    }
+}
+
+/// An RSA signature.
+///
+/// RSA signatures are as long as the public modulus, so they don't fit in a
+/// `Signature`.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct RsaSignature(Box<[u8]>);
+
+#[cfg(feature = "alloc")]
+derive_debug_self_as_ref_hex_bytes!(RsaSignature);
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for RsaSignature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&[u8]> for RsaSignature {
+    type Error = traits::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(bytes.into()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<RsaSignature> for Box<[u8]> {
+    fn from(signature: RsaSignature) -> Self {
+        signature.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl traits::SignatureEncoding for RsaSignature {
+    type Repr = Box<[u8]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<B: AsRef<[u8]>> traits::Verifier<RsaSignature> for signature::UnparsedPublicKey<B> {
+    fn verify(&self, msg: &[u8], signature: &RsaSignature) -> Result<(), traits::Error> {
+        Self::verify(self, msg, signature.as_ref())
+            .map_err(|error::Unspecified| traits::Error::new())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: AsRef<[u8]>> traits::Verifier<RsaSignature> for signature::ParsedPublicKey<B> {
+    fn verify(&self, msg: &[u8], signature: &RsaSignature) -> Result<(), traits::Error> {
+        Self::verify(self, msg, signature.as_ref())
+            .map_err(|error::Unspecified| traits::Error::new())
+    }
+}
+
+/// An RSA key pair and the padding to sign with.
+///
+/// `RsaKeyPair::sign()` takes the padding algorithm as a parameter, but the
+/// `signature` crate's traits don't, so it has to be chosen in advance.
+#[cfg(feature = "alloc")]
+pub struct RsaSigningKey {
+    key_pair: signature::RsaKeyPair,
+    padding_alg: &'static dyn RsaEncoding,
+    verification_alg: &'static RsaParameters,
+}
+
+#[cfg(feature = "alloc")]
+derive_debug_via_field!(RsaSigningKey, stringify!(RsaSigningKey), key_pair);
+
+#[cfg(feature = "alloc")]
+impl RsaSigningKey {
+    /// Constructs an `RsaSigningKey` that signs with `key_pair` using
+    /// `padding_alg`.
+    ///
+    /// `padding_alg` must be one of the `RSA_PKCS1_*` or `RSA_PSS_*`
+    /// encodings. The verifying key uses the `RSA_PKCS1_2048_8192_*` or
+    /// `RSA_PSS_2048_8192_*` algorithm with the same padding and digest
+    /// algorithm.
+    pub fn new(
+        key_pair: signature::RsaKeyPair,
+        padding_alg: &'static dyn RsaEncoding,
+    ) -> Result<Self, error::Unspecified> {
+        let verification_alg = rsa_verification_alg(padding_alg).ok_or(error::Unspecified)?;
+        Ok(Self {
+            key_pair,
+            padding_alg,
+            verification_alg,
+        })
+    }
+
+    /// The key pair.
+    #[inline]
+    pub fn key_pair(&self) -> &signature::RsaKeyPair {
+        &self.key_pair
+    }
+}
+
+/// The random bytes needed for PSS padding are generated by
+/// `rand::SystemRandom`, since the trait doesn't provide a way to pass in a
+/// random number generator.
+#[cfg(feature = "alloc")]
+impl traits::Signer<RsaSignature> for RsaSigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<RsaSignature, traits::Error> {
+        let rng = rand::SystemRandom::new();
+        let mut signature = alloc::vec![0; self.key_pair.public_modulus_len()];
+        self.key_pair
+            .sign(self.padding_alg, &rng, msg, &mut signature)
+            .map_err(|error::Unspecified| traits::Error::new())?;
+        Ok(RsaSignature(signature.into()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl traits::Keypair for RsaSigningKey {
+    type VerifyingKey = signature::UnparsedPublicKey<<signature::RsaKeyPair as KeyPair>::PublicKey>;
+
+    fn verifying_key(&self) -> Self::VerifyingKey {
+        signature::UnparsedPublicKey::new(self.verification_alg, self.key_pair.public_key().clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn rsa_verification_alg(padding_alg: &'static dyn RsaEncoding) -> Option<&'static RsaParameters> {
+    let algs: [(&'static dyn RsaEncoding, &'static RsaParameters); 12] = [
+        (
+            &signature::RSA_PKCS1_SHA256,
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PKCS1_SHA384,
+            &signature::RSA_PKCS1_2048_8192_SHA384,
+        ),
+        (
+            &signature::RSA_PKCS1_SHA512,
+            &signature::RSA_PKCS1_2048_8192_SHA512,
+        ),
+        (
+            &signature::RSA_PSS_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PSS_SHA384,
+            &signature::RSA_PSS_2048_8192_SHA384,
+        ),
+        (
+            &signature::RSA_PSS_SHA512,
+            &signature::RSA_PSS_2048_8192_SHA512,
+        ),
+        (
+            &signature::RSA_PSS_SHA256_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_ZERO,
+        ),
+        (
+            &signature::RSA_PSS_SHA256_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_MAX,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_ZERO,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_MAX,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_ZERO,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_MAX,
+        ),
+    ];
+    // The encodings are statics, so they can be compared by address.
+    let address = |alg: &'static dyn RsaEncoding| alg as *const dyn RsaEncoding as *const u8;
+    algs.iter()
+        .find(|&&(alg, _)| address(alg) == address(padding_alg))
+        .map(|&(_, verification_alg)| verification_alg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand;
+    use traits::{Keypair, Signer, Verifier};
+
+    #[test]
+    fn signature_encoding_test() {
+        let bytes = [0x5a; signature::MAX_LEN];
+        let signature = signature::Signature::try_from(&bytes[..]).unwrap();
+        assert_eq!(signature.as_ref(), &bytes[..]);
+        assert_eq!(
+            traits::SignatureEncoding::encoded_len(&signature),
+            bytes.len()
+        );
+
+        let too_long = [0x5a; signature::MAX_LEN + 1];
+        assert!(signature::Signature::try_from(&too_long[..]).is_err());
+    }
+
+    #[test]
+    fn ed25519_signer_test() {
+        let key_pair = signature::Ed25519KeyPair::from_pkcs8(include_bytes!(
+            "../tests/ed25519_test_private_key.p8"
+        ))
+        .unwrap();
+        let signature: signature::Signature = key_pair.sign(b"hello, world");
+        let public_key = key_pair.verifying_key();
+        assert!(Verifier::verify(&public_key, b"hello, world", &signature).is_ok());
+        assert!(Verifier::verify(&public_key, b"hello, world!", &signature).is_err());
+    }
+
+    #[test]
+    fn ecdsa_signer_test() {
+        let rng = rand::SystemRandom::new();
+        for &(alg, expected_len) in &[
+            (&signature::ECDSA_P256_SHA256_FIXED_SIGNING, Some(64)),
+            (&signature::ECDSA_P384_SHA384_ASN1_SIGNING, None),
+            (
+                &signature::ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING,
+                Some(64),
+            ),
+        ] {
+            let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+            let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+            let signature: signature::Signature = Signer::sign(&key_pair, b"hello, world");
+            if let Some(expected_len) = expected_len {
+                assert_eq!(signature.as_ref().len(), expected_len);
+            }
+
+            // The signatures are deterministic.
+            let again: signature::Signature = Signer::sign(&key_pair, b"hello, world");
+            assert_eq!(signature.as_ref(), again.as_ref());
+
+            let public_key = key_pair.verifying_key();
+            assert!(Verifier::verify(&public_key, b"hello, world", &signature).is_ok());
+            assert!(Verifier::verify(&public_key, b"hello, world!", &signature).is_err());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rsa_signer_test() {
+        const PRIVATE_KEY_DER: &[u8] = include_bytes!("rsa/signature_rsa_example_private_key.der");
+        for &(padding_alg, verification_alg) in &[
+            (
+                &signature::RSA_PKCS1_SHA256 as &'static dyn RsaEncoding,
+                &signature::RSA_PKCS1_2048_8192_SHA256,
+            ),
+            (
+                &signature::RSA_PSS_SHA512,
+                &signature::RSA_PSS_2048_8192_SHA512,
+            ),
+        ] {
+            let key_pair = signature::RsaKeyPair::from_der(PRIVATE_KEY_DER).unwrap();
+            let public_key_bytes = key_pair.public_key().as_ref().to_vec();
+            let signing_key = RsaSigningKey::new(key_pair, padding_alg).unwrap();
+
+            let signature: RsaSignature = signing_key.sign(b"hello, world");
+            assert_eq!(
+                signature.as_ref().len(),
+                signing_key.key_pair().public_modulus_len()
+            );
+
+            let public_key = signing_key.verifying_key();
+            assert!(Verifier::verify(&public_key, b"hello, world", &signature).is_ok());
+            assert!(Verifier::verify(&public_key, b"hello, world!", &signature).is_err());
+
+            let public_key =
+                signature::ParsedPublicKey::new(verification_alg, &public_key_bytes[..]).unwrap();
+            assert!(Verifier::verify(&public_key, b"hello, world", &signature).is_ok());
+
+            let parsed = RsaSignature::try_from(signature.as_ref()).unwrap();
+            assert!(Verifier::verify(&public_key, b"hello, world", &parsed).is_ok());
+        }
+    }
+}
@@ -39,7 +39,7 @@ pub(crate) fn write_all(tag: Tag, write_value: &dyn Fn(&mut dyn Accumulator)) ->
     output.into()
 }
 
-fn write_tlv<F>(output: &mut dyn Accumulator, tag: Tag, write_value: F)
+pub(crate) fn write_tlv<F>(output: &mut dyn Accumulator, tag: Tag, write_value: F)
 where
     F: Fn(&mut dyn Accumulator),
 {
@@ -0,0 +1,232 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The key-based key derivation function (KBKDF) in counter mode, as
+//! specified in [NIST SP 800-108r1] Section 4.1.
+//!
+//! Each block of output is `PRF(key, [i] || Label || 0x00 || Context || [L])`,
+//! where `[i]` is the block counter, starting at 1, and `[L]` is the length
+//! of the output in bits, both big-endian. The widths of `[i]` and `[L]`,
+//! whether `[L]` is included at all, and whether the zero byte is included
+//! are all parameters of the protocol; they're given as an `Encoding`. The
+//! counter always comes before the rest of the input.
+//!
+//! ```
+//! use ring::{hmac, kbkdf};
+//!
+//! let key = hmac::Key::new(hmac::HMAC_SHA256, &[0; 32]);
+//! let mut derived = [0u8; 48];
+//! kbkdf::derive(
+//!     kbkdf::Prf::Hmac(&key),
+//!     &kbkdf::Encoding::DEFAULT,
+//!     b"label",
+//!     b"context",
+//!     &mut derived,
+//! )
+//! .unwrap();
+//! ```
+//!
+//! [NIST SP 800-108r1]: https://doi.org/10.6028/NIST.SP.800-108r1
+
+use crate::{cmac, error, hmac, kdf, polyfill};
+
+/// The pseudorandom function (PRF), with its key.
+#[derive(Clone, Copy, Debug)]
+pub enum Prf<'a> {
+    /// HMAC with the given key.
+    Hmac(&'a hmac::Key),
+
+    /// AES-CMAC with the given key.
+    Cmac(&'a cmac::Key),
+}
+
+impl Prf<'_> {
+    fn output_len(&self) -> usize {
+        match self {
+            Prf::Hmac(key) => key.algorithm().digest_algorithm().output_len,
+            Prf::Cmac(_) => cmac::TAG_LEN,
+        }
+    }
+
+    fn compute(&self, parts: &[&[u8]], out: &mut [u8]) {
+        match self {
+            Prf::Hmac(key) => {
+                let mut ctx = hmac::Context::with_key(key);
+                parts.iter().for_each(|part| ctx.update(part));
+                out.copy_from_slice(&ctx.sign().as_ref()[..out.len()]);
+            }
+            Prf::Cmac(key) => {
+                let mut ctx = cmac::Context::with_key(key);
+                parts.iter().for_each(|part| ctx.update(part));
+                out.copy_from_slice(&ctx.sign().as_ref()[..out.len()]);
+            }
+        }
+    }
+}
+
+/// How the input to the PRF is encoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Encoding {
+    counter_len: usize,
+    length_len: usize,
+    separator: bool,
+}
+
+impl Encoding {
+    /// A 32-bit counter, a zero byte between the label and the context, and a
+    /// 32-bit output length, as used by e.g. the TPM 2.0 `KDFa`.
+    pub const DEFAULT: Self = Self {
+        counter_len: 4,
+        length_len: 4,
+        separator: true,
+    };
+
+    /// Constructs an encoding with a `counter_bits`-bit counter and a
+    /// `length_bits`-bit output length, and with a zero byte between the label
+    /// and the context if `separator` is true.
+    ///
+    /// `counter_bits` must be 8, 16, 24, or 32. `length_bits` must be 8, 16,
+    /// 24, or 32, or 0 if the output length isn't part of the input.
+    pub fn new(
+        counter_bits: usize,
+        length_bits: usize,
+        separator: bool,
+    ) -> Result<Self, error::Unspecified> {
+        if !matches!(counter_bits, 8 | 16 | 24 | 32) || !matches!(length_bits, 0 | 8 | 16 | 24 | 32)
+        {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            counter_len: counter_bits / 8,
+            length_len: length_bits / 8,
+            separator,
+        })
+    }
+}
+
+/// Fills `out` with key material derived from the PRF key, `label`, and
+/// `context`.
+///
+/// Fails if the length of `out` in bits doesn't fit in the encoding's length
+/// field, or if `out` needs more blocks than the encoding's counter can count.
+pub fn derive(
+    prf: Prf,
+    encoding: &Encoding,
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    let length = polyfill::u64_from_usize(out.len())
+        .checked_mul(8)
+        .ok_or(error::Unspecified)?;
+    let length = if encoding.length_len > 0 {
+        encode(length, encoding.length_len)?
+    } else {
+        Encoded {
+            bytes: [0; 4],
+            len: 0,
+        }
+    };
+
+    let block_len = prf.output_len();
+    let num_blocks = polyfill::u64_from_usize(out.chunks(block_len).len());
+    // The counter starts at 1, so its largest value must fit.
+    let _ = encode(num_blocks, encoding.counter_len)?;
+
+    let separator: &[u8] = if encoding.separator { &[0] } else { &[] };
+    for (i, chunk) in (1..).zip(out.chunks_mut(block_len)) {
+        let counter = encode(i, encoding.counter_len)?;
+        prf.compute(
+            &[counter.as_ref(), label, separator, context, length.as_ref()],
+            chunk,
+        );
+    }
+    Ok(())
+}
+
+/// A PRF algorithm and an encoding, for using KBKDF as a `kdf::Kdf`.
+///
+/// The secret is the PRF key, the salt is the Context, and the info is the
+/// Label.
+#[derive(Clone, Copy, Debug)]
+pub struct Params {
+    prf: PrfAlgorithm,
+    encoding: Encoding,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PrfAlgorithm {
+    Hmac(hmac::Algorithm),
+    Cmac(&'static cmac::Algorithm),
+}
+
+impl Params {
+    /// KBKDF with HMAC using `algorithm` as the PRF.
+    pub fn hmac(algorithm: hmac::Algorithm, encoding: Encoding) -> Self {
+        Self {
+            prf: PrfAlgorithm::Hmac(algorithm),
+            encoding,
+        }
+    }
+
+    /// KBKDF with CMAC using `algorithm` as the PRF. Deriving fails unless
+    /// the secret is `algorithm.key_len()` bytes long.
+    pub fn cmac(algorithm: &'static cmac::Algorithm, encoding: Encoding) -> Self {
+        Self {
+            prf: PrfAlgorithm::Cmac(algorithm),
+            encoding,
+        }
+    }
+}
+
+impl kdf::Kdf for Params {
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        match self.prf {
+            PrfAlgorithm::Hmac(algorithm) => {
+                let key = hmac::Key::new(algorithm, secret);
+                derive(Prf::Hmac(&key), &self.encoding, info, salt, out)
+            }
+            PrfAlgorithm::Cmac(algorithm) => {
+                let key = cmac::Key::new(algorithm, secret)?;
+                derive(Prf::Cmac(&key), &self.encoding, info, salt, out)
+            }
+        }
+    }
+}
+
+/// The big-endian encoding of `value` in `len` bytes, `len` <= 4.
+struct Encoded {
+    bytes: [u8; 4],
+    len: usize,
+}
+
+impl AsRef<[u8]> for Encoded {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[(4 - self.len)..]
+    }
+}
+
+fn encode(value: u64, len: usize) -> Result<Encoded, error::Unspecified> {
+    if value >> (8 * len) != 0 {
+        return Err(error::Unspecified);
+    }
+    let bytes = (value as u32).to_be_bytes();
+    Ok(Encoded { bytes, len })
+}
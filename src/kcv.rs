@@ -0,0 +1,111 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Key check values (KCVs).
+//!
+//! A KCV is a short value computed from a symmetric key that identifies the
+//! key without revealing it. KCVs are used when keys are exchanged between
+//! HSMs, e.g. in payment systems, to confirm that every party loaded the same
+//! key material. Two methods are provided:
+//!
+//! * `aes`: the first bytes of the encryption of a block of zeros with the
+//!   raw AES block cipher (AES-ECB). Conventionally three bytes are used.
+//! * `cmac`: the first bytes of the AES-CMAC of a block of zeros, which is
+//!   the method ANSI X9.24-1 specifies for AES keys. Conventionally five
+//!   bytes are used.
+//!
+//! A KCV is not an authenticator: it is short, so different keys often have
+//! the same KCV. With the `aes` method, the KCV is also the start of a
+//! ciphertext that the key produces, so anybody who can have a block of
+//! zeros encrypted with the key learns it; prefer `cmac` where the other
+//! parties support it.
+//!
+//! ```
+//! use ring::{cipher, cmac, kcv};
+//!
+//! let key_value = [0x2b; 16];
+//! let key = cipher::aes::Key::new(&cipher::aes::AES_128, &key_value)?;
+//! let check_value = kcv::aes(&key, kcv::DEFAULT_AES_LEN)?;
+//! assert_eq!(check_value.as_ref().len(), 3);
+//!
+//! let key = cmac::Key::new(&cmac::AES_128, &key_value)?;
+//! let check_value = kcv::cmac(&key, kcv::DEFAULT_CMAC_LEN)?;
+//! assert_eq!(check_value.as_ref().len(), 5);
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+
+use crate::{cipher, cmac, error};
+
+/// The maximum length of a KCV: the length of a block.
+pub const MAX_LEN: usize = cipher::aes::BLOCK_LEN;
+
+/// The conventional length of a KCV computed with `aes`.
+pub const DEFAULT_AES_LEN: usize = 3;
+
+/// The conventional length of a KCV computed with `cmac`.
+pub const DEFAULT_CMAC_LEN: usize = 5;
+
+/// A key check value.
+///
+/// For a given KCV `v`, use `v.as_ref()` to get its value as a byte slice.
+#[derive(Clone, Copy)]
+pub struct CheckValue {
+    value: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl CheckValue {
+    fn new(block: [u8; MAX_LEN], len: usize) -> Result<Self, error::Unspecified> {
+        if len == 0 || len > MAX_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(Self { value: block, len })
+    }
+}
+
+impl AsRef<[u8]> for CheckValue {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.value[..self.len]
+    }
+}
+
+impl PartialEq for CheckValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for CheckValue {}
+
+derive_debug_self_as_ref_hex_bytes!(CheckValue);
+
+/// Computes the KCV of `key` as the first `len` bytes of the AES encryption
+/// of a block of zeros.
+///
+/// Fails unless `len` is between 1 and `MAX_LEN`, inclusive.
+pub fn aes(key: &cipher::aes::Key, len: usize) -> Result<CheckValue, error::Unspecified> {
+    CheckValue::new(key.encrypt_block(&[0u8; MAX_LEN]), len)
+}
+
+/// Computes the KCV of `key` as the first `len` bytes of the AES-CMAC of a
+/// block of zeros.
+///
+/// Fails unless `len` is between 1 and `MAX_LEN`, inclusive.
+pub fn cmac(key: &cmac::Key, len: usize) -> Result<CheckValue, error::Unspecified> {
+    let mut block = [0u8; MAX_LEN];
+    block.copy_from_slice(cmac::sign(key, &[0u8; MAX_LEN]).as_ref());
+    CheckValue::new(block, len)
+}
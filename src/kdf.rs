@@ -0,0 +1,95 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! A common interface to the key derivation functions.
+//!
+//! Each KDF module has its own calling convention, matching its
+//! specification. The `Kdf` trait maps them all onto one, so that code can
+//! be generic over the KDF, or choose one at run time based on
+//! configuration:
+//!
+//! ```
+//! use ring::{aead, hkdf, kbkdf, kdf::Kdf, pbkdf2};
+//! use std::num::NonZeroU32;
+//!
+//! let kbkdf = kbkdf::Params::hmac(ring::hmac::HMAC_SHA256, kbkdf::Encoding::DEFAULT);
+//! let pbkdf2 = pbkdf2::Params::new(
+//!     pbkdf2::PBKDF2_HMAC_SHA256,
+//!     NonZeroU32::new(1_000).unwrap(),
+//! );
+//!
+//! let kdfs: [&dyn Kdf; 3] = [&hkdf::HKDF_SHA256, &kbkdf, &pbkdf2];
+//! for kdf in kdfs.iter() {
+//!     let key = kdf.derive_aead_key(b"secret", b"salt", b"", &aead::AES_128_GCM)?;
+//!     # let _ = key;
+//! }
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! | KDF         | `secret`            | `salt`    | `info`
+//! |-------------|---------------------|-----------|----------------------
+//! | HKDF        | IKM                 | salt      | info
+//! | KBKDF       | K_IN (the PRF key)  | Context   | Label
+//! | PBKDF2      | P (password)        | S (salt)  | must be empty
+//! | scrypt      | P (passphrase)      | S (salt)  | must be empty
+//! | Argon2id    | P (message)         | S (salt)  | X (associated data)
+
+use crate::{aead, error, hmac};
+
+/// A key derivation function, with its parameters.
+pub trait Kdf {
+    /// Fills `out` with key material derived from `secret`, `salt`, and
+    /// `info`, as described in the module documentation.
+    ///
+    /// Fails if the KDF can't derive `out.len()` bytes, or if an input is
+    /// invalid for the KDF.
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified>;
+
+    /// Derives a key for `algorithm`, like `derive`, without copying it
+    /// through a buffer owned by the caller.
+    fn derive_aead_key(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        algorithm: &'static aead::Algorithm,
+    ) -> Result<aead::UnboundKey, error::Unspecified> {
+        let mut key_bytes = [0u8; aead::MAX_KEY_LEN];
+        let key_bytes = &mut key_bytes[..algorithm.key_len()];
+        self.derive(secret, salt, info, key_bytes)?;
+        aead::UnboundKey::new(algorithm, key_bytes)
+    }
+
+    /// Derives an HMAC key for `algorithm`, like `derive`, as long as the
+    /// output of `algorithm`'s digest algorithm.
+    fn derive_hmac_key(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        algorithm: hmac::Algorithm,
+    ) -> Result<hmac::Key, error::Unspecified> {
+        let mut key_bytes = [0u8; crate::digest::MAX_OUTPUT_LEN];
+        let key_bytes = &mut key_bytes[..algorithm.digest_algorithm().output_len];
+        self.derive(secret, salt, info, key_bytes)?;
+        Ok(hmac::Key::new(algorithm, key_bytes))
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The Keccak-p[1600] permutations, as specified in [FIPS 202] Section 3.
+//!
+//! [FIPS 202]: https://doi.org/10.6028/NIST.FIPS.202
+
+/// The 1600-bit state, as 25 64-bit lanes, where lane (x, y) is at index
+/// `x + 5 * y`. Input and output bytes are mapped to the lanes in
+/// little-endian order.
+pub(crate) type State = [u64; 25];
+
+/// Applies Keccak-p[1600, `rounds`], i.e. the last `rounds` of the 24 rounds
+/// of Keccak-f[1600].
+pub(crate) fn permute(a: &mut State, rounds: usize) {
+    debug_assert!(rounds <= ROUND_CONSTANTS.len());
+    for &rc in &ROUND_CONSTANTS[(ROUND_CONSTANTS.len() - rounds)..] {
+        round(a, rc);
+    }
+}
+
+/// XORs `block`, which must be a multiple of 8 bytes long and no longer than
+/// `STATE_LEN`, into the start of the state.
+pub(crate) fn xor_in(a: &mut State, block: &[u8]) {
+    debug_assert_eq!(block.len() % 8, 0);
+    for (lane, bytes) in a.iter_mut().zip(block.chunks_exact(8)) {
+        let mut le = [0u8; 8];
+        le.copy_from_slice(bytes);
+        *lane ^= u64::from_le_bytes(le);
+    }
+}
+
+pub(crate) fn copy_out(a: &State, out: &mut [u8]) {
+    debug_assert_eq!(out.len() % 8, 0);
+    for (lane, bytes) in a.iter().zip(out.chunks_exact_mut(8)) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+}
+
+#[inline(always)]
+fn round(a: &mut State, rc: u64) {
+    // θ
+    let mut c = [0u64; 5];
+    for (x, c) in c.iter_mut().enumerate() {
+        *c = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+    }
+    for x in 0..5 {
+        let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        for y in 0..5 {
+            a[x + 5 * y] ^= d;
+        }
+    }
+
+    // ρ and π
+    let mut last = a[1];
+    for (&pi, &rho) in PI.iter().zip(RHO.iter()) {
+        let next = a[pi];
+        a[pi] = last.rotate_left(rho);
+        last = next;
+    }
+
+    // χ
+    for y in 0..5 {
+        let row = [
+            a[5 * y],
+            a[5 * y + 1],
+            a[5 * y + 2],
+            a[5 * y + 3],
+            a[5 * y + 4],
+        ];
+        for x in 0..5 {
+            a[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+        }
+    }
+
+    // ι
+    a[0] ^= rc;
+}
+
+// The lanes visited by π, starting from lane (1, 0), and the ρ rotation of
+// each lane's value before it is moved to the next lane.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+#[cfg(test)]
+mod tests {
+    // The state after applying Keccak-f[1600] to the all-zero state, from the
+    // Keccak team's KeccakF-1600-IntermediateValues.txt.
+    #[test]
+    fn test_permute_zero() {
+        let mut a = [0u64; 25];
+        super::permute(&mut a, 24);
+        assert_eq!(a[0], 0xf1258f7940e1dde7);
+        assert_eq!(a[1], 0x84d5ccf933c0478a);
+        assert_eq!(a[24], 0xeaf1ff7b5ceca249);
+    }
+}
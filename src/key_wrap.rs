@@ -0,0 +1,347 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES Key Wrap.
+//!
+//! AES-KW ([RFC 3394]) wraps a key that is a multiple of 8 bytes long, and at
+//! least 16 bytes long, under a key-encryption key (KEK). AES-KWP ([RFC 5649])
+//! is AES-KW with padding, and wraps a key of any length from 1 to 2^32 - 1
+//! bytes. Both are deterministic: wrapping the same key twice gives the same
+//! result. Unwrapping checks the integrity of the wrapped key, and when that
+//! check fails it fails without revealing why.
+//!
+//! [RFC 3394]: https://tools.ietf.org/html/rfc3394
+//! [RFC 5649]: https://tools.ietf.org/html/rfc5649
+
+use crate::{
+    aead::{aes, block::Block},
+    constant_time, cpu, error, polyfill,
+};
+use core::convert::{TryFrom, TryInto};
+
+/// The length of a semiblock, half an AES block. Wrapped keys are a multiple
+/// of this length.
+pub const SEMIBLOCK_LEN: usize = 8;
+
+/// An AES Key Wrap algorithm.
+pub struct Algorithm {
+    variant: aes::Variant,
+    key_len: usize,
+    padded: bool,
+    id: AlgorithmID,
+}
+
+impl Algorithm {
+    /// The length of the key-encryption key.
+    #[inline(always)]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    /// The length of the result of wrapping a key that is `in_len` bytes long.
+    ///
+    /// Fails if a key that is `in_len` bytes long can't be wrapped with this
+    /// algorithm.
+    pub fn wrapped_len(&self, in_len: usize) -> Result<usize, error::Unspecified> {
+        let padded_len = if self.padded {
+            if in_len == 0 || u32::try_from(in_len).is_err() {
+                return Err(error::Unspecified);
+            }
+            let partial = in_len % SEMIBLOCK_LEN;
+            in_len + ((SEMIBLOCK_LEN - partial) % SEMIBLOCK_LEN)
+        } else {
+            if in_len < 2 * SEMIBLOCK_LEN || !is_whole_semiblocks(in_len) {
+                return Err(error::Unspecified);
+            }
+            in_len
+        };
+        padded_len
+            .checked_add(SEMIBLOCK_LEN)
+            .ok_or(error::Unspecified)
+    }
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    AES_128_KW,
+    AES_256_KW,
+    AES_128_KWP,
+    AES_256_KWP,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+/// AES-KW with a 128-bit KEK.
+pub static AES_128_KW: Algorithm = Algorithm {
+    variant: aes::Variant::AES_128,
+    key_len: 16,
+    padded: false,
+    id: AlgorithmID::AES_128_KW,
+};
+
+/// AES-KW with a 256-bit KEK, e.g. JOSE's `A256KW`.
+pub static AES_256_KW: Algorithm = Algorithm {
+    variant: aes::Variant::AES_256,
+    key_len: 32,
+    padded: false,
+    id: AlgorithmID::AES_256_KW,
+};
+
+/// AES-KWP with a 128-bit KEK.
+pub static AES_128_KWP: Algorithm = Algorithm {
+    variant: aes::Variant::AES_128,
+    key_len: 16,
+    padded: true,
+    id: AlgorithmID::AES_128_KWP,
+};
+
+/// AES-KWP with a 256-bit KEK.
+pub static AES_256_KWP: Algorithm = Algorithm {
+    variant: aes::Variant::AES_256,
+    key_len: 32,
+    padded: true,
+    id: AlgorithmID::AES_256_KWP,
+};
+
+/// A key-encryption key.
+#[derive(Clone)]
+pub struct Key {
+    encrypt_key: aes::Key,
+    decrypt_key: aes::DecryptKey,
+    algorithm: &'static Algorithm,
+}
+
+impl Key {
+    /// Constructs a key-encryption key.
+    ///
+    /// `key_bytes` must be exactly `algorithm.key_len()` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            encrypt_key: aes::Key::new(key_bytes, algorithm.variant, cpu::features())?,
+            decrypt_key: aes::DecryptKey::new(key_bytes, algorithm.variant)?,
+            algorithm,
+        })
+    }
+
+    /// The key's algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// Wraps `key` into `out`, returning the prefix of `out` that holds the
+    /// wrapped key.
+    ///
+    /// Fails if `key` can't be wrapped with the algorithm, or if `out` is
+    /// shorter than `self.algorithm().wrapped_len(key.len())`.
+    pub fn wrap<'o>(
+        &self,
+        key: &[u8],
+        out: &'o mut [u8],
+    ) -> Result<&'o mut [u8], error::Unspecified> {
+        let wrapped_len = self.algorithm.wrapped_len(key.len())?;
+        let out = out.get_mut(..wrapped_len).ok_or(error::Unspecified)?;
+        let (a, r) = out.split_at_mut(SEMIBLOCK_LEN);
+        r[..key.len()].copy_from_slice(key);
+        r[key.len()..].iter_mut().for_each(|b| *b = 0);
+
+        let iv = if self.algorithm.padded {
+            alternative_iv(key.len())
+        } else {
+            DEFAULT_IV
+        };
+
+        // RFC 5649 Section 4.1: a single semiblock is encrypted directly.
+        let a_out = if r.len() == SEMIBLOCK_LEN {
+            let (a_out, r_out) = self.encrypt_semiblocks(iv, (&*r).try_into()?);
+            r.copy_from_slice(&r_out);
+            a_out
+        } else {
+            self.w(iv, r)
+        };
+        a.copy_from_slice(&a_out);
+        Ok(out)
+    }
+
+    /// Unwraps `wrapped` into `out`, returning the prefix of `out` that holds
+    /// the unwrapped key.
+    ///
+    /// `out` is used as working space, so it must be at least
+    /// `wrapped.len() - SEMIBLOCK_LEN` bytes long even when the unwrapped key
+    /// is shorter. Fails if `wrapped` isn't a validly-wrapped key under this
+    /// key-encryption key. When the integrity check fails, all of `out` that
+    /// was used is zeroed.
+    pub fn unwrap<'o>(
+        &self,
+        wrapped: &[u8],
+        out: &'o mut [u8],
+    ) -> Result<&'o mut [u8], error::Unspecified> {
+        let min_len = if self.algorithm.padded { 2 } else { 3 } * SEMIBLOCK_LEN;
+        if wrapped.len() < min_len || !is_whole_semiblocks(wrapped.len()) {
+            return Err(error::Unspecified);
+        }
+        let (a, c) = wrapped.split_at(SEMIBLOCK_LEN);
+        let out = out.get_mut(..c.len()).ok_or(error::Unspecified)?;
+        out.copy_from_slice(c);
+
+        let a: [u8; SEMIBLOCK_LEN] = a.try_into()?;
+        let a = if out.len() == SEMIBLOCK_LEN {
+            let (a, r) = self.decrypt_semiblocks(a, (&*out).try_into()?);
+            out.copy_from_slice(&r);
+            a
+        } else {
+            self.w_inverse(a, out)
+        };
+
+        let key_len = if self.algorithm.padded {
+            check_alternative_iv(a, out)
+        } else {
+            constant_time::verify_slices_are_equal(&a, &DEFAULT_IV).map(|()| out.len())
+        };
+        match key_len {
+            Ok(key_len) => Ok(&mut out[..key_len]),
+            Err(error::Unspecified) => {
+                out.iter_mut().for_each(|b| *b = 0);
+                Err(error::Unspecified)
+            }
+        }
+    }
+
+    // RFC 3394 Section 2.2.1, the "indexing" formulation.
+    fn w(&self, mut a: [u8; SEMIBLOCK_LEN], r: &mut [u8]) -> [u8; SEMIBLOCK_LEN] {
+        let n = r.len() / SEMIBLOCK_LEN;
+        for j in 0..6 {
+            for (i, r_i) in r.chunks_exact_mut(SEMIBLOCK_LEN).enumerate() {
+                let t = polyfill::u64_from_usize(n * j + i + 1);
+                let (b_msb, b_lsb) = self.encrypt_semiblocks(a, (&*r_i).try_into().unwrap());
+                a = (u64::from_be_bytes(b_msb) ^ t).to_be_bytes();
+                r_i.copy_from_slice(&b_lsb);
+            }
+        }
+        a
+    }
+
+    // RFC 3394 Section 2.2.2, the "indexing" formulation.
+    fn w_inverse(&self, mut a: [u8; SEMIBLOCK_LEN], r: &mut [u8]) -> [u8; SEMIBLOCK_LEN] {
+        let n = r.len() / SEMIBLOCK_LEN;
+        for j in (0..6).rev() {
+            for (i, r_i) in r.chunks_exact_mut(SEMIBLOCK_LEN).enumerate().rev() {
+                let t = polyfill::u64_from_usize(n * j + i + 1);
+                let a_xor_t = (u64::from_be_bytes(a) ^ t).to_be_bytes();
+                let (b_msb, b_lsb) = self.decrypt_semiblocks(a_xor_t, (&*r_i).try_into().unwrap());
+                a = b_msb;
+                r_i.copy_from_slice(&b_lsb);
+            }
+        }
+        a
+    }
+
+    fn encrypt_semiblocks(
+        &self,
+        msb: [u8; SEMIBLOCK_LEN],
+        lsb: &[u8; SEMIBLOCK_LEN],
+    ) -> ([u8; SEMIBLOCK_LEN], [u8; SEMIBLOCK_LEN]) {
+        split_block(self.encrypt_key.encrypt_block(join_semiblocks(msb, lsb)))
+    }
+
+    fn decrypt_semiblocks(
+        &self,
+        msb: [u8; SEMIBLOCK_LEN],
+        lsb: &[u8; SEMIBLOCK_LEN],
+    ) -> ([u8; SEMIBLOCK_LEN], [u8; SEMIBLOCK_LEN]) {
+        split_block(self.decrypt_key.decrypt_block(join_semiblocks(msb, lsb)))
+    }
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+// RFC 3394 Section 2.2.3.1.
+const DEFAULT_IV: [u8; SEMIBLOCK_LEN] = [0xa6; SEMIBLOCK_LEN];
+
+// RFC 5649 Section 3.
+const ALTERNATIVE_IV_PREFIX: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+fn alternative_iv(key_len: usize) -> [u8; SEMIBLOCK_LEN] {
+    let mut iv = [0u8; SEMIBLOCK_LEN];
+    iv[..4].copy_from_slice(&ALTERNATIVE_IV_PREFIX);
+    iv[4..].copy_from_slice(&(key_len as u32).to_be_bytes());
+    iv
+}
+
+// RFC 5649 Section 3: checks the prefix of the alternative IV, that the
+// message length indicator is consistent with the length of `padded`, and
+// that the padding is all zeros, returning the message length indicator. All
+// of the checks are done before any failure is reported.
+fn check_alternative_iv(
+    a: [u8; SEMIBLOCK_LEN],
+    padded: &[u8],
+) -> Result<usize, error::Unspecified> {
+    let mut bad =
+        u8::from(constant_time::verify_slices_are_equal(&a[..4], &ALTERNATIVE_IV_PREFIX).is_err());
+
+    let mli = u32::from_be_bytes(a[4..].try_into()?);
+    let padded_len = polyfill::u64_from_usize(padded.len());
+    let padding_len = padded_len.wrapping_sub(u64::from(mli));
+    bad |= u8::from(padding_len >= polyfill::u64_from_usize(SEMIBLOCK_LEN));
+
+    let last = &padded[(padded.len() - SEMIBLOCK_LEN)..];
+    for (i, b) in last.iter().enumerate() {
+        let is_padding =
+            polyfill::u64_from_usize(i) + padding_len >= polyfill::u64_from_usize(SEMIBLOCK_LEN);
+        bad |= b & 0u8.wrapping_sub(u8::from(is_padding));
+    }
+
+    if bad != 0 {
+        return Err(error::Unspecified);
+    }
+    usize::try_from(mli).map_err(|_| error::Unspecified)
+}
+
+fn join_semiblocks(msb: [u8; SEMIBLOCK_LEN], lsb: &[u8; SEMIBLOCK_LEN]) -> Block {
+    let mut block = [0u8; 2 * SEMIBLOCK_LEN];
+    block[..SEMIBLOCK_LEN].copy_from_slice(&msb);
+    block[SEMIBLOCK_LEN..].copy_from_slice(lsb);
+    Block::from(&block)
+}
+
+fn split_block(block: Block) -> ([u8; SEMIBLOCK_LEN], [u8; SEMIBLOCK_LEN]) {
+    let block: &[u8; 2 * SEMIBLOCK_LEN] = block.as_ref();
+    let mut msb = [0u8; SEMIBLOCK_LEN];
+    let mut lsb = [0u8; SEMIBLOCK_LEN];
+    msb.copy_from_slice(&block[..SEMIBLOCK_LEN]);
+    lsb.copy_from_slice(&block[SEMIBLOCK_LEN..]);
+    (msb, lsb)
+}
+
+fn is_whole_semiblocks(len: usize) -> bool {
+    let partial = len % SEMIBLOCK_LEN;
+    partial == 0
+}
@@ -0,0 +1,178 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! KMAC, as specified in [NIST SP 800-185].
+//!
+//! KMAC is a MAC built on cSHAKE. Unlike HMAC, the length of the tag is
+//! chosen by the caller and is part of the input, so tags of different
+//! lengths computed with the same key are unrelated.
+//!
+//! ```
+//! use ring::kmac;
+//!
+//! let key = kmac::Key::new(kmac::KMAC256, b"a 32-byte secret key, not this!!");
+//!
+//! let msg = b"hello, world";
+//! let mut tag = [0u8; 32];
+//! kmac::sign(&key, msg, &mut tag);
+//!
+//! kmac::verify(&key, msg, &tag)?;
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [NIST SP 800-185]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::{constant_time, digest::xof, error, polyfill};
+
+/// A KMAC algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Algorithm(&'static xof::Algorithm);
+
+impl Algorithm {
+    /// The XOF this KMAC algorithm is based on.
+    #[inline]
+    pub fn xof_algorithm(&self) -> &'static xof::Algorithm {
+        self.0
+    }
+}
+
+/// KMAC128.
+pub static KMAC128: Algorithm = Algorithm(&xof::SHAKE128);
+
+/// KMAC256.
+pub static KMAC256: Algorithm = Algorithm(&xof::SHAKE256);
+
+/// The minimum length of a tag that `verify` accepts, in bytes.
+///
+/// NIST SP 800-185 Section 8.4.2 recommends against tags shorter than 32
+/// bits.
+pub const MIN_TAG_LEN: usize = 32 / 8;
+
+/// The maximum length of a tag that `verify` accepts, in bytes.
+pub const MAX_TAG_LEN: usize = 512 / 8;
+
+/// A key to use for KMAC signing.
+#[derive(Clone)]
+pub struct Key {
+    ctx: xof::Context,
+    algorithm: Algorithm,
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", self.algorithm.xof_algorithm())
+            .finish()
+    }
+}
+
+impl Key {
+    /// Construct a KMAC key from the given key value, with an empty
+    /// customization string.
+    pub fn new(algorithm: Algorithm, key_value: &[u8]) -> Self {
+        Self::with_customization(algorithm, key_value, b"")
+    }
+
+    /// Construct a KMAC key from the given key value, with the customization
+    /// string `customization`.
+    ///
+    /// Tags computed with different customization strings are unrelated, so
+    /// the customization string can be used for domain separation.
+    pub fn with_customization(
+        algorithm: Algorithm,
+        key_value: &[u8],
+        customization: &[u8],
+    ) -> Self {
+        let mut ctx =
+            xof::Context::new_cshake_with_function_name(algorithm.0, b"KMAC", customization);
+        ctx.update_bytepad(|ctx| ctx.update_encoded_string(key_value));
+        Self { ctx, algorithm }
+    }
+
+    /// The algorithm for the key.
+    #[inline]
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) KMAC signing.
+///
+/// Use `sign` for single-step KMAC signing.
+#[derive(Clone)]
+pub struct Context {
+    inner: xof::Context,
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Context")
+            .field("algorithm", self.inner.algorithm())
+            .finish()
+    }
+}
+
+impl Context {
+    /// Constructs a new KMAC signing context using the given key.
+    pub fn with_key(signing_key: &Key) -> Self {
+        Self {
+            inner: signing_key.ctx.clone(),
+        }
+    }
+
+    /// Updates the KMAC with all the data in `data`. `update` may be called
+    /// zero or more times until `sign` is called.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Finalizes the KMAC calculation, filling `tag` with the `tag.len()`-byte
+    /// tag. `sign` consumes the context so it cannot be (mis-)used after
+    /// `sign` has been called.
+    ///
+    /// It is generally not safe to implement KMAC verification by comparing
+    /// a tag computed with `sign` to a received tag. Use `verify` for
+    /// verification instead.
+    pub fn sign(mut self, tag: &mut [u8]) {
+        let len_bits = polyfill::u64_from_usize(tag.len()).checked_mul(8).unwrap();
+        self.inner.update_right_encoded(len_bits);
+        self.inner.finish().squeeze(tag);
+    }
+}
+
+/// Calculates the `tag.len()`-byte KMAC of `data` using the key `key` in one
+/// step, writing it to `tag`.
+///
+/// Use `Context` to calculate KMACs where the input is in multiple parts.
+pub fn sign(key: &Key, data: &[u8], tag: &mut [u8]) {
+    let mut ctx = Context::with_key(key);
+    ctx.update(data);
+    ctx.sign(tag)
+}
+
+/// Calculates the KMAC of `data` using the key `key`, with the same length as
+/// `tag`, and verifies whether the resultant value equals `tag`, in one step.
+///
+/// The comparison of the tags is done in constant time. Tags shorter than
+/// `MIN_TAG_LEN` or longer than `MAX_TAG_LEN` are rejected.
+pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    if tag.len() < MIN_TAG_LEN || tag.len() > MAX_TAG_LEN {
+        return Err(error::Unspecified);
+    }
+    let mut calculated = [0u8; MAX_TAG_LEN];
+    let calculated = &mut calculated[..tag.len()];
+    sign(key, data, calculated);
+    constant_time::verify_slices_are_equal(calculated, tag)
+}
@@ -30,9 +30,31 @@
 //!         <code>dev_urandom_fallback</code> feature is disabled, such
 //!         fallbacks will not occur. See the documentation for
 //!         <code>rand::SystemRandom</code> for more details.
+//! <tr><td><code>insecure_legacy_signing</code>
+//!     <td>Enable <code>signature::RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY</code>
+//!         for producing RSA PKCS#1 1.5 signatures with SHA-1. SHA-1 is broken
+//!         and this must only be used for interoperability with legacy
+//!         protocols that cannot accept anything else.
+//! <tr><td><code>insecure_md5</code>
+//!     <td>Enable <code>digest::MD5_FOR_LEGACY_USE_ONLY</code>. MD5 is broken
+//!         and must only be used for interoperability with legacy protocols
+//!         and formats that use it as a non-security checksum.
+//! <tr><td><code>interop</code>
+//!     <td>Implement the <code>Signer</code>, <code>Verifier</code>, and
+//!         <code>Keypair</code> traits of the
+//!         <a href="https://crates.io/crates/signature">signature</a> crate
+//!         for the Ed25519, ECDSA, and RSA key types in
+//!         <code>ring::signature</code>. Requires Rust 1.60 or later.
+//! <tr><td><code>parallel</code>
+//!     <td>Enable the parallel AES-GCM operations of
+//!         <code>aead::LessSafeKey</code>, which use
+//!         <a href="https://crates.io/crates/rayon">rayon</a> to encrypt and
+//!         decrypt large inputs on multiple threads. Implies `std`.
 //! <tr><td><code>std</code>
 //!     <td>Enable features that use libstd, in particular
-//!         <code>std::error::Error</code> integration. Implies `alloc`.
+//!         <code>std::error::Error</code> integration and the
+//!         <code>std::io::Write</code> implementation of
+//!         <code>digest::Context</code>. Implies `alloc`.
 //! <tr><td><code>wasm32_c</code>
 //!     <td>Enables features that require a C compiler on wasm32 targets, such as
 //!        the <code>constant_time</code> module, HMAC verification, and PBKDF2
@@ -85,29 +107,75 @@ mod polyfill;
 pub mod aead;
 pub mod agreement;
 
+#[cfg(feature = "alloc")]
+pub mod argon2;
+
+mod ascon;
+
+pub mod bcrypt;
 mod bits;
+pub mod bls;
 
 pub(crate) mod c;
+pub mod cipher;
+pub mod cmac;
+pub mod concat_kdf;
 pub mod constant_time;
 
 pub mod io;
 
 mod cpu;
 pub mod digest;
+
+#[cfg(feature = "alloc")]
+mod dsa;
+
 mod ec;
 mod endian;
 pub mod error;
+pub mod frost;
 pub mod hkdf;
 pub mod hmac;
+pub mod kbkdf;
+pub mod kcv;
+pub mod kdf;
+mod keccak;
+pub mod key_wrap;
+pub mod kmac;
+
+#[cfg(feature = "interop")]
+mod interop;
+
 mod limb;
+pub mod minisign;
+mod ml_dsa;
+
+#[cfg(feature = "alloc")]
+pub mod musig2;
+
+pub mod otp;
 pub mod pbkdf2;
 pub mod pkcs8;
 pub mod rand;
 
+#[cfg(feature = "alloc")]
+pub mod scrypt;
+
 #[cfg(feature = "alloc")]
 mod rsa;
 
+#[cfg(feature = "alloc")]
+pub mod rsa_encryption;
+
 pub mod signature;
+pub mod siphash;
+mod slh_dsa;
+
+#[cfg(feature = "alloc")]
+pub mod spki;
+
+pub mod tls12_prf;
+pub mod vrf;
 
 mod sealed {
     /// Traits that are designed to only be implemented internally in *ring*.
@@ -236,16 +236,13 @@ pub fn parse_big_endian_and_pad_consttime(
     })
 }
 
+/// Writes the low-order `out.len()` bytes of `limbs` to `out` in big-endian
+/// order. `out` may be shorter than `limbs` when the value is known to fit,
+/// e.g. for P-521 elements, which are stored in 576 bits.
 pub fn big_endian_from_limbs(limbs: &[Limb], out: &mut [u8]) {
-    let num_limbs = limbs.len();
-    let out_len = out.len();
-    assert_eq!(out_len, num_limbs * LIMB_BYTES);
-    for i in 0..num_limbs {
-        let mut limb = limbs[i];
-        for j in 0..LIMB_BYTES {
-            out[((num_limbs - i - 1) * LIMB_BYTES) + (LIMB_BYTES - j - 1)] = (limb & 0xff) as u8;
-            limb >>= 8;
-        }
+    assert!(out.len() <= limbs.len() * LIMB_BYTES);
+    for (i, out_byte) in out.iter_mut().rev().enumerate() {
+        *out_byte = (limbs[i / LIMB_BYTES] >> (8 * (i % LIMB_BYTES))) as u8;
     }
 }
 
@@ -570,6 +567,19 @@ mod tests {
         assert_eq!(&out[..], &expected[..]);
     }
 
+    #[test]
+    fn test_big_endian_from_limbs_shorter_output() {
+        #[cfg(target_pointer_width = "32")]
+        let limbs = [0x89900aab, 0x00000001];
+
+        #[cfg(target_pointer_width = "64")]
+        let limbs = [0x0000_0001_8990_0aab];
+
+        let mut out = [0xabu8; 5];
+        big_endian_from_limbs(&limbs[..], &mut out);
+        assert_eq!(&out[..], &[0x01, 0x89, 0x90, 0x0a, 0xab]);
+    }
+
     #[should_panic]
     #[test]
     fn test_big_endian_from_limbs_fewer_limbs() {
@@ -0,0 +1,261 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Verification of minisign and OpenBSD signify signatures.
+//!
+//! [minisign] and OpenBSD's [signify] sign files, such as software releases,
+//! with Ed25519. They share a public key format, so a `PublicKey` can verify
+//! signatures made by either.
+//!
+//! ```
+//! use ring::minisign;
+//!
+//! let public_key = minisign::PublicKey::from_text(
+//!     b"untrusted comment: minisign public key 3FC0B6A1C65B1A1A
+//! RWQaGlvGobbAPwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4
+//! ",
+//! )?;
+//!
+//! let signature = b"untrusted comment: signature from minisign secret key
+//! RUQaGlvGobbAP/HFvhXbx/2PDJbUw4hDNqZm227J985XO28HnAKkc9+OcnLjDSg4i8mf7ge992Y+2GIIYvYukN8UprBcp+wKywU=
+//! trusted comment: timestamp:1700000000\tfile:ring.txt
+//! Oicuuzy5LDgitn2tiRt86w9vsPS2mEXSCEhHVmbzGwl0TWoAgJVAfybZtPBD0tPjqn1N22lIlOIx8xBbU9hgDg==
+//! ";
+//! let trusted_comment = minisign::verify(&public_key, b"ring\n", signature)?;
+//! assert_eq!(trusted_comment, b"timestamp:1700000000\tfile:ring.txt");
+//!
+//! assert!(minisign::verify(&public_key, b"gnir\n", signature).is_err());
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! Only the trusted comment is covered by the signature; the untrusted
+//! comments are ignored.
+//!
+//! [minisign]: https://jedisct1.github.io/minisign/
+//! [signify]: https://man.openbsd.org/signify.1
+
+use crate::{digest, error, signature};
+use core::convert::TryInto;
+
+/// The length of a key ID.
+pub const KEY_ID_LEN: usize = 8;
+
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+const ALGORITHM_LEN: usize = 2;
+
+// The Ed25519 algorithm identifier of public keys and of signatures of the
+// message itself.
+const ALGORITHM_ED25519: &[u8; ALGORITHM_LEN] = b"Ed";
+
+// The algorithm identifier of minisign signatures of the BLAKE2b-512 digest
+// of the message.
+const ALGORITHM_ED25519_PREHASHED: &[u8; ALGORITHM_LEN] = b"ED";
+
+/// The length of the longest trusted comment that `verify` accepts.
+pub const MAX_TRUSTED_COMMENT_LEN: usize = 8192;
+
+const UNTRUSTED_COMMENT_PREFIX: &[u8] = b"untrusted comment: ";
+const TRUSTED_COMMENT_PREFIX: &[u8] = b"trusted comment: ";
+
+/// A minisign or signify public key.
+#[derive(Clone, Copy)]
+pub struct PublicKey {
+    key_id: [u8; KEY_ID_LEN],
+    key: [u8; ED25519_PUBLIC_KEY_LEN],
+}
+
+impl PublicKey {
+    /// Parses the contents of a public key file: an untrusted comment line
+    /// followed by the base64-encoded key.
+    pub fn from_text(text: &[u8]) -> Result<Self, error::Unspecified> {
+        let [comment, encoded] = split_lines(text)?;
+        let _ = untrusted_comment(comment)?;
+        Self::from_base64(encoded)
+    }
+
+    /// Parses a base64-encoded public key, as `minisign -P` takes.
+    pub fn from_base64(encoded: &[u8]) -> Result<Self, error::Unspecified> {
+        let mut decoded = [0; ALGORITHM_LEN + KEY_ID_LEN + ED25519_PUBLIC_KEY_LEN];
+        base64_decode(encoded, &mut decoded)?;
+        let (algorithm, rest) = decoded.split_at(ALGORITHM_LEN);
+        let (key_id, key) = rest.split_at(KEY_ID_LEN);
+        if algorithm != ALGORITHM_ED25519 {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            key_id: key_id.try_into()?,
+            key: key.try_into()?,
+        })
+    }
+
+    /// The key ID, which signatures also contain.
+    ///
+    /// minisign displays it as a little-endian 64-bit integer in hex.
+    pub fn key_id(&self) -> &[u8; KEY_ID_LEN] {
+        &self.key_id
+    }
+
+    /// The Ed25519 public key.
+    pub fn ed25519_public_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    fn verify_ed25519(&self, msg: &[u8], signature: &[u8]) -> Result<(), error::Unspecified> {
+        signature::UnparsedPublicKey::new(&signature::ED25519, &self.key).verify(msg, signature)
+    }
+}
+
+derive_debug_via_field!(PublicKey, stringify!(PublicKey), key_id);
+
+/// Verifies the contents of a minisign signature file, `signature`, for
+/// `msg`, and returns the trusted comment.
+///
+/// Both the signatures of the BLAKE2b-512 digest of the message that
+/// minisign makes by default and the legacy signatures of the message itself
+/// are accepted. The key ID in the signature must match that of
+/// `public_key`, and the trusted comment may be at most
+/// `MAX_TRUSTED_COMMENT_LEN` bytes long.
+pub fn verify<'a>(
+    public_key: &PublicKey,
+    msg: &[u8],
+    signature: &'a [u8],
+) -> Result<&'a [u8], error::Unspecified> {
+    let [comment, encoded, trusted_comment_line, encoded_global] = split_lines(signature)?;
+    let _ = untrusted_comment(comment)?;
+    let trusted_comment = trusted_comment_line
+        .strip_prefix(TRUSTED_COMMENT_PREFIX)
+        .ok_or(error::Unspecified)?;
+
+    let (algorithm, sig) = decode_signature(public_key, encoded)?;
+    match &algorithm {
+        ALGORITHM_ED25519_PREHASHED => {
+            let digest = digest::digest(&digest::BLAKE2B_512, msg);
+            public_key.verify_ed25519(digest.as_ref(), &sig)?;
+        }
+        ALGORITHM_ED25519 => public_key.verify_ed25519(msg, &sig)?,
+        _ => return Err(error::Unspecified),
+    }
+
+    // The global signature covers the signature and the trusted comment.
+    if trusted_comment.len() > MAX_TRUSTED_COMMENT_LEN {
+        return Err(error::Unspecified);
+    }
+    let mut global = [0; ED25519_SIGNATURE_LEN];
+    base64_decode(encoded_global, &mut global)?;
+    let mut signed = [0; ED25519_SIGNATURE_LEN + MAX_TRUSTED_COMMENT_LEN];
+    let signed_len = ED25519_SIGNATURE_LEN + trusted_comment.len();
+    signed[..ED25519_SIGNATURE_LEN].copy_from_slice(&sig);
+    signed[ED25519_SIGNATURE_LEN..signed_len].copy_from_slice(trusted_comment);
+    public_key.verify_ed25519(&signed[..signed_len], &global)?;
+
+    Ok(trusted_comment)
+}
+
+/// Verifies the contents of a signify signature file, `signature`, for
+/// `msg`.
+///
+/// The key ID in the signature must match that of `public_key`. Signatures
+/// with the message embedded, from `signify -S -e`, aren't supported.
+pub fn verify_signify(
+    public_key: &PublicKey,
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    let [comment, encoded] = split_lines(signature)?;
+    let _ = untrusted_comment(comment)?;
+    let (algorithm, sig) = decode_signature(public_key, encoded)?;
+    if &algorithm != ALGORITHM_ED25519 {
+        return Err(error::Unspecified);
+    }
+    public_key.verify_ed25519(msg, &sig)
+}
+
+// Decodes the algorithm, key ID, and signature line, and checks that the key
+// ID matches `public_key`.
+fn decode_signature(
+    public_key: &PublicKey,
+    encoded: &[u8],
+) -> Result<([u8; ALGORITHM_LEN], [u8; ED25519_SIGNATURE_LEN]), error::Unspecified> {
+    let mut decoded = [0; ALGORITHM_LEN + KEY_ID_LEN + ED25519_SIGNATURE_LEN];
+    base64_decode(encoded, &mut decoded)?;
+    let (algorithm, rest) = decoded.split_at(ALGORITHM_LEN);
+    let (key_id, sig) = rest.split_at(KEY_ID_LEN);
+    if key_id != public_key.key_id {
+        return Err(error::Unspecified);
+    }
+    Ok((algorithm.try_into()?, sig.try_into()?))
+}
+
+fn untrusted_comment(line: &[u8]) -> Result<&[u8], error::Unspecified> {
+    line.strip_prefix(UNTRUSTED_COMMENT_PREFIX)
+        .ok_or(error::Unspecified)
+}
+
+// Splits `text` into exactly `N` lines. The final line may or may not end
+// with a newline, and a carriage return before each newline is ignored.
+fn split_lines<const N: usize>(text: &[u8]) -> Result<[&[u8]; N], error::Unspecified> {
+    let text = text.strip_suffix(b"\n").unwrap_or(text);
+    let mut split = text.split(|&b| b == b'\n');
+    let mut lines = [&text[..0]; N];
+    for line in lines.iter_mut() {
+        let l = split.next().ok_or(error::Unspecified)?;
+        *line = l.strip_suffix(b"\r").unwrap_or(l);
+    }
+    if split.next().is_some() {
+        return Err(error::Unspecified);
+    }
+    Ok(lines)
+}
+
+/// The base64 alphabet of RFC 4648 Section 4.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Decodes the padded base64 `input` into `out`, which must be exactly the
+/// length of the decoded value. Non-canonical encodings are rejected.
+fn base64_decode(input: &[u8], out: &mut [u8]) -> Result<(), error::Unspecified> {
+    let padding = (3 - out.len() % 3) % 3;
+    if input.len() != (out.len() + padding) / 3 * 4 {
+        return Err(error::Unspecified);
+    }
+    let (input, pad) = input.split_at(input.len() - padding);
+    if pad.iter().any(|&c| c != b'=') {
+        return Err(error::Unspecified);
+    }
+
+    let mut bits = 0u32;
+    let mut num_bits = 0;
+    let mut out = out.iter_mut();
+    for c in input {
+        let value = BASE64_ALPHABET
+            .iter()
+            .position(|a| a == c)
+            .ok_or(error::Unspecified)?;
+        bits = (bits << 6) | (value as u32);
+        num_bits += 6;
+        if num_bits >= 8 {
+            num_bits -= 8;
+            *out.next().ok_or(error::Unspecified)? = (bits >> num_bits) as u8;
+            bits &= (1 << num_bits) - 1;
+        }
+    }
+    // Any bits left over must be zero.
+    if bits != 0 {
+        return Err(error::Unspecified);
+    }
+    debug_assert!(out.next().is_none());
+    Ok(())
+}
@@ -0,0 +1,642 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! ML-DSA signatures, as specified in [FIPS 204].
+//!
+//! [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+
+use self::poly::*;
+use crate::{digest::xof, error};
+
+mod poly;
+pub mod signing;
+pub mod verification;
+
+/// The parameters of one of the ML-DSA parameter sets of FIPS 204 Section 4.
+pub(crate) struct Parameters {
+    k: usize,
+    l: usize,
+    eta: i32,
+    tau: usize,
+    lambda: usize,
+    gamma1_bits: usize,
+    gamma2: i32,
+    beta: i32,
+    omega: usize,
+
+    // The value of the OID that identifies the parameter set, RFC 9881
+    // Section 2.
+    oid: &'static [u8],
+}
+
+impl Parameters {
+    pub(crate) fn public_key_len(&self) -> usize {
+        RHO_LEN + self.k * T1_BITS * N / 8
+    }
+
+    pub(crate) fn private_key_len(&self) -> usize {
+        RHO_LEN
+            + K_LEN
+            + TR_LEN
+            + (self.l + self.k) * self.eta_bits() * N / 8
+            + self.k * T0_BITS * N / 8
+    }
+
+    pub(crate) fn signature_len(&self) -> usize {
+        self.c_tilde_len() + self.l * self.z_bits() * N / 8 + self.omega + self.k
+    }
+
+    fn eta_bits(&self) -> usize {
+        if self.eta == 2 {
+            3
+        } else {
+            4
+        }
+    }
+
+    fn z_bits(&self) -> usize {
+        self.gamma1_bits + 1
+    }
+
+    fn w1_bits(&self) -> usize {
+        if self.gamma2 == (Q - 1) / 88 {
+            6
+        } else {
+            4
+        }
+    }
+
+    fn c_tilde_len(&self) -> usize {
+        self.lambda / 4
+    }
+
+    fn gamma1(&self) -> i32 {
+        1 << self.gamma1_bits
+    }
+}
+
+pub(crate) static ML_DSA_44_PARAMETERS: Parameters = Parameters {
+    k: 4,
+    l: 4,
+    eta: 2,
+    tau: 39,
+    lambda: 128,
+    gamma1_bits: 17,
+    gamma2: (Q - 1) / 88,
+    beta: 78,
+    omega: 80,
+    oid: &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x11],
+};
+
+pub(crate) static ML_DSA_65_PARAMETERS: Parameters = Parameters {
+    k: 6,
+    l: 5,
+    eta: 4,
+    tau: 49,
+    lambda: 192,
+    gamma1_bits: 19,
+    gamma2: (Q - 1) / 32,
+    beta: 196,
+    omega: 55,
+    oid: &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x12],
+};
+
+pub(crate) static ML_DSA_87_PARAMETERS: Parameters = Parameters {
+    k: 8,
+    l: 7,
+    eta: 2,
+    tau: 60,
+    lambda: 256,
+    gamma1_bits: 19,
+    gamma2: (Q - 1) / 32,
+    beta: 120,
+    omega: 75,
+    oid: &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x13],
+};
+
+const MAX_K: usize = 8;
+const MAX_L: usize = 7;
+
+pub(crate) const SEED_LEN: usize = 32;
+const RHO_LEN: usize = 32;
+const RHO_PRIME_LEN: usize = 64;
+const K_LEN: usize = 32;
+const TR_LEN: usize = 64;
+const MU_LEN: usize = 64;
+const RND_LEN: usize = 32;
+
+const T1_BITS: usize = 23 - D as usize;
+const T0_BITS: usize = D as usize;
+
+pub(crate) const PUBLIC_KEY_MAX_LEN: usize = RHO_LEN + MAX_K * T1_BITS * N / 8;
+pub(crate) const PRIVATE_KEY_MAX_LEN: usize = 4896;
+
+/// The maximum length of a context string, FIPS 204 Section 5.2.
+const CONTEXT_MAX_LEN: usize = 255;
+
+type Matrix = [[Poly; MAX_L]; MAX_K];
+
+/// SHAKE256 of the concatenation of `parts`, written to `out`.
+fn h(parts: &[&[u8]], out: &mut [u8]) {
+    let mut ctx = xof::Context::new(&xof::SHAKE256);
+    parts.iter().for_each(|part| ctx.update(part));
+    ctx.finish().squeeze(out);
+}
+
+/// The message prefix of FIPS 204 Algorithms 2 and 3, i.e. the bytes that
+/// precede the message in M'.
+struct MessagePrefix {
+    bytes: [u8; 2 + CONTEXT_MAX_LEN],
+    len: usize,
+}
+
+impl MessagePrefix {
+    fn new(context: &[u8]) -> Result<Self, error::Unspecified> {
+        if context.len() > CONTEXT_MAX_LEN {
+            return Err(error::Unspecified);
+        }
+        let mut bytes = [0; 2 + CONTEXT_MAX_LEN];
+        bytes[0] = 0; // Pure ML-DSA, not HashML-DSA.
+        bytes[1] = context.len() as u8;
+        bytes[2..][..context.len()].copy_from_slice(context);
+        Ok(Self {
+            bytes,
+            len: 2 + context.len(),
+        })
+    }
+
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// FIPS 204 Algorithm 6, ML-DSA.KeyGen_internal. Writes the public key and
+/// the private key to `pk` and `sk`, which must have the right lengths.
+fn key_gen(p: &Parameters, xi: &[u8; SEED_LEN], pk: &mut [u8], sk: &mut [u8]) {
+    let mut seeds = [0; RHO_LEN + RHO_PRIME_LEN + K_LEN];
+    h(&[xi, &[p.k as u8, p.l as u8]], &mut seeds);
+    let (rho, rest) = seeds.split_at(RHO_LEN);
+    let (rho_prime, key) = rest.split_at(RHO_PRIME_LEN);
+
+    let a_hat = expand_a(p, rho);
+    let (s1, s2) = expand_s(p, rho_prime);
+
+    let mut s1_hat = s1;
+    s1_hat[..p.l].iter_mut().for_each(Poly::ntt);
+
+    let mut t1 = [Poly::ZERO; MAX_K];
+    let mut t0 = [Poly::ZERO; MAX_K];
+    for i in 0..p.k {
+        let mut t = matrix_row_mul(p, &a_hat[i], &s1_hat).add(&s2[i]);
+        t.freeze();
+        for (c, (c1, c0)) in t.0.iter().zip(t1[i].0.iter_mut().zip(t0[i].0.iter_mut())) {
+            let (r1, r0) = power2round(*c);
+            *c1 = r1;
+            *c0 = r0;
+        }
+    }
+
+    // Algorithm 22, pkEncode.
+    let mut out = Output(pk);
+    out.take(RHO_LEN).copy_from_slice(rho);
+    for t1 in &t1[..p.k] {
+        pack(t1, T1_BITS, out.take(T1_BITS * N / 8), |c| c);
+    }
+    debug_assert!(out.0.is_empty());
+
+    let mut tr = [0; TR_LEN];
+    h(&[pk], &mut tr);
+
+    // Algorithm 24, skEncode.
+    let eta = p.eta;
+    let gamma = 1 << (D - 1);
+    let mut out = Output(sk);
+    out.take(RHO_LEN).copy_from_slice(rho);
+    out.take(K_LEN).copy_from_slice(key);
+    out.take(TR_LEN).copy_from_slice(&tr);
+    for s in s1[..p.l].iter().chain(s2[..p.k].iter()) {
+        pack(s, p.eta_bits(), out.take(p.eta_bits() * N / 8), |c| eta - c);
+    }
+    for t0 in &t0[..p.k] {
+        pack(t0, T0_BITS, out.take(T0_BITS * N / 8), |c| gamma - c);
+    }
+    debug_assert!(out.0.is_empty());
+}
+
+/// FIPS 204 Algorithm 7, ML-DSA.Sign_internal, with M' being
+/// `prefix || msg`. `sk` must have been produced by `key_gen()` and
+/// `signature` must have the right length.
+fn sign(
+    p: &Parameters,
+    sk: &[u8],
+    prefix: &MessagePrefix,
+    msg: &[u8],
+    rnd: &[u8; RND_LEN],
+    signature: &mut [u8],
+) {
+    let gamma1 = p.gamma1();
+    let gamma2 = p.gamma2;
+
+    // Algorithm 25, skDecode; `sk` is known to be valid.
+    let mut input = Input(sk);
+    let rho = input.take(RHO_LEN);
+    let key = input.take(K_LEN);
+    let tr = input.take(TR_LEN);
+    let mut s1_hat = [Poly::ZERO; MAX_L];
+    let mut s2_hat = [Poly::ZERO; MAX_K];
+    let mut t0_hat = [Poly::ZERO; MAX_K];
+    let eta = p.eta;
+    for s in s1_hat[..p.l].iter_mut().chain(s2_hat[..p.k].iter_mut()) {
+        *s = unpack(input.take(p.eta_bits() * N / 8), p.eta_bits(), |v| eta - v);
+        s.ntt();
+    }
+    let gamma = 1 << (D - 1);
+    for t0 in &mut t0_hat[..p.k] {
+        *t0 = unpack(input.take(T0_BITS * N / 8), T0_BITS, |v| gamma - v);
+        t0.ntt();
+    }
+    let a_hat = expand_a(p, rho);
+
+    let mut mu = [0; MU_LEN];
+    h(&[tr, prefix.as_ref(), msg], &mut mu);
+
+    let mut rho_prime_prime = [0; RHO_PRIME_LEN];
+    h(&[key, rnd, &mu], &mut rho_prime_prime);
+
+    let mut kappa: u16 = 0;
+    loop {
+        let y = expand_mask(p, &rho_prime_prime, kappa);
+        kappa = kappa.wrapping_add(p.l as u16);
+
+        let mut y_hat = y;
+        y_hat[..p.l].iter_mut().for_each(Poly::ntt);
+        let mut w = [Poly::ZERO; MAX_K];
+        for (w, a_hat) in w[..p.k].iter_mut().zip(a_hat.iter()) {
+            *w = matrix_row_mul(p, a_hat, &y_hat);
+            w.freeze();
+        }
+
+        let mut c_tilde = [0; 64];
+        let c_tilde = &mut c_tilde[..p.c_tilde_len()];
+        commitment_hash(p, &mu, &w, |c| decompose(c, gamma2).0, c_tilde);
+        let mut c_hat = sample_in_ball(p, c_tilde);
+        c_hat.ntt();
+
+        // z = y + c*s1.
+        let mut z = [Poly::ZERO; MAX_L];
+        for ((z, y), s1_hat) in z[..p.l].iter_mut().zip(y.iter()).zip(s1_hat.iter()) {
+            let mut cs1 = c_hat.pointwise_mont(s1_hat);
+            cs1.inverse_ntt_to_mont();
+            *z = y.add(&cs1);
+            z.center();
+        }
+        if z[..p.l].iter().any(|z| z.norm_is_at_least(gamma1 - p.beta)) {
+            continue;
+        }
+
+        // r = w - c*s2, and its low bits r0.
+        let mut r = [Poly::ZERO; MAX_K];
+        let mut rejected = false;
+        for ((r, w), s2_hat) in r[..p.k].iter_mut().zip(w.iter()).zip(s2_hat.iter()) {
+            let mut cs2 = c_hat.pointwise_mont(s2_hat);
+            cs2.inverse_ntt_to_mont();
+            *r = w.sub(&cs2);
+            r.freeze();
+            let mut r0 = *r;
+            r0.0.iter_mut().for_each(|c| *c = decompose(*c, gamma2).1);
+            rejected |= r0.norm_is_at_least(gamma2 - p.beta);
+        }
+        if rejected {
+            continue;
+        }
+
+        // The hint h = MakeHint(-c*t0, w - c*s2 + c*t0), Algorithm 39.
+        let mut hints = [Poly::ZERO; MAX_K];
+        let mut hint_count = 0;
+        for ((hints, r), t0_hat) in hints[..p.k].iter_mut().zip(r.iter()).zip(t0_hat.iter()) {
+            let mut ct0 = c_hat.pointwise_mont(t0_hat);
+            ct0.inverse_ntt_to_mont();
+            rejected |= ct0.norm_is_at_least(gamma2);
+            let mut r_plus_ct0 = r.add(&ct0);
+            r_plus_ct0.freeze();
+            for (h, (a, b)) in hints.0.iter_mut().zip(r.0.iter().zip(r_plus_ct0.0.iter())) {
+                *h = i32::from(decompose(*a, gamma2).0 != decompose(*b, gamma2).0);
+                hint_count += *h as usize;
+            }
+        }
+        if rejected || hint_count > p.omega {
+            continue;
+        }
+
+        // Algorithm 26, sigEncode.
+        let mut out = Output(signature);
+        out.take(c_tilde.len()).copy_from_slice(c_tilde);
+        for z in &z[..p.l] {
+            pack(z, p.z_bits(), out.take(p.z_bits() * N / 8), |c| gamma1 - c);
+        }
+        let hint_out = out.take(p.omega + p.k);
+        hint_out.fill(0);
+        let mut index = 0;
+        for (i, hints) in hints[..p.k].iter().enumerate() {
+            for (j, h) in hints.0.iter().enumerate() {
+                if *h != 0 {
+                    hint_out[index] = j as u8;
+                    index += 1;
+                }
+            }
+            hint_out[p.omega + i] = index as u8;
+        }
+        debug_assert!(out.0.is_empty());
+        return;
+    }
+}
+
+/// FIPS 204 Algorithm 8, ML-DSA.Verify_internal, with M' being
+/// `prefix || msg`.
+fn verify(
+    p: &Parameters,
+    pk: &[u8],
+    prefix: &MessagePrefix,
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    if pk.len() != p.public_key_len() || signature.len() != p.signature_len() {
+        return Err(error::Unspecified);
+    }
+    let gamma1 = p.gamma1();
+
+    // Algorithm 23, pkDecode.
+    let mut input = Input(pk);
+    let rho = input.take(RHO_LEN);
+    let mut t1_hat = [Poly::ZERO; MAX_K];
+    for t1 in &mut t1_hat[..p.k] {
+        *t1 = unpack(input.take(T1_BITS * N / 8), T1_BITS, |v| v << D);
+        t1.ntt();
+    }
+
+    // Algorithm 27, sigDecode.
+    let mut input = Input(signature);
+    let c_tilde = input.take(p.c_tilde_len());
+    let mut z_hat = [Poly::ZERO; MAX_L];
+    for z in &mut z_hat[..p.l] {
+        *z = unpack(input.take(p.z_bits() * N / 8), p.z_bits(), |v| gamma1 - v);
+    }
+    let hints = hint_bit_unpack(p, input.take(p.omega + p.k))?;
+
+    if z_hat[..p.l]
+        .iter()
+        .any(|z| z.norm_is_at_least(gamma1 - p.beta))
+    {
+        return Err(error::Unspecified);
+    }
+    z_hat[..p.l].iter_mut().for_each(Poly::ntt);
+
+    let a_hat = expand_a(p, rho);
+
+    let mut tr = [0; TR_LEN];
+    h(&[pk], &mut tr);
+    let mut mu = [0; MU_LEN];
+    h(&[&tr, prefix.as_ref(), msg], &mut mu);
+
+    let mut c_hat = sample_in_ball(p, c_tilde);
+    c_hat.ntt();
+
+    // w'_approx = A*z - c*t1*2**d.
+    let mut w = [Poly::ZERO; MAX_K];
+    for ((w, a_hat), t1_hat) in w[..p.k].iter_mut().zip(a_hat.iter()).zip(t1_hat.iter()) {
+        let mut acc = Poly::ZERO;
+        for (a, z) in a_hat[..p.l].iter().zip(z_hat.iter()) {
+            acc = acc.add(&a.pointwise_mont(z));
+        }
+        *w = acc.sub(&c_hat.pointwise_mont(t1_hat));
+        w.reduce();
+        w.inverse_ntt_to_mont();
+        w.freeze();
+    }
+
+    let gamma2 = p.gamma2;
+    let mut c_tilde_prime = [0; 64];
+    let c_tilde_prime = &mut c_tilde_prime[..p.c_tilde_len()];
+    let mut w1 = w;
+    for (w1, hints) in w1[..p.k].iter_mut().zip(hints.iter()) {
+        for (c, h) in w1.0.iter_mut().zip(hints.0.iter()) {
+            *c = use_hint(*h != 0, *c, gamma2);
+        }
+    }
+    commitment_hash(p, &mu, &w1, |c| c, c_tilde_prime);
+
+    if c_tilde != c_tilde_prime {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// c~ = H(mu || w1Encode(w1)), where `w1` is `high_bits` applied to each
+/// coefficient of `w`; Algorithm 28 is w1Encode.
+fn commitment_hash(
+    p: &Parameters,
+    mu: &[u8; MU_LEN],
+    w: &[Poly; MAX_K],
+    high_bits: impl Fn(i32) -> i32,
+    c_tilde: &mut [u8],
+) {
+    let mut ctx = xof::Context::new(&xof::SHAKE256);
+    ctx.update(mu);
+    let mut packed = [0; 6 * N / 8];
+    let packed = &mut packed[..(p.w1_bits() * N / 8)];
+    for w in &w[..p.k] {
+        pack(w, p.w1_bits(), packed, &high_bits);
+        ctx.update(packed);
+    }
+    ctx.finish().squeeze(c_tilde);
+}
+
+/// FIPS 204 Algorithm 21, HintBitUnpack.
+fn hint_bit_unpack(p: &Parameters, y: &[u8]) -> Result<[Poly; MAX_K], error::Unspecified> {
+    let mut hints = [Poly::ZERO; MAX_K];
+    let mut index = 0;
+    for (i, hints) in hints[..p.k].iter_mut().enumerate() {
+        let end = usize::from(y[p.omega + i]);
+        if end < index || end > p.omega {
+            return Err(error::Unspecified);
+        }
+        let first = index;
+        while index < end {
+            // The indices must be strictly increasing, so that the encoding
+            // is unique.
+            if index > first && y[index - 1] >= y[index] {
+                return Err(error::Unspecified);
+            }
+            hints.0[usize::from(y[index])] = 1;
+            index += 1;
+        }
+    }
+    if y[index..p.omega].iter().any(|b| *b != 0) {
+        return Err(error::Unspecified);
+    }
+    Ok(hints)
+}
+
+/// Returns the *l* polynomials A[i][j] * v[j] summed, with the result
+/// out of the NTT domain, in (-q, q). All inputs are in the NTT domain.
+fn matrix_row_mul(p: &Parameters, a_hat: &[Poly; MAX_L], v_hat: &[Poly; MAX_L]) -> Poly {
+    let mut acc = Poly::ZERO;
+    for (a, v) in a_hat[..p.l].iter().zip(v_hat.iter()) {
+        acc = acc.add(&a.pointwise_mont(v));
+    }
+    acc.reduce();
+    acc.inverse_ntt_to_mont();
+    acc
+}
+
+/// FIPS 204 Algorithm 32, ExpandA.
+fn expand_a(p: &Parameters, rho: &[u8]) -> Matrix {
+    let mut a_hat = [[Poly::ZERO; MAX_L]; MAX_K];
+    for (r, row) in a_hat[..p.k].iter_mut().enumerate() {
+        for (s, a) in row[..p.l].iter_mut().enumerate() {
+            *a = rej_ntt_poly(rho, s as u8, r as u8);
+        }
+    }
+    a_hat
+}
+
+/// FIPS 204 Algorithm 30, RejNTTPoly, with the seed `rho || s || r`.
+fn rej_ntt_poly(rho: &[u8], s: u8, r: u8) -> Poly {
+    let mut ctx = xof::Context::new(&xof::SHAKE128);
+    ctx.update(rho);
+    ctx.update(&[s, r]);
+    let mut reader = ctx.finish();
+
+    let mut a = Poly::ZERO;
+    let mut j = 0;
+    let mut block = [0; 168];
+    while j < N {
+        reader.squeeze(&mut block);
+        for b in block.chunks_exact(3) {
+            // Algorithm 14, CoeffFromThreeBytes.
+            let c = i32::from(b[0]) | (i32::from(b[1]) << 8) | (i32::from(b[2] & 0x7f) << 16);
+            if c < Q && j < N {
+                a.0[j] = c;
+                j += 1;
+            }
+        }
+    }
+    a
+}
+
+/// FIPS 204 Algorithm 33, ExpandS.
+fn expand_s(p: &Parameters, rho_prime: &[u8]) -> ([Poly; MAX_L], [Poly; MAX_K]) {
+    let mut s1 = [Poly::ZERO; MAX_L];
+    let mut s2 = [Poly::ZERO; MAX_K];
+    for (r, s) in s1[..p.l].iter_mut().chain(s2[..p.k].iter_mut()).enumerate() {
+        *s = rej_bounded_poly(p.eta, rho_prime, r as u16);
+    }
+    (s1, s2)
+}
+
+/// FIPS 204 Algorithm 31, RejBoundedPoly, with the seed `rho || r`.
+fn rej_bounded_poly(eta: i32, rho: &[u8], r: u16) -> Poly {
+    // Algorithm 15, CoeffFromHalfByte.
+    let coeff_from_half_byte = |b: i32| -> Option<i32> {
+        match eta {
+            2 if b < 15 => Some(2 - (b % 5)),
+            4 if b < 9 => Some(4 - b),
+            _ => None,
+        }
+    };
+
+    let mut ctx = xof::Context::new(&xof::SHAKE256);
+    ctx.update(rho);
+    ctx.update(&r.to_le_bytes());
+    let mut reader = ctx.finish();
+
+    let mut a = Poly::ZERO;
+    let mut j = 0;
+    let mut block = [0; 136];
+    while j < N {
+        reader.squeeze(&mut block);
+        for b in block.iter() {
+            for half in [i32::from(b & 0xf), i32::from(b >> 4)].iter() {
+                if let Some(c) = coeff_from_half_byte(*half) {
+                    if j < N {
+                        a.0[j] = c;
+                        j += 1;
+                    }
+                }
+            }
+        }
+    }
+    a
+}
+
+/// FIPS 204 Algorithm 34, ExpandMask.
+fn expand_mask(p: &Parameters, rho: &[u8; RHO_PRIME_LEN], kappa: u16) -> [Poly; MAX_L] {
+    let gamma1 = p.gamma1();
+    let mut y = [Poly::ZERO; MAX_L];
+    let mut v = [0; 20 * N / 8];
+    let v = &mut v[..(p.z_bits() * N / 8)];
+    for (r, y) in y[..p.l].iter_mut().enumerate() {
+        h(&[rho, &kappa.wrapping_add(r as u16).to_le_bytes()], v);
+        *y = unpack(v, p.z_bits(), |v| gamma1 - v);
+    }
+    y
+}
+
+/// FIPS 204 Algorithm 29, SampleInBall.
+fn sample_in_ball(p: &Parameters, rho: &[u8]) -> Poly {
+    let mut ctx = xof::Context::new(&xof::SHAKE256);
+    ctx.update(rho);
+    let mut reader = ctx.finish();
+
+    let mut signs = [0; 8];
+    reader.squeeze(&mut signs);
+    let mut signs = u64::from_le_bytes(signs);
+
+    let mut c = Poly::ZERO;
+    for i in (N - p.tau)..N {
+        let j = loop {
+            let mut j = [0];
+            reader.squeeze(&mut j);
+            let j = usize::from(j[0]);
+            if j <= i {
+                break j;
+            }
+        };
+        c.0[i] = c.0[j];
+        c.0[j] = 1 - 2 * ((signs & 1) as i32);
+        signs >>= 1;
+    }
+    c
+}
+
+/// Splits successive fields off the front of a byte string.
+struct Input<'a>(&'a [u8]);
+
+impl<'a> Input<'a> {
+    fn take(&mut self, len: usize) -> &'a [u8] {
+        let (field, rest) = self.0.split_at(len);
+        self.0 = rest;
+        field
+    }
+}
+
+/// Splits successive fields off the front of an output buffer.
+struct Output<'a>(&'a mut [u8]);
+
+impl<'a> Output<'a> {
+    fn take(&mut self, len: usize) -> &'a mut [u8] {
+        let (field, rest) = core::mem::take(&mut self.0).split_at_mut(len);
+        self.0 = rest;
+        field
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! Arithmetic on polynomials in Z_q[X]/(X^256 + 1), as specified in
+//! [FIPS 204].
+//!
+//! Coefficients are stored as `i32`s that aren't necessarily fully reduced;
+//! each function documents the range it expects and produces. The NTT and
+//! multiplication follow the reference implementation, using Montgomery
+//! arithmetic with R = 2**32.
+//!
+//! [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+
+/// The number of coefficients.
+pub const N: usize = 256;
+
+/// The modulus, q = 2**23 - 2**13 + 1.
+pub const Q: i32 = 8_380_417;
+
+/// The number of bits dropped from `t`, i.e. `d`.
+pub const D: u32 = 13;
+
+#[derive(Clone, Copy)]
+pub struct Poly(pub [i32; N]);
+
+impl Poly {
+    pub const ZERO: Self = Self([0; N]);
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut r = *self;
+        r.0.iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(r, b)| *r += b);
+        r
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut r = *self;
+        r.0.iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(r, b)| *r -= b);
+        r
+    }
+
+    /// Reduces each coefficient to the range [0, q).
+    pub fn freeze(&mut self) {
+        self.0.iter_mut().for_each(|c| *c = freeze(*c));
+    }
+
+    /// Reduces each coefficient to the range (-q/2, q/2].
+    pub fn center(&mut self) {
+        self.0.iter_mut().for_each(|c| *c = centered(*c));
+    }
+
+    /// Reduces each coefficient to the range (-6283009, 6283008).
+    pub fn reduce(&mut self) {
+        self.0.iter_mut().for_each(|c| *c = reduce32(*c));
+    }
+
+    /// Returns true if any coefficient, taken as an element of (-q/2, q/2],
+    /// has absolute value of at least `bound`.
+    pub fn norm_is_at_least(&self, bound: i32) -> bool {
+        self.0.iter().fold(false, |acc, c| {
+            let c = centered(*c);
+            let abs = c - ((c >> 31) & (2 * c));
+            acc | (abs >= bound)
+        })
+    }
+
+    /// Transforms `self` into the NTT domain. Coefficients must be in
+    /// (-q, q); they are in (-9q, 9q) afterwards.
+    pub fn ntt(&mut self) {
+        let a = &mut self.0;
+        let mut k = 0;
+        let mut len = N / 2;
+        while len > 0 {
+            let mut start = 0;
+            while start < N {
+                k += 1;
+                let zeta = i64::from(ZETAS[k]);
+                for j in start..(start + len) {
+                    let t = montgomery_reduce(zeta * i64::from(a[j + len]));
+                    a[j + len] = a[j] - t;
+                    a[j] += t;
+                }
+                start += 2 * len;
+            }
+            len /= 2;
+        }
+    }
+
+    /// The inverse of `ntt()`, multiplied by the Montgomery factor 2**32.
+    /// Coefficients must be in (-q, q); they are in (-q, q) afterwards.
+    pub fn inverse_ntt_to_mont(&mut self) {
+        // mont**2 / 256 (mod q).
+        const F: i64 = 41978;
+
+        let a = &mut self.0;
+        let mut k = N;
+        let mut len = 1;
+        while len < N {
+            let mut start = 0;
+            while start < N {
+                k -= 1;
+                let zeta = -i64::from(ZETAS[k]);
+                for j in start..(start + len) {
+                    let t = a[j];
+                    a[j] = t + a[j + len];
+                    a[j + len] = montgomery_reduce(zeta * i64::from(t - a[j + len]));
+                }
+                start += 2 * len;
+            }
+            len *= 2;
+        }
+        a.iter_mut()
+            .for_each(|c| *c = montgomery_reduce(F * i64::from(*c)));
+    }
+
+    /// Returns `self * other / 2**32` coefficient-wise; both must be in the
+    /// NTT domain.
+    pub fn pointwise_mont(&self, other: &Self) -> Self {
+        let mut r = Self::ZERO;
+        r.0.iter_mut()
+            .zip(self.0.iter().zip(other.0.iter()))
+            .for_each(|(r, (a, b))| *r = montgomery_reduce(i64::from(*a) * i64::from(*b)));
+        r
+    }
+}
+
+/// Returns (r1, r0) such that r1 * 2**d + r0 == r where r0 is in
+/// (-2**(d-1), 2**(d-1)], for `r` in [0, q); FIPS 204 Algorithm 35.
+pub fn power2round(r: i32) -> (i32, i32) {
+    let r1 = (r + (1 << (D - 1)) - 1) >> D;
+    (r1, r - (r1 << D))
+}
+
+/// Returns (r1, r0) such that r1 * 2 * gamma2 + r0 == r (mod q) where r0 is
+/// in (-gamma2, gamma2], except that r1 is zero instead of
+/// (q - 1) / (2 * gamma2), for `r` in [0, q); FIPS 204 Algorithm 36.
+pub fn decompose(r: i32, gamma2: i32) -> (i32, i32) {
+    let mut r1 = (r + 127) >> 7;
+    if gamma2 == (Q - 1) / 32 {
+        r1 = (r1 * 1025 + (1 << 21)) >> 22;
+        r1 &= 15;
+    } else {
+        debug_assert_eq!(gamma2, (Q - 1) / 88);
+        r1 = (r1 * 11275 + (1 << 23)) >> 24;
+        r1 ^= ((43 - r1) >> 31) & r1;
+    }
+    let mut r0 = r - r1 * 2 * gamma2;
+    r0 -= (((Q - 1) / 2 - r0) >> 31) & Q;
+    (r1, r0)
+}
+
+/// FIPS 204 Algorithm 40, for `r` in [0, q).
+pub fn use_hint(hint: bool, r: i32, gamma2: i32) -> i32 {
+    let (r1, r0) = decompose(r, gamma2);
+    if !hint {
+        return r1;
+    }
+    let m = (Q - 1) / (2 * gamma2);
+    if r0 > 0 {
+        (r1 + 1) % m
+    } else {
+        (r1 - 1 + m) % m
+    }
+}
+
+/// Packs the values `f(c)` for each coefficient `c` of `p`, which must all
+/// be in [0, 2**bits), into `out` of `bits * N / 8` bytes, least significant
+/// bit first; FIPS 204 Algorithms 16 and 17.
+pub fn pack(p: &Poly, bits: usize, out: &mut [u8], f: impl Fn(i32) -> i32) {
+    debug_assert_eq!(out.len(), bits * N / 8);
+    let mut acc: u64 = 0;
+    let mut acc_bits = 0;
+    let mut out = out.iter_mut();
+    for c in p.0.iter() {
+        let v = f(*c);
+        debug_assert!(v >= 0 && v < (1 << bits));
+        acc |= (v as u64) << acc_bits;
+        acc_bits += bits;
+        while acc_bits >= 8 {
+            *out.next().unwrap() = acc as u8;
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    }
+}
+
+/// The inverse of `pack()`: returns the polynomial with coefficients `f(v)`
+/// for each `bits`-bit value `v` in `input`; FIPS 204 Algorithms 18 and 19.
+pub fn unpack(input: &[u8], bits: usize, f: impl Fn(i32) -> i32) -> Poly {
+    debug_assert_eq!(input.len(), bits * N / 8);
+    let mut r = Poly::ZERO;
+    let mut acc: u64 = 0;
+    let mut acc_bits = 0;
+    let mut input = input.iter();
+    for c in r.0.iter_mut() {
+        while acc_bits < bits {
+            acc |= u64::from(*input.next().unwrap()) << acc_bits;
+            acc_bits += 8;
+        }
+        *c = f((acc & ((1 << bits) - 1)) as i32);
+        acc >>= bits;
+        acc_bits -= bits;
+    }
+    r
+}
+
+/// Returns `a / 2**32 (mod q)` in (-q, q), for `a` in (-2**31 q, 2**31 q).
+fn montgomery_reduce(a: i64) -> i32 {
+    // q**-1 (mod 2**32).
+    const Q_INV: i32 = 58_728_449;
+    let t = (a as i32).wrapping_mul(Q_INV);
+    ((a - i64::from(t) * i64::from(Q)) >> 32) as i32
+}
+
+/// Returns `a (mod q)` in (-6283009, 6283008), for `a` <= 2**31 - 2**22 - 1.
+fn reduce32(a: i32) -> i32 {
+    let t = (a + (1 << 22)) >> 23;
+    a - t * Q
+}
+
+/// Returns `a (mod q)` in [0, q).
+pub fn freeze(a: i32) -> i32 {
+    let a = reduce32(a);
+    a + ((a >> 31) & Q)
+}
+
+/// Returns `a (mod q)` in (-q/2, q/2].
+fn centered(a: i32) -> i32 {
+    let a = freeze(a);
+    a - ((((Q - 1) / 2 - a) >> 31) & Q)
+}
+
+/// Powers of the 512th root of unity 1753, in bit-reversed order, in
+/// Montgomery form.
+static ZETAS: [i32; N] = [
+    -4186625, 25847, -2608894, -518909, 237124, -777960, -876248, 466468, 1826347, 2353451,
+    -359251, -2091905, 3119733, -2884855, 3111497, 2680103, 2725464, 1024112, -1079900, 3585928,
+    -549488, -1119584, 2619752, -2108549, -2118186, -3859737, -1399561, -3277672, 1757237, -19422,
+    4010497, 280005, 2706023, 95776, 3077325, 3530437, -1661693, -3592148, -2537516, 3915439,
+    -3861115, -3043716, 3574422, -2867647, 3539968, -300467, 2348700, -539299, -1699267, -1643818,
+    3505694, -3821735, 3507263, -2140649, -1600420, 3699596, 811944, 531354, 954230, 3881043,
+    3900724, -2556880, 2071892, -2797779, -3930395, -1528703, -3677745, -3041255, -1452451,
+    3475950, 2176455, -1585221, -1257611, 1939314, -4083598, -1000202, -3190144, -3157330,
+    -3632928, 126922, 3412210, -983419, 2147896, 2715295, -2967645, -3693493, -411027, -2477047,
+    -671102, -1228525, -22981, -1308169, -381987, 1349076, 1852771, -1430430, -3343383, 264944,
+    508951, 3097992, 44288, -1100098, 904516, 3958618, -3724342, -8578, 1653064, -3249728, 2389356,
+    -210977, 759969, -1316856, 189548, -3553272, 3159746, -1851402, -2409325, -177440, 1315589,
+    1341330, 1285669, -1584928, -812732, -1439742, -3019102, -3881060, -3628969, 3839961, 2091667,
+    3407706, 2316500, 3817976, -3342478, 2244091, -2446433, -3562462, 266997, 2434439, -1235728,
+    3513181, -3520352, -3759364, -1197226, -3193378, 900702, 1859098, 909542, 819034, 495491,
+    -1613174, -43260, -522500, -655327, -3122442, 2031748, 3207046, -3556995, -525098, -768622,
+    -3595838, 342297, 286988, -2437823, 4108315, 3437287, -3342277, 1735879, 203044, 2842341,
+    2691481, -2590150, 1265009, 4055324, 1247620, 2486353, 1595974, -3767016, 1250494, 2635921,
+    -3548272, -2994039, 1869119, 1903435, -1050970, -1333058, 1237275, -3318210, -1430225, -451100,
+    1312455, 3306115, -1962642, -1279661, 1917081, -2546312, -1374803, 1500165, 777191, 2235880,
+    3406031, -542412, -2831860, -1671176, -1846953, -2584293, -3724270, 594136, -3776993, -2013608,
+    2432395, 2454455, -164721, 1957272, 3369112, 185531, -1207385, -3183426, 162844, 1616392,
+    3014001, 810149, 1652634, -3694233, -1799107, -3038916, 3523897, 3866901, 269760, 2213111,
+    -975884, 1717735, 472078, -426683, 1723600, -1803090, 1910376, -1667432, -1104333, -260646,
+    -3833893, -2939036, -2235985, -420899, -2286327, 183443, -976891, 1612842, -3545687, -554416,
+    3919660, -48306, -1362209, 3937738, 1400424, -846154, 1976782,
+];
@@ -0,0 +1,309 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! ML-DSA key pairs and signing.
+
+use super::{
+    key_gen, sign, MessagePrefix, Parameters, ML_DSA_44_PARAMETERS, ML_DSA_65_PARAMETERS,
+    ML_DSA_87_PARAMETERS, PRIVATE_KEY_MAX_LEN, PUBLIC_KEY_MAX_LEN, RND_LEN, SEED_LEN,
+};
+use crate::{error, io::der, pkcs8, rand, signature};
+use core::convert::TryInto;
+
+/// An ML-DSA signing algorithm.
+pub struct MlDsaSigningAlgorithm {
+    params: &'static Parameters,
+    pkcs8_template: &'static pkcs8::Template,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    ML_DSA_44_SIGNING,
+    ML_DSA_65_SIGNING,
+    ML_DSA_87_SIGNING,
+}
+
+derive_debug_via_id!(MlDsaSigningAlgorithm);
+
+impl MlDsaSigningAlgorithm {
+    /// The length of the signatures this algorithm produces.
+    pub fn signature_len(&self) -> usize {
+        self.params.signature_len()
+    }
+}
+
+/// An ML-DSA key pair, for signing.
+pub struct MlDsaKeyPair {
+    alg: &'static MlDsaSigningAlgorithm,
+    private_key: [u8; PRIVATE_KEY_MAX_LEN],
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(MlDsaKeyPair, stringify!(MlDsaKeyPair), public_key);
+
+impl MlDsaKeyPair {
+    /// Generates a new key pair and returns the key pair serialized as a
+    /// PKCS#8 document.
+    ///
+    /// The PKCS#8 document will be a v1 `OneAsymmetricKey` whose private key
+    /// is the 32-byte seed, i.e. the `seed` choice of `ML-DSA-PrivateKey`, as
+    /// described in [RFC 9881].
+    ///
+    /// [RFC 9881]: https://www.rfc-editor.org/rfc/rfc9881
+    pub fn generate_pkcs8(
+        alg: &'static MlDsaSigningAlgorithm,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let seed: [u8; SEED_LEN] = rand::generate(rng)?.expose();
+        Ok(pkcs8::wrap_key(alg.pkcs8_template, &seed, &[]))
+    }
+
+    /// Constructs an ML-DSA key pair by parsing an unencrypted PKCS#8 v1 or
+    /// v2 ML-DSA private key.
+    ///
+    /// The private key must be either the `seed` or the `both` choice of
+    /// `ML-DSA-PrivateKey` in [RFC 9881]; the `expandedKey` form, which
+    /// lacks the seed, isn't supported. When the expanded key or the public
+    /// key is present, it is verified to be consistent with the seed.
+    ///
+    /// [RFC 9881]: https://www.rfc-editor.org/rfc/rfc9881
+    pub fn from_pkcs8(
+        alg: &'static MlDsaSigningAlgorithm,
+        pkcs8: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let (private_key, public_key) = pkcs8::unwrap_key(
+            alg.pkcs8_template,
+            pkcs8::Version::V1OrV2,
+            untrusted::Input::from(pkcs8),
+        )?;
+        let (seed, expanded_key) = private_key
+            .read_all(error::Unspecified, |input| {
+                if input.peek(der::Tag::Sequence as u8) {
+                    der::nested(input, der::Tag::Sequence, error::Unspecified, |input| {
+                        let seed = der::expect_tag_and_get_value(input, der::Tag::OctetString)?;
+                        let expanded_key =
+                            der::expect_tag_and_get_value(input, der::Tag::OctetString)?;
+                        Ok((seed, Some(expanded_key)))
+                    })
+                } else {
+                    let seed = der::expect_tag_and_get_value(input, der::Tag::ContextSpecific0)?;
+                    Ok((seed, None))
+                }
+            })
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+
+        let key_pair = Self::from_seed(alg, seed.as_slice_less_safe())?;
+        if let Some(expanded_key) = expanded_key {
+            if expanded_key.as_slice_less_safe() != key_pair.private_key() {
+                return Err(error::KeyRejected::inconsistent_components());
+            }
+        }
+        if let Some(public_key) = public_key {
+            if public_key.as_slice_less_safe() != key_pair.public_key.as_ref() {
+                return Err(error::KeyRejected::inconsistent_components());
+            }
+        }
+        Ok(key_pair)
+    }
+
+    /// Constructs an ML-DSA key pair from the 32-byte seed *ξ* of FIPS 204
+    /// Algorithm 6.
+    ///
+    /// It is recommended to use `MlDsaKeyPair::from_pkcs8()` instead.
+    pub fn from_seed(
+        alg: &'static MlDsaSigningAlgorithm,
+        seed: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let seed: [u8; SEED_LEN] = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let params = alg.params;
+        let mut key_pair = Self {
+            alg,
+            private_key: [0; PRIVATE_KEY_MAX_LEN],
+            public_key: PublicKey {
+                params,
+                bytes: [0; PUBLIC_KEY_MAX_LEN],
+            },
+        };
+        key_gen(
+            params,
+            &seed,
+            &mut key_pair.public_key.bytes[..params.public_key_len()],
+            &mut key_pair.private_key[..params.private_key_len()],
+        );
+        Ok(key_pair)
+    }
+
+    /// Returns the algorithm of the key pair.
+    pub fn algorithm(&self) -> &'static MlDsaSigningAlgorithm {
+        self.alg
+    }
+
+    /// Signs the message `msg` with an empty context string, writing the
+    /// signature into `signature`.
+    ///
+    /// `signature` must be exactly `self.algorithm().signature_len()` bytes
+    /// long. The signature is hedged: it uses 32 bytes of randomness from
+    /// `rng` along with the private key, as FIPS 204 recommends.
+    pub fn sign(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        self.sign_with_context(rng, &[], msg, signature)
+    }
+
+    /// Like `sign()`, with the context string `context`.
+    ///
+    /// The context separates the signatures of different protocols, or of
+    /// different uses within a protocol: a signature is only valid for the
+    /// same context. It must be verified with
+    /// `MlDsaVerificationAlgorithm::verify_with_context`.
+    ///
+    /// Fails if `context` is longer than 255 bytes.
+    pub fn sign_with_context(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        context: &[u8],
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        if signature.len() != self.alg.signature_len() {
+            return Err(error::Unspecified);
+        }
+        let prefix = MessagePrefix::new(context)?;
+        let rnd: [u8; RND_LEN] = rand::generate(rng)?.expose();
+        sign(
+            self.alg.params,
+            self.private_key(),
+            &prefix,
+            msg,
+            &rnd,
+            signature,
+        );
+        Ok(())
+    }
+
+    fn private_key(&self) -> &[u8] {
+        &self.private_key[..self.alg.params.private_key_len()]
+    }
+}
+
+impl signature::KeyPair for MlDsaKeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+impl signature::KeyPairGenerator for MlDsaSigningAlgorithm {
+    type KeyPair = MlDsaKeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = MlDsaKeyPair::generate_pkcs8(self, rng)?;
+        let key_pair = MlDsaKeyPair::from_pkcs8(self, pkcs8.as_ref())?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
+/// The public key of an `MlDsaKeyPair`, encoded as in FIPS 204 Algorithm 22.
+#[derive(Clone)]
+pub struct PublicKey {
+    params: &'static Parameters,
+    bytes: [u8; PUBLIC_KEY_MAX_LEN],
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.params.public_key_len()]
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+#[cfg(feature = "alloc")]
+impl PublicKey {
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`, as
+    /// described in [RFC 9881].
+    ///
+    /// [RFC 9881]: https://www.rfc-editor.org/rfc/rfc9881
+    pub fn subject_public_key_info(&self) -> alloc::boxed::Box<[u8]> {
+        use crate::io::der_writer;
+        der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                der_writer::write_tlv(output, der::Tag::OID, |output| {
+                    output.write_bytes(self.params.oid)
+                })
+            });
+            der_writer::write_tlv(output, der::Tag::BitString, |output| {
+                output.write_byte(0); // No unused bits.
+                output.write_bytes(self.as_ref());
+            });
+        })
+    }
+}
+
+/// Signing using ML-DSA-44, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_44_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm {
+    params: &ML_DSA_44_PARAMETERS,
+    pkcs8_template: &ML_DSA_44_PKCS8_TEMPLATE,
+    id: AlgorithmID::ML_DSA_44_SIGNING,
+};
+
+/// Signing using ML-DSA-65, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_65_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm {
+    params: &ML_DSA_65_PARAMETERS,
+    pkcs8_template: &ML_DSA_65_PKCS8_TEMPLATE,
+    id: AlgorithmID::ML_DSA_65_SIGNING,
+};
+
+/// Signing using ML-DSA-87, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_87_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm {
+    params: &ML_DSA_87_PARAMETERS,
+    pkcs8_template: &ML_DSA_87_PKCS8_TEMPLATE,
+    id: AlgorithmID::ML_DSA_87_SIGNING,
+};
+
+static ML_DSA_44_PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ml_dsa_44_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 7, end: 18 },
+    curve_id_index: 0,
+    private_key_index: 22,
+};
+
+static ML_DSA_65_PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ml_dsa_65_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 7, end: 18 },
+    curve_id_index: 0,
+    private_key_index: 22,
+};
+
+static ML_DSA_87_PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("ml_dsa_87_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 7, end: 18 },
+    curve_id_index: 0,
+    private_key_index: 22,
+};
@@ -0,0 +1,124 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! ML-DSA signature verification.
+
+use super::{
+    verify, MessagePrefix, Parameters, ML_DSA_44_PARAMETERS, ML_DSA_65_PARAMETERS,
+    ML_DSA_87_PARAMETERS,
+};
+use crate::{error, io::der, sealed, signature};
+
+/// An ML-DSA verification algorithm.
+pub struct MlDsaVerificationAlgorithm {
+    params: &'static Parameters,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    ML_DSA_44,
+    ML_DSA_65,
+    ML_DSA_87,
+}
+
+derive_debug_via_id!(MlDsaVerificationAlgorithm);
+
+impl signature::VerificationAlgorithm for MlDsaVerificationAlgorithm {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        self.verify_with_context(
+            public_key.as_slice_less_safe(),
+            &[],
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+}
+
+impl sealed::Sealed for MlDsaVerificationAlgorithm {}
+
+impl MlDsaVerificationAlgorithm {
+    /// Verifies that `signature` is a valid signature, with the context
+    /// `context`, by `public_key` of the message `msg`.
+    ///
+    /// Verification through `signature::UnparsedPublicKey` uses an empty
+    /// context. Fails if `context` is longer than 255 bytes, or if the
+    /// signature is invalid.
+    pub fn verify_with_context(
+        &self,
+        public_key: &[u8],
+        context: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let prefix = MessagePrefix::new(context)?;
+        verify(self.params, public_key, &prefix, msg, signature)
+    }
+
+    /// Returns the public key in the DER `SubjectPublicKeyInfo` `spki`, as
+    /// described in [RFC 9881], for use with `signature::UnparsedPublicKey`.
+    ///
+    /// Fails if `spki` isn't for this algorithm. The public key isn't
+    /// otherwise validated.
+    ///
+    /// [RFC 9881]: https://www.rfc-editor.org/rfc/rfc9881
+    pub fn public_key_from_spki<'a>(&self, spki: &'a [u8]) -> Result<&'a [u8], error::Unspecified> {
+        let public_key = untrusted::Input::from(spki).read_all(error::Unspecified, |input| {
+            der::nested(input, der::Tag::Sequence, error::Unspecified, |input| {
+                let alg_id = der::expect_tag_and_get_value(input, der::Tag::Sequence)?;
+                alg_id.read_all(error::Unspecified, |input| {
+                    let oid = der::expect_tag_and_get_value(input, der::Tag::OID)?;
+                    if oid.as_slice_less_safe() != self.params.oid {
+                        return Err(error::Unspecified);
+                    }
+                    Ok(())
+                })?;
+                der::bit_string_with_no_unused_bits(input)
+            })
+        })?;
+        let public_key = public_key.as_slice_less_safe();
+        if public_key.len() != self.params.public_key_len() {
+            return Err(error::Unspecified);
+        }
+        Ok(public_key)
+    }
+}
+
+/// Verification of ML-DSA-44 signatures, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_44: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    params: &ML_DSA_44_PARAMETERS,
+    id: AlgorithmID::ML_DSA_44,
+};
+
+/// Verification of ML-DSA-65 signatures, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_65: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    params: &ML_DSA_65_PARAMETERS,
+    id: AlgorithmID::ML_DSA_65,
+};
+
+/// Verification of ML-DSA-87 signatures, as specified in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+pub static ML_DSA_87: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    params: &ML_DSA_87_PARAMETERS,
+    id: AlgorithmID::ML_DSA_87,
+};
@@ -0,0 +1,80 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! MuSig2 multi-signatures over secp256k1.
+//!
+//! MuSig2 is a two-round protocol in which the holders of several secp256k1
+//! keys produce a single signature for their aggregated public key. The
+//! signature is an ordinary BIP-340 Schnorr signature, which verifies with
+//! `signature::BIP340_SCHNORR_SECP256K1`, so a verifier can't tell that it
+//! was produced by more than one signer.
+//!
+//! This implements [BIP-327]. All the signers first agree on the list of
+//! their public keys and construct the same `KeyAggContext` from it. In
+//! round one, each signer calls `KeyPair::generate_nonce()`, keeps the secret
+//! nonce, and sends the public nonce to the others, or to a coordinator that
+//! calls `aggregate_nonces()`. In round two, each signer constructs a
+//! `Session` for the message and the aggregated nonce and calls
+//! `Session::sign()`, and anybody can then call `Session::aggregate()` to get
+//! the signature.
+//!
+//! ```
+//! use ring::{musig2, rand, signature};
+//!
+//! let rng = rand::SystemRandom::new();
+//! let signers = [
+//!     musig2::KeyPair::generate(&rng)?,
+//!     musig2::KeyPair::generate(&rng)?,
+//! ];
+//! let public_keys = [
+//!     *signature::KeyPair::public_key(&signers[0]),
+//!     *signature::KeyPair::public_key(&signers[1]),
+//! ];
+//! let key_agg_ctx = musig2::KeyAggContext::new(&public_keys)?;
+//! let msg = b"transfer 100";
+//!
+//! // Round one.
+//! let mut secret_nonces = Vec::new();
+//! let mut public_nonces = Vec::new();
+//! for signer in signers.iter() {
+//!     let (secret_nonce, public_nonce) =
+//!         signer.generate_nonce(&key_agg_ctx, Some(msg), &rng)?;
+//!     secret_nonces.push(secret_nonce);
+//!     public_nonces.push(public_nonce);
+//! }
+//! let aggregated_nonce = musig2::aggregate_nonces(&public_nonces)?;
+//!
+//! // Round two.
+//! let session = musig2::Session::new(&key_agg_ctx, &aggregated_nonce, msg)?;
+//! let mut partial_signatures = Vec::new();
+//! for (signer, secret_nonce) in signers.iter().zip(secret_nonces) {
+//!     partial_signatures.push(session.sign(signer, secret_nonce)?);
+//! }
+//!
+//! let sig = session.aggregate(&partial_signatures)?;
+//! let public_key = signature::UnparsedPublicKey::new(
+//!     &signature::BIP340_SCHNORR_SECP256K1,
+//!     key_agg_ctx.aggregated_public_key(),
+//! );
+//! public_key.verify(msg, sig.as_ref())?;
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [BIP-327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+
+pub use crate::ec::suite_b::musig2::{
+    aggregate_nonces, AggregatedNonce, KeyAggContext, KeyPair, PartialSignature, PublicKey,
+    PublicNonce, SecretNonce, Session, AGGREGATED_PUBLIC_KEY_LEN, NONCE_LEN, PARTIAL_SIGNATURE_LEN,
+    PUBLIC_KEY_LEN,
+};
@@ -0,0 +1,216 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! HOTP and TOTP one-time passwords.
+//!
+//! HOTP is specified in [RFC 4226] and TOTP, which is HOTP with a counter
+//! derived from the time, in [RFC 6238]. These are the codes generated by
+//! authenticator apps for two-factor authentication.
+//!
+//! ```
+//! use ring::{hmac, otp};
+//! use std::num::NonZeroU64;
+//!
+//! let key = otp::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, b"12345678901234567890", 8)?;
+//! let step = NonZeroU64::new(otp::DEFAULT_TIME_STEP_SECS).unwrap();
+//!
+//! let code = otp::totp(&key, step, 1_111_111_109);
+//! assert_eq!(code.as_ref(), b"07081804");
+//!
+//! // Accept codes from one time step before or after the current one, to
+//! // allow for clock skew and for the time it takes to type the code.
+//! let time_step = otp::verify_totp(&key, step, 1_111_111_139, 1, b"07081804")?;
+//! assert_eq!(time_step, 1_111_111_109 / 30);
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 4226]: https://tools.ietf.org/html/rfc4226
+//! [RFC 6238]: https://tools.ietf.org/html/rfc6238
+
+use crate::{constant_time, error, hmac};
+use core::num::NonZeroU64;
+
+/// The minimum number of digits in a code, per RFC 4226 Section 5.3.
+pub const MIN_DIGITS: u32 = 6;
+
+/// The maximum number of digits in a code.
+pub const MAX_DIGITS: u32 = 9;
+
+/// The time step recommended by RFC 6238 Section 5.2, in seconds.
+pub const DEFAULT_TIME_STEP_SECS: u64 = 30;
+
+/// A key for generating and verifying one-time passwords.
+#[derive(Clone)]
+pub struct Key {
+    key: hmac::Key,
+    digits: u32,
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm())
+            .field("digits", &self.digits)
+            .finish()
+    }
+}
+
+impl Key {
+    /// Constructs a key for codes of `digits` digits using HMAC with
+    /// `algorithm`. RFC 4226 uses HMAC-SHA1, and RFC 6238 also allows
+    /// HMAC-SHA256 and HMAC-SHA512.
+    ///
+    /// `key_value` is the shared secret, usually provisioned by encoding it
+    /// in a QR code for the user's authenticator app. Fails if `digits`
+    /// isn't between `MIN_DIGITS` and `MAX_DIGITS`.
+    pub fn new(
+        algorithm: hmac::Algorithm,
+        key_value: &[u8],
+        digits: u32,
+    ) -> Result<Self, error::Unspecified> {
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            key: hmac::Key::new(algorithm, key_value),
+            digits,
+        })
+    }
+
+    /// The HMAC algorithm for the key.
+    #[inline]
+    pub fn algorithm(&self) -> hmac::Algorithm {
+        self.key.algorithm()
+    }
+
+    /// The number of digits in the key's codes.
+    #[inline]
+    pub fn digits(&self) -> u32 {
+        self.digits
+    }
+}
+
+/// A one-time password, as ASCII decimal digits with leading zeros.
+#[derive(Clone, Copy)]
+pub struct Code {
+    digits: [u8; MAX_DIGITS as usize],
+    len: usize,
+}
+
+impl AsRef<[u8]> for Code {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+}
+
+impl core::fmt::Debug for Code {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.write_str("Code")
+    }
+}
+
+impl core::fmt::Display for Code {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        // The digits are always ASCII.
+        f.write_str(core::str::from_utf8(self.as_ref()).unwrap())
+    }
+}
+
+/// Generates the HOTP code for `counter`.
+pub fn hotp(key: &Key, counter: u64) -> Code {
+    let tag = hmac::sign(&key.key, &counter.to_be_bytes());
+    let tag = tag.as_ref();
+
+    // RFC 4226 Section 5.3: dynamic truncation. The low four bits of the
+    // last byte are the offset of the 31-bit value.
+    let offset = usize::from(tag[tag.len() - 1] & 0xf);
+    let mut value = u32::from_be_bytes([
+        tag[offset] & 0x7f,
+        tag[offset + 1],
+        tag[offset + 2],
+        tag[offset + 3],
+    ]);
+
+    let len = key.digits as usize;
+    let mut digits = [0u8; MAX_DIGITS as usize];
+    for d in digits[..len].iter_mut().rev() {
+        *d = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    Code { digits, len }
+}
+
+/// Verifies the HOTP code `code`, accepting the codes for `counter` through
+/// `counter + look_ahead`, in constant time.
+///
+/// Returns the counter `code` matched (the earliest one, if several did).
+/// RFC 4226 Section 7.2 requires that the stored counter then be advanced
+/// past it, so that each code is accepted at most once; the look-ahead
+/// window lets the server resynchronize with a client that generated codes
+/// that were never used.
+pub fn verify_hotp(
+    key: &Key,
+    counter: u64,
+    look_ahead: u32,
+    code: &[u8],
+) -> Result<u64, error::Unspecified> {
+    verify_window(key, counter, u64::from(look_ahead), code)
+}
+
+/// Generates the TOTP code for `unix_time`, in seconds since the Unix epoch,
+/// with time steps of `time_step_secs` seconds.
+pub fn totp(key: &Key, time_step_secs: NonZeroU64, unix_time: u64) -> Code {
+    hotp(key, unix_time / time_step_secs.get())
+}
+
+/// Verifies the TOTP code `code` at `unix_time`, accepting the codes for up
+/// to `skew` time steps before and after the current one, in constant time.
+///
+/// Returns the time step `code` matched (the earliest one, if several did).
+/// RFC 6238 Section 5.2 requires that a code be accepted at most once, so
+/// the caller should record the time step and reject codes for it or any
+/// earlier time step afterwards.
+pub fn verify_totp(
+    key: &Key,
+    time_step_secs: NonZeroU64,
+    unix_time: u64,
+    skew: u32,
+    code: &[u8],
+) -> Result<u64, error::Unspecified> {
+    let current = unix_time / time_step_secs.get();
+    let first = current.saturating_sub(u64::from(skew));
+    let window = (current - first) + u64::from(skew);
+    verify_window(key, first, window, code)
+}
+
+/// Checks `code` against the codes for `first` through `first + window`,
+/// all of them, so that the time taken doesn't reveal which one matched.
+fn verify_window(
+    key: &Key,
+    first: u64,
+    window: u64,
+    code: &[u8],
+) -> Result<u64, error::Unspecified> {
+    let last = first.saturating_add(window);
+    let mut matched = None;
+    for counter in first..=last {
+        let ok = constant_time::verify_slices_are_equal(hotp(key, counter).as_ref(), code).is_ok();
+        if ok && matched.is_none() {
+            matched = Some(counter);
+        }
+    }
+    matched.ok_or(error::Unspecified)
+}
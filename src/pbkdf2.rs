@@ -112,11 +112,14 @@
 //!     assert!(db.verify_password("alice", "@74d7]404j|W}6u").is_ok());
 //! }
 
-use crate::{constant_time, digest, error, hmac};
+use crate::{constant_time, digest, error, hmac, kdf, polyfill};
 use core::num::NonZeroU32;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 /// A PBKDF2 algorithm.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Algorithm(hmac::Algorithm);
 
 /// PBKDF2 using HMAC-SHA1.
@@ -131,6 +134,56 @@ pub static PBKDF2_HMAC_SHA384: Algorithm = Algorithm(hmac::HMAC_SHA384);
 /// PBKDF2 using HMAC-SHA512.
 pub static PBKDF2_HMAC_SHA512: Algorithm = Algorithm(hmac::HMAC_SHA512);
 
+/// PBKDF2 using HMAC-SHA512/256.
+pub static PBKDF2_HMAC_SHA512_256: Algorithm = Algorithm(hmac::HMAC_SHA512_256);
+
+/// PBKDF2 using HMAC-SHA3-256.
+pub static PBKDF2_HMAC_SHA3_256: Algorithm = Algorithm(hmac::HMAC_SHA3_256);
+
+/// PBKDF2 using HMAC-SHA3-384.
+pub static PBKDF2_HMAC_SHA3_384: Algorithm = Algorithm(hmac::HMAC_SHA3_384);
+
+/// PBKDF2 using HMAC-SHA3-512.
+pub static PBKDF2_HMAC_SHA3_512: Algorithm = Algorithm(hmac::HMAC_SHA3_512);
+
+/// A PBKDF2 algorithm and iteration count, for using PBKDF2 as a
+/// `kdf::Kdf`.
+///
+/// PBKDF2 has no input corresponding to the info, so deriving fails unless
+/// it is empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    algorithm: Algorithm,
+    iterations: NonZeroU32,
+}
+
+impl Params {
+    /// Constructs the parameters.
+    pub fn new(algorithm: Algorithm, iterations: NonZeroU32) -> Self {
+        Self {
+            algorithm,
+            iterations,
+        }
+    }
+}
+
+impl kdf::Kdf for Params {
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let output_len = polyfill::u64_from_usize(self.algorithm.0.digest_algorithm().output_len);
+        if !info.is_empty() || polyfill::u64_from_usize(out.len()) > output_len * 0xffff_ffff {
+            return Err(error::Unspecified);
+        }
+        derive(self.algorithm, self.iterations, salt, secret, out);
+        Ok(())
+    }
+}
+
 /// Fills `out` with the key derived using PBKDF2 with the given inputs.
 ///
 /// Do not use `derive` as part of verifying a secret; use `verify` instead, to
@@ -200,6 +253,66 @@ fn derive_block(secret: &hmac::Key, iterations: NonZeroU32, salt: &[u8], idx: u3
     }
 }
 
+/// Measures this machine and returns the number of iterations of `algorithm`
+/// that take about `target` to derive a single block of output.
+///
+/// Hard-coded iteration counts get cheaper to attack as hardware gets faster;
+/// calibrating when provisioning a deployment, and storing the result with
+/// each derived value as in the password database example above, keeps the
+/// cost of each guess roughly constant. The measurement takes about half of
+/// `target` and is only as good as the conditions it's made under, so the
+/// result should be checked against a fixed minimum before it's used.
+///
+/// ```
+/// use ring::pbkdf2;
+/// use std::{num::NonZeroU32, time::Duration};
+///
+/// const MIN_ITERATIONS: u32 = 1_000;
+///
+/// let iterations = pbkdf2::calibrate(pbkdf2::PBKDF2_HMAC_SHA256, Duration::from_millis(10));
+/// let iterations = NonZeroU32::new(iterations.get().max(MIN_ITERATIONS)).unwrap();
+/// # let _ = iterations;
+/// ```
+///
+/// The result is at least 1 and saturates at `u32::MAX`.
+#[cfg(feature = "std")]
+pub fn calibrate(algorithm: Algorithm, target: core::time::Duration) -> NonZeroU32 {
+    use std::time::{Duration, Instant};
+
+    const FIRST_SAMPLE: u32 = 1024;
+
+    let secret = hmac::Key::new(algorithm.0, b"calibration");
+    let mut out = [0u8; digest::MAX_OUTPUT_LEN];
+    let out = &mut out[..algorithm.0.digest_algorithm().output_len];
+
+    // Double the sample size until a sample takes at least a quarter of
+    // `target`, so that timer resolution and startup costs don't dominate.
+    let mut iterations = FIRST_SAMPLE;
+    let elapsed = loop {
+        let start = Instant::now();
+        derive_block(
+            &secret,
+            NonZeroU32::new(iterations).unwrap(),
+            b"salt",
+            1,
+            out,
+        );
+        let elapsed = start.elapsed();
+        if (elapsed >= target / 4 && elapsed > Duration::default()) || iterations > u32::MAX / 2 {
+            break elapsed;
+        }
+        iterations *= 2;
+    };
+
+    let estimate = f64::from(iterations) * target.as_secs_f64() / elapsed.as_secs_f64();
+    let estimate = if estimate >= f64::from(u32::MAX) {
+        u32::MAX
+    } else {
+        estimate as u32
+    };
+    NonZeroU32::new(estimate).unwrap_or_else(|| NonZeroU32::new(1).unwrap())
+}
+
 /// Verifies that a previously-derived (e.g., using `derive`) PBKDF2 value
 /// matches the PBKDF2 value derived from the other inputs.
 ///
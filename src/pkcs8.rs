@@ -51,7 +51,7 @@ impl Template {
         untrusted::Input::from(self.alg_id_value_())
     }
 
-    fn alg_id_value_(&self) -> &[u8] {
+    pub(crate) fn alg_id_value_(&self) -> &[u8] {
         &self.bytes[self.alg_id_range.start..self.alg_id_range.end]
     }
 
@@ -161,30 +161,42 @@ fn unwrap_key__<'a>(
 }
 
 /// A generated PKCS#8 document.
-pub struct Document {
-    bytes: [u8; ec::PKCS8_DOCUMENT_MAX_LEN],
-    len: usize,
+pub struct Document(DocumentBytes);
+
+#[allow(clippy::large_enum_variant, variant_size_differences)]
+enum DocumentBytes {
+    Fixed {
+        bytes: [u8; ec::PKCS8_DOCUMENT_MAX_LEN],
+        len: usize,
+    },
+    // RSA private keys are too large to be stored inline.
+    #[cfg(feature = "alloc")]
+    Boxed(alloc::boxed::Box<[u8]>),
 }
 
 impl AsRef<[u8]> for Document {
     #[inline]
     fn as_ref(&self) -> &[u8] {
-        &self.bytes[..self.len]
+        match &self.0 {
+            DocumentBytes::Fixed { bytes, len } => &bytes[..*len],
+            #[cfg(feature = "alloc")]
+            DocumentBytes::Boxed(bytes) => bytes,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Document {
+    pub(crate) fn from_boxed(bytes: alloc::boxed::Box<[u8]>) -> Self {
+        Self(DocumentBytes::Boxed(bytes))
     }
 }
 
 pub(crate) fn wrap_key(template: &Template, private_key: &[u8], public_key: &[u8]) -> Document {
-    let mut result = Document {
-        bytes: [0; ec::PKCS8_DOCUMENT_MAX_LEN],
-        len: template.bytes.len() + private_key.len() + public_key.len(),
-    };
-    wrap_key_(
-        template,
-        private_key,
-        public_key,
-        &mut result.bytes[..result.len],
-    );
-    result
+    let len = template.bytes.len() + private_key.len() + public_key.len();
+    let mut bytes = [0; ec::PKCS8_DOCUMENT_MAX_LEN];
+    wrap_key_(template, private_key, public_key, &mut bytes[..len]);
+    Document(DocumentBytes::Fixed { bytes, len })
 }
 
 /// Formats a private key "prefix||private_key||middle||public_key" where
@@ -15,6 +15,8 @@
 //! Polyfills for functionality that will (hopefully) be added to Rust's
 //! standard library soon.
 
+use core::mem::MaybeUninit;
+
 #[inline(always)]
 pub const fn u64_from_usize(x: usize) -> u64 {
     x as u64
@@ -24,6 +26,27 @@ pub fn usize_from_u32(x: u32) -> usize {
     x as usize
 }
 
+/// Initializes `dst` with a copy of `src`, which must be the same length,
+/// returning the initialized `dst`.
+///
+/// This is `MaybeUninit::write_slice`, which isn't stable yet.
+pub fn write_slice<'a>(dst: &'a mut [MaybeUninit<u8>], src: &[u8]) -> &'a mut [u8] {
+    assert_eq!(dst.len(), src.len());
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = MaybeUninit::new(*s);
+    }
+    // Every element of `dst` was just initialized.
+    unsafe { slice_assume_init_mut(dst) }
+}
+
+/// This is `MaybeUninit::slice_assume_init_mut`, which isn't stable yet.
+///
+/// The caller must ensure that every element of `slice` is initialized.
+pub unsafe fn slice_assume_init_mut(slice: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    // `MaybeUninit<u8>` has the same layout as `u8`.
+    &mut *(slice as *mut [MaybeUninit<u8>] as *mut [u8])
+}
+
 #[macro_use]
 mod chunks_fixed;
 
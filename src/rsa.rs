@@ -26,13 +26,15 @@ use crate::{
     limb,
 };
 
+pub(crate) mod encryption;
+pub(crate) mod keygen;
 pub(crate) mod padding;
 
 // Maximum RSA modulus size supported for signature verification (in bytes).
 const PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN: usize = bigint::MODULUS_MAX_LIMBS * limb::LIMB_BYTES;
 
 // Keep in sync with the documentation comment for `KeyPair`.
-const PRIVATE_KEY_PUBLIC_MODULUS_MAX_BITS: bits::BitLength = bits::BitLength::from_usize_bits(4096);
+const PRIVATE_KEY_PUBLIC_MODULUS_MAX_BITS: bits::BitLength = bits::BitLength::from_usize_bits(8192);
 
 /// Parameters for RSA verification.
 #[derive(Debug)]
@@ -76,6 +78,8 @@ unsafe impl bigint::PublicModulus for N {}
 
 pub mod public;
 
+pub mod raw;
+
 pub(crate) mod verification;
 
 pub(crate) mod signing;
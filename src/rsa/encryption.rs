@@ -0,0 +1,476 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! RSA encryption and decryption.
+
+use super::{
+    padding::mgf1, parse_public_key, public, signing::RsaKeyPair, PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN,
+};
+use crate::{arithmetic::bigint, bits, constant_time, cpu, digest, error, hmac, rand};
+use alloc::vec;
+use core::convert::TryFrom;
+
+/// An RSA public key used for encryption.
+#[derive(Debug)]
+pub struct PublicKey {
+    key: public::Key,
+}
+
+impl PublicKey {
+    /// Parses a DER-encoded `RSAPublicKey` as described in [RFC 8017
+    /// Appendix A.1.1], e.g. the output of `RsaKeyPair::public_key()`.
+    ///
+    /// The public modulus must be 2048-8192 bits.
+    ///
+    /// [RFC 8017 Appendix A.1.1]: https://tools.ietf.org/html/rfc8017#appendix-A.1.1
+    pub fn from_der(public_key: &[u8]) -> Result<Self, error::KeyRejected> {
+        let (n, e) = parse_public_key(untrusted::Input::from(public_key))
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        Self::from_modulus_and_exponent(
+            n.big_endian_without_leading_zero_as_input(),
+            e.big_endian_without_leading_zero_as_input(),
+        )
+    }
+
+    /// Constructs a public key from its modulus and exponent.
+    ///
+    /// The public modulus must be 2048-8192 bits.
+    pub fn from_components<B>(
+        components: &public::Components<B>,
+    ) -> Result<Self, error::KeyRejected>
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::from_modulus_and_exponent(
+            untrusted::Input::from(components.n.as_ref()),
+            untrusted::Input::from(components.e.as_ref()),
+        )
+    }
+
+    fn from_modulus_and_exponent(
+        n: untrusted::Input,
+        e: untrusted::Input,
+    ) -> Result<Self, error::KeyRejected> {
+        let _ = cpu::features();
+        let max_bits = bits::BitLength::from_usize_bytes(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)
+            .map_err(|error::Unspecified| error::KeyRejected::unexpected_error())?;
+        let key = public::Key::from_modulus_and_exponent(
+            n,
+            e,
+            bits::BitLength::from_usize_bits(2048),
+            max_bits,
+            public::Exponent::_3,
+        )?;
+        Ok(Self { key })
+    }
+
+    /// Returns the length in bytes of the public modulus.
+    ///
+    /// A ciphertext has the same length as the public modulus.
+    pub fn modulus_len(&self) -> usize {
+        self.key.n_bits.as_usize_bytes_rounded_up()
+    }
+
+    /// Encrypts `plaintext` using RSAES-OAEP as described in [RFC 8017
+    /// Section 7.1.1], writing the ciphertext into `ciphertext`.
+    ///
+    /// `ciphertext`'s length must be exactly `modulus_len()`. `plaintext` must
+    /// be no longer than `alg.max_plaintext_len(self.modulus_len())`. `label`
+    /// is usually empty; the same label must be used for decryption. `rng` is
+    /// used to generate the random seed.
+    ///
+    /// [RFC 8017 Section 7.1.1]: https://tools.ietf.org/html/rfc8017#section-7.1.1
+    pub fn encrypt_oaep(
+        &self,
+        alg: &'static OaepAlgorithm,
+        plaintext: &[u8],
+        label: &[u8],
+        rng: &dyn rand::SecureRandom,
+        ciphertext: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let k = self.modulus_len();
+        if ciphertext.len() != k || plaintext.len() > alg.max_plaintext_len(k) {
+            return Err(error::Unspecified);
+        }
+        let h_len = alg.digest_alg.output_len;
+
+        // Step 2: EME-OAEP encoding.
+        let (y, seed_and_db) = ciphertext.split_at_mut(1);
+        y[0] = 0;
+        let (seed, db) = seed_and_db.split_at_mut(h_len);
+
+        let l_hash = digest::digest(alg.digest_alg, label);
+        let (db_l_hash, ps_and_m) = db.split_at_mut(h_len);
+        db_l_hash.copy_from_slice(l_hash.as_ref());
+        let (ps, one_and_m) = ps_and_m.split_at_mut(ps_and_m.len() - plaintext.len() - 1);
+        ps.fill(0);
+        one_and_m[0] = 0x01;
+        one_and_m[1..].copy_from_slice(plaintext);
+
+        rng.fill(seed)?;
+
+        let mut db_mask = vec![0; db.len()];
+        mgf1(alg.digest_alg, seed, &mut db_mask);
+        xor_assign(db, &db_mask);
+
+        let mut seed_mask = [0; digest::MAX_OUTPUT_LEN];
+        let seed_mask = &mut seed_mask[..h_len];
+        mgf1(alg.digest_alg, db, seed_mask);
+        xor_assign(seed, seed_mask);
+
+        // Step 3: RSAEP.
+        self.rsaep(ciphertext)
+    }
+
+    /// Encrypts `plaintext` using RSAES-PKCS1-v1_5 as described in [RFC 8017
+    /// Section 7.2.1], writing the ciphertext into `ciphertext`.
+    ///
+    /// RSAES-PKCS1-v1_5 is only provided for interoperability with legacy
+    /// systems; use `encrypt_oaep()` whenever the recipient supports it.
+    ///
+    /// `ciphertext`'s length must be exactly `modulus_len()`. `plaintext` must
+    /// be no longer than `modulus_len() - 11` bytes.
+    ///
+    /// [RFC 8017 Section 7.2.1]: https://tools.ietf.org/html/rfc8017#section-7.2.1
+    pub fn encrypt_pkcs1_for_legacy_use_only(
+        &self,
+        plaintext: &[u8],
+        rng: &dyn rand::SecureRandom,
+        ciphertext: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let k = self.modulus_len();
+        if ciphertext.len() != k || plaintext.len() > k - PKCS1_MIN_PADDING_LEN {
+            return Err(error::Unspecified);
+        }
+
+        // Step 2: EME-PKCS1-v1_5 encoding.
+        let (ps, zero_and_m) = ciphertext.split_at_mut(k - plaintext.len() - 1);
+        let (prefix, ps) = ps.split_at_mut(2);
+        prefix.copy_from_slice(&[0x00, 0x02]);
+        rng.fill(ps)?;
+        for b in ps.iter_mut() {
+            while *b == 0 {
+                rng.fill(core::slice::from_mut(b))?;
+            }
+        }
+        zero_and_m[0] = 0x00;
+        zero_and_m[1..].copy_from_slice(plaintext);
+
+        // Step 3: RSAEP.
+        self.rsaep(ciphertext)
+    }
+
+    // RFC 8017 Section 5.1.1: RSAEP. `in_out` must be exactly as long as the
+    // public modulus; the input is replaced with the output.
+    fn rsaep(&self, in_out: &mut [u8]) -> Result<(), error::Unspecified> {
+        let m = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(&*in_out), &self.key.n)?;
+        let c = super::elem_exp_vartime(m, self.key.e, &self.key.n);
+        c.fill_be_bytes(in_out);
+        Ok(())
+    }
+}
+
+/// RSAES-OAEP parameters.
+#[derive(Debug)]
+pub struct OaepAlgorithm {
+    digest_alg: &'static digest::Algorithm,
+}
+
+impl OaepAlgorithm {
+    /// The digest algorithm, which is used both for hashing the label and
+    /// for MGF1.
+    #[inline]
+    pub fn digest_algorithm(&self) -> &'static digest::Algorithm {
+        self.digest_alg
+    }
+
+    /// The maximum length of a plaintext that can be encrypted with a key
+    /// whose public modulus is `modulus_len` bytes long.
+    pub fn max_plaintext_len(&self, modulus_len: usize) -> usize {
+        modulus_len.saturating_sub((2 * self.digest_alg.output_len) + 2)
+    }
+}
+
+/// RSAES-OAEP with SHA-256 and MGF1 with SHA-256, e.g. JOSE `RSA-OAEP-256`.
+pub static RSA_OAEP_SHA256: OaepAlgorithm = OaepAlgorithm {
+    digest_alg: &digest::SHA256,
+};
+
+/// RSAES-OAEP with SHA-384 and MGF1 with SHA-384.
+pub static RSA_OAEP_SHA384: OaepAlgorithm = OaepAlgorithm {
+    digest_alg: &digest::SHA384,
+};
+
+/// RSAES-OAEP with SHA-512 and MGF1 with SHA-512.
+pub static RSA_OAEP_SHA512: OaepAlgorithm = OaepAlgorithm {
+    digest_alg: &digest::SHA512,
+};
+
+impl RsaKeyPair {
+    /// Decrypts `ciphertext` using RSAES-OAEP as described in [RFC 8017
+    /// Section 7.1.2], writing the plaintext into the beginning of `plaintext`
+    /// and returning it.
+    ///
+    /// `ciphertext`'s length must be exactly `public_modulus_len()`.
+    /// `plaintext` must be at least `public_modulus_len() - 2 * hLen - 2`
+    /// bytes long, the longest message that can be encrypted with the key,
+    /// where hLen is the output length of `alg`'s digest algorithm; this is
+    /// checked before anything is decrypted. `label` must be the label that
+    /// was used for encryption.
+    ///
+    /// All failures are reported identically, and the decoding is done in
+    /// constant time, to avoid disclosing which check failed as in Manger's
+    /// attack.
+    ///
+    /// [RFC 8017 Section 7.1.2]: https://tools.ietf.org/html/rfc8017#section-7.1.2
+    pub fn decrypt_oaep<'o>(
+        &self,
+        alg: &'static OaepAlgorithm,
+        ciphertext: &[u8],
+        label: &[u8],
+        plaintext: &'o mut [u8],
+    ) -> Result<&'o mut [u8], error::Unspecified> {
+        let k = self.public_modulus_len();
+        let h_len = alg.digest_alg.output_len;
+        // Step 1.
+        if ciphertext.len() != k || k < (2 * h_len) + 2 {
+            return Err(error::Unspecified);
+        }
+        // Checking the length of the output buffer now, instead of once the
+        // length of the message is known, keeps its length from being
+        // revealed only for ciphertexts with valid padding.
+        if plaintext.len() < k - (2 * h_len) - 2 {
+            return Err(error::Unspecified);
+        }
+
+        // Step 2: RSADP.
+        let mut em = vec![0; k];
+        em.copy_from_slice(ciphertext);
+        self.rsadp(&mut em)?;
+
+        // Step 3: EME-OAEP decoding.
+        let (y, seed_and_db) = em.split_at_mut(1);
+        let (seed, db) = seed_and_db.split_at_mut(h_len);
+
+        let mut seed_mask = [0; digest::MAX_OUTPUT_LEN];
+        let seed_mask = &mut seed_mask[..h_len];
+        mgf1(alg.digest_alg, db, seed_mask);
+        xor_assign(seed, seed_mask);
+
+        let mut db_mask = vec![0; db.len()];
+        mgf1(alg.digest_alg, seed, &mut db_mask);
+        xor_assign(db, &db_mask);
+
+        let l_hash = digest::digest(alg.digest_alg, label);
+        let (db_l_hash, ps_and_m) = db.split_at(h_len);
+
+        let mut bad = y[0];
+        bad |=
+            u8::from(constant_time::verify_slices_are_equal(db_l_hash, l_hash.as_ref()).is_err());
+
+        // Find the 0x01 that separates the zero padding from the message,
+        // without branching on the padding's contents.
+        let mut looking_for_one = 0xffu8;
+        let mut one_index = 0usize;
+        for (i, &b) in ps_and_m.iter().enumerate() {
+            let is_zero = is_zero_mask(b);
+            let is_one = is_zero_mask(b ^ 0x01);
+            let found = looking_for_one & is_one;
+            one_index = select_usize(found, i, one_index);
+            bad |= looking_for_one & !is_zero & !is_one;
+            looking_for_one &= !is_one;
+        }
+        bad |= looking_for_one;
+
+        if bad != 0 {
+            return Err(error::Unspecified);
+        }
+
+        // Step 4.
+        let m = &ps_and_m[(one_index + 1)..];
+        let plaintext = &mut plaintext[..m.len()];
+        plaintext.copy_from_slice(m);
+        Ok(plaintext)
+    }
+}
+
+impl RsaKeyPair {
+    /// Decrypts `ciphertext` using RSAES-PKCS1-v1_5 as described in [RFC 8017
+    /// Section 7.2.2], writing the plaintext into the beginning of
+    /// `plaintext` and returning it.
+    ///
+    /// RSAES-PKCS1-v1_5 is only provided for interoperability with legacy
+    /// systems; use `decrypt_oaep()` whenever the sender supports it.
+    ///
+    /// `ciphertext`'s length must be exactly `public_modulus_len()` and
+    /// `plaintext` must be at least `public_modulus_len() - 11` bytes long.
+    /// A ciphertext that is zero or isn't less than the public modulus is
+    /// rejected with an error.
+    ///
+    /// To resist Bleichenbacher's attack, an invalid padding is not reported
+    /// as an error. Instead, the padding is checked in constant time and, if
+    /// it is invalid, a synthetic plaintext is returned. The synthetic
+    /// plaintext is derived deterministically, using the implicit rejection
+    /// method of draft-irtf-cfrg-rsa-guidance, from the private key and the
+    /// ciphertext, so an attacker can't distinguish it from a real one by
+    /// decrypting the same ciphertext repeatedly. The protocol must be
+    /// designed so that a wrong plaintext (e.g. a wrong content encryption
+    /// key) is detected later without revealing why; for example, a
+    /// decrypted key for an AEAD will fail to open the message. The
+    /// derivation is compatible with OpenSSL's.
+    ///
+    /// [RFC 8017 Section 7.2.2]: https://tools.ietf.org/html/rfc8017#section-7.2.2
+    pub fn decrypt_pkcs1_for_legacy_use_only<'o>(
+        &self,
+        ciphertext: &[u8],
+        plaintext: &'o mut [u8],
+    ) -> Result<&'o mut [u8], error::Unspecified> {
+        let k = self.public_modulus_len();
+        // Step 1.
+        if ciphertext.len() != k || plaintext.len() < k - PKCS1_MIN_PADDING_LEN {
+            return Err(error::Unspecified);
+        }
+
+        // Step 2: RSADP.
+        let mut em = vec![0; k];
+        em.copy_from_slice(ciphertext);
+        self.rsadp(&mut em)?;
+
+        // Prepare the synthetic plaintext before looking at the padding.
+        let kdk = hmac::sign(&self.implicit_rejection_seed.key(), ciphertext);
+        let kdk = hmac::Key::new(hmac::HMAC_SHA256, kdk.as_ref());
+        let mut synthetic = vec![0; k];
+        implicit_rejection_prf(&kdk, b"message", &mut synthetic);
+
+        // Choose the length of the synthetic plaintext from 128 candidates
+        // without a division, which wouldn't be constant time, and without
+        // bias.
+        let mut candidate_lens = [0; 128 * 2];
+        implicit_rejection_prf(&kdk, b"length", &mut candidate_lens);
+        let max_len = k - 2 - 8;
+        let mut len_mask = max_len;
+        len_mask |= len_mask >> 1;
+        len_mask |= len_mask >> 2;
+        len_mask |= len_mask >> 4;
+        len_mask |= len_mask >> 8;
+        let synthetic_len = candidate_lens
+            .chunks_exact(2)
+            .fold(0, |synthetic_len, candidate| {
+                let candidate = usize::from(u16::from_be_bytes([candidate[0], candidate[1]]));
+                let candidate = candidate & len_mask;
+                select_usize(less_than_mask(candidate, max_len), candidate, synthetic_len)
+            });
+
+        // Step 3: EME-PKCS1-v1_5 decoding.
+        let mut good = is_zero_mask(em[0]) & is_zero_mask(em[1] ^ 0x02);
+        let mut found_zero = 0u8;
+        let mut zero_index = 0usize;
+        for (i, &b) in em.iter().enumerate().skip(2) {
+            let is_zero = is_zero_mask(b);
+            zero_index = select_usize(!found_zero & is_zero, i, zero_index);
+            found_zero |= is_zero;
+        }
+        // The padding string must be at least 8 bytes long. If no zero byte
+        // was found then `zero_index` is zero, which fails this check too.
+        good &= !less_than_mask(zero_index, 2 + 8);
+
+        // Step 4. Both the decoded and synthetic plaintexts are read
+        // regardless of which is returned. Since the length of the synthetic
+        // plaintext is independent of the padding, the length of the result
+        // doesn't reveal whether the padding was valid.
+        let m_index = select_usize(good, zero_index + 1, k - synthetic_len);
+        let plaintext = &mut plaintext[..(k - m_index)];
+        for (out, (&m, &synthetic)) in plaintext
+            .iter_mut()
+            .zip(em[m_index..].iter().zip(&synthetic[m_index..]))
+        {
+            *out = (m & good) | (synthetic & !good);
+        }
+        Ok(plaintext)
+    }
+}
+
+// RFC 8017 Section 7.2.1: "mLen <= k - 11".
+const PKCS1_MIN_PADDING_LEN: usize = 11;
+
+// SHA-256(I2OSP(d, k)), the key for deriving per-ciphertext implicit
+// rejection keys, as in draft-irtf-cfrg-rsa-guidance. It is kept instead of a
+// copy of `d` and is overwritten with zeros when it is dropped.
+pub(super) struct ImplicitRejectionSeed([u8; digest::SHA256_OUTPUT_LEN]);
+
+impl ImplicitRejectionSeed {
+    // `d` is the big-endian encoding of the private exponent, which must be
+    // less than the `n_bits`-bit public modulus.
+    pub(super) fn new(d: &[u8], n_bits: bits::BitLength) -> Self {
+        let k = n_bits.as_usize_bytes_rounded_up();
+        let mut ctx = digest::Context::new(&digest::SHA256);
+        for _ in d.len()..k {
+            ctx.update(&[0]);
+        }
+        ctx.update(d);
+        let mut seed = [0; digest::SHA256_OUTPUT_LEN];
+        seed.copy_from_slice(ctx.finish().as_ref());
+        Self(seed)
+    }
+
+    fn key(&self) -> hmac::Key {
+        hmac::Key::new(hmac::HMAC_SHA256, &self.0)
+    }
+}
+
+impl Drop for ImplicitRejectionSeed {
+    fn drop(&mut self) {
+        for b in self.0.iter_mut() {
+            // Volatile so that the compiler can't elide the dead stores.
+            unsafe { core::ptr::write_volatile(b, 0) };
+        }
+    }
+}
+
+// The implicit rejection PRF of draft-irtf-cfrg-rsa-guidance, filling `out`.
+fn implicit_rejection_prf(kdk: &hmac::Key, label: &[u8], out: &mut [u8]) {
+    // The length will always fit in a `u16` because the modulus is limited
+    // to 8192 bits.
+    let bit_len = u16::try_from(out.len() * 8).unwrap();
+    for (i, chunk) in out.chunks_mut(digest::SHA256_OUTPUT_LEN).enumerate() {
+        let mut ctx = hmac::Context::with_key(kdk);
+        ctx.update(&u16::try_from(i).unwrap().to_be_bytes());
+        ctx.update(label);
+        ctx.update(&bit_len.to_be_bytes());
+        let tag = ctx.sign();
+        let chunk_len = chunk.len();
+        chunk.copy_from_slice(&tag.as_ref()[..chunk_len]);
+    }
+}
+
+fn xor_assign(a: &mut [u8], b: &[u8]) {
+    a.iter_mut().zip(b).for_each(|(a, b)| *a ^= b);
+}
+
+// Returns 0xff if `b` is zero and 0x00 otherwise.
+pub(super) fn is_zero_mask(b: u8) -> u8 {
+    (u16::from(b).wrapping_sub(1) >> 8) as u8
+}
+
+// Returns `a` if `mask` is 0xff and `b` if `mask` is 0x00.
+fn select_usize(mask: u8, a: usize, b: usize) -> usize {
+    let mask = 0usize.wrapping_sub(usize::from(mask & 1));
+    (a & mask) | (b & !mask)
+}
+
+// Returns 0xff if `a < b` and 0x00 otherwise. `a` and `b` must be less than
+// `usize::MAX / 2`.
+pub(super) fn less_than_mask(a: usize, b: usize) -> u8 {
+    0u8.wrapping_sub((a.wrapping_sub(b) >> (usize::BITS - 1)) as u8)
+}
@@ -0,0 +1,729 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! RSA key pair generation.
+//!
+//! The primes are generated as described in [FIPS 186-5] Appendix A.1.3,
+//! "Generation of Random Primes that are Probably Prime", and the private
+//! exponent as described in Appendix A.1.1.
+//!
+//! Everything that is computed from a candidate that is accepted is computed
+//! in constant time: `bigint` does the modular arithmetic and the fixed-width
+//! helpers at the end of this module do the rest. Only the work done on the
+//! candidates that are rejected varies.
+//!
+//! [FIPS 186-5]: https://doi.org/10.6028/NIST.FIPS.186-5
+
+use super::encryption::{is_zero_mask, less_than_mask};
+use crate::{
+    arithmetic::{bigint, montgomery::R},
+    error,
+    io::{self, der, der_writer},
+    rand,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The size of an RSA key pair to generate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RsaKeySize {
+    /// A 2048-bit public modulus.
+    Rsa2048,
+
+    /// A 3072-bit public modulus.
+    Rsa3072,
+
+    /// A 4096-bit public modulus.
+    Rsa4096,
+
+    /// An 8192-bit public modulus.
+    Rsa8192,
+}
+
+impl RsaKeySize {
+    /// The length of the public modulus, in bits.
+    pub fn modulus_len_bits(self) -> usize {
+        match self {
+            Self::Rsa2048 => 2048,
+            Self::Rsa3072 => 3072,
+            Self::Rsa4096 => 4096,
+            Self::Rsa8192 => 8192,
+        }
+    }
+
+    /// The key size with a `bits`-bit public modulus, if it is supported.
+    pub fn from_modulus_len_bits(bits: usize) -> Option<Self> {
+        match bits {
+            2048 => Some(Self::Rsa2048),
+            3072 => Some(Self::Rsa3072),
+            4096 => Some(Self::Rsa4096),
+            8192 => Some(Self::Rsa8192),
+            _ => None,
+        }
+    }
+
+    // The number of rounds of Miller-Rabin testing that FIPS 186-5 Table B.1
+    // requires for each prime. The table stops at 4096 bits; larger primes
+    // need no more rounds.
+    fn miller_rabin_rounds(self) -> usize {
+        match self {
+            Self::Rsa2048 => 5,
+            Self::Rsa3072 | Self::Rsa4096 | Self::Rsa8192 => 4,
+        }
+    }
+}
+
+/// The public exponent that `RsaKeyPair::generate_pkcs8()` is normally used
+/// with, 65537.
+pub const RSA_DEFAULT_PUBLIC_EXPONENT: u64 = 65537;
+
+/// Generates a key pair and returns it as a DER-encoded `RSAPrivateKey`.
+pub(super) fn generate(
+    key_size: RsaKeySize,
+    public_exponent: u64,
+    rng: &dyn rand::SecureRandom,
+) -> Result<Box<[u8]>, error::Unspecified> {
+    // FIPS 186-5 Appendix A.1.1 requires 2**16 < e < 2**256; *ring* also
+    // limits the public exponent to 33 bits.
+    if !(RSA_DEFAULT_PUBLIC_EXPONENT..(1 << 33)).contains(&public_exponent)
+        || public_exponent & 1 == 0
+    {
+        return Err(error::Unspecified);
+    }
+    let e = PublicExponent::new(public_exponent);
+    let n_len = key_size.modulus_len_bits() / 8;
+    let prime_len = n_len / 2;
+
+    // `n` and `d` are less than 2**nlen - 1, so they are computed exactly by
+    // multiplying and adding modulo 2**nlen - 1.
+    let wide = vec![0xff; n_len];
+    let (wide, _) =
+        bigint::Modulus::<Wide>::from_be_bytes_with_bit_length(untrusted::Input::from(&wide))
+            .map_err(|_: error::KeyRejected| error::Unspecified)?;
+
+    // The odd primes less than 2000, for trial division.
+    let small_primes: Vec<u64> = (3..2000)
+        .step_by(2)
+        .filter(|&f| {
+            (3..f)
+                .step_by(2)
+                .take_while(|g| g * g <= f)
+                .all(|g| f % g != 0)
+        })
+        .collect();
+
+    loop {
+        let p = generate_prime(key_size, &e, None, &small_primes, rng)?;
+        let q = generate_prime(key_size, &e, Some(&p), &small_primes, rng)?;
+
+        // `RsaKeyPair` requires `p > q`.
+        let (p, q) = {
+            let mut difference = vec![0; prime_len];
+            let p_is_smaller = sub(&mut difference, &p, &q);
+            let (mut larger, mut smaller) = (p.clone(), q.clone());
+            select(p_is_smaller, &mut larger, &q);
+            select(p_is_smaller, &mut smaller, &p);
+            (larger, smaller)
+        };
+
+        // λ(n) = LCM(p - 1, q - 1) = (p - 1) * (q - 1) / g.
+        let g = gcd(minus_one(&p), minus_one(&q));
+
+        // d = e**-1 (mod λ(n)), so d * e = 1 + k * λ(n) where
+        // k = -(λ(n)**-1) (mod e). Since `e` is coprime to `p - 1` and
+        // `q - 1`, it is coprime to `g` and λ(n).
+        let lambda_mod_e = e.mul(
+            e.mul(
+                rem_small(&minus_one(&p), e.value),
+                rem_small(&minus_one(&q), e.value),
+            ),
+            e.inverse(rem_small(&g, e.value)),
+        );
+        let k = e.value - e.inverse(lambda_mod_e);
+
+        let p_factor = Factor::<P>::new(&p, &q, &g, k, &e)?;
+        let q_factor = Factor::<Q>::new(&q, &p, &g, k, &e)?;
+        let p_modulus = &p_factor.modulus;
+
+        let q_inv = bigint::elem_inverse_consttime(to_mont(&q, p_modulus)?, p_modulus)?;
+        let q_inv = bigint::elem_mul(p_modulus.oneRR().as_ref(), q_inv, p_modulus);
+
+        // d = (d mod q) + q * (((d mod p) - (d mod q)) * qInv (mod p)), by
+        // the Chinese Remainder Theorem.
+        let d_mod_q = to_be_bytes(&q_factor.d, prime_len);
+        let h = bigint::elem_sub(p_factor.d, &to_elem(&d_mod_q, p_modulus)?, p_modulus);
+        let h = to_be_bytes(&bigint::elem_mul(&q_inv, h, p_modulus), prime_len);
+        let d = bigint::elem_add(
+            bigint::elem_mul(&to_mont(&q, &wide)?, to_elem(&h, &wide)?, &wide),
+            to_elem(&d_mod_q, &wide)?,
+            &wide,
+        );
+        let d = to_be_bytes(&d, n_len);
+
+        // FIPS 186-5 Appendix A.1.1 Step 3.b: If d <= 2**(nlen/2), generate
+        // new primes. This rejects d < 2**(nlen/2 + 1), which is slightly
+        // stricter.
+        let (d_high_last, d_high) = d[..(n_len - prime_len)].split_last().unwrap();
+        let d_high = d_high.iter().fold(d_high_last & !1, |acc, &b| acc | b);
+        if is_zero_mask(d_high) != 0 {
+            continue;
+        }
+
+        let n = bigint::elem_mul(&to_mont(&p, &wide)?, to_elem(&q, &wide)?, &wide);
+        let values = [
+            to_be_bytes(&n, n_len),
+            Vec::from(&e.value.to_be_bytes()[..]),
+            d,
+            p,
+            q,
+            to_be_bytes(&p_factor.d_crt, prime_len),
+            to_be_bytes(&q_factor.d_crt, prime_len),
+            to_be_bytes(&q_inv.into_unencoded(p_modulus), prime_len),
+        ];
+
+        // The lengths of the values are revealed by their encodings anyway.
+        let values = values
+            .iter()
+            .map(|value| {
+                let leading_zeros = value.iter().take_while(|&&b| b == 0).count();
+                io::Positive::from_be_bytes(untrusted::Input::from(&value[leading_zeros..]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Integer, |output| {
+                output.write_byte(0); // Version 0, two-prime.
+            });
+            for value in &values {
+                der_writer::write_positive_integer(output, value);
+            }
+        }));
+    }
+}
+
+/// Generates a prime for a `key_size` key with public exponent `e`, as
+/// described in FIPS 186-5 Appendix A.1.3, and returns it as a big-endian
+/// integer of exactly `key_size.modulus_len_bits() / 16` bytes. When generating
+/// `q`, `p` is the already-generated other prime.
+fn generate_prime(
+    key_size: RsaKeySize,
+    e: &PublicExponent,
+    p: Option<&[u8]>,
+    small_primes: &[u64],
+    rng: &dyn rand::SecureRandom,
+) -> Result<Vec<u8>, error::Unspecified> {
+    let bits = key_size.modulus_len_bits() / 2;
+
+    let mut w = vec![0u8; bits / 8];
+    // Step 4.7 (Step 5.8 for `q`) limits the number of candidates.
+    for _ in 0..(5 * bits) {
+        loop {
+            // Steps 4.2 and 4.3 (Steps 5.2 and 5.3 for `q`).
+            rng.fill(&mut w)?;
+            *w.last_mut().unwrap() |= 1;
+
+            // Step 4.4 (Step 5.4 for `q`): w >= √2 * 2**(bits - 1). Comparing
+            // the top 64 bits of `w` to ⌈√2 * 2**63⌉ is slightly stricter.
+            let mut top = [0u8; 8];
+            top.copy_from_slice(&w[..8]);
+            if u64::from_be_bytes(top) < 0xb504_f333_f9de_6485 {
+                continue;
+            }
+
+            // Step 5.5: |p - q| must be larger than 2**(bits - 100). That is
+            // the case when the top 100 bits of `p` and `q` differ by at least
+            // two; rejecting the rest is slightly stricter.
+            if let Some(p) = p {
+                let (p_top, w_top) = (top_100_bits(p), top_100_bits(&w));
+                if (p_top.wrapping_sub(w_top) <= 1) | (w_top.wrapping_sub(p_top) <= 1) {
+                    continue;
+                }
+            }
+            break;
+        }
+
+        // Step 4.5 (Step 5.6 for `q`): GCD(w - 1, e) must be 1, i.e. no prime
+        // factor of `e` may divide `w - 1`.
+        let w_minus_1 = minus_one(&w);
+        if e.prime_factors
+            .iter()
+            .any(|&f| rem_small(&w_minus_1, f) == 0)
+        {
+            continue;
+        }
+
+        // Trial division by small primes rejects most composite candidates
+        // more quickly than the Miller-Rabin test does.
+        if small_primes.iter().any(|&f| rem_small(&w, f) == 0) {
+            continue;
+        }
+
+        if is_probably_prime(&w, key_size.miller_rabin_rounds(), rng)? {
+            return Ok(w);
+        }
+    }
+    Err(error::Unspecified)
+}
+
+// Returns the top 100 bits of `a`, which must be at least 13 bytes long.
+fn top_100_bits(a: &[u8]) -> u128 {
+    let mut top = [0u8; 16];
+    top[3..].copy_from_slice(&a[..13]);
+    u128::from_be_bytes(top) >> 4
+}
+
+// Type-level representation of a candidate prime.
+enum W {}
+
+// Type-level representations of the generated primes.
+enum P {}
+unsafe impl bigint::Prime for P {}
+enum Q {}
+unsafe impl bigint::Prime for Q {}
+
+// Type-level representation of 2**nlen - 1.
+enum Wide {}
+
+/// The Miller-Rabin probabilistic primality test of FIPS 186-5 Appendix
+/// B.3.1, for a big-endian, odd `w` that is larger than 3.
+///
+/// The test takes the same time for every `w` that passes it, so that the
+/// primes that are generated aren't revealed; it returns early only when `w`
+/// is known to be composite.
+fn is_probably_prime(
+    w: &[u8],
+    rounds: usize,
+    rng: &dyn rand::SecureRandom,
+) -> Result<bool, error::Unspecified> {
+    let (w_modulus, _) =
+        bigint::Modulus::<W>::from_be_bytes_with_bit_length(untrusted::Input::from(w))
+            .map_err(|_: error::KeyRejected| error::Unspecified)?;
+    let bits = w.len() * 8;
+
+    // Steps 1 and 2: w - 1 == 2**a * m where m is odd.
+    let w_minus_1 = minus_one(w);
+    let a = trailing_zeros(&w_minus_1);
+    let mut m = w_minus_1.clone();
+    for i in 0..bits {
+        shr1_masked(&mut m, less_than_mask(i, a));
+    }
+    let m = bigint::PrivateExponent::from_be_bytes_padded(untrusted::Input::from(&m), &w_modulus)?;
+
+    let one = to_mont(&[1], &w_modulus)?;
+    let minus_one = to_mont(&w_minus_1, &w_modulus)?;
+    let is = |a: &bigint::Elem<W, R>, b: &bigint::Elem<W, R>| {
+        bigint::elem_verify_equal_consttime(a, b).is_ok()
+    };
+
+    let mut b_bytes = vec![0u8; 2 * w.len()];
+    for _ in 0..rounds {
+        // Steps 4.1 and 4.2: 1 < b < w - 1. Reducing twice as many random
+        // bits as `w` has, instead of rejecting the values that are too
+        // large, makes `b` (almost exactly) uniformly distributed without the
+        // number of attempts depending on `w`.
+        let b = loop {
+            rng.fill(&mut b_bytes)?;
+            let b = to_mont(&b_bytes, &w_modulus)?;
+            if !(b.is_zero() | is(&b, &one) | is(&b, &minus_one)) {
+                break b;
+            }
+        };
+
+        // Step 4.3.
+        let z = bigint::elem_exp_consttime(b, &m, &w_modulus)?;
+        let mut z = bigint::elem_mul(w_modulus.oneRR().as_ref(), z, &w_modulus);
+
+        // Step 4.4.
+        let mut is_possibly_prime = is(&z, &one) | is(&z, &minus_one);
+
+        // Step 4.5. To avoid revealing `a`, the loop always continues for as
+        // long as it could for any `a`, ignoring the iterations after the
+        // first `a - 1`.
+        for j in 1..bits {
+            let done = j >= a;
+            if done & !is_possibly_prime {
+                return Ok(false);
+            }
+            z = bigint::elem_squared(z, &w_modulus.as_partial());
+            is_possibly_prime |= !done & is(&z, &minus_one);
+            // 1 has no square roots modulo a prime other than 1 and -1.
+            if is(&z, &one) & !is_possibly_prime {
+                return Ok(false);
+            }
+        }
+
+        // Step 4.6.
+        if !is_possibly_prime {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// The components of the private key that are computed modulo one of the
+// primes.
+struct Factor<M> {
+    modulus: bigint::Modulus<M>,
+
+    // e**-1 (mod x - 1), i.e. dP or dQ, where `x` is the prime.
+    d_crt: bigint::Elem<M>,
+
+    // d (mod x).
+    d: bigint::Elem<M>,
+}
+
+impl<M: bigint::Prime> Factor<M> {
+    // `x` is the prime and `y` the other prime. `g` is GCD(x - 1, y - 1) and
+    // `k` is such that d * e = 1 + k * λ(n).
+    fn new(
+        x: &[u8],
+        y: &[u8],
+        g: &[u8],
+        k: u64,
+        e: &PublicExponent,
+    ) -> Result<Self, error::Unspecified> {
+        let (m, _) = bigint::Modulus::<M>::from_be_bytes_with_bit_length(untrusted::Input::from(x))
+            .map_err(|_: error::KeyRejected| error::Unspecified)?;
+        let one = to_elem(&[1], &m)?;
+        let e_inv = bigint::elem_inverse_consttime(to_mont(&e.value.to_be_bytes(), &m)?, &m)?;
+        let e_inv = bigint::elem_mul(m.oneRR().as_ref(), e_inv, &m);
+
+        // dX * e = 1 + k_x * (x - 1) where k_x = -((x - 1)**-1) (mod e), so
+        // dX == (1 - k_x) * e**-1 (mod x). Since dX < x, that is dX itself.
+        let k_x = e.value - e.inverse(rem_small(&minus_one(x), e.value));
+        let d_crt = bigint::elem_sub(one.clone(), &to_elem(&k_x.to_be_bytes(), &m)?, &m);
+        let d_crt = bigint::elem_mul(&e_inv, d_crt, &m);
+
+        // λ(n) == (x - 1) * (y - 1) * g**-1 == (1 - y) * g**-1 (mod x).
+        let lambda = bigint::elem_sub(to_mont(&[1], &m)?, &to_mont(y, &m)?, &m);
+        let g_inv = bigint::elem_inverse_consttime(to_mont(g, &m)?, &m)?;
+        let lambda = bigint::elem_mul(&lambda, g_inv, &m);
+
+        // d == (1 + k * λ(n)) * e**-1 (mod x).
+        let d = bigint::elem_mul(&to_mont(&k.to_be_bytes(), &m)?, lambda, &m);
+        let d = bigint::elem_mul(&e_inv, bigint::elem_add(one, d, &m), &m);
+
+        Ok(Self {
+            modulus: m,
+            d_crt,
+            d,
+        })
+    }
+}
+
+/// Returns the big-endian `value` reduced modulo `m`.
+fn to_elem<M>(value: &[u8], m: &bigint::Modulus<M>) -> Result<bigint::Elem<M>, error::Unspecified> {
+    bigint::Elem::from_be_bytes_reduced(untrusted::Input::from(value), m)
+}
+
+/// Returns the big-endian `value` reduced modulo `m`, Montgomery-encoded.
+fn to_mont<M>(
+    value: &[u8],
+    m: &bigint::Modulus<M>,
+) -> Result<bigint::Elem<M, R>, error::Unspecified> {
+    Ok(bigint::elem_mul(m.oneRR().as_ref(), to_elem(value, m)?, m))
+}
+
+fn to_be_bytes<M>(a: &bigint::Elem<M>, len: usize) -> Vec<u8> {
+    let mut r = vec![0; len];
+    a.fill_be_bytes(&mut r);
+    r
+}
+
+/// The public exponent, and what is needed to do arithmetic modulo it in
+/// constant time.
+struct PublicExponent {
+    value: u64,
+
+    // The distinct prime factors of `value`.
+    prime_factors: Vec<u64>,
+
+    // Euler's totient of `value`, for computing inverses.
+    totient: u64,
+}
+
+impl PublicExponent {
+    // Factors the odd `value`. This isn't constant-time, but `value` is
+    // public.
+    fn new(value: u64) -> Self {
+        let mut prime_factors = Vec::new();
+        let mut totient = value;
+        let mut remaining = value;
+        let mut f = 3;
+        while f * f <= remaining {
+            let mut is_factor = false;
+            loop {
+                let (quotient, remainder) = (remaining / f, remaining % f);
+                if remainder != 0 {
+                    break;
+                }
+                remaining = quotient;
+                is_factor = true;
+            }
+            if is_factor {
+                prime_factors.push(f);
+                totient = totient / f * (f - 1);
+            }
+            f += 2;
+        }
+        if remaining > 1 {
+            prime_factors.push(remaining);
+            totient = totient / remaining * (remaining - 1);
+        }
+        Self {
+            value,
+            prime_factors,
+            totient,
+        }
+    }
+
+    /// Returns `a * b (mod e)`.
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        rem_small(&(u128::from(a) * u128::from(b)).to_be_bytes(), self.value)
+    }
+
+    /// Returns `a**-1 (mod e)` for `a` coprime to `e`, using Euler's theorem.
+    fn inverse(&self, a: u64) -> u64 {
+        let exponent = self.totient - 1;
+        (0..u64::BITS).rev().fold(1, |r, i| {
+            let r = self.mul(r, r);
+            if (exponent >> i) & 1 == 1 {
+                self.mul(r, a)
+            } else {
+                r
+            }
+        })
+    }
+}
+
+// Constant-time arithmetic on big-endian integers, for what key generation
+// needs that `bigint` doesn't provide because there is no odd modulus, e.g.
+// GCD(p - 1, q - 1). The widths of the integers are public. Masks are 0xff
+// for true and 0x00 for false.
+
+/// Returns `a (mod d)` for `1 < d < 2**33`. The length of `a` must be even.
+fn rem_small(a: &[u8], d: u64) -> u64 {
+    debug_assert_eq!(a.len() % 2, 0);
+    let d = u128::from(d);
+    // Barrett reduction, sixteen bits at a time. `reciprocal` is less than
+    // 2**64 / d by less than one, and `v < 2**64`, so the estimated quotient
+    // is at most one less than the quotient.
+    let reciprocal = (1u128 << 64) / d;
+    let r = a.chunks_exact(2).fold(0u128, |r, digit| {
+        let v = (r << 16) | u128::from(u16::from_be_bytes([digit[0], digit[1]]));
+        let r = v - ((v * reciprocal) >> 64) * d;
+        let (reduced, borrow) = r.overflowing_sub(d);
+        let borrow = 0u128.wrapping_sub(u128::from(borrow));
+        (r & borrow) | (reduced & !borrow)
+    });
+    r as u64
+}
+
+/// Returns `a - 1` for an odd `a`.
+fn minus_one(a: &[u8]) -> Vec<u8> {
+    let mut r = Vec::from(a);
+    *r.last_mut().unwrap() &= !1;
+    r
+}
+
+/// Sets `r = a - b (mod 2**(8 * r.len()))` and returns a mask that is true if
+/// `a < b`.
+fn sub(r: &mut [u8], a: &[u8], b: &[u8]) -> u8 {
+    let mut borrow = 0u8;
+    for ((r, &a), &b) in r.iter_mut().zip(a).zip(b).rev() {
+        let difference = u16::from(a)
+            .wrapping_sub(u16::from(b))
+            .wrapping_sub(u16::from(borrow));
+        *r = difference as u8;
+        borrow = (difference >> 15) as u8;
+    }
+    0u8.wrapping_sub(borrow)
+}
+
+/// Sets `r = a` if `mask` is true.
+fn select(mask: u8, r: &mut [u8], a: &[u8]) {
+    for (r, &a) in r.iter_mut().zip(a) {
+        *r = (a & mask) | (*r & !mask);
+    }
+}
+
+fn is_odd_mask(a: &[u8]) -> u8 {
+    0u8.wrapping_sub(a[a.len() - 1] & 1)
+}
+
+/// Shifts `a` right by one bit if `mask` is true.
+fn shr1_masked(a: &mut [u8], mask: u8) {
+    let mut carry = 0;
+    for a in a.iter_mut() {
+        let shifted = (*a >> 1) | carry;
+        carry = *a << 7;
+        *a = (shifted & mask) | (*a & !mask);
+    }
+}
+
+/// Shifts `a` left by one bit if `mask` is true.
+fn shl1_masked(a: &mut [u8], mask: u8) {
+    let mut carry = 0;
+    for a in a.iter_mut().rev() {
+        let shifted = (*a << 1) | carry;
+        carry = *a >> 7;
+        *a = (shifted & mask) | (*a & !mask);
+    }
+}
+
+/// Returns the number of trailing zero bits of the nonzero `a`.
+fn trailing_zeros(a: &[u8]) -> usize {
+    let mut r = 0;
+    let mut found = 0usize;
+    for &byte in a.iter().rev() {
+        r += (byte.trailing_zeros() as usize) & !found;
+        found |= 0usize.wrapping_sub(usize::from(!is_zero_mask(byte) & 1));
+    }
+    r
+}
+
+/// Returns GCD(u, v) for nonzero `u` and `v` of the same length.
+fn gcd(mut u: Vec<u8>, mut v: Vec<u8>) -> Vec<u8> {
+    // The binary GCD algorithm. Each iteration halves `u`, `v`, or both, so
+    // one of them is zero after this many iterations.
+    let bits = u.len() * 8;
+    let mut tmp = vec![0; u.len()];
+    let mut shift = 0;
+    for _ in 0..(2 * bits) {
+        // If both are odd, subtract the smaller from the larger.
+        let both_odd = is_odd_mask(&u) & is_odd_mask(&v);
+        let u_is_smaller = sub(&mut tmp, &u, &v);
+        select(both_odd & !u_is_smaller, &mut u, &tmp);
+        let _ = sub(&mut tmp, &v, &u);
+        select(both_odd & u_is_smaller, &mut v, &tmp);
+
+        // Now at least one of them is even. If both are, the GCD has another
+        // factor of two.
+        let (u_is_odd, v_is_odd) = (is_odd_mask(&u), is_odd_mask(&v));
+        shift += usize::from(!u_is_odd & !v_is_odd & 1);
+        shr1_masked(&mut u, !u_is_odd);
+        shr1_masked(&mut v, !v_is_odd);
+    }
+
+    for (v, &u) in v.iter_mut().zip(&u) {
+        *v |= u;
+    }
+    for i in 0..bits {
+        shl1_masked(&mut v, less_than_mask(i, shift));
+    }
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns 2**bits - k as a big-endian integer of `bits / 8` bytes.
+    fn two_to_the_minus(bits: usize, k: u16) -> Vec<u8> {
+        let mut k_bytes = vec![0; bits / 8];
+        let k_len = k_bytes.len();
+        k_bytes[(k_len - 2)..].copy_from_slice(&k.to_be_bytes());
+        let mut r = vec![0; bits / 8];
+        let _ = sub(&mut r, &vec![0; bits / 8], &k_bytes);
+        r
+    }
+
+    #[test]
+    fn test_is_probably_prime() {
+        let rng = rand::SystemRandom::new();
+        // The assembly exponentiation routines want a multiple of 512 bits.
+        // 2**1024 - 105 and 2**512 - 569 and 2**512 - 629 are prime;
+        // 2**1024 - 107 is not.
+        let test = |w: &[u8]| is_probably_prime(w, 5, &rng).unwrap();
+        assert!(test(&two_to_the_minus(1024, 105)));
+        assert!(!test(&two_to_the_minus(1024, 107)));
+
+        let p = two_to_the_minus(512, 569);
+        let q = two_to_the_minus(512, 629);
+        assert!(test(&p));
+        assert!(test(&q));
+
+        let (wide, _) = bigint::Modulus::<Wide>::from_be_bytes_with_bit_length(
+            untrusted::Input::from(&[0xff; 128]),
+        )
+        .unwrap();
+        let n = bigint::elem_mul(
+            &to_mont(&p, &wide).unwrap(),
+            to_elem(&q, &wide).unwrap(),
+            &wide,
+        );
+        assert!(!test(&to_be_bytes(&n, 128)));
+    }
+
+    #[test]
+    fn test_rem_small() {
+        let a = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        for &d in &[3, 255, 65537, (1 << 32) + 15, (1 << 33) - 1] {
+            assert_eq!(
+                u128::from(rem_small(&a.to_be_bytes(), d)),
+                a % u128::from(d)
+            );
+            assert_eq!(
+                u128::from(rem_small(&u128::MAX.to_be_bytes(), d)),
+                u128::MAX % u128::from(d)
+            );
+        }
+        // 2**200 - 1 is divisible by 2**8 - 1, and 2**32 == 1 (mod 65537).
+        let a = [&[0][..], &[0xff; 25][..]].concat();
+        assert_eq!(rem_small(&a, 255), 0);
+        assert_eq!(rem_small(&a, 65537), 255);
+    }
+
+    #[test]
+    fn test_public_exponent() {
+        let e = PublicExponent::new(65537);
+        assert_eq!(e.prime_factors, [65537]);
+        assert_eq!(e.mul(e.inverse(3), 3), 1);
+
+        let e = PublicExponent::new(3 * 3 * 65537);
+        assert_eq!(e.prime_factors, [3, 65537]);
+        assert_eq!(e.totient, 6 * 65536);
+        assert_eq!(e.mul(e.inverse(65539), 65539), 1);
+    }
+
+    #[test]
+    fn test_gcd() {
+        // GCD(2**200 - 1, 2**72 - 1) == 2**GCD(200, 72) - 1.
+        let a = [&[0][..], &[0xff; 25][..]].concat();
+        let mut b = vec![0; a.len()];
+        b[(a.len() - 9)..].copy_from_slice(&[0xff; 9]);
+        let mut expected = vec![0; a.len()];
+        *expected.last_mut().unwrap() = 0xff;
+        assert_eq!(gcd(a.clone(), b.clone()), expected);
+
+        // GCD((2**200 - 1) * 4, (2**72 - 1) * 8) == (2**8 - 1) * 4.
+        let mut a = a;
+        shl1_masked(&mut a, 0xff);
+        shl1_masked(&mut a, 0xff);
+        for _ in 0..3 {
+            shl1_masked(&mut b, 0xff);
+        }
+        shl1_masked(&mut expected, 0xff);
+        shl1_masked(&mut expected, 0xff);
+        assert_eq!(gcd(a, b.clone()), expected);
+        assert_eq!(gcd(b.clone(), b.clone()), b);
+    }
+
+    #[test]
+    fn test_trailing_zeros() {
+        assert_eq!(trailing_zeros(&[0x01, 0x00, 0x00]), 16);
+        assert_eq!(trailing_zeros(&[0x00, 0x08, 0x00]), 11);
+        assert_eq!(trailing_zeros(&[0x80, 0x00, 0x01]), 0);
+    }
+}
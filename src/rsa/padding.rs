@@ -143,15 +143,15 @@ macro_rules! rsa_pkcs1_padding {
     };
 }
 
-// Intentionally not exposed except internally for signature verification. At a
-// minimum, we'd need to create test vectors for signing with it, which we
-// don't currently have. But, it's a bad idea to use SHA-1 anyway, so perhaps
-// we just won't ever expose it.
+// Only exposed for signing when the `insecure_legacy_signing` feature is
+// enabled; SHA-1 signatures should only be produced for legacy protocols that
+// cannot accept anything else.
 rsa_pkcs1_padding!(
-    pub(in super) RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY,
+    pub RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY,
     &digest::SHA1_FOR_LEGACY_USE_ONLY,
     &SHA1_PKCS1_DIGESTINFO_PREFIX,
-    "PKCS#1 1.5 padding using SHA-1 for RSA signatures."
+    "PKCS#1 1.5 padding using SHA-1 for RSA signatures.\n\n\
+     SHA-1 is broken; only use this to interoperate with legacy protocols."
 );
 
 rsa_pkcs1_padding!(
@@ -225,13 +225,28 @@ pkcs1_digestinfo_prefix!(
 #[derive(Debug)]
 pub struct PSS {
     digest_alg: &'static digest::Algorithm,
+    salt_len: SaltLen,
 }
 
 impl crate::sealed::Sealed for PSS {}
 
-// Maximum supported length of the salt in bytes.
-// In practice, this is constrained by the maximum digest length.
-const MAX_SALT_LEN: usize = digest::MAX_OUTPUT_LEN;
+// The length of the salt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SaltLen {
+    // The length of the digest, which is what TLS 1.3 and other recent
+    // specifications require.
+    DigestLen,
+
+    // No salt, which makes the signature deterministic.
+    Zero,
+
+    // The largest salt that fits, `emLen - hLen - 2` bytes.
+    Max,
+
+    // Any length; the length is recovered from the encoded message. This is
+    // only used for verification.
+    Any,
+}
 
 impl Padding for PSS {
     fn digest_alg(&self) -> &'static digest::Algorithm {
@@ -249,7 +264,11 @@ impl RsaEncoding for PSS {
         mod_bits: bits::BitLength,
         rng: &dyn rand::SecureRandom,
     ) -> Result<(), error::Unspecified> {
-        let metrics = PSSMetrics::new(self.digest_alg, mod_bits)?;
+        // The salt length must be known in order to sign.
+        if self.salt_len == SaltLen::Any {
+            return Err(error::Unspecified);
+        }
+        let metrics = PSSMetrics::new(self.digest_alg, self.salt_len, mod_bits)?;
 
         // The `m_out` this function fills is the big-endian-encoded value of `m`
         // from the specification, padded to `k` bytes, where `k` is the length
@@ -269,8 +288,8 @@ impl RsaEncoding for PSS {
 
         // Step 3 is done by `PSSMetrics::new()` above.
 
-        // Step 4.
-        let mut salt = [0u8; MAX_SALT_LEN];
+        // Step 4. The salt can't be longer than the modulus.
+        let mut salt = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
         let salt = &mut salt[..metrics.s_len];
         rng.fill(salt)?;
 
@@ -320,7 +339,7 @@ impl Verification for PSS {
         m: &mut untrusted::Reader,
         mod_bits: bits::BitLength,
     ) -> Result<(), error::Unspecified> {
-        let metrics = PSSMetrics::new(self.digest_alg, mod_bits)?;
+        let metrics = PSSMetrics::new(self.digest_alg, self.salt_len, mod_bits)?;
 
         // RSASSA-PSS-VERIFY Step 2(c). The `m` this function is given is the
         // big-endian-encoded value of `m` from the specification, padded to
@@ -378,17 +397,24 @@ impl Verification for PSS {
         // Step 9.
         db[0] &= metrics.top_byte_mask;
 
-        // Step 10.
-        let ps_len = metrics.ps_len;
+        // Step 10. When any salt length is accepted, `PS` is everything
+        // before the first nonzero byte.
+        let ps_len = if self.salt_len == SaltLen::Any {
+            db.iter()
+                .position(|&db| db != 0)
+                .ok_or(error::Unspecified)?
+        } else {
+            metrics.ps_len
+        };
         if db[0..ps_len].iter().any(|&db| db != 0) {
             return Err(error::Unspecified);
         }
-        if db[metrics.ps_len] != 1 {
+        if db[ps_len] != 1 {
             return Err(error::Unspecified);
         }
 
         // Step 11.
-        let salt = &db[(db.len() - metrics.s_len)..];
+        let salt = &db[(ps_len + 1)..];
 
         // Step 12 and 13.
         let h_prime = pss_digest(self.digest_alg, m_hash, salt);
@@ -407,6 +433,7 @@ struct PSSMetrics {
     em_len: usize,
     db_len: usize,
     ps_len: usize,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
     s_len: usize,
     h_len: usize,
     top_byte_mask: u8,
@@ -415,6 +442,7 @@ struct PSSMetrics {
 impl PSSMetrics {
     fn new(
         digest_alg: &'static digest::Algorithm,
+        salt_len: SaltLen,
         mod_bits: bits::BitLength,
     ) -> Result<Self, error::Unspecified> {
         let em_bits = mod_bits.try_sub_1()?;
@@ -425,9 +453,6 @@ impl PSSMetrics {
 
         let h_len = digest_alg.output_len;
 
-        // We require the salt length to be equal to the digest length.
-        let s_len = h_len;
-
         // Step 3 of both `EMSA-PSS-ENCODE` is `EMSA-PSS-VERIFY` requires that
         // we reject inputs where "emLen < hLen + sLen + 2". The definition of
         // `emBits` in RFC 3447 Sections 9.1.1 and 9.1.2 says `emBits` must be
@@ -435,8 +460,16 @@ impl PSSMetrics {
         // two conditions are equivalent. 9 bits are required as the 0x01
         // before the salt requires 1 bit and the 0xbc after the digest
         // requires 8 bits.
-        let db_len = em_len.checked_sub(1 + s_len).ok_or(error::Unspecified)?;
-        let ps_len = db_len.checked_sub(h_len + 1).ok_or(error::Unspecified)?;
+        let db_len = em_len.checked_sub(1 + h_len).ok_or(error::Unspecified)?;
+
+        // `SaltLen::Any` is checked against the smallest salt here; the actual
+        // length is only known once `DB` has been unmasked.
+        let s_len = match salt_len {
+            SaltLen::DigestLen => h_len,
+            SaltLen::Zero | SaltLen::Any => 0,
+            SaltLen::Max => db_len.checked_sub(1).ok_or(error::Unspecified)?,
+        };
+        let ps_len = db_len.checked_sub(s_len + 1).ok_or(error::Unspecified)?;
 
         debug_assert!(em_bits.as_usize_bits() >= (8 * h_len) + (8 * s_len) + 9);
 
@@ -453,7 +486,7 @@ impl PSSMetrics {
 
 // Mask-generating function MGF1 as described in
 // https://tools.ietf.org/html/rfc3447#appendix-B.2.1.
-fn mgf1(digest_alg: &'static digest::Algorithm, seed: &[u8], mask: &mut [u8]) {
+pub(super) fn mgf1(digest_alg: &'static digest::Algorithm, seed: &[u8], mask: &mut [u8]) {
     let digest_len = digest_alg.output_len;
 
     // Maximum counter value is the value of (mask_len / digest_len) rounded up.
@@ -486,10 +519,11 @@ fn pss_digest(
 }
 
 macro_rules! rsa_pss_padding {
-    ( $vis:vis $PADDING_ALGORITHM:ident, $digest_alg:expr, $doc_str:expr ) => {
+    ( $vis:vis $PADDING_ALGORITHM:ident, $digest_alg:expr, $salt_len:expr, $doc_str:expr ) => {
         #[doc=$doc_str]
         $vis static $PADDING_ALGORITHM: PSS = PSS {
             digest_alg: $digest_alg,
+            salt_len: $salt_len,
         };
     };
 }
@@ -497,6 +531,7 @@ macro_rules! rsa_pss_padding {
 rsa_pss_padding!(
     pub RSA_PSS_SHA256,
     &digest::SHA256,
+    SaltLen::DigestLen,
     "RSA PSS padding using SHA-256 for RSA signatures.\n\nSee
                  \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
                  documentation for more details."
@@ -505,6 +540,7 @@ rsa_pss_padding!(
 rsa_pss_padding!(
     pub RSA_PSS_SHA384,
     &digest::SHA384,
+    SaltLen::DigestLen,
     "RSA PSS padding using SHA-384 for RSA signatures.\n\nSee
                  \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
                  documentation for more details."
@@ -513,11 +549,90 @@ rsa_pss_padding!(
 rsa_pss_padding!(
     pub RSA_PSS_SHA512,
     &digest::SHA512,
+    SaltLen::DigestLen,
     "RSA PSS padding using SHA-512 for RSA signatures.\n\nSee
                  \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
                  documentation for more details."
 );
 
+rsa_pss_padding!(
+    pub RSA_PSS_SHA256_SALT_LEN_ZERO,
+    &digest::SHA256,
+    SaltLen::Zero,
+    "RSA PSS padding using SHA-256 and an empty salt for RSA signatures.\n\nSee
+                 \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
+                 documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub RSA_PSS_SHA256_SALT_LEN_MAX,
+    &digest::SHA256,
+    SaltLen::Max,
+    "RSA PSS padding using SHA-256 and the longest possible salt for RSA
+                 signatures.\n\nSee \"`RSA_PSS_*` Details\" in `ring::signature`'s
+                 module-level documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub(super) RSA_PSS_SHA256_ANY_SALT_LEN,
+    &digest::SHA256,
+    SaltLen::Any,
+    "RSA PSS padding using SHA-256 and a salt of any length, for verification
+                 only."
+);
+
+rsa_pss_padding!(
+    pub RSA_PSS_SHA384_SALT_LEN_ZERO,
+    &digest::SHA384,
+    SaltLen::Zero,
+    "RSA PSS padding using SHA-384 and an empty salt for RSA signatures.\n\nSee
+                 \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
+                 documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub RSA_PSS_SHA384_SALT_LEN_MAX,
+    &digest::SHA384,
+    SaltLen::Max,
+    "RSA PSS padding using SHA-384 and the longest possible salt for RSA
+                 signatures.\n\nSee \"`RSA_PSS_*` Details\" in `ring::signature`'s
+                 module-level documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub(super) RSA_PSS_SHA384_ANY_SALT_LEN,
+    &digest::SHA384,
+    SaltLen::Any,
+    "RSA PSS padding using SHA-384 and a salt of any length, for verification
+                 only."
+);
+
+rsa_pss_padding!(
+    pub RSA_PSS_SHA512_SALT_LEN_ZERO,
+    &digest::SHA512,
+    SaltLen::Zero,
+    "RSA PSS padding using SHA-512 and an empty salt for RSA signatures.\n\nSee
+                 \"`RSA_PSS_*` Details\" in `ring::signature`'s module-level
+                 documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub RSA_PSS_SHA512_SALT_LEN_MAX,
+    &digest::SHA512,
+    SaltLen::Max,
+    "RSA PSS padding using SHA-512 and the longest possible salt for RSA
+                 signatures.\n\nSee \"`RSA_PSS_*` Details\" in `ring::signature`'s
+                 module-level documentation for more details."
+);
+
+rsa_pss_padding!(
+    pub(super) RSA_PSS_SHA512_ANY_SALT_LEN,
+    &digest::SHA512,
+    SaltLen::Any,
+    "RSA PSS padding using SHA-512 and a salt of any length, for verification
+                 only."
+);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -556,6 +671,18 @@ mod test {
                     encoded.read_all(error::Unspecified, |m| alg.verify(&m_hash, m, bit_len));
                 assert_eq!(actual_result.is_ok(), is_valid);
 
+                // A salt of any length includes a salt of the digest's length.
+                if is_valid {
+                    let any_salt_len = PSS {
+                        digest_alg: alg.digest_alg,
+                        salt_len: SaltLen::Any,
+                    };
+                    assert!(encoded
+                        .read_all(error::Unspecified, |m| any_salt_len
+                            .verify(&m_hash, m, bit_len))
+                        .is_ok());
+                }
+
                 Ok(())
             },
         );
@@ -0,0 +1,101 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Raw RSA signature primitives, for building padding schemes that *ring*
+//! doesn't implement.
+//!
+//! # Hazardous
+//!
+//! RSA without padding isn't a secure signature scheme: raw signatures are
+//! trivially forgeable and malleable. These functions are only safe to use
+//! as part of a complete, well-analyzed scheme, e.g. ISO/IEC 9796-2 as used in
+//! EMV or RSA blind signatures ([RFC 9474]), whose padding is implemented and
+//! verified by the caller. Use `RsaKeyPair::sign()` and
+//! `signature::UnparsedPublicKey::verify()` for anything else.
+//!
+//! `rsasp1()` uses the same constant-time private key operation as
+//! `RsaKeyPair::sign()`, including the check of the result that protects
+//! against fault attacks.
+//!
+//! [RFC 9474]: https://tools.ietf.org/html/rfc9474
+
+use super::{public, signing::RsaKeyPair, PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN};
+use crate::{arithmetic::bigint, bits, cpu, error};
+
+/// RSASP1, as described in [RFC 8017 Section 5.2.1]: computes the signature
+/// representative of the message representative `m` using `key_pair`'s
+/// private key.
+///
+/// `m` and `signature` are big-endian integers whose lengths must both be
+/// exactly `key_pair.public_modulus_len()`, and `m` must be less than the
+/// public modulus. Although [RFC 8017 Section 5.2.1] allows `m` to be zero,
+/// zero is rejected.
+///
+/// See the module-level documentation.
+///
+/// [RFC 8017 Section 5.2.1]: https://tools.ietf.org/html/rfc8017#section-5.2.1
+pub fn rsasp1(
+    key_pair: &RsaKeyPair,
+    m: &[u8],
+    signature: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    let k = key_pair.public_modulus_len();
+    if m.len() != k || signature.len() != k {
+        return Err(error::Unspecified);
+    }
+    signature.copy_from_slice(m);
+    key_pair.rsadp(signature)
+}
+
+/// RSAVP1, as described in [RFC 8017 Section 5.2.2]: recovers the message
+/// representative from the signature representative `signature` using the
+/// public key `public_key`.
+///
+/// The public modulus must be 1024-8192 bits. `signature` and `m` are
+/// big-endian integers whose lengths must both be exactly the length of the
+/// public modulus in bytes, and `signature` must be less than the public
+/// modulus. Each public key is validated, so when many signatures are
+/// verified with the same key, its cost is repeated.
+///
+/// See the module-level documentation.
+///
+/// [RFC 8017 Section 5.2.2]: https://tools.ietf.org/html/rfc8017#section-5.2.2
+pub fn rsavp1<B>(
+    public_key: &public::Components<B>,
+    signature: &[u8],
+    m: &mut [u8],
+) -> Result<(), error::Unspecified>
+where
+    B: AsRef<[u8]>,
+{
+    let _ = cpu::features();
+    let max_bits = bits::BitLength::from_usize_bytes(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)?;
+    let public::Key { n, e, n_bits } = public::Key::from_modulus_and_exponent(
+        untrusted::Input::from(public_key.n.as_ref()),
+        untrusted::Input::from(public_key.e.as_ref()),
+        bits::BitLength::from_usize_bits(1024),
+        max_bits,
+        public::Exponent::_3,
+    )
+    .map_err(|_| error::Unspecified)?;
+
+    let k = n_bits.as_usize_bytes_rounded_up();
+    if signature.len() != k || m.len() != k {
+        return Err(error::Unspecified);
+    }
+
+    let s = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(signature), &n)?;
+    super::elem_exp_vartime(s, e, &n).fill_be_bytes(m);
+    Ok(())
+}
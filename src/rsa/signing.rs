@@ -12,7 +12,12 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use super::{padding::RsaEncoding, public, N};
+use super::{
+    encryption::ImplicitRejectionSeed,
+    keygen::{self, RsaKeySize},
+    padding::RsaEncoding,
+    public, N,
+};
 
 /// RSA PKCS#1 1.5 signatures.
 use crate::{
@@ -25,7 +30,7 @@ use crate::{
     io::{self, der, der_writer},
     pkcs8, rand, signature,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec};
 
 /// An RSA key pair, used for signing.
 pub struct RsaKeyPair {
@@ -36,16 +41,85 @@ pub struct RsaKeyPair {
     q_mod_n: bigint::Elem<N, R>,
     public: public::Key,
     public_key: RsaSubjectPublicKey,
+    // SHA-256(I2OSP(d, k)), from which the implicit rejection key for
+    // `decrypt_pkcs1_for_legacy_use_only()` is derived when it is needed.
+    pub(super) implicit_rejection_seed: ImplicitRejectionSeed,
 }
 
 derive_debug_via_field!(RsaKeyPair, stringify!(RsaKeyPair), public_key);
 
 impl RsaKeyPair {
+    /// Generates a new RSA key pair with a `key_size` public modulus and the
+    /// public exponent `public_exponent`, and serializes it as a PKCS#8 v1
+    /// document.
+    ///
+    /// The primes are generated as described in [FIPS 186-5] Appendix A.1.3
+    /// and the private exponent is computed as described in Appendix A.1.1.
+    /// `public_exponent` must be odd, at least 65537, and no more than 33
+    /// bits; unless a protocol requires something else, it should be
+    /// `RSA_DEFAULT_PUBLIC_EXPONENT` (65537).
+    ///
+    /// Generating a key pair takes a long time, especially for the larger key
+    /// sizes: many random candidates are tested before two primes are found.
+    /// Only the time spent on the rejected candidates varies; the primes that
+    /// are found are processed in constant time.
+    ///
+    /// Use `from_pkcs8()` to parse the generated document.
+    ///
+    /// [FIPS 186-5]: https://doi.org/10.6028/NIST.FIPS.186-5
+    pub fn generate_pkcs8(
+        key_size: RsaKeySize,
+        public_exponent: u64,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let private_key = keygen::generate(key_size, public_exponent, rng)?;
+
+        // A pairwise consistency test.
+        let key_pair = Self::from_der(&private_key).map_err(|_| error::Unspecified)?;
+        let mut signature = vec![0; key_pair.public_modulus_len()];
+        key_pair.sign(&super::padding::RSA_PKCS1_SHA256, rng, b"", &mut signature)?;
+        signature::UnparsedPublicKey::new(
+            &super::verification::RSA_PKCS1_2048_8192_SHA256,
+            &key_pair.public_key,
+        )
+        .verify(b"", &signature)?;
+
+        Ok(pkcs8::Document::from_boxed(der_writer::write_all(
+            der::Tag::Sequence,
+            &|output| {
+                der_writer::write_tlv(output, der::Tag::Integer, |output| {
+                    output.write_byte(0); // v1
+                });
+                der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                    output.write_bytes(RSA_ENCRYPTION)
+                });
+                der_writer::write_tlv(output, der::Tag::OctetString, |output| {
+                    output.write_bytes(&private_key)
+                });
+            },
+        )))
+    }
+
+    /// Generates a new RSA key pair with a `bits`-bit public modulus and the
+    /// public exponent `public_exponent`, and serializes it as a PKCS#8 v1
+    /// document.
+    ///
+    /// `bits` must be 2048, 3072, 4096, or 8192. This is otherwise the same as
+    /// `generate_pkcs8()`.
+    pub fn generate(
+        bits: usize,
+        public_exponent: u64,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let key_size = RsaKeySize::from_modulus_len_bits(bits).ok_or(error::Unspecified)?;
+        Self::generate_pkcs8(key_size, public_exponent, rng)
+    }
+
     /// Parses an unencrypted PKCS#8-encoded RSA private key.
     ///
     /// Only two-prime (not multi-prime) keys are supported. The public modulus
     /// (n) must be at least 2047 bits. The public modulus must be no larger
-    /// than 4096 bits. It is recommended that the public modulus be exactly
+    /// than 8192 bits. It is recommended that the public modulus be exactly
     /// 2048 or 3072 bits. The public exponent must be at least 65537.
     ///
     /// This will generate a 2048-bit RSA private key of the correct form using
@@ -116,12 +190,12 @@ impl RsaKeyPair {
     ///     pair.
     ///
     ///     * `d` is not fully validated, neither at construction nor during
-    ///     signing. This is OK as far as *ring*'s usage of the key is
-    ///     concerned because *ring* never uses the value of `d` (*ring* always
-    ///     uses `p`, `q`, `dP` and `dQ` via the Chinese Remainder Theorem,
-    ///     instead). However, *ring*'s checks would not be sufficient for
-    ///     validating a key pair for use by some other system; that other
-    ///     system must check the value of `d` itself if `d` is to be used.
+    ///     signing. This is OK for *ring*, which only hashes `d`, to derive
+    ///     the PKCS#1 v1.5 decryption implicit rejection key, and otherwise
+    ///     uses `p`, `q`, `dP` and `dQ` via the Chinese Remainder Theorem.
+    ///     However, *ring*'s checks would not be sufficient for validating a
+    ///     key pair for use by some other system; that other system must
+    ///     check the value of `d` itself if `d` is to be used.
     ///
     /// In addition to the NIST requirements, *ring* requires that `p > q` and
     /// that `e` must be no more than 33 bits.
@@ -138,7 +212,6 @@ impl RsaKeyPair {
     /// [RFC 5958]:
     ///     https://tools.ietf.org/html/rfc5958
     pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
-        const RSA_ENCRYPTION: &[u8] = include_bytes!("../data/alg-rsa-encryption.der");
         let (der, _) = pkcs8::unwrap_key_(
             untrusted::Input::from(RSA_ENCRYPTION),
             pkcs8::Version::V1Only,
@@ -223,12 +296,8 @@ impl RsaKeyPair {
 
         // Step 1.b is omitted per above. Instead, we check that the public
         // modulus is 2048 to `PRIVATE_KEY_PUBLIC_MODULUS_MAX_BITS` bits.
-        // XXX: The maximum limit of 4096 bits is primarily due to lack of
-        // testing of larger key sizes; see, in particular,
-        // https://www.mail-archive.com/openssl-dev@openssl.org/msg44586.html
-        // and
-        // https://www.mail-archive.com/openssl-dev@openssl.org/msg44759.html.
-        // Also, this limit might help with memory management decisions later.
+        // The maximum limit of 8192 bits is the same as the limit for
+        // verification, which is imposed by `bigint::MODULUS_MAX_LIMBS`.
 
         // Step 1.c. We validate e >= 65537.
         let public_key = public::Key::from_modulus_and_exponent(
@@ -313,7 +382,8 @@ impl RsaKeyPair {
         // First, validate `2**half_n_bits < d`. Since 2**half_n_bits has a bit
         // length of half_n_bits + 1, this check gives us 2**half_n_bits <= d,
         // and knowing d is odd makes the inequality strict.
-        let (d, d_bits) = bigint::Nonnegative::from_be_bytes_with_bit_length(d)
+        let d_bytes = d;
+        let (d, d_bits) = bigint::Nonnegative::from_be_bytes_with_bit_length(d_bytes)
             .map_err(|_| error::KeyRejected::invalid_encoding())?;
         if !(half_n_bits < d_bits) {
             return Err(KeyRejected::inconsistent_components());
@@ -326,6 +396,11 @@ impl RsaKeyPair {
             return Err(KeyRejected::invalid_component());
         }
 
+        // Now that we know `d < n`, derive the seed for implicit rejection in
+        // `decrypt_pkcs1_for_legacy_use_only()` from it.
+        let implicit_rejection_seed =
+            ImplicitRejectionSeed::new(d_bytes.as_slice_less_safe(), public_key.n_bits);
+
         // Step 6.b is omitted as explained above.
 
         // 6.4.1.4.3 - Step 7.
@@ -371,6 +446,7 @@ impl RsaKeyPair {
             qq,
             public: public_key,
             public_key: public_key_serialized,
+            implicit_rejection_seed,
         })
     }
 
@@ -385,6 +461,41 @@ impl RsaKeyPair {
     }
 }
 
+/// A context for signing a message given in parts with an `RsaKeyPair`.
+///
+/// See `RsaKeyPair::signing_context()`.
+#[derive(Clone)]
+pub struct RsaSigningContext<'a> {
+    key_pair: &'a RsaKeyPair,
+    padding_alg: &'static dyn RsaEncoding,
+    digest: digest::Context,
+}
+
+derive_debug_via_field!(
+    RsaSigningContext<'_>,
+    stringify!(RsaSigningContext),
+    padding_alg
+);
+
+impl RsaSigningContext<'_> {
+    /// Updates the message to sign with the next part `data`.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.digest.update(data)
+    }
+
+    /// Signs the whole message, writing the signature into `signature`, like
+    /// `RsaKeyPair::sign()` does.
+    pub fn finish(
+        self,
+        rng: &dyn rand::SecureRandom,
+        signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        self.key_pair
+            .sign_digest(self.padding_alg, rng, &self.digest.finish(), signature)
+    }
+}
+
 impl signature::KeyPair for RsaKeyPair {
     type PublicKey = RsaSubjectPublicKey;
 
@@ -393,6 +504,22 @@ impl signature::KeyPair for RsaKeyPair {
     }
 }
 
+impl signature::KeyPairGenerator for RsaKeySize {
+    type KeyPair = RsaKeyPair;
+
+    /// Generates a key pair with the public exponent 65537.
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = RsaKeyPair::generate_pkcs8(*self, keygen::RSA_DEFAULT_PUBLIC_EXPONENT, rng)?;
+        let key_pair = RsaKeyPair::from_pkcs8(pkcs8.as_ref()).map_err(|_| error::Unspecified)?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
+const RSA_ENCRYPTION: &[u8] = include_bytes!("../data/alg-rsa-encryption.der");
+
 /// A serialized RSA public key.
 #[derive(Clone)]
 pub struct RsaSubjectPublicKey(Box<[u8]>);
@@ -414,6 +541,23 @@ impl RsaSubjectPublicKey {
         Self(bytes)
     }
 
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`, as
+    /// described in [RFC 3279 Section 2.3.1].
+    ///
+    /// [RFC 3279 Section 2.3.1]:
+    ///     https://tools.ietf.org/html/rfc3279#section-2.3.1
+    pub fn subject_public_key_info(&self) -> Box<[u8]> {
+        der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                output.write_bytes(RSA_ENCRYPTION)
+            });
+            der_writer::write_tlv(output, der::Tag::BitString, |output| {
+                output.write_byte(0); // No unused bits.
+                output.write_bytes(self.as_ref());
+            });
+        })
+    }
+
     /// The public modulus (n).
     pub fn modulus(&self) -> io::Positive {
         // Parsing won't fail because we serialized it ourselves.
@@ -525,7 +669,8 @@ impl RsaKeyPair {
     /// Many other crypto libraries have signing functions that takes a
     /// precomputed digest as input, instead of the message to digest. This
     /// function does *not* take a precomputed digest; instead, `sign`
-    /// calculates the digest itself.
+    /// calculates the digest itself. Use `sign_digest` to sign a precomputed
+    /// digest.
     ///
     /// Lots of effort has been made to make the signing operations close to
     /// constant time to protect the private key from side channel attacks. On
@@ -537,22 +682,63 @@ impl RsaKeyPair {
         rng: &dyn rand::SecureRandom,
         msg: &[u8],
         signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let m_hash = digest::digest(padding_alg.digest_alg(), msg);
+        self.sign_digest(padding_alg, rng, &m_hash, signature)
+    }
+
+    /// Returns a context for signing a message that is given in parts, e.g.
+    /// because it is too large to hold in memory.
+    ///
+    /// The message is digested incrementally using the digest algorithm from
+    /// `padding_alg` and then signed like `sign_digest()` does.
+    pub fn signing_context(&self, padding_alg: &'static dyn RsaEncoding) -> RsaSigningContext<'_> {
+        RsaSigningContext {
+            key_pair: self,
+            padding_alg,
+            digest: digest::Context::new(padding_alg.digest_alg()),
+        }
+    }
+
+    /// Like `sign`, but signs the message whose digest is `m_hash` instead of
+    /// digesting the message itself.
+    ///
+    /// This is for callers that have already digested the message, e.g.
+    /// because it was too large to buffer. Fails if `m_hash` wasn't calculated
+    /// using the digest algorithm from `padding_alg`.
+    pub fn sign_digest(
+        &self,
+        padding_alg: &'static dyn RsaEncoding,
+        rng: &dyn rand::SecureRandom,
+        m_hash: &digest::Digest,
+        signature: &mut [u8],
     ) -> Result<(), error::Unspecified> {
         let mod_bits = self.public.n_bits;
         if signature.len() != mod_bits.as_usize_bytes_rounded_up() {
             return Err(error::Unspecified);
         }
 
-        let m_hash = digest::digest(padding_alg.digest_alg(), msg);
-        padding_alg.encode(&m_hash, signature, mod_bits, rng)?;
+        if m_hash.algorithm() != padding_alg.digest_alg() {
+            return Err(error::Unspecified);
+        }
+        padding_alg.encode(m_hash, signature, mod_bits, rng)?;
 
-        // RFC 8017 Section 5.1.2: RSADP, using the Chinese Remainder Theorem
-        // with Garner's algorithm.
+        self.rsadp(signature)
+    }
 
+    /// RFC 8017 Section 5.1.2: RSADP, using the Chinese Remainder Theorem
+    /// with Garner's algorithm. `in_out` must be exactly as long as the public
+    /// modulus; the input is replaced with the output.
+    pub(super) fn rsadp(&self, in_out: &mut [u8]) -> Result<(), error::Unspecified> {
         let n = &self.public.n;
 
-        // Step 1. The value zero is also rejected.
-        let base = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(signature), n)?;
+        // Step 1 requires `c < n`. Zero is rejected here as well: no padding
+        // produces it, and it can be recognized without the private key, so
+        // rejecting it reveals nothing.
+        let base = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(&*in_out), n)?;
+        if base.is_zero() {
+            return Err(error::Unspecified);
+        }
 
         // Step 2
         let c = base;
@@ -597,7 +783,7 @@ impl RsaKeyPair {
         // Step 3.
         //
         // See Falko Strenzke, "Manger's Attack revisited", ICICS 2010.
-        m.fill_be_bytes(signature);
+        m.fill_be_bytes(in_out);
 
         Ok(())
     }
@@ -28,6 +28,16 @@ impl signature::VerificationAlgorithm for RsaParameters {
         public_key: untrusted::Input,
         msg: untrusted::Input,
         signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let m_hash = digest::digest(self.padding_alg.digest_alg(), msg.as_slice_less_safe());
+        self.verify_digest(public_key, &m_hash, signature)
+    }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        m_hash: &digest::Digest,
+        signature: untrusted::Input,
     ) -> Result<(), error::Unspecified> {
         let (n, e) = parse_public_key(public_key)?;
         verify_rsa_(
@@ -36,10 +46,51 @@ impl signature::VerificationAlgorithm for RsaParameters {
                 n.big_endian_without_leading_zero_as_input(),
                 e.big_endian_without_leading_zero_as_input(),
             ),
-            msg,
+            m_hash,
             signature,
         )
     }
+
+    fn parse_public_key(
+        &self,
+        public_key: untrusted::Input,
+    ) -> Result<signature::parsed::PublicKey, error::KeyRejected> {
+        let (n, e) = parse_public_key(public_key)
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        let key = public_key_from_modulus_and_exponent(
+            self,
+            (
+                n.big_endian_without_leading_zero_as_input(),
+                e.big_endian_without_leading_zero_as_input(),
+            ),
+        )?;
+        Ok(signature::parsed::PublicKey::Rsa(key))
+    }
+
+    fn verify_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        public_key_bytes: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let m_hash = digest::digest(self.padding_alg.digest_alg(), msg.as_slice_less_safe());
+        self.verify_digest_parsed(public_key, public_key_bytes, &m_hash, signature)
+    }
+
+    fn verify_digest_parsed(
+        &self,
+        public_key: &signature::parsed::PublicKey,
+        _public_key_bytes: untrusted::Input,
+        m_hash: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let key = match public_key {
+            signature::parsed::PublicKey::Rsa(key) => key,
+            _ => return Err(error::Unspecified),
+        };
+        verify_rsa_key(self, key, m_hash, signature)
+    }
 }
 
 impl sealed::Sealed for RsaParameters {}
@@ -146,6 +197,78 @@ rsa_params!(
              PSS padding, and SHA-512.\n\nSee \"`RSA_PSS_*` Details\" in
              `ring::signature`'s module-level documentation for more details."
 );
+rsa_params!(
+    RSA_PSS_2048_8192_SHA256_SALT_LEN_ZERO,
+    2048,
+    &super::padding::RSA_PSS_SHA256_SALT_LEN_ZERO,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-256, and an empty salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA384_SALT_LEN_ZERO,
+    2048,
+    &super::padding::RSA_PSS_SHA384_SALT_LEN_ZERO,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-384, and an empty salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA512_SALT_LEN_ZERO,
+    2048,
+    &super::padding::RSA_PSS_SHA512_SALT_LEN_ZERO,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-512, and an empty salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA256_SALT_LEN_MAX,
+    2048,
+    &super::padding::RSA_PSS_SHA256_SALT_LEN_MAX,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-256, and the longest possible salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA384_SALT_LEN_MAX,
+    2048,
+    &super::padding::RSA_PSS_SHA384_SALT_LEN_MAX,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-384, and the longest possible salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA512_SALT_LEN_MAX,
+    2048,
+    &super::padding::RSA_PSS_SHA512_SALT_LEN_MAX,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-512, and the longest possible salt.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA256_ANY_SALT_LEN,
+    2048,
+    &super::padding::RSA_PSS_SHA256_ANY_SALT_LEN,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-256, and a salt of any length.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA384_ANY_SALT_LEN,
+    2048,
+    &super::padding::RSA_PSS_SHA384_ANY_SALT_LEN,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-384, and a salt of any length.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
+rsa_params!(
+    RSA_PSS_2048_8192_SHA512_ANY_SALT_LEN,
+    2048,
+    &super::padding::RSA_PSS_SHA512_ANY_SALT_LEN,
+    "Verification of signatures using RSA keys of 2048-8192 bits,
+             PSS padding, SHA-512, and a salt of any length.\n\nSee \"`RSA_PSS_*` Details\"
+             in `ring::signature`'s module-level documentation for more details."
+);
 
 pub use super::public::Components as RsaPublicKeyComponents;
 
@@ -178,6 +301,21 @@ where
         params: &RsaParameters,
         message: &[u8],
         signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let m_hash = digest::digest(params.padding_alg.digest_alg(), message);
+        self.verify_digest(params, &m_hash, signature)
+    }
+
+    /// Like `verify`, but verifies the signature of the message whose digest
+    /// is `m_hash` instead of digesting the message itself.
+    ///
+    /// Fails if `m_hash` wasn't calculated using the digest algorithm from
+    /// `params`.
+    pub fn verify_digest(
+        &self,
+        params: &RsaParameters,
+        m_hash: &digest::Digest,
+        signature: &[u8],
     ) -> Result<(), error::Unspecified> {
         let _ = cpu::features();
         verify_rsa_(
@@ -186,7 +324,7 @@ where
                 untrusted::Input::from(self.n.as_ref()),
                 untrusted::Input::from(self.e.as_ref()),
             ),
-            untrusted::Input::from(message),
+            m_hash,
             untrusted::Input::from(signature),
         )
     }
@@ -195,22 +333,37 @@ where
 pub(crate) fn verify_rsa_(
     params: &RsaParameters,
     (n, e): (untrusted::Input, untrusted::Input),
-    msg: untrusted::Input,
+    m_hash: &digest::Digest,
     signature: untrusted::Input,
 ) -> Result<(), error::Unspecified> {
-    let max_bits = bits::BitLength::from_usize_bytes(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)?;
+    let key = public_key_from_modulus_and_exponent(params, (n, e))?;
+    verify_rsa_key(params, &key, m_hash, signature)
+}
+
+fn public_key_from_modulus_and_exponent(
+    params: &RsaParameters,
+    (n, e): (untrusted::Input, untrusted::Input),
+) -> Result<public::Key, error::KeyRejected> {
+    let max_bits = bits::BitLength::from_usize_bytes(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)
+        .map_err(|error::Unspecified| error::KeyRejected::unexpected_error())?;
 
     // XXX: FIPS 186-4 seems to indicate that the minimum
     // exponent value is 2**16 + 1, but it isn't clear if this is just for
     // signing or also for verification. We support exponents of 3 and larger
     // for compatibility with other commonly-used crypto libraries.
-    let public::Key { n, e, n_bits } = public::Key::from_modulus_and_exponent(
-        n,
-        e,
-        params.min_bits,
-        max_bits,
-        public::Exponent::_3,
-    )?;
+    public::Key::from_modulus_and_exponent(n, e, params.min_bits, max_bits, public::Exponent::_3)
+}
+
+fn verify_rsa_key(
+    params: &RsaParameters,
+    public::Key { n, e, n_bits }: &public::Key,
+    m_hash: &digest::Digest,
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    if m_hash.algorithm() != params.padding_alg.digest_alg() {
+        return Err(error::Unspecified);
+    }
+    let (e, n_bits) = (*e, *n_bits);
 
     // The signature must be the same length as the modulus, in bytes.
     if signature.len() != n_bits.as_usize_bytes_rounded_up() {
@@ -220,22 +373,21 @@ pub(crate) fn verify_rsa_(
     // RFC 8017 Section 5.2.2: RSAVP1.
 
     // Step 1.
-    let s = bigint::Elem::from_be_bytes_padded(signature, &n)?;
+    let s = bigint::Elem::from_be_bytes_padded(signature, n)?;
     if s.is_zero() {
         return Err(error::Unspecified);
     }
 
     // Step 2.
-    let m = super::elem_exp_vartime(s, e, &n);
+    let m = super::elem_exp_vartime(s, e, n);
 
     // Step 3.
     let mut decoded = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
     let decoded = fill_be_bytes_n(m, n_bits, &mut decoded);
 
     // Verify the padded message is correct.
-    let m_hash = digest::digest(params.padding_alg.digest_alg(), msg.as_slice_less_safe());
     untrusted::Input::from(decoded).read_all(error::Unspecified, |m| {
-        params.padding_alg.verify(&m_hash, m, n_bits)
+        params.padding_alg.verify(m_hash, m, n_bits)
     })
 }
 
@@ -0,0 +1,63 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! RSA public-key encryption, for key transport.
+//!
+//! Encryption is done with the recipient's `PublicKey` and decryption with
+//! the recipient's `ring::signature::RsaKeyPair`.
+//!
+//! RSAES-OAEP should be used whenever possible. RSAES-PKCS1-v1_5 is provided,
+//! via the `*_pkcs1_for_legacy_use_only` functions, only for interoperability
+//! with systems that don't support OAEP.
+//!
+//! # Example
+//!
+//! ```
+//! use ring::{rand, rsa_encryption, signature};
+//!
+//! # fn main() -> Result<(), ring::error::Unspecified> {
+//! let rng = rand::SystemRandom::new();
+//!
+//! # let private_key_der = include_bytes!("../src/rsa/signature_rsa_example_private_key.der");
+//! let key_pair = signature::RsaKeyPair::from_der(private_key_der)
+//!     .map_err(|_| ring::error::Unspecified)?;
+//! let public_key = rsa_encryption::PublicKey::from_der(
+//!     signature::KeyPair::public_key(&key_pair).as_ref(),
+//! )
+//! .map_err(|_| ring::error::Unspecified)?;
+//!
+//! let mut ciphertext = vec![0; public_key.modulus_len()];
+//! public_key.encrypt_oaep(
+//!     &rsa_encryption::RSA_OAEP_SHA256,
+//!     b"content encryption key",
+//!     b"",
+//!     &rng,
+//!     &mut ciphertext,
+//! )?;
+//!
+//! let mut plaintext = vec![0; key_pair.public_modulus_len()];
+//! let plaintext = key_pair.decrypt_oaep(
+//!     &rsa_encryption::RSA_OAEP_SHA256,
+//!     &ciphertext,
+//!     b"",
+//!     &mut plaintext,
+//! )?;
+//! assert_eq!(plaintext, b"content encryption key");
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::rsa::encryption::{
+    OaepAlgorithm, PublicKey, RSA_OAEP_SHA256, RSA_OAEP_SHA384, RSA_OAEP_SHA512,
+};
@@ -0,0 +1,265 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! scrypt derivation and verification.
+//!
+//! scrypt is a memory-hard password-based key derivation function, specified
+//! in [RFC 7914]. Use `derive` to derive scrypt outputs, e.g. to hash a new
+//! password. Use `verify` to verify a secret against a previously-derived
+//! output.
+//!
+//! ```
+//! use ring::scrypt;
+//!
+//! // These parameters are far too weak for real use; see `Params::new`.
+//! let params = scrypt::Params::new(10, 8, 1).unwrap();
+//! let salt = b"a per-user salt";
+//!
+//! let mut stored = [0u8; 32];
+//! scrypt::derive(&params, salt, b"correct horse", &mut stored);
+//!
+//! assert!(scrypt::verify(&params, salt, b"correct horse", &stored).is_ok());
+//! assert!(scrypt::verify(&params, salt, b"wrong horse", &stored).is_err());
+//! ```
+//!
+//! [RFC 7914]: https://tools.ietf.org/html/rfc7914
+
+use crate::{constant_time, error, kdf, pbkdf2, polyfill};
+use alloc::vec;
+use core::{
+    convert::{TryFrom, TryInto},
+    num::NonZeroU32,
+};
+
+/// The length of a block of the scrypt state for `r = 1`, in bytes.
+const BLOCK_LEN: usize = 128;
+
+/// The maximum length of the output, per RFC 7914.
+const MAX_OUTPUT_LEN: u64 = ((1 << 32) - 1) * 32;
+
+/// scrypt's cost parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Params {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl Params {
+    /// Constructs the parameters with the CPU/memory cost parameter
+    /// `N = 2^log_n`, the block size `r`, and the parallelization parameter
+    /// `p`.
+    ///
+    /// Deriving a key uses about `128 * r * N` bytes of memory, and time
+    /// proportional to `r * N * p`. `r = 8` and `p = 1` are the usual choices,
+    /// with `log_n` as large as the time and memory budget allows; at least
+    /// 17 for interactive logins.
+    ///
+    /// Fails if `log_n` is zero or at least `16 * r` (the limit of RFC 7914),
+    /// if `r` or `p` is zero, if `r * p` is at least 2^30, or if the memory
+    /// required isn't addressable.
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self, error::Unspecified> {
+        if log_n == 0
+            || r == 0
+            || p == 0
+            || u64::from(log_n) >= 16 * u64::from(r)
+            || u64::from(r) * u64::from(p) >= (1 << 30)
+            || u32::from(log_n) >= usize::MAX.count_ones()
+        {
+            return Err(error::Unspecified);
+        }
+        let r_len = usize::try_from(r)
+            .ok()
+            .and_then(|r| r.checked_mul(BLOCK_LEN))
+            .ok_or(error::Unspecified)?;
+        // The state of each of the `p` lanes, and the `N` blocks of the
+        // memory-hard step.
+        let p_len = usize::try_from(p).map_err(|_| error::Unspecified)?;
+        if r_len.checked_mul(p_len).is_none() || r_len.checked_mul(1 << log_n).is_none() {
+            return Err(error::Unspecified);
+        }
+        Ok(Self { log_n, r, p })
+    }
+
+    /// The CPU/memory cost parameter, as its base-2 logarithm.
+    #[inline]
+    pub fn log_n(&self) -> u8 {
+        self.log_n
+    }
+
+    /// The block size parameter.
+    #[inline]
+    pub fn r(&self) -> u32 {
+        self.r
+    }
+
+    /// The parallelization parameter.
+    #[inline]
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+}
+
+/// scrypt has no input corresponding to the info, so deriving fails unless it
+/// is empty.
+impl kdf::Kdf for Params {
+    fn derive(
+        &self,
+        secret: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        if !info.is_empty() || polyfill::u64_from_usize(out.len()) > MAX_OUTPUT_LEN {
+            return Err(error::Unspecified);
+        }
+        derive(self, salt, secret, out);
+        Ok(())
+    }
+}
+
+/// Fills `out` with the key derived using scrypt with the given inputs.
+///
+/// Do not use `derive` as part of verifying a secret; use `verify` instead, to
+/// minimize the effectiveness of timing attacks.
+///
+/// | Parameter   | RFC 7914 Section 6 Term
+/// |-------------|------------------------
+/// | `params`    | N, r, and p
+/// | `salt`      | S (salt)
+/// | `secret`    | P (passphrase)
+/// | `out`       | DK (derived key)
+/// | `out.len()` | dkLen (derived key length)
+///
+/// # Panics
+///
+/// `derive` panics if `out.len()` is larger than (2**32 - 1) * 32, per the
+/// scrypt specification, or if the memory can't be allocated.
+pub fn derive(params: &Params, salt: &[u8], secret: &[u8], out: &mut [u8]) {
+    assert!(polyfill::u64_from_usize(out.len()) <= MAX_OUTPUT_LEN);
+
+    let r = params.r as usize;
+    let lane_len = BLOCK_LEN * r;
+
+    let mut b = vec![0u8; lane_len * (params.p as usize)];
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, ONE, salt, secret, &mut b);
+
+    let mut x = vec![0u32; lane_len / 4];
+    let mut v = vec![0u32; (lane_len / 4) << params.log_n];
+    let mut scratch = vec![0u32; lane_len / 4];
+    for lane in b.chunks_exact_mut(lane_len) {
+        for (x, bytes) in x.iter_mut().zip(lane.chunks_exact(4)) {
+            *x = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        ro_mix(&mut x, &mut v, &mut scratch, params.log_n);
+        for (x, bytes) in x.iter().zip(lane.chunks_exact_mut(4)) {
+            bytes.copy_from_slice(&x.to_le_bytes());
+        }
+    }
+
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, ONE, &b, secret, out);
+}
+
+/// Verifies that a previously-derived (e.g., using `derive`) scrypt value
+/// matches the scrypt value derived from the other inputs.
+///
+/// The comparison is done in constant time to prevent timing attacks. The
+/// comparison will fail if `previously_derived` is empty (has a length of
+/// zero).
+///
+/// # Panics
+///
+/// `verify` panics if `previously_derived.len()` is larger than
+/// (2**32 - 1) * 32, or if the memory can't be allocated.
+pub fn verify(
+    params: &Params,
+    salt: &[u8],
+    secret: &[u8],
+    previously_derived: &[u8],
+) -> Result<(), error::Unspecified> {
+    if previously_derived.is_empty() {
+        return Err(error::Unspecified);
+    }
+    let mut derived = vec![0u8; previously_derived.len()];
+    derive(params, salt, secret, &mut derived);
+    constant_time::verify_slices_are_equal(&derived, previously_derived)
+}
+
+const ONE: NonZeroU32 = NonZeroU32::new(1).unwrap();
+
+/// RFC 7914 Section 5: `scryptROMix`, on the words of a block of length
+/// `128 * r`. `v` has room for `N` such blocks.
+fn ro_mix(x: &mut [u32], v: &mut [u32], scratch: &mut [u32], log_n: u8) {
+    let block_words = x.len();
+    for v in v.chunks_exact_mut(block_words) {
+        v.copy_from_slice(x);
+        block_mix(x, scratch);
+    }
+    let n_mask = (1u64 << log_n) - 1;
+    for _ in 0..(1u64 << log_n) {
+        let j = (integerify(x) & n_mask) as usize;
+        let v = &v[(j * block_words)..][..block_words];
+        x.iter_mut().zip(v.iter()).for_each(|(x, v)| *x ^= v);
+        block_mix(x, scratch);
+    }
+}
+
+/// The low 64 bits of the first 64-byte chunk of the last 128-byte block.
+fn integerify(x: &[u32]) -> u64 {
+    let last = x.len() - 16;
+    u64::from(x[last]) | (u64::from(x[last + 1]) << 32)
+}
+
+/// RFC 7914 Section 4: `scryptBlockMix`, using `y` as scratch space.
+fn block_mix(b: &mut [u32], y: &mut [u32]) {
+    let mut x: [u32; 16] = b[(b.len() - 16)..].try_into().unwrap();
+    let half = b.len() / 2;
+    for (i, b) in b.chunks_exact(16).enumerate() {
+        x.iter_mut().zip(b.iter()).for_each(|(x, b)| *x ^= b);
+        salsa20_8(&mut x);
+        // The even-numbered chunks go in the first half of the output and
+        // the odd-numbered chunks in the second half.
+        let out = (i / 2) * 16 + (i % 2) * half;
+        y[out..][..16].copy_from_slice(&x);
+    }
+    b.copy_from_slice(y);
+}
+
+/// RFC 7914 Section 3: the Salsa20/8 core.
+fn salsa20_8(b: &mut [u32; 16]) {
+    let mut x = *b;
+    macro_rules! quarter_round {
+        ( $a:expr, $b:expr, $c:expr, $d:expr ) => {
+            x[$b] ^= x[$a].wrapping_add(x[$d]).rotate_left(7);
+            x[$c] ^= x[$b].wrapping_add(x[$a]).rotate_left(9);
+            x[$d] ^= x[$c].wrapping_add(x[$b]).rotate_left(13);
+            x[$a] ^= x[$d].wrapping_add(x[$c]).rotate_left(18);
+        };
+    }
+    for _ in 0..4 {
+        // Columns.
+        quarter_round!(0, 4, 8, 12);
+        quarter_round!(5, 9, 13, 1);
+        quarter_round!(10, 14, 2, 6);
+        quarter_round!(15, 3, 7, 11);
+        // Rows.
+        quarter_round!(0, 1, 2, 3);
+        quarter_round!(5, 6, 7, 4);
+        quarter_round!(10, 11, 8, 9);
+        quarter_round!(15, 12, 13, 14);
+    }
+    b.iter_mut()
+        .zip(x.iter())
+        .for_each(|(b, x)| *b = b.wrapping_add(*x));
+}
@@ -26,11 +26,15 @@
 //! reduce the risks of algorithm agility and to provide consistency with ECDSA
 //! and EdDSA.
 //!
-//! Currently this module does not support digesting the message to be signed
-//! separately from the public key operation, as it is currently being
-//! optimized for Ed25519 and for the implementation of protocols that do not
-//! requiring signing large messages. An interface for efficiently supporting
-//! larger messages may be added later.
+//! ECDSA, RSA, and Ed25519ph signatures can also be made and verified over a
+//! digest of the message that was calculated separately, e.g. incrementally
+//! with a `digest::Context` so that a large message never has to be in memory
+//! all at once. See `EcdsaKeyPair::sign_digest`, `RsaKeyPair::sign_digest`,
+//! `Ed25519KeyPair::sign_prehashed`, and `UnparsedPublicKey::verify_digest`.
+//! `EcdsaKeyPair::signing_context` and `RsaKeyPair::signing_context` do the
+//! incremental digesting for the signer. `digest::Digest::from_bytes` imports
+//! a digest calculated elsewhere. EdDSA signatures other than Ed25519ph always
+//! cover the whole message.
 //!
 //!
 //! # Algorithm Details
@@ -56,8 +60,9 @@
 //!
 //! The signature is *r*||*s*, where || denotes concatenation, and where both
 //! *r* and *s* are both big-endian-encoded values that are left-padded to the
-//! maximum length. A P-256 signature will be 64 bytes long (two 32-byte
-//! components) and a P-384 signature will be 96 bytes long (two 48-byte
+//! maximum length. A P-256 or secp256k1 signature will be 64 bytes long (two
+//! 32-byte components), a P-384 signature will be 96 bytes long (two 48-byte
+//! components), and a P-521 signature will be 132 bytes long (two 66-byte
 //! components). This is the form of ECDSA signature used PKCS#11 and DNSSEC.
 //!
 //! The public key is encoding in uncompressed form using the
@@ -71,6 +76,66 @@
 //! in the NSA guide, ECC Partial Public-Key Validation is equivalent to ECC
 //! Full Public-Key Validation for prime-order curves like this one.
 //!
+//! `ecdsa_asn1_to_fixed` and `ecdsa_fixed_to_asn1` convert signatures between
+//! the `ECDSA_*_ASN1` and `ECDSA_*_FIXED` encodings.
+//!
+//! `ecdsa_verify_batch` verifies many signatures at once, which is faster
+//! than verifying them one at a time when many of them share a public key.
+//!
+//! ## `ECDSA_*_LOW_S` Details: Non-malleable ECDSA Signatures
+//!
+//! If (*r*, *s*) is a valid ECDSA signature then so is (*r*, *n* - *s*), so
+//! anybody can change a signature without invalidating it. Systems that
+//! identify signed data by a hash that covers the signature, such as Bitcoin
+//! transactions, need to prevent that.
+//!
+//! The `ECDSA_*_LOW_S_SIGNING` algorithms produce signatures where *s* is at
+//! most *n* / 2, replacing *s* with *n* - *s* when necessary; these are valid
+//! signatures for every verification algorithm that uses the same encoding.
+//! The `ECDSA_*_LOW_S` verification algorithms reject signatures where *s* is
+//! greater than *n* / 2. `EcdsaVerificationAlgorithm::normalize_s` converts an
+//! existing signature to the low-*s* form.
+//!
+//! ## `ML_DSA_*` Details: ML-DSA Signatures
+//!
+//! ML-DSA is the post-quantum signature algorithm of [FIPS 204]. The public
+//! key and the signature are encoded as in FIPS 204 Algorithms 22 and 26.
+//! ML-DSA-44, ML-DSA-65, and ML-DSA-87 have 1312-, 1952-, and 2592-byte
+//! public keys and 2420-, 3309-, and 4627-byte signatures.
+//!
+//! Since the signatures are so large, `MlDsaKeyPair::sign` writes them into
+//! a buffer supplied by the caller instead of returning a `Signature`.
+//! Signing is hedged, and only the "pure" variant of ML-DSA, not HashML-DSA,
+//! is supported. Verification through `UnparsedPublicKey` uses an empty
+//! context string; see `MlDsaVerificationAlgorithm::verify_with_context`.
+//!
+//! [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+//!
+//! ## `SLH_DSA_*` Details: SLH-DSA Signatures
+//!
+//! SLH-DSA is the stateless hash-based signature algorithm of [FIPS 205].
+//! Its security relies only on the hash function, here SHA-256, which makes
+//! it the conservative choice where hash-based signatures are mandated, e.g.
+//! for firmware signing. The public keys are 32 bytes. SLH-DSA-SHA2-128s
+//! signatures are 7856 bytes and slow to generate; SLH-DSA-SHA2-128f
+//! signatures are 17088 bytes and faster to generate.
+//!
+//! As with ML-DSA, `SlhDsaKeyPair::sign` writes the signature into a buffer
+//! supplied by the caller, signing is hedged, only the "pure" variant is
+//! supported, and `UnparsedPublicKey` uses an empty context string.
+//!
+//! [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+//!
+//! ## `BIP340_SCHNORR_SECP256K1` Details: BIP-340 Schnorr Signatures
+//!
+//! The Schnorr signatures of Bitcoin's [BIP-340], using secp256k1 and
+//! SHA-256. The public key is the 32-byte *x* coordinate of a point with an
+//! even *y* coordinate, and the signature is 64 bytes. Only verification is
+//! supported here; `ring::musig2` produces such signatures with several
+//! signers.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//!
 //! ## `RSA_PKCS1_*` Details: RSA PKCS#1 1.5 Signatures
 //!
 //! The signature is an RSASSA-PKCS1-v1_5 signature as described in
@@ -105,6 +170,13 @@
 //! Additionally, the entire salt is randomly generated separately for each
 //! signature using the secure random number generator passed to `sign()`.
 //!
+//! The `*_SALT_LEN_ZERO` and `*_SALT_LEN_MAX` variants instead use an empty
+//! salt, which makes signatures deterministic, or the longest salt that fits,
+//! `emLen - hLen - 2` bytes, which depends on the size of the key. The
+//! `*_ANY_SALT_LEN` verification algorithms accept a salt of any length, which
+//! is needed to verify signatures, such as those in some certificates, whose
+//! salt length isn't known in advance.
+//!
 //!
 //! [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
 //!     http://www.secg.org/sec1-v2.pdf
@@ -254,47 +326,123 @@
 //! # }
 //! ```
 
-use crate::{cpu, ec, error, sealed};
+use crate::{cpu, debug, digest, ec, error, pkcs8, rand, sealed};
+
+pub mod registry;
 
 pub use crate::ec::{
     curve25519::ed25519::{
         signing::Ed25519KeyPair,
-        verification::{EdDSAParameters, ED25519},
+        verification::{
+            verify_ed25519ctx, verify_ed25519ph, Ed25519phParameters, EdDSAParameters, ED25519,
+            ED25519PH, ED25519_STRICT, ED25519_ZIP215,
+        },
         ED25519_PUBLIC_KEY_LEN,
     },
+    curve448::ed448::{
+        signing::Ed448KeyPair,
+        verification::{Ed448Parameters, ED448},
+        ED448_PUBLIC_KEY_LEN,
+    },
     suite_b::ecdsa::{
+        recovery::{
+            EcdsaRecoveryAlgorithm, RecoveryId, ECDSA_P256_SHA256_ASN1_RECOVERY,
+            ECDSA_P256_SHA256_FIXED_RECOVERY, ECDSA_P384_SHA384_ASN1_RECOVERY,
+            ECDSA_P384_SHA384_FIXED_RECOVERY, ECDSA_SECP256K1_SHA256_ASN1_RECOVERY,
+            ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+        },
         signing::{
-            EcdsaKeyPair, EcdsaSigningAlgorithm, ECDSA_P256_SHA256_ASN1_SIGNING,
-            ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
-            ECDSA_P384_SHA384_FIXED_SIGNING,
+            EcdsaKeyPair, EcdsaSigningAlgorithm, EcdsaSigningContext,
+            ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING, ECDSA_P256_SHA256_ASN1_SIGNING,
+            ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING, ECDSA_P256_SHA256_FIXED_SIGNING,
+            ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
+            ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING, ECDSA_P384_SHA384_FIXED_SIGNING,
+            ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING, ECDSA_P521_SHA512_ASN1_SIGNING,
+            ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING, ECDSA_P521_SHA512_FIXED_SIGNING,
+            ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING, ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+            ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING, ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
         },
         verification::{
-            EcdsaVerificationAlgorithm, ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_FIXED,
-            ECDSA_P256_SHA384_ASN1, ECDSA_P384_SHA256_ASN1, ECDSA_P384_SHA384_ASN1,
-            ECDSA_P384_SHA384_FIXED,
+            ecdsa_asn1_to_fixed, ecdsa_fixed_to_asn1, EcdsaVerificationAlgorithm,
+            ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_LOW_S, ECDSA_P256_SHA256_FIXED,
+            ECDSA_P256_SHA256_FIXED_LOW_S, ECDSA_P256_SHA384_ASN1, ECDSA_P384_SHA256_ASN1,
+            ECDSA_P384_SHA384_ASN1, ECDSA_P384_SHA384_ASN1_LOW_S, ECDSA_P384_SHA384_FIXED,
+            ECDSA_P384_SHA384_FIXED_LOW_S, ECDSA_P521_SHA512_ASN1, ECDSA_P521_SHA512_ASN1_LOW_S,
+            ECDSA_P521_SHA512_FIXED, ECDSA_P521_SHA512_FIXED_LOW_S, ECDSA_SECP256K1_SHA256_ASN1,
+            ECDSA_SECP256K1_SHA256_ASN1_LOW_S, ECDSA_SECP256K1_SHA256_FIXED,
+            ECDSA_SECP256K1_SHA256_FIXED_LOW_S,
         },
     },
+    suite_b::schnorr::{SchnorrVerificationAlgorithm, BIP340_SCHNORR_SECP256K1},
+    suite_b::sm2::{
+        signing::{Sm2KeyPair, Sm2SigningAlgorithm, SM2_SM3_ASN1_SIGNING, SM2_SM3_FIXED_SIGNING},
+        verification::{Sm2VerificationAlgorithm, SM2_SM3_ASN1, SM2_SM3_FIXED},
+        SM2_DEFAULT_ID,
+    },
+};
+
+pub use crate::ml_dsa::{
+    signing::{
+        MlDsaKeyPair, MlDsaSigningAlgorithm, ML_DSA_44_SIGNING, ML_DSA_65_SIGNING,
+        ML_DSA_87_SIGNING,
+    },
+    verification::{MlDsaVerificationAlgorithm, ML_DSA_44, ML_DSA_65, ML_DSA_87},
+};
+
+pub use crate::slh_dsa::{
+    signing::{
+        SlhDsaKeyPair, SlhDsaSigningAlgorithm, SLH_DSA_SHA2_128F_SIGNING, SLH_DSA_SHA2_128S_SIGNING,
+    },
+    verification::{SlhDsaVerificationAlgorithm, SLH_DSA_SHA2_128F, SLH_DSA_SHA2_128S},
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::dsa::{
+    DsaParameters, DsaPublicKeyComponents, DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY,
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::ec::curve25519::ed25519::verification::{verify_batch, BatchVerificationError};
+
+#[cfg(feature = "alloc")]
+pub use crate::ec::suite_b::ecdsa::batch::ecdsa_verify_batch;
+
+#[cfg(feature = "alloc")]
+pub use crate::rsa::raw as rsa_raw;
+
+#[cfg(all(feature = "alloc", feature = "insecure_legacy_signing"))]
+pub use crate::rsa::padding::RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY;
+
 #[cfg(feature = "alloc")]
 pub use crate::rsa::{
+    keygen::{RsaKeySize, RSA_DEFAULT_PUBLIC_EXPONENT},
     padding::{
         RsaEncoding, RSA_PKCS1_SHA256, RSA_PKCS1_SHA384, RSA_PKCS1_SHA512, RSA_PSS_SHA256,
-        RSA_PSS_SHA384, RSA_PSS_SHA512,
+        RSA_PSS_SHA256_SALT_LEN_MAX, RSA_PSS_SHA256_SALT_LEN_ZERO, RSA_PSS_SHA384,
+        RSA_PSS_SHA384_SALT_LEN_MAX, RSA_PSS_SHA384_SALT_LEN_ZERO, RSA_PSS_SHA512,
+        RSA_PSS_SHA512_SALT_LEN_MAX, RSA_PSS_SHA512_SALT_LEN_ZERO,
     },
-    signing::RsaKeyPair,
     signing::RsaSubjectPublicKey,
+    signing::{RsaKeyPair, RsaSigningContext},
     verification::{
         RsaPublicKeyComponents, RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
         RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
         RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
         RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY, RSA_PKCS1_2048_8192_SHA256,
         RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512, RSA_PKCS1_3072_8192_SHA384,
-        RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512,
+        RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA256_ANY_SALT_LEN,
+        RSA_PSS_2048_8192_SHA256_SALT_LEN_MAX, RSA_PSS_2048_8192_SHA256_SALT_LEN_ZERO,
+        RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA384_ANY_SALT_LEN,
+        RSA_PSS_2048_8192_SHA384_SALT_LEN_MAX, RSA_PSS_2048_8192_SHA384_SALT_LEN_ZERO,
+        RSA_PSS_2048_8192_SHA512, RSA_PSS_2048_8192_SHA512_ANY_SALT_LEN,
+        RSA_PSS_2048_8192_SHA512_SALT_LEN_MAX, RSA_PSS_2048_8192_SHA512_SALT_LEN_ZERO,
     },
     RsaParameters,
 };
 
+#[cfg(all(feature = "interop", feature = "alloc"))]
+pub use crate::interop::{RsaSignature, RsaSigningKey};
+
 /// A public key signature returned from a signing operation.
 #[derive(Clone, Copy)]
 pub struct Signature {
@@ -332,12 +480,60 @@ pub trait KeyPair: core::fmt::Debug + Send + Sized + Sync {
     fn public_key(&self) -> &Self::PublicKey;
 }
 
-/// The longest signature is an ASN.1 P-384 signature where *r* and *s* are of
-/// maximum length with the leading high bit set on each. Then each component
-/// will have a tag, a one-byte length, and a one-byte “I'm not negative”
-/// prefix, and the outer sequence will have a two-byte length.
-pub(crate) const MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
-    (2 * (1/*tag:INTEGER*/ + 1/*len*/ + 1/*zero*/ + ec::SCALAR_MAX_BYTES));
+/// Algorithms that can generate key pairs.
+///
+/// This lets provisioning code be generic over the algorithm of the keys it
+/// generates:
+///
+/// ```
+/// use ring::{error, pkcs8, rand, signature::{self, KeyPair, KeyPairGenerator}};
+///
+/// fn provision<G: KeyPairGenerator>(
+///     alg: &'static G,
+///     rng: &dyn rand::SecureRandom,
+/// ) -> Result<(Vec<u8>, pkcs8::Document), error::Unspecified> {
+///     let (key_pair, pkcs8) = alg.generate_key_pair(rng)?;
+///     Ok((key_pair.public_key().as_ref().to_vec(), pkcs8))
+/// }
+///
+/// let rng = rand::SystemRandom::new();
+/// let (ed25519_public_key, _) = provision(&signature::ED25519, &rng)?;
+/// let (p256_public_key, _) = provision(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &rng)?;
+/// assert_eq!(ed25519_public_key.len(), 32);
+/// assert_eq!(p256_public_key.len(), 65);
+/// # Ok::<(), error::Unspecified>(())
+/// ```
+pub trait KeyPairGenerator: core::fmt::Debug + Sync {
+    /// The type of the generated key pairs.
+    type KeyPair: KeyPair;
+
+    /// Generates a new key pair and returns it together with the key pair
+    /// serialized as a PKCS#8 document.
+    ///
+    /// The PKCS#8 document is the one the key pair type's `generate_pkcs8()`
+    /// would have returned, and it can be parsed again with the key pair
+    /// type's `from_pkcs8()`.
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified>;
+}
+
+/// The longest signature is either an Ed448 signature or an ASN.1 ECDSA
+/// signature for the largest curve where *r* and *s* are of maximum length
+/// with the leading high bit set on each. Then each component will have a
+/// tag, a one-byte length, and a one-byte “I'm not negative” prefix, and the
+/// outer sequence will have a two-byte length.
+pub(crate) const MAX_LEN: usize = {
+    const ECDSA_ASN1_MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
+        (2 * (1/*tag:INTEGER*/ + 1/*len*/ + 1/*zero*/ + ec::SCALAR_MAX_BYTES));
+    const ED448_LEN: usize = ec::curve448::ed448::signing::SIGNATURE_LEN;
+    if ECDSA_ASN1_MAX_LEN > ED448_LEN {
+        ECDSA_ASN1_MAX_LEN
+    } else {
+        ED448_LEN
+    }
+};
 
 /// A signature verification algorithm.
 pub trait VerificationAlgorithm: core::fmt::Debug + Sync + sealed::Sealed {
@@ -349,6 +545,89 @@ pub trait VerificationAlgorithm: core::fmt::Debug + Sync + sealed::Sealed {
         msg: untrusted::Input,
         signature: untrusted::Input,
     ) -> Result<(), error::Unspecified>;
+
+    /// Verify the signature `signature`, with the public key `public_key`, of
+    /// the message whose digest is `digest`.
+    ///
+    /// Fails if the algorithm can't verify signatures of precomputed digests,
+    /// or if `digest` wasn't calculated using the algorithm's digest
+    /// algorithm.
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let _ = (public_key, digest, signature);
+        Err(error::Unspecified)
+    }
+
+    /// Validates `public_key` without verifying a signature.
+    ///
+    /// By default this is the validation that `parse_public_key` does, and
+    /// it fails for algorithms whose keys are only parsed during
+    /// verification.
+    #[doc(hidden)]
+    fn validate_public_key(&self, public_key: untrusted::Input) -> Result<(), error::KeyRejected> {
+        match self.parse_public_key(public_key)? {
+            parsed::PublicKey::Unparsed => Err(error::KeyRejected::wrong_algorithm()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses and validates `public_key`, doing any precomputation that
+    /// `verify_parsed` and `verify_digest_parsed` can reuse.
+    #[doc(hidden)]
+    fn parse_public_key(
+        &self,
+        public_key: untrusted::Input,
+    ) -> Result<parsed::PublicKey, error::KeyRejected> {
+        let _ = public_key;
+        Ok(parsed::PublicKey::Unparsed)
+    }
+
+    /// Like `verify`, but uses `public_key`, the result of
+    /// `parse_public_key(public_key_bytes)`, instead of parsing the key.
+    #[doc(hidden)]
+    fn verify_parsed(
+        &self,
+        public_key: &parsed::PublicKey,
+        public_key_bytes: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let _ = public_key;
+        self.verify(public_key_bytes, msg, signature)
+    }
+
+    /// Like `verify_digest`, but uses `public_key`, the result of
+    /// `parse_public_key(public_key_bytes)`, instead of parsing the key.
+    #[doc(hidden)]
+    fn verify_digest_parsed(
+        &self,
+        public_key: &parsed::PublicKey,
+        public_key_bytes: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let _ = public_key;
+        self.verify_digest(public_key_bytes, digest, signature)
+    }
+}
+
+pub(crate) mod parsed {
+    use crate::ec;
+
+    /// The result of `VerificationAlgorithm::parse_public_key`.
+    pub enum PublicKey {
+        /// The algorithm has nothing to precompute; the key is parsed each
+        /// time it is used.
+        Unparsed,
+        Ecdsa(ec::suite_b::ecdsa::verification::ParsedPublicKey),
+        Ed25519(ec::curve25519::ed25519::verification::ParsedPublicKey),
+        #[cfg(feature = "alloc")]
+        Rsa(crate::rsa::public::Key),
+    }
 }
 
 /// An unparsed, possibly malformed, public key for signature verification.
@@ -374,12 +653,30 @@ where
 impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
     /// Construct a new `UnparsedPublicKey`.
     ///
-    /// No validation of `bytes` is done until `verify()` is called.
+    /// No validation of `bytes` is done until `verify()` is called. Use
+    /// `ParsedPublicKey` instead to validate the key once when it will be
+    /// used to verify many signatures.
     #[inline]
     pub fn new(algorithm: &'static dyn VerificationAlgorithm, bytes: B) -> Self {
         Self { algorithm, bytes }
     }
 
+    /// Checks that the public key is valid for the algorithm, without
+    /// verifying a signature, e.g. to reject bad keys when they are
+    /// registered instead of when they are first used.
+    ///
+    /// ECDSA and SM2 public keys must be correctly-encoded points on the
+    /// curve; the point at infinity can't be encoded. Ed25519 public keys
+    /// must be canonically-encoded points that are not of small order, which
+    /// also excludes the identity. RSA public keys get the same checks that
+    /// verification does. This fails for the other algorithms, which can't
+    /// validate a public key separately from verifying a signature.
+    pub fn validate(&self) -> Result<(), error::KeyRejected> {
+        let _ = cpu::features();
+        self.algorithm
+            .validate_public_key(untrusted::Input::from(self.bytes.as_ref()))
+    }
+
     /// Parses the public key and verifies `signature` is a valid signature of
     /// `message` using it.
     ///
@@ -392,4 +689,110 @@ impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
             untrusted::Input::from(signature),
         )
     }
+
+    /// Parses the public key and verifies `signature` is a valid signature,
+    /// using it, of the message whose digest is `digest`.
+    ///
+    /// This is for callers that have already digested the message. Only
+    /// ECDSA, RSA, and Ed25519ph signatures can be verified this way, and
+    /// only when `digest` was calculated using the verification algorithm's
+    /// digest algorithm.
+    pub fn verify_digest(
+        &self,
+        digest: &digest::Digest,
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let _ = cpu::features();
+        self.algorithm.verify_digest(
+            untrusted::Input::from(self.bytes.as_ref()),
+            digest,
+            untrusted::Input::from(signature),
+        )
+    }
+}
+
+/// A public key for signature verification that has already been parsed and
+/// validated.
+///
+/// `UnparsedPublicKey::verify()` parses and validates the public key every
+/// time it is called. `ParsedPublicKey::new()` does that once, along with any
+/// precomputation that depends only on the key, so that verifying many
+/// signatures with the same key is faster: ECDSA public keys are checked to
+/// be on the curve, Ed25519 public keys are decompressed, and RSA public keys
+/// are validated and their Montgomery constants are computed. Keys for the
+/// other algorithms are still parsed during each verification.
+pub struct ParsedPublicKey<B: AsRef<[u8]>> {
+    algorithm: &'static dyn VerificationAlgorithm,
+    bytes: B,
+    parsed: parsed::PublicKey,
+}
+
+impl<B: AsRef<[u8]>> ParsedPublicKey<B> {
+    /// Parses and validates the public key `bytes` for use with `algorithm`.
+    ///
+    /// `bytes` is in the same format that `UnparsedPublicKey` accepts for
+    /// `algorithm`.
+    pub fn new(
+        algorithm: &'static dyn VerificationAlgorithm,
+        bytes: B,
+    ) -> Result<Self, error::KeyRejected> {
+        let _ = cpu::features();
+        let parsed = algorithm.parse_public_key(untrusted::Input::from(bytes.as_ref()))?;
+        Ok(Self {
+            algorithm,
+            bytes,
+            parsed,
+        })
+    }
+
+    /// The verification algorithm the key was parsed for.
+    #[inline]
+    pub fn algorithm(&self) -> &'static dyn VerificationAlgorithm {
+        self.algorithm
+    }
+
+    /// Verifies `signature` is a valid signature of `message` using the
+    /// public key.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), error::Unspecified> {
+        let _ = cpu::features();
+        self.algorithm.verify_parsed(
+            &self.parsed,
+            untrusted::Input::from(self.bytes.as_ref()),
+            untrusted::Input::from(message),
+            untrusted::Input::from(signature),
+        )
+    }
+
+    /// Verifies `signature` is a valid signature, using the public key, of
+    /// the message whose digest is `digest`.
+    ///
+    /// See `UnparsedPublicKey::verify_digest()`.
+    pub fn verify_digest(
+        &self,
+        digest: &digest::Digest,
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let _ = cpu::features();
+        self.algorithm.verify_digest_parsed(
+            &self.parsed,
+            untrusted::Input::from(self.bytes.as_ref()),
+            digest,
+            untrusted::Input::from(signature),
+        )
+    }
+}
+
+impl<B: AsRef<[u8]>> AsRef<[u8]> for ParsedPublicKey<B> {
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+}
+
+impl<B: AsRef<[u8]>> core::fmt::Debug for ParsedPublicKey<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("ParsedPublicKey")
+            .field("algorithm", &self.algorithm)
+            .field("bytes", &debug::HexStr(self.bytes.as_ref()))
+            .finish()
+    }
 }
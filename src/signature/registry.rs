@@ -0,0 +1,277 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Lookup of signature algorithms by their JOSE, COSE, and X.509 identifiers.
+//!
+//! Each [`Algorithm`] ties together the identifiers that the JOSE ([RFC 7518],
+//! [RFC 8037], [RFC 8812], [RFC 9864]) and COSE ([RFC 9053], [RFC 8812],
+//! [RFC 9864]) registries assign to a signature algorithm, the OID used for it
+//! in X.509 `AlgorithmIdentifier`s when there is one, and the *ring* objects
+//! for verifying and producing signatures with it.
+//!
+//! ```
+//! use ring::signature::{self, registry};
+//!
+//! let alg = registry::Algorithm::from_jose_name("ES256").unwrap();
+//! assert_eq!(alg.cose_id(), -7);
+//! assert!(matches!(
+//!     alg.signing_algorithm(),
+//!     registry::SigningAlgorithm::Ecdsa(signing_alg)
+//!         if core::ptr::eq(signing_alg, &signature::ECDSA_P256_SHA256_FIXED_SIGNING)
+//! ));
+//!
+//! let public_key_bytes = [0u8; 65];
+//! let _public_key =
+//!     signature::UnparsedPublicKey::new(alg.verification_algorithm(), &public_key_bytes);
+//! ```
+//!
+//! `EdDSA` (COSE -8) identifies EdDSA with whatever curve the key is for; it
+//! is mapped to Ed25519, which is the only curve it is used with in practice.
+//! Keys for Ed448 must use the fully-specified `Ed448` identifier.
+//!
+//! The OIDs for ECDSA signatures don't identify the curve, and
+//! `id-RSASSA-PSS` needs parameters to identify the digest algorithm, so
+//! lookup by OID isn't supported for those algorithms.
+//!
+//! Only the algorithms that *ring* implements are registered; in particular
+//! no algorithms using SHA-1 are.
+//!
+//! [RFC 7518]: https://tools.ietf.org/html/rfc7518
+//! [RFC 8037]: https://tools.ietf.org/html/rfc8037
+//! [RFC 8812]: https://tools.ietf.org/html/rfc8812
+//! [RFC 9053]: https://tools.ietf.org/html/rfc9053
+//! [RFC 9864]: https://www.rfc-editor.org/rfc/rfc9864
+
+use super::{
+    EcdsaSigningAlgorithm, MlDsaSigningAlgorithm, VerificationAlgorithm, ECDSA_P256_SHA256_FIXED,
+    ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_FIXED, ECDSA_P384_SHA384_FIXED_SIGNING,
+    ECDSA_P521_SHA512_FIXED, ECDSA_P521_SHA512_FIXED_SIGNING, ECDSA_SECP256K1_SHA256_FIXED,
+    ECDSA_SECP256K1_SHA256_FIXED_SIGNING, ED25519, ED448, ML_DSA_44, ML_DSA_44_SIGNING, ML_DSA_65,
+    ML_DSA_65_SIGNING, ML_DSA_87, ML_DSA_87_SIGNING,
+};
+
+#[cfg(feature = "alloc")]
+use super::{
+    RsaEncoding, RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_2048_8192_SHA384,
+    RSA_PKCS1_2048_8192_SHA512, RSA_PKCS1_SHA256, RSA_PKCS1_SHA384, RSA_PKCS1_SHA512,
+    RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512, RSA_PSS_SHA256,
+    RSA_PSS_SHA384, RSA_PSS_SHA512,
+};
+
+/// A registered signature algorithm.
+pub struct Algorithm {
+    jose_name: &'static str,
+    cose_id: i64,
+    oid: Option<&'static [u8]>,
+    verification_algorithm: &'static dyn VerificationAlgorithm,
+    signing_algorithm: SigningAlgorithm,
+}
+
+derive_debug_via_field!(Algorithm, jose_name);
+
+impl Algorithm {
+    /// Finds the algorithm with the JOSE `alg` name `jose_name`, e.g. `"ES256"`.
+    pub fn from_jose_name(jose_name: &str) -> Option<&'static Self> {
+        ALGORITHMS.iter().find(|alg| alg.jose_name == jose_name)
+    }
+
+    /// Finds the algorithm with the COSE algorithm identifier `cose_id`, e.g.
+    /// `-7`.
+    pub fn from_cose_id(cose_id: i64) -> Option<&'static Self> {
+        ALGORITHMS.iter().find(|alg| alg.cose_id == cose_id)
+    }
+
+    /// Finds the algorithm identified by the OID `oid` in an X.509
+    /// `AlgorithmIdentifier`. `oid` is the value of the DER-encoded OID,
+    /// without the tag and length.
+    pub fn from_oid(oid: &[u8]) -> Option<&'static Self> {
+        ALGORITHMS.iter().find(|alg| alg.oid == Some(oid))
+    }
+
+    /// The JOSE `alg` name of the algorithm.
+    pub fn jose_name(&self) -> &'static str {
+        self.jose_name
+    }
+
+    /// The COSE algorithm identifier of the algorithm.
+    pub fn cose_id(&self) -> i64 {
+        self.cose_id
+    }
+
+    /// The value of the DER-encoded OID of the algorithm, if it can be
+    /// identified by an OID alone.
+    pub fn oid(&self) -> Option<&'static [u8]> {
+        self.oid
+    }
+
+    /// The algorithm for verifying signatures. The signature encoding is the
+    /// one JOSE and COSE use, e.g. the fixed-length encoding for ECDSA.
+    pub fn verification_algorithm(&self) -> &'static dyn VerificationAlgorithm {
+        self.verification_algorithm
+    }
+
+    /// The algorithm for producing signatures.
+    pub fn signing_algorithm(&self) -> SigningAlgorithm {
+        self.signing_algorithm
+    }
+}
+
+/// How to produce signatures for an [`Algorithm`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum SigningAlgorithm {
+    /// Sign with an `EcdsaKeyPair` for the given algorithm.
+    Ecdsa(&'static EcdsaSigningAlgorithm),
+
+    /// Sign with an `Ed25519KeyPair`.
+    Ed25519,
+
+    /// Sign with an `Ed448KeyPair`.
+    Ed448,
+
+    /// Sign with an `MlDsaKeyPair` for the given algorithm.
+    MlDsa(&'static MlDsaSigningAlgorithm),
+
+    /// Sign with an `RsaKeyPair` using the given padding.
+    #[cfg(feature = "alloc")]
+    Rsa(&'static dyn RsaEncoding),
+}
+
+/// Every registered algorithm.
+pub static ALGORITHMS: &[Algorithm] = &[
+    Algorithm {
+        jose_name: "ES256",
+        cose_id: -7,
+        oid: None,
+        verification_algorithm: &ECDSA_P256_SHA256_FIXED,
+        signing_algorithm: SigningAlgorithm::Ecdsa(&ECDSA_P256_SHA256_FIXED_SIGNING),
+    },
+    Algorithm {
+        jose_name: "ES384",
+        cose_id: -35,
+        oid: None,
+        verification_algorithm: &ECDSA_P384_SHA384_FIXED,
+        signing_algorithm: SigningAlgorithm::Ecdsa(&ECDSA_P384_SHA384_FIXED_SIGNING),
+    },
+    Algorithm {
+        jose_name: "ES512",
+        cose_id: -36,
+        oid: None,
+        verification_algorithm: &ECDSA_P521_SHA512_FIXED,
+        signing_algorithm: SigningAlgorithm::Ecdsa(&ECDSA_P521_SHA512_FIXED_SIGNING),
+    },
+    Algorithm {
+        jose_name: "ES256K",
+        cose_id: -47,
+        oid: None,
+        verification_algorithm: &ECDSA_SECP256K1_SHA256_FIXED,
+        signing_algorithm: SigningAlgorithm::Ecdsa(&ECDSA_SECP256K1_SHA256_FIXED_SIGNING),
+    },
+    Algorithm {
+        jose_name: "EdDSA",
+        cose_id: -8,
+        oid: None,
+        verification_algorithm: &ED25519,
+        signing_algorithm: SigningAlgorithm::Ed25519,
+    },
+    Algorithm {
+        jose_name: "Ed25519",
+        cose_id: -19,
+        oid: Some(&[0x2b, 0x65, 0x70]), // 1.3.101.112
+        verification_algorithm: &ED25519,
+        signing_algorithm: SigningAlgorithm::Ed25519,
+    },
+    Algorithm {
+        jose_name: "Ed448",
+        cose_id: -53,
+        oid: Some(&[0x2b, 0x65, 0x71]), // 1.3.101.113
+        verification_algorithm: &ED448,
+        signing_algorithm: SigningAlgorithm::Ed448,
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "RS256",
+        cose_id: -257,
+        // 1.2.840.113549.1.1.11
+        oid: Some(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b]),
+        verification_algorithm: &RSA_PKCS1_2048_8192_SHA256,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PKCS1_SHA256),
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "RS384",
+        cose_id: -258,
+        // 1.2.840.113549.1.1.12
+        oid: Some(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c]),
+        verification_algorithm: &RSA_PKCS1_2048_8192_SHA384,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PKCS1_SHA384),
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "RS512",
+        cose_id: -259,
+        // 1.2.840.113549.1.1.13
+        oid: Some(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d]),
+        verification_algorithm: &RSA_PKCS1_2048_8192_SHA512,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PKCS1_SHA512),
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "PS256",
+        cose_id: -37,
+        oid: None,
+        verification_algorithm: &RSA_PSS_2048_8192_SHA256,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PSS_SHA256),
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "PS384",
+        cose_id: -38,
+        oid: None,
+        verification_algorithm: &RSA_PSS_2048_8192_SHA384,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PSS_SHA384),
+    },
+    #[cfg(feature = "alloc")]
+    Algorithm {
+        jose_name: "PS512",
+        cose_id: -39,
+        oid: None,
+        verification_algorithm: &RSA_PSS_2048_8192_SHA512,
+        signing_algorithm: SigningAlgorithm::Rsa(&RSA_PSS_SHA512),
+    },
+    Algorithm {
+        jose_name: "ML-DSA-44",
+        cose_id: -48,
+        // 2.16.840.1.101.3.4.3.17
+        oid: Some(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x11]),
+        verification_algorithm: &ML_DSA_44,
+        signing_algorithm: SigningAlgorithm::MlDsa(&ML_DSA_44_SIGNING),
+    },
+    Algorithm {
+        jose_name: "ML-DSA-65",
+        cose_id: -49,
+        // 2.16.840.1.101.3.4.3.18
+        oid: Some(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x12]),
+        verification_algorithm: &ML_DSA_65,
+        signing_algorithm: SigningAlgorithm::MlDsa(&ML_DSA_65_SIGNING),
+    },
+    Algorithm {
+        jose_name: "ML-DSA-87",
+        cose_id: -50,
+        // 2.16.840.1.101.3.4.3.19
+        oid: Some(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x13]),
+        verification_algorithm: &ML_DSA_87,
+        signing_algorithm: SigningAlgorithm::MlDsa(&ML_DSA_87_SIGNING),
+    },
+];
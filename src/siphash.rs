@@ -0,0 +1,309 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SipHash-2-4 and SipHash-1-3.
+//!
+//! [SipHash] is a fast keyed hash for short inputs, designed to protect hash
+//! tables against hash-flooding attacks. It can also serve as a MAC with a
+//! 64-bit tag where that is enough, e.g. for authenticating in-memory data
+//! structures; for anything else, use `hmac`.
+//!
+//! The API mirrors that of `hmac`: use `sign` and `verify` when the input is
+//! in one contiguous slice, and `Context` otherwise. A `Key` is also a
+//! `core::hash::BuildHasher`, so it can be used to key a `HashMap`.
+//!
+//! ```
+//! use ring::siphash;
+//!
+//! let key = siphash::Key::new(&siphash::SIPHASH_2_4, &[1; siphash::KEY_LEN]).unwrap();
+//! let tag = siphash::sign(&key, b"hello, world");
+//!
+//! let mut ctx = siphash::Context::with_key(&key);
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! assert_eq!(ctx.sign().as_ref(), tag.as_ref());
+//!
+//! siphash::verify(&key, b"hello, world", tag.as_ref()).unwrap();
+//! ```
+//!
+//! SipHash-1-3 does fewer rounds. It is what Rust's standard library uses for
+//! `HashMap`, where speed matters more than a security margin.
+//!
+//! [SipHash]: https://www.aumasson.jp/siphash/siphash.pdf
+
+use crate::{constant_time, error, polyfill};
+use core::convert::TryInto;
+
+/// The length of a SipHash key.
+pub const KEY_LEN: usize = 16;
+
+/// The length of a SipHash tag.
+pub const TAG_LEN: usize = 8;
+
+/// A SipHash algorithm.
+pub struct Algorithm {
+    c_rounds: usize,
+    d_rounds: usize,
+    id: AlgorithmID,
+}
+
+derive_debug_via_id!(Algorithm);
+
+#[derive(Debug, Eq, PartialEq)]
+enum AlgorithmID {
+    SIPHASH_2_4,
+    SIPHASH_1_3,
+}
+
+impl PartialEq for Algorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Algorithm {}
+
+/// SipHash-2-4: two compression rounds per word and four finalization
+/// rounds.
+pub static SIPHASH_2_4: Algorithm = Algorithm {
+    c_rounds: 2,
+    d_rounds: 4,
+    id: AlgorithmID::SIPHASH_2_4,
+};
+
+/// SipHash-1-3: one compression round per word and three finalization
+/// rounds.
+pub static SIPHASH_1_3: Algorithm = Algorithm {
+    c_rounds: 1,
+    d_rounds: 3,
+    id: AlgorithmID::SIPHASH_1_3,
+};
+
+/// A SipHash tag: the 64-bit output, as little-endian bytes.
+///
+/// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct Tag([u8; TAG_LEN]);
+
+impl AsRef<[u8]> for Tag {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A key to use for SipHash.
+#[derive(Clone)]
+pub struct Key {
+    k0: u64,
+    k1: u64,
+    algorithm: &'static Algorithm,
+}
+
+impl core::fmt::Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+impl Key {
+    /// Constructs a SipHash key.
+    ///
+    /// `key_value` must be exactly `KEY_LEN` bytes long.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_value: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if key_value.len() != KEY_LEN {
+            return Err(error::Unspecified);
+        }
+        let (k0, k1) = key_value.split_at(KEY_LEN / 2);
+        Ok(Self {
+            k0: u64::from_le_bytes(k0.try_into().unwrap()),
+            k1: u64::from_le_bytes(k1.try_into().unwrap()),
+            algorithm,
+        })
+    }
+
+    /// The key's algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+/// Hashing with a `Key` as a `BuildHasher` computes the SipHash, as a
+/// little-endian `u64`, of the data the `Hash` implementation writes.
+impl core::hash::BuildHasher for Key {
+    type Hasher = Context;
+
+    #[inline]
+    fn build_hasher(&self) -> Context {
+        Context::with_key(self)
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) SipHash signing.
+///
+/// Use `sign` for single-step SipHash signing.
+#[derive(Clone)]
+pub struct Context {
+    v: [u64; 4],
+    /// The input that doesn't fill a whole word yet, in the low bytes.
+    pending: u64,
+    num_pending: usize,
+    /// The length of the input so far, modulo 2**64.
+    len: u64,
+    algorithm: &'static Algorithm,
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Context")
+            .field("algorithm", self.algorithm)
+            .finish()
+    }
+}
+
+impl Context {
+    /// Constructs a new SipHash signing context using the given key.
+    pub fn with_key(key: &Key) -> Self {
+        Self {
+            v: [
+                key.k0 ^ 0x736f_6d65_7073_6575,
+                key.k1 ^ 0x646f_7261_6e64_6f6d,
+                key.k0 ^ 0x6c79_6765_6e65_7261,
+                key.k1 ^ 0x7465_6462_7974_6573,
+            ],
+            pending: 0,
+            num_pending: 0,
+            len: 0,
+            algorithm: key.algorithm,
+        }
+    }
+
+    /// Updates the SipHash with all the data in `data`. `update` may be
+    /// called zero or more times until `sign` is called.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(polyfill::u64_from_usize(data.len()));
+
+        if self.num_pending > 0 {
+            let n = core::cmp::min(data.len(), 8 - self.num_pending);
+            let (head, rest) = data.split_at(n);
+            for (i, b) in head.iter().enumerate() {
+                self.pending |= u64::from(*b) << (8 * (self.num_pending + i));
+            }
+            self.num_pending += n;
+            data = rest;
+            if self.num_pending < 8 {
+                return;
+            }
+            let word = self.pending;
+            self.compress(word);
+            self.pending = 0;
+            self.num_pending = 0;
+        }
+
+        let words = data.chunks_exact(8);
+        let remainder = words.remainder();
+        for word in words {
+            self.compress(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        for (i, b) in remainder.iter().enumerate() {
+            self.pending |= u64::from(*b) << (8 * i);
+        }
+        self.num_pending = remainder.len();
+    }
+
+    /// Finalizes the SipHash calculation and returns the tag. `sign`
+    /// consumes the context so it cannot be (mis-)used after `sign` has been
+    /// called.
+    ///
+    /// It is generally not safe to implement SipHash verification by
+    /// comparing the return value of `sign` to a tag. Use `verify` for
+    /// verification instead.
+    pub fn sign(mut self) -> Tag {
+        // The last word holds the remaining bytes and the length modulo 256.
+        let word = self.pending | (self.len << 56);
+        self.compress(word);
+        self.v[2] ^= 0xff;
+        for _ in 0..self.algorithm.d_rounds {
+            sip_round(&mut self.v);
+        }
+        let v = self.v;
+        Tag((v[0] ^ v[1] ^ v[2] ^ v[3]).to_le_bytes())
+    }
+
+    /// The algorithm for the context.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v[3] ^= word;
+        for _ in 0..self.algorithm.c_rounds {
+            sip_round(&mut self.v);
+        }
+        self.v[0] ^= word;
+    }
+}
+
+impl core::hash::Hasher for Context {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        u64::from_le_bytes(self.clone().sign().0)
+    }
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+/// Calculates the SipHash of `data` using the key `key` in one step.
+///
+/// Use `Context` to calculate SipHashes where the input is in multiple parts.
+///
+/// It is generally not safe to implement SipHash verification by comparing
+/// the return value of `sign` to a tag. Use `verify` for verification
+/// instead.
+pub fn sign(key: &Key, data: &[u8]) -> Tag {
+    let mut ctx = Context::with_key(key);
+    ctx.update(data);
+    ctx.sign()
+}
+
+/// Calculates the SipHash of `data` using the key `key`, and verifies
+/// whether the resultant value equals `tag`, in one step.
+///
+/// The verification will be done in constant time to prevent timing attacks.
+pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
+}
@@ -0,0 +1,675 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! SLH-DSA signatures, as specified in [FIPS 205].
+//!
+//! Only the SHA2-128s and SHA2-128f parameter sets are implemented. Both use
+//! SHA-256 for every hash function (FIPS 205 Section 11.2.1).
+//!
+//! [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+
+use crate::{digest, error, hmac};
+
+pub mod signing;
+pub mod verification;
+
+/// The parameters of one of the SLH-DSA parameter sets of FIPS 205 Section
+/// 11 that use n = 16 and lg_w = 4.
+pub(crate) struct Parameters {
+    h: usize,
+    d: usize,
+    hp: usize,
+    a: usize,
+    k: usize,
+    m: usize,
+
+    // The value of the OID that NIST assigned to the parameter set.
+    oid: &'static [u8],
+}
+
+impl Parameters {
+    pub(crate) fn signature_len(&self) -> usize {
+        N + self.fors_signature_len() + self.d * self.xmss_signature_len()
+    }
+
+    fn fors_signature_len(&self) -> usize {
+        self.k * (1 + self.a) * N
+    }
+
+    fn xmss_signature_len(&self) -> usize {
+        (LEN + self.hp) * N
+    }
+}
+
+pub(crate) static SLH_DSA_SHA2_128S_PARAMETERS: Parameters = Parameters {
+    h: 63,
+    d: 7,
+    hp: 9,
+    a: 12,
+    k: 14,
+    m: 30,
+    oid: &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x14],
+};
+
+pub(crate) static SLH_DSA_SHA2_128F_PARAMETERS: Parameters = Parameters {
+    h: 66,
+    d: 22,
+    hp: 3,
+    a: 6,
+    k: 33,
+    m: 34,
+    oid: &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x15],
+};
+
+const N: usize = 16;
+const LG_W: usize = 4;
+const W: usize = 1 << LG_W;
+const LEN1: usize = 8 * N / LG_W;
+const LEN2: usize = 3;
+const LEN: usize = LEN1 + LEN2;
+
+const MAX_K: usize = 33;
+const MAX_M: usize = 34;
+
+pub(crate) const SEED_LEN: usize = 3 * N;
+pub(crate) const PUBLIC_KEY_LEN: usize = 2 * N;
+pub(crate) const PRIVATE_KEY_LEN: usize = 4 * N;
+
+const CONTEXT_MAX_LEN: usize = 255;
+
+fn check_context(context: &[u8]) -> Result<(), error::Unspecified> {
+    if context.len() > CONTEXT_MAX_LEN {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// FIPS 205 Algorithm 18, slh_keygen_internal. `seed` is
+/// SK.seed || SK.prf || PK.seed. Returns the private key
+/// SK.seed || SK.prf || PK.seed || PK.root.
+fn key_gen(p: &Parameters, seed: &[u8; SEED_LEN]) -> [u8; PRIVATE_KEY_LEN] {
+    let mut sk = [0; PRIVATE_KEY_LEN];
+    sk[..SEED_LEN].copy_from_slice(seed);
+    let root = root(p, &seed[..N], &seed[2 * N..]);
+    sk[SEED_LEN..].copy_from_slice(&root);
+    sk
+}
+
+/// Computes PK.root, the root of the top XMSS tree of the hypertree.
+fn root(p: &Parameters, sk_seed: &[u8], pk_seed: &[u8]) -> [u8; N] {
+    let hasher = Hasher::new(pk_seed);
+    let mut adrs = Address::new();
+    adrs.set_layer(p.d - 1);
+    xmss_node(&hasher, sk_seed, 0, p.hp, &mut adrs)
+}
+
+/// FIPS 205 Algorithms 23 and 19, the pure slh_sign and slh_sign_internal.
+/// `signature` must be exactly `p.signature_len()` bytes long.
+fn sign(
+    p: &Parameters,
+    sk: &[u8; PRIVATE_KEY_LEN],
+    context: &[u8],
+    msg: &[u8],
+    opt_rand: &[u8; N],
+    signature: &mut [u8],
+) {
+    let (sk_seed, rest) = sk.split_at(N);
+    let (sk_prf, pk) = rest.split_at(N);
+    let (pk_seed, pk_root) = pk.split_at(N);
+    let hasher = Hasher::new(pk_seed);
+
+    let (r, signature) = signature.split_at_mut(N);
+    let (fors_signature, ht_signature) = signature.split_at_mut(p.fors_signature_len());
+
+    r.copy_from_slice(&prf_msg(sk_prf, opt_rand, context, msg));
+    let digest = h_msg(p, r, pk_seed, pk_root, context, msg);
+    let (md, idx_tree, idx_leaf) = split_digest(p, &digest);
+
+    let mut adrs = Address::new();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(AddressType::ForsTree);
+    adrs.set_key_pair(idx_leaf);
+    fors_sign(&hasher, p, md, sk_seed, &mut adrs, fors_signature);
+    let pk_fors = fors_pk_from_signature(&hasher, p, fors_signature, md, &mut adrs);
+
+    ht_sign(
+        &hasher,
+        p,
+        &pk_fors,
+        sk_seed,
+        idx_tree,
+        idx_leaf,
+        ht_signature,
+    );
+}
+
+/// FIPS 205 Algorithms 24 and 20, the pure slh_verify and
+/// slh_verify_internal.
+fn verify(
+    p: &Parameters,
+    pk: &[u8],
+    context: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    if pk.len() != PUBLIC_KEY_LEN || signature.len() != p.signature_len() {
+        return Err(error::Unspecified);
+    }
+    let (pk_seed, pk_root) = pk.split_at(N);
+    let hasher = Hasher::new(pk_seed);
+
+    let (r, signature) = signature.split_at(N);
+    let (fors_signature, ht_signature) = signature.split_at(p.fors_signature_len());
+
+    let digest = h_msg(p, r, pk_seed, pk_root, context, msg);
+    let (md, idx_tree, idx_leaf) = split_digest(p, &digest);
+
+    let mut adrs = Address::new();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(AddressType::ForsTree);
+    adrs.set_key_pair(idx_leaf);
+    let pk_fors = fors_pk_from_signature(&hasher, p, fors_signature, md, &mut adrs);
+
+    let root = ht_root_from_signature(&hasher, p, &pk_fors, ht_signature, idx_tree, idx_leaf);
+    if root != pk_root {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// Splits the message digest into md, idx_tree, and idx_leaf, as in FIPS 205
+/// Algorithm 19.
+fn split_digest<'a>(p: &Parameters, digest: &'a [u8; MAX_M]) -> (&'a [u8], u64, u32) {
+    let (md, rest) = digest.split_at((p.k * p.a).div_ceil(8));
+    let tree_bits = p.h - p.hp;
+    let (tmp_idx_tree, rest) = rest.split_at(tree_bits.div_ceil(8));
+    let tmp_idx_leaf = &rest[..p.hp.div_ceil(8)];
+
+    let idx_tree = tmp_idx_tree
+        .iter()
+        .fold(0u64, |acc, &b| (acc << 8) | u64::from(b))
+        & ((1 << tree_bits) - 1);
+    let idx_leaf = tmp_idx_leaf
+        .iter()
+        .fold(0u32, |acc, &b| (acc << 8) | u32::from(b))
+        & ((1 << p.hp) - 1);
+    (md, idx_tree, idx_leaf)
+}
+
+/// PRF_msg of FIPS 205 Section 11.2.1, applied to M' of Algorithm 22.
+fn prf_msg(sk_prf: &[u8], opt_rand: &[u8], context: &[u8], msg: &[u8]) -> [u8; N] {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, sk_prf);
+    let mut ctx = hmac::Context::with_key(&key);
+    ctx.update(opt_rand);
+    update_with_prefix(|bytes| ctx.update(bytes), context, msg);
+    truncate(ctx.sign().as_ref())
+}
+
+/// H_msg of FIPS 205 Section 11.2.1, applied to M' of Algorithm 22.
+fn h_msg(
+    p: &Parameters,
+    r: &[u8],
+    pk_seed: &[u8],
+    pk_root: &[u8],
+    context: &[u8],
+    msg: &[u8],
+) -> [u8; MAX_M] {
+    let mut ctx = digest::Context::new(&digest::SHA256);
+    ctx.update(r);
+    ctx.update(pk_seed);
+    ctx.update(pk_root);
+    update_with_prefix(|bytes| ctx.update(bytes), context, msg);
+    let inner = ctx.finish();
+
+    // MGF1-SHA-256(R || PK.seed || inner, m).
+    let mut out = [0; MAX_M];
+    for (counter, chunk) in out[..p.m].chunks_mut(digest::SHA256_OUTPUT_LEN).enumerate() {
+        let mut ctx = digest::Context::new(&digest::SHA256);
+        ctx.update(r);
+        ctx.update(pk_seed);
+        ctx.update(inner.as_ref());
+        ctx.update(&(counter as u32).to_be_bytes());
+        chunk.copy_from_slice(&ctx.finish().as_ref()[..chunk.len()]);
+    }
+    out
+}
+
+/// Feeds M' = 0 || |ctx| || ctx || M of FIPS 205 Algorithm 22 to `update`.
+fn update_with_prefix(mut update: impl FnMut(&[u8]), context: &[u8], msg: &[u8]) {
+    update(&[0, context.len() as u8]); // Pure SLH-DSA, not HashSLH-DSA.
+    update(context);
+    update(msg);
+}
+
+fn truncate(bytes: &[u8]) -> [u8; N] {
+    let mut out = [0; N];
+    out.copy_from_slice(&bytes[..N]);
+    out
+}
+
+/// F, H, T_l, and PRF of FIPS 205 Section 11.2.1, which differ only in their
+/// inputs. The SHA-256 state after the block PK.seed || toByte(0, 64 - n),
+/// which is the same for every call, is computed once.
+struct Hasher {
+    seeded: digest::Context,
+}
+
+impl Hasher {
+    fn new(pk_seed: &[u8]) -> Self {
+        let mut seeded = digest::Context::new(&digest::SHA256);
+        seeded.update(pk_seed);
+        seeded.update(&[0; 64 - N]);
+        Self { seeded }
+    }
+
+    fn hash(&self, adrs: &Address, inputs: &[&[u8]]) -> [u8; N] {
+        let mut ctx = self.seeded.clone();
+        ctx.update(&adrs.compressed());
+        for input in inputs {
+            ctx.update(input);
+        }
+        truncate(ctx.finish().as_ref())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum AddressType {
+    WotsHash = 0,
+    WotsPk = 1,
+    Tree = 2,
+    ForsTree = 3,
+    ForsRoots = 4,
+    WotsPrf = 5,
+    ForsPrf = 6,
+}
+
+/// The ADRS of FIPS 205 Section 4.2.
+#[derive(Clone, Copy)]
+struct Address([u8; 32]);
+
+impl Address {
+    fn new() -> Self {
+        Self([0; 32])
+    }
+
+    fn set_word(&mut self, offset: usize, value: u32) {
+        self.0[offset..][..4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn word(&self, offset: usize) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.0[offset..][..4]);
+        u32::from_be_bytes(bytes)
+    }
+
+    fn set_layer(&mut self, layer: usize) {
+        self.set_word(0, layer as u32);
+    }
+
+    fn set_tree(&mut self, tree: u64) {
+        self.0[4..8].copy_from_slice(&[0; 4]);
+        self.0[8..16].copy_from_slice(&tree.to_be_bytes());
+    }
+
+    fn set_type_and_clear(&mut self, address_type: AddressType) {
+        self.set_word(16, address_type as u32);
+        self.0[20..].copy_from_slice(&[0; 12]);
+    }
+
+    fn set_key_pair(&mut self, key_pair: u32) {
+        self.set_word(20, key_pair);
+    }
+
+    fn key_pair(&self) -> u32 {
+        self.word(20)
+    }
+
+    fn set_chain(&mut self, chain: usize) {
+        self.set_word(24, chain as u32);
+    }
+
+    fn set_tree_height(&mut self, height: usize) {
+        self.set_word(24, height as u32);
+    }
+
+    fn set_hash(&mut self, hash: usize) {
+        self.set_word(28, hash as u32);
+    }
+
+    fn set_tree_index(&mut self, index: u32) {
+        self.set_word(28, index);
+    }
+
+    fn tree_index(&self) -> u32 {
+        self.word(28)
+    }
+
+    /// The 22-byte ADRS^c of FIPS 205 Section 11.2.
+    fn compressed(&self) -> [u8; 22] {
+        let mut out = [0; 22];
+        out[0] = self.0[3];
+        out[1..9].copy_from_slice(&self.0[8..16]);
+        out[9] = self.0[19];
+        out[10..].copy_from_slice(&self.0[20..]);
+        out
+    }
+}
+
+/// FIPS 205 Algorithm 4, base_2b.
+fn base_2b(x: &[u8], b: usize, out: &mut [u32]) {
+    let mut input = x.iter();
+    let mut bits = 0;
+    let mut total: u32 = 0;
+    for out in out {
+        while bits < b {
+            total = (total << 8) | u32::from(*input.next().unwrap());
+            bits += 8;
+        }
+        bits -= b;
+        *out = (total >> bits) & ((1 << b) - 1);
+        total &= (1 << bits) - 1;
+    }
+}
+
+/// FIPS 205 Algorithm 5, chain.
+fn chain(hasher: &Hasher, x: &[u8], start: u32, steps: u32, adrs: &mut Address) -> [u8; N] {
+    let mut tmp = truncate(x);
+    for j in start..(start + steps) {
+        adrs.set_hash(j as usize);
+        tmp = hasher.hash(adrs, &[&tmp]);
+    }
+    tmp
+}
+
+/// The base-w message and checksum of FIPS 205 Algorithms 7 and 8.
+fn wots_digits(m: &[u8]) -> [u32; LEN] {
+    let mut digits = [0; LEN];
+    let (msg, checksum) = digits.split_at_mut(LEN1);
+    base_2b(m, LG_W, msg);
+    let csum: u32 = msg.iter().map(|&d| (W as u32 - 1) - d).sum();
+    let csum = csum << ((8 - ((LEN2 * LG_W) % 8)) % 8);
+    base_2b(&(csum as u16).to_be_bytes(), LG_W, checksum);
+    digits
+}
+
+/// The WOTS+ private key address for the key pair addressed by `adrs`.
+fn wots_sk_address(adrs: &Address) -> Address {
+    let mut sk_adrs = *adrs;
+    sk_adrs.set_type_and_clear(AddressType::WotsPrf);
+    sk_adrs.set_key_pair(adrs.key_pair());
+    sk_adrs
+}
+
+/// Compresses the ends of the WOTS+ chains into the WOTS+ public key, as in
+/// FIPS 205 Algorithms 6 and 8.
+fn wots_pk_from_chains(hasher: &Hasher, adrs: &Address, tmp: &[u8; LEN * N]) -> [u8; N] {
+    let mut pk_adrs = *adrs;
+    pk_adrs.set_type_and_clear(AddressType::WotsPk);
+    pk_adrs.set_key_pair(adrs.key_pair());
+    hasher.hash(&pk_adrs, &[tmp])
+}
+
+/// FIPS 205 Algorithm 6, wots_pkGen.
+fn wots_pk_gen(hasher: &Hasher, sk_seed: &[u8], adrs: &mut Address) -> [u8; N] {
+    let mut sk_adrs = wots_sk_address(adrs);
+    let mut tmp = [0; LEN * N];
+    for (i, tmp) in tmp.chunks_exact_mut(N).enumerate() {
+        sk_adrs.set_chain(i);
+        let sk = hasher.hash(&sk_adrs, &[sk_seed]);
+        adrs.set_chain(i);
+        tmp.copy_from_slice(&chain(hasher, &sk, 0, W as u32 - 1, adrs));
+    }
+    wots_pk_from_chains(hasher, adrs, &tmp)
+}
+
+/// FIPS 205 Algorithm 7, wots_sign.
+fn wots_sign(hasher: &Hasher, m: &[u8], sk_seed: &[u8], adrs: &mut Address, signature: &mut [u8]) {
+    let digits = wots_digits(m);
+    let mut sk_adrs = wots_sk_address(adrs);
+    for (i, (signature, &digit)) in signature.chunks_exact_mut(N).zip(&digits).enumerate() {
+        sk_adrs.set_chain(i);
+        let sk = hasher.hash(&sk_adrs, &[sk_seed]);
+        adrs.set_chain(i);
+        signature.copy_from_slice(&chain(hasher, &sk, 0, digit, adrs));
+    }
+}
+
+/// FIPS 205 Algorithm 8, wots_pkFromSig.
+fn wots_pk_from_signature(
+    hasher: &Hasher,
+    signature: &[u8],
+    m: &[u8],
+    adrs: &mut Address,
+) -> [u8; N] {
+    let digits = wots_digits(m);
+    let mut tmp = [0; LEN * N];
+    for (i, ((tmp, signature), &digit)) in tmp
+        .chunks_exact_mut(N)
+        .zip(signature.chunks_exact(N))
+        .zip(&digits)
+        .enumerate()
+    {
+        adrs.set_chain(i);
+        tmp.copy_from_slice(&chain(hasher, signature, digit, W as u32 - 1 - digit, adrs));
+    }
+    wots_pk_from_chains(hasher, adrs, &tmp)
+}
+
+/// FIPS 205 Algorithm 9, xmss_node.
+fn xmss_node(hasher: &Hasher, sk_seed: &[u8], i: u32, z: usize, adrs: &mut Address) -> [u8; N] {
+    if z == 0 {
+        adrs.set_type_and_clear(AddressType::WotsHash);
+        adrs.set_key_pair(i);
+        return wots_pk_gen(hasher, sk_seed, adrs);
+    }
+    let left = xmss_node(hasher, sk_seed, 2 * i, z - 1, adrs);
+    let right = xmss_node(hasher, sk_seed, 2 * i + 1, z - 1, adrs);
+    adrs.set_type_and_clear(AddressType::Tree);
+    adrs.set_tree_height(z);
+    adrs.set_tree_index(i);
+    hasher.hash(adrs, &[&left, &right])
+}
+
+/// FIPS 205 Algorithm 10, xmss_sign.
+fn xmss_sign(
+    hasher: &Hasher,
+    m: &[u8],
+    sk_seed: &[u8],
+    idx: u32,
+    adrs: &mut Address,
+    signature: &mut [u8],
+) {
+    let (wots_signature, auth) = signature.split_at_mut(LEN * N);
+    for (j, auth) in auth.chunks_exact_mut(N).enumerate() {
+        let k = (idx >> j) ^ 1;
+        auth.copy_from_slice(&xmss_node(hasher, sk_seed, k, j, adrs));
+    }
+    adrs.set_type_and_clear(AddressType::WotsHash);
+    adrs.set_key_pair(idx);
+    wots_sign(hasher, m, sk_seed, adrs, wots_signature);
+}
+
+/// FIPS 205 Algorithm 11, xmss_pkFromSig.
+fn xmss_pk_from_signature(
+    hasher: &Hasher,
+    idx: u32,
+    signature: &[u8],
+    m: &[u8],
+    adrs: &mut Address,
+) -> [u8; N] {
+    let (wots_signature, auth) = signature.split_at(LEN * N);
+    adrs.set_type_and_clear(AddressType::WotsHash);
+    adrs.set_key_pair(idx);
+    let node = wots_pk_from_signature(hasher, wots_signature, m, adrs);
+
+    adrs.set_type_and_clear(AddressType::Tree);
+    adrs.set_tree_index(idx);
+    climb(hasher, node, idx, auth, adrs)
+}
+
+/// Computes the root of a Merkle tree from a leaf `node` at index `idx` and
+/// its authentication path `auth`, as in FIPS 205 Algorithms 11 and 17.
+/// `adrs` must already have the tree index of the leaf.
+fn climb(hasher: &Hasher, mut node: [u8; N], idx: u32, auth: &[u8], adrs: &mut Address) -> [u8; N] {
+    for (k, auth) in auth.chunks_exact(N).enumerate() {
+        adrs.set_tree_height(k + 1);
+        let tree_index = adrs.tree_index();
+        node = if (idx >> k) & 1 == 0 {
+            adrs.set_tree_index(tree_index / 2);
+            hasher.hash(adrs, &[&node, auth])
+        } else {
+            adrs.set_tree_index((tree_index - 1) / 2);
+            hasher.hash(adrs, &[auth, &node])
+        };
+    }
+    node
+}
+
+/// FIPS 205 Algorithm 12, ht_sign.
+fn ht_sign(
+    hasher: &Hasher,
+    p: &Parameters,
+    m: &[u8],
+    sk_seed: &[u8],
+    mut idx_tree: u64,
+    mut idx_leaf: u32,
+    signature: &mut [u8],
+) {
+    let mut adrs = Address::new();
+    let mut root = truncate(m);
+    for (j, signature) in signature
+        .chunks_exact_mut(p.xmss_signature_len())
+        .enumerate()
+    {
+        if j > 0 {
+            idx_leaf = (idx_tree & ((1 << p.hp) - 1)) as u32;
+            idx_tree >>= p.hp;
+        }
+        adrs.set_layer(j);
+        adrs.set_tree(idx_tree);
+        xmss_sign(hasher, &root, sk_seed, idx_leaf, &mut adrs, signature);
+        if j < p.d - 1 {
+            root = xmss_pk_from_signature(hasher, idx_leaf, signature, &root, &mut adrs);
+        }
+    }
+}
+
+/// Computes the root of the hypertree from its signature, as in FIPS 205
+/// Algorithm 13, ht_verify.
+fn ht_root_from_signature(
+    hasher: &Hasher,
+    p: &Parameters,
+    m: &[u8],
+    signature: &[u8],
+    mut idx_tree: u64,
+    mut idx_leaf: u32,
+) -> [u8; N] {
+    let mut adrs = Address::new();
+    let mut node = truncate(m);
+    for (j, signature) in signature.chunks_exact(p.xmss_signature_len()).enumerate() {
+        if j > 0 {
+            idx_leaf = (idx_tree & ((1 << p.hp) - 1)) as u32;
+            idx_tree >>= p.hp;
+        }
+        adrs.set_layer(j);
+        adrs.set_tree(idx_tree);
+        node = xmss_pk_from_signature(hasher, idx_leaf, signature, &node, &mut adrs);
+    }
+    node
+}
+
+/// FIPS 205 Algorithm 14, fors_skGen.
+fn fors_sk_gen(hasher: &Hasher, sk_seed: &[u8], adrs: &Address, idx: u32) -> [u8; N] {
+    let mut sk_adrs = *adrs;
+    sk_adrs.set_type_and_clear(AddressType::ForsPrf);
+    sk_adrs.set_key_pair(adrs.key_pair());
+    sk_adrs.set_tree_index(idx);
+    hasher.hash(&sk_adrs, &[sk_seed])
+}
+
+/// FIPS 205 Algorithm 15, fors_node.
+fn fors_node(hasher: &Hasher, sk_seed: &[u8], i: u32, z: usize, adrs: &mut Address) -> [u8; N] {
+    if z == 0 {
+        let sk = fors_sk_gen(hasher, sk_seed, adrs, i);
+        adrs.set_tree_height(0);
+        adrs.set_tree_index(i);
+        return hasher.hash(adrs, &[&sk]);
+    }
+    let left = fors_node(hasher, sk_seed, 2 * i, z - 1, adrs);
+    let right = fors_node(hasher, sk_seed, 2 * i + 1, z - 1, adrs);
+    adrs.set_tree_height(z);
+    adrs.set_tree_index(i);
+    hasher.hash(adrs, &[&left, &right])
+}
+
+fn fors_indices(p: &Parameters, md: &[u8]) -> [u32; MAX_K] {
+    let mut indices = [0; MAX_K];
+    base_2b(md, p.a, &mut indices[..p.k]);
+    indices
+}
+
+/// FIPS 205 Algorithm 16, fors_sign.
+fn fors_sign(
+    hasher: &Hasher,
+    p: &Parameters,
+    md: &[u8],
+    sk_seed: &[u8],
+    adrs: &mut Address,
+    signature: &mut [u8],
+) {
+    let indices = fors_indices(p, md);
+    for (i, (signature, &index)) in signature
+        .chunks_exact_mut((1 + p.a) * N)
+        .zip(&indices[..p.k])
+        .enumerate()
+    {
+        let i = i as u32;
+        let (sk, auth) = signature.split_at_mut(N);
+        sk.copy_from_slice(&fors_sk_gen(hasher, sk_seed, adrs, (i << p.a) + index));
+        for (j, auth) in auth.chunks_exact_mut(N).enumerate() {
+            let s = (index >> j) ^ 1;
+            let node = fors_node(hasher, sk_seed, (i << (p.a - j)) + s, j, adrs);
+            auth.copy_from_slice(&node);
+        }
+    }
+}
+
+/// FIPS 205 Algorithm 17, fors_pkFromSig.
+fn fors_pk_from_signature(
+    hasher: &Hasher,
+    p: &Parameters,
+    signature: &[u8],
+    md: &[u8],
+    adrs: &mut Address,
+) -> [u8; N] {
+    let indices = fors_indices(p, md);
+    let mut roots = [0; MAX_K * N];
+    for (i, ((root, signature), &index)) in roots
+        .chunks_exact_mut(N)
+        .zip(signature.chunks_exact((1 + p.a) * N))
+        .zip(&indices[..p.k])
+        .enumerate()
+    {
+        let (sk, auth) = signature.split_at(N);
+        let leaf = ((i as u32) << p.a) + index;
+        adrs.set_tree_height(0);
+        adrs.set_tree_index(leaf);
+        let node = hasher.hash(adrs, &[sk]);
+        root.copy_from_slice(&climb(hasher, node, index, auth, adrs));
+    }
+
+    let mut pk_adrs = *adrs;
+    pk_adrs.set_type_and_clear(AddressType::ForsRoots);
+    pk_adrs.set_key_pair(adrs.key_pair());
+    hasher.hash(&pk_adrs, &[&roots[..p.k * N]])
+}
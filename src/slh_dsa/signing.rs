@@ -0,0 +1,264 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! SLH-DSA key pairs and signing.
+
+use super::{
+    check_context, key_gen, sign, Parameters, N, PRIVATE_KEY_LEN, PUBLIC_KEY_LEN, SEED_LEN,
+    SLH_DSA_SHA2_128F_PARAMETERS, SLH_DSA_SHA2_128S_PARAMETERS,
+};
+use crate::{error, pkcs8, rand, signature};
+use core::convert::TryInto;
+
+/// An SLH-DSA signing algorithm.
+pub struct SlhDsaSigningAlgorithm {
+    params: &'static Parameters,
+    pkcs8_template: &'static pkcs8::Template,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    SLH_DSA_SHA2_128S_SIGNING,
+    SLH_DSA_SHA2_128F_SIGNING,
+}
+
+derive_debug_via_id!(SlhDsaSigningAlgorithm);
+
+impl SlhDsaSigningAlgorithm {
+    /// The length of the signatures this algorithm produces.
+    pub fn signature_len(&self) -> usize {
+        self.params.signature_len()
+    }
+}
+
+/// An SLH-DSA key pair, for signing.
+pub struct SlhDsaKeyPair {
+    alg: &'static SlhDsaSigningAlgorithm,
+    private_key: [u8; PRIVATE_KEY_LEN],
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(SlhDsaKeyPair, stringify!(SlhDsaKeyPair), public_key);
+
+impl SlhDsaKeyPair {
+    /// Generates a new key pair and returns the key pair serialized as a
+    /// PKCS#8 document.
+    ///
+    /// The PKCS#8 document will be a v1 `OneAsymmetricKey` whose private key
+    /// is the 64-byte SK.seed || SK.prf || PK.seed || PK.root, as described
+    /// in draft-ietf-lamps-x509-slhdsa.
+    pub fn generate_pkcs8(
+        alg: &'static SlhDsaSigningAlgorithm,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<pkcs8::Document, error::Unspecified> {
+        let seed: [u8; SEED_LEN] = rand::generate(rng)?.expose();
+        let private_key = key_gen(alg.params, &seed);
+        Ok(pkcs8::wrap_key(alg.pkcs8_template, &private_key, &[]))
+    }
+
+    /// Constructs an SLH-DSA key pair by parsing an unencrypted PKCS#8 v1 or
+    /// v2 SLH-DSA private key, as described in draft-ietf-lamps-x509-slhdsa.
+    ///
+    /// PK.root is recomputed from the rest of the private key and must be
+    /// consistent with it, as must the public key, if present. This takes
+    /// about as long as generating a key pair.
+    pub fn from_pkcs8(
+        alg: &'static SlhDsaSigningAlgorithm,
+        pkcs8: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let (private_key, public_key) = pkcs8::unwrap_key(
+            alg.pkcs8_template,
+            pkcs8::Version::V1OrV2,
+            untrusted::Input::from(pkcs8),
+        )?;
+        let private_key: [u8; PRIVATE_KEY_LEN] = private_key
+            .as_slice_less_safe()
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+
+        let key_pair = Self::from_seed(alg, &private_key[..SEED_LEN])?;
+        if key_pair.private_key != private_key {
+            return Err(error::KeyRejected::inconsistent_components());
+        }
+        if let Some(public_key) = public_key {
+            if public_key.as_slice_less_safe() != key_pair.public_key.as_ref() {
+                return Err(error::KeyRejected::inconsistent_components());
+            }
+        }
+        Ok(key_pair)
+    }
+
+    /// Constructs an SLH-DSA key pair from the 48-byte seed
+    /// SK.seed || SK.prf || PK.seed of FIPS 205 Algorithm 18.
+    ///
+    /// It is recommended to use `SlhDsaKeyPair::from_pkcs8()` instead.
+    pub fn from_seed(
+        alg: &'static SlhDsaSigningAlgorithm,
+        seed: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let seed: [u8; SEED_LEN] = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let private_key = key_gen(alg.params, &seed);
+        let mut public_key = PublicKey {
+            params: alg.params,
+            bytes: [0; PUBLIC_KEY_LEN],
+        };
+        public_key
+            .bytes
+            .copy_from_slice(&private_key[(PRIVATE_KEY_LEN - PUBLIC_KEY_LEN)..]);
+        Ok(Self {
+            alg,
+            private_key,
+            public_key,
+        })
+    }
+
+    /// Returns the algorithm of the key pair.
+    pub fn algorithm(&self) -> &'static SlhDsaSigningAlgorithm {
+        self.alg
+    }
+
+    /// Signs the message `msg` with an empty context string, writing the
+    /// signature into `signature`.
+    ///
+    /// `signature` must be exactly `self.algorithm().signature_len()` bytes
+    /// long. The signature is hedged: the randomizer is derived from 16
+    /// bytes of randomness from `rng` along with the private key.
+    pub fn sign(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        self.sign_with_context(rng, &[], msg, signature)
+    }
+
+    /// Like `sign()`, with the context string `context`.
+    ///
+    /// A signature is only valid for the same context. It must be verified
+    /// with `SlhDsaVerificationAlgorithm::verify_with_context`.
+    ///
+    /// Fails if `context` is longer than 255 bytes.
+    pub fn sign_with_context(
+        &self,
+        rng: &dyn rand::SecureRandom,
+        context: &[u8],
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        if signature.len() != self.alg.signature_len() {
+            return Err(error::Unspecified);
+        }
+        check_context(context)?;
+        let opt_rand: [u8; N] = rand::generate(rng)?.expose();
+        sign(
+            self.alg.params,
+            &self.private_key,
+            context,
+            msg,
+            &opt_rand,
+            signature,
+        );
+        Ok(())
+    }
+}
+
+impl signature::KeyPair for SlhDsaKeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+impl signature::KeyPairGenerator for SlhDsaSigningAlgorithm {
+    type KeyPair = SlhDsaKeyPair;
+
+    fn generate_key_pair(
+        &'static self,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<(Self::KeyPair, pkcs8::Document), error::Unspecified> {
+        let pkcs8 = SlhDsaKeyPair::generate_pkcs8(self, rng)?;
+        let key_pair = SlhDsaKeyPair::from_pkcs8(self, pkcs8.as_ref())?;
+        Ok((key_pair, pkcs8))
+    }
+}
+
+/// The public key of an `SlhDsaKeyPair`, PK.seed || PK.root.
+#[derive(Clone)]
+pub struct PublicKey {
+    params: &'static Parameters,
+    bytes: [u8; PUBLIC_KEY_LEN],
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+#[cfg(feature = "alloc")]
+impl PublicKey {
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`, as
+    /// described in draft-ietf-lamps-x509-slhdsa.
+    pub fn subject_public_key_info(&self) -> alloc::boxed::Box<[u8]> {
+        use crate::io::{der, der_writer};
+        der_writer::write_all(der::Tag::Sequence, &|output| {
+            der_writer::write_tlv(output, der::Tag::Sequence, |output| {
+                der_writer::write_tlv(output, der::Tag::OID, |output| {
+                    output.write_bytes(self.params.oid)
+                })
+            });
+            der_writer::write_tlv(output, der::Tag::BitString, |output| {
+                output.write_byte(0); // No unused bits.
+                output.write_bytes(self.as_ref());
+            });
+        })
+    }
+}
+
+/// Signing using SLH-DSA-SHA2-128s, as specified in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+pub static SLH_DSA_SHA2_128S_SIGNING: SlhDsaSigningAlgorithm = SlhDsaSigningAlgorithm {
+    params: &SLH_DSA_SHA2_128S_PARAMETERS,
+    pkcs8_template: &SLH_DSA_SHA2_128S_PKCS8_TEMPLATE,
+    id: AlgorithmID::SLH_DSA_SHA2_128S_SIGNING,
+};
+
+/// Signing using SLH-DSA-SHA2-128f, as specified in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+pub static SLH_DSA_SHA2_128F_SIGNING: SlhDsaSigningAlgorithm = SlhDsaSigningAlgorithm {
+    params: &SLH_DSA_SHA2_128F_PARAMETERS,
+    pkcs8_template: &SLH_DSA_SHA2_128F_PKCS8_TEMPLATE,
+    id: AlgorithmID::SLH_DSA_SHA2_128F_SIGNING,
+};
+
+static SLH_DSA_SHA2_128S_PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("slh_dsa_sha2_128s_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 7, end: 18 },
+    curve_id_index: 0,
+    private_key_index: 20,
+};
+
+static SLH_DSA_SHA2_128F_PKCS8_TEMPLATE: pkcs8::Template = pkcs8::Template {
+    bytes: include_bytes!("slh_dsa_sha2_128f_pkcs8_v1_template.der"),
+    alg_id_range: core::ops::Range { start: 7, end: 18 },
+    curve_id_index: 0,
+    private_key_index: 20,
+};
@@ -0,0 +1,113 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! SLH-DSA signature verification.
+
+use super::{
+    check_context, verify, Parameters, PUBLIC_KEY_LEN, SLH_DSA_SHA2_128F_PARAMETERS,
+    SLH_DSA_SHA2_128S_PARAMETERS,
+};
+use crate::{error, io::der, sealed, signature};
+
+/// An SLH-DSA verification algorithm.
+pub struct SlhDsaVerificationAlgorithm {
+    params: &'static Parameters,
+    id: AlgorithmID,
+}
+
+#[derive(Debug)]
+enum AlgorithmID {
+    SLH_DSA_SHA2_128S,
+    SLH_DSA_SHA2_128F,
+}
+
+derive_debug_via_id!(SlhDsaVerificationAlgorithm);
+
+impl signature::VerificationAlgorithm for SlhDsaVerificationAlgorithm {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        self.verify_with_context(
+            public_key.as_slice_less_safe(),
+            &[],
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+}
+
+impl sealed::Sealed for SlhDsaVerificationAlgorithm {}
+
+impl SlhDsaVerificationAlgorithm {
+    /// Verifies that `signature` is a valid signature, with the context
+    /// `context`, by `public_key` of the message `msg`.
+    ///
+    /// Verification through `signature::UnparsedPublicKey` uses an empty
+    /// context. Fails if `context` is longer than 255 bytes, or if the
+    /// signature is invalid.
+    pub fn verify_with_context(
+        &self,
+        public_key: &[u8],
+        context: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        check_context(context)?;
+        verify(self.params, public_key, context, msg, signature)
+    }
+
+    /// Returns the public key in the DER `SubjectPublicKeyInfo` `spki`, as
+    /// described in draft-ietf-lamps-x509-slhdsa, for use with
+    /// `signature::UnparsedPublicKey`.
+    ///
+    /// Fails if `spki` isn't for this algorithm.
+    pub fn public_key_from_spki<'a>(&self, spki: &'a [u8]) -> Result<&'a [u8], error::Unspecified> {
+        let public_key = untrusted::Input::from(spki).read_all(error::Unspecified, |input| {
+            der::nested(input, der::Tag::Sequence, error::Unspecified, |input| {
+                let alg_id = der::expect_tag_and_get_value(input, der::Tag::Sequence)?;
+                alg_id.read_all(error::Unspecified, |input| {
+                    let oid = der::expect_tag_and_get_value(input, der::Tag::OID)?;
+                    if oid.as_slice_less_safe() != self.params.oid {
+                        return Err(error::Unspecified);
+                    }
+                    Ok(())
+                })?;
+                der::bit_string_with_no_unused_bits(input)
+            })
+        })?;
+        let public_key = public_key.as_slice_less_safe();
+        if public_key.len() != PUBLIC_KEY_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(public_key)
+    }
+}
+
+/// Verification of SLH-DSA-SHA2-128s signatures, as specified in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+pub static SLH_DSA_SHA2_128S: SlhDsaVerificationAlgorithm = SlhDsaVerificationAlgorithm {
+    params: &SLH_DSA_SHA2_128S_PARAMETERS,
+    id: AlgorithmID::SLH_DSA_SHA2_128S,
+};
+
+/// Verification of SLH-DSA-SHA2-128f signatures, as specified in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+pub static SLH_DSA_SHA2_128F: SlhDsaVerificationAlgorithm = SlhDsaVerificationAlgorithm {
+    params: &SLH_DSA_SHA2_128F_PARAMETERS,
+    id: AlgorithmID::SLH_DSA_SHA2_128F,
+};
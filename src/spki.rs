@@ -0,0 +1,107 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Fingerprints of public keys.
+//!
+//! A fingerprint is the digest of the DER-encoded `SubjectPublicKeyInfo` of a
+//! public key. Hashing the `SubjectPublicKeyInfo`, instead of the raw public
+//! key, means the fingerprint also identifies the algorithm the key is for,
+//! so it is the form used for public key pinning ([RFC 7469]) and for logging
+//! keys in key transparency systems.
+//!
+//! ```
+//! use ring::{digest, rand, signature, spki};
+//! use ring::signature::KeyPair;
+//!
+//! let rng = rand::SystemRandom::new();
+//! let pkcs8_bytes = signature::Ed25519KeyPair::generate_pkcs8(&rng)?;
+//! let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8_bytes.as_ref())?;
+//!
+//! let fingerprint = spki::fingerprint(&digest::SHA256, key_pair.public_key());
+//! assert_eq!(fingerprint.as_ref().len(), digest::SHA256_OUTPUT_LEN);
+//!
+//! // The value of an RFC 7469 `pin-sha256` directive.
+//! let pin = spki::pin_sha256(key_pair.public_key());
+//! assert_eq!(pin.len(), 44);
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 7469]: https://tools.ietf.org/html/rfc7469
+
+use crate::{digest, ec, ml_dsa, rsa, sealed, slh_dsa};
+use alloc::{boxed::Box, string::String};
+
+/// A public key that can be encoded as a `SubjectPublicKeyInfo`.
+pub trait PublicKey: sealed::Sealed {
+    /// Returns the public key encoded as a DER `SubjectPublicKeyInfo`.
+    fn subject_public_key_info(&self) -> Box<[u8]>;
+}
+
+macro_rules! impl_public_key {
+    ( $PublicKey:ty ) => {
+        impl sealed::Sealed for $PublicKey {}
+
+        impl PublicKey for $PublicKey {
+            fn subject_public_key_info(&self) -> Box<[u8]> {
+                <$PublicKey>::subject_public_key_info(self)
+            }
+        }
+    };
+}
+
+impl_public_key!(ec::curve25519::ed25519::signing::PublicKey);
+impl_public_key!(ec::suite_b::ecdsa::signing::PublicKey);
+impl_public_key!(ml_dsa::signing::PublicKey);
+impl_public_key!(rsa::signing::RsaSubjectPublicKey);
+impl_public_key!(slh_dsa::signing::PublicKey);
+
+/// Returns the digest of `public_key`'s `SubjectPublicKeyInfo` encoding.
+pub fn fingerprint(
+    digest_alg: &'static digest::Algorithm,
+    public_key: &dyn PublicKey,
+) -> digest::Digest {
+    digest::digest(digest_alg, &public_key.subject_public_key_info())
+}
+
+/// Returns the base64-encoded SHA-256 fingerprint of `public_key`, which is
+/// the value of a `pin-sha256` directive as described in [RFC 7469 Section
+/// 2.4].
+///
+/// [RFC 7469 Section 2.4]: https://tools.ietf.org/html/rfc7469#section-2.4
+pub fn pin_sha256(public_key: &dyn PublicKey) -> String {
+    base64_encode(fingerprint(&digest::SHA256, public_key).as_ref())
+}
+
+/// The base64 alphabet of RFC 4648 Section 4.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `input` as padded base64.
+fn base64_encode(input: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in input.chunks(3) {
+        let mut block = [0u8; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let bits = (u32::from(block[0]) << 16) | (u32::from(block[1]) << 8) | u32::from(block[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (bits >> (18 - (6 * i))) & 0x3f;
+                out.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,95 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The TLS 1.2 pseudorandom function (PRF), as specified in
+//! [RFC 5246 Section 5].
+//!
+//! The PRF is `P_hash(secret, label || seed)`. In TLS 1.2 it derives the
+//! master secret, the extended master secret ([RFC 7627]), the key block,
+//! and the `verify_data` of the `Finished` messages.
+//!
+//! ```
+//! use ring::tls12_prf;
+//!
+//! # let pre_master_secret = [0u8; 48];
+//! # let client_random = [1u8; 32];
+//! # let server_random = [2u8; 32];
+//! let mut master_secret = [0u8; 48];
+//! tls12_prf::derive(
+//!     tls12_prf::P_SHA256,
+//!     &pre_master_secret,
+//!     b"master secret",
+//!     &[&client_random, &server_random],
+//!     &mut master_secret,
+//! );
+//! ```
+//!
+//! [RFC 5246 Section 5]: https://tools.ietf.org/html/rfc5246#section-5
+//! [RFC 7627]: https://tools.ietf.org/html/rfc7627
+
+use crate::hmac;
+
+/// A TLS 1.2 PRF algorithm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Algorithm(hmac::Algorithm);
+
+impl Algorithm {
+    /// The underlying HMAC algorithm.
+    #[inline]
+    pub fn hmac_algorithm(&self) -> hmac::Algorithm {
+        self.0
+    }
+}
+
+/// The PRF using HMAC-SHA256, which all the TLS 1.2 cipher suites of RFC 5246
+/// use.
+pub static P_SHA256: Algorithm = Algorithm(hmac::HMAC_SHA256);
+
+/// The PRF using HMAC-SHA384, which the SHA-384 cipher suites (e.g.
+/// `TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384`) use.
+pub static P_SHA384: Algorithm = Algorithm(hmac::HMAC_SHA384);
+
+/// Fills `out` with `PRF(secret, label, seed)`, where `seed` is the
+/// concatenation of the parts of `seed`.
+///
+/// `label` is the ASCII label, e.g. `b"key expansion"`, without any length
+/// or terminator.
+pub fn derive(algorithm: Algorithm, secret: &[u8], label: &[u8], seed: &[&[u8]], out: &mut [u8]) {
+    let key = hmac::Key::new(algorithm.0, secret);
+
+    let update_seed = |ctx: &mut hmac::Context| {
+        ctx.update(label);
+        for part in seed {
+            ctx.update(part);
+        }
+    };
+
+    // A(1) = HMAC_hash(secret, A(0)), where A(0) is the seed.
+    let mut a = {
+        let mut ctx = hmac::Context::with_key(&key);
+        update_seed(&mut ctx);
+        ctx.sign()
+    };
+
+    let output_len = algorithm.0.digest_algorithm().output_len;
+    for chunk in out.chunks_mut(output_len) {
+        let mut ctx = hmac::Context::with_key(&key);
+        ctx.update(a.as_ref());
+        update_seed(&mut ctx);
+        let t = ctx.sign();
+        chunk.copy_from_slice(&t.as_ref()[..chunk.len()]);
+
+        a = hmac::sign(&key, a.as_ref());
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Verifiable random functions.
+//!
+//! A verifiable random function (VRF) is the public-key version of a keyed
+//! hash: only the holder of the private key can compute the output for an
+//! input, and anybody with the public key can check that the output is
+//! correct, using a proof that the private key holder provides. Outputs are
+//! unique; there is only one valid output per public key and input.
+//!
+//! This implements ECVRF-EDWARDS25519-SHA512-TAI, as specified in
+//! [RFC 9381].
+//!
+//! ```
+//! use ring::{signature::KeyPair, vrf};
+//!
+//! let seed = [0x42; 32];
+//! let key_pair = vrf::KeyPair::from_seed_unchecked(&seed)?;
+//!
+//! let proof = key_pair.prove(b"round 7");
+//! let output = proof.to_hash();
+//!
+//! let verified_output =
+//!     vrf::verify(key_pair.public_key().as_ref(), b"round 7", proof.as_ref())?;
+//! assert_eq!(verified_output.as_ref(), output.as_ref());
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+pub use crate::ec::curve25519::ecvrf::{
+    verify, KeyPair, Output, Proof, PublicKey, OUTPUT_LEN, PROOF_LEN,
+};
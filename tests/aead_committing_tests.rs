@@ -0,0 +1,120 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{
+    aead::{self, committing},
+    test,
+};
+
+const NONCE: [u8; 12] = [0x11; 12];
+const AAD: &[u8] = b"header";
+const PLAINTEXT: &[u8] = b"committing plaintext";
+
+fn key(algorithm: &'static aead::Algorithm, key_bytes: &[u8]) -> committing::Key {
+    committing::Key::new(algorithm, &key_bytes[..algorithm.key_len()]).unwrap()
+}
+
+fn seal(key: &committing::Key) -> Vec<u8> {
+    let mut in_out = Vec::from(PLAINTEXT);
+    key.seal_in_place_append_tag(
+        aead::Nonce::assume_unique_for_key(NONCE),
+        aead::Aad::from(AAD),
+        &mut in_out,
+    )
+    .unwrap();
+    in_out
+}
+
+fn open<'a>(
+    key: &committing::Key,
+    in_out: &'a mut [u8],
+) -> Result<&'a mut [u8], ring::error::Unspecified> {
+    key.open_in_place(
+        aead::Nonce::assume_unique_for_key(NONCE),
+        aead::Aad::from(AAD),
+        in_out,
+    )
+}
+
+#[test]
+fn committing_known_answer() {
+    let key_bytes: Vec<u8> = (0..32).collect();
+    for (algorithm, expected) in [
+        (
+            &aead::AES_128_GCM,
+            "185d88366f53c7b56a95ce7bd4591a0fc5109ae98d1b956d8207c47cb328df27abf4876a769d6fa710de4f1327e9c21c945c1bed8b200c50942f3ced62ce482fabb30d11",
+        ),
+        (
+            &aead::AES_256_GCM,
+            "d71d4b6b1222db553ee6cd082b9e4e8a4a8b1d109d28f3f20c54513cfeec6bf055eecadefb9deffc8be67b0b18e59eef1959e15c6bcfc8d1231f8c178aea8298d86ee32f",
+        ),
+        (
+            &aead::CHACHA20_POLY1305,
+            "0beb79a67e38be963e19e809da35c2d6dd71851754546e6c5ff310d17d53ebc23a27082bfb9deffc8be67b0b18e59eef1959e15c6bcfc8d1231f8c178aea8298d86ee32f",
+        ),
+    ] {
+        let key = key(algorithm, &key_bytes);
+        let mut sealed = seal(&key);
+        assert_eq!(sealed, test::from_hex(expected).unwrap());
+        assert_eq!(sealed.len(), PLAINTEXT.len() + key.overhead_len());
+        assert_eq!(open(&key, &mut sealed).unwrap(), PLAINTEXT);
+    }
+}
+
+#[test]
+fn committing_wrong_key_fails() {
+    for algorithm in [&aead::AES_256_GCM, &aead::CHACHA20_POLY1305] {
+        let sealed = seal(&key(algorithm, &[1; 32]));
+        let mut in_out = sealed.clone();
+        assert!(open(&key(algorithm, &[2; 32]), &mut in_out).is_err());
+    }
+}
+
+#[test]
+fn committing_modified_fails() {
+    let key = key(&aead::AES_256_GCM, &[1; 32]);
+    let sealed = seal(&key);
+    for i in 0..sealed.len() {
+        let mut in_out = sealed.clone();
+        in_out[i] ^= 1;
+        assert!(open(&key, &mut in_out).is_err());
+    }
+
+    // Truncated, including to shorter than the commitment.
+    for len in 0..sealed.len() {
+        let mut in_out = sealed[..len].to_vec();
+        assert!(open(&key, &mut in_out).is_err());
+    }
+}
+
+#[test]
+fn committing_key_len() {
+    assert!(committing::Key::new(&aead::AES_128_GCM, &[0; 15]).is_err());
+    assert!(committing::Key::new(&aead::AES_128_GCM, &[0; 17]).is_err());
+    assert!(committing::Key::new(&aead::AES_128_GCM, &[0; 16]).is_ok());
+}
+
+#[test]
+fn committing_key_debug() {
+    let key = key(&aead::AES_128_GCM, &[0; 16]);
+    assert_eq!("Key { algorithm: AES_128_GCM }", format!("{:?}", key));
+}
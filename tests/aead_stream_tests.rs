@@ -0,0 +1,216 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::aead::{self, stream};
+
+const KEY: [u8; 32] = [0x42; 32];
+const AAD: &[u8] = b"header";
+
+fn key(algorithm: &'static aead::Algorithm) -> aead::UnboundKey {
+    aead::UnboundKey::new(algorithm, &KEY[..algorithm.key_len()]).unwrap()
+}
+
+fn nonce_prefix(algorithm: &'static aead::Algorithm) -> Vec<u8> {
+    (0..stream::nonce_prefix_len(algorithm).unwrap())
+        .map(|i| i as u8)
+        .collect()
+}
+
+/// Seals `segments` as a message, returning the sealed segments.
+fn seal(algorithm: &'static aead::Algorithm, segments: &[&[u8]]) -> Vec<Vec<u8>> {
+    let mut encryptor = stream::Encryptor::new(key(algorithm), &nonce_prefix(algorithm)).unwrap();
+    let (last, init) = segments.split_last().unwrap();
+    let mut sealed = Vec::new();
+    for segment in init {
+        let mut in_out = segment.to_vec();
+        encryptor
+            .seal_next_in_place_append_tag(aead::Aad::from(AAD), &mut in_out)
+            .unwrap();
+        sealed.push(in_out);
+    }
+    let mut in_out = last.to_vec();
+    encryptor
+        .seal_last_in_place_append_tag(aead::Aad::from(AAD), &mut in_out)
+        .unwrap();
+    sealed.push(in_out);
+    sealed
+}
+
+/// Opens `sealed` as a message, treating only the final segment as the last.
+fn open(algorithm: &'static aead::Algorithm, sealed: &[Vec<u8>]) -> Result<Vec<u8>, usize> {
+    let mut decryptor = stream::Decryptor::new(key(algorithm), &nonce_prefix(algorithm)).unwrap();
+    let (last, init) = sealed.split_last().unwrap();
+    let mut plaintext = Vec::new();
+    for (i, segment) in init.iter().enumerate() {
+        let mut in_out = segment.clone();
+        let opened = decryptor
+            .open_next_in_place(aead::Aad::from(AAD), &mut in_out)
+            .map_err(|_| i)?;
+        plaintext.extend_from_slice(opened);
+    }
+    let mut in_out = last.clone();
+    let opened = decryptor
+        .open_last_in_place(aead::Aad::from(AAD), &mut in_out)
+        .map_err(|_| init.len())?;
+    plaintext.extend_from_slice(opened);
+    Ok(plaintext)
+}
+
+const ALGORITHMS: &[&aead::Algorithm] = &[
+    &aead::AES_128_GCM,
+    &aead::AES_256_GCM,
+    &aead::CHACHA20_POLY1305,
+    &aead::XCHACHA20_POLY1305,
+];
+
+#[test]
+fn stream_round_trip() {
+    let segments: &[&[u8]] = &[&[1; 100], &[2; 100], &[], &[3; 17]];
+    for algorithm in ALGORITHMS {
+        for n in 1..=segments.len() {
+            let sealed = seal(algorithm, &segments[..n]);
+            assert_eq!(open(algorithm, &sealed), Ok(segments[..n].concat()));
+        }
+    }
+}
+
+// Each segment is sealed with the nonce prefix || BE32(index) || last flag.
+#[test]
+fn stream_nonces() {
+    for algorithm in ALGORITHMS {
+        let sealed = seal(algorithm, &[b"first", b"second", b"third"]);
+        let key = aead::LessSafeKey::new(key(algorithm));
+        for (i, (segment, plaintext)) in sealed
+            .iter()
+            .zip(&[&b"first"[..], b"second", b"third"])
+            .enumerate()
+        {
+            let mut nonce = nonce_prefix(algorithm);
+            nonce.extend_from_slice(&(i as u32).to_be_bytes());
+            nonce.push(if i == 2 { 1 } else { 0 });
+            let nonce = aead::Nonce::try_assume_unique_for_key_any_len(&nonce).unwrap();
+            let mut in_out = plaintext.to_vec();
+            key.seal_in_place_append_tag(nonce, aead::Aad::from(AAD), &mut in_out)
+                .unwrap();
+            assert_eq!(segment, &in_out);
+        }
+    }
+}
+
+#[test]
+fn stream_reordered_fails() {
+    for algorithm in ALGORITHMS {
+        let mut sealed = seal(algorithm, &[b"a", b"b", b"c"]);
+        sealed.swap(0, 1);
+        assert_eq!(open(algorithm, &sealed), Err(0));
+    }
+}
+
+#[test]
+fn stream_truncated_fails() {
+    for algorithm in ALGORITHMS {
+        let mut sealed = seal(algorithm, &[b"a", b"b", b"c"]);
+        let _ = sealed.pop();
+        // The second segment wasn't sealed as the last segment.
+        assert_eq!(open(algorithm, &sealed), Err(1));
+    }
+}
+
+#[test]
+fn stream_extended_fails() {
+    for algorithm in ALGORITHMS {
+        let mut sealed = seal(algorithm, &[b"a", b"b"]);
+        let duplicate = sealed[0].clone();
+        sealed.push(duplicate);
+        // The last segment of the original message isn't the last any more.
+        assert_eq!(open(algorithm, &sealed), Err(1));
+    }
+}
+
+#[test]
+fn stream_retry_after_failure() {
+    let algorithm = &aead::CHACHA20_POLY1305;
+    let sealed = seal(algorithm, &[b"a", b"b"]);
+    let mut decryptor = stream::Decryptor::new(key(algorithm), &nonce_prefix(algorithm)).unwrap();
+
+    let mut corrupted = sealed[0].clone();
+    corrupted[0] ^= 1;
+    assert!(decryptor
+        .open_next_in_place(aead::Aad::from(AAD), &mut corrupted)
+        .is_err());
+
+    let mut in_out = sealed[0].clone();
+    assert_eq!(
+        decryptor.open_next_in_place(aead::Aad::from(AAD), &mut in_out),
+        Ok(&mut b"a".to_vec()[..])
+    );
+    let mut in_out = sealed[1].clone();
+    assert_eq!(
+        decryptor.open_last_in_place(aead::Aad::from(AAD), &mut in_out),
+        Ok(&mut b"b".to_vec()[..])
+    );
+}
+
+#[test]
+fn stream_nonce_prefix_len() {
+    assert_eq!(stream::nonce_prefix_len(&aead::AES_128_GCM), Some(7));
+    assert_eq!(
+        stream::nonce_prefix_len(&aead::XCHACHA20_POLY1305),
+        Some(19)
+    );
+
+    for algorithm in ALGORITHMS {
+        let len = stream::nonce_prefix_len(algorithm).unwrap();
+        let prefix = [0u8; aead::MAX_NONCE_LEN];
+        assert!(stream::Encryptor::new(key(algorithm), &prefix[..len]).is_ok());
+        assert!(stream::Encryptor::new(key(algorithm), &prefix[..(len - 1)]).is_err());
+        assert!(stream::Encryptor::new(key(algorithm), &prefix[..(len + 1)]).is_err());
+        assert!(stream::Decryptor::new(key(algorithm), &prefix[..(len + 1)]).is_err());
+    }
+}
+
+// AES-SIV doesn't take a nonce, so there's no room for the segment index.
+#[test]
+fn stream_aes_siv_rejected() {
+    let algorithm = &aead::AES_SIV_CMAC_256;
+    assert_eq!(stream::nonce_prefix_len(algorithm), None);
+    for prefix_len in [0, 7] {
+        let prefix = [0u8; 7];
+        assert!(stream::Encryptor::new(key(algorithm), &prefix[..prefix_len]).is_err());
+        assert!(stream::Decryptor::new(key(algorithm), &prefix[..prefix_len]).is_err());
+    }
+}
+
+#[test]
+fn stream_debug() {
+    let algorithm = &aead::AES_128_GCM;
+    let encryptor = stream::Encryptor::new(key(algorithm), &nonce_prefix(algorithm)).unwrap();
+    assert_eq!(
+        "Encryptor { algorithm: AES_128_GCM }",
+        format!("{:?}", encryptor)
+    );
+    let decryptor = stream::Decryptor::new(key(algorithm), &nonce_prefix(algorithm)).unwrap();
+    assert_eq!(
+        "Decryptor { algorithm: AES_128_GCM }",
+        format!("{:?}", decryptor)
+    );
+}
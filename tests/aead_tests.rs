@@ -20,7 +20,11 @@ use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
 #[cfg(target_arch = "wasm32")]
 wasm_bindgen_test_configure!(run_in_browser);
 
-use core::{convert::TryInto, ops::RangeFrom};
+use core::{
+    convert::{TryFrom, TryInto},
+    mem::MaybeUninit,
+    ops::RangeFrom,
+};
 use ring::{aead, error, test, test_file};
 
 /// Generate the known answer test functions for the given algorithm and test
@@ -60,14 +64,25 @@ macro_rules! test_aead {
                         $alg,
                         $test_file,
                         [
+                            less_safe_key_aad_slices,
+                            less_safe_key_open_gather,
                             less_safe_key_open_in_place,
+                            less_safe_key_open_to,
+                            less_safe_key_open_to_uninit,
                             less_safe_key_open_within,
                             less_safe_key_seal_in_place_append_tag,
                             less_safe_key_seal_in_place_separate_tag,
+                            less_safe_key_seal_to,
+                            less_safe_key_seal_to_uninit,
                             opening_key_open_in_place,
+                            opening_key_open_in_place_separate_tag,
+                            opening_key_open_to,
+                            opening_key_open_to_uninit,
                             opening_key_open_within,
                             sealing_key_seal_in_place_append_tag,
                             sealing_key_seal_in_place_separate_tag,
+                            sealing_key_seal_to,
+                            sealing_key_seal_to_uninit,
                             test_open_in_place_seperate_tag,
                         ]);
 
@@ -82,14 +97,31 @@ macro_rules! test_aead {
 }
 
 test_aead! {
+    { AEGIS_128L, "aead_aegis_128l_tests.txt" },
+    { AEGIS_256, "aead_aegis_256_tests.txt" },
+    { AES_128_CCM, "aead_aes_128_ccm_tests.txt" },
+    { AES_256_CCM, "aead_aes_256_ccm_tests.txt" },
+    { AES_128_CCM_8, "aead_aes_128_ccm_8_tests.txt" },
+    { AES_256_CCM_8, "aead_aes_256_ccm_8_tests.txt" },
+    { AES_128_CCM_13_4, "aead_aes_128_ccm_13_4_tests.txt" },
+    { AES_128_CCM_13_8, "aead_aes_128_ccm_13_8_tests.txt" },
+    { AES_128_CCM_13_16, "aead_aes_128_ccm_13_16_tests.txt" },
     { AES_128_GCM, "aead_aes_128_gcm_tests.txt" },
     { AES_256_GCM, "aead_aes_256_gcm_tests.txt" },
+    { AES_128_GCM_SIV, "aead_aes_128_gcm_siv_tests.txt" },
+    { AES_256_GCM_SIV, "aead_aes_256_gcm_siv_tests.txt" },
+    { AES_128_OCB, "aead_aes_128_ocb_tests.txt" },
+    { AES_256_OCB, "aead_aes_256_ocb_tests.txt" },
+    { AES_SIV_CMAC_256, "aead_aes_siv_cmac_256_tests.txt" },
+    { AES_SIV_CMAC_512, "aead_aes_siv_cmac_512_tests.txt" },
+    { ASCON_AEAD128, "aead_ascon_aead128_tests.txt" },
     { CHACHA20_POLY1305, "aead_chacha20_poly1305_tests.txt" },
+    { XCHACHA20_POLY1305, "aead_xchacha20_poly1305_tests.txt" },
 }
 
 struct KnownAnswerTestCase<'a> {
     key: &'a [u8],
-    nonce: [u8; aead::NONCE_LEN],
+    nonce: &'a [u8],
     plaintext: &'a [u8],
     aad: aead::Aad<&'a [u8]>,
     ciphertext: &'a [u8],
@@ -127,7 +159,7 @@ fn test_aead(
 
         let test_case = KnownAnswerTestCase {
             key: &key,
-            nonce: nonce.as_slice().try_into().unwrap(),
+            nonce: &nonce,
             plaintext: &plaintext,
             aad: aead::Aad::from(&aad),
             ciphertext: &ct,
@@ -146,7 +178,7 @@ where
     Seal: FnOnce(aead::Nonce, &mut Vec<u8>) -> Result<(), error::Unspecified>,
 {
     let mut in_out = Vec::from(tc.plaintext);
-    seal(aead::Nonce::assume_unique_for_key(tc.nonce), &mut in_out)?;
+    seal(make_nonce(tc.nonce), &mut in_out)?;
 
     let mut expected_ciphertext_and_tag = Vec::from(tc.ciphertext);
     expected_ciphertext_and_tag.extend_from_slice(tc.tag);
@@ -164,13 +196,109 @@ where
     Seal: Fn(aead::Nonce, &mut [u8]) -> Result<aead::Tag, error::Unspecified>,
 {
     let mut in_out = Vec::from(tc.plaintext);
-    let actual_tag = seal(aead::Nonce::assume_unique_for_key(tc.nonce), &mut in_out)?;
+    let actual_tag = seal(make_nonce(tc.nonce), &mut in_out)?;
     assert_eq!(actual_tag.as_ref(), tc.tag);
     assert_eq!(in_out, tc.ciphertext);
 
     Ok(())
 }
 
+fn test_seal_to<SealTo>(tc: &KnownAnswerTestCase, seal_to: SealTo) -> Result<(), error::Unspecified>
+where
+    SealTo:
+        for<'a> Fn(aead::Nonce, &[u8], &'a mut [u8]) -> Result<&'a mut [u8], error::Unspecified>,
+{
+    let mut expected_ciphertext_and_tag = Vec::from(tc.ciphertext);
+    expected_ciphertext_and_tag.extend_from_slice(tc.tag);
+    let sealed_len = expected_ciphertext_and_tag.len();
+
+    // `out` may be longer than needed; the excess is left untouched.
+    let mut out = vec![123u8; sealed_len + 3];
+    let actual = seal_to(make_nonce(tc.nonce), tc.plaintext, &mut out)?;
+    assert_eq!(actual, &expected_ciphertext_and_tag[..]);
+    assert_eq!(&out[sealed_len..], &[123u8; 3]);
+
+    // `out` is too short.
+    let mut out = vec![123u8; sealed_len - 1];
+    assert!(seal_to(make_nonce(tc.nonce), tc.plaintext, &mut out).is_err());
+
+    Ok(())
+}
+
+fn test_open_to<OpenTo>(tc: &KnownAnswerTestCase, open_to: OpenTo) -> Result<(), error::Unspecified>
+where
+    OpenTo:
+        for<'a> Fn(aead::Nonce, &[u8], &'a mut [u8]) -> Result<&'a mut [u8], error::Unspecified>,
+{
+    let mut ciphertext_and_tag = Vec::from(tc.ciphertext);
+    ciphertext_and_tag.extend_from_slice(tc.tag);
+    let plaintext_len = tc.plaintext.len();
+
+    let mut out = vec![123u8; plaintext_len + 3];
+    let actual = open_to(make_nonce(tc.nonce), &ciphertext_and_tag, &mut out)?;
+    assert_eq!(actual, tc.plaintext);
+    assert_eq!(&out[plaintext_len..], &[123u8; 3]);
+
+    if plaintext_len > 0 {
+        let mut out = vec![123u8; plaintext_len - 1];
+        assert!(open_to(make_nonce(tc.nonce), &ciphertext_and_tag, &mut out).is_err());
+    }
+
+    Ok(())
+}
+
+fn test_seal_to_uninit<SealTo>(
+    tc: &KnownAnswerTestCase,
+    seal_to: SealTo,
+) -> Result<(), error::Unspecified>
+where
+    SealTo: for<'a> Fn(
+        aead::Nonce,
+        &[u8],
+        &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], error::Unspecified>,
+{
+    let mut expected_ciphertext_and_tag = Vec::from(tc.ciphertext);
+    expected_ciphertext_and_tag.extend_from_slice(tc.tag);
+    let sealed_len = expected_ciphertext_and_tag.len();
+
+    let mut out = vec![MaybeUninit::uninit(); sealed_len + 3];
+    let actual = seal_to(make_nonce(tc.nonce), tc.plaintext, &mut out)?;
+    assert_eq!(actual, &expected_ciphertext_and_tag[..]);
+
+    let mut out = vec![MaybeUninit::uninit(); sealed_len - 1];
+    assert!(seal_to(make_nonce(tc.nonce), tc.plaintext, &mut out).is_err());
+
+    Ok(())
+}
+
+fn test_open_to_uninit<OpenTo>(
+    tc: &KnownAnswerTestCase,
+    open_to: OpenTo,
+) -> Result<(), error::Unspecified>
+where
+    OpenTo: for<'a> Fn(
+        aead::Nonce,
+        &[u8],
+        &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], error::Unspecified>,
+{
+    let mut ciphertext_and_tag = Vec::from(tc.ciphertext);
+    ciphertext_and_tag.extend_from_slice(tc.tag);
+    let plaintext_len = tc.plaintext.len();
+
+    let mut out = vec![MaybeUninit::uninit(); plaintext_len + 3];
+    let actual = open_to(make_nonce(tc.nonce), &ciphertext_and_tag, &mut out)?;
+    assert_eq!(actual, tc.plaintext);
+
+    let mut modified = ciphertext_and_tag.clone();
+    *modified.last_mut().unwrap() ^= 1;
+    let mut out = vec![MaybeUninit::uninit(); plaintext_len];
+    assert!(open_to(make_nonce(tc.nonce), &modified, &mut out).is_err());
+
+    Ok(())
+}
+
 fn test_open_in_place<OpenInPlace>(
     tc: &KnownAnswerTestCase<'_>,
     open_in_place: OpenInPlace,
@@ -179,7 +307,7 @@ where
     OpenInPlace:
         for<'a> FnOnce(aead::Nonce, &'a mut [u8]) -> Result<&'a mut [u8], error::Unspecified>,
 {
-    let nonce = aead::Nonce::assume_unique_for_key(tc.nonce);
+    let nonce = make_nonce(tc.nonce);
 
     let mut in_out = Vec::from(tc.ciphertext);
     in_out.extend_from_slice(tc.tag);
@@ -202,7 +330,7 @@ fn test_open_in_place_seperate_tag(
 
     // Test the simplest behavior.
     {
-        let nonce = aead::Nonce::assume_unique_for_key(tc.nonce);
+        let nonce = make_nonce(tc.nonce);
         let actual_plaintext =
             key.open_in_place_separate_tag(nonce, tc.aad, tag, &mut in_out, 0..)?;
 
@@ -215,7 +343,7 @@ fn test_open_in_place_seperate_tag(
         let range = in_out.len()..;
         in_out.extend_from_slice(tc.ciphertext);
 
-        let nonce = aead::Nonce::assume_unique_for_key(tc.nonce);
+        let nonce = make_nonce(tc.nonce);
         let actual_plaintext =
             key.open_in_place_separate_tag(nonce, tc.aad, tag, &mut in_out, range)?;
 
@@ -308,11 +436,7 @@ where
         in_out.extend_from_slice(tc.ciphertext);
         in_out.extend_from_slice(tc.tag);
 
-        let actual_plaintext = open_within(
-            aead::Nonce::assume_unique_for_key(tc.nonce),
-            &mut in_out,
-            in_prefix_len..,
-        )?;
+        let actual_plaintext = open_within(make_nonce(tc.nonce), &mut in_out, in_prefix_len..)?;
         assert_eq!(actual_plaintext, tc.plaintext);
         assert_eq!(&in_out[..tc.plaintext.len()], tc.plaintext);
     }
@@ -350,6 +474,64 @@ fn opening_key_open_in_place(
     })
 }
 
+fn sealing_key_seal_to(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_seal_to(&tc, |nonce, plaintext, out| {
+        let mut key: aead::SealingKey<_> = make_key(alg, tc.key, nonce);
+        key.seal_to(tc.aad, plaintext, out)
+    })
+}
+
+fn opening_key_open_to(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_open_to(&tc, |nonce, ciphertext_and_tag, out| {
+        let mut key: aead::OpeningKey<_> = make_key(alg, tc.key, nonce);
+        key.open_to(tc.aad, ciphertext_and_tag, out)
+    })
+}
+
+fn sealing_key_seal_to_uninit(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_seal_to_uninit(&tc, |nonce, plaintext, out| {
+        let mut key: aead::SealingKey<_> = make_key(alg, tc.key, nonce);
+        key.seal_to_uninit(tc.aad, plaintext, out)
+    })
+}
+
+fn opening_key_open_to_uninit(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_open_to_uninit(&tc, |nonce, ciphertext_and_tag, out| {
+        let mut key: aead::OpeningKey<_> = make_key(alg, tc.key, nonce);
+        key.open_to_uninit(tc.aad, ciphertext_and_tag, out)
+    })
+}
+
+fn opening_key_open_in_place_separate_tag(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    let tag = aead::Tag::try_from(tc.tag)?;
+
+    // The ciphertext may be preceded by a header, e.g. one carrying the tag.
+    for prefix_len in [0, 5] {
+        let mut in_out = vec![123u8; prefix_len];
+        in_out.extend_from_slice(tc.ciphertext);
+        let mut key: aead::OpeningKey<_> = make_key(alg, tc.key, make_nonce(tc.nonce));
+        let actual_plaintext =
+            key.open_in_place_separate_tag(tc.aad, tag, &mut in_out, prefix_len..)?;
+        assert_eq!(actual_plaintext, tc.plaintext);
+    }
+    Ok(())
+}
+
 fn opening_key_open_within(
     alg: &'static aead::Algorithm,
     tc: KnownAnswerTestCase,
@@ -390,6 +572,107 @@ fn less_safe_key_seal_in_place_separate_tag(
     })
 }
 
+fn less_safe_key_seal_to(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_seal_to(&tc, |nonce, plaintext, out| {
+        let key = make_less_safe_key(alg, tc.key);
+        key.seal_to(nonce, tc.aad, plaintext, out)
+    })
+}
+
+fn less_safe_key_open_to(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_open_to(&tc, |nonce, ciphertext_and_tag, out| {
+        let key = make_less_safe_key(alg, tc.key);
+        key.open_to(nonce, tc.aad, ciphertext_and_tag, out)
+    })
+}
+
+fn less_safe_key_seal_to_uninit(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_seal_to_uninit(&tc, |nonce, plaintext, out| {
+        let key = make_less_safe_key(alg, tc.key);
+        key.seal_to_uninit(nonce, tc.aad, plaintext, out)
+    })
+}
+
+fn less_safe_key_open_to_uninit(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    test_open_to_uninit(&tc, |nonce, ciphertext_and_tag, out| {
+        let key = make_less_safe_key(alg, tc.key);
+        key.open_to_uninit(nonce, tc.aad, ciphertext_and_tag, out)
+    })
+}
+
+fn less_safe_key_aad_slices(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    let key = make_less_safe_key(alg, tc.key);
+    let aad = tc.aad.as_ref();
+    let len = aad.len();
+
+    // Split the AAD into three slices at positions that straddle blocks in
+    // various ways.
+    let positions = [0, 1, 15, 16, 17, 33, len / 2, len.saturating_sub(1), len];
+    let positions = positions.iter().copied().filter(|p| *p <= len);
+    for a in positions.clone() {
+        for b in positions.clone().filter(|b| *b >= a) {
+            let slices = [&aad[..a], &aad[a..b], &aad[b..]];
+
+            let mut in_out = Vec::from(tc.plaintext);
+            let tag = key.seal_in_place_separate_tag(
+                make_nonce(tc.nonce),
+                aead::Aad::from_slices(&slices),
+                &mut in_out,
+            )?;
+            assert_eq!(&in_out, tc.ciphertext);
+            assert_eq!(tag.as_ref(), tc.tag);
+
+            in_out.extend_from_slice(tc.tag);
+            let actual_plaintext = key.open_in_place(
+                make_nonce(tc.nonce),
+                aead::Aad::from_slices(&slices),
+                &mut in_out,
+            )?;
+            assert_eq!(actual_plaintext, tc.plaintext);
+        }
+    }
+    Ok(())
+}
+
+fn less_safe_key_open_gather(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    let key = make_less_safe_key(alg, tc.key);
+    let mut ciphertext_and_tag = Vec::from(tc.ciphertext);
+    ciphertext_and_tag.extend_from_slice(tc.tag);
+
+    // Split the input into three segments, at both ends and in the middle of
+    // the ciphertext and the tag.
+    let len = ciphertext_and_tag.len();
+    for (a, b) in [(0, 0), (0, len), (len / 3, len - len / 3), (len - 1, len)] {
+        let segments = [
+            &ciphertext_and_tag[..a],
+            &ciphertext_and_tag[a..b],
+            &ciphertext_and_tag[b..],
+        ];
+        let mut out = vec![123u8; tc.plaintext.len()];
+        let actual = key.open_gather(make_nonce(tc.nonce), tc.aad, &segments, &mut out)?;
+        assert_eq!(actual, tc.plaintext);
+    }
+    Ok(())
+}
+
 fn less_safe_key_open_within(
     alg: &'static aead::Algorithm,
     tc: KnownAnswerTestCase,
@@ -444,6 +727,30 @@ fn test_aead_nonce_sizes() {
     assert!(aead::Nonce::try_assume_unique_for_key(&nonce[..16]).is_err()); // 128 bits.
 }
 
+#[test]
+fn test_aead_nonce_as_bytes() {
+    let value: [u8; aead::NONCE_LEN] = [1; aead::NONCE_LEN];
+    let nonce = aead::Nonce::assume_unique_for_key(value);
+    assert_eq!(nonce.as_bytes(), &value[..]);
+
+    // `XCHACHA20_POLY1305` and `AEGIS_256` nonces.
+    for len in [24, aead::MAX_NONCE_LEN] {
+        let value = [2; aead::MAX_NONCE_LEN];
+        let nonce = aead::Nonce::try_assume_unique_for_key_any_len(&value[..len]).unwrap();
+        assert_eq!(nonce.as_bytes(), &value[..len]);
+    }
+
+    assert_eq!(aead::Nonce::empty().as_bytes(), &[]);
+}
+
+#[test]
+fn test_aead_nonce_any_len_rejects_unused_lengths() {
+    let value = [0; aead::MAX_NONCE_LEN + 1];
+    for len in [0, 1, 8, 11, 14, 20, 31, aead::MAX_NONCE_LEN + 1] {
+        assert!(aead::Nonce::try_assume_unique_for_key_any_len(&value[..len]).is_err());
+    }
+}
+
 #[allow(clippy::range_plus_one)]
 #[test]
 fn aead_chacha20_poly1305_openssh() {
@@ -492,6 +799,67 @@ fn aead_chacha20_poly1305_openssh() {
     );
 }
 
+#[test]
+fn aead_aes_siv_multi_header() {
+    test::run(
+        test_file!("aead_aes_siv_multi_header_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let key_bytes = test_case.consume_bytes("KEY");
+            let headers: Vec<Vec<u8>> = (1..=test_case.consume_usize("HEADERS"))
+                .map(|i| test_case.consume_bytes(&format!("HEADER{}", i)))
+                .collect();
+            let headers: Vec<&[u8]> = headers.iter().map(Vec::as_slice).collect();
+            let plaintext = test_case.consume_bytes("IN");
+            let ct = test_case.consume_bytes("CT");
+            let tag = test_case.consume_bytes("TAG");
+
+            let algorithm = match key_bytes.len() {
+                32 => &aead::AES_SIV_CMAC_256,
+                64 => &aead::AES_SIV_CMAC_512,
+                _ => unreachable!(),
+            };
+            let key = aead::siv::Key::new(algorithm, &key_bytes)?;
+
+            let mut in_out = plaintext.clone();
+            let actual_tag = key.seal_in_place_separate_tag(&headers, &mut in_out)?;
+            assert_eq!(actual_tag.as_ref(), &tag[..]);
+            assert_eq!(in_out, ct);
+
+            let mut in_out = plaintext.clone();
+            key.seal_in_place_append_tag(&headers, &mut in_out)?;
+            assert_eq!(in_out, [&ct[..], &tag].concat());
+            assert_eq!(key.open_in_place(&headers, &mut in_out)?, &plaintext[..]);
+
+            // The headers are authenticated separately, not concatenated.
+            if headers.len() >= 2 {
+                let concatenated = headers.concat();
+                let mut in_out = [&ct[..], &tag].concat();
+                assert!(key.open_in_place(&[&concatenated], &mut in_out).is_err());
+            }
+
+            let mut in_out = [&ct[..], &tag].concat();
+            in_out[0] ^= 1;
+            assert!(key.open_in_place(&headers, &mut in_out).is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn aead_aes_siv_multi_header_limits() {
+    assert!(aead::siv::Key::new(&aead::AES_128_GCM, &[0; 16]).is_err());
+    assert!(aead::siv::Key::new(&aead::AES_SIV_CMAC_256, &[0; 64]).is_err());
+
+    let key = aead::siv::Key::new(&aead::AES_SIV_CMAC_512, &[0; 64]).unwrap();
+    let headers = [&[][..]; 127];
+    assert!(key
+        .seal_in_place_separate_tag(&headers[..126], &mut [])
+        .is_ok());
+    assert!(key.seal_in_place_separate_tag(&headers, &mut []).is_err());
+}
+
 #[test]
 fn aead_test_aad_traits() {
     test::compile_time_assert_copy::<aead::Aad<&'_ [u8]>>();
@@ -570,23 +938,30 @@ fn test_aead_lesssafekey_clone_for_algorithm(algorithm: &'static aead::Algorithm
     let mut buf1: Vec<u8> = (0..100).collect();
     let mut buf2 = buf1.clone();
     let tag1 = key1
-        .seal_in_place_separate_tag(
-            aead::Nonce::try_assume_unique_for_key(nonce_bytes).unwrap(),
-            aead::Aad::empty(),
-            &mut buf1,
-        )
+        .seal_in_place_separate_tag(make_nonce(nonce_bytes), aead::Aad::empty(), &mut buf1)
         .unwrap();
     let tag2 = key2
-        .seal_in_place_separate_tag(
-            aead::Nonce::try_assume_unique_for_key(nonce_bytes).unwrap(),
-            aead::Aad::empty(),
-            &mut buf2,
-        )
+        .seal_in_place_separate_tag(make_nonce(nonce_bytes), aead::Aad::empty(), &mut buf2)
         .unwrap();
     assert_eq!(tag1.as_ref(), tag2.as_ref());
     assert_eq!(buf1, buf2);
 }
 
+#[test]
+fn test_aead_lesssafekey_clone_aegis_128l() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AEGIS_128L);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_aegis_256() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AEGIS_256);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_aes_128_ccm_8() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AES_128_CCM_8);
+}
+
 #[test]
 fn test_aead_lesssafekey_clone_aes_128_gcm() {
     test_aead_lesssafekey_clone_for_algorithm(&aead::AES_128_GCM);
@@ -597,11 +972,652 @@ fn test_aead_lesssafekey_clone_aes_256_gcm() {
     test_aead_lesssafekey_clone_for_algorithm(&aead::AES_256_GCM);
 }
 
+#[test]
+fn test_aead_lesssafekey_clone_aes_128_gcm_siv() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AES_128_GCM_SIV);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_aes_256_gcm_siv() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AES_256_GCM_SIV);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_aes_128_ocb() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AES_128_OCB);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_aes_256_ocb() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::AES_256_OCB);
+}
+
+#[test]
+fn test_aead_lesssafekey_clone_ascon_aead128() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::ASCON_AEAD128);
+}
+
 #[test]
 fn test_aead_lesssafekey_clone_chacha20_poly1305() {
     test_aead_lesssafekey_clone_for_algorithm(&aead::CHACHA20_POLY1305);
 }
 
+#[test]
+fn test_aead_lesssafekey_clone_xchacha20_poly1305() {
+    test_aead_lesssafekey_clone_for_algorithm(&aead::XCHACHA20_POLY1305);
+}
+
+// Test that opening fails, and the plaintext is zeroed, when the tag or the
+// ciphertext has been modified.
+#[test]
+fn test_aead_open_modified_fails() {
+    for algorithm in [
+        &aead::AEGIS_128L,
+        &aead::AEGIS_256,
+        &aead::AES_128_CCM,
+        &aead::AES_128_CCM_8,
+        &aead::AES_128_CCM_13_4,
+        &aead::AES_128_GCM,
+        &aead::AES_128_GCM_SIV,
+        &aead::AES_256_GCM_SIV,
+        &aead::AES_128_OCB,
+        &aead::AES_256_OCB,
+        &aead::AES_SIV_CMAC_256,
+        &aead::AES_SIV_CMAC_512,
+        &aead::ASCON_AEAD128,
+        &aead::CHACHA20_POLY1305,
+        &aead::XCHACHA20_POLY1305,
+    ] {
+        let key = make_less_safe_key(algorithm, &[1u8; 64][..algorithm.key_len()]);
+        let nonce_bytes = &[2u8; aead::MAX_NONCE_LEN][..algorithm.nonce_len()];
+
+        let mut sealed = vec![3u8; 33];
+        key.seal_in_place_append_tag(make_nonce(nonce_bytes), aead::Aad::empty(), &mut sealed)
+            .unwrap();
+
+        for i in [0, 32, sealed.len() - 1] {
+            let mut in_out = sealed.clone();
+            in_out[i] ^= 1;
+            assert!(key
+                .open_in_place(make_nonce(nonce_bytes), aead::Aad::empty(), &mut in_out)
+                .is_err());
+            assert!(in_out[..33].iter().all(|b| *b == 0));
+
+            let mut modified = sealed.clone();
+            modified[i] ^= 1;
+            let mut out = [4u8; 34];
+            assert!(key
+                .open_to(
+                    make_nonce(nonce_bytes),
+                    aead::Aad::empty(),
+                    &modified,
+                    &mut out
+                )
+                .is_err());
+            assert!(out[..33].iter().all(|b| *b == 0));
+            assert_eq!(out[33], 4);
+        }
+
+        let mut in_out = sealed.clone();
+        assert!(key
+            .open_in_place(make_nonce(nonce_bytes), aead::Aad::from(&[0]), &mut in_out)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_aead_open_in_place_scatter() {
+    const PLAINTEXT_LEN: usize = if cfg!(debug_assertions) { 70 } else { 150 };
+    // The first four decrypt the segments in place; the others are opened in
+    // a temporary buffer.
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::CHACHA20_POLY1305,
+        &aead::XCHACHA20_POLY1305,
+        &aead::AES_128_GCM_SIV,
+        &aead::AES_SIV_CMAC_512,
+        &aead::AES_128_CCM_13_4,
+        &aead::AES_256_OCB,
+        &aead::AEGIS_128L,
+        &aead::ASCON_AEAD128,
+    ] {
+        let key = make_less_safe_key(algorithm, &[1u8; 64][..algorithm.key_len()]);
+        let nonce_bytes = &[2u8; aead::MAX_NONCE_LEN][..algorithm.nonce_len()];
+        let aad = aead::Aad::from(b"aad");
+        let plaintext: Vec<u8> = (0..PLAINTEXT_LEN).map(|i| i as u8).collect();
+        let mut sealed = plaintext.clone();
+        key.seal_in_place_append_tag(make_nonce(nonce_bytes), aad, &mut sealed)
+            .unwrap();
+        let len = sealed.len();
+
+        // Split the sealed message into three segments at every pair of
+        // positions, so that blocks straddle the segments in every way.
+        for a in 0..=len {
+            for b in (a..=len).step_by(7) {
+                let mut in_out = sealed.clone();
+                let (first, rest) = in_out.split_at_mut(a);
+                let (second, third) = rest.split_at_mut(b - a);
+                let mut segments = [first, second, third];
+                assert_eq!(
+                    key.open_in_place_scatter(make_nonce(nonce_bytes), aad, &mut segments),
+                    Ok(PLAINTEXT_LEN)
+                );
+                assert_eq!(&in_out[..PLAINTEXT_LEN], &plaintext[..]);
+            }
+        }
+
+        // Modifications are detected and the plaintext is zeroed.
+        for i in [0, PLAINTEXT_LEN, len - 1] {
+            let mut in_out = sealed.clone();
+            in_out[i] ^= 1;
+            let (first, second) = in_out.split_at_mut(33);
+            assert!(key
+                .open_in_place_scatter(make_nonce(nonce_bytes), aad, &mut [first, second])
+                .is_err());
+            assert!(in_out[..PLAINTEXT_LEN].iter().all(|b| *b == 0));
+        }
+
+        // Too short to contain a tag.
+        let mut short = [0u8; aead::MAX_TAG_LEN];
+        let short = &mut short[..(algorithm.tag_len() - 1)];
+        assert!(key
+            .open_in_place_scatter(make_nonce(nonce_bytes), aad, &mut [short])
+            .is_err());
+    }
+}
+
+#[test]
+fn test_aead_counter_nonce_sequence() {
+    use aead::{CounterNonceSequence, Endianness, NonceSequence as _};
+
+    let mut big = CounterNonceSequence::new(Endianness::Big);
+    assert_eq!(big.advance().unwrap().as_bytes(), &[0; 12]);
+    assert_eq!(
+        big.advance().unwrap().as_bytes(),
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]
+    );
+
+    let mut little = CounterNonceSequence::starting_at(0x0102, Endianness::Little).unwrap();
+    assert_eq!(
+        little.advance().unwrap().as_bytes(),
+        &[2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        little.advance().unwrap().as_bytes(),
+        &[3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    // The sequence stops, permanently, after 2^96 - 1.
+    const MAX: u128 = (1 << 96) - 1;
+    assert!(CounterNonceSequence::starting_at(MAX + 1, Endianness::Big).is_err());
+    let mut last = CounterNonceSequence::starting_at(MAX - 1, Endianness::Big).unwrap();
+    assert_eq!(
+        last.advance().unwrap().as_bytes(),
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]
+    );
+    assert_eq!(last.advance().unwrap().as_bytes(), &[0xff; 12]);
+    assert!(last.advance().is_err());
+    assert!(last.advance().is_err());
+}
+
+#[test]
+fn test_aead_xor_counter_nonce_sequence() {
+    use aead::{Endianness, NonceSequence as _, XorCounterNonceSequence};
+
+    const IV: [u8; 12] = [
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b,
+    ];
+
+    // RFC 8446 Section 5.3.
+    let mut tls = XorCounterNonceSequence::new(IV, Endianness::Big);
+    assert_eq!(tls.advance().unwrap().as_bytes(), &IV);
+    assert_eq!(
+        tls.advance().unwrap().as_bytes(),
+        &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1a]
+    );
+
+    let mut little = XorCounterNonceSequence::starting_at(IV, 0x0102, Endianness::Little);
+    assert_eq!(
+        little.advance().unwrap().as_bytes(),
+        &[0x12, 0x10, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b]
+    );
+
+    // The sequence stops, permanently, after 2^64 - 1.
+    let mut last = XorCounterNonceSequence::starting_at(IV, u64::MAX, Endianness::Big);
+    assert_eq!(
+        last.advance().unwrap().as_bytes(),
+        &[0x10, 0x11, 0x12, 0x13, 0xeb, 0xea, 0xe9, 0xe8, 0xe7, 0xe6, 0xe5, 0xe4]
+    );
+    assert!(last.advance().is_err());
+    assert!(last.advance().is_err());
+}
+
+#[test]
+fn test_aead_counter_nonce_sequence_bound_keys() {
+    use aead::{BoundKey as _, Endianness, XorCounterNonceSequence};
+
+    const IV: [u8; 12] = [7; 12];
+    let key_bytes = [1u8; 16];
+    let mut sealing_key = aead::SealingKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM, &key_bytes).unwrap(),
+        XorCounterNonceSequence::new(IV, Endianness::Big),
+    );
+    let mut opening_key = aead::OpeningKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM, &key_bytes).unwrap(),
+        XorCounterNonceSequence::new(IV, Endianness::Big),
+    );
+    for record in [&b"first"[..], b"second", b"third"] {
+        let mut in_out = Vec::from(record);
+        sealing_key
+            .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        let plaintext = opening_key
+            .open_in_place(aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        assert_eq!(plaintext, record);
+    }
+}
+
+#[test]
+fn test_aead_rekey() {
+    use aead::{BoundKey as _, Endianness, XorCounterNonceSequence};
+    use ring::hkdf;
+
+    const IV: [u8; 12] = [7; 12];
+    const SECRET: [u8; 32] = [9; 32];
+    let key_bytes = [1u8; 16];
+    let mut sealing_key = aead::SealingKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM, &key_bytes).unwrap(),
+        XorCounterNonceSequence::new(IV, Endianness::Big),
+    );
+    let mut opening_key = aead::OpeningKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM, &key_bytes).unwrap(),
+        XorCounterNonceSequence::new(IV, Endianness::Big),
+    );
+    sealing_key.set_usage_limits(aead::UsageLimits::new(10, 1000));
+    let mut sealing_ratchet = aead::KeyRatchet::new(&aead::AES_128_GCM, hkdf::HKDF_SHA256, &SECRET);
+    let mut opening_ratchet = aead::KeyRatchet::new(&aead::AES_128_GCM, hkdf::HKDF_SHA256, &SECRET);
+
+    // The derived keys are the documented HKDF-Expand outputs.
+    let expected_keys = {
+        let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &SECRET);
+        let first = aead::LessSafeKey::new(
+            prk.expand(&[b"ring aead ratchet key"], &aead::AES_128_GCM)
+                .unwrap()
+                .into(),
+        );
+        let prk: hkdf::Prk = prk
+            .expand(&[b"ring aead ratchet secret"], hkdf::HKDF_SHA256)
+            .unwrap()
+            .into();
+        let second = aead::LessSafeKey::new(
+            prk.expand(&[b"ring aead ratchet key"], &aead::AES_128_GCM)
+                .unwrap()
+                .into(),
+        );
+        [first, second]
+    };
+
+    let mut in_out = Vec::from(&b"before"[..]);
+    sealing_key
+        .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+        .unwrap();
+    opening_key
+        .open_in_place(aead::Aad::empty(), &mut in_out)
+        .unwrap();
+
+    // The nonce sequence continues across key updates.
+    for (counter, expected_key) in [1u128, 2].iter().zip(&expected_keys) {
+        sealing_key
+            .rekey(sealing_ratchet.next_key().unwrap())
+            .unwrap();
+        assert_eq!(sealing_key.usage().unwrap().messages(), 0);
+
+        let mut in_out = Vec::from(&b"after"[..]);
+        sealing_key
+            .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+            .unwrap();
+
+        let mut nonce = IV;
+        for (n, c) in nonce.iter_mut().zip(&counter.to_be_bytes()[4..]) {
+            *n ^= c;
+        }
+        let mut expected = Vec::from(&b"after"[..]);
+        expected_key
+            .seal_in_place_append_tag(
+                aead::Nonce::assume_unique_for_key(nonce),
+                aead::Aad::empty(),
+                &mut expected,
+            )
+            .unwrap();
+        assert_eq!(in_out, expected);
+
+        opening_key
+            .rekey(opening_ratchet.next_key().unwrap())
+            .unwrap();
+        assert_eq!(
+            opening_key
+                .open_in_place(aead::Aad::empty(), &mut in_out[..])
+                .unwrap(),
+            b"after"
+        );
+    }
+
+    // The algorithm can't change.
+    assert!(sealing_key
+        .rekey(aead::UnboundKey::new(&aead::AES_256_GCM, &[0; 32]).unwrap())
+        .is_err());
+    assert!(opening_key
+        .rekey(aead::UnboundKey::new(&aead::CHACHA20_POLY1305, &[0; 32]).unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_aead_with_tag_len() {
+    for algorithm in [&aead::AES_128_GCM, &aead::AES_256_GCM] {
+        let key_bytes = &[1u8; 32][..algorithm.key_len()];
+        let full_key = make_less_safe_key(algorithm, key_bytes);
+        let plaintext = b"truncated tag plaintext";
+        let mut full = Vec::from(&plaintext[..]);
+        let full_tag = full_key
+            .seal_in_place_separate_tag(make_nonce(&[2; 12]), aead::Aad::from(b"aad"), &mut full)
+            .unwrap();
+
+        for tag_len in [8, 12, 13, 14, 15, 16] {
+            let truncated = algorithm.with_tag_len(tag_len).unwrap();
+            assert_eq!(truncated.tag_len(), tag_len);
+            assert_eq!(truncated.key_len(), algorithm.key_len());
+            assert_eq!(truncated.with_tag_len(16).unwrap(), algorithm);
+            assert_eq!(truncated == algorithm, tag_len == 16);
+
+            // The tag is the truncated full tag.
+            let key = make_less_safe_key(truncated, key_bytes);
+            let mut in_out = Vec::from(&plaintext[..]);
+            key.seal_in_place_append_tag(
+                make_nonce(&[2; 12]),
+                aead::Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+            assert_eq!(&in_out[..plaintext.len()], &full[..]);
+            assert_eq!(&in_out[plaintext.len()..], &full_tag.as_ref()[..tag_len]);
+
+            let mut modified = in_out.clone();
+            *modified.last_mut().unwrap() ^= 1;
+            assert!(key
+                .open_in_place(make_nonce(&[2; 12]), aead::Aad::from(b"aad"), &mut modified)
+                .is_err());
+
+            let mut segments = in_out.clone();
+            let (first, second) = segments.split_at_mut(5);
+            assert_eq!(
+                key.open_in_place_scatter(
+                    make_nonce(&[2; 12]),
+                    aead::Aad::from(b"aad"),
+                    &mut [first, second]
+                ),
+                Ok(plaintext.len())
+            );
+
+            let opened = key
+                .open_in_place(make_nonce(&[2; 12]), aead::Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            assert_eq!(opened, &plaintext[..]);
+        }
+
+        // Shorter tags, and tags that aren't allowed by SP 800-38D, are
+        // rejected.
+        for tag_len in [0, 1, 4, 7, 9, 10, 11, 17] {
+            assert!(algorithm.with_tag_len(tag_len).is_err());
+        }
+    }
+
+    // CCM tag lengths map to the corresponding algorithms.
+    assert_eq!(
+        aead::AES_128_CCM.with_tag_len(8).unwrap(),
+        &aead::AES_128_CCM_8
+    );
+    assert_eq!(
+        aead::AES_256_CCM_8.with_tag_len(16).unwrap(),
+        &aead::AES_256_CCM
+    );
+    assert_eq!(
+        aead::AES_128_CCM_13_4.with_tag_len(8).unwrap(),
+        &aead::AES_128_CCM_13_8
+    );
+    assert!(aead::AES_128_CCM.with_tag_len(4).is_err());
+
+    // Other algorithms only have their own tag length.
+    assert_eq!(
+        aead::CHACHA20_POLY1305.with_tag_len(16).unwrap(),
+        &aead::CHACHA20_POLY1305
+    );
+    assert!(aead::CHACHA20_POLY1305.with_tag_len(12).is_err());
+    assert!(aead::AES_128_GCM_SIV.with_tag_len(12).is_err());
+
+    assert_eq!(
+        format!("{:?}", aead::AES_256_GCM.with_tag_len(12).unwrap()),
+        "AES_256_GCM_12"
+    );
+}
+
+#[test]
+fn test_aead_usage_tracker() {
+    let mut usage = aead::UsageTracker::new(aead::UsageLimits::new(3, 100));
+    assert!(!usage.is_exhausted());
+    assert_eq!(usage.record(60), Ok(()));
+
+    // Failures don't count.
+    assert!(usage.record(41).is_err());
+    assert_eq!((usage.messages(), usage.bytes()), (1, 60));
+
+    assert_eq!(usage.record(40), Ok(()));
+    assert!(usage.is_exhausted());
+    assert_eq!(usage.record(0), Ok(()));
+    assert!(usage.record(0).is_err());
+    assert_eq!((usage.messages(), usage.bytes()), (3, 100));
+    assert_eq!(usage.limits(), aead::UsageLimits::new(3, 100));
+}
+
+#[test]
+fn test_aead_sealing_key_usage_limits() {
+    use aead::{BoundKey as _, CounterNonceSequence, Endianness};
+
+    let new_key = || {
+        aead::SealingKey::new(
+            aead::UnboundKey::new(&aead::AES_128_GCM, &[1; 16]).unwrap(),
+            CounterNonceSequence::new(Endianness::Big),
+        )
+    };
+
+    let mut key = new_key();
+    assert!(key.usage().is_none());
+    key.set_usage_limits(aead::UsageLimits::new(2, 1000));
+
+    let mut first = vec![0u8; 10];
+    key.seal_in_place_append_tag(aead::Aad::empty(), &mut first)
+        .unwrap();
+    let mut second = [0u8; 20];
+    let _ = key
+        .seal_in_place_separate_tag(aead::Aad::empty(), &mut second)
+        .unwrap();
+    assert_eq!(key.usage().map(|usage| usage.bytes()), Some(30));
+    assert!(key.usage().unwrap().is_exhausted());
+
+    let mut out = [0u8; 16];
+    assert!(key.seal_to(aead::Aad::empty(), &[], &mut out).is_err());
+
+    // A message that fails to be sealed isn't recorded.
+    key.set_usage_limits(aead::UsageLimits::new(2, 1000));
+    let mut too_small = [0u8; 15];
+    assert!(key
+        .seal_to(aead::Aad::empty(), &[], &mut too_small)
+        .is_err());
+    assert_eq!(key.usage().map(|usage| usage.messages()), Some(0));
+
+    // The operation that failed the limits didn't advance the nonce sequence,
+    // but the one with the short output buffer did, so the next message uses
+    // the fourth nonce.
+    key.set_usage_limits(aead::UsageLimits::new(1, 1000));
+    let mut third = vec![0u8; 10];
+    key.seal_in_place_append_tag(aead::Aad::empty(), &mut third)
+        .unwrap();
+
+    let mut unlimited = new_key();
+    let mut expected = Vec::new();
+    for _ in 0..4 {
+        expected = vec![0u8; 10];
+        unlimited
+            .seal_in_place_append_tag(aead::Aad::empty(), &mut expected)
+            .unwrap();
+    }
+    assert_eq!(third, expected);
+}
+
+// Test that a key rejects nonces whose length doesn't match its algorithm's
+// `nonce_len()`.
+#[test]
+fn test_aead_nonce_len_mismatch() {
+    let nonce_bytes = [0u8; aead::MAX_NONCE_LEN];
+    for (algorithm, wrong_nonce_len) in [
+        (&aead::AES_128_GCM, aead::MAX_NONCE_LEN),
+        (&aead::AES_256_GCM_SIV, aead::MAX_NONCE_LEN),
+        (&aead::CHACHA20_POLY1305, aead::MAX_NONCE_LEN),
+        (&aead::XCHACHA20_POLY1305, aead::NONCE_LEN),
+    ] {
+        let key = make_less_safe_key(algorithm, &[0u8; 32][..algorithm.key_len()]);
+
+        let mut in_out = vec![0u8; 16];
+        assert!(key
+            .seal_in_place_separate_tag(
+                make_nonce(&nonce_bytes[..wrong_nonce_len]),
+                aead::Aad::empty(),
+                &mut in_out,
+            )
+            .is_err());
+        assert!(key
+            .open_in_place(
+                make_nonce(&nonce_bytes[..wrong_nonce_len]),
+                aead::Aad::empty(),
+                &mut in_out,
+            )
+            .is_err());
+    }
+
+    assert!(
+        aead::Nonce::try_assume_unique_for_key_any_len(&[0u8; aead::MAX_NONCE_LEN + 1]).is_err()
+    );
+}
+
+// Test that opening rejects tags whose length doesn't match the algorithm's
+// `tag_len()`, including a full-length tag for a truncated-tag algorithm.
+#[test]
+fn test_aead_tag_len_mismatch() {
+    let nonce = [0u8; aead::NONCE_LEN];
+    for (algorithm, wrong_tag_len) in [
+        (&aead::AES_128_GCM, 8),
+        (&aead::AES_128_CCM, 8),
+        (&aead::AES_128_CCM_8, 16),
+        (&aead::AES_128_CCM_8, 4),
+    ] {
+        let key = make_less_safe_key(algorithm, &[0u8; 16]);
+        let mut in_out = vec![0u8; 16];
+        let tag = key
+            .seal_in_place_separate_tag(make_nonce(&nonce), aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        assert_eq!(tag.as_ref().len(), algorithm.tag_len());
+
+        let mut wrong_tag = vec![0u8; wrong_tag_len];
+        let common_len = core::cmp::min(wrong_tag_len, algorithm.tag_len());
+        wrong_tag[..common_len].copy_from_slice(&tag.as_ref()[..common_len]);
+        let wrong_tag = aead::Tag::try_from(&wrong_tag[..]).unwrap();
+        assert!(key
+            .open_in_place_separate_tag(
+                make_nonce(&nonce),
+                aead::Aad::empty(),
+                wrong_tag,
+                &mut in_out.clone(),
+                0..
+            )
+            .is_err());
+        assert!(key
+            .open_in_place_separate_tag(
+                make_nonce(&nonce),
+                aead::Aad::empty(),
+                tag,
+                &mut in_out,
+                0..
+            )
+            .is_ok());
+    }
+
+    assert!(aead::Tag::try_from(&[][..]).is_err());
+    assert!(aead::Tag::try_from(&[0u8; aead::MAX_TAG_LEN + 1][..]).is_err());
+    assert_eq!(
+        aead::Tag::try_from(&[1u8; 4][..]).unwrap().as_ref(),
+        &[1u8; 4]
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_aead_parallel() {
+    const MIB: usize = 1024 * 1024;
+    let algorithms = [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        aead::AES_256_GCM.with_tag_len(12).unwrap(),
+        &aead::CHACHA20_POLY1305,
+    ];
+    for algorithm in algorithms {
+        let key = make_less_safe_key(algorithm, &[3; 32][..algorithm.key_len()]);
+        for len in [0, 17, MIB - 1, MIB, (3 * MIB) + (3 * MIB / 4) + 17] {
+            let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+            let mut expected = plaintext.clone();
+            let expected_tag = key
+                .seal_in_place_separate_tag(make_nonce(&[4; 12]), aead::Aad::empty(), &mut expected)
+                .unwrap();
+
+            let mut in_out = plaintext.clone();
+            let tag = key
+                .seal_in_place_separate_tag_parallel(
+                    make_nonce(&[4; 12]),
+                    aead::Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(in_out, expected);
+            assert_eq!(tag.as_ref(), expected_tag.as_ref());
+
+            let opened = key
+                .open_in_place_separate_tag_parallel(
+                    make_nonce(&[4; 12]),
+                    aead::Aad::empty(),
+                    tag,
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(opened, &plaintext[..]);
+
+            if len > 0 {
+                let mut modified = expected.clone();
+                modified[len / 2] ^= 1;
+                assert!(key
+                    .open_in_place_separate_tag_parallel(
+                        make_nonce(&[4; 12]),
+                        aead::Aad::empty(),
+                        expected_tag,
+                        &mut modified,
+                    )
+                    .is_err());
+                assert!(modified.iter().all(|&b| b == 0));
+            }
+        }
+    }
+}
+
 fn make_key<K: aead::BoundKey<OneNonceSequence>>(
     algorithm: &'static aead::Algorithm,
     key: &[u8],
@@ -612,6 +1628,13 @@ fn make_key<K: aead::BoundKey<OneNonceSequence>>(
     K::new(key, nonce_sequence)
 }
 
+fn make_nonce(nonce: &[u8]) -> aead::Nonce {
+    if nonce.is_empty() {
+        return aead::Nonce::empty();
+    }
+    aead::Nonce::try_assume_unique_for_key_any_len(nonce).unwrap()
+}
+
 fn make_less_safe_key(algorithm: &'static aead::Algorithm, key: &[u8]) -> aead::LessSafeKey {
     let key = aead::UnboundKey::new(algorithm, key).unwrap();
     aead::LessSafeKey::new(key)
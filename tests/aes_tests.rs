@@ -0,0 +1,152 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{cipher::aes, test};
+
+// NIST SP 800-38A Appendix F.
+const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                         30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+const KEY_128: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+const KEY_256: &str = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+const CBC_IV: [u8; aes::BLOCK_LEN] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+const CTR_IV: [u8; aes::BLOCK_LEN] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff,
+];
+
+fn key(algorithm: &'static aes::Algorithm, key_hex: &str) -> aes::Key {
+    aes::Key::new(algorithm, &test::from_hex(key_hex).unwrap()).unwrap()
+}
+
+#[test]
+fn aes_cbc() {
+    for &(algorithm, key_hex, ciphertext) in &[
+        (
+            &aes::AES_128,
+            KEY_128,
+            "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+             73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+        ),
+        (
+            &aes::AES_256,
+            KEY_256,
+            "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d\
+             39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+        ),
+    ] {
+        let key = key(algorithm, key_hex);
+        let plaintext = test::from_hex(PLAINTEXT).unwrap();
+        let ciphertext = test::from_hex(ciphertext).unwrap();
+
+        let mut in_out = plaintext.clone();
+        key.cbc_encrypt(&CBC_IV, &mut in_out).unwrap();
+        assert_eq!(in_out, ciphertext);
+
+        key.cbc_decrypt(&CBC_IV, &mut in_out).unwrap();
+        assert_eq!(in_out, plaintext);
+    }
+}
+
+#[test]
+fn aes_cbc_partial_block() {
+    let key = key(&aes::AES_128, KEY_128);
+    let mut in_out = [0u8; aes::BLOCK_LEN + 1];
+    assert!(key.cbc_encrypt(&CBC_IV, &mut in_out).is_err());
+    assert!(key.cbc_decrypt(&CBC_IV, &mut in_out).is_err());
+    assert_eq!(in_out, [0u8; aes::BLOCK_LEN + 1]);
+
+    assert!(key.cbc_encrypt(&CBC_IV, &mut []).is_ok());
+}
+
+#[test]
+fn aes_ctr() {
+    for &(algorithm, key_hex, ciphertext) in &[
+        (
+            &aes::AES_128,
+            KEY_128,
+            "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+             5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+        ),
+        (
+            &aes::AES_256,
+            KEY_256,
+            "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5\
+             2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+        ),
+    ] {
+        let key = key(algorithm, key_hex);
+        let plaintext = test::from_hex(PLAINTEXT).unwrap();
+        let ciphertext = test::from_hex(ciphertext).unwrap();
+
+        // Every length, so that partial final blocks are exercised.
+        for len in 0..=plaintext.len() {
+            let mut in_out = plaintext[..len].to_vec();
+            key.ctr_apply_keystream(&CTR_IV, &mut in_out);
+            assert_eq!(&in_out[..], &ciphertext[..len]);
+
+            key.ctr_apply_keystream(&CTR_IV, &mut in_out);
+            assert_eq!(&in_out[..], &plaintext[..len]);
+        }
+    }
+}
+
+#[test]
+fn aes_ctr_counter_carry() {
+    let key = key(&aes::AES_128, KEY_128);
+
+    // The low 32 bits of the counter carry into the rest of the block.
+    let iv = [
+        0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    ];
+    let mut in_out = [0u8; 70];
+    key.ctr_apply_keystream(&iv, &mut in_out);
+    assert_eq!(
+        &in_out[..],
+        &test::from_hex(
+            "744705854ce8223d889bf0bd507b2757658ecd9c327a31e08b228c3f00d2251c\
+             b0f178d05a9afc90616a1fa86680f49962e9b1c0195c3d343774abb1491f57ec\
+             9604d64f9822"
+        )
+        .unwrap()[..]
+    );
+
+    // The whole counter wraps around at 2^128.
+    let mut iv = [0xff; aes::BLOCK_LEN];
+    iv[aes::BLOCK_LEN - 1] = 0xfe;
+    let mut in_out = [0u8; 40];
+    key.ctr_apply_keystream(&iv, &mut in_out);
+    assert_eq!(
+        &in_out[..],
+        &test::from_hex(
+            "d1b714b6fbf5fff1289aee2a4c4eeda38af2860142f786f409307c1a3f7eaaac\
+             7df76b0c1ab899b3"
+        )
+        .unwrap()[..]
+    );
+}
+
+#[test]
+fn aes_key_len() {
+    assert!(aes::Key::new(&aes::AES_128, &[0; 32]).is_err());
+    assert!(aes::Key::new(&aes::AES_256, &[0; 16]).is_err());
+    assert!(aes::Key::new(&aes::AES_128, &[0; 16]).is_ok());
+    assert!(aes::Key::new(&aes::AES_256, &[0; 32]).is_ok());
+}
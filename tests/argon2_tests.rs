@@ -0,0 +1,98 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{argon2, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn argon2_tests() {
+    test::run(test_file!("argon2_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let secret = test_case.consume_bytes("Password");
+        let salt = test_case.consume_bytes("Salt");
+        let key = test_case.consume_bytes("Key");
+        let ad = test_case.consume_bytes("AD");
+        let memory = test_case.consume_usize("Memory");
+        let iterations = test_case.consume_usize("Iterations");
+        let parallelism = test_case.consume_usize("Parallelism");
+        let tag = test_case.consume_bytes("Tag");
+
+        let params =
+            argon2::Params::new(memory as u32, iterations as u32, parallelism as u32).unwrap();
+
+        let mut out = vec![0u8; tag.len()];
+        argon2::derive_keyed(&params, &key, &ad, &salt, &secret, &mut out).unwrap();
+        assert_eq!(tag, out);
+
+        assert!(argon2::verify_keyed(&params, &key, &ad, &salt, &secret, &tag).is_ok());
+
+        let mut wrong = tag.clone();
+        wrong[0] ^= 1;
+        assert!(argon2::verify_keyed(&params, &key, &ad, &salt, &secret, &wrong).is_err());
+
+        if key.is_empty() && ad.is_empty() {
+            argon2::derive(&params, &salt, &secret, &mut out).unwrap();
+            assert_eq!(tag, out);
+            assert!(argon2::verify(&params, &salt, &secret, &tag).is_ok());
+        } else {
+            assert!(argon2::verify(&params, &salt, &secret, &tag).is_err());
+        }
+
+        let mut wrong_secret = secret.clone();
+        wrong_secret.push(b'!');
+        assert!(argon2::verify_keyed(&params, &key, &ad, &salt, &wrong_secret, &tag).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn argon2_params() {
+    assert!(argon2::Params::new(8, 1, 1).is_ok());
+    assert!(argon2::Params::new(64 * 1024, 3, 4).is_ok());
+
+    assert!(argon2::Params::new(8, 0, 1).is_err());
+    assert!(argon2::Params::new(8, 1, 0).is_err());
+    assert!(argon2::Params::new(7, 1, 1).is_err());
+    assert!(argon2::Params::new(31, 1, 4).is_err());
+    assert!(argon2::Params::new(u32::MAX, 1, 1 << 24).is_err());
+
+    let params = argon2::Params::new(19 * 1024, 2, 1).unwrap();
+    assert_eq!(params.memory_kib(), 19 * 1024);
+    assert_eq!(params.iterations(), 2);
+    assert_eq!(params.parallelism(), 1);
+}
+
+#[test]
+fn argon2_invalid_inputs() {
+    let params = argon2::Params::new(8, 1, 1).unwrap();
+    let salt = [0u8; argon2::MIN_SALT_LEN];
+
+    let mut out = [0u8; argon2::MIN_OUTPUT_LEN];
+    assert!(argon2::derive(&params, &salt, b"secret", &mut out).is_ok());
+    assert!(argon2::derive(&params, &salt[1..], b"secret", &mut out).is_err());
+    assert!(argon2::derive(&params, &salt, b"secret", &mut out[1..]).is_err());
+    assert!(argon2::verify(&params, &salt, b"secret", &[]).is_err());
+
+    let key = [0u8; argon2::MAX_KEY_LEN + 1];
+    assert!(argon2::derive_keyed(&params, &key[1..], b"", &salt, b"secret", &mut out).is_ok());
+    assert!(argon2::derive_keyed(&params, &key, b"", &salt, b"secret", &mut out).is_err());
+}
@@ -0,0 +1,136 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{bcrypt, rand, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn bcrypt_tests() {
+    test::run(test_file!("bcrypt_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let password = test_case.consume_bytes("Password");
+        let hash = String::from_utf8(test_case.consume_bytes("Hash")).unwrap();
+
+        assert!(bcrypt::verify(&password, &hash).is_ok());
+
+        let mut wrong_password = password.clone();
+        wrong_password.insert(0, b'!');
+        assert!(bcrypt::verify(&wrong_password, &hash).is_err());
+
+        // Only the checksum differs.
+        let mut wrong_hash = hash.clone().into_bytes();
+        let last = wrong_hash.last_mut().unwrap();
+        *last = if *last == b'a' { b'b' } else { b'a' };
+        let wrong_hash = String::from_utf8(wrong_hash).unwrap();
+        assert!(bcrypt::verify(&password, &wrong_hash).is_err());
+
+        // Hashing with the same salt and cost gives the same hash, in the
+        // `$2b$` format.
+        if password.len() <= bcrypt::MAX_PASSWORD_LEN {
+            let cost = hash[4..6].parse().unwrap();
+            let mut salt = [0u8; bcrypt::SALT_LEN];
+            decode_salt(&hash[7..29], &mut salt);
+            let actual = bcrypt::hash_with_salt(&password, cost, &salt).unwrap();
+            assert_eq!(&actual.as_ref()[4..], &hash[4..]);
+            assert!(actual.as_ref().starts_with("$2b$"));
+        } else {
+            // Only the first 72 bytes count.
+            assert!(bcrypt::verify(&password[..bcrypt::MAX_PASSWORD_LEN], &hash).is_ok());
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn bcrypt_hash() {
+    let rng = rand::SystemRandom::new();
+    let a = bcrypt::hash(b"password", 4, &rng).unwrap();
+    let b = bcrypt::hash(b"password", 4, &rng).unwrap();
+    assert_ne!(a.as_ref(), b.as_ref());
+    assert_eq!(a.as_ref().len(), bcrypt::ENCODED_LEN);
+    assert!(a.as_ref().starts_with("$2b$04$"));
+    assert_eq!(format!("{}", a), a.as_ref());
+    assert_eq!(format!("{:?}", a), "PasswordHash");
+    assert!(bcrypt::verify(b"password", a.as_ref()).is_ok());
+    assert!(bcrypt::verify(b"password", b.as_ref()).is_ok());
+
+    // The cost must be in range.
+    let salt = [0u8; bcrypt::SALT_LEN];
+    assert!(bcrypt::hash_with_salt(b"pw", bcrypt::MIN_COST - 1, &salt).is_err());
+    assert!(bcrypt::hash_with_salt(b"pw", bcrypt::MAX_COST + 1, &salt).is_err());
+
+    // Passwords that bcrypt would silently truncate are rejected.
+    let long = [b'a'; bcrypt::MAX_PASSWORD_LEN + 1];
+    assert!(bcrypt::hash_with_salt(&long[1..], 4, &salt).is_ok());
+    assert!(bcrypt::hash_with_salt(&long, 4, &salt).is_err());
+    assert!(bcrypt::hash_with_salt(b"pass\0word", 4, &salt).is_err());
+}
+
+#[test]
+fn bcrypt_verify_truncates_at_zero_byte() {
+    let hash = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+    assert!(bcrypt::verify(b"U*U", hash).is_ok());
+    assert!(bcrypt::verify(b"U*U\0ignored", hash).is_ok());
+}
+
+#[test]
+fn bcrypt_verify_invalid_encoding() {
+    let hash = "$2b$04$......................LAtw7/ohmmBAhnXqmkuIz83Rl5Qdjhm";
+    assert!(bcrypt::verify(b"password", hash).is_ok());
+
+    let with = |range: core::ops::Range<usize>, replacement: &str| {
+        let mut h = hash.to_string();
+        h.replace_range(range, replacement);
+        bcrypt::verify(b"password", &h)
+    };
+    // Other versions.
+    assert!(with(0..4, "$2x$").is_err());
+    assert!(with(0..4, "$2$$").is_err());
+    assert!(with(0..4, "$1$$").is_err());
+    // The cost must be two digits, in range.
+    assert!(with(4..6, "4$").is_err());
+    assert!(with(4..6, "03").is_err());
+    assert!(with(4..6, "32").is_err());
+    assert!(with(4..6, "a4").is_err());
+    assert!(with(6..7, ".").is_err());
+    // The salt must be valid base64 in bcrypt's alphabet, and canonical.
+    assert!(with(7..8, "+").is_err());
+    assert!(with(28..29, "/").is_err());
+    // The length is fixed.
+    assert!(bcrypt::verify(b"password", &hash[..59]).is_err());
+    assert!(bcrypt::verify(b"password", &format!("{}.", hash)).is_err());
+    assert!(bcrypt::verify(b"password", "").is_err());
+}
+
+/// Decodes bcrypt's base64, for getting the salt out of an encoded hash.
+fn decode_salt(encoded: &str, out: &mut [u8; bcrypt::SALT_LEN]) {
+    const ALPHABET: &[u8] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    let mut bits: u128 = 0;
+    for (i, c) in encoded.bytes().enumerate() {
+        let value = ALPHABET.iter().position(|&a| a == c).unwrap() as u128;
+        // 22 characters are 132 bits; the last 4 are padding.
+        bits = if i < 21 {
+            (bits << 6) | value
+        } else {
+            (bits << 2) | (value >> 4)
+        };
+    }
+    *out = bits.to_be_bytes();
+}
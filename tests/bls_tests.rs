@@ -0,0 +1,274 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{bls, error, rand, signature, signature::KeyPair, test, test_file};
+
+fn algorithm(section: &str) -> &'static bls::Algorithm {
+    match section {
+        "MIN_PK_BASIC" => &bls::BLS12_381_MIN_PK_BASIC,
+        "MIN_PK_POP" => &bls::BLS12_381_MIN_PK_POP,
+        "MIN_SIG_BASIC" => &bls::BLS12_381_MIN_SIG_BASIC,
+        "MIN_SIG_POP" => &bls::BLS12_381_MIN_SIG_POP,
+        _ => unreachable!(),
+    }
+}
+
+static ALGORITHMS: [&bls::Algorithm; 4] = [
+    &bls::BLS12_381_MIN_PK_BASIC,
+    &bls::BLS12_381_MIN_PK_POP,
+    &bls::BLS12_381_MIN_SIG_BASIC,
+    &bls::BLS12_381_MIN_SIG_POP,
+];
+
+#[test]
+fn test_bls_key_gen() {
+    test::run(test_file!("bls_keygen_tests.txt"), |section, test_case| {
+        let alg = algorithm(section);
+        let ikm = test_case.consume_bytes("IKM");
+        let key_info = test_case.consume_bytes("KeyInfo");
+        let private_key = test_case.consume_bytes("SK");
+        let public_key = test_case.consume_bytes("PK");
+
+        let key_pair = bls::KeyPair::from_ikm(alg, &ikm, &key_info).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), &public_key[..]);
+
+        let key_pair = bls::KeyPair::from_private_key(alg, &private_key).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), &public_key[..]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_bls_sign() {
+    test::run(test_file!("bls_sign_tests.txt"), |section, test_case| {
+        let alg = algorithm(section);
+        let private_key = test_case.consume_bytes("SK");
+        let public_key = test_case.consume_bytes("PK");
+
+        let key_pair = bls::KeyPair::from_private_key(alg, &private_key).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), &public_key[..]);
+
+        if let Some(expected_proof) = test_case.consume_optional_string("PoP") {
+            let expected_proof = test::from_hex(&expected_proof).unwrap();
+            let proof = key_pair.prove_possession().unwrap();
+            assert_eq!(proof.as_ref(), &expected_proof[..]);
+            assert!(bls::verify_possession(alg, &public_key, &expected_proof).is_ok());
+            return Ok(());
+        }
+
+        let msg = test_case.consume_bytes("Msg");
+        let expected_signature = test_case.consume_bytes("Sig");
+
+        let signature = key_pair.sign(&msg);
+        assert_eq!(signature.as_ref(), &expected_signature[..]);
+
+        assert!(bls::verify(alg, &public_key, &msg, &expected_signature).is_ok());
+        let public_key = signature::UnparsedPublicKey::new(alg, &public_key);
+        assert!(public_key.verify(&msg, &expected_signature).is_ok());
+        assert!(public_key.verify(b"wrong", &expected_signature).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_bls_verify_rejects() {
+    for &alg in ALGORITHMS.iter() {
+        let key_pair = bls::KeyPair::from_ikm(alg, &[1; 32], b"").unwrap();
+        let other = bls::KeyPair::from_ikm(alg, &[2; 32], b"").unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let signature = key_pair.sign(b"msg");
+        let signature = signature.as_ref();
+        assert!(bls::verify(alg, public_key, b"msg", signature).is_ok());
+
+        assert!(bls::verify(alg, public_key, b"other msg", signature).is_err());
+        assert!(bls::verify(alg, other.public_key().as_ref(), b"msg", signature).is_err());
+        assert!(bls::verify(alg, public_key, b"msg", other.sign(b"msg").as_ref()).is_err());
+        assert!(bls::verify(alg, public_key, b"msg", &signature[1..]).is_err());
+        assert!(bls::verify(alg, &public_key[1..], b"msg", signature).is_err());
+
+        // The public key and signature groups are not interchangeable.
+        assert!(bls::verify(alg, signature, b"msg", public_key).is_err());
+
+        // The identity is never a valid public key.
+        let mut identity = vec![0; public_key.len()];
+        identity[0] = 0xc0;
+        assert!(bls::verify(alg, &identity, b"msg", signature).is_err());
+
+        // A flipped sign bit gives a different point.
+        let mut negated = signature.to_vec();
+        negated[0] ^= 0x20;
+        assert!(bls::verify(alg, public_key, b"msg", &negated).is_err());
+
+        // The signature of a different scheme doesn't verify.
+        let dst_mismatch = ALGORITHMS
+            .iter()
+            .find(|&&a| a.signature_len() == alg.signature_len() && !core::ptr::eq(a, alg))
+            .unwrap();
+        let other_scheme = bls::KeyPair::from_ikm(dst_mismatch, &[1; 32], b"").unwrap();
+        assert!(bls::verify(alg, public_key, b"msg", other_scheme.sign(b"msg").as_ref()).is_err());
+    }
+}
+
+#[test]
+fn test_bls_aggregate_verify() {
+    for &alg in ALGORITHMS.iter() {
+        let key_pairs = [1u8, 2, 3]
+            .iter()
+            .map(|&i| bls::KeyPair::from_ikm(alg, &[i; 32], b"").unwrap())
+            .collect::<Vec<_>>();
+        let public_keys = key_pairs
+            .iter()
+            .map(|k| k.public_key().as_ref())
+            .collect::<Vec<_>>();
+        let messages: [&[u8]; 3] = [b"one", b"two", b"three"];
+        let signatures = key_pairs
+            .iter()
+            .zip(messages.iter())
+            .map(|(k, m)| k.sign(m))
+            .collect::<Vec<_>>();
+        let signatures = signatures.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+
+        let aggregate = bls::aggregate(alg, &signatures).unwrap();
+        assert_eq!(aggregate.as_ref().len(), alg.signature_len());
+        assert!(bls::aggregate_verify(alg, &public_keys, &messages, aggregate.as_ref()).is_ok());
+
+        // Aggregation is commutative.
+        let reversed = bls::aggregate(alg, &[signatures[2], signatures[1], signatures[0]]).unwrap();
+        assert_eq!(reversed.as_ref(), aggregate.as_ref());
+
+        // The aggregate of one signature is the signature.
+        let single = bls::aggregate(alg, &signatures[..1]).unwrap();
+        assert_eq!(single.as_ref(), signatures[0]);
+
+        let swapped: [&[u8]; 3] = [b"two", b"one", b"three"];
+        assert!(bls::aggregate_verify(alg, &public_keys, &swapped, aggregate.as_ref()).is_err());
+        assert!(
+            bls::aggregate_verify(alg, &public_keys[..2], &messages[..2], aggregate.as_ref())
+                .is_err()
+        );
+        assert!(
+            bls::aggregate_verify(alg, &public_keys, &messages[..2], aggregate.as_ref()).is_err()
+        );
+        assert!(bls::aggregate_verify(alg, &[], &[], aggregate.as_ref()).is_err());
+        let partial = bls::aggregate(alg, &signatures[..2]).unwrap();
+        assert!(bls::aggregate_verify(alg, &public_keys, &messages, partial.as_ref()).is_err());
+
+        assert_eq!(bls::aggregate(alg, &[]).unwrap_err(), error::Unspecified);
+        assert!(bls::aggregate(alg, &[&signatures[0][1..]]).is_err());
+    }
+}
+
+#[test]
+fn test_bls_aggregate_verify_same_message() {
+    for &alg in ALGORITHMS.iter() {
+        let key_pairs = [1u8, 2]
+            .iter()
+            .map(|&i| bls::KeyPair::from_ikm(alg, &[i; 32], b"").unwrap())
+            .collect::<Vec<_>>();
+        let public_keys = key_pairs
+            .iter()
+            .map(|k| k.public_key().as_ref())
+            .collect::<Vec<_>>();
+        let signatures = key_pairs.iter().map(|k| k.sign(b"msg")).collect::<Vec<_>>();
+        let signatures = signatures.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+        let aggregate = bls::aggregate(alg, &signatures).unwrap();
+        let aggregate = aggregate.as_ref();
+        let messages: [&[u8]; 2] = [b"msg", b"msg"];
+
+        let aggregate_verified = bls::aggregate_verify(alg, &public_keys, &messages, aggregate);
+        let fast_aggregate_verified =
+            bls::fast_aggregate_verify(alg, &public_keys, b"msg", aggregate);
+        if core::ptr::eq(alg, &bls::BLS12_381_MIN_PK_POP)
+            || core::ptr::eq(alg, &bls::BLS12_381_MIN_SIG_POP)
+        {
+            assert!(aggregate_verified.is_ok());
+            assert!(fast_aggregate_verified.is_ok());
+            assert!(bls::fast_aggregate_verify(alg, &public_keys, b"other", aggregate).is_err());
+            assert!(bls::fast_aggregate_verify(alg, &public_keys[..1], b"msg", aggregate).is_err());
+            assert!(bls::fast_aggregate_verify(alg, &[], b"msg", aggregate).is_err());
+        } else {
+            // The basic schemes require distinct messages, and don't have
+            // `FastAggregateVerify`.
+            assert!(aggregate_verified.is_err());
+            assert!(fast_aggregate_verified.is_err());
+        }
+    }
+}
+
+#[test]
+fn test_bls_proof_of_possession() {
+    for &alg in ALGORITHMS.iter() {
+        let key_pair = bls::KeyPair::from_ikm(alg, &[1; 32], b"").unwrap();
+        let other = bls::KeyPair::from_ikm(alg, &[2; 32], b"").unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        match key_pair.prove_possession() {
+            Ok(proof) => {
+                assert!(bls::verify_possession(alg, public_key, proof.as_ref()).is_ok());
+                assert!(
+                    bls::verify_possession(alg, other.public_key().as_ref(), proof.as_ref())
+                        .is_err()
+                );
+                // A proof of possession isn't an ordinary signature of the
+                // public key.
+                assert!(bls::verify(alg, public_key, public_key, proof.as_ref()).is_err());
+                let signature = key_pair.sign(public_key);
+                assert!(bls::verify_possession(alg, public_key, signature.as_ref()).is_err());
+            }
+            Err(error::Unspecified) => {
+                let signature = key_pair.sign(public_key);
+                assert!(bls::verify_possession(alg, public_key, signature.as_ref()).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bls_key_pair_rejects() {
+    let alg = &bls::BLS12_381_MIN_PK_POP;
+    assert!(bls::KeyPair::from_ikm(alg, &[1; 31], b"").is_err());
+    assert!(bls::KeyPair::from_ikm(alg, &[1; 32], b"").is_ok());
+
+    // 0, r, and 2**256 - 1 are not valid private keys; 1 and r - 1 are.
+    let r =
+        test::from_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001").unwrap();
+    let mut r_minus_1 = r.clone();
+    r_minus_1[31] -= 1;
+    let mut one = [0; bls::PRIVATE_KEY_LEN];
+    one[31] = 1;
+    assert!(bls::KeyPair::from_private_key(alg, &[0; bls::PRIVATE_KEY_LEN]).is_err());
+    assert!(bls::KeyPair::from_private_key(alg, &r).is_err());
+    assert!(bls::KeyPair::from_private_key(alg, &[0xff; bls::PRIVATE_KEY_LEN]).is_err());
+    assert!(bls::KeyPair::from_private_key(alg, &one[1..]).is_err());
+    assert!(bls::KeyPair::from_private_key(alg, &one).is_ok());
+    assert!(bls::KeyPair::from_private_key(alg, &r_minus_1).is_ok());
+}
+
+#[test]
+fn test_bls_generate() {
+    let rng = rand::SystemRandom::new();
+    for &alg in ALGORITHMS.iter() {
+        let key_pair = bls::KeyPair::generate(alg, &rng).unwrap();
+        assert_eq!(key_pair.public_key().as_ref().len(), alg.public_key_len());
+        let signature = key_pair.sign(b"msg");
+        assert!(bls::verify(
+            alg,
+            key_pair.public_key().as_ref(),
+            b"msg",
+            signature.as_ref()
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{cipher::chacha20, test};
+
+fn key() -> chacha20::Key {
+    let key_bytes: Vec<u8> = (0..32).collect();
+    chacha20::Key::new(&key_bytes).unwrap()
+}
+
+// RFC 8439 Section 2.4.2.
+#[test]
+fn chacha20_ietf() {
+    let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    let expected = test::from_hex(
+        "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+         f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+         07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+         5af90bbf74a35be6b40b8eedf2785e42874d",
+    )
+    .unwrap();
+
+    let mut in_out = plaintext.to_vec();
+    key().apply_keystream(&nonce, 1, &mut in_out).unwrap();
+    assert_eq!(in_out, expected);
+
+    key().apply_keystream(&nonce, 1, &mut in_out).unwrap();
+    assert_eq!(&in_out[..], &plaintext[..]);
+
+    // Encrypting in pieces that start on block boundaries is equivalent.
+    let (first, second) = in_out.split_at_mut(64);
+    key().apply_keystream(&nonce, 1, first).unwrap();
+    key().apply_keystream(&nonce, 2, second).unwrap();
+    assert_eq!(in_out, expected);
+}
+
+#[test]
+fn chacha20_ietf_counter_overflow() {
+    let nonce = [0; chacha20::NONCE_LEN];
+    let mut in_out = [0u8; 65];
+    assert!(key()
+        .apply_keystream(&nonce, u32::MAX, &mut in_out[..64])
+        .is_ok());
+    let before = in_out;
+    assert!(key()
+        .apply_keystream(&nonce, u32::MAX, &mut in_out)
+        .is_err());
+    assert_eq!(in_out, before);
+    assert!(key()
+        .apply_keystream(&nonce, u32::MAX - 1, &mut in_out)
+        .is_ok());
+}
+
+#[test]
+fn chacha20_legacy() {
+    let nonce = [0, 1, 2, 3, 4, 5, 6, 7];
+
+    let mut in_out = [0u8; 70];
+    key()
+        .apply_keystream_legacy(&nonce, 0, &mut in_out)
+        .unwrap();
+    assert_eq!(
+        &in_out[..],
+        &test::from_hex(
+            "f798a189f195e66982105ffb640bb7757f579da31602fc93ec01ac56f85ac3c1\
+             34a4547b733b46413042c9440049176905d3be59ea1c53f15916155c2be8241a\
+             38008b9a26bc"
+        )
+        .unwrap()[..]
+    );
+
+    // The low half of the counter carries into the high half.
+    let mut in_out = [0u8; 150];
+    key()
+        .apply_keystream_legacy(&nonce, (7 << 32) | 0xffff_ffff, &mut in_out)
+        .unwrap();
+    assert_eq!(
+        &in_out[..],
+        &test::from_hex(
+            "eb45f06fec4cba70a5e5a072bb998fd84f9ee66033830726900d21e5421acdf2\
+             c569accea504144413afb6e7c0f5b91f8fc7b5e3c4052e50dcc7c973f83b7b7e\
+             1b76b8e1ecd5318f5d170f23d2ad86a1dabd8fc020f17c375fd08c04b86d5da9\
+             1a4ff019ffee24608d0a06e142153edf14245c93bda694e893dd984494a4b31a\
+             9388934f7296cefc936be82d055a59fcd93b1791e299"
+        )
+        .unwrap()[..]
+    );
+}
+
+#[test]
+fn chacha20_legacy_counter_overflow() {
+    let nonce = [0; chacha20::LEGACY_NONCE_LEN];
+    let mut in_out = [0u8; 65];
+    assert!(key()
+        .apply_keystream_legacy(&nonce, u64::MAX, &mut in_out[..64])
+        .is_ok());
+    assert!(key()
+        .apply_keystream_legacy(&nonce, u64::MAX, &mut in_out)
+        .is_err());
+}
+
+#[test]
+fn chacha20_key_len() {
+    assert!(chacha20::Key::new(&[0; 31]).is_err());
+    assert!(chacha20::Key::new(&[0; 33]).is_err());
+    assert!(chacha20::Key::new(&[0; chacha20::KEY_LEN]).is_ok());
+}
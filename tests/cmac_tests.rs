@@ -0,0 +1,85 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{cmac, test, test_file};
+
+#[test]
+fn cmac_tests() {
+    test::run(test_file!("cmac_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let algorithm = match test_case.consume_string("CMAC").as_ref() {
+            "AES_128" => &cmac::AES_128,
+            "AES_256" => &cmac::AES_256,
+            name => panic!("Unsupported CMAC: {}", name),
+        };
+        let key_value = test_case.consume_bytes("Key");
+        let mut input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+
+        let key = cmac::Key::new(algorithm, &key_value).unwrap();
+        assert_eq!(key.algorithm(), algorithm);
+
+        assert_eq!(cmac::sign(&key, &input).as_ref(), &expected[..]);
+
+        let mut ctx = cmac::Context::with_key(&key);
+        for chunk in input.chunks(5) {
+            ctx.update(chunk);
+        }
+        assert_eq!(ctx.sign().as_ref(), &expected[..]);
+
+        assert!(cmac::verify(&key, &input, &expected).is_ok());
+
+        // A truncated tag isn't a valid tag.
+        assert!(cmac::verify(&key, &input, &expected[..(expected.len() - 1)]).is_err());
+
+        // Tamper with the input and check that verification fails.
+        if input.is_empty() {
+            input.push(0);
+        } else {
+            input[0] ^= 1;
+        }
+        assert!(cmac::verify(&key, &input, &expected).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn cmac_key_new() {
+    for &algorithm in &[&cmac::AES_128, &cmac::AES_256] {
+        let key_len = algorithm.key_len();
+        assert!(cmac::Key::new(algorithm, &vec![0; key_len]).is_ok());
+        assert!(cmac::Key::new(algorithm, &vec![0; key_len - 1]).is_err());
+        assert!(cmac::Key::new(algorithm, &vec![0; key_len + 1]).is_err());
+        assert!(cmac::Key::new(algorithm, &[]).is_err());
+    }
+    assert!(cmac::Key::new(&cmac::AES_128, &[0; 24]).is_err());
+}
+
+#[test]
+fn cmac_debug() {
+    let key = cmac::Key::new(&cmac::AES_256, &[0; 32]).unwrap();
+    assert_eq!("Key { algorithm: AES_256 }", format!("{:?}", &key));
+
+    let ctx = cmac::Context::with_key(&key);
+    assert_eq!("Context { algorithm: AES_256 }", format!("{:?}", &ctx));
+}
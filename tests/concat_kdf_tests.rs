@@ -0,0 +1,47 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{concat_kdf, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn concat_kdf_tests() {
+    test::run(test_file!("concat_kdf_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let kdf = match test_case.consume_string("KDF").as_ref() {
+            "OneStep" => concat_kdf::derive,
+            "X963" => concat_kdf::derive_x963,
+            name => panic!("Unsupported KDF: {}", name),
+        };
+        let algorithm = test_case.consume_digest_alg("Hash").unwrap();
+        let z = test_case.consume_bytes("Z");
+        let other_info = test_case.consume_bytes("OtherInfo");
+        let expected = test_case.consume_bytes("Output");
+
+        // The result must not depend on how the other info is split.
+        for split in 0..=other_info.len() {
+            let (a, b) = other_info.split_at(split);
+            let mut actual = vec![0u8; expected.len()];
+            kdf(algorithm, &z, &[a, b], &mut actual).unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    });
+}
@@ -23,7 +23,18 @@ wasm_bindgen_test_configure!(run_in_browser);
 /// Test vectors from BoringSSL, Go, and other sources.
 #[test]
 fn digest_misc() {
-    test::run(test_file!("digest_tests.txt"), |section, test_case| {
+    run_digest_tests(test_file!("digest_tests.txt"));
+}
+
+#[cfg(feature = "insecure_md5")]
+#[test]
+fn digest_md5() {
+    run_digest_tests(test_file!("digest_md5_tests.txt"));
+    assert_eq!("MD5", format!("{:?}", digest::MD5_FOR_LEGACY_USE_ONLY));
+}
+
+fn run_digest_tests(test_file: test::File) {
+    test::run(test_file, |section, test_case| {
         assert_eq!(section, "");
         let digest_alg = test_case.consume_digest_alg("Hash").unwrap();
         let input = test_case.consume_bytes("Input");
@@ -169,6 +180,7 @@ mod digest_shavs {
     }
 
     shavs_tests!(SHA1, SHA1_FOR_LEGACY_USE_ONLY);
+    shavs_tests!(SHA224, SHA224);
     shavs_tests!(SHA256, SHA256);
     shavs_tests!(SHA384, SHA384);
     shavs_tests!(SHA512, SHA512);
@@ -215,9 +227,18 @@ macro_rules! test_i_u_f {
     };
 }
 test_i_u_f!(digest_test_i_u_f_sha1, digest::SHA1_FOR_LEGACY_USE_ONLY);
+test_i_u_f!(digest_test_i_u_f_sha224, digest::SHA224);
 test_i_u_f!(digest_test_i_u_f_sha256, digest::SHA256);
 test_i_u_f!(digest_test_i_u_f_sha384, digest::SHA384);
 test_i_u_f!(digest_test_i_u_f_sha512, digest::SHA512);
+test_i_u_f!(digest_test_i_u_f_sha3_224, digest::SHA3_224);
+test_i_u_f!(digest_test_i_u_f_sha3_256, digest::SHA3_256);
+test_i_u_f!(digest_test_i_u_f_sha3_384, digest::SHA3_384);
+test_i_u_f!(digest_test_i_u_f_sha3_512, digest::SHA3_512);
+test_i_u_f!(digest_test_i_u_f_blake2b_512, digest::BLAKE2B_512);
+test_i_u_f!(digest_test_i_u_f_blake2s_256, digest::BLAKE2S_256);
+test_i_u_f!(digest_test_i_u_f_sm3, digest::SM3);
+test_i_u_f!(digest_test_i_u_f_ascon_hash256, digest::ASCON_HASH256);
 
 /// See https://bugzilla.mozilla.org/show_bug.cgi?id=610162. This tests the
 /// calculation of 8GB of the byte 123.
@@ -318,10 +339,37 @@ test_large_digest!(
 #[test]
 fn test_fmt_algorithm() {
     assert_eq!("SHA1", &format!("{:?}", digest::SHA1_FOR_LEGACY_USE_ONLY));
+    assert_eq!("SHA224", &format!("{:?}", digest::SHA224));
     assert_eq!("SHA256", &format!("{:?}", digest::SHA256));
     assert_eq!("SHA384", &format!("{:?}", digest::SHA384));
     assert_eq!("SHA512", &format!("{:?}", digest::SHA512));
+    assert_eq!("SHA512_224", &format!("{:?}", digest::SHA512_224));
     assert_eq!("SHA512_256", &format!("{:?}", digest::SHA512_256));
+    assert_eq!("SHA3_256", &format!("{:?}", digest::SHA3_256));
+    assert_eq!("BLAKE2B_512", &format!("{:?}", digest::BLAKE2B_512));
+    assert_eq!("SM3", &format!("{:?}", digest::SM3));
+    assert_eq!("SHAKE128", &format!("{:?}", digest::xof::SHAKE128));
+    assert_eq!("SHAKE256", &format!("{:?}", digest::xof::SHAKE256));
+    assert_eq!(
+        "TURBOSHAKE128",
+        &format!("{:?}", digest::xof::TURBOSHAKE128)
+    );
+    assert_eq!("KT128", &format!("{:?}", digest::kangaroo_twelve::KT128));
+}
+
+#[test]
+fn digest_from_bytes() {
+    let expected = digest::digest(&digest::SHA384, b"hello, world");
+
+    let imported = digest::Digest::from_bytes(&digest::SHA384, expected.as_ref()).unwrap();
+    assert_eq!(imported.algorithm(), &digest::SHA384);
+    assert_eq!(imported.as_ref(), expected.as_ref());
+    assert_eq!(format!("{:?}", imported), format!("{:?}", expected));
+
+    // The value must be exactly as long as the algorithm's output.
+    assert!(digest::Digest::from_bytes(&digest::SHA256, expected.as_ref()).is_err());
+    assert!(digest::Digest::from_bytes(&digest::SHA512, expected.as_ref()).is_err());
+    assert!(digest::Digest::from_bytes(&digest::SHA384, &[]).is_err());
 }
 
 #[test]
@@ -357,3 +405,635 @@ fn digest_test_fmt() {
         &format!("{:?}", digest::digest(&digest::SHA512_256, b"hello, world"))
     );
 }
+
+#[test]
+fn digest_xof() {
+    use digest::xof;
+
+    test::run(test_file!("digest_xof_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let alg = consume_xof_alg(test_case, "XOF");
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+
+        let mut ctx = xof::Context::new(alg);
+        ctx.update(&input);
+        let reader = ctx.finish();
+
+        let mut actual = vec![0u8; expected.len()];
+        reader.clone().squeeze(&mut actual);
+        assert_eq!(&expected, &actual);
+
+        // Squeezing in pieces must give the same output as squeezing all at
+        // once, and absorbing in pieces the same as absorbing all at once.
+        for split in &[1, 7, alg.block_len() - 1, alg.block_len()] {
+            let mut ctx = xof::Context::new(alg);
+            for chunk in input.chunks(*split) {
+                ctx.update(chunk);
+            }
+            let mut reader = ctx.finish();
+            let mut actual = vec![0u8; expected.len()];
+            for chunk in actual.chunks_mut(*split) {
+                reader.squeeze(chunk);
+            }
+            assert_eq!(&expected, &actual);
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn digest_cshake() {
+    use digest::xof;
+
+    test::run(
+        test_file!("digest_cshake_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = consume_xof_alg(test_case, "XOF");
+            let customization = test_case.consume_bytes("Customization");
+            let input = test_case.consume_bytes("Input");
+            let expected = test_case.consume_bytes("Output");
+
+            let mut ctx = xof::Context::new_cshake(alg, &customization);
+            ctx.update(&input);
+            let mut actual = vec![0u8; expected.len()];
+            ctx.finish().squeeze(&mut actual);
+            assert_eq!(&expected, &actual);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn digest_tuple_hash() {
+    use digest::tuple_hash;
+
+    test::run(
+        test_file!("digest_tuple_hash_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = consume_xof_alg(test_case, "XOF");
+            let customization = test_case.consume_bytes("Customization");
+            let num_elements = test_case.consume_usize("NumElements");
+            let elements = (0..num_elements)
+                .map(|i| test_case.consume_bytes(&format!("Element{}", i)))
+                .collect::<Vec<_>>();
+            let is_xof = test_case.consume_optional_string("IsXOF").is_some();
+            let expected = test_case.consume_bytes("Output");
+
+            let mut ctx = tuple_hash::Context::new(alg, &customization);
+            for element in &elements {
+                ctx.append(element);
+            }
+            let mut actual = vec![0u8; expected.len()];
+            if is_xof {
+                let mut reader = ctx.finish_xof();
+                for chunk in actual.chunks_mut(7) {
+                    reader.squeeze(chunk);
+                }
+            } else {
+                ctx.finish(&mut actual);
+                let mut one_shot = vec![0u8; expected.len()];
+                let elements = elements.iter().map(|e| &e[..]).collect::<Vec<_>>();
+                tuple_hash::tuple_hash(alg, &elements, &customization, &mut one_shot);
+                assert_eq!(&expected, &one_shot);
+            }
+            assert_eq!(&expected, &actual);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn digest_turboshake() {
+    use digest::xof;
+
+    test::run(
+        test_file!("digest_turboshake_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = consume_xof_alg(test_case, "XOF");
+            let domain_separation = test_case.consume_bytes("DomainSeparation");
+            let input = pattern(test_case.consume_usize("InputLen"));
+            let expected = test_case.consume_bytes("Output");
+
+            let mut ctx = xof::Context::new_turboshake(alg, domain_separation[0]).unwrap();
+            ctx.update(&input);
+            let mut actual = vec![0u8; expected.len()];
+            ctx.finish().squeeze(&mut actual);
+            assert_eq!(&expected, &actual);
+
+            if domain_separation[0] == 0x1f {
+                let mut ctx = xof::Context::new(alg);
+                ctx.update(&input);
+                ctx.finish().squeeze(&mut actual);
+                assert_eq!(&expected, &actual);
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn digest_turboshake_domain_separation() {
+    use digest::xof;
+
+    for &d in &[0x00, 0x80, 0xff] {
+        assert!(xof::Context::new_turboshake(&xof::TURBOSHAKE128, d).is_err());
+    }
+    for &d in &[0x01, 0x7f] {
+        assert!(xof::Context::new_turboshake(&xof::TURBOSHAKE256, d).is_ok());
+    }
+    assert!(xof::Context::new_turboshake(&xof::SHAKE128, 0x1f).is_err());
+}
+
+#[test]
+fn digest_kangaroo_twelve() {
+    use digest::kangaroo_twelve;
+
+    test::run(
+        test_file!("digest_kangaroo_twelve_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = match test_case.consume_string("KT").as_ref() {
+                "KT128" => &kangaroo_twelve::KT128,
+                "KT256" => &kangaroo_twelve::KT256,
+                name => panic!("Unsupported KangarooTwelve: {}", name),
+            };
+            let message = pattern(test_case.consume_usize("MessageLen"));
+            let customization = pattern(test_case.consume_usize("CustomizationLen"));
+            let expected = test_case.consume_bytes("Output");
+
+            let mut actual = vec![0u8; expected.len()];
+            kangaroo_twelve::hash(alg, &message, &customization, &mut actual);
+            assert_eq!(&expected, &actual);
+
+            for split in &[1000, 8192, 8193] {
+                let mut ctx = kangaroo_twelve::Context::new(alg);
+                for chunk in message.chunks(*split) {
+                    ctx.update(chunk);
+                }
+                let mut actual = vec![0u8; expected.len()];
+                ctx.finish(&customization).squeeze(&mut actual);
+                assert_eq!(&expected, &actual);
+            }
+
+            Ok(())
+        },
+    );
+}
+
+/// The input pattern of the test vectors of RFC 9861 and BLAKE3.
+fn pattern(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+fn consume_xof_alg(test_case: &mut test::TestCase, key: &str) -> &'static digest::xof::Algorithm {
+    match test_case.consume_string(key).as_ref() {
+        "SHAKE128" => &digest::xof::SHAKE128,
+        "SHAKE256" => &digest::xof::SHAKE256,
+        "TURBOSHAKE128" => &digest::xof::TURBOSHAKE128,
+        "TURBOSHAKE256" => &digest::xof::TURBOSHAKE256,
+        name => panic!("Unsupported XOF: {}", name),
+    }
+}
+
+#[test]
+fn digest_blake2_keyed() {
+    use digest::blake2;
+
+    test::run(
+        test_file!("digest_blake2_keyed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let digest_alg = test_case.consume_digest_alg("Hash").unwrap();
+            let key_value = test_case.consume_bytes("Key");
+            let input = test_case.consume_bytes("Input");
+            let expected = test_case.consume_bytes("Output");
+
+            let key = blake2::Key::new(digest_alg, &key_value).unwrap();
+            assert_eq!(key.algorithm(), digest_alg);
+            assert_eq!(&expected, &blake2::sign(&key, &input).as_ref());
+            assert!(blake2::verify(&key, &input, &expected).is_ok());
+
+            let mut ctx = key.context();
+            for chunk in input.chunks(7) {
+                ctx.update(chunk);
+            }
+            assert_eq!(&expected, &ctx.finish().as_ref());
+
+            let mut bad = expected.clone();
+            bad[0] ^= 1;
+            assert!(blake2::verify(&key, &input, &bad).is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn digest_blake2_key_new() {
+    use digest::blake2;
+
+    assert!(blake2::Key::new(&digest::BLAKE2B_512, &[]).is_err());
+    assert!(blake2::Key::new(&digest::BLAKE2B_512, &[0; 64]).is_ok());
+    assert!(blake2::Key::new(&digest::BLAKE2B_512, &[0; 65]).is_err());
+    assert!(blake2::Key::new(&digest::BLAKE2S_256, &[0; 32]).is_ok());
+    assert!(blake2::Key::new(&digest::BLAKE2S_256, &[0; 33]).is_err());
+    assert!(blake2::Key::new(&digest::SHA256, &[0; 32]).is_err());
+
+    let key = blake2::Key::new(&digest::BLAKE2S_256, &[0; 32]).unwrap();
+    assert_eq!("Key { algorithm: BLAKE2S_256 }", format!("{:?}", key));
+    assert_eq!(
+        "Context { algorithm: BLAKE2S_256 }",
+        format!("{:?}", key.context())
+    );
+}
+
+#[test]
+fn digest_blake3() {
+    use digest::blake3;
+
+    const KEY: &[u8; blake3::KEY_LEN] = b"whats the Elephant we want here?";
+    const CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+    test::run(
+        test_file!("digest_blake3_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let input_len = test_case.consume_usize("InputLen");
+            let input = pattern(input_len);
+
+            type NewContext = fn() -> blake3::Context;
+            let cases: [(&str, NewContext); 3] = [
+                ("Hash", blake3::Context::new),
+                ("KeyedHash", || blake3::Context::new_keyed(KEY)),
+                ("DeriveKey", || blake3::Context::new_derive_key(CONTEXT)),
+            ];
+            for (name, new_context) in cases.iter() {
+                let expected = test_case.consume_bytes(name);
+
+                match *name {
+                    "Hash" => assert_eq!(
+                        &expected[..blake3::OUTPUT_LEN],
+                        blake3::hash(&input).as_ref()
+                    ),
+                    "KeyedHash" => {
+                        let tag = &expected[..blake3::OUTPUT_LEN];
+                        assert_eq!(tag, blake3::keyed_hash(KEY, &input).as_ref());
+                        assert!(blake3::verify_keyed_hash(KEY, &input, tag).is_ok());
+                        assert!(blake3::verify_keyed_hash(KEY, &input, &tag[1..]).is_err());
+                    }
+                    _ => {
+                        let mut actual = vec![0u8; expected.len()];
+                        blake3::derive_key(CONTEXT, &input, &mut actual);
+                        assert_eq!(&expected, &actual);
+                    }
+                }
+
+                let mut ctx = new_context();
+                ctx.update(&input);
+                assert_eq!(
+                    &expected[..blake3::OUTPUT_LEN],
+                    ctx.clone().finish().as_ref()
+                );
+                let mut actual = vec![0u8; expected.len()];
+                ctx.finish_extended(&mut actual);
+                assert_eq!(&expected, &actual);
+
+                let mut ctx = new_context();
+                for chunk in input.chunks(1000) {
+                    ctx.update(chunk);
+                }
+                assert_eq!(&expected[..blake3::OUTPUT_LEN], ctx.finish().as_ref());
+
+                #[cfg(feature = "parallel")]
+                for split in &[0, 1, 1024, 3000] {
+                    let split = core::cmp::min(*split, input.len());
+                    let mut ctx = new_context();
+                    ctx.update_parallel(&input[..split]);
+                    ctx.update_parallel(&input[split..]);
+                    assert_eq!(&expected[..blake3::OUTPUT_LEN], ctx.finish().as_ref());
+                }
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn digest_io_write() {
+    use std::io::Write;
+
+    let input = pattern(3000);
+    let expected = digest::digest(&digest::SHA384, &input);
+
+    let mut ctx = digest::Context::new(&digest::SHA384);
+    for chunk in input.chunks(100) {
+        ctx.write_all(chunk).unwrap();
+    }
+    ctx.flush().unwrap();
+    assert_eq!(expected.as_ref(), ctx.finish().as_ref());
+
+    let mut ctx = digest::Context::new(&digest::SHA384);
+    let copied = std::io::copy(&mut &input[..], &mut ctx).unwrap();
+    assert_eq!(copied, 3000);
+    assert_eq!(expected.as_ref(), ctx.finish().as_ref());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn digest_batch() {
+    // Lengths around the padding boundaries, in an order that isn't sorted,
+    // and a number of messages that isn't a multiple of the lane count.
+    let lengths = [
+        0, 1, 55, 56, 63, 64, 65, 119, 120, 127, 128, 1000, 3, 200, 64, 0, 17, 4096, 111,
+    ];
+    let inputs = lengths.iter().map(|&len| pattern(len)).collect::<Vec<_>>();
+
+    for &alg in &[
+        &digest::SHA256,
+        &digest::SHA224,
+        &digest::SHA384,
+        &digest::BLAKE2S_256,
+    ] {
+        for count in 0..=inputs.len() {
+            let messages = inputs[..count]
+                .iter()
+                .map(|input| &input[..])
+                .collect::<Vec<_>>();
+            let digests = digest::batch::digest_all(alg, &messages);
+            assert_eq!(digests.len(), count);
+            for (message, actual) in messages.iter().zip(digests.iter()) {
+                let expected = digest::digest(alg, message);
+                assert_eq!(actual.algorithm(), alg);
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+    }
+}
+
+#[test]
+fn digest_context_state() {
+    let input = pattern(1000);
+    for &alg in &[
+        &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        &digest::SHA224,
+        &digest::SHA256,
+        &digest::SHA384,
+        &digest::SHA512,
+        &digest::SHA512_224,
+        &digest::SHA512_256,
+        &digest::SHA3_224,
+        &digest::SHA3_256,
+        &digest::SHA3_384,
+        &digest::SHA3_512,
+        &digest::BLAKE2B_256,
+        &digest::BLAKE2B_512,
+        &digest::BLAKE2S_256,
+        &digest::SM3,
+        &digest::ASCON_HASH256,
+        #[cfg(feature = "insecure_md5")]
+        &digest::MD5_FOR_LEGACY_USE_ONLY,
+    ] {
+        let expected = digest::digest(alg, &input);
+        for &split in &[0, 1, 63, 64, 65, 127, 128, 129, 136, 144, 500, 1000] {
+            let mut ctx = digest::Context::new(alg);
+            ctx.update(&input[..split]);
+            let state = ctx.state();
+
+            let mut resumed = digest::Context::from_state(state.as_ref()).unwrap();
+            assert_eq!(resumed.algorithm(), alg);
+            assert_eq!(resumed.state().as_ref(), state.as_ref());
+            resumed.update(&input[split..]);
+            assert_eq!(resumed.finish().as_ref(), expected.as_ref());
+        }
+    }
+}
+
+#[test]
+fn digest_context_state_encoding() {
+    let mut ctx = digest::Context::new(&digest::SHA256);
+    ctx.update(b"abc");
+    let expected = test::from_hex(
+        "01030000000000000000\
+         6a09e667bb67ae853c6ef372a54ff53a510e527f9b05688c1f83d9ab5be0cd19\
+         03616263",
+    )
+    .unwrap();
+    assert_eq!(ctx.state().as_ref(), &expected[..]);
+    let resumed = digest::Context::from_state(&expected).unwrap();
+    assert_eq!(
+        resumed.finish().as_ref(),
+        digest::digest(&digest::SHA256, b"abc").as_ref()
+    );
+
+    let invalid = |f: &dyn Fn(&mut Vec<u8>)| {
+        let mut state = expected.clone();
+        f(&mut state);
+        assert!(digest::Context::from_state(&state).is_err());
+    };
+    invalid(&|s| s[0] = digest::CONTEXT_STATE_VERSION + 1); // Unknown version.
+    invalid(&|s| s[1] = 0); // Unknown algorithm.
+    invalid(&|s| s[1] = 18); // Unknown algorithm.
+    invalid(&|s| s[2] = 0xff); // Too many blocks.
+    invalid(&|s| s[42] = 64); // Too much pending input.
+    invalid(&|s| s[42] = 4); // Truncated pending input.
+    invalid(&|s| s.push(0)); // Trailing garbage.
+    invalid(&|s| s.truncate(20)); // Truncated state.
+    invalid(&|s| s.clear());
+}
+
+#[test]
+fn digest_finish_and_reset() {
+    let messages = [pattern(0), pattern(100), pattern(1000), pattern(3)];
+
+    let mut ctx = digest::Context::new(&digest::SHA512);
+    for message in &messages {
+        ctx.update(message);
+        let expected = digest::digest(&digest::SHA512, message);
+        assert_eq!(ctx.finish_and_reset().as_ref(), expected.as_ref());
+    }
+
+    // A keyed context is reset to its keyed state.
+    let key = digest::blake2::Key::new(&digest::BLAKE2S_256, b"key").unwrap();
+    let mut ctx = key.context();
+    for message in &messages {
+        ctx.update(message);
+        let expected = digest::blake2::sign(&key, message);
+        assert_eq!(ctx.finish_and_reset().as_ref(), expected.as_ref());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn digest_reader() {
+    use std::io;
+
+    /// Returns the input a few bytes at a time, interrupting every other
+    /// read.
+    struct Trickle<'a> {
+        input: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let n = core::cmp::min(core::cmp::min(buf.len(), self.input.len()), 7);
+            buf[..n].copy_from_slice(&self.input[..n]);
+            self.input = &self.input[n..];
+            Ok(n)
+        }
+    }
+
+    struct Broken;
+
+    impl io::Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    for &len in &[0, 1, 1000, 16 * 1024, 40000] {
+        let input = pattern(len);
+        let expected = digest::digest(&digest::SHA256, &input);
+
+        let actual = digest::digest_reader(&digest::SHA256, &input[..]).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        let trickle = Trickle {
+            input: &input,
+            interrupt: false,
+        };
+        let actual = digest::digest_reader(&digest::SHA256, trickle).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+    }
+
+    let err = digest::digest_reader(&digest::SHA256, Broken).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn digest_merkle() {
+    use digest::merkle;
+
+    let leaves = [
+        "",
+        "00",
+        "10",
+        "2021",
+        "3031",
+        "40414243",
+        "5051525354555657",
+        "606162636465666768696a6b6c6d6e6f",
+    ]
+    .iter()
+    .map(|leaf| test::from_hex(leaf).unwrap())
+    .collect::<Vec<_>>();
+
+    test::run(
+        test_file!("digest_merkle_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = &digest::SHA256;
+            let size = test_case.consume_usize("Size");
+            let index = test_case
+                .consume_optional_string("Index")
+                .map(|index| index.parse::<usize>().unwrap());
+            let expected_proof = index.map(|_| test_case.consume_bytes("Proof"));
+            let expected_root = test_case.consume_bytes("Root");
+
+            let tree = &leaves[..size];
+            let root = merkle::root(alg, tree);
+            assert_eq!(root.as_ref(), &expected_root[..]);
+
+            let (index, expected_proof) = match (index, expected_proof) {
+                (Some(index), Some(expected_proof)) => (index, expected_proof),
+                _ => return Ok(()),
+            };
+
+            let proof = merkle::inclusion_proof(alg, tree, index).unwrap();
+            let actual_proof = proof
+                .iter()
+                .flat_map(|p| p.as_ref().iter().copied())
+                .collect::<Vec<_>>();
+            assert_eq!(actual_proof, expected_proof);
+
+            let leaf = &tree[index];
+            let (index, size) = (index as u64, size as u64);
+            let verify = |leaf: &[u8], index, size, proof: &[digest::Digest]| {
+                merkle::verify_inclusion(alg, leaf, index, size, proof, &expected_root)
+            };
+            assert!(verify(leaf, index, size, &proof).is_ok());
+
+            assert!(verify(b"wrong leaf", index, size, &proof).is_err());
+            assert!(verify(leaf, index + 1, size, &proof).is_err());
+            assert!(verify(leaf, size, size, &proof).is_err());
+            let mut extended = proof.clone();
+            extended.push(root);
+            assert!(verify(leaf, index, size, &extended).is_err());
+            if !proof.is_empty() {
+                assert!(verify(leaf, index, size, &proof[1..]).is_err());
+                assert!(verify(leaf, index, size, &proof[..(proof.len() - 1)]).is_err());
+            }
+
+            Ok(())
+        },
+    );
+
+    assert!(merkle::inclusion_proof(&digest::SHA256, &leaves, 8).is_err());
+    let no_leaves: [&[u8]; 0] = [];
+    assert!(merkle::inclusion_proof(&digest::SHA256, &no_leaves, 0).is_err());
+}
+
+#[test]
+fn digest_fixed() {
+    use digest::fixed;
+
+    fn test<const N: usize>(alg: &'static fixed::Algorithm<N>) {
+        assert_eq!(alg.algorithm().output_len, N);
+        assert_eq!(format!("{:?}", alg), format!("{:?}", alg.algorithm()));
+
+        let input = pattern(300);
+        let expected = digest::digest(alg.algorithm(), &input);
+        assert_eq!(&alg.digest(&input)[..], expected.as_ref());
+
+        let mut ctx = fixed::Context::new(alg);
+        assert_eq!(ctx.algorithm(), alg.algorithm());
+        for chunk in input.chunks(7) {
+            ctx.update(chunk);
+        }
+        assert_eq!(&ctx.finish()[..], expected.as_ref());
+    }
+
+    #[cfg(feature = "insecure_md5")]
+    test(&fixed::MD5_FOR_LEGACY_USE_ONLY);
+    test(&fixed::SHA1_FOR_LEGACY_USE_ONLY);
+    test(&fixed::SHA224);
+    test(&fixed::SHA256);
+    test(&fixed::SHA384);
+    test(&fixed::SHA512);
+    test(&fixed::SHA512_224);
+    test(&fixed::SHA512_256);
+    test(&fixed::SHA3_224);
+    test(&fixed::SHA3_256);
+    test(&fixed::SHA3_384);
+    test(&fixed::SHA3_512);
+    test(&fixed::BLAKE2B_256);
+    test(&fixed::BLAKE2B_512);
+    test(&fixed::BLAKE2S_256);
+    test(&fixed::SM3);
+    test(&fixed::ASCON_HASH256);
+}
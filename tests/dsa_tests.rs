@@ -0,0 +1,95 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+#![cfg(feature = "alloc")]
+
+use ring::{digest, error, signature, test, test_file};
+
+#[test]
+fn dsa_verify_test() {
+    test::run(test_file!("dsa_verify_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let key = test_case.consume_bytes("Key");
+        let msg = test_case.consume_bytes("Msg");
+        let sig = test_case.consume_bytes("Sig");
+        let expected = match test_case.consume_string("Result").as_str() {
+            "P" => Ok(()),
+            "F" => Err(error::Unspecified),
+            s => panic!("{:?} is not a valid result", s),
+        };
+
+        let key = signature::UnparsedPublicKey::new(
+            &signature::DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY,
+            &key,
+        );
+        assert_eq!(key.verify(&msg, &sig), expected);
+        let digest = digest::digest(&digest::SHA256, &msg);
+        assert_eq!(key.verify_digest(&digest, &sig), expected);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn dsa_public_key_components_verify_test() {
+    test::run(
+        test_file!("dsa_components_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let p = test_case.consume_bytes("P");
+            let q = test_case.consume_bytes("Q");
+            let g = test_case.consume_bytes("G");
+            let y = test_case.consume_bytes("Y");
+            let msg = test_case.consume_bytes("Msg");
+            let sig = test_case.consume_bytes("Sig");
+
+            let params = &signature::DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY;
+            let key = signature::DsaPublicKeyComponents { p, q, g, y };
+            assert_eq!(key.verify(params, &msg, &sig), Ok(()));
+
+            let mut wrong = msg.clone();
+            wrong.push(0);
+            assert_eq!(key.verify(params, &wrong, &sig), Err(error::Unspecified));
+
+            // The components must not be swapped.
+            let swapped = signature::DsaPublicKeyComponents {
+                p: &key.p,
+                q: &key.q,
+                g: &key.y,
+                y: &key.g,
+            };
+            assert_eq!(swapped.verify(params, &msg, &sig), Err(error::Unspecified));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn dsa_verify_digest_wrong_algorithm_test() {
+    test::run(test_file!("dsa_verify_tests.txt"), |_section, test_case| {
+        let key = test_case.consume_bytes("Key");
+        let msg = test_case.consume_bytes("Msg");
+        let sig = test_case.consume_bytes("Sig");
+        let _ = test_case.consume_string("Result");
+
+        let key = signature::UnparsedPublicKey::new(
+            &signature::DSA_2048_3072_SHA256_FOR_LEGACY_USE_ONLY,
+            &key,
+        );
+        let digest = digest::digest(&digest::SHA384, &msg);
+        assert_eq!(key.verify_digest(&digest, &sig), Err(error::Unspecified));
+
+        Ok(())
+    });
+}
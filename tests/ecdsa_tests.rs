@@ -12,8 +12,9 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
+use core::convert::TryFrom;
 use ring::{
-    rand,
+    digest, rand,
     signature::{self, KeyPair},
     test, test_file,
 };
@@ -51,6 +52,26 @@ fn ecdsa_from_pkcs8_test() {
                         &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
                     ),
                 ),
+                "P-521" => (
+                    (
+                        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+                    ),
+                    (
+                        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                    ),
+                ),
+                "secp256k1" => (
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+                    ),
+                    (
+                        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+                    ),
+                ),
                 _ => unreachable!(),
             };
 
@@ -96,6 +117,10 @@ fn ecdsa_generate_pkcs8_test() {
         &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
         &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
         &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+        &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
     ] {
         let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
         println!();
@@ -129,6 +154,8 @@ fn signature_ecdsa_verify_asn1_test() {
                 ("P-256", "SHA384") => &signature::ECDSA_P256_SHA384_ASN1,
                 ("P-384", "SHA256") => &signature::ECDSA_P384_SHA256_ASN1,
                 ("P-384", "SHA384") => &signature::ECDSA_P384_SHA384_ASN1,
+                ("P-521", "SHA512") => &signature::ECDSA_P521_SHA512_ASN1,
+                ("secp256k1", "SHA256") => &signature::ECDSA_SECP256K1_SHA256_ASN1,
                 _ => {
                     panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                 }
@@ -138,6 +165,12 @@ fn signature_ecdsa_verify_asn1_test() {
                 signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
             assert_eq!(actual_result.is_ok(), is_valid);
 
+            let parsed_result = match signature::ParsedPublicKey::new(alg, &public_key) {
+                Ok(public_key) => public_key.verify(&msg, &sig).is_ok(),
+                Err(_) => false,
+            };
+            assert_eq!(parsed_result, is_valid);
+
             Ok(())
         },
     );
@@ -161,6 +194,8 @@ fn signature_ecdsa_verify_fixed_test() {
             let alg = match (curve_name.as_str(), digest_name.as_str()) {
                 ("P-256", "SHA256") => &signature::ECDSA_P256_SHA256_FIXED,
                 ("P-384", "SHA384") => &signature::ECDSA_P384_SHA384_FIXED,
+                ("P-521", "SHA512") => &signature::ECDSA_P521_SHA512_FIXED,
+                ("secp256k1", "SHA256") => &signature::ECDSA_SECP256K1_SHA256_FIXED,
                 _ => {
                     panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                 }
@@ -172,11 +207,117 @@ fn signature_ecdsa_verify_fixed_test() {
                 signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
             assert_eq!(actual_result.is_ok(), is_valid);
 
+            let parsed_result = match signature::ParsedPublicKey::new(alg, &public_key) {
+                Ok(public_key) => public_key.verify(&msg, &sig).is_ok(),
+                Err(_) => false,
+            };
+            assert_eq!(parsed_result, is_valid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn signature_ecdsa_convert_test() {
+    test::run(
+        test_file!("ecdsa_verify_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+            let msg = test_case.consume_bytes("Msg");
+            let public_key = test_case.consume_bytes("Q");
+            let sig = test_case.consume_bytes("Sig");
+            let is_valid = test_case.consume_string("Result") == "P (0 )";
+            if !is_valid {
+                return Ok(());
+            }
+
+            let (fixed_alg, asn1_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => (
+                    &signature::ECDSA_P256_SHA256_FIXED,
+                    &signature::ECDSA_P256_SHA256_ASN1,
+                ),
+                ("P-384", "SHA384") => (
+                    &signature::ECDSA_P384_SHA384_FIXED,
+                    &signature::ECDSA_P384_SHA384_ASN1,
+                ),
+                ("P-521", "SHA512") => (
+                    &signature::ECDSA_P521_SHA512_FIXED,
+                    &signature::ECDSA_P521_SHA512_ASN1,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                    &signature::ECDSA_SECP256K1_SHA256_ASN1,
+                ),
+                _ => {
+                    panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                }
+            };
+
+            let asn1 = signature::ecdsa_fixed_to_asn1(fixed_alg, &sig).unwrap();
+            let public_key = signature::UnparsedPublicKey::new(asn1_alg, &public_key);
+            assert_eq!(public_key.verify(&msg, asn1.as_ref()), Ok(()));
+
+            let fixed = signature::ecdsa_asn1_to_fixed(asn1_alg, asn1.as_ref()).unwrap();
+            assert_eq!(fixed.as_ref(), &sig[..]);
+
             Ok(())
         },
     );
 }
 
+#[test]
+fn signature_ecdsa_convert_malformed_test() {
+    let alg = &signature::ECDSA_P256_SHA256_FIXED;
+
+    // Leading zeros of the fixed-length form are removed, and a zero is
+    // prepended when the high bit is set.
+    let mut fixed = [0u8; 64];
+    fixed[31] = 1;
+    fixed[32] = 0x80;
+    let asn1 = signature::ecdsa_fixed_to_asn1(alg, &fixed).unwrap();
+    let mut expected = vec![0x30, 0x26, 0x02, 0x01, 0x01, 0x02, 0x21, 0x00, 0x80];
+    expected.extend_from_slice(&[0u8; 31]);
+    assert_eq!(asn1.as_ref(), &expected[..]);
+    assert_eq!(
+        signature::ecdsa_asn1_to_fixed(alg, asn1.as_ref())
+            .unwrap()
+            .as_ref(),
+        &fixed[..]
+    );
+
+    // The fixed-length form must be exactly two scalars long.
+    assert!(signature::ecdsa_fixed_to_asn1(alg, &fixed[..63]).is_err());
+    assert!(signature::ecdsa_fixed_to_asn1(alg, &[fixed.as_ref(), &[0]].concat()).is_err());
+
+    // Zero and out-of-range scalars are rejected.
+    let mut zero_r = fixed;
+    zero_r[31] = 0;
+    assert!(signature::ecdsa_fixed_to_asn1(alg, &zero_r).is_err());
+    let mut big_s = fixed;
+    big_s[32..].copy_from_slice(&[0xff; 32]);
+    assert!(signature::ecdsa_fixed_to_asn1(alg, &big_s).is_err());
+
+    // Non-minimal DER is rejected, as is trailing data.
+    assert!(signature::ecdsa_asn1_to_fixed(
+        alg,
+        &[0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01]
+    )
+    .is_err());
+    assert!(signature::ecdsa_asn1_to_fixed(
+        alg,
+        &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00]
+    )
+    .is_err());
+    assert!(
+        signature::ecdsa_asn1_to_fixed(alg, &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01])
+            .is_ok()
+    );
+}
+
 #[test]
 fn ecdsa_test_public_key_coverage() {
     const PRIVATE_KEY: &[u8] = include_bytes!("ecdsa_test_private_key_p256.p8");
@@ -209,6 +350,138 @@ fn ecdsa_test_public_key_coverage() {
     );
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn ecdsa_test_public_key_spki() {
+    let rng = rand::SystemRandom::new();
+
+    // The DER encoding of everything in the `SubjectPublicKeyInfo` that
+    // precedes the uncompressed public key.
+    let algs: &[(&signature::EcdsaSigningAlgorithm, &str)] = &[
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            "3059301306072a8648ce3d020106082a8648ce3d030107034200",
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            "3076301006072a8648ce3d020106052b81040022036200",
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            "30819b301006072a8648ce3d020106052b8104002303818600",
+        ),
+        (
+            &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+            "3056301006072a8648ce3d020106052b8104000a034200",
+        ),
+    ];
+    for &(alg, prefix) in algs {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+        let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key = key_pair.public_key();
+        let spki = public_key.subject_public_key_info();
+        let (actual_prefix, actual_public_key) =
+            spki.split_at(spki.len() - public_key.as_ref().len());
+        assert_eq!(actual_prefix, &test::from_hex(prefix).unwrap()[..]);
+        assert_eq!(actual_public_key, public_key.as_ref());
+    }
+}
+
+#[test]
+fn ecdsa_from_private_key_test() {
+    let rng = rand::SystemRandom::new();
+
+    test::run(
+        test_file!("../src/ec/suite_b/ecdsa/ecdsa_sign_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let _digest_name = test_case.consume_string("Digest");
+            let _msg = test_case.consume_bytes("Msg");
+            let d = test_case.consume_bytes("d");
+            let q = test_case.consume_bytes("Q");
+            let _k = test_case.consume_bytes("k");
+            let _expected_result = test_case.consume_bytes("Sig");
+
+            let alg = match curve_name.as_str() {
+                "P-256" => &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                "P-384" => &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                "P-521" => &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                "secp256k1" => &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                _ => panic!("Unsupported curve: {}", curve_name),
+            };
+
+            let key_pair = signature::EcdsaKeyPair::from_private_key(alg, &d, &rng).unwrap();
+            assert_eq!(key_pair.public_key().as_ref(), &q[..]);
+
+            Ok(())
+        },
+    );
+
+    // The private key must be in [1, n) and have the curve's length.
+    const P256_N: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63,
+        0x25, 0x51,
+    ];
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let mut n_minus_one = P256_N;
+    n_minus_one[31] -= 1;
+    assert!(signature::EcdsaKeyPair::from_private_key(alg, &n_minus_one, &rng).is_ok());
+    for bad in [
+        &[0u8; 32][..],
+        &P256_N[..],
+        &[0xff; 32][..],
+        &P256_N[1..],
+        &[],
+    ] {
+        assert!(signature::EcdsaKeyPair::from_private_key(alg, bad, &rng).is_err());
+    }
+}
+
+#[test]
+fn signature_ecdsa_signing_context_test() {
+    let rng = rand::SystemRandom::new();
+    let msg: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+    ] {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair =
+            signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key =
+            signature::UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+
+        for chunk_len in [1, 7, 64, 1000] {
+            let mut ctx = key_pair.signing_context();
+            for chunk in msg.chunks(chunk_len) {
+                ctx.update(chunk);
+            }
+            let signature = ctx.finish(&rng).unwrap();
+            assert_eq!(public_key.verify(&msg, signature.as_ref()), Ok(()));
+        }
+
+        // A context can be cloned to sign messages with a common prefix.
+        let mut prefix = key_pair.signing_context();
+        prefix.update(&msg[..10]);
+        let mut ctx = prefix.clone();
+        ctx.update(&msg[10..]);
+        let signature = ctx.finish(&rng).unwrap();
+        assert_eq!(public_key.verify(&msg, signature.as_ref()), Ok(()));
+        let signature = prefix.finish(&rng).unwrap();
+        assert_eq!(public_key.verify(&msg[..10], signature.as_ref()), Ok(()));
+    }
+}
+
 // This test is not a known-answer test, though it re-uses the known-answer
 // test vectors. Because the nonce is randomized, the signature will be
 // different each time. Because of that, here we simply verify that the
@@ -244,6 +517,14 @@ fn signature_ecdsa_sign_fixed_sign_and_verify_test() {
                     &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
                     &signature::ECDSA_P384_SHA384_FIXED,
                 ),
+                ("P-521", "SHA512") => (
+                    &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                    &signature::ECDSA_P521_SHA512_FIXED,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                ),
                 _ => {
                     panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                 }
@@ -298,6 +579,14 @@ fn signature_ecdsa_sign_asn1_test() {
                     &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
                     &signature::ECDSA_P384_SHA384_ASN1,
                 ),
+                ("P-521", "SHA512") => (
+                    &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+                    &signature::ECDSA_P521_SHA512_ASN1,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+                    &signature::ECDSA_SECP256K1_SHA256_ASN1,
+                ),
                 _ => {
                     panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                 }
@@ -316,3 +605,581 @@ fn signature_ecdsa_sign_asn1_test() {
         },
     );
 }
+
+#[test]
+fn signature_ecdsa_recover_public_key_test() {
+    test::run(
+        test_file!("ecdsa_recovery_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+            let (alg, verification_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => (
+                    &signature::ECDSA_P256_SHA256_FIXED_RECOVERY,
+                    &signature::ECDSA_P256_SHA256_FIXED,
+                ),
+                ("P-384", "SHA384") => (
+                    &signature::ECDSA_P384_SHA384_FIXED_RECOVERY,
+                    &signature::ECDSA_P384_SHA384_FIXED,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                ),
+                _ => {
+                    panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                }
+            };
+
+            let msg = test_case.consume_bytes("Msg");
+            let sig = test_case.consume_bytes("Sig");
+            let recovery_id = test_case.consume_usize("RecoveryId");
+            let recovery_id = signature::RecoveryId::try_from(recovery_id as u8).unwrap();
+            let error = test_case.consume_optional_string("Error");
+
+            let actual_result = alg.recover_public_key(&msg, &sig, recovery_id);
+            match error {
+                None => {
+                    let expected_q = test_case.consume_bytes("Q");
+                    let actual_q = actual_result.unwrap();
+                    assert_eq!(actual_q.as_ref(), &expected_q[..]);
+
+                    // The recovered public key must verify the signature.
+                    let public_key = signature::UnparsedPublicKey::new(verification_alg, actual_q);
+                    assert_eq!(public_key.verify(&msg, &sig), Ok(()));
+                }
+                Some(_) => assert!(actual_result.is_err()),
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn signature_ecdsa_recovery_id_test() {
+    for id in 0..4u8 {
+        let recovery_id = signature::RecoveryId::try_from(id).unwrap();
+        assert_eq!(u8::from(recovery_id), id);
+    }
+    for id in 4..=255u8 {
+        assert!(signature::RecoveryId::try_from(id).is_err());
+    }
+}
+
+// Like the other sign-and-verify tests, this is not a known-answer test.
+#[test]
+fn signature_ecdsa_sign_recoverable_test() {
+    let rng = rand::SystemRandom::new();
+
+    test::run(
+        test_file!("../src/ec/suite_b/ecdsa/ecdsa_sign_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+
+            let msg = test_case.consume_bytes("Msg");
+            let d = test_case.consume_bytes("d");
+            let q = test_case.consume_bytes("Q");
+
+            // Ignored since the actual signature will use a randomized nonce.
+            let _k = test_case.consume_bytes("k");
+            let _expected_result = test_case.consume_bytes("Sig");
+
+            let algs: &[(_, _)] = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => &[
+                    (
+                        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_P256_SHA256_FIXED_RECOVERY,
+                    ),
+                    (
+                        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+                        &signature::ECDSA_P256_SHA256_ASN1_RECOVERY,
+                    ),
+                ],
+                ("P-384", "SHA384") => &[
+                    (
+                        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                        &signature::ECDSA_P384_SHA384_FIXED_RECOVERY,
+                    ),
+                    (
+                        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                        &signature::ECDSA_P384_SHA384_ASN1_RECOVERY,
+                    ),
+                ],
+                ("secp256k1", "SHA256") => &[
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+                    ),
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_ASN1_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_ASN1_RECOVERY,
+                    ),
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_RECOVERY,
+                    ),
+                ],
+                _ => &[],
+            };
+
+            for (signing_alg, recovery_alg) in algs {
+                let private_key = signature::EcdsaKeyPair::from_private_key_and_public_key(
+                    signing_alg,
+                    &d,
+                    &q,
+                    &rng,
+                )
+                .unwrap();
+
+                let (signature, recovery_id) = private_key.sign_recoverable(&rng, &msg).unwrap();
+
+                let recovered = recovery_alg
+                    .recover_public_key(&msg, signature.as_ref(), recovery_id)
+                    .unwrap();
+                assert_eq!(recovered.as_ref(), &q[..]);
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn signature_ecdsa_verify_low_s_test() {
+    fn test_low_s(
+        alg: &signature::EcdsaVerificationAlgorithm,
+        low_s_alg: &'static signature::EcdsaVerificationAlgorithm,
+        msg: &[u8],
+        public_key: &[u8],
+        sig: &[u8],
+    ) {
+        let normalized = alg.normalize_s(sig).unwrap();
+        assert_eq!(
+            low_s_alg.normalize_s(normalized.as_ref()).unwrap().as_ref(),
+            normalized.as_ref()
+        );
+
+        let low_s_key = signature::UnparsedPublicKey::new(low_s_alg, public_key);
+        assert_eq!(low_s_key.verify(msg, normalized.as_ref()), Ok(()));
+        assert_eq!(
+            low_s_key.verify(msg, sig).is_ok(),
+            normalized.as_ref() == sig
+        );
+    }
+
+    test::run(
+        test_file!("ecdsa_verify_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+            let msg = test_case.consume_bytes("Msg");
+            let public_key = test_case.consume_bytes("Q");
+            let sig = test_case.consume_bytes("Sig");
+            let is_valid = test_case.consume_string("Result") == "P (0 )";
+            if !is_valid {
+                return Ok(());
+            }
+
+            let (alg, low_s_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => (
+                    &signature::ECDSA_P256_SHA256_FIXED,
+                    &signature::ECDSA_P256_SHA256_FIXED_LOW_S,
+                ),
+                ("P-384", "SHA384") => (
+                    &signature::ECDSA_P384_SHA384_FIXED,
+                    &signature::ECDSA_P384_SHA384_FIXED_LOW_S,
+                ),
+                ("P-521", "SHA512") => (
+                    &signature::ECDSA_P521_SHA512_FIXED,
+                    &signature::ECDSA_P521_SHA512_FIXED_LOW_S,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                    &signature::ECDSA_SECP256K1_SHA256_FIXED_LOW_S,
+                ),
+                _ => {
+                    panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                }
+            };
+
+            test_low_s(alg, low_s_alg, &msg, &public_key, &sig);
+
+            Ok(())
+        },
+    );
+
+    test::run(
+        test_file!("ecdsa_verify_asn1_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+            let msg = test_case.consume_bytes("Msg");
+            let public_key = test_case.consume_bytes("Q");
+            let sig = test_case.consume_bytes("Sig");
+            let is_valid = test_case.consume_string("Result") == "P (0 )";
+            if !is_valid {
+                return Ok(());
+            }
+
+            let (alg, low_s_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => (
+                    &signature::ECDSA_P256_SHA256_ASN1,
+                    &signature::ECDSA_P256_SHA256_ASN1_LOW_S,
+                ),
+                ("P-384", "SHA384") => (
+                    &signature::ECDSA_P384_SHA384_ASN1,
+                    &signature::ECDSA_P384_SHA384_ASN1_LOW_S,
+                ),
+                ("P-521", "SHA512") => (
+                    &signature::ECDSA_P521_SHA512_ASN1,
+                    &signature::ECDSA_P521_SHA512_ASN1_LOW_S,
+                ),
+                ("secp256k1", "SHA256") => (
+                    &signature::ECDSA_SECP256K1_SHA256_ASN1,
+                    &signature::ECDSA_SECP256K1_SHA256_ASN1_LOW_S,
+                ),
+                // There are no low-s variants of the mixed curve+digest
+                // algorithms.
+                _ => return Ok(()),
+            };
+
+            test_low_s(alg, low_s_alg, &msg, &public_key, &sig);
+
+            Ok(())
+        },
+    );
+}
+
+// Like the other sign-and-verify tests, this is not a known-answer test.
+#[test]
+fn signature_ecdsa_sign_low_s_test() {
+    let rng = rand::SystemRandom::new();
+
+    test::run(
+        test_file!("../src/ec/suite_b/ecdsa/ecdsa_sign_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+
+            let msg = test_case.consume_bytes("Msg");
+            let d = test_case.consume_bytes("d");
+            let q = test_case.consume_bytes("Q");
+
+            // Ignored since the actual signature will use a randomized nonce.
+            let _k = test_case.consume_bytes("k");
+            let _expected_result = test_case.consume_bytes("Sig");
+
+            let algs: &[(_, _)] = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => &[
+                    (
+                        &signature::ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING,
+                        &signature::ECDSA_P256_SHA256_FIXED_LOW_S,
+                    ),
+                    (
+                        &signature::ECDSA_P256_SHA256_ASN1_LOW_S_SIGNING,
+                        &signature::ECDSA_P256_SHA256_ASN1_LOW_S,
+                    ),
+                ],
+                ("P-384", "SHA384") => &[
+                    (
+                        &signature::ECDSA_P384_SHA384_FIXED_LOW_S_SIGNING,
+                        &signature::ECDSA_P384_SHA384_FIXED_LOW_S,
+                    ),
+                    (
+                        &signature::ECDSA_P384_SHA384_ASN1_LOW_S_SIGNING,
+                        &signature::ECDSA_P384_SHA384_ASN1_LOW_S,
+                    ),
+                ],
+                ("P-521", "SHA512") => &[
+                    (
+                        &signature::ECDSA_P521_SHA512_FIXED_LOW_S_SIGNING,
+                        &signature::ECDSA_P521_SHA512_FIXED_LOW_S,
+                    ),
+                    (
+                        &signature::ECDSA_P521_SHA512_ASN1_LOW_S_SIGNING,
+                        &signature::ECDSA_P521_SHA512_ASN1_LOW_S,
+                    ),
+                ],
+                ("secp256k1", "SHA256") => &[
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_LOW_S_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_LOW_S,
+                    ),
+                    (
+                        &signature::ECDSA_SECP256K1_SHA256_ASN1_LOW_S_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_ASN1_LOW_S,
+                    ),
+                ],
+                _ => {
+                    panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                }
+            };
+
+            for (signing_alg, verification_alg) in algs {
+                let private_key = signature::EcdsaKeyPair::from_private_key_and_public_key(
+                    signing_alg,
+                    &d,
+                    &q,
+                    &rng,
+                )
+                .unwrap();
+                let public_key = signature::UnparsedPublicKey::new(*verification_alg, &q);
+
+                // Without normalization about half of the signatures would
+                // have a high s.
+                for _ in 0..8 {
+                    let signature = private_key.sign(&rng, &msg).unwrap();
+                    assert_eq!(public_key.verify(&msg, signature.as_ref()), Ok(()));
+                    assert_eq!(
+                        verification_alg
+                            .normalize_s(signature.as_ref())
+                            .unwrap()
+                            .as_ref(),
+                        signature.as_ref()
+                    );
+                }
+            }
+
+            Ok(())
+        },
+    );
+}
+
+// Like the other sign-and-verify tests, this is not a known-answer test.
+#[test]
+fn signature_ecdsa_sign_digest_and_verify_digest_test() {
+    let rng = rand::SystemRandom::new();
+
+    test::run(
+        test_file!("../src/ec/suite_b/ecdsa/ecdsa_sign_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+
+            let msg = test_case.consume_bytes("Msg");
+            let d = test_case.consume_bytes("d");
+            let q = test_case.consume_bytes("Q");
+
+            // Ignored since the actual signature will use a randomized nonce.
+            let _k = test_case.consume_bytes("k");
+            let _expected_result = test_case.consume_bytes("Sig");
+
+            let (signing_alg, verification_alg, digest_alg) =
+                match (curve_name.as_str(), digest_name.as_str()) {
+                    ("P-256", "SHA256") => (
+                        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_P256_SHA256_FIXED,
+                        &digest::SHA256,
+                    ),
+                    ("P-384", "SHA384") => (
+                        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                        &signature::ECDSA_P384_SHA384_FIXED,
+                        &digest::SHA384,
+                    ),
+                    ("P-521", "SHA512") => (
+                        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+                        &signature::ECDSA_P521_SHA512_FIXED,
+                        &digest::SHA512,
+                    ),
+                    ("secp256k1", "SHA256") => (
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_SECP256K1_SHA256_FIXED,
+                        &digest::SHA256,
+                    ),
+                    _ => {
+                        panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                    }
+                };
+
+            let private_key =
+                signature::EcdsaKeyPair::from_private_key_and_public_key(signing_alg, &d, &q, &rng)
+                    .unwrap();
+            let public_key = signature::UnparsedPublicKey::new(verification_alg, &q);
+
+            // The digest may have been calculated elsewhere.
+            let h = digest::digest(digest_alg, &msg);
+            let h = digest::Digest::from_bytes(digest_alg, h.as_ref()).unwrap();
+
+            let signature = private_key.sign_digest(&rng, &h).unwrap();
+            assert_eq!(public_key.verify_digest(&h, signature.as_ref()), Ok(()));
+            assert_eq!(public_key.verify(&msg, signature.as_ref()), Ok(()));
+
+            // Signatures of the message verify against its digest.
+            let signature = private_key.sign(&rng, &msg).unwrap();
+            assert_eq!(public_key.verify_digest(&h, signature.as_ref()), Ok(()));
+
+            // A digest calculated with a different algorithm is rejected.
+            let wrong_h = digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, &msg);
+            assert!(private_key.sign_digest(&rng, &wrong_h).is_err());
+            assert!(public_key
+                .verify_digest(&wrong_h, signature.as_ref())
+                .is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn signature_ecdsa_verify_batch_test() {
+    let rng = rand::SystemRandom::new();
+
+    for &(signing_alg, verification_alg) in &[
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+        ),
+        (
+            &signature::ECDSA_SECP256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_SECP256K1_SHA256_FIXED,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_LOW_S_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED_LOW_S,
+        ),
+    ] {
+        let key_pairs: Vec<_> = (0..2)
+            .map(|_| {
+                let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+                signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap()
+            })
+            .collect();
+
+        // Most of the signatures are by the same key, as in certificate
+        // transparency, and the last chunk is partial.
+        let cases: Vec<_> = (0..19u8)
+            .map(|i| {
+                let key_pair = &key_pairs[usize::from(i % 5 == 4)];
+                let msg = vec![i; usize::from(i)];
+                let sig = key_pair.sign(&rng, &msg).unwrap();
+                (
+                    key_pair.public_key().as_ref().to_vec(),
+                    msg,
+                    sig.as_ref().to_vec(),
+                )
+            })
+            .collect();
+        let batch: Vec<(&[u8], &[u8], &[u8])> = cases
+            .iter()
+            .map(|(public_key, msg, sig)| (&public_key[..], &msg[..], &sig[..]))
+            .collect();
+
+        assert_eq!(
+            signature::ecdsa_verify_batch(verification_alg, &[], &rng),
+            Ok(())
+        );
+        assert_eq!(
+            signature::ecdsa_verify_batch(verification_alg, &batch[..1], &rng),
+            Ok(())
+        );
+        assert_eq!(
+            signature::ecdsa_verify_batch(verification_alg, &batch, &rng),
+            Ok(())
+        );
+
+        // Each kind of invalid item is identified.
+        let mut wrong_msg = cases[2].1.clone();
+        wrong_msg.push(0);
+        let mut tampered_sig = cases[9].2.clone();
+        let last = tampered_sig.len() - 1;
+        tampered_sig[last] ^= 1;
+        let mut invalid = batch.clone();
+        invalid[2].1 = &wrong_msg;
+        invalid[9].2 = &tampered_sig;
+        invalid[10].0 = &cases[4].0;
+        invalid[11].2 = &cases[11].2[..(cases[11].2.len() - 1)];
+        invalid[17].0 = &cases[17].0[1..];
+        let err = signature::ecdsa_verify_batch(verification_alg, &invalid, &rng).unwrap_err();
+        assert_eq!(err.invalid_indices(), &[2, 9, 10, 11, 17]);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn signature_ecdsa_verify_batch_vectors_test() {
+    let rng = rand::SystemRandom::new();
+
+    let mut cases = Vec::new();
+    test::run(
+        test_file!("ecdsa_verify_fixed_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+            let msg = test_case.consume_bytes("Msg");
+            let public_key = test_case.consume_bytes("Q");
+            let sig = test_case.consume_bytes("Sig");
+            let is_valid = test_case.consume_string("Result") == "P (0 )";
+            cases.push((
+                curve_name + digest_name.as_str(),
+                public_key,
+                msg,
+                sig,
+                is_valid,
+            ));
+            Ok(())
+        },
+    );
+
+    for &(name, alg) in &[
+        ("P-256SHA256", &signature::ECDSA_P256_SHA256_FIXED),
+        ("P-384SHA384", &signature::ECDSA_P384_SHA384_FIXED),
+        ("P-521SHA512", &signature::ECDSA_P521_SHA512_FIXED),
+        ("secp256k1SHA256", &signature::ECDSA_SECP256K1_SHA256_FIXED),
+    ] {
+        let cases: Vec<_> = cases.iter().filter(|case| case.0 == name).collect();
+        let batch: Vec<(&[u8], &[u8], &[u8])> = cases
+            .iter()
+            .map(|(_, public_key, msg, sig, _)| (&public_key[..], &msg[..], &sig[..]))
+            .collect();
+        let expected_invalid: Vec<usize> = cases
+            .iter()
+            .enumerate()
+            .filter(|(_, case)| !case.4)
+            .map(|(i, _)| i)
+            .collect();
+        assert!(!expected_invalid.is_empty());
+
+        let err = signature::ecdsa_verify_batch(alg, &batch, &rng).unwrap_err();
+        assert_eq!(err.invalid_indices(), &expected_invalid[..]);
+
+        let valid: Vec<_> = batch
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !expected_invalid.contains(i))
+            .map(|(_, item)| *item)
+            .collect();
+        assert_eq!(signature::ecdsa_verify_batch(alg, &valid, &rng), Ok(()));
+    }
+}
@@ -13,7 +13,7 @@
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
 use ring::{
-    error,
+    agreement, digest, error,
     signature::{self, Ed25519KeyPair, KeyPair},
     test, test_file,
 };
@@ -83,6 +83,294 @@ fn test_signature_ed25519_verify() {
     );
 }
 
+/// Edge cases on which the verification policies differ.
+#[test]
+fn test_signature_ed25519_verify_policies() {
+    let algs: [(&signature::EdDSAParameters, &str); 3] = [
+        (&signature::ED25519, "Default"),
+        (&signature::ED25519_STRICT, "Strict"),
+        (&signature::ED25519_ZIP215, "Zip215"),
+    ];
+    test::run(
+        test_file!("ed25519_verify_policy_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            for &(alg, name) in &algs {
+                let is_valid = match test_case.consume_string(name).as_str() {
+                    "P" => true,
+                    "F" => false,
+                    s => panic!("{:?} is not a valid result", s),
+                };
+                let actual_result =
+                    signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
+                assert_eq!(actual_result.is_ok(), is_valid, "{:?}", alg);
+
+                let parsed_result = match signature::ParsedPublicKey::new(alg, &public_key) {
+                    Ok(public_key) => public_key.verify(&msg, &sig).is_ok(),
+                    Err(_) => false,
+                };
+                assert_eq!(parsed_result, is_valid, "{:?}", alg);
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_signature_ed25519ph() {
+    test::run(test_file!("ed25519ph_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("SEED");
+        let public_key = test_case.consume_bytes("PUB");
+        let msg = test_case.consume_bytes("MESSAGE");
+        let context = test_case.consume_bytes("CONTEXT");
+        let expected_sig = test_case.consume_bytes("SIG");
+
+        let key_pair = Ed25519KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+
+        // The message can be hashed in pieces.
+        let prehash = {
+            let mut ctx = digest::Context::new(&digest::SHA512);
+            for chunk in msg.chunks(7) {
+                ctx.update(chunk);
+            }
+            ctx.finish()
+        };
+        let actual_sig = key_pair.sign_prehashed(&context, &prehash).unwrap();
+        assert_eq!(&expected_sig[..], actual_sig.as_ref());
+
+        assert_eq!(
+            signature::verify_ed25519ph(&public_key, &context, &prehash, &expected_sig),
+            Ok(())
+        );
+        if context.is_empty() {
+            let parsed = signature::ParsedPublicKey::new(&signature::ED25519PH, &public_key[..])?;
+            assert_eq!(parsed.verify(&msg, &expected_sig), Ok(()));
+            assert_eq!(parsed.verify_digest(&prehash, &expected_sig), Ok(()));
+
+            let public_key = signature::UnparsedPublicKey::new(&signature::ED25519PH, &public_key);
+            assert_eq!(public_key.verify(&msg, &expected_sig), Ok(()));
+            assert_eq!(public_key.verify_digest(&prehash, &expected_sig), Ok(()));
+        }
+
+        // An Ed25519ph signature is only valid for the same context.
+        let mut other_context = context.clone();
+        other_context.push(b'!');
+        assert!(
+            signature::verify_ed25519ph(&public_key, &other_context, &prehash, &expected_sig)
+                .is_err()
+        );
+
+        // An Ed25519ph signature isn't an Ed25519 signature of the message or
+        // of its digest.
+        test_signature_verification(&public_key, &msg, &expected_sig, Err(error::Unspecified));
+        test_signature_verification(
+            &public_key,
+            prehash.as_ref(),
+            &expected_sig,
+            Err(error::Unspecified),
+        );
+
+        let mut tampered_sig = expected_sig;
+        tampered_sig[0] ^= 1;
+        assert!(
+            signature::verify_ed25519ph(&public_key, &context, &prehash, &tampered_sig).is_err()
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_signature_ed25519ph_misuse() {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&[1; 32]).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let prehash = digest::digest(&digest::SHA512, b"message");
+
+    // Only SHA-512 digests are accepted.
+    let wrong_prehash = digest::digest(&digest::SHA384, b"message");
+    assert!(key_pair.sign_prehashed(b"", &wrong_prehash).is_err());
+    let sig = key_pair.sign_prehashed(b"", &prehash).unwrap();
+    assert!(signature::verify_ed25519ph(public_key, b"", &wrong_prehash, sig.as_ref()).is_err());
+
+    // Contexts may be up to 255 bytes long.
+    let context = [b'a'; 256];
+    let sig = key_pair.sign_prehashed(&context[..255], &prehash).unwrap();
+    assert_eq!(
+        signature::verify_ed25519ph(public_key, &context[..255], &prehash, sig.as_ref()),
+        Ok(())
+    );
+    assert!(key_pair.sign_prehashed(&context, &prehash).is_err());
+    assert!(signature::verify_ed25519ph(public_key, &context, &prehash, sig.as_ref()).is_err());
+
+    assert_eq!(
+        format!("{:?}", &signature::ED25519PH),
+        "ring::signature::ED25519PH"
+    );
+}
+
+#[test]
+fn test_signature_ed25519ctx() {
+    test::run(test_file!("ed25519ctx_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("SEED");
+        let public_key = test_case.consume_bytes("PUB");
+        let msg = test_case.consume_bytes("MESSAGE");
+        let context = test_case.consume_bytes("CONTEXT");
+        let expected_sig = test_case.consume_bytes("SIG");
+
+        let key_pair = Ed25519KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+        let actual_sig = key_pair.sign_with_context(&context, &msg).unwrap();
+        assert_eq!(&expected_sig[..], actual_sig.as_ref());
+
+        assert_eq!(
+            signature::verify_ed25519ctx(&public_key, &context, &msg, &expected_sig),
+            Ok(())
+        );
+
+        // An Ed25519ctx signature is only valid for the same context and
+        // message.
+        let mut other_context = context.clone();
+        other_context.push(b'!');
+        assert!(
+            signature::verify_ed25519ctx(&public_key, &other_context, &msg, &expected_sig).is_err()
+        );
+        let mut other_msg = msg.clone();
+        other_msg.push(b'!');
+        assert!(
+            signature::verify_ed25519ctx(&public_key, &context, &other_msg, &expected_sig).is_err()
+        );
+
+        // An Ed25519ctx signature isn't an Ed25519 or Ed25519ph signature.
+        test_signature_verification(&public_key, &msg, &expected_sig, Err(error::Unspecified));
+        let prehash = digest::digest(&digest::SHA512, &msg);
+        assert!(
+            signature::verify_ed25519ph(&public_key, &context, &prehash, &expected_sig).is_err()
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_signature_ed25519ctx_context_len() {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&[1; 32]).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let msg = b"message";
+
+    // Ed25519ctx requires a context.
+    assert!(key_pair.sign_with_context(b"", msg).is_err());
+    let sig = key_pair.sign(msg);
+    assert!(signature::verify_ed25519ctx(public_key, b"", msg, sig.as_ref()).is_err());
+
+    let context = [b'a'; 256];
+    let sig = key_pair.sign_with_context(&context[..255], msg).unwrap();
+    assert_eq!(
+        signature::verify_ed25519ctx(public_key, &context[..255], msg, sig.as_ref()),
+        Ok(())
+    );
+    assert!(key_pair.sign_with_context(&context, msg).is_err());
+    assert!(signature::verify_ed25519ctx(public_key, &context, msg, sig.as_ref()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_ed25519_verify_batch() {
+    let rng = ring::rand::SystemRandom::new();
+
+    let mut cases = Vec::new();
+    test::run(test_file!("ed25519_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let _ = test_case.consume_bytes("SEED");
+        let public_key = test_case.consume_bytes("PUB");
+        let msg = test_case.consume_bytes("MESSAGE");
+        let sig = test_case.consume_bytes("SIG");
+        cases.push((public_key, msg, sig));
+        Ok(())
+    });
+    let batch: Vec<(&[u8], &[u8], &[u8])> = cases
+        .iter()
+        .map(|(public_key, msg, sig)| (&public_key[..], &msg[..], &sig[..]))
+        .collect();
+
+    assert_eq!(
+        signature::verify_batch(&signature::ED25519, &[], &rng),
+        Ok(())
+    );
+    assert_eq!(
+        signature::verify_batch(&signature::ED25519, &batch[..1], &rng),
+        Ok(())
+    );
+    assert_eq!(
+        signature::verify_batch(&signature::ED25519, &batch, &rng),
+        Ok(())
+    );
+
+    // Each kind of invalid item is identified.
+    let mut wrong_msg = cases[3].1.clone();
+    wrong_msg.push(0);
+    let mut tampered_sig = cases[7].2.clone();
+    tampered_sig[0] ^= 1;
+    let mut s_too_large = cases[11].2.clone();
+    s_too_large[63] = 0xff;
+    let mut invalid = batch.clone();
+    invalid[3].1 = &wrong_msg;
+    invalid[7].2 = &tampered_sig;
+    invalid[11].2 = &s_too_large;
+    invalid[12].0 = &cases[12].0[1..];
+    invalid[13].2 = &cases[13].2[..63];
+    let err = signature::verify_batch(&signature::ED25519, &invalid, &rng).unwrap_err();
+    assert_eq!(err.invalid_indices(), &[3, 7, 11, 12, 13]);
+    assert_eq!(err.to_string(), "ring::signature::BatchVerificationError");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_ed25519_verify_batch_vectors() {
+    let rng = ring::rand::SystemRandom::new();
+
+    let mut cases = Vec::new();
+    let mut expected_invalid = Vec::new();
+    test::run(
+        test_file!("ed25519_verify_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            match test_case.consume_string("Result").as_str() {
+                "P" => {}
+                "F" => expected_invalid.push(cases.len()),
+                s => panic!("{:?} is not a valid result", s),
+            }
+            cases.push((public_key, msg, sig));
+            Ok(())
+        },
+    );
+    assert!(!expected_invalid.is_empty());
+    let batch: Vec<(&[u8], &[u8], &[u8])> = cases
+        .iter()
+        .map(|(public_key, msg, sig)| (&public_key[..], &msg[..], &sig[..]))
+        .collect();
+
+    let err = signature::verify_batch(&signature::ED25519, &batch, &rng).unwrap_err();
+    assert_eq!(err.invalid_indices(), &expected_invalid[..]);
+
+    let valid: Vec<_> = batch
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !expected_invalid.contains(i))
+        .map(|(_, item)| *item)
+        .collect();
+    assert_eq!(
+        signature::verify_batch(&signature::ED25519, &valid, &rng),
+        Ok(())
+    );
+}
+
 fn test_signature_verification(
     public_key: &[u8],
     msg: &[u8],
@@ -93,6 +381,12 @@ fn test_signature_verification(
         expected_result,
         signature::UnparsedPublicKey::new(&signature::ED25519, public_key).verify(msg, sig)
     );
+    assert_eq!(
+        expected_result,
+        signature::ParsedPublicKey::new(&signature::ED25519, public_key)
+            .map_err(|_| error::Unspecified)
+            .and_then(|public_key| public_key.verify(msg, sig))
+    );
 }
 
 #[test]
@@ -112,6 +406,43 @@ fn test_ed25519_from_seed_and_public_key_misuse() {
     assert!(Ed25519KeyPair::from_seed_and_public_key(PUBLIC_KEY, PRIVATE_KEY).is_err());
 }
 
+#[test]
+fn test_ed25519_seed_export() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("ed25519_test_private_key.bin");
+    const PUBLIC_KEY: &[u8] = include_bytes!("ed25519_test_public_key.bin");
+
+    // Key pairs aren't exportable by default.
+    let key_pair = Ed25519KeyPair::from_seed_and_public_key(PRIVATE_KEY, PUBLIC_KEY).unwrap();
+    assert!(key_pair.seed().is_err());
+    let rng = test::rand::FixedSliceRandom { bytes: PRIVATE_KEY };
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert!(key_pair.seed().is_err());
+
+    let key_pair =
+        Ed25519KeyPair::from_seed_and_public_key_exportable(PRIVATE_KEY, PUBLIC_KEY).unwrap();
+    assert_eq!(key_pair.seed().unwrap(), PRIVATE_KEY);
+    let key_pair = Ed25519KeyPair::from_pkcs8_exportable(pkcs8.as_ref()).unwrap();
+    assert_eq!(key_pair.seed().unwrap(), PRIVATE_KEY);
+    assert_eq!(key_pair.public_key().as_ref(), PUBLIC_KEY);
+
+    // The exported seed round-trips.
+    let round_tripped =
+        Ed25519KeyPair::from_seed_and_public_key(key_pair.seed().unwrap(), PUBLIC_KEY).unwrap();
+    assert_eq!(
+        round_tripped.sign(b"hello").as_ref(),
+        key_pair.sign(b"hello").as_ref()
+    );
+
+    // The exportable constructors are as strict as the others.
+    assert!(
+        Ed25519KeyPair::from_seed_and_public_key_exportable(&PRIVATE_KEY[..31], PUBLIC_KEY)
+            .is_err()
+    );
+    assert!(Ed25519KeyPair::from_seed_and_public_key_exportable(PUBLIC_KEY, PRIVATE_KEY).is_err());
+    assert!(Ed25519KeyPair::from_pkcs8_exportable(&pkcs8.as_ref()[1..]).is_err());
+}
+
 #[test]
 fn test_ed25519_from_pkcs8_unchecked() {
     // Just test that we can parse the input.
@@ -185,3 +516,120 @@ fn ed25519_test_public_key_coverage() {
         format!("{:?}", key_pair)
     );
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ed25519_test_public_key_spki() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("ed25519_test_private_key.p8");
+    const SPKI: &[u8] = include_bytes!("ed25519_test_public_key_spki.der");
+
+    let key_pair = signature::Ed25519KeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    assert_eq!(&key_pair.public_key().subject_public_key_info()[..], SPKI);
+}
+
+// Unlike with the other policies, the combined check never accepts a batch
+// that verifying individually would reject with `ED25519_ZIP215`.
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_ed25519_zip215_verify_batch() {
+    let rng = ring::rand::SystemRandom::new();
+
+    let mut cases = Vec::new();
+    let mut expected_invalid = Vec::new();
+    test::run(
+        test_file!("ed25519_verify_policy_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            let _ = test_case.consume_string("Default");
+            let _ = test_case.consume_string("Strict");
+            if test_case.consume_string("Zip215") == "F" {
+                expected_invalid.push(cases.len());
+            }
+            cases.push((public_key, msg, sig));
+            Ok(())
+        },
+    );
+    let batch: Vec<(&[u8], &[u8], &[u8])> = cases
+        .iter()
+        .map(|(public_key, msg, sig)| (&public_key[..], &msg[..], &sig[..]))
+        .collect();
+
+    for _ in 0..8 {
+        let err = signature::verify_batch(&signature::ED25519_ZIP215, &batch, &rng).unwrap_err();
+        assert_eq!(err.invalid_indices(), &expected_invalid[..]);
+    }
+}
+
+#[test]
+fn test_ed25519_to_x25519() {
+    test::run(
+        test_file!("ed25519_to_x25519_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let seed = test_case.consume_bytes("SEED");
+            let public_key = test_case.consume_bytes("PUB");
+            let expected = test_case.consume_bytes("X25519");
+
+            let actual = agreement::x25519_public_key_from_ed25519(&public_key).unwrap();
+            assert_eq!(actual.algorithm(), &agreement::X25519);
+            assert_eq!(actual.as_ref(), &expected[..]);
+
+            // The negated public key has the same u-coordinate.
+            let mut negated = public_key.clone();
+            negated[31] ^= 0x80;
+            let actual = agreement::x25519_public_key_from_ed25519(&negated).unwrap();
+            assert_eq!(actual.as_ref(), &expected[..]);
+
+            let key_pair = Ed25519KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+            let private_key = key_pair.x25519_private_key();
+            assert_eq!(private_key.algorithm(), &agreement::X25519);
+            let actual = private_key.compute_public_key().unwrap();
+            assert_eq!(actual.as_ref(), &expected[..]);
+
+            // Agreement with the converted keys matches agreement with an
+            // ephemeral key.
+            let rng = ring::rand::SystemRandom::new();
+            let ephemeral = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng)?;
+            let ephemeral_public_key = ephemeral.compute_public_key()?;
+            let ours = agreement::agree_ephemeral(
+                key_pair.x25519_private_key(),
+                &agreement::UnparsedPublicKey::new(&agreement::X25519, ephemeral_public_key),
+                |secret| secret.to_vec(),
+            )?;
+            let theirs = agreement::agree_ephemeral(
+                ephemeral,
+                &agreement::UnparsedPublicKey::new(&agreement::X25519, &expected),
+                |secret| secret.to_vec(),
+            )?;
+            assert_eq!(ours, theirs);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_ed25519_to_x25519_invalid() {
+    const INVALID: &[&str] = &[
+        // Too short and too long.
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f70751",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a00",
+        // Not on the curve (y = 2).
+        "0200000000000000000000000000000000000000000000000000000000000000",
+        // The identity, which has small order.
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        // A point of order 2.
+        "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        // A point of order 8.
+        "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+        // A non-canonical encoding of the identity (y = p + 1).
+        "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    ];
+    for public_key in INVALID {
+        let public_key = test::from_hex(public_key).unwrap();
+        assert!(agreement::x25519_public_key_from_ed25519(&public_key).is_err());
+    }
+}
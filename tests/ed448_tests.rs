@@ -0,0 +1,170 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{
+    error,
+    signature::{self, Ed448KeyPair, KeyPair},
+    test, test_file,
+};
+
+#[test]
+fn test_signature_ed448() {
+    test::run(test_file!("ed448_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("SEED");
+        assert_eq!(57, seed.len());
+
+        let public_key = test_case.consume_bytes("PUB");
+        assert_eq!(signature::ED448_PUBLIC_KEY_LEN, public_key.len());
+
+        let msg = test_case.consume_bytes("MESSAGE");
+
+        let expected_sig = test_case.consume_bytes("SIG");
+
+        {
+            let key_pair = Ed448KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+            let actual_sig = key_pair.sign(&msg);
+            assert_eq!(&expected_sig[..], actual_sig.as_ref());
+        }
+
+        // Test PKCS#8 generation, parsing, and private-to-public calculations.
+        let rng = test::rand::FixedSliceRandom { bytes: &seed };
+        let pkcs8 = Ed448KeyPair::generate_pkcs8(&rng).unwrap();
+        let key_pair = Ed448KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        assert_eq!(public_key, key_pair.public_key().as_ref());
+
+        // Test Signature generation.
+        let actual_sig = key_pair.sign(&msg);
+        assert_eq!(&expected_sig[..], actual_sig.as_ref());
+
+        // Test Signature verification.
+        test_signature_verification(&public_key, &msg, &expected_sig, Ok(()));
+
+        let mut tampered_sig = expected_sig;
+        tampered_sig[0] ^= 1;
+
+        test_signature_verification(&public_key, &msg, &tampered_sig, Err(error::Unspecified));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_signature_ed448_verify() {
+    test::run(
+        test_file!("ed448_verify_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            let expected_result = match test_case.consume_string("Result").as_str() {
+                "P" => Ok(()),
+                "F" => Err(error::Unspecified),
+                s => panic!("{:?} is not a valid result", s),
+            };
+            test_signature_verification(&public_key, &msg, &sig, expected_result);
+            Ok(())
+        },
+    );
+}
+
+fn test_signature_verification(
+    public_key: &[u8],
+    msg: &[u8],
+    sig: &[u8],
+    expected_result: Result<(), error::Unspecified>,
+) {
+    assert_eq!(
+        expected_result,
+        signature::UnparsedPublicKey::new(&signature::ED448, public_key).verify(msg, sig)
+    );
+}
+
+#[test]
+fn test_ed448_from_seed_and_public_key_misuse() {
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = Ed448KeyPair::generate_pkcs8(&rng).unwrap();
+    let key_pair = Ed448KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    // The seed is the contents of the inner OCTET STRING.
+    let seed = &pkcs8.as_ref()[17..][..57];
+
+    assert!(Ed448KeyPair::from_seed_and_public_key(seed, public_key).is_ok());
+
+    // Truncated private key.
+    assert!(Ed448KeyPair::from_seed_and_public_key(&seed[..56], public_key).is_err());
+
+    // Truncated public key.
+    assert!(Ed448KeyPair::from_seed_and_public_key(seed, &public_key[..56]).is_err());
+
+    // Swapped public and private key.
+    assert!(Ed448KeyPair::from_seed_and_public_key(public_key, seed).is_err());
+}
+
+#[test]
+fn test_ed448_from_pkcs8_unchecked() {
+    test::run(
+        test_file!("ed448_from_pkcs8_unchecked_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let input = test_case.consume_bytes("Input");
+            let error = test_case.consume_optional_string("Error");
+
+            match (Ed448KeyPair::from_pkcs8_maybe_unchecked(&input), error) {
+                (Ok(_), None) => (),
+                (Err(e), None) => panic!("Failed with error \"{}\", but expected to succeed", e),
+                (Ok(_), Some(e)) => panic!("Succeeded, but expected error \"{}\"", e),
+                (Err(actual), Some(expected)) => assert_eq!(format!("{}", actual), expected),
+            };
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_ed448_from_pkcs8() {
+    test::run(
+        test_file!("ed448_from_pkcs8_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let input = test_case.consume_bytes("Input");
+            let error = test_case.consume_optional_string("Error");
+
+            match (Ed448KeyPair::from_pkcs8(&input), error) {
+                (Ok(_), None) => (),
+                (Err(e), None) => panic!("Failed with error \"{}\", but expected to succeed", e),
+                (Ok(_), Some(e)) => panic!("Succeeded, but expected error \"{}\"", e),
+                (Err(actual), Some(expected)) => assert_eq!(format!("{}", actual), expected),
+            };
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_ed448_debug() {
+    assert_eq!("ring::signature::ED448", format!("{:?}", signature::ED448));
+
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = Ed448KeyPair::generate_pkcs8(&rng).unwrap();
+    let key_pair = Ed448KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert_eq!(
+        format!("Ed448KeyPair {{ public_key: {:?} }}", key_pair.public_key()),
+        format!("{:?}", key_pair)
+    );
+}
@@ -0,0 +1,301 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, frost, rand, signature, test, test_file};
+
+/// Test vectors from RFC 9591, plus generated test vectors.
+#[test]
+fn test_frost_sign() {
+    test::run(test_file!("frost_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let group_public_key = test_case.consume_bytes("GroupPublicKey");
+        let min_signers = test_case.consume_usize("MinSigners") as u16;
+        let msg = test_case.consume_bytes("Msg");
+
+        let mut key_packages = Vec::new();
+        let mut nonces = Vec::new();
+        let mut commitments = Vec::new();
+        let mut expected_shares = Vec::new();
+        for i in 1..=2 {
+            let identifier = test_case.consume_usize(&format!("ID{}", i)) as u16;
+            let signing_share = test_case.consume_bytes(&format!("SigningShare{}", i));
+            let hiding_randomness = test_case.consume_bytes(&format!("HidingRandomness{}", i));
+            let binding_randomness = test_case.consume_bytes(&format!("BindingRandomness{}", i));
+            let hiding = test_case.consume_bytes(&format!("HidingCommitment{}", i));
+            let binding = test_case.consume_bytes(&format!("BindingCommitment{}", i));
+            expected_shares.push(test_case.consume_bytes(&format!("SignatureShare{}", i)));
+
+            let key_package = frost::KeyPackage::from_signing_share(
+                identifier,
+                min_signers,
+                &signing_share,
+                &group_public_key,
+            )
+            .unwrap();
+            assert_eq!(key_package.identifier(), identifier);
+            assert_eq!(
+                key_package.group_public_key().as_ref(),
+                &group_public_key[..]
+            );
+
+            let bytes: [&[u8]; 2] = [&hiding_randomness, &binding_randomness];
+            let rng = test::rand::FixedSliceSequenceRandom {
+                bytes: &bytes,
+                current: core::cell::UnsafeCell::new(0),
+            };
+            let (n, c) = key_package.commit(&rng).unwrap();
+            assert_eq!(c.identifier(), identifier);
+            assert_eq!(c.hiding(), &hiding[..]);
+            assert_eq!(c.binding(), &binding[..]);
+            assert_eq!(
+                frost::SigningCommitments::from_bytes(identifier, &hiding, &binding).unwrap(),
+                c
+            );
+
+            key_packages.push(key_package);
+            nonces.push(n);
+            commitments.push(c);
+        }
+        let expected_signature = test_case.consume_bytes("Signature");
+
+        let mut shares = Vec::new();
+        for ((key_package, n), expected_share) in
+            key_packages.iter().zip(nonces).zip(expected_shares)
+        {
+            let share = key_package.sign(n, &commitments, &msg).unwrap();
+            assert_eq!(share.as_ref(), &expected_share[..]);
+            assert!(frost::verify_signature_share(
+                key_package.verifying_share().as_ref(),
+                &group_public_key,
+                &commitments,
+                &msg,
+                &share,
+            )
+            .is_ok());
+            shares.push(
+                frost::SignatureShare::from_bytes(key_package.identifier(), &expected_share)
+                    .unwrap(),
+            );
+        }
+
+        let sig = frost::aggregate(&group_public_key, &commitments, &msg, &shares).unwrap();
+        assert_eq!(sig.as_ref(), &expected_signature[..]);
+
+        let public_key = signature::UnparsedPublicKey::new(&signature::ED25519, &group_public_key);
+        assert!(public_key.verify(&msg, sig.as_ref()).is_ok());
+
+        Ok(())
+    });
+}
+
+// Runs both rounds with `signers` and returns the commitments and shares.
+fn sign(
+    signers: &[&frost::KeyPackage],
+    msg: &[u8],
+) -> (Vec<frost::SigningCommitments>, Vec<frost::SignatureShare>) {
+    let rng = rand::SystemRandom::new();
+    let (nonces, commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|s| s.commit(&rng).unwrap()).unzip();
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(s, n)| s.sign(n, &commitments, msg).unwrap())
+        .collect();
+    (commitments, shares)
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_frost_generate_with_dealer() {
+    let rng = rand::SystemRandom::new();
+    let key_packages = frost::generate_with_dealer(3, 5, &rng).unwrap();
+    assert_eq!(key_packages.len(), 5);
+    let group_public_key = key_packages[0].group_public_key().as_ref();
+    let public_key = signature::UnparsedPublicKey::new(&signature::ED25519, group_public_key);
+    for (i, key_package) in key_packages.iter().enumerate() {
+        assert_eq!(usize::from(key_package.identifier()), i + 1);
+        assert_eq!(key_package.min_signers(), 3);
+        assert_eq!(key_package.group_public_key().as_ref(), group_public_key);
+    }
+
+    for signers in [[0, 2, 4], [1, 2, 3], [4, 3, 0]].iter() {
+        let mut signers = signers
+            .iter()
+            .map(|&i| &key_packages[i])
+            .collect::<Vec<_>>();
+        signers.sort_by_key(|k| k.identifier());
+        let (commitments, shares) = sign(&signers, b"msg");
+        let sig = frost::aggregate(group_public_key, &commitments, b"msg", &shares).unwrap();
+        assert!(public_key.verify(b"msg", sig.as_ref()).is_ok());
+        assert!(public_key.verify(b"other", sig.as_ref()).is_err());
+    }
+
+    // Any four also work.
+    let signers = key_packages[1..].iter().collect::<Vec<_>>();
+    let (commitments, shares) = sign(&signers, b"msg");
+    let sig = frost::aggregate(group_public_key, &commitments, b"msg", &shares).unwrap();
+    assert!(public_key.verify(b"msg", sig.as_ref()).is_ok());
+
+    assert!(frost::generate_with_dealer(1, 3, &rng).is_err());
+    assert!(frost::generate_with_dealer(4, 3, &rng).is_err());
+    assert!(frost::generate_with_dealer(2, 2, &rng).is_ok());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_frost_bad_share() {
+    let rng = rand::SystemRandom::new();
+    let key_packages = frost::generate_with_dealer(2, 3, &rng).unwrap();
+    let group_public_key = key_packages[0].group_public_key().as_ref();
+
+    // Participant 3 signs a different message.
+    let (n1, c1) = key_packages[0].commit(&rng).unwrap();
+    let (n3, c3) = key_packages[2].commit(&rng).unwrap();
+    let commitments = [c1, c3];
+    let shares = [
+        key_packages[0].sign(n1, &commitments, b"msg").unwrap(),
+        key_packages[2].sign(n3, &commitments, b"other").unwrap(),
+    ];
+    assert!(frost::aggregate(group_public_key, &commitments, b"msg", &shares).is_err());
+
+    let verify = |key_package: &frost::KeyPackage, share| {
+        frost::verify_signature_share(
+            key_package.verifying_share().as_ref(),
+            group_public_key,
+            &commitments,
+            b"msg",
+            share,
+        )
+    };
+    assert!(verify(&key_packages[0], &shares[0]).is_ok());
+    assert_eq!(
+        verify(&key_packages[2], &shares[1]),
+        Err(error::Unspecified)
+    );
+    assert!(verify(&key_packages[1], &shares[0]).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_frost_aggregate_rejects() {
+    let rng = rand::SystemRandom::new();
+    let key_packages = frost::generate_with_dealer(2, 3, &rng).unwrap();
+    let group_public_key = key_packages[0].group_public_key().as_ref();
+    let (commitments, mut shares) = sign(&[&key_packages[0], &key_packages[2]], b"msg");
+    assert!(frost::aggregate(group_public_key, &commitments, b"msg", &shares).is_ok());
+    assert!(frost::aggregate(group_public_key, &commitments, b"other", &shares).is_err());
+    assert!(frost::aggregate(group_public_key, &commitments, b"msg", &shares[..1]).is_err());
+    let other_key = key_packages[1].verifying_share().as_ref();
+    assert!(frost::aggregate(other_key, &commitments, b"msg", &shares).is_err());
+
+    // The shares must be in the same order as the commitments.
+    shares.swap(0, 1);
+    assert!(frost::aggregate(group_public_key, &commitments, b"msg", &shares).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_frost_sign_rejects_commitment_list() {
+    let rng = rand::SystemRandom::new();
+    let key_packages = frost::generate_with_dealer(2, 3, &rng).unwrap();
+    let commit = |i: usize| key_packages[i].commit(&rng).unwrap();
+
+    let (n0, c0) = commit(0);
+    let (_, c1) = commit(1);
+    let (_, c2) = commit(2);
+    let sign = |n, commitments: &[frost::SigningCommitments]| {
+        key_packages[0].sign(n, commitments, b"msg").map(|_| ())
+    };
+
+    // Too few signers.
+    assert!(sign(n0, &[c0]).is_err());
+    // Not sorted.
+    let (n0, c0) = commit(0);
+    assert!(sign(n0, &[c1, c0]).is_err());
+    // A duplicate identifier.
+    let (n0, c0) = commit(0);
+    let (_, c1_again) = commit(1);
+    assert!(sign(n0, &[c0, c1, c1_again]).is_err());
+    // Missing the signer's own commitments.
+    let (n0, _) = commit(0);
+    assert!(sign(n0, &[c1, c2]).is_err());
+    // The signer's commitments are for other nonces.
+    let (n0, _) = commit(0);
+    let (_, c0) = commit(0);
+    assert!(sign(n0, &[c0, c1]).is_err());
+
+    let (n0, c0) = commit(0);
+    assert!(sign(n0, &[c0, c1, c2]).is_ok());
+}
+
+#[test]
+fn test_frost_signing_commitments_rejects() {
+    let key_package = frost::KeyPackage::from_signing_share(
+        1,
+        2,
+        &test::from_hex("929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509")
+            .unwrap(),
+        &test::from_hex("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673")
+            .unwrap(),
+    )
+    .unwrap();
+    let (_, c) = key_package.commit(&rand::SystemRandom::new()).unwrap();
+    let (hiding, binding) = (c.hiding(), c.binding());
+    assert!(frost::SigningCommitments::from_bytes(1, hiding, binding).is_ok());
+    assert!(frost::SigningCommitments::from_bytes(0, hiding, binding).is_err());
+    assert!(frost::SigningCommitments::from_bytes(1, &hiding[1..], binding).is_err());
+
+    let mut identity = [0; frost::COMMITMENT_LEN];
+    identity[0] = 1;
+    assert!(frost::SigningCommitments::from_bytes(1, hiding, &identity).is_err());
+
+    // A point of order 4.
+    let mut small_order = [0; frost::COMMITMENT_LEN];
+    small_order[31] = 0x80;
+    assert!(frost::SigningCommitments::from_bytes(1, &small_order, binding).is_err());
+
+    // The sum of the base point and the point of order 2.
+    let b_plus_t =
+        test::from_hex("9599999999999999999999999999999999999999999999999999999999999999").unwrap();
+    assert!(frost::SigningCommitments::from_bytes(1, hiding, &b_plus_t).is_err());
+
+    // A non-canonical encoding of the identity, y = p + 1.
+    let mut non_canonical = [0xff; frost::COMMITMENT_LEN];
+    non_canonical[0] = 0xee;
+    non_canonical[31] = 0x7f;
+    assert!(frost::SigningCommitments::from_bytes(1, &non_canonical, binding).is_err());
+}
+
+#[test]
+fn test_frost_key_package_rejects() {
+    let share =
+        test::from_hex("929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509").unwrap();
+    let group_public_key =
+        test::from_hex("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673").unwrap();
+    let from = frost::KeyPackage::from_signing_share;
+    assert!(from(1, 2, &share, &group_public_key).is_ok());
+    assert!(from(0, 2, &share, &group_public_key).is_err());
+    assert!(from(1, 1, &share, &group_public_key).is_err());
+    assert!(from(1, 2, &share[1..], &group_public_key).is_err());
+    assert!(from(1, 2, &[0xff; 32], &group_public_key).is_err());
+    assert!(from(1, 2, &share, &group_public_key[1..]).is_err());
+    let mut identity = [0; 32];
+    identity[0] = 1;
+    assert!(from(1, 2, &share, &identity).is_err());
+
+    assert!(frost::SignatureShare::from_bytes(1, &share).is_ok());
+    assert!(frost::SignatureShare::from_bytes(0, &share).is_err());
+    assert!(frost::SignatureShare::from_bytes(1, &[0xff; 32]).is_err());
+    assert!(frost::SignatureShare::from_bytes(1, &share[1..]).is_err());
+}
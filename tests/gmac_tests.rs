@@ -0,0 +1,107 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{aead, aead::gmac, test, test_file};
+
+#[test]
+fn gmac_aes_128() {
+    test_gmac(&gmac::AES_128, test_file!("gmac_aes_128_tests.txt"));
+}
+
+#[test]
+fn gmac_aes_256() {
+    test_gmac(&gmac::AES_256, test_file!("gmac_aes_256_tests.txt"));
+}
+
+fn test_gmac(alg: &'static gmac::Algorithm, test_file: test::File) {
+    test::run(test_file, |section, test_case| {
+        assert_eq!(section, "");
+        let key_bytes = test_case.consume_bytes("KEY");
+        let nonce = test_case.consume_bytes("NONCE");
+        let input = test_case.consume_bytes("IN");
+        let tag = test_case.consume_bytes("TAG");
+
+        let key = gmac::Key::new(alg, &key_bytes)?;
+        let nonce = || aead::Nonce::try_assume_unique_for_key(&nonce).unwrap();
+
+        let actual = key.sign(nonce(), &input)?;
+        assert_eq!(actual.as_ref(), &tag[..]);
+        assert_eq!(key.verify(nonce(), &input, &tag), Ok(()));
+
+        let mut modified = tag.clone();
+        modified[0] ^= 1;
+        assert!(key.verify(nonce(), &input, &modified).is_err());
+        assert!(key.verify(nonce(), &input, &tag[..15]).is_err());
+        if !input.is_empty() {
+            let mut modified = input.clone();
+            modified[input.len() - 1] ^= 0x80;
+            assert!(key.verify(nonce(), &modified, &tag).is_err());
+        }
+
+        Ok(())
+    });
+}
+
+// GMAC is AES-GCM with an empty plaintext.
+#[test]
+fn gmac_matches_aes_gcm() {
+    for (alg, aead_alg) in [
+        (&gmac::AES_128, &aead::AES_128_GCM),
+        (&gmac::AES_256, &aead::AES_256_GCM),
+    ] {
+        let key_bytes = &[0x42; 32][..alg.key_len()];
+        let key = gmac::Key::new(alg, key_bytes).unwrap();
+        let aead_key = aead::LessSafeKey::new(aead::UnboundKey::new(aead_alg, key_bytes).unwrap());
+        let data = [0x17; 100];
+        for len in 0..data.len() {
+            let nonce = || aead::Nonce::assume_unique_for_key([len as u8; 12]);
+            let expected = aead_key
+                .seal_in_place_separate_tag(nonce(), aead::Aad::from(&data[..len]), &mut [])
+                .unwrap();
+            assert_eq!(
+                key.sign(nonce(), &data[..len]).unwrap().as_ref(),
+                expected.as_ref()
+            );
+        }
+    }
+}
+
+#[test]
+fn gmac_lengths() {
+    for alg in [&gmac::AES_128, &gmac::AES_256] {
+        assert_eq!(alg.nonce_len(), 12);
+        assert_eq!(alg.tag_len(), 16);
+        let key_len = alg.key_len();
+        assert!(gmac::Key::new(alg, &[0; 33][..(key_len - 1)]).is_err());
+        assert!(gmac::Key::new(alg, &[0; 33][..(key_len + 1)]).is_err());
+        let key = gmac::Key::new(alg, &[0; 33][..key_len]).unwrap();
+
+        let nonce = aead::Nonce::try_assume_unique_for_key_any_len(&[0; 16]).unwrap();
+        assert!(key.sign(nonce, b"data").is_err());
+    }
+}
+
+#[test]
+fn gmac_key_debug() {
+    let key = gmac::Key::new(&gmac::AES_256, &[0; 32]).unwrap();
+    assert_eq!("Key { algorithm: AES_256 }", format!("{:?}", key));
+}
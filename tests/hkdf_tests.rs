@@ -12,7 +12,8 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use ring::{digest, error, hkdf, test, test_file};
+use core::convert::TryFrom;
+use ring::{aead, agreement, digest, error, hkdf, hmac, test, test_file};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
@@ -55,6 +56,36 @@ fn hkdf_tests() {
     });
 }
 
+#[test]
+fn hkdf_derive_tests() {
+    test::run(test_file!("hkdf_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let _ = test_case.consume_digest_alg("Hash");
+        let secret = test_case.consume_bytes("IKM");
+        let salt = test_case.consume_bytes("salt");
+        let info = test_case.consume_bytes("info");
+        let _ = test_case.consume_bytes("PRK");
+        let expected_out = test_case.consume_bytes("OKM");
+
+        // A shorter output is a prefix of a longer one.
+        let out: [u8; 82] = hkdf::derive(hkdf::HKDF_SHA256, &salt, &secret, &[&info]).unwrap();
+        assert_eq!(&out[..expected_out.len()], &expected_out[..]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn hkdf_derive_limits() {
+    const MAX_LEN: usize = 255 * 32;
+    let alg = hkdf::HKDF_SHA256;
+    assert!(hkdf::derive::<0>(alg, b"salt", b"secret", &[]).is_ok());
+    let max = hkdf::derive::<MAX_LEN>(alg, b"salt", b"secret", &[b"info"]).unwrap();
+    let short = hkdf::derive::<32>(alg, b"salt", b"secret", &[b"info"]).unwrap();
+    assert_eq!(&max[..32], &short[..]);
+    assert!(hkdf::derive::<{ MAX_LEN + 1 }>(alg, b"salt", b"secret", &[b"info"]).is_err());
+}
+
 #[test]
 fn hkdf_output_len_tests() {
     for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
@@ -107,6 +138,154 @@ fn hkdf_output_len_tests() {
     }
 }
 
+#[test]
+fn hkdf_expand_label_tests() {
+    test::run(
+        test_file!("hkdf_expand_label_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let secret = test_case.consume_bytes("Secret");
+            let label = test_case.consume_bytes("Label");
+            let context = test_case.consume_bytes("Context");
+            let expected_out = test_case.consume_bytes("Output");
+
+            let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &secret);
+            let My(out) = prk
+                .expand_label(&label, &context, My(expected_out.len()))
+                .unwrap()
+                .into();
+            assert_eq!(out, expected_out);
+
+            // HKDF-Expand-Label is HKDF-Expand with an encoded `info`.
+            let mut info = vec![];
+            info.extend_from_slice(&(expected_out.len() as u16).to_be_bytes());
+            info.push((6 + label.len()) as u8);
+            info.extend_from_slice(b"tls13 ");
+            info.extend_from_slice(&label);
+            info.push(context.len() as u8);
+            info.extend_from_slice(&context);
+            let My(out) = prk.expand(&[&info], My(expected_out.len())).unwrap().into();
+            assert_eq!(out, expected_out);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn hkdf_expand_label_limits() {
+    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &[0; 32]);
+    let max_out_len = 255 * 32;
+
+    assert!(prk.expand_label(b"key", b"", My(max_out_len)).is_ok());
+    assert!(prk.expand_label(b"key", b"", My(max_out_len + 1)).is_err());
+
+    assert!(prk.expand_label(b"", b"", My(16)).is_err());
+    assert!(prk.expand_label(&[b'a'; 249], b"", My(16)).is_ok());
+    assert!(prk.expand_label(&[b'a'; 250], b"", My(16)).is_err());
+
+    assert!(prk.expand_label(b"key", &[0; 255], My(16)).is_ok());
+    assert!(prk.expand_label(b"key", &[0; 256], My(16)).is_err());
+}
+
+#[test]
+fn hkdf_okm_into_aead_key() {
+    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &[1; 32]);
+    let info: &[&[u8]] = &[b"aead"];
+    let nonce = [0u8; aead::NONCE_LEN];
+
+    for &algorithm in &[
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let seal = |key: aead::UnboundKey| {
+            let key = aead::LessSafeKey::new(key);
+            let mut in_out = b"hello".to_vec();
+            let nonce = aead::Nonce::assume_unique_for_key(nonce);
+            key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+                .unwrap();
+            in_out
+        };
+
+        let key = prk.expand(info, My(algorithm.key_len())).unwrap();
+        let key = key.into_aead_key(algorithm).unwrap();
+        let expected = {
+            let My(bytes) = prk.expand(info, My(algorithm.key_len())).unwrap().into();
+            aead::UnboundKey::new(algorithm, &bytes).unwrap()
+        };
+        assert_eq!(seal(key), seal(expected));
+
+        let key = prk.expand(info, algorithm).unwrap();
+        assert!(key.into_aead_key(algorithm).is_ok());
+
+        let key = prk.expand(info, My(algorithm.key_len() + 1)).unwrap();
+        assert!(key.into_aead_key(algorithm).is_err());
+    }
+}
+
+#[test]
+fn hkdf_okm_into_hmac_key() {
+    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &[1; 32]);
+    let info: &[&[u8]] = &[b"hmac"];
+
+    for &algorithm in &[hmac::HMAC_SHA256, hmac::HMAC_SHA384, hmac::HMAC_SHA512] {
+        let len = algorithm.digest_algorithm().output_len;
+        let key = prk.expand(info, My(len)).unwrap();
+        let key = key.into_hmac_key(algorithm).unwrap();
+        let My(bytes) = prk.expand(info, My(len)).unwrap().into();
+        let expected = hmac::Key::new(algorithm, &bytes);
+        assert_eq!(
+            hmac::sign(&key, b"hello").as_ref(),
+            hmac::sign(&expected, b"hello").as_ref()
+        );
+
+        let key = prk.expand(info, My(len - 1)).unwrap();
+        assert!(key.into_hmac_key(algorithm).is_err());
+    }
+}
+
+#[test]
+fn hkdf_okm_into_agreement_private_key() {
+    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &[1; 32]);
+
+    for &algorithm in &[
+        &agreement::X25519,
+        &agreement::ECDH_P256,
+        &agreement::ECDH_P384,
+    ] {
+        let derive = |info: &[u8]| {
+            let info = [info];
+            let okm = prk.expand(&info, algorithm).unwrap();
+            let key = agreement::EphemeralPrivateKey::try_from(okm).unwrap();
+            assert_eq!(key.algorithm(), algorithm);
+            key
+        };
+
+        // The derivation is deterministic.
+        let a = derive(b"a").compute_public_key().unwrap();
+        assert_eq!(
+            a.as_ref(),
+            derive(b"a").compute_public_key().unwrap().as_ref()
+        );
+        let b = derive(b"b").compute_public_key().unwrap();
+        assert_ne!(a.as_ref(), b.as_ref());
+
+        // Both sides agree on the shared secret.
+        let agree = |private_key, public_key: &agreement::PublicKey| {
+            let public_key = agreement::UnparsedPublicKey::new(algorithm, public_key.as_ref());
+            agreement::agree_ephemeral(private_key, &public_key, |secret| secret.to_vec()).unwrap()
+        };
+        assert_eq!(agree(derive(b"a"), &b), agree(derive(b"b"), &a));
+
+        let len = hkdf::KeyType::len(&algorithm);
+        let okm = prk.expand(&[b"a"], My(len)).unwrap();
+        assert!(okm.into_agreement_private_key(algorithm).is_ok());
+        let okm = prk.expand(&[b"a"], My(len + 1)).unwrap();
+        assert!(okm.into_agreement_private_key(algorithm).is_err());
+    }
+}
+
 /// Generic newtype wrapper that lets us implement traits for externally-defined
 /// types.
 #[derive(Debug, PartialEq)]
@@ -36,6 +36,9 @@ fn hmac_tests() {
                     return Ok(());
                 } // Unsupported digest algorithm
             };
+            if digest_alg == &digest::SHA224 {
+                return Ok(()); // There is no HMAC-SHA224.
+            }
             if digest_alg == &digest::SHA1_FOR_LEGACY_USE_ONLY {
                 hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY
             } else if digest_alg == &digest::SHA256 {
@@ -44,6 +47,14 @@ fn hmac_tests() {
                 hmac::HMAC_SHA384
             } else if digest_alg == &digest::SHA512 {
                 hmac::HMAC_SHA512
+            } else if digest_alg == &digest::SHA512_256 {
+                hmac::HMAC_SHA512_256
+            } else if digest_alg == &digest::SHA3_256 {
+                hmac::HMAC_SHA3_256
+            } else if digest_alg == &digest::SHA3_384 {
+                hmac::HMAC_SHA3_384
+            } else if digest_alg == &digest::SHA3_512 {
+                hmac::HMAC_SHA3_512
             } else {
                 unreachable!()
             }
@@ -99,6 +110,18 @@ fn hmac_test_case_inner(
         let signature = ctx.sign();
         assert_eq!(is_ok, signature.as_ref() == output);
     }
+
+    // Multi-part API, reusing the context.
+    {
+        let mut ctx = hmac::Context::with_key(&key);
+        ctx.update(b"something else");
+        let _ = ctx.sign_and_reset();
+        for _ in 0..2 {
+            ctx.update(input);
+            let signature = ctx.sign_and_reset();
+            assert_eq!(is_ok, signature.as_ref() == output);
+        }
+    }
 }
 
 #[test]
@@ -109,3 +132,83 @@ fn hmac_debug() {
     let ctx = hmac::Context::with_key(&key);
     assert_eq!("Context { algorithm: SHA256 }", format!("{:?}", &ctx));
 }
+
+#[test]
+fn hmac_verify_truncated() {
+    // RFC 4231 Test Case 5. A 128-bit tag is less than half the output of
+    // SHA-384 and SHA-512, so only the SHA-256 one is long enough.
+    let key_value = [0x0c; 20];
+    let data = b"Test With Truncation";
+    for &(algorithm, tag, long_enough) in &[
+        (hmac::HMAC_SHA256, "a3b6167473100ee06e0c796c2955552b", true),
+        (hmac::HMAC_SHA384, "3abf34c3503b2a23a46efc619baef897", false),
+        (hmac::HMAC_SHA512, "415fad6271580a531d4179bc891d87a6", false),
+    ] {
+        let key = hmac::Key::new(algorithm, &key_value);
+        let tag = test::from_hex(tag).unwrap();
+        assert_eq!(
+            hmac::verify_truncated(&key, data, &tag).is_ok(),
+            long_enough
+        );
+
+        let mut wrong = tag.clone();
+        wrong[15] ^= 1;
+        assert!(hmac::verify_truncated(&key, data, &wrong).is_err());
+        assert!(hmac::verify_truncated(&key, b"Test With Truncatio", &tag).is_err());
+
+        // Every length from the minimum to the full tag is accepted.
+        let full = hmac::sign(&key, data);
+        let full = full.as_ref();
+        let min = algorithm.min_truncated_tag_len();
+        assert_eq!(
+            min,
+            core::cmp::max(hmac::MIN_TRUNCATED_TAG_LEN, full.len() / 2)
+        );
+        for len in 0..=full.len() + 1 {
+            let mut tag = full.to_vec();
+            tag.resize(len, 0);
+            let result = hmac::verify_truncated(&key, data, &tag);
+            assert_eq!(result.is_ok(), len >= min && len <= full.len());
+        }
+    }
+
+    // 80 bits is the floor for short digests.
+    assert_eq!(
+        hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY.min_truncated_tag_len(),
+        hmac::MIN_TRUNCATED_TAG_LEN
+    );
+}
+
+#[test]
+fn hmac_sign_slices() {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+    let data = b"header and payload";
+    let expected = hmac::sign(&key, data);
+
+    for split in 0..=data.len() {
+        let (a, b) = data.split_at(split);
+        for parts in &[&[a, b][..], &[a, &[], b][..], &[&[], a, b, &[]][..]] {
+            let tag = hmac::sign_slices(&key, parts);
+            assert_eq!(tag.as_ref(), expected.as_ref());
+        }
+    }
+
+    let empty = hmac::sign(&key, b"");
+    assert_eq!(hmac::sign_slices(&key, &[]).as_ref(), empty.as_ref());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hmac_write() {
+    use std::io::Write;
+
+    let key = hmac::Key::new(hmac::HMAC_SHA384, b"key");
+    let data = [0x5a; 1000];
+
+    let mut ctx = hmac::Context::with_key(&key);
+    for chunk in data.chunks(7) {
+        ctx.write_all(chunk).unwrap();
+    }
+    ctx.flush().unwrap();
+    assert_eq!(ctx.sign().as_ref(), hmac::sign(&key, &data).as_ref());
+}
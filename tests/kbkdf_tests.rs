@@ -0,0 +1,110 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+use ring::{cmac, hmac, kbkdf, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn kbkdf_tests() {
+    test::run(test_file!("kbkdf_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let prf = test_case.consume_string("PRF");
+        let key_value = test_case.consume_bytes("Key");
+        let counter_bits = test_case.consume_usize("CounterBits");
+        let length_bits = test_case.consume_usize("LengthBits");
+        let separator = test_case.consume_bool("Separator");
+        let label = test_case.consume_bytes("Label");
+        let context = test_case.consume_bytes("Context");
+        let expected = test_case.consume_bytes("Output");
+
+        let encoding = kbkdf::Encoding::new(counter_bits, length_bits, separator).unwrap();
+
+        let hmac_key;
+        let cmac_key;
+        let prf = match prf.as_ref() {
+            "HMAC_SHA256" | "HMAC_SHA384" | "HMAC_SHA512" => {
+                let algorithm = match prf.as_ref() {
+                    "HMAC_SHA256" => hmac::HMAC_SHA256,
+                    "HMAC_SHA384" => hmac::HMAC_SHA384,
+                    _ => hmac::HMAC_SHA512,
+                };
+                hmac_key = hmac::Key::new(algorithm, &key_value);
+                kbkdf::Prf::Hmac(&hmac_key)
+            }
+            "AES_128_CMAC" | "AES_256_CMAC" => {
+                let algorithm = if prf == "AES_128_CMAC" {
+                    &cmac::AES_128
+                } else {
+                    &cmac::AES_256
+                };
+                cmac_key = cmac::Key::new(algorithm, &key_value).unwrap();
+                kbkdf::Prf::Cmac(&cmac_key)
+            }
+            name => panic!("Unsupported PRF: {}", name),
+        };
+
+        let mut actual = vec![0u8; expected.len()];
+        kbkdf::derive(prf, &encoding, &label, &context, &mut actual).unwrap();
+        assert_eq!(actual, expected);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn kbkdf_encoding_new() {
+    for &counter_bits in &[8, 16, 24, 32] {
+        for &length_bits in &[0, 8, 16, 24, 32] {
+            assert!(kbkdf::Encoding::new(counter_bits, length_bits, true).is_ok());
+        }
+        assert!(kbkdf::Encoding::new(counter_bits, 64, true).is_err());
+        assert!(kbkdf::Encoding::new(counter_bits, 7, true).is_err());
+    }
+    assert!(kbkdf::Encoding::new(0, 32, true).is_err());
+    assert!(kbkdf::Encoding::new(64, 32, true).is_err());
+    assert_eq!(
+        kbkdf::Encoding::new(32, 32, true).unwrap(),
+        kbkdf::Encoding::DEFAULT
+    );
+}
+
+#[test]
+fn kbkdf_limits() {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, &[0; 32]);
+    let prf = kbkdf::Prf::Hmac(&key);
+
+    // An 8-bit counter counts up to 255 blocks.
+    let encoding = kbkdf::Encoding::new(8, 0, true).unwrap();
+    let mut out = vec![0u8; 255 * 32];
+    assert!(kbkdf::derive(prf, &encoding, b"", b"", &mut out).is_ok());
+    let mut out = vec![0u8; 255 * 32 + 1];
+    assert!(kbkdf::derive(prf, &encoding, b"", b"", &mut out).is_err());
+
+    // An 8-bit length counts up to 255 bits.
+    let encoding = kbkdf::Encoding::new(32, 8, true).unwrap();
+    let mut out = [0u8; 31];
+    assert!(kbkdf::derive(prf, &encoding, b"", b"", &mut out).is_ok());
+    let mut out = [0u8; 32];
+    assert!(kbkdf::derive(prf, &encoding, b"", b"", &mut out).is_err());
+
+    let mut out = [];
+    assert!(kbkdf::derive(prf, &kbkdf::Encoding::DEFAULT, b"", b"", &mut out).is_ok());
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{cipher, cmac, kcv, test};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// The keys are the AES-128 and AES-256 keys of the examples in RFC 4493 and
+// NIST SP 800-38B; the expected values were computed with another
+// implementation.
+const AES_128_KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+const AES_256_KEY: &str = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+
+#[test]
+fn kcv_aes() {
+    for &(alg, key, expected) in &[
+        (
+            &cipher::aes::AES_128,
+            AES_128_KEY,
+            "7df76b0c1ab899b33e42f047b91b546f",
+        ),
+        (
+            &cipher::aes::AES_256,
+            AES_256_KEY,
+            "e568f68194cf76d6174d4cc04310a854",
+        ),
+    ] {
+        let key = cipher::aes::Key::new(alg, &test::from_hex(key).unwrap()).unwrap();
+        let expected = test::from_hex(expected).unwrap();
+        for len in 1..=kcv::MAX_LEN {
+            assert_eq!(kcv::aes(&key, len).unwrap().as_ref(), &expected[..len]);
+        }
+        assert!(kcv::aes(&key, 0).is_err());
+        assert!(kcv::aes(&key, kcv::MAX_LEN + 1).is_err());
+    }
+}
+
+#[test]
+fn kcv_cmac() {
+    for &(alg, key, expected) in &[
+        (
+            &cmac::AES_128,
+            AES_128_KEY,
+            "7ad386c3760fb3498361a1cb5563bd70",
+        ),
+        (
+            &cmac::AES_256,
+            AES_256_KEY,
+            "1a0b2df26763cfd6afb9d3b1a2a846da",
+        ),
+    ] {
+        let key = cmac::Key::new(alg, &test::from_hex(key).unwrap()).unwrap();
+        let expected = test::from_hex(expected).unwrap();
+        for len in 1..=kcv::MAX_LEN {
+            assert_eq!(kcv::cmac(&key, len).unwrap().as_ref(), &expected[..len]);
+        }
+        assert!(kcv::cmac(&key, 0).is_err());
+        assert!(kcv::cmac(&key, kcv::MAX_LEN + 1).is_err());
+    }
+}
+
+#[test]
+fn kcv_check_value_eq_and_debug() {
+    let key = cmac::Key::new(&cmac::AES_128, &test::from_hex(AES_128_KEY).unwrap()).unwrap();
+    let a = kcv::cmac(&key, kcv::DEFAULT_CMAC_LEN).unwrap();
+    let b = kcv::cmac(&key, kcv::DEFAULT_CMAC_LEN).unwrap();
+    let c = kcv::cmac(&key, kcv::DEFAULT_CMAC_LEN + 1).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(format!("{:?}", a), "CheckValue(\"7ad386c376\")");
+}
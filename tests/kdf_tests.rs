@@ -0,0 +1,173 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use core::num::NonZeroU32;
+use ring::{aead, cmac, hkdf, hmac, kbkdf, kdf::Kdf, pbkdf2};
+
+#[cfg(feature = "alloc")]
+use ring::{argon2, scrypt};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SECRET: &[u8] = b"secret";
+const SALT: &[u8] = b"salt and pepper";
+const INFO: &[u8] = b"info";
+
+fn derive(kdf: &dyn Kdf, secret: &[u8], salt: &[u8], info: &[u8], len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; len];
+    kdf.derive(secret, salt, info, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn kdf_hkdf() {
+    for &algorithm in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+        let prk = hkdf::Salt::new(algorithm, SALT).extract(SECRET);
+        for &len in &[1, 32, 100] {
+            let mut expected = vec![0u8; len];
+            prk.expand(&[INFO], My(len))
+                .unwrap()
+                .fill(&mut expected)
+                .unwrap();
+            assert_eq!(derive(&algorithm, SECRET, SALT, INFO, len), expected);
+        }
+
+        let max_len = 255 * algorithm.hmac_algorithm().digest_algorithm().output_len;
+        let mut out = vec![0u8; max_len + 1];
+        assert!(algorithm.derive(SECRET, SALT, INFO, &mut out).is_err());
+        assert!(algorithm
+            .derive(SECRET, SALT, INFO, &mut out[..max_len])
+            .is_ok());
+    }
+}
+
+#[test]
+fn kdf_kbkdf() {
+    let params = kbkdf::Params::hmac(hmac::HMAC_SHA256, kbkdf::Encoding::DEFAULT);
+    let key = hmac::Key::new(hmac::HMAC_SHA256, SECRET);
+    let mut expected = [0u8; 40];
+    kbkdf::derive(
+        kbkdf::Prf::Hmac(&key),
+        &kbkdf::Encoding::DEFAULT,
+        INFO,
+        SALT,
+        &mut expected,
+    )
+    .unwrap();
+    assert_eq!(derive(&params, SECRET, SALT, INFO, 40), expected);
+
+    let encoding = kbkdf::Encoding::new(8, 0, false).unwrap();
+    let params = kbkdf::Params::cmac(&cmac::AES_128, encoding);
+    let key = cmac::Key::new(&cmac::AES_128, &[7; 16]).unwrap();
+    let mut expected = [0u8; 40];
+    kbkdf::derive(kbkdf::Prf::Cmac(&key), &encoding, INFO, SALT, &mut expected).unwrap();
+    assert_eq!(derive(&params, &[7; 16], SALT, INFO, 40), expected);
+
+    // The secret must be an AES key.
+    let mut out = [0u8; 16];
+    assert!(params.derive(&[7; 15], SALT, INFO, &mut out).is_err());
+    // The counter can't count more than 255 blocks.
+    let mut out = vec![0u8; 255 * 16 + 1];
+    assert!(params.derive(&[7; 16], SALT, INFO, &mut out).is_err());
+}
+
+#[test]
+fn kdf_pbkdf2() {
+    let iterations = NonZeroU32::new(100).unwrap();
+    let params = pbkdf2::Params::new(pbkdf2::PBKDF2_HMAC_SHA256, iterations);
+    let mut expected = [0u8; 50];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        SALT,
+        SECRET,
+        &mut expected,
+    );
+    assert_eq!(derive(&params, SECRET, SALT, b"", 50), expected);
+
+    // PBKDF2 has nothing to bind the info to.
+    let mut out = [0u8; 50];
+    assert!(params.derive(SECRET, SALT, INFO, &mut out).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn kdf_scrypt() {
+    let params = scrypt::Params::new(4, 1, 1).unwrap();
+    let mut expected = [0u8; 40];
+    scrypt::derive(&params, SALT, SECRET, &mut expected);
+    assert_eq!(derive(&params, SECRET, SALT, b"", 40), expected);
+
+    let mut out = [0u8; 40];
+    assert!(params.derive(SECRET, SALT, INFO, &mut out).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn kdf_argon2() {
+    let params = argon2::Params::new(8, 1, 1).unwrap();
+    let mut expected = [0u8; 40];
+    argon2::derive_keyed(&params, b"", INFO, SALT, SECRET, &mut expected).unwrap();
+    assert_eq!(derive(&params, SECRET, SALT, INFO, 40), expected);
+
+    // The salt is too short.
+    let mut out = [0u8; 40];
+    assert!(params.derive(SECRET, b"salt", INFO, &mut out).is_err());
+}
+
+#[test]
+fn kdf_derive_keys() {
+    let kbkdf = kbkdf::Params::hmac(hmac::HMAC_SHA384, kbkdf::Encoding::DEFAULT);
+    let kdfs: [&dyn Kdf; 2] = [&hkdf::HKDF_SHA256, &kbkdf];
+    for kdf in kdfs.iter() {
+        for &algorithm in &[&aead::AES_128_GCM, &aead::CHACHA20_POLY1305] {
+            let key_bytes = derive(*kdf, SECRET, SALT, INFO, algorithm.key_len());
+            let expected = aead::UnboundKey::new(algorithm, &key_bytes).unwrap();
+            let key = kdf.derive_aead_key(SECRET, SALT, INFO, algorithm).unwrap();
+            assert_eq!(seal(key), seal(expected));
+        }
+
+        for &algorithm in &[hmac::HMAC_SHA256, hmac::HMAC_SHA512] {
+            let len = algorithm.digest_algorithm().output_len;
+            let key_bytes = derive(*kdf, SECRET, SALT, INFO, len);
+            let expected = hmac::Key::new(algorithm, &key_bytes);
+            let key = kdf.derive_hmac_key(SECRET, SALT, INFO, algorithm).unwrap();
+            assert_eq!(
+                hmac::sign(&key, b"data").as_ref(),
+                hmac::sign(&expected, b"data").as_ref()
+            );
+        }
+    }
+}
+
+fn seal(key: aead::UnboundKey) -> Vec<u8> {
+    let key = aead::LessSafeKey::new(key);
+    let nonce = aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+    let mut in_out = b"hello".to_vec();
+    key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+        .unwrap();
+    in_out
+}
+
+struct My(usize);
+
+impl hkdf::KeyType for My {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{key_wrap, test, test_file};
+
+#[test]
+fn key_wrap_aes_kw() {
+    test_key_wrap(
+        &key_wrap::AES_128_KW,
+        &key_wrap::AES_256_KW,
+        test_file!("key_wrap_aes_kw_tests.txt"),
+    );
+}
+
+#[test]
+fn key_wrap_aes_kwp() {
+    test_key_wrap(
+        &key_wrap::AES_128_KWP,
+        &key_wrap::AES_256_KWP,
+        test_file!("key_wrap_aes_kwp_tests.txt"),
+    );
+}
+
+fn test_key_wrap(
+    aes_128: &'static key_wrap::Algorithm,
+    aes_256: &'static key_wrap::Algorithm,
+    test_file: test::File,
+) {
+    test::run(test_file, |section, test_case| {
+        assert_eq!(section, "");
+        let kek = test_case.consume_bytes("KEK");
+        let input = test_case.consume_bytes("IN");
+        let output = test_case.consume_bytes("OUT");
+
+        let alg = match kek.len() {
+            16 => aes_128,
+            32 => aes_256,
+            _ => unreachable!(),
+        };
+        let key = key_wrap::Key::new(alg, &kek)?;
+        assert_eq!(alg.wrapped_len(input.len()), Ok(output.len()));
+
+        let mut wrapped = vec![0u8; output.len()];
+        assert_eq!(key.wrap(&input, &mut wrapped)?, &output[..]);
+        assert!(key.wrap(&input, &mut wrapped[1..]).is_err());
+
+        let mut unwrapped = vec![0u8; output.len() - key_wrap::SEMIBLOCK_LEN];
+        assert_eq!(key.unwrap(&output, &mut unwrapped)?, &input[..]);
+
+        // Every bit of the wrapped key is covered by the integrity check.
+        for i in 0..output.len() {
+            for bit in 0..8 {
+                let mut modified = output.clone();
+                modified[i] ^= 1 << bit;
+                assert!(key.unwrap(&modified, &mut unwrapped).is_err());
+                assert!(unwrapped.iter().all(|&b| b == 0));
+            }
+        }
+        assert!(key.unwrap(&output[1..], &mut unwrapped).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn key_wrap_aes_kw_lengths() {
+    let key = key_wrap::Key::new(&key_wrap::AES_128_KW, &[0; 16]).unwrap();
+    let mut out = [0u8; 64];
+    assert!(key.wrap(&[], &mut out).is_err());
+    assert!(key.wrap(&[0; 8], &mut out).is_err());
+    assert!(key.wrap(&[0; 17], &mut out).is_err());
+    assert!(key.wrap(&[0; 16], &mut out).is_ok());
+    assert!(key.unwrap(&[0; 16], &mut out).is_err());
+}
+
+#[test]
+fn key_wrap_aes_kwp_lengths() {
+    let key = key_wrap::Key::new(&key_wrap::AES_128_KWP, &[0; 16]).unwrap();
+    let mut out = [0u8; 64];
+    assert!(key.wrap(&[], &mut out).is_err());
+    for len in 1..=32 {
+        let input = vec![0x5a; len];
+        let wrapped = key.wrap(&input, &mut out).unwrap().to_vec();
+        assert_eq!(wrapped.len(), (len - 1) / 8 * 8 + 16);
+        let mut unwrapped = [0u8; 64];
+        assert_eq!(key.unwrap(&wrapped, &mut unwrapped).unwrap(), &input[..]);
+    }
+
+    // A KWP-wrapped key doesn't unwrap as a KW-wrapped key, or vice versa.
+    let kw_key = key_wrap::Key::new(&key_wrap::AES_128_KW, &[0; 16]).unwrap();
+    let wrapped = key.wrap(&[0x5a; 16], &mut out).unwrap().to_vec();
+    assert!(kw_key.unwrap(&wrapped, &mut [0u8; 64]).is_err());
+    let wrapped = kw_key.wrap(&[0x5a; 16], &mut out).unwrap().to_vec();
+    assert!(key.unwrap(&wrapped, &mut [0u8; 64]).is_err());
+}
+
+#[test]
+fn key_wrap_key_len() {
+    assert!(key_wrap::Key::new(&key_wrap::AES_128_KW, &[0; 32]).is_err());
+    assert!(key_wrap::Key::new(&key_wrap::AES_256_KWP, &[0; 16]).is_err());
+}
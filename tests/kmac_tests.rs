@@ -0,0 +1,110 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{kmac, test, test_file};
+
+#[test]
+fn kmac_tests() {
+    test::run(test_file!("kmac_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let algorithm = match test_case.consume_string("KMAC").as_ref() {
+            "KMAC128" => kmac::KMAC128,
+            "KMAC256" => kmac::KMAC256,
+            name => panic!("Unsupported KMAC: {}", name),
+        };
+        let key_value = test_case.consume_bytes("Key");
+        let customization = test_case.consume_bytes("Customization");
+        let mut input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+
+        let key = kmac::Key::with_customization(algorithm, &key_value, &customization);
+        assert_eq!(key.algorithm(), algorithm);
+
+        let mut actual = vec![0u8; expected.len()];
+        kmac::sign(&key, &input, &mut actual);
+        assert_eq!(&expected, &actual);
+
+        let mut ctx = kmac::Context::with_key(&key);
+        for chunk in input.chunks(5) {
+            ctx.update(chunk);
+        }
+        let mut actual = vec![0u8; expected.len()];
+        ctx.sign(&mut actual);
+        assert_eq!(&expected, &actual);
+
+        let verify_result = kmac::verify(&key, &input, &expected);
+        if expected.len() <= kmac::MAX_TAG_LEN {
+            assert!(verify_result.is_ok());
+        } else {
+            assert!(verify_result.is_err());
+        }
+
+        // A truncated tag isn't a valid tag.
+        assert!(kmac::verify(&key, &input, &expected[..(expected.len() - 1)]).is_err());
+
+        // Tamper with the input and check that verification fails.
+        if input.is_empty() {
+            input.push(0);
+        } else {
+            input[0] ^= 1;
+        }
+        assert!(kmac::verify(&key, &input, &expected).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn kmac_customization_and_key_new() {
+    let key = kmac::Key::new(kmac::KMAC128, b"key");
+    let customized = kmac::Key::with_customization(kmac::KMAC128, b"key", b"");
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    kmac::sign(&key, b"data", &mut a);
+    kmac::sign(&customized, b"data", &mut b);
+    assert_eq!(a, b);
+
+    let customized = kmac::Key::with_customization(kmac::KMAC128, b"key", b"other");
+    kmac::sign(&customized, b"data", &mut b);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn kmac_verify_tag_len() {
+    let key = kmac::Key::new(kmac::KMAC256, b"key");
+    for len in 0..kmac::MIN_TAG_LEN {
+        let mut tag = vec![0u8; len];
+        kmac::sign(&key, b"data", &mut tag);
+        assert!(kmac::verify(&key, b"data", &tag).is_err());
+    }
+    let mut tag = [0u8; kmac::MIN_TAG_LEN];
+    kmac::sign(&key, b"data", &mut tag);
+    assert!(kmac::verify(&key, b"data", &tag).is_ok());
+}
+
+#[test]
+fn kmac_debug() {
+    let key = kmac::Key::new(kmac::KMAC128, b"key");
+    assert_eq!("Key { algorithm: SHAKE128 }", format!("{:?}", key));
+    let ctx = kmac::Context::with_key(&key);
+    assert_eq!("Context { algorithm: SHAKE128 }", format!("{:?}", ctx));
+}
@@ -0,0 +1,175 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, minisign, signature, signature::KeyPair};
+
+static PUBLIC_KEY: &[u8] = include_bytes!("minisign_test.pub");
+static PREHASHED: &[u8] = include_bytes!("minisign_test_prehashed.minisig");
+static LEGACY: &[u8] = include_bytes!("minisign_test_legacy.minisig");
+static SIGNIFY: &[u8] = include_bytes!("signify_test.sig");
+static MSG: &[u8] = b"ring\n";
+static TRUSTED_COMMENT: &[u8] = b"timestamp:1700000000\tfile:ring.txt";
+
+fn public_key() -> minisign::PublicKey {
+    minisign::PublicKey::from_text(PUBLIC_KEY).unwrap()
+}
+
+#[test]
+fn test_minisign_public_key() {
+    let public_key = public_key();
+    assert_eq!(
+        public_key.key_id(),
+        &[0x1a, 0x1a, 0x5b, 0xc6, 0xa1, 0xb6, 0xc0, 0x3f]
+    );
+    let seed = (0..32).collect::<Vec<u8>>();
+    let key_pair = signature::Ed25519KeyPair::from_seed_unchecked(&seed).unwrap();
+    assert_eq!(
+        public_key.ed25519_public_key(),
+        key_pair.public_key().as_ref()
+    );
+
+    let encoded = b"RWQaGlvGobbAPwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4";
+    let from_base64 = minisign::PublicKey::from_base64(encoded).unwrap();
+    assert_eq!(from_base64.key_id(), public_key.key_id());
+    assert_eq!(
+        from_base64.ed25519_public_key(),
+        public_key.ed25519_public_key()
+    );
+
+    // Not an Ed25519 key.
+    assert!(minisign::PublicKey::from_base64(
+        b"RVQaGlvGobbAPwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4"
+    )
+    .is_err());
+    // Wrong lengths.
+    assert!(minisign::PublicKey::from_base64(&encoded[..52]).is_err());
+    assert!(minisign::PublicKey::from_base64(
+        b"RWQaGlvGobbAPwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4AAAA"
+    )
+    .is_err());
+    // Not base64.
+    assert!(minisign::PublicKey::from_base64(
+        b"RWQaGlvGobbAPwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG-"
+    )
+    .is_err());
+
+    // The untrusted comment is required, but its contents don't matter.
+    let text = [b"untrusted comment: \n", &encoded[..], b"\n"].concat();
+    assert!(minisign::PublicKey::from_text(&text).is_ok());
+    let text = [b"untrusted comment: anything\r\n", &encoded[..]].concat();
+    assert!(minisign::PublicKey::from_text(&text).is_ok());
+    let text = [b"comment: anything\n", &encoded[..], b"\n"].concat();
+    assert!(minisign::PublicKey::from_text(&text).is_err());
+    assert!(minisign::PublicKey::from_text(encoded).is_err());
+    let text = [PUBLIC_KEY, b"\n"].concat();
+    assert!(minisign::PublicKey::from_text(&text).is_err());
+}
+
+#[test]
+fn test_minisign_verify() {
+    let public_key = public_key();
+    for signature in [PREHASHED, LEGACY].iter() {
+        assert_eq!(
+            minisign::verify(&public_key, MSG, signature).unwrap(),
+            TRUSTED_COMMENT
+        );
+        assert!(minisign::verify(&public_key, b"ring", signature).is_err());
+
+        let text = String::from_utf8(signature.to_vec()).unwrap();
+
+        // Line endings.
+        let crlf = text.replace('\n', "\r\n");
+        assert!(minisign::verify(&public_key, MSG, crlf.as_bytes()).is_ok());
+        let no_final_newline = text.trim_end();
+        assert!(minisign::verify(&public_key, MSG, no_final_newline.as_bytes()).is_ok());
+        let extra_line = text.clone() + "\n";
+        assert!(minisign::verify(&public_key, MSG, extra_line.as_bytes()).is_err());
+
+        // The untrusted comment isn't signed.
+        let untrusted = text.replace(
+            "untrusted comment: signature from minisign secret key",
+            "untrusted comment: anything",
+        );
+        assert!(minisign::verify(&public_key, MSG, untrusted.as_bytes()).is_ok());
+
+        // The trusted comment is.
+        let trusted = text.replace("1700000000", "1700000001");
+        assert_eq!(
+            minisign::verify(&public_key, MSG, trusted.as_bytes()),
+            Err(error::Unspecified)
+        );
+        let missing_prefix = text.replace("trusted comment: ", "trusted comment:");
+        assert!(minisign::verify(&public_key, MSG, missing_prefix.as_bytes()).is_err());
+
+        // A signify signature file isn't a minisign signature file.
+        assert!(minisign::verify_signify(&public_key, MSG, signature).is_err());
+    }
+}
+
+#[test]
+fn test_minisign_verify_wrong_key() {
+    let other = minisign::PublicKey::from_base64(
+        b"RWQBAgMEBQYHCHm1Vi6P5lT5QHixEuipi6eQH4U65pW+1+DjkQutBJZk",
+    )
+    .unwrap();
+    assert!(minisign::verify(&other, MSG, PREHASHED).is_err());
+    assert!(minisign::verify_signify(&other, MSG, SIGNIFY).is_err());
+
+    // The key ID of `public_key()`, but a signature by `other`.
+    let signature = b"untrusted comment: signature from minisign secret key
+RUQaGlvGobbAP2+i9IkEAPvEPsCv0gLIZVQJSq4XkjQ7IppqTpp14wThwQ70INP4J4eyF4gXXXR+hCdxZ1rEgIO5tUtqcietrgI=
+trusted comment: x
+Nz0/V2Q7lwWd62KOVRgB+sIRSeboTbl9SGn0iDWVkdpMkAEghoHAD8nzyj9ajFwmYG0mMR8+bATIYbG1ykQ/AQ==
+";
+    assert!(minisign::verify(&public_key(), MSG, signature).is_err());
+}
+
+#[test]
+fn test_minisign_verify_non_canonical_base64() {
+    let public_key = public_key();
+    let text = String::from_utf8(PREHASHED.to_vec()).unwrap();
+    // The last character before the padding encodes two unused bits, which
+    // must be zero.
+    let non_canonical = text.replace("ywU=", "ywV=");
+    assert_ne!(non_canonical, text);
+    assert!(minisign::verify(&public_key, MSG, non_canonical.as_bytes()).is_err());
+    let unpadded = text.replace("ywU=", "ywU");
+    assert!(minisign::verify(&public_key, MSG, unpadded.as_bytes()).is_err());
+}
+
+#[test]
+fn test_signify_verify() {
+    let public_key = public_key();
+    assert!(minisign::verify_signify(&public_key, MSG, SIGNIFY).is_ok());
+    assert!(minisign::verify_signify(&public_key, b"ring", SIGNIFY).is_err());
+
+    let text = String::from_utf8(SIGNIFY.to_vec()).unwrap();
+    let comment = text.replace("verify with key.pub", "anything");
+    assert!(minisign::verify_signify(&public_key, MSG, comment.as_bytes()).is_ok());
+    let embedded = text.clone() + "ring\n";
+    assert!(minisign::verify_signify(&public_key, MSG, embedded.as_bytes()).is_err());
+
+    // A minisign signature file isn't a signify signature file.
+    assert!(minisign::verify(&public_key, MSG, SIGNIFY).is_err());
+    // A legacy minisign signature line is a signify signature line, but not
+    // a prehashed one.
+    for (minisig, ok) in [(LEGACY, true), (PREHASHED, false)].iter() {
+        let lines = minisig.split(|&b| b == b'\n').take(2).collect::<Vec<_>>();
+        let signify = lines.join(&b'\n');
+        assert_eq!(
+            minisign::verify_signify(&public_key, MSG, &signify).is_ok(),
+            *ok
+        );
+    }
+}
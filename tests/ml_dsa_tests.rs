@@ -0,0 +1,247 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{
+    error, rand,
+    signature::{self, KeyPair, MlDsaKeyPair},
+    test, test_file,
+};
+
+fn algorithms(
+    name: &str,
+) -> (
+    &'static signature::MlDsaSigningAlgorithm,
+    &'static signature::MlDsaVerificationAlgorithm,
+) {
+    match name {
+        "ML-DSA-44" => (&signature::ML_DSA_44_SIGNING, &signature::ML_DSA_44),
+        "ML-DSA-65" => (&signature::ML_DSA_65_SIGNING, &signature::ML_DSA_65),
+        "ML-DSA-87" => (&signature::ML_DSA_87_SIGNING, &signature::ML_DSA_87),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn ml_dsa_test() {
+    test::run(test_file!("ml_dsa_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let (signing_alg, verification_alg) = algorithms(&test_case.consume_string("Algorithm"));
+        let seed = test_case.consume_bytes("Seed");
+        let msg = test_case.consume_bytes("Msg");
+        let context = test_case.consume_bytes("Context");
+        let public_key = test_case.consume_bytes("PublicKey");
+        let expected_sig = test_case.consume_bytes("Sig");
+
+        let key_pair = MlDsaKeyPair::from_seed(signing_alg, &seed).unwrap();
+        assert_eq!(public_key, key_pair.public_key().as_ref());
+
+        // The test vectors use the deterministic variant, where the
+        // randomness is all zeros.
+        let rng = test::rand::FixedSliceRandom { bytes: &[0; 32] };
+        let mut actual_sig = vec![0; signing_alg.signature_len()];
+        key_pair
+            .sign_with_context(&rng, &context, &msg, &mut actual_sig)
+            .unwrap();
+        assert_eq!(expected_sig, actual_sig);
+
+        assert_eq!(
+            verification_alg.verify_with_context(&public_key, &context, &msg, &expected_sig),
+            Ok(())
+        );
+        if context.is_empty() {
+            let public_key = signature::UnparsedPublicKey::new(verification_alg, &public_key);
+            assert_eq!(public_key.verify(&msg, &expected_sig), Ok(()));
+        }
+
+        let mut tampered_sig = expected_sig.clone();
+        tampered_sig[0] ^= 1;
+        assert_eq!(
+            verification_alg.verify_with_context(&public_key, &context, &msg, &tampered_sig),
+            Err(error::Unspecified)
+        );
+
+        let mut tampered_msg = msg.clone();
+        tampered_msg.push(0);
+        assert_eq!(
+            verification_alg.verify_with_context(
+                &public_key,
+                &context,
+                &tampered_msg,
+                &expected_sig
+            ),
+            Err(error::Unspecified)
+        );
+
+        let mut tampered_context = context.clone();
+        tampered_context.push(0);
+        assert_eq!(
+            verification_alg.verify_with_context(
+                &public_key,
+                &tampered_context,
+                &msg,
+                &expected_sig
+            ),
+            Err(error::Unspecified)
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn ml_dsa_from_pkcs8_test() {
+    test::run(
+        test_file!("ml_dsa_from_pkcs8_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let (signing_alg, _) = algorithms(&test_case.consume_string("Algorithm"));
+            let input = test_case.consume_bytes("Input");
+            let error = test_case.consume_optional_string("Error");
+
+            match (MlDsaKeyPair::from_pkcs8(signing_alg, &input), error) {
+                (Ok(key_pair), None) => assert_eq!(
+                    test_case.consume_bytes("PublicKey"),
+                    key_pair.public_key().as_ref()
+                ),
+                (Err(e), None) => panic!("Failed with error \"{}\", but expected to succeed", e),
+                (Ok(_), Some(e)) => panic!("Succeeded, but expected error \"{}\"", e),
+                (Err(actual), Some(expected)) => assert_eq!(format!("{}", actual), expected),
+            };
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn ml_dsa_spki_test() {
+    test::run(test_file!("ml_dsa_spki_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let (signing_alg, verification_alg) = algorithms(&test_case.consume_string("Algorithm"));
+        let spki = test_case.consume_bytes("SPKI");
+        let public_key = test_case.consume_bytes("PublicKey");
+
+        assert_eq!(
+            verification_alg.public_key_from_spki(&spki),
+            Ok(&public_key[..])
+        );
+        for other in [
+            &signature::ML_DSA_44,
+            &signature::ML_DSA_65,
+            &signature::ML_DSA_87,
+        ]
+        .iter()
+        {
+            if !core::ptr::eq(*other, verification_alg) {
+                assert_eq!(other.public_key_from_spki(&spki), Err(error::Unspecified));
+            }
+        }
+        assert_eq!(
+            verification_alg.public_key_from_spki(&spki[..spki.len() - 1]),
+            Err(error::Unspecified)
+        );
+
+        let rng = rand::SystemRandom::new();
+        let pkcs8 = MlDsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair = MlDsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+        let generated_spki = key_pair.public_key().subject_public_key_info();
+        assert_eq!(generated_spki.len(), spki.len());
+        assert_eq!(
+            verification_alg.public_key_from_spki(&generated_spki),
+            Ok(key_pair.public_key().as_ref())
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn ml_dsa_sign_verify_test() {
+    let rng = rand::SystemRandom::new();
+    for (signing_alg, verification_alg) in [
+        (&signature::ML_DSA_44_SIGNING, &signature::ML_DSA_44),
+        (&signature::ML_DSA_65_SIGNING, &signature::ML_DSA_65),
+        (&signature::ML_DSA_87_SIGNING, &signature::ML_DSA_87),
+    ]
+    .iter()
+    {
+        let pkcs8 = MlDsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair = MlDsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let msg = b"hello, world";
+
+        let mut sig = vec![0; signing_alg.signature_len()];
+        key_pair.sign(&rng, msg, &mut sig).unwrap();
+        let unparsed = signature::UnparsedPublicKey::new(*verification_alg, public_key);
+        assert_eq!(unparsed.verify(msg, &sig), Ok(()));
+
+        // Hedged signatures differ each time but all verify.
+        let mut sig2 = vec![0; signing_alg.signature_len()];
+        key_pair
+            .sign_with_context(&rng, b"context", msg, &mut sig2)
+            .unwrap();
+        assert_ne!(sig, sig2);
+        assert_eq!(
+            verification_alg.verify_with_context(public_key, b"context", msg, &sig2),
+            Ok(())
+        );
+        assert_eq!(unparsed.verify(msg, &sig2), Err(error::Unspecified));
+
+        // The context is limited to 255 bytes.
+        let context = [0u8; 256];
+        assert_eq!(
+            key_pair.sign_with_context(&rng, &context, msg, &mut sig2),
+            Err(error::Unspecified)
+        );
+        assert_eq!(
+            verification_alg.verify_with_context(public_key, &context, msg, &sig),
+            Err(error::Unspecified)
+        );
+        assert!(key_pair
+            .sign_with_context(&rng, &context[..255], msg, &mut sig2)
+            .is_ok());
+
+        // The signature buffer must be exactly the right length.
+        let mut short_sig = vec![0; signing_alg.signature_len() - 1];
+        assert_eq!(
+            key_pair.sign(&rng, msg, &mut short_sig),
+            Err(error::Unspecified)
+        );
+        assert_eq!(
+            unparsed.verify(msg, &sig[..sig.len() - 1]),
+            Err(error::Unspecified)
+        );
+        let mut long_sig = sig.clone();
+        long_sig.push(0);
+        assert_eq!(unparsed.verify(msg, &long_sig), Err(error::Unspecified));
+
+        // Public keys of the wrong length are rejected.
+        assert_eq!(
+            signature::UnparsedPublicKey::new(*verification_alg, &public_key[1..])
+                .verify(msg, &sig),
+            Err(error::Unspecified)
+        );
+    }
+}
+
+#[test]
+fn ml_dsa_from_seed_test() {
+    assert!(MlDsaKeyPair::from_seed(&signature::ML_DSA_44_SIGNING, &[0; 31]).is_err());
+    assert!(MlDsaKeyPair::from_seed(&signature::ML_DSA_44_SIGNING, &[0; 33]).is_err());
+    let key_pair = MlDsaKeyPair::from_seed(&signature::ML_DSA_44_SIGNING, &[0; 32]).unwrap();
+    assert!(core::ptr::eq(
+        key_pair.algorithm(),
+        &signature::ML_DSA_44_SIGNING
+    ));
+}
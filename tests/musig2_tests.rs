@@ -0,0 +1,277 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+#![cfg(feature = "alloc")]
+
+use ring::{error, musig2, rand, signature, test, test_file};
+
+fn consume_optional_bytes(test_case: &mut test::TestCase, key: &str) -> Option<Vec<u8>> {
+    test_case
+        .consume_optional_string(key)
+        .map(|s| test::from_hex(&s).unwrap())
+}
+
+/// Test vectors from BIP-327, plus generated test vectors.
+#[test]
+fn musig2_key_agg_test() {
+    test::run(
+        test_file!("musig2_key_agg_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_keys = test_case.consume_string("PublicKeys");
+            let plain_tweak = consume_optional_bytes(test_case, "PlainTweak");
+            let x_only_tweak = consume_optional_bytes(test_case, "XOnlyTweak");
+            let expected = consume_optional_bytes(test_case, "AggregatedPublicKey");
+            let expected_result = test_case.consume_optional_string("Result");
+
+            let result = (|| {
+                let public_keys = public_keys
+                    .split(", ")
+                    .map(|pk| {
+                        musig2::PublicKey::from_bytes(&test::from_hex(pk).unwrap())
+                            .map_err(|_| error::Unspecified)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut key_agg_ctx = musig2::KeyAggContext::new(&public_keys)?;
+                if let Some(tweak) = &plain_tweak {
+                    key_agg_ctx.apply_plain_tweak(tweak)?;
+                }
+                if let Some(tweak) = &x_only_tweak {
+                    key_agg_ctx.apply_x_only_tweak(tweak)?;
+                }
+                Ok(key_agg_ctx.aggregated_public_key().to_vec())
+            })();
+
+            match expected {
+                Some(expected) => assert_eq!(result, Ok(expected)),
+                None => {
+                    assert_eq!(expected_result.as_deref(), Some("F"));
+                    assert_eq!(result, Err(error::Unspecified));
+                }
+            }
+
+            Ok(())
+        },
+    );
+}
+
+/// Test vectors generated with an implementation of BIP-327 that passes
+/// BIP-327's own test vectors.
+#[test]
+fn musig2_sign_test() {
+    test::run(test_file!("musig2_sign_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let plain_tweak = consume_optional_bytes(test_case, "PlainTweak");
+        let x_only_tweak = consume_optional_bytes(test_case, "XOnlyTweak");
+        let msg = test_case.consume_bytes("Msg");
+        let nonce_includes_msg = test_case.consume_bool("NonceIncludesMsg");
+
+        let mut key_pairs = Vec::new();
+        let mut public_keys = Vec::new();
+        let mut rands = Vec::new();
+        let mut expected_public_nonces = Vec::new();
+        for i in 1.. {
+            let private_key = match consume_optional_bytes(test_case, &format!("PrivateKey{}", i)) {
+                Some(private_key) => private_key,
+                None => break,
+            };
+            let public_key = test_case.consume_bytes(&format!("PublicKey{}", i));
+            let key_pair = musig2::KeyPair::from_private_key(&private_key).unwrap();
+            assert_eq!(
+                signature::KeyPair::public_key(&key_pair).as_ref(),
+                &public_key[..]
+            );
+            key_pairs.push(key_pair);
+            public_keys.push(musig2::PublicKey::from_bytes(&public_key).unwrap());
+            rands.push(test_case.consume_bytes(&format!("Rand{}", i)));
+            expected_public_nonces.push(test_case.consume_bytes(&format!("PublicNonce{}", i)));
+        }
+        let expected_aggregated_nonce = test_case.consume_bytes("AggregatedNonce");
+        let expected_partial_signatures = (1..=key_pairs.len())
+            .map(|i| test_case.consume_bytes(&format!("PartialSignature{}", i)))
+            .collect::<Vec<_>>();
+        let expected_aggregated_public_key = test_case.consume_bytes("AggregatedPublicKey");
+        let expected_signature = test_case.consume_bytes("Signature");
+
+        let mut key_agg_ctx = musig2::KeyAggContext::new(&public_keys).unwrap();
+        if let Some(tweak) = &plain_tweak {
+            key_agg_ctx.apply_plain_tweak(tweak).unwrap();
+        }
+        if let Some(tweak) = &x_only_tweak {
+            key_agg_ctx.apply_x_only_tweak(tweak).unwrap();
+        }
+        assert_eq!(
+            key_agg_ctx.aggregated_public_key(),
+            &expected_aggregated_public_key[..]
+        );
+
+        // Round one.
+        let nonce_msg = if nonce_includes_msg {
+            Some(&msg[..])
+        } else {
+            None
+        };
+        let mut secret_nonces = Vec::new();
+        let mut public_nonces = Vec::new();
+        for ((key_pair, rand), expected) in
+            key_pairs.iter().zip(&rands).zip(&expected_public_nonces)
+        {
+            let rng = test::rand::FixedSliceRandom { bytes: rand };
+            let (secret_nonce, public_nonce) = key_pair
+                .generate_nonce(&key_agg_ctx, nonce_msg, &rng)
+                .unwrap();
+            assert_eq!(public_nonce.as_ref(), &expected[..]);
+            assert_eq!(secret_nonce.public_nonce(), &public_nonce);
+            assert_eq!(
+                musig2::PublicNonce::from_bytes(expected).unwrap(),
+                public_nonce
+            );
+            secret_nonces.push(secret_nonce);
+            public_nonces.push(public_nonce);
+        }
+        let aggregated_nonce = musig2::aggregate_nonces(&public_nonces).unwrap();
+        assert_eq!(aggregated_nonce.as_ref(), &expected_aggregated_nonce[..]);
+        assert_eq!(
+            musig2::AggregatedNonce::from_bytes(&expected_aggregated_nonce).unwrap(),
+            aggregated_nonce
+        );
+
+        // Round two.
+        let session = musig2::Session::new(&key_agg_ctx, &aggregated_nonce, &msg).unwrap();
+        let mut partial_signatures = Vec::new();
+        for (i, (key_pair, secret_nonce)) in key_pairs.iter().zip(secret_nonces).enumerate() {
+            let partial_signature = session.sign(key_pair, secret_nonce).unwrap();
+            assert_eq!(
+                partial_signature.as_ref(),
+                &expected_partial_signatures[i][..]
+            );
+            assert!(session
+                .verify_partial_signature(&public_keys[i], &public_nonces[i], &partial_signature)
+                .is_ok());
+            let other = (i + 1) % key_pairs.len();
+            if other != i {
+                assert!(session
+                    .verify_partial_signature(
+                        &public_keys[i],
+                        &public_nonces[other],
+                        &partial_signature
+                    )
+                    .is_err());
+            }
+            partial_signatures.push(partial_signature);
+        }
+
+        let sig = session.aggregate(&partial_signatures).unwrap();
+        assert_eq!(sig.as_ref(), &expected_signature[..]);
+        if partial_signatures.len() > 1 {
+            assert!(session.aggregate(&partial_signatures[1..]).is_err());
+        }
+
+        let public_key = signature::UnparsedPublicKey::new(
+            &signature::BIP340_SCHNORR_SECP256K1,
+            key_agg_ctx.aggregated_public_key(),
+        );
+        assert!(public_key.verify(&msg, sig.as_ref()).is_ok());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn musig2_sign_rejects_test() {
+    let rng = rand::SystemRandom::new();
+    let key_pairs = [
+        musig2::KeyPair::generate(&rng).unwrap(),
+        musig2::KeyPair::generate(&rng).unwrap(),
+        musig2::KeyPair::generate(&rng).unwrap(),
+    ];
+    let public_keys = [
+        *signature::KeyPair::public_key(&key_pairs[0]),
+        *signature::KeyPair::public_key(&key_pairs[1]),
+    ];
+    let key_agg_ctx = musig2::KeyAggContext::new(&public_keys).unwrap();
+    assert!(musig2::KeyAggContext::new(&[]).is_err());
+
+    let (s1, p1) = key_pairs[0]
+        .generate_nonce(&key_agg_ctx, None, &rng)
+        .unwrap();
+    let (s2, p2) = key_pairs[1]
+        .generate_nonce(&key_agg_ctx, None, &rng)
+        .unwrap();
+    let (s3, _) = key_pairs[2]
+        .generate_nonce(&key_agg_ctx, None, &rng)
+        .unwrap();
+    assert!(musig2::aggregate_nonces(&[]).is_err());
+    let aggregated_nonce = musig2::aggregate_nonces(&[p1, p2]).unwrap();
+    let session = musig2::Session::new(&key_agg_ctx, &aggregated_nonce, b"msg").unwrap();
+    let other_session = musig2::Session::new(&key_agg_ctx, &aggregated_nonce, b"other").unwrap();
+
+    // The third key isn't one of the aggregated keys.
+    assert!(session.sign(&key_pairs[2], s3).is_err());
+    // The secret nonce is for a different key.
+    let (s1_again, _) = key_pairs[0]
+        .generate_nonce(&key_agg_ctx, None, &rng)
+        .unwrap();
+    assert!(session.sign(&key_pairs[1], s1_again).is_err());
+
+    let psig1 = session.sign(&key_pairs[0], s1).unwrap();
+    let psig2 = other_session.sign(&key_pairs[1], s2).unwrap();
+    assert!(session
+        .verify_partial_signature(&public_keys[0], &p1, &psig1)
+        .is_ok());
+    assert!(session
+        .verify_partial_signature(&public_keys[1], &p2, &psig2)
+        .is_err());
+    assert!(session
+        .verify_partial_signature(&public_keys[1], &p1, &psig1)
+        .is_err());
+    assert!(session.aggregate(&[psig1, psig2]).is_err());
+
+    let public_key = signature::KeyPair::public_key(&key_pairs[0]).as_ref();
+    assert!(musig2::PublicKey::from_bytes(&public_key[1..]).is_err());
+    assert!(musig2::PublicKey::from_bytes(&public_key[..32]).is_err());
+    let mut uncompressed_prefix = public_key.to_vec();
+    uncompressed_prefix[0] = 4;
+    assert!(musig2::PublicKey::from_bytes(&uncompressed_prefix).is_err());
+
+    let mut bytes = [0; musig2::NONCE_LEN];
+    assert!(musig2::AggregatedNonce::from_bytes(&bytes).is_ok());
+    assert!(musig2::PublicNonce::from_bytes(&bytes).is_err());
+    bytes[..musig2::PUBLIC_KEY_LEN].copy_from_slice(public_key);
+    assert!(musig2::AggregatedNonce::from_bytes(&bytes).is_ok());
+    assert!(musig2::PublicNonce::from_bytes(&bytes).is_err());
+    assert!(musig2::AggregatedNonce::from_bytes(&bytes[1..]).is_err());
+
+    assert!(musig2::PartialSignature::from_bytes(&[0; musig2::PARTIAL_SIGNATURE_LEN]).is_ok());
+    assert!(musig2::PartialSignature::from_bytes(&[0xff; musig2::PARTIAL_SIGNATURE_LEN]).is_err());
+    assert!(musig2::PartialSignature::from_bytes(&[0; musig2::PARTIAL_SIGNATURE_LEN + 1]).is_err());
+}
+
+/// Test vectors from BIP-340, plus generated test vectors.
+#[test]
+fn bip340_verify_test() {
+    test::run(test_file!("bip340_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let public_key = test_case.consume_bytes("PublicKey");
+        let msg = test_case.consume_bytes("Msg");
+        let sig = test_case.consume_bytes("Sig");
+        let expected_result = test_case.consume_string("Result");
+
+        let public_key =
+            signature::UnparsedPublicKey::new(&signature::BIP340_SCHNORR_SECP256K1, &public_key);
+        let actual_result = public_key.verify(&msg, &sig);
+        assert_eq!(actual_result.is_ok(), expected_result == "P");
+
+        Ok(())
+    });
+}
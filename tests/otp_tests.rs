@@ -0,0 +1,132 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use core::num::NonZeroU64;
+use ring::{digest, hmac, otp, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn step() -> NonZeroU64 {
+    NonZeroU64::new(otp::DEFAULT_TIME_STEP_SECS).unwrap()
+}
+
+#[test]
+fn otp_tests() {
+    test::run(test_file!("otp_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let algorithm = match test_case.consume_digest_alg("Hash").unwrap() {
+            alg if alg == &digest::SHA1_FOR_LEGACY_USE_ONLY => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+            alg if alg == &digest::SHA256 => hmac::HMAC_SHA256,
+            alg if alg == &digest::SHA512 => hmac::HMAC_SHA512,
+            alg => panic!("Unsupported algorithm: {:?}", alg),
+        };
+        let key_value = test_case.consume_bytes("Key");
+        let counter = test_case.consume_optional_string("Counter");
+        let time = test_case.consume_optional_string("Time");
+        let code = test_case.consume_bytes("Code");
+
+        let key = otp::Key::new(algorithm, &key_value, code.len() as u32).unwrap();
+
+        match (counter, time) {
+            (Some(counter), None) => {
+                let counter: u64 = counter.parse().unwrap();
+                assert_eq!(otp::hotp(&key, counter).as_ref(), &code[..]);
+                assert_eq!(otp::verify_hotp(&key, counter, 0, &code), Ok(counter));
+                assert_eq!(otp::verify_hotp(&key, 0, 9, &code), Ok(counter));
+                assert!(otp::verify_hotp(&key, counter + 1, 9, &code).is_err());
+            }
+            (None, Some(time)) => {
+                let time: u64 = time.parse().unwrap();
+                let time_step = time / step().get();
+                assert_eq!(otp::totp(&key, step(), time).as_ref(), &code[..]);
+                assert_eq!(
+                    otp::verify_totp(&key, step(), time, 0, &code),
+                    Ok(time_step)
+                );
+
+                // One step late or early.
+                let later = time + step().get();
+                assert!(otp::verify_totp(&key, step(), later, 0, &code).is_err());
+                assert_eq!(
+                    otp::verify_totp(&key, step(), later, 1, &code),
+                    Ok(time_step)
+                );
+                let earlier = time - step().get();
+                assert!(otp::verify_totp(&key, step(), earlier, 0, &code).is_err());
+                assert_eq!(
+                    otp::verify_totp(&key, step(), earlier, 1, &code),
+                    Ok(time_step)
+                );
+            }
+            _ => panic!("Exactly one of Counter and Time is required"),
+        }
+
+        // Wrong codes.
+        let mut wrong = code.clone();
+        wrong[0] = if wrong[0] == b'9' { b'0' } else { wrong[0] + 1 };
+        assert!(otp::verify_hotp(&key, 0, 9, &wrong).is_err());
+        assert!(otp::verify_hotp(&key, 0, 9, &code[1..]).is_err());
+        assert!(otp::verify_hotp(&key, 0, 9, b"").is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn otp_digits() {
+    let key_value = b"12345678901234567890";
+    for &digits in &[0, 1, otp::MIN_DIGITS - 1, otp::MAX_DIGITS + 1] {
+        assert!(otp::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, key_value, digits).is_err());
+    }
+
+    // RFC 4226 Appendix D's first value, 1284755224, truncated.
+    for (digits, expected) in
+        (otp::MIN_DIGITS..=otp::MAX_DIGITS).zip(&["755224", "4755224", "84755224", "284755224"])
+    {
+        let key = otp::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, key_value, digits).unwrap();
+        assert_eq!(key.digits(), digits);
+        let code = otp::hotp(&key, 0);
+        assert_eq!(code.as_ref(), expected.as_bytes());
+        assert_eq!(format!("{}", code), *expected);
+    }
+}
+
+#[test]
+fn otp_window_edges() {
+    let key = otp::Key::new(hmac::HMAC_SHA256, b"a key", 6).unwrap();
+
+    // The window is clamped at both ends of the counter range.
+    let code = otp::hotp(&key, u64::MAX);
+    assert_eq!(
+        otp::verify_hotp(&key, u64::MAX - 1, 5, code.as_ref()),
+        Ok(u64::MAX)
+    );
+    let code = otp::totp(&key, step(), 0);
+    assert_eq!(otp::verify_totp(&key, step(), 0, 3, code.as_ref()), Ok(0));
+    assert_eq!(otp::verify_totp(&key, step(), 29, 3, code.as_ref()), Ok(0));
+    let code = otp::totp(&key, step(), u64::MAX);
+    assert_eq!(
+        otp::verify_totp(&key, step(), u64::MAX, 3, code.as_ref()),
+        Ok(u64::MAX / 30)
+    );
+
+    assert_eq!(
+        format!("{:?}", key),
+        "Key { algorithm: Algorithm(SHA256), digits: 6 }"
+    );
+}
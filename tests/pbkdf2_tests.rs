@@ -36,6 +36,14 @@ pub fn pbkdf2_tests() {
                 pbkdf2::PBKDF2_HMAC_SHA384
             } else if digest_alg == &digest::SHA512 {
                 pbkdf2::PBKDF2_HMAC_SHA512
+            } else if digest_alg == &digest::SHA512_256 {
+                pbkdf2::PBKDF2_HMAC_SHA512_256
+            } else if digest_alg == &digest::SHA3_256 {
+                pbkdf2::PBKDF2_HMAC_SHA3_256
+            } else if digest_alg == &digest::SHA3_384 {
+                pbkdf2::PBKDF2_HMAC_SHA3_384
+            } else if digest_alg == &digest::SHA3_512 {
+                pbkdf2::PBKDF2_HMAC_SHA3_512
             } else {
                 unreachable!()
             }
@@ -67,3 +75,26 @@ pub fn pbkdf2_tests() {
         Ok(())
     });
 }
+
+#[cfg(feature = "std")]
+#[test]
+pub fn pbkdf2_calibrate() {
+    use std::time::Duration;
+
+    for &algorithm in &[
+        pbkdf2::PBKDF2_HMAC_SHA1,
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        pbkdf2::PBKDF2_HMAC_SHA384,
+        pbkdf2::PBKDF2_HMAC_SHA512,
+    ] {
+        assert_eq!(
+            pbkdf2::calibrate(algorithm, Duration::from_secs(0)).get(),
+            1
+        );
+
+        // Any machine can do more than one iteration in 50ms; the exact
+        // count depends on the machine and its load.
+        let iterations = pbkdf2::calibrate(algorithm, Duration::from_millis(50));
+        assert!(iterations.get() > 1);
+    }
+}
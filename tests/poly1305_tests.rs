@@ -0,0 +1,69 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(any(not(target_arch = "wasm32"), feature = "wasm32_c"))]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use ring::{aead::poly1305, test};
+
+// RFC 8439 Section 2.5.2.
+const KEY: &str = "85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b";
+const MESSAGE: &[u8] = b"Cryptographic Forum Research Group";
+const TAG: &str = "a8061dc1305136c6c22b8baf0c0127a9";
+
+fn key() -> poly1305::Key {
+    let mut key = [0u8; poly1305::KEY_LEN];
+    key.copy_from_slice(&test::from_hex(KEY).unwrap());
+    poly1305::Key::new(key)
+}
+
+#[test]
+fn poly1305_sign() {
+    let tag = poly1305::sign(key(), MESSAGE);
+    assert_eq!(tag.as_ref(), &test::from_hex(TAG).unwrap()[..]);
+    assert_eq!(tag.as_ref().len(), poly1305::TAG_LEN);
+}
+
+#[test]
+fn poly1305_verify() {
+    let tag = test::from_hex(TAG).unwrap();
+    assert_eq!(poly1305::verify(key(), MESSAGE, &tag), Ok(()));
+    assert!(poly1305::verify(key(), &MESSAGE[1..], &tag).is_err());
+    assert!(poly1305::verify(key(), MESSAGE, &tag[..15]).is_err());
+
+    let mut modified = tag;
+    modified[15] ^= 1;
+    assert!(poly1305::verify(key(), MESSAGE, &modified).is_err());
+}
+
+#[test]
+fn poly1305_context() {
+    let expected = test::from_hex(TAG).unwrap();
+
+    // The message may be split anywhere, including within blocks.
+    for a in 0..=MESSAGE.len() {
+        for b in a..=MESSAGE.len() {
+            let mut ctx = poly1305::Context::from_key(key());
+            ctx.update(&MESSAGE[..a]);
+            ctx.update(&MESSAGE[a..b]);
+            ctx.update(&MESSAGE[b..]);
+            assert_eq!(ctx.finish().as_ref(), &expected[..]);
+        }
+    }
+}
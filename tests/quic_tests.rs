@@ -68,3 +68,45 @@ fn test_sample_len(alg: &'static quic::Algorithm) {
     // Sample is empty.
     assert!(key.new_mask(&[]).is_err());
 }
+
+// RFC 9001 Appendix A.4 and RFC 9369 Appendix A.4.
+#[test]
+fn quic_retry_integrity_tag() {
+    const ODCID: [u8; 8] = [0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08];
+    for &(version, retry) in &[
+        (
+            quic::Version::V1,
+            "ff000000010008f067a5502a4262b5746f6b656e04a265ba2eff4d829058fb3f0f2496ba",
+        ),
+        (
+            quic::Version::V2,
+            "cf6b3343cf0008f067a5502a4262b5746f6b656ec8646ce8bfe33952d955543665dcc7b6",
+        ),
+    ] {
+        let retry = test::from_hex(retry).unwrap();
+        let (packet, tag) = retry.split_at(retry.len() - 16);
+        let mut pseudo_packet = vec![ODCID.len() as u8];
+        pseudo_packet.extend_from_slice(&ODCID);
+        pseudo_packet.extend_from_slice(packet);
+
+        assert_eq!(
+            quic::retry_integrity_tag(version, &pseudo_packet).as_ref(),
+            tag
+        );
+        assert_eq!(
+            quic::verify_retry_integrity_tag(version, &pseudo_packet, tag),
+            Ok(())
+        );
+
+        let mut modified = pseudo_packet.clone();
+        modified[1] ^= 1;
+        assert!(quic::verify_retry_integrity_tag(version, &modified, tag).is_err());
+        assert!(quic::verify_retry_integrity_tag(version, &pseudo_packet, &tag[1..]).is_err());
+    }
+
+    // The tags of the two versions differ.
+    assert_ne!(
+        quic::retry_integrity_tag(quic::Version::V1, b"").as_ref(),
+        quic::retry_integrity_tag(quic::Version::V2, b"").as_ref()
+    );
+}
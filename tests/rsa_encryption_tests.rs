@@ -0,0 +1,298 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{
+    rand, rsa_encryption,
+    signature::{self, KeyPair},
+    test, test_file,
+};
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm32_c"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm32_c"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn oaep_alg_from_name(name: &str) -> &'static rsa_encryption::OaepAlgorithm {
+    match name {
+        "SHA256" => &rsa_encryption::RSA_OAEP_SHA256,
+        "SHA384" => &rsa_encryption::RSA_OAEP_SHA384,
+        "SHA512" => &rsa_encryption::RSA_OAEP_SHA512,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn rsa_oaep_decrypt_test() {
+    test::run(test_file!("rsa_oaep_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+
+        let alg = oaep_alg_from_name(&test_case.consume_string("Digest"));
+        let private_key = test_case.consume_bytes("Key");
+        let label = test_case.consume_bytes("Label");
+        let msg = test_case.consume_bytes("Msg");
+        let ct = test_case.consume_bytes("Ct");
+        let result = test_case.consume_string("Result");
+
+        let key_pair = signature::RsaKeyPair::from_der(&private_key).unwrap();
+        let mut plaintext = vec![0; key_pair.public_modulus_len()];
+        let actual = key_pair.decrypt_oaep(alg, &ct, &label, &mut plaintext);
+        match result.as_str() {
+            "Pass" => assert_eq!(actual.unwrap(), &msg[..]),
+            "Fail" => assert!(actual.is_err()),
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn rsa_oaep_round_trip_test() {
+    const PRIVATE_KEY_DER: &[u8] =
+        include_bytes!("../src/rsa/signature_rsa_example_private_key.der");
+    let rng = rand::SystemRandom::new();
+    let key_pair = signature::RsaKeyPair::from_der(PRIVATE_KEY_DER).unwrap();
+    let public_key = rsa_encryption::PublicKey::from_der(key_pair.public_key().as_ref()).unwrap();
+    assert_eq!(public_key.modulus_len(), key_pair.public_modulus_len());
+
+    let components = signature::RsaPublicKeyComponents {
+        n: key_pair
+            .public_key()
+            .modulus()
+            .big_endian_without_leading_zero(),
+        e: key_pair
+            .public_key()
+            .exponent()
+            .big_endian_without_leading_zero(),
+    };
+    let public_key_from_components =
+        rsa_encryption::PublicKey::from_components(&components).unwrap();
+
+    for alg in [
+        &rsa_encryption::RSA_OAEP_SHA256,
+        &rsa_encryption::RSA_OAEP_SHA384,
+        &rsa_encryption::RSA_OAEP_SHA512,
+    ] {
+        let max_len = alg.max_plaintext_len(public_key.modulus_len());
+        assert_eq!(
+            max_len,
+            public_key.modulus_len() - (2 * alg.digest_algorithm().output_len) - 2
+        );
+        let msg = vec![0xa5; max_len + 1];
+
+        for (public_key, msg, label) in [
+            (&public_key, &msg[..0], &b""[..]),
+            (&public_key, &msg[..max_len], &b""[..]),
+            (&public_key_from_components, &msg[..16], &b"label"[..]),
+        ] {
+            let mut ct = vec![0; public_key.modulus_len()];
+            public_key
+                .encrypt_oaep(alg, msg, label, &rng, &mut ct)
+                .unwrap();
+
+            // The encryption is randomized.
+            let mut ct2 = vec![0; public_key.modulus_len()];
+            public_key
+                .encrypt_oaep(alg, msg, label, &rng, &mut ct2)
+                .unwrap();
+            assert_ne!(ct, ct2);
+
+            let mut plaintext = vec![0; key_pair.public_modulus_len()];
+            assert_eq!(
+                key_pair
+                    .decrypt_oaep(alg, &ct, label, &mut plaintext)
+                    .unwrap(),
+                msg
+            );
+
+            // An output buffer that can hold the longest possible message
+            // works; a shorter one doesn't, regardless of the message's
+            // length.
+            let mut plaintext = vec![0; max_len];
+            assert_eq!(
+                key_pair
+                    .decrypt_oaep(alg, &ct, label, &mut plaintext)
+                    .unwrap(),
+                msg
+            );
+            let mut plaintext = vec![0; max_len - 1];
+            assert!(key_pair
+                .decrypt_oaep(alg, &ct, label, &mut plaintext)
+                .is_err());
+
+            // The wrong label.
+            let mut plaintext = vec![0; key_pair.public_modulus_len()];
+            assert!(key_pair
+                .decrypt_oaep(alg, &ct, b"wrong", &mut plaintext)
+                .is_err());
+
+            // A corrupted ciphertext.
+            ct[10] ^= 1;
+            assert!(key_pair
+                .decrypt_oaep(alg, &ct, label, &mut plaintext)
+                .is_err());
+
+            // The wrong ciphertext length.
+            assert!(key_pair
+                .decrypt_oaep(alg, &ct[1..], label, &mut plaintext)
+                .is_err());
+        }
+
+        // The plaintext is too long.
+        let mut ct = vec![0; public_key.modulus_len()];
+        assert!(public_key
+            .encrypt_oaep(alg, &msg, b"", &rng, &mut ct)
+            .is_err());
+
+        // The ciphertext buffer is the wrong length.
+        let mut ct = vec![0; public_key.modulus_len() + 1];
+        assert!(public_key
+            .encrypt_oaep(alg, &msg[..16], b"", &rng, &mut ct)
+            .is_err());
+    }
+}
+
+#[test]
+fn rsa_encryption_public_key_test() {
+    let error = rsa_encryption::PublicKey::from_der(&[]).unwrap_err();
+    assert_eq!(format!("{}", error), "InvalidEncoding");
+
+    // 1024-bit keys are too small.
+    let n = [0xff; 1024 / 8];
+    let components = signature::RsaPublicKeyComponents {
+        n: &n[..],
+        e: &[0x01, 0x00, 0x01][..],
+    };
+    let error = rsa_encryption::PublicKey::from_components(&components).unwrap_err();
+    assert_eq!(format!("{}", error), "TooSmall");
+}
+
+#[test]
+fn rsa_pkcs1_decrypt_test() {
+    test::run(
+        test_file!("rsa_pkcs1_encryption_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let private_key = test_case.consume_bytes("Key");
+            let ct = test_case.consume_bytes("Ct");
+            let msg = test_case.consume_bytes("Msg");
+            let padding = test_case.consume_string("Padding");
+            assert!(padding == "Valid" || padding == "Invalid");
+
+            // Invalid padding isn't reported; a synthetic plaintext is
+            // returned instead.
+            let key_pair = signature::RsaKeyPair::from_der(&private_key).unwrap();
+            let mut plaintext = vec![0; key_pair.public_modulus_len() - 11];
+            let actual = key_pair
+                .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+                .unwrap();
+            assert_eq!(actual, &msg[..]);
+
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn rsa_decrypt_out_of_range_ciphertext_test() {
+    const PRIVATE_KEY_DER: &[u8] =
+        include_bytes!("../src/rsa/signature_rsa_example_private_key.der");
+    let key_pair = signature::RsaKeyPair::from_der(PRIVATE_KEY_DER).unwrap();
+    let k = key_pair.public_modulus_len();
+    let n = key_pair
+        .public_key()
+        .modulus()
+        .big_endian_without_leading_zero();
+    assert_eq!(n.len(), k);
+
+    // Zero, the modulus, and a value larger than the modulus.
+    for ct in [vec![0; k], n.to_vec(), vec![0xff; k]] {
+        let mut plaintext = vec![0; k];
+        assert!(key_pair
+            .decrypt_oaep(&rsa_encryption::RSA_OAEP_SHA256, &ct, b"", &mut plaintext)
+            .is_err());
+        assert!(key_pair
+            .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+            .is_err());
+    }
+}
+
+#[test]
+fn rsa_pkcs1_round_trip_test() {
+    const PRIVATE_KEY_DER: &[u8] =
+        include_bytes!("../src/rsa/signature_rsa_example_private_key.der");
+    let rng = rand::SystemRandom::new();
+    let key_pair = signature::RsaKeyPair::from_der(PRIVATE_KEY_DER).unwrap();
+    let public_key = rsa_encryption::PublicKey::from_der(key_pair.public_key().as_ref()).unwrap();
+    let max_len = public_key.modulus_len() - 11;
+    let msg = vec![0x5a; max_len + 1];
+
+    for msg in [&msg[..0], &msg[..16], &msg[..max_len]] {
+        let mut ct = vec![0; public_key.modulus_len()];
+        public_key
+            .encrypt_pkcs1_for_legacy_use_only(msg, &rng, &mut ct)
+            .unwrap();
+
+        let mut plaintext = vec![0; max_len];
+        assert_eq!(
+            key_pair
+                .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+                .unwrap(),
+            msg
+        );
+
+        // The output buffer must be large enough for any plaintext.
+        let mut plaintext = vec![0; max_len - 1];
+        assert!(key_pair
+            .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+            .is_err());
+
+        // The wrong ciphertext length.
+        let mut plaintext = vec![0; max_len];
+        assert!(key_pair
+            .decrypt_pkcs1_for_legacy_use_only(&ct[1..], &mut plaintext)
+            .is_err());
+
+        // A corrupted ciphertext decrypts to a consistent synthetic plaintext.
+        ct[10] ^= 1;
+        let synthetic = key_pair
+            .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+            .unwrap()
+            .to_vec();
+        assert_ne!(synthetic, msg);
+        let mut plaintext = vec![0; max_len];
+        assert_eq!(
+            key_pair
+                .decrypt_pkcs1_for_legacy_use_only(&ct, &mut plaintext)
+                .unwrap(),
+            &synthetic[..]
+        );
+    }
+
+    // The plaintext is too long.
+    let mut ct = vec![0; public_key.modulus_len()];
+    assert!(public_key
+        .encrypt_pkcs1_for_legacy_use_only(&msg, &rng, &mut ct)
+        .is_err());
+
+    // The ciphertext buffer is the wrong length.
+    let mut ct = vec![0; public_key.modulus_len() - 1];
+    assert!(public_key
+        .encrypt_pkcs1_for_legacy_use_only(&msg[..16], &rng, &mut ct)
+        .is_err());
+}
@@ -14,7 +14,7 @@
 
 #[cfg(feature = "alloc")]
 use ring::{
-    error,
+    digest, error,
     io::der,
     rand,
     signature::{self, KeyPair},
@@ -61,10 +61,22 @@ fn test_signature_rsa_pkcs1_sign() {
             assert_eq!(section, "");
 
             let digest_name = test_case.consume_string("Digest");
-            let alg = match digest_name.as_ref() {
-                "SHA256" => &signature::RSA_PKCS1_SHA256,
-                "SHA384" => &signature::RSA_PKCS1_SHA384,
-                "SHA512" => &signature::RSA_PKCS1_SHA512,
+            let (alg, digest_alg, verification_alg) = match digest_name.as_ref() {
+                "SHA256" => (
+                    &signature::RSA_PKCS1_SHA256,
+                    &digest::SHA256,
+                    &signature::RSA_PKCS1_2048_8192_SHA256,
+                ),
+                "SHA384" => (
+                    &signature::RSA_PKCS1_SHA384,
+                    &digest::SHA384,
+                    &signature::RSA_PKCS1_2048_8192_SHA384,
+                ),
+                "SHA512" => (
+                    &signature::RSA_PKCS1_SHA512,
+                    &digest::SHA512,
+                    &signature::RSA_PKCS1_2048_8192_SHA512,
+                ),
                 _ => panic!("Unsupported digest: {}", digest_name),
             };
 
@@ -87,6 +99,83 @@ fn test_signature_rsa_pkcs1_sign() {
                 .sign(alg, &rng, &msg, actual.as_mut_slice())
                 .unwrap();
             assert_eq!(actual.as_slice() == &expected[..], result == "Pass");
+
+            // Signing the precomputed digest gives the same signature.
+            let m_hash = digest::digest(digest_alg, &msg);
+            let mut actual_prehashed = vec![0u8; key_pair.public_modulus_len()];
+            key_pair
+                .sign_digest(alg, &rng, &m_hash, actual_prehashed.as_mut_slice())
+                .unwrap();
+            assert_eq!(actual_prehashed, actual);
+
+            // A digest calculated with a different algorithm is rejected.
+            let wrong_hash = digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, &msg);
+            assert!(key_pair
+                .sign_digest(alg, &rng, &wrong_hash, actual_prehashed.as_mut_slice())
+                .is_err());
+
+            let public_key =
+                signature::UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+            assert_eq!(
+                public_key.verify_digest(&m_hash, &actual),
+                public_key.verify(&msg, &actual)
+            );
+            assert!(public_key.verify_digest(&wrong_hash, &actual).is_err());
+
+            let public_key =
+                signature::ParsedPublicKey::new(verification_alg, key_pair.public_key().as_ref())?;
+            assert_eq!(public_key.verify_digest(&m_hash, &actual), Ok(()));
+            assert_eq!(public_key.verify(&msg, &actual), Ok(()));
+            assert!(public_key.verify_digest(&wrong_hash, &actual).is_err());
+            Ok(())
+        },
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "insecure_legacy_signing"))]
+#[test]
+fn test_signature_rsa_pkcs1_sign_sha1() {
+    let rng = rand::SystemRandom::new();
+    test::run(
+        test_file!("rsa_pkcs1_sign_sha1_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let digest_name = test_case.consume_string("Digest");
+            assert_eq!(digest_name, "SHA1");
+
+            let private_key = test_case.consume_bytes("Key");
+            let msg = test_case.consume_bytes("Msg");
+            let expected = test_case.consume_bytes("Sig");
+            let result = test_case.consume_string("Result");
+            assert_eq!(result, "Pass");
+
+            let key_pair = signature::RsaKeyPair::from_der(&private_key).unwrap();
+            let alg = &signature::RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY;
+
+            let mut actual = vec![0u8; key_pair.public_modulus_len()];
+            key_pair
+                .sign(alg, &rng, &msg, actual.as_mut_slice())
+                .unwrap();
+            assert_eq!(actual, expected);
+
+            let m_hash = digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, &msg);
+            let mut actual_prehashed = vec![0u8; key_pair.public_modulus_len()];
+            key_pair
+                .sign_digest(alg, &rng, &m_hash, actual_prehashed.as_mut_slice())
+                .unwrap();
+            assert_eq!(actual_prehashed, expected);
+
+            let wrong_hash = digest::digest(&digest::SHA256, &msg);
+            assert!(key_pair
+                .sign_digest(alg, &rng, &wrong_hash, actual_prehashed.as_mut_slice())
+                .is_err());
+
+            let public_key = signature::UnparsedPublicKey::new(
+                &signature::RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+                key_pair.public_key().as_ref(),
+            );
+            assert_eq!(public_key.verify(&msg, &actual), Ok(()));
             Ok(())
         },
     );
@@ -203,6 +292,12 @@ fn test_signature_rsa_pkcs1_verify() {
                 let actual_result =
                     signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
                 assert_eq!(actual_result.is_ok(), is_valid && width_ok);
+
+                let parsed_result = match signature::ParsedPublicKey::new(alg, &public_key) {
+                    Ok(public_key) => public_key.verify(&msg, &sig).is_ok(),
+                    Err(_) => false,
+                };
+                assert_eq!(parsed_result, is_valid && width_ok);
             }
 
             Ok(())
@@ -252,11 +347,425 @@ fn test_signature_rsa_pss_verify() {
                 signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
             assert_eq!(actual_result.is_ok(), is_valid);
 
+            let parsed_result = match signature::ParsedPublicKey::new(alg, &public_key) {
+                Ok(public_key) => public_key.verify(&msg, &sig).is_ok(),
+                Err(_) => false,
+            };
+            assert_eq!(parsed_result, is_valid);
+
             Ok(())
         },
     );
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_pss_salt_len_verify() {
+    test::run(
+        test_file!("rsa_pss_salt_len_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let digest_name = test_case.consume_string("Digest");
+            let algs: [&signature::RsaParameters; 4] = match digest_name.as_ref() {
+                "SHA256" => [
+                    &signature::RSA_PSS_2048_8192_SHA256,
+                    &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_ZERO,
+                    &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_MAX,
+                    &signature::RSA_PSS_2048_8192_SHA256_ANY_SALT_LEN,
+                ],
+                "SHA384" => [
+                    &signature::RSA_PSS_2048_8192_SHA384,
+                    &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_ZERO,
+                    &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_MAX,
+                    &signature::RSA_PSS_2048_8192_SHA384_ANY_SALT_LEN,
+                ],
+                "SHA512" => [
+                    &signature::RSA_PSS_2048_8192_SHA512,
+                    &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_ZERO,
+                    &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_MAX,
+                    &signature::RSA_PSS_2048_8192_SHA512_ANY_SALT_LEN,
+                ],
+                _ => panic!("Unsupported digest: {}", digest_name),
+            };
+
+            let salt_len = test_case.consume_string("SaltLen");
+            let public_key = test_case.consume_bytes("Key");
+            let msg = test_case.consume_bytes("Msg");
+            let sig = test_case.consume_bytes("Sig");
+
+            for (alg, name) in algs.iter().zip(&["Default", "Zero", "Max", "Any"]) {
+                let is_valid = test_case.consume_string(name) == "P";
+
+                let actual_result =
+                    signature::UnparsedPublicKey::new(*alg, &public_key).verify(&msg, &sig);
+                assert_eq!(actual_result.is_ok(), is_valid, "{}", name);
+
+                let public_key = signature::ParsedPublicKey::new(*alg, &public_key)?;
+                assert_eq!(public_key.verify(&msg, &sig).is_ok(), is_valid, "{}", name);
+            }
+
+            // Without a salt, signing is deterministic, so *ring* must produce
+            // the same signatures as OpenSSL.
+            if salt_len == "0"
+                && signature::UnparsedPublicKey::new(algs[1], &public_key)
+                    .verify(&msg, &sig)
+                    .is_ok()
+            {
+                const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+                let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+                let padding_alg: &'static dyn signature::RsaEncoding = match digest_name.as_ref() {
+                    "SHA256" => &signature::RSA_PSS_SHA256_SALT_LEN_ZERO,
+                    "SHA384" => &signature::RSA_PSS_SHA384_SALT_LEN_ZERO,
+                    _ => &signature::RSA_PSS_SHA512_SALT_LEN_ZERO,
+                };
+                let rng = rand::SystemRandom::new();
+                let mut actual = vec![0u8; key_pair.public_modulus_len()];
+                key_pair.sign(padding_alg, &rng, &msg, &mut actual)?;
+                assert_eq!(actual, sig);
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa_generate_test() {
+    let rng = rand::SystemRandom::new();
+
+    let pkcs8 = signature::RsaKeyPair::generate_pkcs8(
+        signature::RsaKeySize::Rsa2048,
+        signature::RSA_DEFAULT_PUBLIC_EXPONENT,
+        &rng,
+    )
+    .unwrap();
+    let key_pair = signature::RsaKeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert_eq!(key_pair.public_modulus_len(), 256);
+    assert_eq!(key_pair.public_key().modulus().first_byte() & 0x80, 0x80);
+    assert_eq!(
+        key_pair
+            .public_key()
+            .exponent()
+            .big_endian_without_leading_zero(),
+        &[0x01, 0x00, 0x01]
+    );
+
+    const MESSAGE: &[u8] = b"hello, world";
+    let mut signature = vec![0; key_pair.public_modulus_len()];
+    key_pair
+        .sign(&signature::RSA_PSS_SHA256, &rng, MESSAGE, &mut signature)
+        .unwrap();
+    let public_key = signature::UnparsedPublicKey::new(
+        &signature::RSA_PSS_2048_8192_SHA256,
+        key_pair.public_key().as_ref(),
+    );
+    assert_eq!(public_key.verify(MESSAGE, &signature), Ok(()));
+
+    // Another key pair is different.
+    let other = signature::RsaKeyPair::generate_pkcs8(
+        signature::RsaKeySize::Rsa2048,
+        signature::RSA_DEFAULT_PUBLIC_EXPONENT,
+        &rng,
+    )
+    .unwrap();
+    assert_ne!(pkcs8.as_ref(), other.as_ref());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa_generate_public_exponent_test() {
+    let rng = rand::SystemRandom::new();
+
+    for &e in &[0, 1, 3, 65535, 65536, 65538, 1 << 33, u64::MAX] {
+        assert!(
+            signature::RsaKeyPair::generate_pkcs8(signature::RsaKeySize::Rsa2048, e, &rng).is_err()
+        );
+    }
+
+    let e = (1 << 33) - 1;
+    let pkcs8 =
+        signature::RsaKeyPair::generate_pkcs8(signature::RsaKeySize::Rsa2048, e, &rng).unwrap();
+    let key_pair = signature::RsaKeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert_eq!(
+        key_pair
+            .public_key()
+            .exponent()
+            .big_endian_without_leading_zero(),
+        &[0x01, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa_generate_bits_test() {
+    let rng = rand::SystemRandom::new();
+
+    for &bits in &[0, 1024, 2047, 2049, 4095, 16384] {
+        assert!(signature::RsaKeyPair::generate(
+            bits,
+            signature::RSA_DEFAULT_PUBLIC_EXPONENT,
+            &rng
+        )
+        .is_err());
+    }
+
+    let pkcs8 = signature::RsaKeyPair::generate(2048, signature::RSA_DEFAULT_PUBLIC_EXPONENT, &rng)
+        .unwrap();
+    let key_pair = signature::RsaKeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert_eq!(key_pair.public_modulus_len(), 256);
+
+    for &(bits, key_size) in &[
+        (2048, signature::RsaKeySize::Rsa2048),
+        (3072, signature::RsaKeySize::Rsa3072),
+        (4096, signature::RsaKeySize::Rsa4096),
+        (8192, signature::RsaKeySize::Rsa8192),
+    ] {
+        assert_eq!(
+            signature::RsaKeySize::from_modulus_len_bits(bits),
+            Some(key_size)
+        );
+        assert_eq!(key_size.modulus_len_bits(), bits);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa_key_pair_generator_test() {
+    let rng = rand::SystemRandom::new();
+    let (key_pair, pkcs8) =
+        signature::KeyPairGenerator::generate_key_pair(&signature::RsaKeySize::Rsa2048, &rng)
+            .unwrap();
+    let parsed = signature::RsaKeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    assert_eq!(parsed.public_key().as_ref(), key_pair.public_key().as_ref());
+}
+
+#[cfg(all(feature = "alloc", feature = "slow_tests"))]
+#[test]
+fn rsa_generate_larger_test() {
+    let rng = rand::SystemRandom::new();
+    for &(key_size, len) in &[
+        (signature::RsaKeySize::Rsa3072, 384),
+        (signature::RsaKeySize::Rsa4096, 512),
+        (signature::RsaKeySize::Rsa8192, 1024),
+    ] {
+        let pkcs8 = signature::RsaKeyPair::generate_pkcs8(
+            key_size,
+            signature::RSA_DEFAULT_PUBLIC_EXPONENT,
+            &rng,
+        )
+        .unwrap();
+        let key_pair = signature::RsaKeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        assert_eq!(key_pair.public_modulus_len(), len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa_test_public_key_spki() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    const SPKI: &[u8] = include_bytes!("rsa_test_public_key_2048_spki.der");
+
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    assert_eq!(&key_pair.public_key().subject_public_key_info()[..], SPKI);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_pss_salt_len_sign() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let rng = rand::SystemRandom::new();
+    let msg = b"ring PSS salt length test";
+
+    let algs: &[(
+        &'static dyn signature::RsaEncoding,
+        &'static signature::RsaParameters,
+        &'static signature::RsaParameters,
+    )] = &[
+        (
+            &signature::RSA_PSS_SHA256_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA256_ANY_SALT_LEN,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA384_ANY_SALT_LEN,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_ZERO,
+            &signature::RSA_PSS_2048_8192_SHA512_ANY_SALT_LEN,
+        ),
+        (
+            &signature::RSA_PSS_SHA256_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA256_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA256_ANY_SALT_LEN,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA384_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA384_ANY_SALT_LEN,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA512_SALT_LEN_MAX,
+            &signature::RSA_PSS_2048_8192_SHA512_ANY_SALT_LEN,
+        ),
+    ];
+
+    for &(padding_alg, verification_alg, any_salt_len_alg) in algs {
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair.sign(padding_alg, &rng, msg, &mut sig).unwrap();
+
+        for alg in &[verification_alg, any_salt_len_alg] {
+            let public_key = signature::UnparsedPublicKey::new(*alg, public_key);
+            assert_eq!(public_key.verify(msg, &sig), Ok(()));
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_signing_context() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let rng = rand::SystemRandom::new();
+    let msg: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+    // PKCS#1 1.5 signatures are deterministic, so signing in parts gives the
+    // same signature as signing all at once.
+    let mut expected = vec![0u8; key_pair.public_modulus_len()];
+    key_pair
+        .sign(&signature::RSA_PKCS1_SHA256, &rng, &msg, &mut expected)
+        .unwrap();
+    for chunk_len in [1, 7, 64, 999, 1000] {
+        let mut ctx = key_pair.signing_context(&signature::RSA_PKCS1_SHA256);
+        for chunk in msg.chunks(chunk_len) {
+            ctx.update(chunk);
+        }
+        let mut actual = vec![0u8; key_pair.public_modulus_len()];
+        ctx.finish(&rng, &mut actual).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    let mut ctx = key_pair.signing_context(&signature::RSA_PSS_SHA384);
+    ctx.update(&msg[..500]);
+    ctx.update(&msg[500..]);
+    let mut sig = vec![0u8; key_pair.public_modulus_len()];
+    ctx.finish(&rng, &mut sig).unwrap();
+    let public_key = signature::UnparsedPublicKey::new(
+        &signature::RSA_PSS_2048_8192_SHA384,
+        key_pair.public_key().as_ref(),
+    );
+    assert_eq!(public_key.verify(&msg, &sig), Ok(()));
+
+    // The signature buffer must be the right length.
+    let ctx = key_pair.signing_context(&signature::RSA_PKCS1_SHA256);
+    let mut short = vec![0u8; key_pair.public_modulus_len() - 1];
+    assert!(ctx.finish(&rng, &mut short).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_8192_sign() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_8192.p8");
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    assert_eq!(key_pair.public_modulus_len(), 8192 / 8);
+    let rng = rand::SystemRandom::new();
+    const MSG: &[u8] = b"hello, world";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::RSA_PKCS1_SHA256 as &dyn signature::RsaEncoding,
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PSS_SHA512,
+            &signature::RSA_PSS_2048_8192_SHA512,
+        ),
+    ] {
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair.sign(signing_alg, &rng, MSG, &mut sig).unwrap();
+        let public_key =
+            signature::UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+        assert_eq!(public_key.verify(MSG, &sig), Ok(()));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_raw() {
+    use signature::rsa_raw;
+
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = signature::RsaPublicKeyComponents {
+        n: key_pair
+            .public_key()
+            .modulus()
+            .big_endian_without_leading_zero(),
+        e: key_pair
+            .public_key()
+            .exponent()
+            .big_endian_without_leading_zero(),
+    };
+    let rng = rand::SystemRandom::new();
+    const MSG: &[u8] = b"hello, world";
+    let k = key_pair.public_modulus_len();
+
+    // RSAVP1 recovers the PKCS#1 1.5 encoding of the digest from a PKCS#1 1.5
+    // signature, and RSASP1 of that encoding is the signature.
+    let mut sig = vec![0u8; k];
+    key_pair
+        .sign(&signature::RSA_PKCS1_SHA256, &rng, MSG, &mut sig)
+        .unwrap();
+    let mut em = vec![0u8; k];
+    rsa_raw::rsavp1(&public_key, &sig, &mut em).unwrap();
+    assert_eq!(&em[..3], &[0x00, 0x01, 0xff]);
+    assert_eq!(
+        &em[(k - 32)..],
+        digest::digest(&digest::SHA256, MSG).as_ref()
+    );
+
+    let mut actual = vec![0u8; k];
+    rsa_raw::rsasp1(&key_pair, &em, &mut actual).unwrap();
+    assert_eq!(actual, sig);
+
+    // The representatives must be exactly as long as the modulus.
+    let mut short = vec![0u8; k - 1];
+    assert!(rsa_raw::rsasp1(&key_pair, &em[1..], &mut actual).is_err());
+    assert!(rsa_raw::rsasp1(&key_pair, &em, &mut short).is_err());
+    assert!(rsa_raw::rsavp1(&public_key, &sig[1..], &mut em).is_err());
+    assert!(rsa_raw::rsavp1(&public_key, &sig, &mut short).is_err());
+
+    // RSASP1 rejects a zero message representative.
+    let zero = vec![0u8; k];
+    assert!(rsa_raw::rsasp1(&key_pair, &zero, &mut actual).is_err());
+
+    // The representatives must be less than the modulus.
+    let too_large = vec![0xffu8; k];
+    assert!(rsa_raw::rsasp1(&key_pair, &too_large, &mut actual).is_err());
+    assert!(rsa_raw::rsavp1(&public_key, &too_large, &mut em).is_err());
+
+    // Public keys as small as 1024 bits, with exponents as small as 3, are
+    // accepted.
+    let n = [0xffu8; 1024 / 8];
+    let small_public_key = signature::RsaPublicKeyComponents {
+        n: &n[..],
+        e: &[0x03][..],
+    };
+    let mut s = [0u8; 1024 / 8];
+    s[127] = 2;
+    let mut m = [0u8; 1024 / 8];
+    rsa_raw::rsavp1(&small_public_key, &s, &mut m).unwrap();
+    assert_eq!(m[..127], [0u8; 127][..]);
+    assert_eq!(m[127], 8);
+}
+
 // Test for `primitive::verify()`. Read public key parts from a file
 // and use them to verify a signature.
 #[cfg(feature = "alloc")]
@@ -0,0 +1,81 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{scrypt, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn scrypt_tests() {
+    test::run(test_file!("scrypt_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let secret = test_case.consume_bytes("Password");
+        let salt = test_case.consume_bytes("Salt");
+        let log_n = test_case.consume_usize("LogN");
+        let r = test_case.consume_usize("R");
+        let p = test_case.consume_usize("P");
+        let dk = test_case.consume_bytes("DK");
+
+        let params = scrypt::Params::new(log_n as u8, r as u32, p as u32).unwrap();
+
+        let mut out = vec![0u8; dk.len()];
+        scrypt::derive(&params, &salt, &secret, &mut out);
+        assert_eq!(dk, out);
+
+        assert!(scrypt::verify(&params, &salt, &secret, &dk).is_ok());
+        assert!(scrypt::verify(&params, &salt, &secret, &[]).is_err());
+
+        let mut wrong = dk.clone();
+        wrong[0] ^= 1;
+        assert!(scrypt::verify(&params, &salt, &secret, &wrong).is_err());
+
+        let mut wrong_secret = secret.clone();
+        wrong_secret.push(b'!');
+        assert!(scrypt::verify(&params, &salt, &wrong_secret, &dk).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn scrypt_params() {
+    assert!(scrypt::Params::new(1, 1, 1).is_ok());
+    assert!(scrypt::Params::new(15, 1, 1).is_ok());
+    assert!(scrypt::Params::new(20, 8, 1).is_ok());
+
+    // N must be at least 2, and less than 2^(16 * r).
+    assert!(scrypt::Params::new(0, 8, 1).is_err());
+    assert!(scrypt::Params::new(16, 1, 1).is_err());
+
+    // r and p must be positive, and r * p less than 2^30.
+    assert!(scrypt::Params::new(10, 0, 1).is_err());
+    assert!(scrypt::Params::new(10, 8, 0).is_err());
+    assert!(scrypt::Params::new(10, 1 << 15, (1 << 15) - 1).is_ok());
+    assert!(scrypt::Params::new(10, 1 << 15, 1 << 15).is_err());
+
+    // The memory must be addressable.
+    assert!(scrypt::Params::new(63, 8, 1).is_err());
+    assert!(scrypt::Params::new(255, 32, 1).is_err());
+
+    let params = scrypt::Params::new(14, 8, 2).unwrap();
+    assert_eq!(params.log_n(), 14);
+    assert_eq!(params.r(), 8);
+    assert_eq!(params.p(), 2);
+}
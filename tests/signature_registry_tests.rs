@@ -0,0 +1,118 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{
+    rand,
+    signature::{self, registry, KeyPair},
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn registry_lookup_test() {
+    for alg in registry::ALGORITHMS {
+        let by_jose = registry::Algorithm::from_jose_name(alg.jose_name()).unwrap();
+        assert!(core::ptr::eq(by_jose, alg));
+        let by_cose = registry::Algorithm::from_cose_id(alg.cose_id()).unwrap();
+        assert!(core::ptr::eq(by_cose, alg));
+        if let Some(oid) = alg.oid() {
+            let by_oid = registry::Algorithm::from_oid(oid).unwrap();
+            assert!(core::ptr::eq(by_oid, alg));
+        }
+    }
+
+    let eddsa = registry::Algorithm::from_jose_name("EdDSA").unwrap();
+    assert_eq!(eddsa.cose_id(), -8);
+    assert!(matches!(
+        eddsa.signing_algorithm(),
+        registry::SigningAlgorithm::Ed25519
+    ));
+
+    // ecdsa-with-SHA256 doesn't identify the curve.
+    assert!(
+        registry::Algorithm::from_oid(&[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02]).is_none()
+    );
+    assert!(registry::Algorithm::from_jose_name("es256").is_none());
+    assert!(registry::Algorithm::from_jose_name("none").is_none());
+    assert!(registry::Algorithm::from_jose_name("HS256").is_none());
+    assert!(registry::Algorithm::from_cose_id(-65535).is_none()); // RS1
+    assert!(registry::Algorithm::from_cose_id(0).is_none());
+}
+
+#[test]
+fn registry_sign_verify_test() {
+    const MESSAGE: &[u8] = b"registry";
+    let rng = rand::SystemRandom::new();
+
+    for alg in registry::ALGORITHMS {
+        let (public_key, signature) = match alg.signing_algorithm() {
+            registry::SigningAlgorithm::Ecdsa(signing_alg) => {
+                let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+                let key_pair =
+                    signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+                let signature = key_pair.sign(&rng, MESSAGE).unwrap();
+                (
+                    key_pair.public_key().as_ref().to_vec(),
+                    signature.as_ref().to_vec(),
+                )
+            }
+            registry::SigningAlgorithm::Ed25519 => {
+                let pkcs8 = signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+                let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+                let signature = key_pair.sign(MESSAGE);
+                (
+                    key_pair.public_key().as_ref().to_vec(),
+                    signature.as_ref().to_vec(),
+                )
+            }
+            registry::SigningAlgorithm::Ed448 => {
+                let pkcs8 = signature::Ed448KeyPair::generate_pkcs8(&rng).unwrap();
+                let key_pair = signature::Ed448KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+                let signature = key_pair.sign(MESSAGE);
+                (
+                    key_pair.public_key().as_ref().to_vec(),
+                    signature.as_ref().to_vec(),
+                )
+            }
+            registry::SigningAlgorithm::MlDsa(signing_alg) => {
+                let pkcs8 = signature::MlDsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+                let key_pair =
+                    signature::MlDsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+                let mut signature = vec![0; signing_alg.signature_len()];
+                key_pair.sign(&rng, MESSAGE, &mut signature).unwrap();
+                (key_pair.public_key().as_ref().to_vec(), signature)
+            }
+            #[cfg(feature = "alloc")]
+            registry::SigningAlgorithm::Rsa(padding_alg) => {
+                const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+                let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+                let mut signature = vec![0; key_pair.public_modulus_len()];
+                key_pair
+                    .sign(padding_alg, &rng, MESSAGE, &mut signature)
+                    .unwrap();
+                (key_pair.public_key().as_ref().to_vec(), signature)
+            }
+            _ => unreachable!(),
+        };
+
+        let public_key =
+            signature::UnparsedPublicKey::new(alg.verification_algorithm(), &public_key);
+        assert_eq!(public_key.verify(MESSAGE, &signature), Ok(()), "{:?}", alg);
+        assert!(public_key.verify(b"other", &signature).is_err());
+    }
+}
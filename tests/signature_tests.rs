@@ -12,4 +12,151 @@ fn signature_impl_test() {
     test::compile_time_assert_copy::<signature::Signature>();
     test::compile_time_assert_send::<signature::Signature>();
     test::compile_time_assert_sync::<signature::Signature>();
+
+    test::compile_time_assert_send::<signature::ParsedPublicKey<&[u8]>>();
+    test::compile_time_assert_sync::<signature::ParsedPublicKey<&[u8]>>();
+}
+
+#[test]
+fn key_pair_generator_test() {
+    use signature::{KeyPair, KeyPairGenerator};
+
+    // Provisioning code that is generic over the algorithm.
+    fn check<G: KeyPairGenerator>(alg: &'static G, from_pkcs8: fn(&[u8]) -> G::KeyPair) {
+        let rng = ring::rand::SystemRandom::new();
+        let (key_pair, pkcs8) = alg.generate_key_pair(&rng).unwrap();
+        let parsed = from_pkcs8(pkcs8.as_ref());
+        assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+
+        // Each call generates a new key.
+        let (other, _) = alg.generate_key_pair(&rng).unwrap();
+        assert_ne!(key_pair.public_key().as_ref(), other.public_key().as_ref());
+    }
+
+    check(&signature::ED25519, |pkcs8| {
+        signature::Ed25519KeyPair::from_pkcs8(pkcs8).unwrap()
+    });
+    check(&signature::ED448, |pkcs8| {
+        signature::Ed448KeyPair::from_pkcs8(pkcs8).unwrap()
+    });
+    check(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, |pkcs8| {
+        signature::EcdsaKeyPair::from_pkcs8(
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            pkcs8,
+            &ring::rand::SystemRandom::new(),
+        )
+        .unwrap()
+    });
+    check(&signature::ECDSA_P384_SHA384_FIXED_SIGNING, |pkcs8| {
+        signature::EcdsaKeyPair::from_pkcs8(
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            pkcs8,
+            &ring::rand::SystemRandom::new(),
+        )
+        .unwrap()
+    });
+    check(&signature::SM2_SM3_ASN1_SIGNING, |pkcs8| {
+        signature::Sm2KeyPair::from_pkcs8(
+            &signature::SM2_SM3_ASN1_SIGNING,
+            pkcs8,
+            &ring::rand::SystemRandom::new(),
+        )
+        .unwrap()
+    });
+    check(&signature::ML_DSA_44_SIGNING, |pkcs8| {
+        signature::MlDsaKeyPair::from_pkcs8(&signature::ML_DSA_44_SIGNING, pkcs8).unwrap()
+    });
+}
+
+#[test]
+fn unparsed_public_key_validate_test() {
+    use signature::{KeyPair, KeyPairGenerator};
+
+    fn validate(
+        alg: &'static dyn signature::VerificationAlgorithm,
+        public_key: &[u8],
+    ) -> Result<(), ring::error::KeyRejected> {
+        signature::UnparsedPublicKey::new(alg, public_key).validate()
+    }
+
+    let rng = ring::rand::SystemRandom::new();
+
+    // Ed25519.
+    let (key_pair, _) = signature::ED25519.generate_key_pair(&rng).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    for alg in [
+        &signature::ED25519,
+        &signature::ED25519_STRICT,
+        &signature::ED25519_ZIP215,
+    ] {
+        assert!(validate(alg, public_key).is_ok());
+    }
+    assert!(validate(&signature::ED25519PH, public_key).is_ok());
+
+    let mut y3 = [0u8; 32];
+    y3[0] = 3;
+    assert!(validate(&signature::ED25519, &y3).is_ok());
+    let bad: &[&[u8]] = &[
+        // Wrong lengths.
+        &public_key[..31],
+        &[],
+        // y = 2 isn't on the curve.
+        &test::from_hex("0200000000000000000000000000000000000000000000000000000000000000")
+            .unwrap(),
+        // The identity.
+        &test::from_hex("0100000000000000000000000000000000000000000000000000000000000000")
+            .unwrap(),
+        // A point of order 2.
+        &test::from_hex("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap(),
+        // A non-canonical encoding of the point with y = 3.
+        &test::from_hex("f0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap(),
+    ];
+    for bad in bad {
+        assert!(validate(&signature::ED25519, bad).is_err());
+        assert!(validate(&signature::ED25519_ZIP215, bad).is_err());
+        assert!(validate(&signature::ED25519PH, bad).is_err());
+    }
+
+    // ECDSA and SM2.
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+    ] {
+        let (key_pair, _) = signing_alg.generate_key_pair(&rng).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        assert!(validate(verification_alg, public_key).is_ok());
+        check_bad_ec_public_keys(verification_alg, public_key);
+    }
+    let (key_pair, _) = signature::SM2_SM3_ASN1_SIGNING
+        .generate_key_pair(&rng)
+        .unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    assert!(validate(&signature::SM2_SM3_ASN1, public_key).is_ok());
+    check_bad_ec_public_keys(&signature::SM2_SM3_ASN1, public_key);
+
+    fn check_bad_ec_public_keys(alg: &'static dyn signature::VerificationAlgorithm, good: &[u8]) {
+        // Not on the curve.
+        let mut off_curve = good.to_vec();
+        *off_curve.last_mut().unwrap() ^= 1;
+        assert!(validate(alg, &off_curve).is_err());
+
+        // Compressed, truncated, and the point at infinity.
+        let mut compressed = good[..1 + (good.len() - 1) / 2].to_vec();
+        compressed[0] = 0x02;
+        assert!(validate(alg, &compressed).is_err());
+        assert!(validate(alg, &good[..good.len() - 1]).is_err());
+        assert!(validate(alg, &[0]).is_err());
+    }
+
+    // Algorithms that can't validate keys separately.
+    let (key_pair, _) = signature::ED448.generate_key_pair(&rng).unwrap();
+    assert!(validate(&signature::ED448, key_pair.public_key().as_ref()).is_err());
 }
@@ -0,0 +1,110 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use core::hash::{BuildHasher, Hasher};
+use ring::{siphash, test, test_file};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn siphash_tests() {
+    test::run(test_file!("siphash_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let algorithm = match test_case.consume_string("Algorithm").as_str() {
+            "SipHash-2-4" => &siphash::SIPHASH_2_4,
+            "SipHash-1-3" => &siphash::SIPHASH_1_3,
+            alg => panic!("Unsupported algorithm: {}", alg),
+        };
+        let key_value = test_case.consume_bytes("Key");
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Tag");
+
+        let key = siphash::Key::new(algorithm, &key_value).unwrap();
+        assert_eq!(key.algorithm(), algorithm);
+
+        assert_eq!(siphash::sign(&key, &input).as_ref(), &expected[..]);
+        assert!(siphash::verify(&key, &input, &expected).is_ok());
+
+        let mut wrong = expected.clone();
+        wrong[0] ^= 1;
+        assert!(siphash::verify(&key, &input, &wrong).is_err());
+        assert!(siphash::verify(&key, &input, &expected[1..]).is_err());
+
+        // The result must not depend on how the input is split.
+        for split in 0..=input.len() {
+            let (a, b) = input.split_at(split);
+            let mut ctx = siphash::Context::with_key(&key);
+            ctx.update(a);
+            ctx.update(b);
+            assert_eq!(ctx.sign().as_ref(), &expected[..]);
+        }
+        for chunk_len in 1..9 {
+            let mut ctx = siphash::Context::with_key(&key);
+            input.chunks(chunk_len).for_each(|chunk| ctx.update(chunk));
+            assert_eq!(ctx.sign().as_ref(), &expected[..]);
+        }
+
+        let mut hasher = key.build_hasher();
+        hasher.write(&input);
+        assert_eq!(hasher.finish().to_le_bytes(), &expected[..]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn siphash_key_len() {
+    for len in &[0, siphash::KEY_LEN - 1, siphash::KEY_LEN + 1, 32] {
+        let key_value = vec![0; *len];
+        assert!(siphash::Key::new(&siphash::SIPHASH_2_4, &key_value).is_err());
+    }
+}
+
+#[test]
+fn siphash_build_hasher() {
+    let key = siphash::Key::new(&siphash::SIPHASH_1_3, &[7; siphash::KEY_LEN]).unwrap();
+
+    let hash = |value: &str| {
+        let mut hasher = key.build_hasher();
+        hasher.write(value.as_bytes());
+        hasher.finish()
+    };
+    assert_eq!(hash("hello"), hash("hello"));
+    assert_ne!(hash("hello"), hash("world"));
+
+    // `finish` doesn't consume the state.
+    let mut hasher = key.build_hasher();
+    hasher.write(b"hello");
+    let first = hasher.finish();
+    assert_eq!(first, hasher.finish());
+    hasher.write(b", world");
+    assert_ne!(first, hasher.finish());
+
+    let mut map = std::collections::HashMap::with_hasher(key.clone());
+    assert!(map.insert("key", 1).is_none());
+    assert_eq!(map.get("key"), Some(&1));
+}
+
+#[test]
+fn siphash_debug() {
+    let key = siphash::Key::new(&siphash::SIPHASH_2_4, &[0; siphash::KEY_LEN]).unwrap();
+    assert_eq!("Key { algorithm: SIPHASH_2_4 }", format!("{:?}", &key));
+
+    let ctx = siphash::Context::with_key(&key);
+    assert_eq!("Context { algorithm: SIPHASH_2_4 }", format!("{:?}", &ctx));
+}
@@ -0,0 +1,154 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{
+    rand,
+    signature::{self, KeyPair},
+    test, test_file,
+};
+
+#[test]
+fn test_signature_sm2_verify() {
+    test::run(test_file!("sm2_verify_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+
+        let public_key = test_case.consume_bytes("Q");
+        let id = test_case.consume_bytes("ID");
+        let msg = test_case.consume_bytes("Msg");
+        let sig = test_case.consume_bytes("Sig");
+        let expected_result = test_case.consume_string("Result");
+        let expected_result = match expected_result.as_str() {
+            "P" => Ok(()),
+            "F" => Err(ring::error::Unspecified),
+            s => panic!("{:?} is not a valid result", s),
+        };
+
+        let actual_result = signature::SM2_SM3_ASN1.verify_with_id(&public_key, &id, &msg, &sig);
+        assert_eq!(actual_result, expected_result);
+
+        if id == signature::SM2_DEFAULT_ID {
+            let public_key =
+                signature::UnparsedPublicKey::new(&signature::SM2_SM3_ASN1, &public_key);
+            assert_eq!(public_key.verify(&msg, &sig), expected_result);
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_signature_sm2_sign_verify() {
+    const MSG: &[u8] = b"SM2 signature test";
+    const ID: &[u8] = b"ALICE123@YAHOO.COM";
+
+    let rng = rand::SystemRandom::new();
+
+    for &(signing_alg, verification_alg) in &[
+        (&signature::SM2_SM3_ASN1_SIGNING, &signature::SM2_SM3_ASN1),
+        (&signature::SM2_SM3_FIXED_SIGNING, &signature::SM2_SM3_FIXED),
+    ] {
+        let pkcs8 = signature::Sm2KeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair =
+            signature::Sm2KeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+
+        let sig = key_pair.sign(&rng, MSG).unwrap();
+        let unparsed = signature::UnparsedPublicKey::new(verification_alg, public_key);
+        assert!(unparsed.verify(MSG, sig.as_ref()).is_ok());
+        assert!(verification_alg
+            .verify_with_id(public_key, signature::SM2_DEFAULT_ID, MSG, sig.as_ref())
+            .is_ok());
+        assert!(verification_alg
+            .verify_with_id(public_key, ID, MSG, sig.as_ref())
+            .is_err());
+
+        let sig = key_pair.sign_with_id(&rng, ID, MSG).unwrap();
+        assert!(verification_alg
+            .verify_with_id(public_key, ID, MSG, sig.as_ref())
+            .is_ok());
+        assert!(unparsed.verify(MSG, sig.as_ref()).is_err());
+        assert!(verification_alg
+            .verify_with_id(public_key, ID, b"SM2 signature tesT", sig.as_ref())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_signature_sm2_id_len() {
+    let rng = rand::SystemRandom::new();
+    let pkcs8 =
+        signature::Sm2KeyPair::generate_pkcs8(&signature::SM2_SM3_FIXED_SIGNING, &rng).unwrap();
+    let key_pair =
+        signature::Sm2KeyPair::from_pkcs8(&signature::SM2_SM3_FIXED_SIGNING, pkcs8.as_ref(), &rng)
+            .unwrap();
+    let public_key = key_pair.public_key().as_ref();
+
+    // The length of the ID in bits must fit in 16 bits.
+    let id = [b'a'; 8192];
+    let longest_id = &id[..8191];
+    let sig = key_pair.sign_with_id(&rng, longest_id, b"").unwrap();
+    assert!(signature::SM2_SM3_FIXED
+        .verify_with_id(public_key, longest_id, b"", sig.as_ref())
+        .is_ok());
+    assert!(key_pair.sign_with_id(&rng, &id, b"").is_err());
+    assert!(signature::SM2_SM3_FIXED
+        .verify_with_id(public_key, &id, b"", sig.as_ref())
+        .is_err());
+}
+
+#[test]
+fn test_signature_sm2_from_pkcs8() {
+    // Generated by OpenSSL.
+    const PRIVATE_KEY: &[u8] = include_bytes!("sm2_test_private_key.p8");
+    const PUBLIC_KEY: &[u8] = include_bytes!("sm2_test_public_key.bin");
+    const P256_PRIVATE_KEY: &[u8] = include_bytes!("ecdsa_test_private_key_p256.p8");
+
+    let rng = rand::SystemRandom::new();
+
+    let key_pair =
+        signature::Sm2KeyPair::from_pkcs8(&signature::SM2_SM3_ASN1_SIGNING, PRIVATE_KEY, &rng)
+            .unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), PUBLIC_KEY);
+
+    // The curve is part of the algorithm identifier.
+    assert!(signature::Sm2KeyPair::from_pkcs8(
+        &signature::SM2_SM3_ASN1_SIGNING,
+        P256_PRIVATE_KEY,
+        &rng
+    )
+    .is_err());
+    assert!(signature::EcdsaKeyPair::from_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        PRIVATE_KEY,
+        &rng
+    )
+    .is_err());
+}
+
+#[test]
+fn test_signature_sm2_key_pair_debug() {
+    let public_key: &[u8] = include_bytes!("sm2_test_public_key.bin");
+    let rng = rand::SystemRandom::new();
+    let key_pair = signature::Sm2KeyPair::from_pkcs8(
+        &signature::SM2_SM3_ASN1_SIGNING,
+        include_bytes!("sm2_test_private_key.p8"),
+        &rng,
+    )
+    .unwrap();
+    let hex: String = public_key.iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(
+        format!("{:?}", key_pair),
+        format!("Sm2KeyPair {{ public_key: PublicKey(\"{}\") }}", hex)
+    );
+}
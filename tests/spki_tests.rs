@@ -0,0 +1,84 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{
+    digest, rand,
+    signature::{self, KeyPair},
+    spki, test,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// The expected values were computed from the `SubjectPublicKeyInfo` encodings
+// produced by another implementation.
+
+fn check(public_key: &dyn spki::PublicKey, sha256: &str, sha3_256: &str, pin: &str) {
+    assert_eq!(
+        spki::fingerprint(&digest::SHA256, public_key).as_ref(),
+        &test::from_hex(sha256).unwrap()[..]
+    );
+    assert_eq!(
+        spki::fingerprint(&digest::SHA3_256, public_key).as_ref(),
+        &test::from_hex(sha3_256).unwrap()[..]
+    );
+    assert_eq!(spki::pin_sha256(public_key), pin);
+}
+
+#[test]
+fn spki_fingerprint_ed25519() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("ed25519_test_private_key.p8");
+    let key_pair = signature::Ed25519KeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    check(
+        key_pair.public_key(),
+        "0837d59f302d53c7be3b8050b76fa94c02fe7f9bb36475bab7ecb2d21e34a2d0",
+        "730986953a66dcdd4cb239c64685cf8a52cd8a61373574170a90394335621545",
+        "CDfVnzAtU8e+O4BQt2+pTAL+f5uzZHW6t+yy0h40otA=",
+    );
+}
+
+#[test]
+fn spki_fingerprint_ecdsa() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("ecdsa_test_private_key_p256.p8");
+    let rng = rand::SystemRandom::new();
+    let key_pair = signature::EcdsaKeyPair::from_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        PRIVATE_KEY,
+        &rng,
+    )
+    .unwrap();
+    check(
+        key_pair.public_key(),
+        "d94a9de174403555d3d34d2842d00897c6900ca0f6789ca86376e9525af87d2c",
+        "4d39f0780c4851347f6f5d8e90ab28a3be5a6064be6daa2df6fd48dd0e8c70dc",
+        "2Uqd4XRANVXT000oQtAIl8aQDKD2eJyoY3bpUlr4fSw=",
+    );
+}
+
+#[test]
+fn spki_fingerprint_rsa() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    check(
+        key_pair.public_key(),
+        "b4f547362d13b5f343cb2795be20017b6c932cbd6830a1b454e51a02b9890ba3",
+        "af744488d303ba520505ce1b771a86ecde3957441155f4db96d1c716a751dcd3",
+        "tPVHNi0TtfNDyyeVviABe2yTLL1oMKG0VOUaArmJC6M=",
+    );
+}
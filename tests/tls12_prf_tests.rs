@@ -0,0 +1,52 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{digest, test, test_file, tls12_prf};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn tls12_prf_tests() {
+    test::run(test_file!("tls12_prf_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let algorithm = match test_case.consume_digest_alg("Hash").unwrap() {
+            alg if alg == &digest::SHA256 => tls12_prf::P_SHA256,
+            alg if alg == &digest::SHA384 => tls12_prf::P_SHA384,
+            alg => panic!("Unsupported algorithm: {:?}", alg),
+        };
+        let secret = test_case.consume_bytes("Secret");
+        let label = test_case.consume_bytes("Label");
+        let seed = test_case.consume_bytes("Seed");
+        let expected = test_case.consume_bytes("Output");
+
+        // The result must not depend on how the seed is split.
+        for split in 0..=seed.len() {
+            let (a, b) = seed.split_at(split);
+            let mut actual = vec![0u8; expected.len()];
+            tls12_prf::derive(algorithm, &secret, &label, &[a, b], &mut actual);
+            assert_eq!(actual, expected);
+        }
+
+        // A shorter output is a prefix of a longer one.
+        let mut actual = vec![0u8; expected.len() - 1];
+        tls12_prf::derive(algorithm, &secret, &label, &[&seed], &mut actual);
+        assert_eq!(actual, &expected[..actual.len()]);
+
+        Ok(())
+    });
+}
@@ -0,0 +1,94 @@
+// Copyright 2026 The ring Authors.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, signature::KeyPair, test, test_file, vrf};
+
+/// Test vectors from RFC 9381, plus generated test vectors.
+#[test]
+fn test_vrf_prove() {
+    test::run(test_file!("ecvrf_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("SK");
+        let public_key = test_case.consume_bytes("PK");
+        let alpha = test_case.consume_bytes("ALPHA");
+        let expected_proof = test_case.consume_bytes("PI");
+        let expected_output = test_case.consume_bytes("BETA");
+
+        let key_pair = vrf::KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), &public_key[..]);
+
+        let proof = key_pair.prove(&alpha);
+        assert_eq!(proof.as_ref(), &expected_proof[..]);
+        assert_eq!(proof.to_hash().as_ref(), &expected_output[..]);
+
+        let output = vrf::verify(&public_key, &alpha, &expected_proof).unwrap();
+        assert_eq!(output.as_ref(), &expected_output[..]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_vrf_verify() {
+    test::run(
+        test_file!("ecvrf_verify_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_key = test_case.consume_bytes("PK");
+            let alpha = test_case.consume_bytes("ALPHA");
+            let proof = test_case.consume_bytes("PI");
+            let expected_result = match test_case.consume_string("Result").as_str() {
+                "P" => Ok(()),
+                "F" => Err(error::Unspecified),
+                s => panic!("{:?} is not a valid result", s),
+            };
+            let actual_result = vrf::verify(&public_key, &alpha, &proof).map(|_| ());
+            assert_eq!(actual_result, expected_result);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_vrf_verify_wrong_length() {
+    let key_pair = vrf::KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let proof = key_pair.prove(b"alpha");
+    assert!(vrf::verify(public_key, b"alpha", proof.as_ref()).is_ok());
+    assert!(vrf::verify(public_key, b"alpha", &proof.as_ref()[..vrf::PROOF_LEN - 1]).is_err());
+    let mut too_long = proof.as_ref().to_vec();
+    too_long.push(0);
+    assert!(vrf::verify(public_key, b"alpha", &too_long).is_err());
+    assert!(vrf::verify(&public_key[1..], b"alpha", proof.as_ref()).is_err());
+}
+
+#[test]
+fn test_vrf_from_seed_and_public_key_mismatch() {
+    let key_pair = vrf::KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let other = vrf::KeyPair::from_seed_unchecked(&[8; 32]).unwrap();
+    assert!(vrf::KeyPair::from_seed_and_public_key(&[7; 32], other.public_key().as_ref()).is_err());
+    assert!(
+        vrf::KeyPair::from_seed_and_public_key(&[7; 32], key_pair.public_key().as_ref()).is_ok()
+    );
+    assert!(vrf::KeyPair::from_seed_unchecked(&[7; 31]).is_err());
+}
+
+#[test]
+fn test_vrf_uniqueness() {
+    let key_pair = vrf::KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let a = key_pair.prove(b"a").to_hash();
+    let b = key_pair.prove(b"b").to_hash();
+    assert_ne!(a.as_ref(), b.as_ref());
+    assert_eq!(a.as_ref(), key_pair.prove(b"a").to_hash().as_ref());
+}